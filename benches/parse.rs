@@ -1,5 +1,45 @@
-use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
-use duckling::{parse, Context, DimensionKind, Lang, Locale, Options};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use criterion::{
+    black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion, Throughput,
+};
+use duckling::{
+    clear_rule_cache, parse, Context, DimensionKind, Entity, Lang, Locale, Options, Parser,
+};
+
+/// Counts heap allocations so benchmarks can report them next to timings.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Heap allocations made by one call of `run`.
+fn count_allocations(run: impl FnOnce() -> Vec<Entity>) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    black_box(run());
+    ALLOCATIONS.load(Ordering::Relaxed).saturating_sub(before)
+}
 
 fn bench_parse_time(c: &mut Criterion) {
     let locale = Locale::new(Lang::EN, None);
@@ -64,10 +104,186 @@ fn bench_parse_all_dims(c: &mut Criterion) {
     });
 }
 
+fn bench_parse_time_per_lang(c: &mut Criterion) {
+    let options = Options::default();
+    let dims = [DimensionKind::Time];
+
+    let inputs: &[(Lang, &str)] = &[
+        (Lang::EN, "next tuesday at 5pm"),
+        (Lang::DE, "nächsten Dienstag um 17 Uhr"),
+        (Lang::ES, "el próximo martes a las 5 de la tarde"),
+        (Lang::FR, "mardi prochain à 17h"),
+        (Lang::PT, "3 de março de 2025"),
+        (Lang::RU, "в следующий вторник"),
+        (Lang::ZH, "下周二下午五点"),
+    ];
+
+    let mut group = c.benchmark_group("parse_time_lang");
+    for (lang, text) in inputs {
+        let locale = Locale::new(*lang, None);
+        let context = Context {
+            locale,
+            ..Context::default()
+        };
+        group.bench_with_input(
+            BenchmarkId::from_parameter(format!("{:?}", lang)),
+            text,
+            |b, text| {
+                b.iter(|| parse(black_box(text), &locale, &dims, &context, &options));
+            },
+        );
+    }
+    group.finish();
+}
+
+fn bench_parse_document(c: &mut Criterion) {
+    let locale = Locale::new(Lang::EN, None);
+    let context = Context::default();
+    let options = Options::default();
+    let dims = [];
+
+    let paragraph = "Hi team, the quarterly review moved to next Wednesday at 2:30pm and \
+        should take about 2 hours. Please send the $1,200 invoice to billing@example.com \
+        before March 3rd, and call +1 (650) 555-0199 if the 5 miles to the venue are a problem. \
+        Details are at https://example.com/review. ";
+    let document = paragraph.repeat(20);
+
+    c.bench_function("parse_document", |b| {
+        b.iter(|| parse(black_box(&document), &locale, &dims, &context, &options));
+    });
+}
+
+fn bench_rule_cache(c: &mut Criterion) {
+    let locale = Locale::new(Lang::EN, None);
+    let context = Context::default();
+    let options = Options::default();
+    let dims = [DimensionKind::Time];
+    let text = "tomorrow at 3pm";
+
+    let mut group = c.benchmark_group("rule_cache");
+    group.sample_size(10);
+    group.bench_function("cold", |b| {
+        b.iter_batched(
+            clear_rule_cache,
+            |()| parse(black_box(text), &locale, &dims, &context, &options),
+            BatchSize::PerIteration,
+        );
+    });
+    group.bench_function("warm", |b| {
+        parse(text, &locale, &dims, &context, &options);
+        b.iter(|| parse(black_box(text), &locale, &dims, &context, &options));
+    });
+    group.finish();
+}
+
+/// Latency of the first parse for a locale and dims: building the rules,
+/// compiling the regex prefilter and the regexes the text reaches.
+fn bench_cold_start(c: &mut Criterion) {
+    let context = Context::default();
+    let options = Options::default();
+
+    let inputs: &[(&str, Lang, &[DimensionKind], &str)] = &[
+        ("en_all", Lang::EN, &[], "tomorrow at 3pm for $50"),
+        (
+            "en_time",
+            Lang::EN,
+            &[DimensionKind::Time],
+            "tomorrow at 3pm",
+        ),
+        (
+            "en_numeral",
+            Lang::EN,
+            &[DimensionKind::Numeral],
+            "forty-two",
+        ),
+        ("de_all", Lang::DE, &[], "nächsten Dienstag um 17 Uhr"),
+    ];
+
+    let mut group = c.benchmark_group("cold_start");
+    group.sample_size(10);
+    for (name, lang, dims, text) in inputs {
+        let locale = Locale::new(*lang, None);
+        group.bench_with_input(BenchmarkId::new("first_parse", name), text, |b, text| {
+            b.iter_batched(
+                clear_rule_cache,
+                |()| parse(black_box(text), &locale, dims, &context, &options),
+                BatchSize::PerIteration,
+            );
+        });
+    }
+    group.finish();
+}
+
+fn bench_parallel_parse(c: &mut Criterion) {
+    const THREADS: u64 = 32;
+    let locale = Locale::new(Lang::EN, None);
+    let context = Context::default();
+    let options = Options::default();
+    let dims = [DimensionKind::Numeral];
+
+    let mut group = c.benchmark_group("parallel_parse");
+    group.throughput(Throughput::Elements(THREADS));
+    group.bench_function(BenchmarkId::new("threads", THREADS), |b| {
+        parse("42", &locale, &dims, &context, &options);
+        b.iter_custom(|iters| {
+            let start = Instant::now();
+            thread::scope(|scope| {
+                for _ in 0..THREADS {
+                    scope.spawn(|| {
+                        for _ in 0..iters {
+                            parse(black_box("42"), &locale, &dims, &context, &options);
+                        }
+                    });
+                }
+            });
+            start.elapsed().max(Duration::from_nanos(1))
+        });
+    });
+    group.finish();
+}
+
+fn bench_allocations(c: &mut Criterion) {
+    let locale = Locale::new(Lang::EN, None);
+    let context = Context::default();
+    let options = Options::default();
+    let dims = [DimensionKind::Time];
+
+    let inputs: &[(&str, &str)] = &[
+        ("short", "tomorrow at 3pm"),
+        ("long", "meet me next Wednesday at 2:30pm for about 2 hours"),
+        ("no_match", "the quick brown fox jumps over the lazy dog"),
+    ];
+
+    let mut parser = Parser::new(locale, &dims);
+    let mut group = c.benchmark_group("allocations");
+    for (name, text) in inputs {
+        // Warm the rule cache and the parser's buffers before counting.
+        parse(text, &locale, &dims, &context, &options);
+        parser.parse(text, &context, &options);
+        let oneshot = count_allocations(|| parse(text, &locale, &dims, &context, &options));
+        let reused = count_allocations(|| parser.parse(text, &context, &options));
+        println!("allocations/{name}: parse {oneshot}, Parser::parse {reused}");
+
+        group.bench_with_input(BenchmarkId::new("parse", name), text, |b, text| {
+            b.iter(|| parse(black_box(text), &locale, &dims, &context, &options));
+        });
+        group.bench_with_input(BenchmarkId::new("parser", name), text, |b, text| {
+            b.iter(|| parser.parse(black_box(text), &context, &options));
+        });
+    }
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_parse_time,
     bench_parse_numeral,
-    bench_parse_all_dims
+    bench_parse_all_dims,
+    bench_parse_time_per_lang,
+    bench_parse_document,
+    bench_rule_cache,
+    bench_cold_start,
+    bench_parallel_parse,
+    bench_allocations
 );
 criterion_main!(benches);
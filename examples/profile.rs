@@ -7,7 +7,7 @@ fn main() {
     let locale = Locale::new(Lang::EN, None);
     let context = Context::default();
     let options = Options::default();
-    let options_latent = Options { with_latent: true };
+    let options_latent = Options::builder().with_latent(true).build();
 
     // Representative inputs for each dimension
     let cases: Vec<(&[DimensionKind], &[&str])> = vec![
@@ -0,0 +1,13 @@
+// Regenerates include/duckling.h from the extern "C" functions in src/lib.rs.
+
+fn main() {
+    let crate_dir =
+        std::env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR is set by cargo");
+    println!("cargo:rerun-if-changed=src/lib.rs");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+    let config = cbindgen::Config::from_file(format!("{crate_dir}/cbindgen.toml"))
+        .expect("ffi/cbindgen.toml is valid");
+    cbindgen::generate_with_config(&crate_dir, config)
+        .expect("ffi/src/lib.rs is parseable by cbindgen")
+        .write_to_file(format!("{crate_dir}/include/duckling.h"));
+}
@@ -0,0 +1,57 @@
+#ifndef DUCKLING_H
+#define DUCKLING_H
+
+/* Generated by cbindgen from ffi/src/lib.rs; do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Parse `text` and return the entities found in it as a JSON array, in the
+ * shape of `duckling::Entity` serialized with serde.
+ *
+ * - `locale`: a code such as `"en"`, `"en_US"` or `"pt-BR"`; NULL for en_US.
+ * - `dims`: comma-separated dimension names (`"time,amount-of-money"`);
+ *   NULL or empty for all dimensions.
+ * - `reference_time_ms`: the reference time in milliseconds since the Unix
+ *   epoch, which anchors relative expressions such as "tomorrow".
+ * - `tz_offset_minutes`: the offset from UTC in minutes that wall-clock
+ *   times are read in, e.g. -120 for UTC-2.
+ *
+ * Returns NULL if an argument is not valid UTF-8, names an unknown locale or
+ * dimension, or the reference time is out of range.
+ *
+ * # Safety
+ *
+ * `text` must be a valid NUL-terminated string; `locale` and `dims` must be
+ * NULL or valid NUL-terminated strings. A non-NULL result must be released
+ * with [`duckling_free_string`] and nothing else.
+ */
+char *duckling_parse_json(const char *text,
+                          const char *locale,
+                          const char *dims,
+                          int64_t reference_time_ms,
+                          int32_t tz_offset_minutes);
+
+/**
+ * Release a string returned by [`duckling_parse_json`]. NULL is ignored.
+ *
+ * # Safety
+ *
+ * `s` must be NULL or a pointer returned by this library that has not
+ * already been freed.
+ */
+void duckling_free_string(char *s);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* DUCKLING_H */
@@ -0,0 +1,104 @@
+//! C API for the `duckling` crate, for embedding the parser in Swift, Kotlin,
+//! Go and other languages with a C FFI.
+//!
+//! The build regenerates the header `include/duckling.h` with cbindgen.
+//! Results are returned as JSON strings owned by the library; release them
+//! with [`duckling_free_string`].
+
+use std::ffi::{c_char, CStr, CString};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::ptr;
+
+use chrono::DateTime;
+use duckling::{Context, DimensionKind, Locale, Options};
+
+/// Parse `text` and return the entities found in it as a JSON array, in the
+/// shape of `duckling::Entity` serialized with serde.
+///
+/// - `locale`: a code such as `"en"`, `"en_US"` or `"pt-BR"`; NULL for en_US.
+/// - `dims`: comma-separated dimension names (`"time,amount-of-money"`);
+///   NULL or empty for all dimensions.
+/// - `reference_time_ms`: the reference time in milliseconds since the Unix
+///   epoch, which anchors relative expressions such as "tomorrow".
+/// - `tz_offset_minutes`: the offset from UTC in minutes that wall-clock
+///   times are read in, e.g. -120 for UTC-2.
+///
+/// Returns NULL if an argument is not valid UTF-8, names an unknown locale or
+/// dimension, or the reference time is out of range.
+///
+/// # Safety
+///
+/// `text` must be a valid NUL-terminated string; `locale` and `dims` must be
+/// NULL or valid NUL-terminated strings. A non-NULL result must be released
+/// with [`duckling_free_string`] and nothing else.
+#[no_mangle]
+pub unsafe extern "C" fn duckling_parse_json(
+    text: *const c_char,
+    locale: *const c_char,
+    dims: *const c_char,
+    reference_time_ms: i64,
+    tz_offset_minutes: i32,
+) -> *mut c_char {
+    let parsed = catch_unwind(AssertUnwindSafe(|| {
+        // SAFETY: the caller passes valid NUL-terminated strings or NULL.
+        let text = unsafe { str_arg(text) }?;
+        let locale = if locale.is_null() {
+            Locale::default()
+        } else {
+            Locale::from_code(unsafe { str_arg(locale) }?)?
+        };
+        let dims = if dims.is_null() {
+            Vec::new()
+        } else {
+            dims_arg(unsafe { str_arg(dims) }?)?
+        };
+        let context = Context {
+            reference_time: DateTime::from_timestamp_millis(reference_time_ms)?,
+            locale,
+            timezone_offset_minutes: tz_offset_minutes,
+            ..Context::default()
+        };
+        let entities = duckling::parse(text, &locale, &dims, &context, &Options::default());
+        let json = serde_json::to_string(&entities).ok()?;
+        CString::new(json).ok()
+    }));
+    match parsed {
+        Ok(Some(json)) => json.into_raw(),
+        _ => ptr::null_mut(),
+    }
+}
+
+/// Release a string returned by [`duckling_parse_json`]. NULL is ignored.
+///
+/// # Safety
+///
+/// `s` must be NULL or a pointer returned by this library that has not
+/// already been freed.
+#[no_mangle]
+pub unsafe extern "C" fn duckling_free_string(s: *mut c_char) {
+    if !s.is_null() {
+        // SAFETY: `s` came from `CString::into_raw` in `duckling_parse_json`.
+        drop(unsafe { CString::from_raw(s) });
+    }
+}
+
+/// # Safety
+///
+/// `s` must be NULL or a valid NUL-terminated string that outlives `'a`.
+/// Returns `None` for NULL and for strings that are not UTF-8.
+unsafe fn str_arg<'a>(s: *const c_char) -> Option<&'a str> {
+    if s.is_null() {
+        return None;
+    }
+    // SAFETY: checked non-NULL above; validity is the caller's contract.
+    unsafe { CStr::from_ptr(s) }.to_str().ok()
+}
+
+fn dims_arg(names: &str) -> Option<Vec<DimensionKind>> {
+    names
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(DimensionKind::from_name)
+        .collect()
+}
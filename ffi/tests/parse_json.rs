@@ -0,0 +1,62 @@
+use std::ffi::{CStr, CString};
+use std::ptr;
+
+use duckling_ffi::{duckling_free_string, duckling_parse_json};
+
+// 2013-02-12T04:30:00Z
+const REFERENCE_TIME_MS: i64 = 1_360_643_400_000;
+
+fn parse(text: &str, locale: Option<&str>, dims: Option<&str>) -> Option<serde_json::Value> {
+    let text = CString::new(text).unwrap();
+    let locale = locale.map(|l| CString::new(l).unwrap());
+    let dims = dims.map(|d| CString::new(d).unwrap());
+    unsafe {
+        let json = duckling_parse_json(
+            text.as_ptr(),
+            locale.as_ref().map_or(ptr::null(), |l| l.as_ptr()),
+            dims.as_ref().map_or(ptr::null(), |d| d.as_ptr()),
+            REFERENCE_TIME_MS,
+            -120,
+        );
+        if json.is_null() {
+            return None;
+        }
+        let value = serde_json::from_str(CStr::from_ptr(json).to_str().unwrap()).unwrap();
+        duckling_free_string(json);
+        Some(value)
+    }
+}
+
+#[test]
+fn parse_json_returns_entities() {
+    let entities = parse("tomorrow at 3pm", Some("en_US"), Some("time")).unwrap();
+    assert_eq!(entities[0]["body"], "tomorrow at 3pm");
+    assert_eq!(
+        entities[0]["value"]["Time"]["Single"]["value"]["Naive"]["value"],
+        "2013-02-13T15:00:00"
+    );
+}
+
+#[test]
+fn parse_json_defaults_locale_and_dims() {
+    let entities = parse("$42.50 tomorrow", None, None).unwrap();
+    let bodies: Vec<_> = entities
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| &e["body"])
+        .collect();
+    assert_eq!(bodies, ["$42.50", "tomorrow"]);
+    let entities = parse("$42.50 tomorrow", Some("en"), Some(" amount-of-money, ")).unwrap();
+    assert_eq!(entities.as_array().unwrap().len(), 1);
+}
+
+#[test]
+fn parse_json_rejects_bad_arguments() {
+    assert_eq!(parse("tomorrow", Some("xx"), None), None);
+    assert_eq!(parse("tomorrow", None, Some("time,weather")), None);
+    unsafe {
+        assert!(duckling_parse_json(ptr::null(), ptr::null(), ptr::null(), 0, 0).is_null());
+        duckling_free_string(ptr::null_mut());
+    }
+}
@@ -0,0 +1,108 @@
+//! Feeds arbitrary UTF-8 to `parse` with a random locale and set of
+//! dimensions. Fails on a panic, on a parse that takes far longer than its
+//! input length warrants, and on entities whose spans are out of bounds, not
+//! on char boundaries, or disagree with their body.
+//!
+//! cargo-fuzz builds with debug assertions, so `parse` lets panics through
+//! instead of catching them as release builds do.
+
+#![no_main]
+
+use std::time::{Duration, Instant};
+
+use arbitrary::Arbitrary;
+use duckling::{parse, Context, DimensionKind, Locale, Options};
+use libfuzzer_sys::fuzz_target;
+
+const LOCALES: &[&str] = &[
+    "af", "ar", "bg", "bn", "ca", "cs", "da", "de", "el", "en", "es", "et", "fa", "fi", "fr", "ga",
+    "he", "hi", "hr", "hu", "id", "is", "it", "ja", "ka", "km", "kn", "ko", "lo", "ml", "mn", "my",
+    "nb", "ne", "nl", "pl", "pt", "ro", "ru", "sk", "sv", "sw", "ta", "te", "th", "tr", "uk", "vi",
+    "zh", "en_US", "en_GB", "en_AU", "en_CA", "en_IN", "en_NZ", "en_PH", "en_ZA", "es_MX", "es_AR",
+    "es_CO", "fr_CA", "fr_CH", "de_AT", "de_CH", "nl_BE", "pt_BR", "zh_CN", "zh_HK", "zh_TW",
+];
+
+const DIMS: &[&str] = &[
+    "number",
+    "ordinal",
+    "temperature",
+    "distance",
+    "volume",
+    "quantity",
+    "age",
+    "amount-of-money",
+    "percentage",
+    "rate",
+    "blood-pressure",
+    "dosage",
+    "email",
+    "phone-number",
+    "url",
+    "network-address",
+    "coordinate",
+    "credit-card-number",
+    "time-grain",
+    "duration",
+    "time",
+];
+
+/// Time allowed for any parse, on top of the per-byte allowance. Generous,
+/// since fuzz builds are instrumented; a quadratic blowup on a long input
+/// still overshoots it by far.
+const BASE_LIMIT: Duration = Duration::from_secs(1);
+const PER_BYTE_LIMIT: Duration = Duration::from_millis(2);
+
+#[derive(Debug, Arbitrary)]
+struct Input<'a> {
+    locale: u8,
+    /// An index into `DIMS`, or every dimension when out of range. Rule sets
+    /// are compiled and cached per set of dimensions, so arbitrary subsets
+    /// would spend most of the run compiling rules.
+    dim: u8,
+    text: &'a str,
+}
+
+fuzz_target!(|input: Input| {
+    let locale = Locale::from_code(LOCALES[usize::from(input.locale) % LOCALES.len()]).unwrap();
+    let dims: Vec<DimensionKind> = DIMS
+        .get(usize::from(input.dim))
+        .map(|name| DimensionKind::from_name(name).unwrap())
+        .into_iter()
+        .collect();
+    let context = Context {
+        locale,
+        ..Context::default()
+    };
+    let options = Options::default();
+
+    // Compile and cache the rules first so the timing covers only the parse.
+    parse("", &locale, &dims, &context, &options);
+
+    let text = input.text;
+    let started = Instant::now();
+    let entities = parse(text, &locale, &dims, &context, &options);
+    let elapsed = started.elapsed();
+    let limit = BASE_LIMIT + PER_BYTE_LIMIT * u32::try_from(text.len()).unwrap_or(u32::MAX);
+    assert!(
+        elapsed <= limit,
+        "parsing {} bytes took {elapsed:?}",
+        text.len()
+    );
+
+    for entity in &entities {
+        assert!(
+            entity.start <= entity.end && entity.end <= text.len(),
+            "span {}..{} out of bounds for {} bytes",
+            entity.start,
+            entity.end,
+            text.len()
+        );
+        assert!(
+            text.is_char_boundary(entity.start) && text.is_char_boundary(entity.end),
+            "span {}..{} splits a char",
+            entity.start,
+            entity.end
+        );
+        assert_eq!(entity.body, text[entity.start..entity.end]);
+    }
+});
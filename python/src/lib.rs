@@ -0,0 +1,187 @@
+//! Python bindings for the `duckling` crate.
+//!
+//! Exposes `duckling.parse(text, locale, dims, reference_time, tz)` returning
+//! [`Entity`] objects. Build with `maturin develop` from this directory.
+
+use chrono::{DateTime, Duration, FixedOffset, NaiveDateTime, Utc};
+use duckling::{Context, DimensionKind, Locale, Options};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+use pyo3::IntoPyObjectExt;
+
+/// A parsed entity. `start` and `end` are character offsets into the input,
+/// so `text[e.start:e.end] == e.body`; `value` is the resolved value as
+/// plain dicts and lists, shaped like the Rust `DimensionValue`.
+#[pyclass(frozen, module = "duckling")]
+struct Entity {
+    #[pyo3(get)]
+    body: String,
+    #[pyo3(get)]
+    start: usize,
+    #[pyo3(get)]
+    end: usize,
+    #[pyo3(get)]
+    dim: String,
+    #[pyo3(get)]
+    latent: bool,
+    value: serde_json::Value,
+}
+
+impl Entity {
+    fn new(text: &str, entity: duckling::Entity) -> PyResult<Entity> {
+        let value = serde_json::to_value(&entity.value)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        Ok(Entity {
+            start: char_offset(text, entity.start),
+            end: char_offset(text, entity.end),
+            dim: entity.value.kind().to_string(),
+            latent: entity.latent.unwrap_or(false),
+            body: entity.body,
+            value,
+        })
+    }
+}
+
+#[pymethods]
+impl Entity {
+    #[getter]
+    fn value(&self, py: Python<'_>) -> PyResult<PyObject> {
+        json_to_py(py, &self.value)
+    }
+
+    /// The entity's fields as a dict.
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        dict.set_item("body", &self.body)?;
+        dict.set_item("start", self.start)?;
+        dict.set_item("end", self.end)?;
+        dict.set_item("dim", &self.dim)?;
+        dict.set_item("latent", self.latent)?;
+        dict.set_item("value", self.value(py)?)?;
+        Ok(dict)
+    }
+
+    fn __eq__(&self, other: &Entity) -> bool {
+        self.body == other.body
+            && self.start == other.start
+            && self.end == other.end
+            && self.dim == other.dim
+            && self.latent == other.latent
+            && self.value == other.value
+    }
+
+    fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
+        let body = self.body.clone().into_pyobject(py)?.repr()?;
+        let value = self.value(py)?.into_bound(py).repr()?;
+        Ok(format!(
+            "Entity(body={body}, start={}, end={}, dim='{}', latent={}, value={value})",
+            self.start,
+            self.end,
+            self.dim,
+            if self.latent { "True" } else { "False" },
+        ))
+    }
+}
+
+/// Parse `text` and return the entities found in it.
+///
+/// `locale` is a code such as "en", "en_US" or "pt-BR"; `dims` restricts the
+/// result to dimension names such as "time" or "amount-of-money" (all when
+/// omitted). `reference_time` anchors relative expressions ("tomorrow") and
+/// defaults to now; a naive datetime is read in `tz`, the offset from UTC in
+/// minutes (e.g. -120 for UTC-2), which otherwise defaults to the offset of
+/// an aware `reference_time`, or UTC.
+#[pyfunction]
+#[pyo3(signature = (text, locale = "en_US", dims = None, reference_time = None, tz = None, with_latent = false))]
+fn parse(
+    py: Python<'_>,
+    text: &str,
+    locale: &str,
+    dims: Option<Vec<String>>,
+    reference_time: Option<&Bound<'_, PyAny>>,
+    tz: Option<i32>,
+    with_latent: bool,
+) -> PyResult<Vec<Entity>> {
+    let locale = Locale::from_code(locale)
+        .ok_or_else(|| PyValueError::new_err(format!("unknown locale: {locale:?}")))?;
+    let dims = dims
+        .unwrap_or_default()
+        .iter()
+        .map(|name| {
+            DimensionKind::from_name(name)
+                .ok_or_else(|| PyValueError::new_err(format!("unknown dimension: {name:?}")))
+        })
+        .collect::<PyResult<Vec<_>>>()?;
+    let (reference_time, timezone_offset_minutes) = reference_time_and_offset(reference_time, tz)?;
+    let context = Context {
+        reference_time,
+        locale,
+        timezone_offset_minutes,
+        ..Context::default()
+    };
+    let options = Options::builder().with_latent(with_latent).build();
+    let entities = py.allow_threads(|| duckling::parse(text, &locale, &dims, &context, &options));
+    entities
+        .into_iter()
+        .map(|entity| Entity::new(text, entity))
+        .collect()
+}
+
+fn reference_time_and_offset(
+    reference_time: Option<&Bound<'_, PyAny>>,
+    tz: Option<i32>,
+) -> PyResult<(DateTime<Utc>, i32)> {
+    let Some(reference_time) = reference_time else {
+        return Ok((Utc::now(), tz.unwrap_or(0)));
+    };
+    if let Ok(aware) = reference_time.extract::<DateTime<FixedOffset>>() {
+        let offset = aware.offset().local_minus_utc() / 60;
+        return Ok((aware.with_timezone(&Utc), tz.unwrap_or(offset)));
+    }
+    let naive = reference_time.extract::<NaiveDateTime>()?;
+    let offset = tz.unwrap_or(0);
+    let utc = Duration::try_minutes(i64::from(offset))
+        .and_then(|d| naive.checked_sub_signed(d))
+        .ok_or_else(|| PyValueError::new_err("reference_time out of range"))?;
+    Ok((utc.and_utc(), offset))
+}
+
+fn char_offset(text: &str, byte_offset: usize) -> usize {
+    text.get(..byte_offset)
+        .map_or(0, |prefix| prefix.chars().count())
+}
+
+fn json_to_py(py: Python<'_>, value: &serde_json::Value) -> PyResult<PyObject> {
+    match value {
+        serde_json::Value::Null => Ok(py.None()),
+        serde_json::Value::Bool(b) => b.into_py_any(py),
+        serde_json::Value::Number(n) => match n.as_i64() {
+            Some(i) => i.into_py_any(py),
+            None => n.as_f64().unwrap_or(f64::NAN).into_py_any(py),
+        },
+        serde_json::Value::String(s) => s.into_py_any(py),
+        serde_json::Value::Array(items) => {
+            let items = items
+                .iter()
+                .map(|item| json_to_py(py, item))
+                .collect::<PyResult<Vec<_>>>()?;
+            PyList::new(py, items)?.into_py_any(py)
+        }
+        serde_json::Value::Object(map) => {
+            let dict = PyDict::new(py);
+            for (key, item) in map {
+                dict.set_item(key, json_to_py(py, item)?)?;
+            }
+            dict.into_py_any(py)
+        }
+    }
+}
+
+#[pymodule]
+#[pyo3(name = "duckling")]
+fn py_duckling(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(parse, m)?)?;
+    m.add_class::<Entity>()?;
+    Ok(())
+}
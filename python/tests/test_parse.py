@@ -0,0 +1,49 @@
+import datetime
+
+import pytest
+
+import duckling
+
+REF = datetime.datetime(2013, 2, 12, 4, 30)
+
+
+def test_parse_time():
+    [entity] = duckling.parse("tomorrow at 3pm", "en_US", ["time"], REF, -120)
+    assert entity.body == "tomorrow at 3pm"
+    assert (entity.start, entity.end) == (0, 15)
+    assert entity.dim == "time"
+    assert not entity.latent
+    point = entity.value["Time"]["Single"]["value"]["Naive"]
+    assert point == {"value": "2013-02-13T15:00:00", "grain": "Hour"}
+
+
+def test_offsets_are_characters():
+    text = "café costs €5"
+    [entity] = duckling.parse(text, dims=["amount-of-money"])
+    assert text[entity.start : entity.end] == "€5"
+    assert entity.value == {"AmountOfMoney": {"Value": {"value": 5.0, "unit": "EUR"}}}
+
+
+def test_aware_reference_time_sets_tz():
+    aware = REF.replace(tzinfo=datetime.timezone(datetime.timedelta(hours=-2)))
+    naive = duckling.parse("tomorrow", dims=["time"], reference_time=REF, tz=-120)
+    assert duckling.parse("tomorrow", dims=["time"], reference_time=aware) == naive
+
+
+def test_to_dict():
+    [entity] = duckling.parse("forty-two", dims=["number"])
+    assert entity.to_dict() == {
+        "body": "forty-two",
+        "start": 0,
+        "end": 9,
+        "dim": "number",
+        "latent": False,
+        "value": {"Numeral": 42.0},
+    }
+
+
+def test_rejects_unknown_locale_and_dims():
+    with pytest.raises(ValueError):
+        duckling.parse("tomorrow", "xx")
+    with pytest.raises(ValueError):
+        duckling.parse("tomorrow", dims=["weather"])
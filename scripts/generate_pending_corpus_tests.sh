@@ -103,6 +103,7 @@ fn parse_context(locale: Locale) -> Context {
         reference_time: Utc.with_ymd_and_hms(2013, 2, 12, 4, 30, 0).unwrap(),
         locale,
         timezone_offset_minutes: -120,
+        ..Context::default()
     }
 }
 
@@ -279,7 +280,7 @@ while IFS=$'\t' read -r dim lang locale rel; do
     echo "    let dim = dim_from_name(\"$dim\");"
     echo "    let locale = Locale::new(lang_from_code(\"$lang\"), region_from_code(\"$locale\"));"
     echo "    let ctx = parse_context(locale);"
-    echo "    let options = Options { with_latent: true };"
+    echo "    let options = Options::builder().with_latent(true).build();"
     echo "    let utterances = extract_latent_examples_strings(corpus);"
     echo "    if utterances.is_empty() { return; }"
     echo "    for utterance in utterances {"
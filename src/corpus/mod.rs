@@ -30,7 +30,10 @@ pub fn build_corpus(
     }
     TrainingCorpus {
         context,
-        options: Options { with_latent: false },
+        options: Options {
+            with_latent: false,
+            ..Options::default()
+        },
         examples,
     }
 }
@@ -9,6 +9,7 @@ pub fn corpus() -> TrainingCorpus {
         reference_time: Utc.with_ymd_and_hms(2013, 2, 12, 4, 30, 0).unwrap(),
         locale: crate::locale::Locale::new(crate::locale::Lang::EN, None),
         timezone_offset_minutes: -120,
+        ..crate::resolve::Context::default()
     };
     build_corpus(
         context,
@@ -1221,7 +1222,7 @@ pub fn corpus() -> TrainingCorpus {
                 vec!["by 2:00pm"],
             ),
             examples(
-                datetime_interval(2013, 2, 12, 4, 30, 0, 2013, 2, 13, 0, 0, 0, Grain::Second),
+                datetime_open_interval_before(2013, 2, 12, 17, 0, 0, Grain::Hour),
                 vec!["by EOD"],
             ),
             examples(
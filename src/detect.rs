@@ -0,0 +1,271 @@
+use crate::locale::Lang;
+
+/// Picks the language of a text for [`parse_auto`](crate::parse_auto).
+///
+/// Implement it to plug in a detector such as `whatlang` or `lingua`; any
+/// `Fn(&str) -> Option<Lang>` closure works as one too.
+///
+/// # Example
+/// ```
+/// use duckling::{parse_auto, Context, DimensionKind, Lang, LangDetector, Options};
+///
+/// let detector = |text: &str| text.contains("morgen").then_some(Lang::DE);
+/// assert_eq!(detector.detect("morgen um 8"), Some(Lang::DE));
+///
+/// let entities = parse_auto("morgen um 8", &detector, &[DimensionKind::Time], &Context::default(), &Options::default());
+/// assert_eq!(entities[0].body, "morgen um 8");
+/// ```
+pub trait LangDetector {
+    /// The language `text` is written in, or `None` when unsure.
+    fn detect(&self, text: &str) -> Option<Lang>;
+}
+
+impl<F> LangDetector for F
+where
+    F: Fn(&str) -> Option<Lang>,
+{
+    fn detect(&self, text: &str) -> Option<Lang> {
+        self(text)
+    }
+}
+
+/// A small built-in [`LangDetector`]: the script for non-Latin text, and
+/// counts of common function words for a few Latin-script languages.
+///
+/// Good enough to route short messages between a handful of languages;
+/// plug in a statistical detector for anything finer.
+#[cfg(feature = "lang-detect")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HeuristicDetector;
+
+#[cfg(feature = "lang-detect")]
+impl LangDetector for HeuristicDetector {
+    fn detect(&self, text: &str) -> Option<Lang> {
+        heuristic::by_script(text).or_else(|| heuristic::by_words(text))
+    }
+}
+
+#[cfg(feature = "lang-detect")]
+mod heuristic {
+    use crate::locale::Lang;
+
+    /// Function words per Latin-script language, lowercase.
+    const WORDS: &[(Lang, &[&str])] = &[
+        (
+            Lang::EN,
+            &[
+                "the", "and", "is", "are", "of", "to", "at", "on", "with", "for", "tomorrow",
+                "next", "this", "you", "it", "my",
+            ],
+        ),
+        (
+            Lang::DE,
+            &[
+                "der", "die", "das", "und", "ist", "nicht", "mit", "um", "am", "morgen", "uhr",
+                "ich", "ein", "eine", "für", "zu", "auf",
+            ],
+        ),
+        (
+            Lang::FR,
+            &[
+                "le", "les", "et", "est", "des", "une", "un", "à", "demain", "pour", "avec",
+                "dans", "du", "au", "je", "pas", "ce",
+            ],
+        ),
+        (
+            Lang::ES,
+            &[
+                "el", "los", "las", "y", "es", "que", "mañana", "para", "con", "una", "por", "del",
+                "al", "está",
+            ],
+        ),
+        (
+            Lang::IT,
+            &[
+                "il", "lo", "gli", "è", "di", "che", "per", "domani", "alle", "del", "non", "sono",
+                "della",
+            ],
+        ),
+        (
+            Lang::PT,
+            &[
+                "o", "os", "é", "em", "amanhã", "com", "uma", "não", "do", "da", "às", "você",
+            ],
+        ),
+        (
+            Lang::NL,
+            &[
+                "het", "een", "en", "van", "morgen", "om", "met", "voor", "niet", "ik", "op",
+                "dat", "uur",
+            ],
+        ),
+        (
+            Lang::SV,
+            &[
+                "och", "är", "att", "det", "på", "med", "för", "imorgon", "klockan", "inte", "jag",
+                "som",
+            ],
+        ),
+        (
+            Lang::PL,
+            &[
+                "w", "z", "jest", "na", "się", "nie", "do", "jutro", "że", "o", "godzinie",
+            ],
+        ),
+        (
+            Lang::TR,
+            &["ve", "bir", "bu", "için", "ile", "yarın", "saat", "değil"],
+        ),
+    ];
+
+    /// The language of the dominant non-Latin script, if letters of one
+    /// outnumber Latin letters.
+    pub(super) fn by_script(text: &str) -> Option<Lang> {
+        let mut latin = 0usize;
+        let mut counts: Vec<(Lang, usize)> = Vec::new();
+        let mut kana = false;
+        let mut ukrainian = false;
+        let mut persian = false;
+        for ch in text.chars().filter(|c| c.is_alphabetic()) {
+            let lang = match ch {
+                'a'..='z' | 'A'..='Z' | '\u{00C0}'..='\u{024F}' => {
+                    latin = latin.saturating_add(1);
+                    continue;
+                }
+                '\u{3040}'..='\u{30FF}' => {
+                    kana = true;
+                    Lang::JA
+                }
+                '\u{4E00}'..='\u{9FFF}' => Lang::ZH,
+                '\u{AC00}'..='\u{D7AF}' | '\u{1100}'..='\u{11FF}' => Lang::KO,
+                '\u{0400}'..='\u{04FF}' => {
+                    ukrainian |= matches!(ch, 'і' | 'ї' | 'є' | 'ґ' | 'І' | 'Ї' | 'Є' | 'Ґ');
+                    Lang::RU
+                }
+                '\u{0370}'..='\u{03FF}' => Lang::EL,
+                '\u{0590}'..='\u{05FF}' => Lang::HE,
+                '\u{0600}'..='\u{06FF}' => {
+                    persian |= matches!(ch, 'پ' | 'چ' | 'ژ' | 'گ' | 'ی');
+                    Lang::AR
+                }
+                '\u{0900}'..='\u{097F}' => Lang::HI,
+                '\u{0980}'..='\u{09FF}' => Lang::BN,
+                '\u{0B80}'..='\u{0BFF}' => Lang::TA,
+                '\u{0C00}'..='\u{0C7F}' => Lang::TE,
+                '\u{0C80}'..='\u{0CFF}' => Lang::KN,
+                '\u{0D00}'..='\u{0D7F}' => Lang::ML,
+                '\u{0E00}'..='\u{0E7F}' => Lang::TH,
+                '\u{0E80}'..='\u{0EFF}' => Lang::LO,
+                '\u{1000}'..='\u{109F}' => Lang::MY,
+                '\u{10A0}'..='\u{10FF}' => Lang::KA,
+                '\u{1780}'..='\u{17FF}' => Lang::KM,
+                _ => continue,
+            };
+            match counts.iter_mut().find(|(l, _)| *l == lang) {
+                Some((_, n)) => *n = n.saturating_add(1),
+                None => counts.push((lang, 1)),
+            }
+        }
+        // Japanese mixes kanji with kana; count them together.
+        if kana {
+            let han = counts
+                .iter()
+                .find(|(l, _)| *l == Lang::ZH)
+                .map_or(0, |(_, n)| *n);
+            counts.retain(|(l, _)| *l != Lang::ZH);
+            if let Some((_, n)) = counts.iter_mut().find(|(l, _)| *l == Lang::JA) {
+                *n = n.saturating_add(han);
+            }
+        }
+        let (lang, count) = counts.into_iter().max_by_key(|(_, n)| *n)?;
+        if count <= latin {
+            return None;
+        }
+        Some(match lang {
+            Lang::RU if ukrainian => Lang::UK,
+            Lang::AR if persian => Lang::FA,
+            lang => lang,
+        })
+    }
+
+    /// The language with the most function words in `text`, if one leads.
+    pub(super) fn by_words(text: &str) -> Option<Lang> {
+        let lower = text.to_lowercase();
+        let words: Vec<&str> = lower
+            .split(|c: char| !c.is_alphabetic())
+            .filter(|w| !w.is_empty())
+            .collect();
+        let mut scores: Vec<(Lang, usize)> = WORDS
+            .iter()
+            .map(|(lang, list)| {
+                let hits = words.iter().filter(|w| list.contains(w)).count();
+                (*lang, hits)
+            })
+            .collect();
+        scores.sort_by_key(|(_, hits)| std::cmp::Reverse(*hits));
+        match scores.as_slice() {
+            [(lang, best), (_, next), ..] if *best > *next => Some(*lang),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resolve::{Context, Options};
+    use crate::types::DimensionKind;
+
+    #[test]
+    fn parse_auto_uses_detected_language() {
+        let detector = |text: &str| text.contains("demain").then_some(Lang::FR);
+        let dims = [DimensionKind::Numeral];
+        let options = Options::default();
+        let context = Context::default();
+        let french = crate::parse_auto("demain, vingt-deux", &detector, &dims, &context, &options);
+        assert_eq!(french.len(), 1, "{french:?}");
+        assert_eq!(french[0].body, "vingt-deux");
+        // Undetected text falls back to the context's locale.
+        let english = crate::parse_auto("twenty-two", &detector, &dims, &context, &options);
+        assert_eq!(english.len(), 1, "{english:?}");
+    }
+
+    #[test]
+    fn parse_auto_sentences_switches_language_per_sentence() {
+        let detector = |text: &str| {
+            if text.contains("Uhr") {
+                Some(Lang::DE)
+            } else if text.contains("at") {
+                Some(Lang::EN)
+            } else {
+                None
+            }
+        };
+        let text = "Wir treffen uns um drei Uhr. See you at four.";
+        let entities = crate::parse_auto_sentences(
+            text,
+            &detector,
+            &[DimensionKind::Numeral],
+            &Context::default(),
+            &Options::default(),
+        );
+        let bodies: Vec<&str> = entities.iter().map(|e| &text[e.start..e.end]).collect();
+        assert_eq!(bodies, ["drei", "four"]);
+    }
+
+    #[cfg(feature = "lang-detect")]
+    #[test]
+    fn heuristic_detector_reads_scripts_and_function_words() {
+        let detect = |text: &str| HeuristicDetector.detect(text);
+        assert_eq!(detect("see you tomorrow at the office"), Some(Lang::EN));
+        assert_eq!(detect("wir sehen uns morgen um 8 Uhr"), Some(Lang::DE));
+        assert_eq!(detect("on se voit demain à 8 heures"), Some(Lang::FR));
+        assert_eq!(detect("nos vemos mañana por la tarde"), Some(Lang::ES));
+        assert_eq!(detect("увидимся завтра"), Some(Lang::RU));
+        assert_eq!(detect("побачимося завтра ввечері і вдома"), Some(Lang::UK));
+        assert_eq!(detect("明日の午後3時に会いましょう"), Some(Lang::JA));
+        assert_eq!(detect("明天下午三点见"), Some(Lang::ZH));
+        assert_eq!(detect("내일 만나요"), Some(Lang::KO));
+        assert_eq!(detect("42"), None);
+    }
+}
@@ -0,0 +1,259 @@
+use crate::dimensions::numeral::helpers::{is_positive, numeral_data};
+use crate::pattern::{predicate, regex};
+use crate::types::{PatternItem, Rule, TokenData};
+
+use super::{AgeData, AgeUnit};
+
+fn age_data(token_data: &TokenData) -> Option<&AgeData> {
+    match token_data {
+        TokenData::Age(data) => Some(data),
+        _ => None,
+    }
+}
+
+/// Matches Age tokens with a single value.
+fn is_simple_age() -> PatternItem {
+    predicate(|td| matches!(td, TokenData::Age(data) if data.value.is_some()))
+}
+
+fn regex_text(token_data: &TokenData) -> Option<String> {
+    match token_data {
+        TokenData::RegexMatch(m) => Some(m.group(0)?.to_lowercase()),
+        _ => None,
+    }
+}
+
+/// First year of a decade written as "forties" or "40s".
+fn decade_start(s: &str) -> Option<f64> {
+    let start = match s {
+        "twenties" => 20.0,
+        "thirties" => 30.0,
+        "forties" => 40.0,
+        "fifties" => 50.0,
+        "sixties" => 60.0,
+        "seventies" => 70.0,
+        "eighties" => 80.0,
+        "nineties" => 90.0,
+        _ => s.get(..2)?.parse::<f64>().ok()?,
+    };
+    Some(start)
+}
+
+pub fn rules() -> Vec<Rule> {
+    vec![
+        // "32 years old", "32-year-old", "6 months old", "5 y/o"
+        Rule {
+            name: "<number> years|months old".to_string(),
+            pattern: vec![
+                predicate(is_positive),
+                regex(r"(-?(years?|yrs?|months?|mos?)[- ]old|y/o|yo)\b"),
+            ],
+            production: Box::new(|nodes| {
+                let num = numeral_data(&nodes[0].token_data)?;
+                let unit = if regex_text(&nodes[1].token_data)?
+                    .trim_start_matches('-')
+                    .starts_with("mo")
+                {
+                    AgeUnit::Month
+                } else {
+                    AgeUnit::Year
+                };
+                Some(TokenData::Age(AgeData::new(num.value, unit)))
+            }),
+        },
+        // "aged 5", "age 40"
+        Rule {
+            name: "aged <number>".to_string(),
+            pattern: vec![regex(r"\baged?"), predicate(is_positive)],
+            production: Box::new(|nodes| {
+                let num = numeral_data(&nodes[1].token_data)?;
+                Some(TokenData::Age(AgeData::new(num.value, AgeUnit::Year)))
+            }),
+        },
+        // "aged 5-7", "ages 18 to 25", "aged between 30 and 40"
+        Rule {
+            name: "aged <number> - <number>".to_string(),
+            pattern: vec![
+                regex(r"\bage[ds]?( between| from)?"),
+                predicate(is_positive),
+                regex(r"\-|to|and"),
+                predicate(is_positive),
+            ],
+            production: Box::new(|nodes| {
+                let from = numeral_data(&nodes[1].token_data)?.value;
+                let to = numeral_data(&nodes[3].token_data)?.value;
+                if from >= to {
+                    return None;
+                }
+                Some(TokenData::Age(AgeData::interval(from, to, AgeUnit::Year)))
+            }),
+        },
+        // "5-7 years old", "2 to 4 months old"
+        Rule {
+            name: "<number> - <age>".to_string(),
+            pattern: vec![predicate(is_positive), regex(r"\-|to"), is_simple_age()],
+            production: Box::new(|nodes| {
+                let from = numeral_data(&nodes[0].token_data)?.value;
+                let age = age_data(&nodes[2].token_data)?;
+                let to = age.value?;
+                if from >= to {
+                    return None;
+                }
+                Some(TokenData::Age(AgeData::interval(from, to, age.unit)))
+            }),
+        },
+        // "in her 40s", "in his early thirties", "in their late 20's"
+        Rule {
+            name: "in <possessive> <decade>s".to_string(),
+            pattern: vec![regex(
+                r"in (?:his|her|their|my|your|our|the)\s+(?:(early|mid|late)[\s-]+)?(twenties|thirties|forties|fifties|sixties|seventies|eighties|nineties|[1-9]0'?s)\b",
+            )],
+            production: Box::new(|nodes| {
+                let (part, decade) = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => (
+                        m.group(1).map(str::to_lowercase),
+                        m.group(2)?.to_lowercase(),
+                    ),
+                    _ => return None,
+                };
+                let start = decade_start(&decade)?;
+                let (from, to) = match part.as_deref() {
+                    Some("early") => (start, start + 3.0),
+                    Some("mid") => (start + 3.0, start + 6.0),
+                    Some("late") => (start + 6.0, start + 9.0),
+                    _ => (start, start + 9.0),
+                };
+                Some(TokenData::Age(AgeData::interval(from, to, AgeUnit::Year)))
+            }),
+        },
+        // "under 18 years old", "younger than 6 months old"
+        Rule {
+            name: "under <age>".to_string(),
+            pattern: vec![
+                regex(r"under|below|younger than|less than"),
+                is_simple_age(),
+            ],
+            production: Box::new(|nodes| {
+                let age = age_data(&nodes[1].token_data)?;
+                Some(TokenData::Age(AgeData::with_max(age.value?, age.unit)))
+            }),
+        },
+        // "over 65 years old", "older than 2 years old"
+        Rule {
+            name: "over <age>".to_string(),
+            pattern: vec![regex(r"over|above|older than|at least"), is_simple_age()],
+            production: Box::new(|nodes| {
+                let age = age_data(&nodes[1].token_data)?;
+                Some(TokenData::Age(AgeData::with_min(age.value?, age.unit)))
+            }),
+        },
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dimensions::numeral;
+    use crate::engine;
+    use crate::resolve::{Context, Options};
+    use crate::types::{DimensionKind, DimensionValue, MeasurementPoint, MeasurementValue};
+
+    fn parse_age(text: &str) -> Vec<DimensionValue> {
+        let mut all_rules = numeral::en::rules();
+        all_rules.extend(rules());
+        engine::parse_and_resolve(
+            text,
+            &all_rules,
+            &Context::default(),
+            &Options::default(),
+            &[DimensionKind::Age],
+        )
+        .into_iter()
+        .map(|e| e.value)
+        .collect()
+    }
+
+    fn interval(from: Option<f64>, to: Option<f64>, unit: AgeUnit) -> DimensionValue {
+        let point = |value| MeasurementPoint {
+            value,
+            unit: unit.into(),
+        };
+        DimensionValue::Age(MeasurementValue::Interval {
+            from: from.map(point),
+            to: to.map(point),
+        })
+    }
+
+    #[test]
+    fn test_age_values() {
+        for (text, value, unit) in [
+            ("32 years old", 32.0, AgeUnit::Year),
+            ("a 32-year-old patient", 32.0, AgeUnit::Year),
+            ("she is 6 months old", 6.0, AgeUnit::Month),
+            ("aged 5", 5.0, AgeUnit::Year),
+            ("5 y/o", 5.0, AgeUnit::Year),
+        ] {
+            let expected = DimensionValue::Age(MeasurementValue::Value {
+                value,
+                unit: unit.into(),
+            });
+            let values = parse_age(text);
+            assert!(
+                values.contains(&expected),
+                "Expected {:?} for '{}', got: {:?}",
+                expected,
+                text,
+                values
+            );
+        }
+    }
+
+    #[test]
+    fn test_age_intervals() {
+        for (text, expected) in [
+            (
+                "in her 40s",
+                interval(Some(40.0), Some(49.0), AgeUnit::Year),
+            ),
+            (
+                "in his early thirties",
+                interval(Some(30.0), Some(33.0), AgeUnit::Year),
+            ),
+            ("aged 5-7", interval(Some(5.0), Some(7.0), AgeUnit::Year)),
+            (
+                "5 to 7 years old",
+                interval(Some(5.0), Some(7.0), AgeUnit::Year),
+            ),
+            (
+                "under 18 years old",
+                interval(None, Some(18.0), AgeUnit::Year),
+            ),
+            (
+                "over 65 years old",
+                interval(Some(65.0), None, AgeUnit::Year),
+            ),
+        ] {
+            let values = parse_age(text);
+            assert!(
+                values.contains(&expected),
+                "Expected {:?} for '{}', got: {:?}",
+                expected,
+                text,
+                values
+            );
+        }
+    }
+
+    #[test]
+    fn test_age_negatives() {
+        for text in ["32", "32 years", "average 5", "5 young kids"] {
+            let values = parse_age(text);
+            assert!(
+                values.is_empty(),
+                "Expected no age for '{}', got: {:?}",
+                text,
+                values
+            );
+        }
+    }
+}
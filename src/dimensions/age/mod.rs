@@ -0,0 +1,82 @@
+pub mod en;
+
+use crate::types::{DimensionValue, MeasurementPoint, MeasurementValue, Unit};
+
+#[derive(Debug, Clone)]
+pub struct AgeData {
+    pub value: Option<f64>,
+    pub unit: AgeUnit,
+    pub min_value: Option<f64>,
+    pub max_value: Option<f64>,
+}
+
+/// The unit of an age.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AgeUnit {
+    /// Years.
+    Year,
+    /// Months.
+    Month,
+}
+
+impl AgeUnit {
+    /// Returns the unit name (e.g. `"year"`).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AgeUnit::Year => "year",
+            AgeUnit::Month => "month",
+        }
+    }
+}
+
+impl AgeData {
+    pub fn new(value: f64, unit: AgeUnit) -> Self {
+        AgeData {
+            value: Some(value),
+            unit,
+            min_value: None,
+            max_value: None,
+        }
+    }
+
+    pub fn interval(from: f64, to: f64, unit: AgeUnit) -> Self {
+        AgeData {
+            value: None,
+            unit,
+            min_value: Some(from),
+            max_value: Some(to),
+        }
+    }
+
+    pub fn with_min(value: f64, unit: AgeUnit) -> Self {
+        AgeData {
+            value: None,
+            unit,
+            min_value: Some(value),
+            max_value: None,
+        }
+    }
+
+    pub fn with_max(value: f64, unit: AgeUnit) -> Self {
+        AgeData {
+            value: None,
+            unit,
+            min_value: None,
+            max_value: Some(value),
+        }
+    }
+}
+
+pub fn resolve(data: &AgeData) -> Option<DimensionValue> {
+    let unit = Unit::Age(data.unit);
+    let point = |value: f64| MeasurementPoint { value, unit };
+    let mv = match (data.value, data.min_value, data.max_value) {
+        (Some(v), _, _) => MeasurementValue::Value { value: v, unit },
+        (None, None, None) => return None,
+        (None, from, to) => MeasurementValue::Interval {
+            from: from.map(point),
+            to: to.map(point),
+        },
+    };
+    Some(DimensionValue::Age(mv))
+}
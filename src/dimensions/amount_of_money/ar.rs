@@ -1,7 +1,7 @@
 use crate::pattern::regex;
 use crate::types::{Rule, TokenData};
 
-use super::{AmountOfMoneyData, Currency};
+use super::{AmountOfMoneyData, MoneyCurrency};
 
 pub fn rules() -> Vec<Rule> {
     vec![Rule {
@@ -13,23 +13,23 @@ pub fn rules() -> Vec<Rule> {
                 _ => return None,
             };
             let c = if m.contains("€") || m.contains("يورو") || m.contains("اورو") || m.contains("أورو") {
-                Currency::EUR
+                MoneyCurrency::EUR
             } else if m.contains('£') || m.contains("جنيه") {
-                Currency::Pound
+                MoneyCurrency::Pound
             } else if m.contains("سنت") || m.contains("سينت") || m.contains('¢') {
-                Currency::Cent
+                MoneyCurrency::Cent
             } else if m.contains("دينار") || m.contains("KWD") || m.contains("JOD") {
-                Currency::Dinar
+                MoneyCurrency::Dinar
             } else if m.contains("ريال") || m.contains("QAR") || m.contains("SAR") {
-                Currency::Riyal
+                MoneyCurrency::Riyal
             } else if m.contains("ليرة") || m.contains("LBP") {
-                Currency::LBP
+                MoneyCurrency::LBP
             } else if m.contains("شيقل") || m.contains("ILS") {
-                Currency::ILS
+                MoneyCurrency::ILS
             } else if m.contains("EGP") || m.contains("ج.م") {
-                Currency::EGP
+                MoneyCurrency::EGP
             } else {
-                Currency::Dollar
+                MoneyCurrency::Dollar
             };
             Some(TokenData::AmountOfMoney(
                 AmountOfMoneyData::currency_only(c).with_value(1.0),
@@ -1,8 +1,8 @@
 use crate::dimensions::numeral::helpers::{is_natural, is_positive, numeral_data};
 use crate::pattern::{predicate, regex};
-use crate::types::{Rule, TokenData};
+use crate::types::{Precision, Rule, TokenData};
 
-use super::{AmountOfMoneyData, Currency};
+use super::{AmountOfMoneyData, MoneyCurrency};
 
 fn money_data(td: &TokenData) -> Option<&AmountOfMoneyData> {
     match td {
@@ -38,14 +38,14 @@ fn is_simple_amount_of_money(td: &TokenData) -> bool {
 fn is_without_cents(td: &TokenData) -> bool {
     match td {
         TokenData::AmountOfMoney(d) => {
-            d.currency != Currency::Cent && d.value.map(|v| v == v.floor()).unwrap_or(false)
+            d.currency != MoneyCurrency::Cent && d.value.map(|v| v == v.floor()).unwrap_or(false)
         }
         _ => false,
     }
 }
 
 fn is_cents(td: &TokenData) -> bool {
-    matches!(td, TokenData::AmountOfMoney(d) if d.currency == Currency::Cent && d.value.is_some())
+    matches!(td, TokenData::AmountOfMoney(d) if d.currency == MoneyCurrency::Cent && d.value.is_some())
 }
 
 pub fn rules() -> Vec<Rule> {
@@ -66,7 +66,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("ле?ва?")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::BGN,
+                    MoneyCurrency::BGN,
                 )))
             }),
         },
@@ -75,7 +75,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("долар(а|и)?")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::Dollar,
+                    MoneyCurrency::Dollar,
                 )))
             }),
         },
@@ -88,7 +88,7 @@ pub fn rules() -> Vec<Rule> {
                     _ => return None,
                 };
                 Some(TokenData::AmountOfMoney(
-                    AmountOfMoneyData::currency_only(Currency::Pound).with_value(v),
+                    AmountOfMoneyData::currency_only(MoneyCurrency::Pound).with_value(v),
                 ))
             }),
         },
@@ -97,7 +97,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("ст(отинк(a|и))?|цента?|пени(та)?|пенса?|ц")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::Cent,
+                    MoneyCurrency::Cent,
                 )))
             }),
         },
@@ -110,7 +110,7 @@ pub fn rules() -> Vec<Rule> {
                     _ => return None,
                 };
                 Some(TokenData::AmountOfMoney(
-                    AmountOfMoneyData::currency_only(Currency::EUR).with_value(v),
+                    AmountOfMoneyData::currency_only(MoneyCurrency::EUR).with_value(v),
                 ))
             }),
         },
@@ -119,7 +119,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("евр(о|а)")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::EUR,
+                    MoneyCurrency::EUR,
                 )))
             }),
         },
@@ -128,7 +128,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("паунд(а|и)?")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::Pound,
+                    MoneyCurrency::Pound,
                 )))
             }),
         },
@@ -285,7 +285,14 @@ pub fn rules() -> Vec<Rule> {
                 regex("точно|около|приблизително|близо (до)?|почти"),
                 predicate(is_money_with_value),
             ],
-            production: Box::new(|nodes| Some(nodes[1].token_data.clone())),
+            production: Box::new(|nodes| {
+                Some(
+                    nodes[1]
+                        .token_data
+                        .clone()
+                        .with_precision(Precision::of_qualifier(nodes[0])),
+                )
+            }),
         },
     ]
 }
@@ -2,7 +2,7 @@ use crate::dimensions::numeral::helpers::{is_natural, is_positive, numeral_data}
 use crate::pattern::{predicate, regex};
 use crate::types::{Rule, TokenData};
 
-use super::{AmountOfMoneyData, Currency};
+use super::{AmountOfMoneyData, MoneyCurrency};
 
 fn money_data(td: &TokenData) -> Option<&AmountOfMoneyData> {
     match td {
@@ -30,14 +30,14 @@ fn is_simple_money(td: &TokenData) -> bool {
 fn is_without_cents(td: &TokenData) -> bool {
     match td {
         TokenData::AmountOfMoney(d) => {
-            d.currency != Currency::Cent && d.value.map(|v| v == v.floor()).unwrap_or(false)
+            d.currency != MoneyCurrency::Cent && d.value.map(|v| v == v.floor()).unwrap_or(false)
         }
         _ => false,
     }
 }
 
 fn is_cents(td: &TokenData) -> bool {
-    matches!(td, TokenData::AmountOfMoney(d) if d.currency == Currency::Cent && d.value.is_some())
+    matches!(td, TokenData::AmountOfMoney(d) if d.currency == MoneyCurrency::Cent && d.value.is_some())
 }
 
 pub fn rules() -> Vec<Rule> {
@@ -58,7 +58,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("c(e|è)ntims?")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::Cent,
+                    MoneyCurrency::Cent,
                 )))
             }),
         },
@@ -67,7 +67,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("d(ò|o|ó)lar(s)?")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::Dollar,
+                    MoneyCurrency::Dollar,
                 )))
             }),
         },
@@ -76,7 +76,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("(lliure|lliura)s?")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::Pound,
+                    MoneyCurrency::Pound,
                 )))
             }),
         },
@@ -0,0 +1,33 @@
+use crate::locale::Region;
+use crate::pattern::regex;
+use crate::types::{Rule, TokenData};
+
+use super::{AmountOfMoneyData, MoneyCurrency};
+
+/// Region overlays for German, whose amounts otherwise come from the common
+/// rules: Swiss francs and their Rappen.
+pub fn locale_rules(region: Region) -> Vec<Rule> {
+    match region {
+        Region::CH => vec![
+            Rule {
+                name: "Franken (de_CH)".to_string(),
+                pattern: vec![regex("(schweizer )?franken|\\bfr\\.|\\bstutz\\b")],
+                production: Box::new(|_| {
+                    Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
+                        MoneyCurrency::CHF,
+                    )))
+                }),
+            },
+            Rule {
+                name: "Rappen (de_CH)".to_string(),
+                pattern: vec![regex("rappen|\\brp\\.")],
+                production: Box::new(|_| {
+                    Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
+                        MoneyCurrency::Cent,
+                    )))
+                }),
+            },
+        ],
+        _ => Vec::new(),
+    }
+}
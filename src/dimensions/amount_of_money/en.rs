@@ -1,8 +1,8 @@
 use crate::dimensions::numeral::helpers::{is_natural, is_positive, numeral_data};
 use crate::pattern::{predicate, regex};
-use crate::types::{Rule, TokenData};
+use crate::types::{Precision, Rule, TokenData};
 
-use super::{AmountOfMoneyData, Currency};
+use super::{AmountOfMoneyData, MoneyCurrency};
 
 // ---------------------------------------------------------------------------
 // Helpers
@@ -45,7 +45,7 @@ fn is_money_with_value(td: &TokenData) -> bool {
 fn is_without_cents(td: &TokenData) -> bool {
     match td {
         TokenData::AmountOfMoney(d) => {
-            d.currency != Currency::Cent && d.value.map(|v| v == v.floor()).unwrap_or(false)
+            d.currency != MoneyCurrency::Cent && d.value.map(|v| v == v.floor()).unwrap_or(false)
         }
         _ => false,
     }
@@ -53,68 +53,68 @@ fn is_without_cents(td: &TokenData) -> bool {
 
 fn is_cents(td: &TokenData) -> bool {
     match td {
-        TokenData::AmountOfMoney(d) => d.currency == Currency::Cent && d.value.is_some(),
+        TokenData::AmountOfMoney(d) => d.currency == MoneyCurrency::Cent && d.value.is_some(),
         _ => false,
     }
 }
 
-fn lookup_currency(s: &str) -> Option<Currency> {
+fn lookup_currency(s: &str) -> Option<MoneyCurrency> {
     match s {
-        "aed" => Some(Currency::AED),
-        "aud" => Some(Currency::AUD),
-        "bgn" => Some(Currency::BGN),
-        "brl" => Some(Currency::BRL),
-        "byn" => Some(Currency::BYN),
-        "cad" => Some(Currency::CAD),
-        "\u{00a2}" | "c" => Some(Currency::Cent),
-        "chf" => Some(Currency::CHF),
-        "cny" | "rmb" | "yuan" => Some(Currency::CNY),
-        "czk" => Some(Currency::CZK),
-        "$" => Some(Currency::Dollar),
-        "dinar" | "dinars" => Some(Currency::Dinar),
-        "dkk" => Some(Currency::DKK),
-        "dollar" | "dollars" => Some(Currency::Dollar),
-        "egp" => Some(Currency::EGP),
+        "aed" => Some(MoneyCurrency::AED),
+        "aud" | "a$" | "au$" => Some(MoneyCurrency::AUD),
+        "bgn" => Some(MoneyCurrency::BGN),
+        "brl" | "r$" => Some(MoneyCurrency::BRL),
+        "byn" => Some(MoneyCurrency::BYN),
+        "cad" | "c$" | "ca$" => Some(MoneyCurrency::CAD),
+        "\u{00a2}" | "c" => Some(MoneyCurrency::Cent),
+        "chf" => Some(MoneyCurrency::CHF),
+        "cny" | "rmb" | "yuan" => Some(MoneyCurrency::CNY),
+        "czk" => Some(MoneyCurrency::CZK),
+        "$" => Some(MoneyCurrency::Dollar),
+        "dinar" | "dinars" => Some(MoneyCurrency::Dinar),
+        "dkk" => Some(MoneyCurrency::DKK),
+        "dollar" | "dollars" => Some(MoneyCurrency::Dollar),
+        "egp" => Some(MoneyCurrency::EGP),
         "\u{20ac}" | "x20ac" | "eur" | "euro" | "euros" | "eurs" | "\u{20ac}ur" | "\u{20ac}uro"
-        | "\u{20ac}uros" | "\u{20ac}urs" => Some(Currency::EUR),
-        "gbp" => Some(Currency::GBP),
-        "gel" | "lari" | "\u{20be}" => Some(Currency::GEL),
-        "hkd" => Some(Currency::HKD),
-        "hrk" => Some(Currency::HRK),
-        "idr" => Some(Currency::IDR),
-        "ils" | "\u{20aa}" | "nis" | "shekel" | "shekels" => Some(Currency::ILS),
-        "inr" | "rs" | "rs." | "rupee" | "rupees" => Some(Currency::INR),
-        "iqd" => Some(Currency::IQD),
-        "jmd" => Some(Currency::JMD),
-        "jod" => Some(Currency::JOD),
-        "\u{00a5}" | "jpy" | "yen" => Some(Currency::JPY),
-        "krw" => Some(Currency::KRW),
-        "kwd" => Some(Currency::KWD),
-        "lbp" => Some(Currency::LBP),
-        "mad" => Some(Currency::MAD),
-        "\u{20ae}" | "mnt" | "tugrik" | "tugriks" => Some(Currency::MNT),
-        "myr" | "rm" => Some(Currency::MYR),
-        "nok" => Some(Currency::NOK),
-        "nzd" => Some(Currency::NZD),
-        "\u{00a3}" => Some(Currency::Pound),
-        "pkr" => Some(Currency::PKR),
-        "pln" => Some(Currency::PLN),
-        "pt" | "pts" | "pta" | "ptas" => Some(Currency::PTS),
-        "qar" => Some(Currency::QAR),
-        "rial" | "rials" => Some(Currency::Rial),
-        "riyal" | "riyals" => Some(Currency::Riyal),
-        "ron" => Some(Currency::RON),
-        "\u{20bd}" | "rub" => Some(Currency::RUB),
-        "sar" => Some(Currency::SAR),
-        "sek" => Some(Currency::SEK),
-        "sgd" => Some(Currency::SGD),
-        "thb" => Some(Currency::THB),
-        "ttd" => Some(Currency::TTD),
-        "\u{20b4}" | "uah" => Some(Currency::UAH),
-        "usd" | "us$" => Some(Currency::USD),
-        "vnd" => Some(Currency::VND),
-        "zar" => Some(Currency::ZAR),
-        "tl" | "lira" | "\u{20ba}" => Some(Currency::TRY),
+        | "\u{20ac}uros" | "\u{20ac}urs" => Some(MoneyCurrency::EUR),
+        "gbp" => Some(MoneyCurrency::GBP),
+        "gel" | "lari" | "\u{20be}" => Some(MoneyCurrency::GEL),
+        "hkd" | "hk$" => Some(MoneyCurrency::HKD),
+        "hrk" => Some(MoneyCurrency::HRK),
+        "idr" => Some(MoneyCurrency::IDR),
+        "ils" | "\u{20aa}" | "nis" | "shekel" | "shekels" => Some(MoneyCurrency::ILS),
+        "inr" | "rs" | "rs." | "rupee" | "rupees" | "\u{20b9}" => Some(MoneyCurrency::INR),
+        "iqd" => Some(MoneyCurrency::IQD),
+        "jmd" => Some(MoneyCurrency::JMD),
+        "jod" => Some(MoneyCurrency::JOD),
+        "\u{00a5}" | "jpy" | "yen" => Some(MoneyCurrency::JPY),
+        "krw" | "\u{20a9}" => Some(MoneyCurrency::KRW),
+        "kwd" => Some(MoneyCurrency::KWD),
+        "lbp" => Some(MoneyCurrency::LBP),
+        "mad" => Some(MoneyCurrency::MAD),
+        "\u{20ae}" | "mnt" | "tugrik" | "tugriks" => Some(MoneyCurrency::MNT),
+        "myr" | "rm" => Some(MoneyCurrency::MYR),
+        "nok" => Some(MoneyCurrency::NOK),
+        "nzd" | "nz$" => Some(MoneyCurrency::NZD),
+        "\u{00a3}" => Some(MoneyCurrency::Pound),
+        "pkr" => Some(MoneyCurrency::PKR),
+        "pln" | "z\u{142}" => Some(MoneyCurrency::PLN),
+        "pt" | "pts" | "pta" | "ptas" => Some(MoneyCurrency::PTS),
+        "qar" => Some(MoneyCurrency::QAR),
+        "rial" | "rials" => Some(MoneyCurrency::Rial),
+        "riyal" | "riyals" => Some(MoneyCurrency::Riyal),
+        "ron" => Some(MoneyCurrency::RON),
+        "\u{20bd}" | "rub" => Some(MoneyCurrency::RUB),
+        "sar" => Some(MoneyCurrency::SAR),
+        "sek" => Some(MoneyCurrency::SEK),
+        "sgd" => Some(MoneyCurrency::SGD),
+        "thb" => Some(MoneyCurrency::THB),
+        "ttd" => Some(MoneyCurrency::TTD),
+        "\u{20b4}" | "uah" => Some(MoneyCurrency::UAH),
+        "usd" | "us$" => Some(MoneyCurrency::USD),
+        "vnd" | "\u{20ab}" => Some(MoneyCurrency::VND),
+        "zar" | "rand" => Some(MoneyCurrency::ZAR),
+        "tl" | "lira" | "\u{20ba}" => Some(MoneyCurrency::TRY),
         _ => None,
     }
 }
@@ -131,7 +131,7 @@ pub fn rules() -> Vec<Rule> {
         Rule {
             name: "currencies".to_string(),
             pattern: vec![regex(
-                r"(aed|aud|bgn|brl|byn|\u{00a2}|cad|chf|cny|czk|c|\$|dinars?|dkk|dollars?|egp|(e|\u{20ac}|x20ac)uro?s?|\u{20ac}|x20ac|gbp|gel|\u{20be}|hkd|hrk|idr|ils|\u{20aa}|inr|iqd|jmd|jod|\u{00a5}|jpy|lari|krw|kwd|lbp|mad|\u{20ae}|mnt|tugriks?|myr|rm|nis|nok|nzd|\u{00a3}|pkr|pln|pta?s?|qar|\u{20bd}|rs\.?|riy?als?|ron|rub|rupees?|sar|sek|sgd|shekels?|thb|ttd|\u{20b4}|uah|us(d|\$)|vnd|yen|yuan|zar|tl|lira|\u{20ba})",
+                r"(a\$|au\$|c\$|ca\$|nz\$|hk\$|r\$|z\u{142}|\u{20b9}|\u{20a9}|\u{20ab}|aed|aud|bgn|brl|byn|\u{00a2}|cad|chf|cny|czk|c|\$|dinars?|dkk|dollars?|egp|(e|\u{20ac}|x20ac)uro?s?|\u{20ac}|x20ac|gbp|gel|\u{20be}|hkd|hrk|idr|ils|\u{20aa}|inr|iqd|jmd|jod|\u{00a5}|jpy|lari|krw|kwd|lbp|mad|\u{20ae}|mnt|tugriks?|myr|rm|nis|nok|nzd|\u{00a3}|pkr|pln|pta?s?|qar|\u{20bd}|\brand\b|rs\.?|riy?als?|ron|rub|rupees?|sar|sek|sgd|shekels?|thb|ttd|\u{20b4}|uah|us(d|\$)|vnd|yen|yuan|zar|tl|lira|\u{20ba})",
             )],
             production: Box::new(|nodes| {
                 let text = match &nodes[0].token_data {
@@ -176,7 +176,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex(r"pounds?")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::Pound,
+                    MoneyCurrency::Pound,
                 )))
             }),
         },
@@ -191,8 +191,8 @@ pub fn rules() -> Vec<Rule> {
                 };
                 let kind = m.group(1)?.to_lowercase();
                 let c = match kind.as_str() {
-                    "egyptian" => Currency::EGP,
-                    "lebanese" => Currency::LBP,
+                    "egyptian" => MoneyCurrency::EGP,
+                    "lebanese" => MoneyCurrency::LBP,
                     _ => return None,
                 };
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
@@ -206,7 +206,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex(r"[lL].?[eE].?")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::EGP,
+                    MoneyCurrency::EGP,
                 )))
             }),
         },
@@ -216,7 +216,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex(r"[Gg][eiy]*n[eiy]*h(at)?( m[aiey]?sr[eiy]+a?)?")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::EGP,
+                    MoneyCurrency::EGP,
                 )))
             }),
         },
@@ -231,8 +231,8 @@ pub fn rules() -> Vec<Rule> {
                 };
                 let kind = m.group(1)?.to_lowercase();
                 let c = match kind.as_str() {
-                    "qatari" => Currency::QAR,
-                    "saudi" => Currency::SAR,
+                    "qatari" => MoneyCurrency::QAR,
+                    "saudi" => MoneyCurrency::SAR,
                     _ => return None,
                 };
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
@@ -251,7 +251,7 @@ pub fn rules() -> Vec<Rule> {
                 };
                 let kind = m.group(1)?.to_lowercase();
                 let c = match kind.as_str() {
-                    "kuwaiti" => Currency::KWD,
+                    "kuwaiti" => MoneyCurrency::KWD,
                     _ => return None,
                 };
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
@@ -265,7 +265,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex(r"dirhams?")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::AED,
+                    MoneyCurrency::AED,
                 )))
             }),
         },
@@ -275,7 +275,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex(r"(malaysian? )?ringgits?")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::MYR,
+                    MoneyCurrency::MYR,
                 )))
             }),
         },
@@ -285,7 +285,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex(r"hryvnia")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::UAH,
+                    MoneyCurrency::UAH,
                 )))
             }),
         },
@@ -295,7 +295,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex(r"cents?|penn(y|ies)|pence|sens?")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::Cent,
+                    MoneyCurrency::Cent,
                 )))
             }),
         },
@@ -305,7 +305,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex(r"kopiy(ok|kas?)")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::Cent,
+                    MoneyCurrency::Cent,
                 )))
             }),
         },
@@ -315,7 +315,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex(r"bucks?")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::Unnamed,
+                    MoneyCurrency::Unnamed,
                 )))
             }),
         },
@@ -325,7 +325,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex(r"a grand")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(
-                    AmountOfMoneyData::currency_only(Currency::Unnamed).with_value(1000.0),
+                    AmountOfMoneyData::currency_only(MoneyCurrency::Unnamed).with_value(1000.0),
                 ))
             }),
         },
@@ -336,7 +336,7 @@ pub fn rules() -> Vec<Rule> {
             production: Box::new(|nodes| {
                 let num = numeral_data(&nodes[0].token_data)?;
                 Some(TokenData::AmountOfMoney(
-                    AmountOfMoneyData::currency_only(Currency::Unnamed)
+                    AmountOfMoneyData::currency_only(MoneyCurrency::Unnamed)
                         .with_value(1000.0 * num.value),
                 ))
             }),
@@ -358,7 +358,7 @@ pub fn rules() -> Vec<Rule> {
                     _ => return None,
                 };
                 Some(TokenData::AmountOfMoney(
-                    AmountOfMoneyData::currency_only(Currency::Dollar).with_value(value),
+                    AmountOfMoneyData::currency_only(MoneyCurrency::Dollar).with_value(value),
                 ))
             }),
         },
@@ -445,7 +445,14 @@ pub fn rules() -> Vec<Rule> {
                 ),
                 predicate(is_money_with_value),
             ],
-            production: Box::new(|nodes| Some(nodes[1].token_data.clone())),
+            production: Box::new(|nodes| {
+                Some(
+                    nodes[1]
+                        .token_data
+                        .clone()
+                        .with_precision(Precision::of_qualifier(nodes[0])),
+                )
+            }),
         },
         // between|from <numeral> to|and <amount-of-money>
         Rule {
@@ -596,7 +603,10 @@ mod tests {
     fn test_money() {
         let mut rules = numeral::en::rules();
         rules.extend(super::rules());
-        let options = Options { with_latent: false };
+        let options = Options {
+            with_latent: false,
+            ..Options::default()
+        };
         let context = Context::default();
 
         for (text, expected_val, expected_unit) in &[
@@ -1,8 +1,9 @@
 use crate::dimensions::numeral::helpers::{is_natural, is_positive, numeral_data};
+use crate::locale::Region;
 use crate::pattern::{predicate, regex};
 use crate::types::{Rule, TokenData};
 
-use super::{AmountOfMoneyData, Currency};
+use super::{AmountOfMoneyData, MoneyCurrency};
 
 fn money_data(td: &TokenData) -> Option<&AmountOfMoneyData> {
     match td {
@@ -30,14 +31,14 @@ fn is_simple_money(td: &TokenData) -> bool {
 fn is_without_cents(td: &TokenData) -> bool {
     match td {
         TokenData::AmountOfMoney(d) => {
-            d.currency != Currency::Cent && d.value.map(|v| v == v.floor()).unwrap_or(false)
+            d.currency != MoneyCurrency::Cent && d.value.map(|v| v == v.floor()).unwrap_or(false)
         }
         _ => false,
     }
 }
 
 fn is_cents(td: &TokenData) -> bool {
-    matches!(td, TokenData::AmountOfMoney(d) if d.currency == Currency::Cent && d.value.is_some())
+    matches!(td, TokenData::AmountOfMoney(d) if d.currency == MoneyCurrency::Cent && d.value.is_some())
 }
 
 pub fn rules() -> Vec<Rule> {
@@ -53,12 +54,34 @@ pub fn rules() -> Vec<Rule> {
                 ))
             }),
         },
+        // "dólares" are US dollars across Latin America, where a bare "$" is
+        // the local peso
         Rule {
             name: "dollar".to_string(),
             pattern: vec![regex("d(ó|o)lar(es)?")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::Dollar,
+                    MoneyCurrency::USD,
+                )))
+            }),
+        },
+        Rule {
+            name: "peso".to_string(),
+            pattern: vec![regex("pesos?( (mexican|argentin|chilen|colombian)os?)?")],
+            production: Box::new(|nodes| {
+                let country = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(2),
+                    _ => return None,
+                };
+                let currency = match country.map(str::to_lowercase).as_deref() {
+                    Some("mexican") => MoneyCurrency::MXN,
+                    Some("argentin") => MoneyCurrency::ARS,
+                    Some("chilen") => MoneyCurrency::CLP,
+                    Some("colombian") => MoneyCurrency::COP,
+                    _ => MoneyCurrency::Peso,
+                };
+                Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
+                    currency,
                 )))
             }),
         },
@@ -67,7 +90,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("centavos?")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::Cent,
+                    MoneyCurrency::Cent,
                 )))
             }),
         },
@@ -76,7 +99,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("(pound|libra)s?")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::Pound,
+                    MoneyCurrency::Pound,
                 )))
             }),
         },
@@ -224,3 +247,19 @@ pub fn rules() -> Vec<Rule> {
         },
     ]
 }
+
+/// Region overlays for Spanish: "pavos" for euros in Spain (ES-ES).
+pub fn locale_rules(region: Region) -> Vec<Rule> {
+    match region {
+        Region::ES => vec![Rule {
+            name: "pavos (es_ES)".to_string(),
+            pattern: vec![regex("pavos?")],
+            production: Box::new(|_| {
+                Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
+                    MoneyCurrency::EUR,
+                )))
+            }),
+        }],
+        _ => Vec::new(),
+    }
+}
@@ -0,0 +1,38 @@
+use crate::dimensions::numeral::helpers::{is_positive, numeral_data};
+use crate::pattern::{predicate, regex};
+use crate::types::{Rule, TokenData};
+
+use super::{AmountOfMoneyData, MoneyCurrency};
+
+fn currency_rule(name: &str, pattern: &str, currency: MoneyCurrency) -> Rule {
+    Rule {
+        name: name.to_string(),
+        pattern: vec![regex(pattern)],
+        production: Box::new(move |_| {
+            Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
+                currency,
+            )))
+        }),
+    }
+}
+
+pub fn rules() -> Vec<Rule> {
+    vec![
+        currency_rule("rial (fa)", "ریال|ريال", MoneyCurrency::IRR),
+        currency_rule("dollar (fa)", "دلار", MoneyCurrency::Dollar),
+        currency_rule("euro (fa)", "یورو|يورو", MoneyCurrency::EUR),
+        currency_rule("pound (fa)", "پوند", MoneyCurrency::Pound),
+        // Prices are mostly quoted in tomans, ten rials each, which have no
+        // ISO code of their own: "۵۰۰ هزار تومان" is 5,000,000 IRR
+        Rule {
+            name: "<amount> toman (fa)".to_string(),
+            pattern: vec![predicate(is_positive), regex("تومان|تومن")],
+            production: Box::new(|nodes| {
+                let v = numeral_data(&nodes[0].token_data)?.value;
+                Some(TokenData::AmountOfMoney(
+                    AmountOfMoneyData::currency_only(MoneyCurrency::IRR).with_value(v * 10.0),
+                ))
+            }),
+        },
+    ]
+}
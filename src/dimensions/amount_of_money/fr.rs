@@ -1,8 +1,9 @@
 use crate::dimensions::numeral::helpers::{is_natural, is_positive, numeral_data};
+use crate::locale::Region;
 use crate::pattern::{predicate, regex};
-use crate::types::{Rule, TokenData};
+use crate::types::{Precision, Rule, TokenData};
 
-use super::{AmountOfMoneyData, Currency};
+use super::{AmountOfMoneyData, MoneyCurrency};
 
 fn money_data(td: &TokenData) -> Option<&AmountOfMoneyData> {
     match td {
@@ -38,14 +39,14 @@ fn is_money_with_value(td: &TokenData) -> bool {
 fn is_without_cents(td: &TokenData) -> bool {
     match td {
         TokenData::AmountOfMoney(d) => {
-            d.currency != Currency::Cent && d.value.map(|v| v == v.floor()).unwrap_or(false)
+            d.currency != MoneyCurrency::Cent && d.value.map(|v| v == v.floor()).unwrap_or(false)
         }
         _ => false,
     }
 }
 
 fn is_cents(td: &TokenData) -> bool {
-    matches!(td, TokenData::AmountOfMoney(d) if d.currency == Currency::Cent && d.value.is_some())
+    matches!(td, TokenData::AmountOfMoney(d) if d.currency == MoneyCurrency::Cent && d.value.is_some())
 }
 
 pub fn rules() -> Vec<Rule> {
@@ -66,7 +67,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("cent(ime)?s?")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::Cent,
+                    MoneyCurrency::Cent,
                 )))
             }),
         },
@@ -75,7 +76,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("(livre|pound)s?")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::Pound,
+                    MoneyCurrency::Pound,
                 )))
             }),
         },
@@ -84,7 +85,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("(balle|pouloute)s?")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::Unnamed,
+                    MoneyCurrency::Unnamed,
                 )))
             }),
         },
@@ -241,7 +242,31 @@ pub fn rules() -> Vec<Rule> {
                 regex("exactement|quasi|plus ou moins|environ|autour de|(a|à) peu pr(e|è)s"),
                 predicate(is_money_with_value),
             ],
-            production: Box::new(|nodes| Some(nodes[1].token_data.clone())),
+            production: Box::new(|nodes| {
+                Some(
+                    nodes[1]
+                        .token_data
+                        .clone()
+                        .with_precision(Precision::of_qualifier(nodes[0])),
+                )
+            }),
         },
     ]
 }
+
+/// Region overlays for French: "piasses" for dollars in Québec (FR-CA),
+/// where a bare dollar resolves to CAD.
+pub fn locale_rules(region: Region) -> Vec<Rule> {
+    match region {
+        Region::CA => vec![Rule {
+            name: "piasse (fr_CA)".to_string(),
+            pattern: vec![regex("pia(ss|str)es?")],
+            production: Box::new(|_| {
+                Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
+                    MoneyCurrency::Dollar,
+                )))
+            }),
+        }],
+        _ => Vec::new(),
+    }
+}
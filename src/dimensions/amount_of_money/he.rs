@@ -1,7 +1,7 @@
 use crate::pattern::regex;
 use crate::types::{Rule, TokenData};
 
-use super::{AmountOfMoneyData, Currency};
+use super::{AmountOfMoneyData, MoneyCurrency};
 
 pub fn rules() -> Vec<Rule> {
     vec![Rule {
@@ -13,15 +13,15 @@ pub fn rules() -> Vec<Rule> {
                 _ => return None,
             };
             let c = if m.contains('₪') || m.contains("שקל") || m.contains("ש\"ח") || m.contains("ש״ח") {
-                Currency::ILS
+                MoneyCurrency::ILS
             } else if m.contains("אגור") {
-                Currency::Cent
+                MoneyCurrency::Cent
             } else if m.contains("€") || m.contains("יורו") || m.contains("אירו") || m.contains("EUR") {
-                Currency::EUR
+                MoneyCurrency::EUR
             } else if m.contains('£') || m.contains("פאונד") || m.contains("לירה") || m.contains("GBP") {
-                Currency::GBP
+                MoneyCurrency::GBP
             } else {
-                Currency::Dollar
+                MoneyCurrency::Dollar
             };
             Some(TokenData::AmountOfMoney(
                 AmountOfMoneyData::currency_only(c).with_value(1.0),
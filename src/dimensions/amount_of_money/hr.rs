@@ -1,8 +1,8 @@
 use crate::dimensions::numeral::helpers::{is_natural, is_positive, numeral_data};
 use crate::pattern::{predicate, regex};
-use crate::types::{Rule, TokenData};
+use crate::types::{Precision, Rule, TokenData};
 
-use super::{AmountOfMoneyData, Currency};
+use super::{AmountOfMoneyData, MoneyCurrency};
 
 fn money_data(td: &TokenData) -> Option<&AmountOfMoneyData> {
     match td {
@@ -38,14 +38,14 @@ fn is_money_with_value(td: &TokenData) -> bool {
 fn is_without_cents(td: &TokenData) -> bool {
     match td {
         TokenData::AmountOfMoney(d) => {
-            d.currency != Currency::Cent && d.value.map(|v| v == v.floor()).unwrap_or(false)
+            d.currency != MoneyCurrency::Cent && d.value.map(|v| v == v.floor()).unwrap_or(false)
         }
         _ => false,
     }
 }
 
 fn is_cents(td: &TokenData) -> bool {
-    matches!(td, TokenData::AmountOfMoney(d) if d.currency == Currency::Cent && d.value.is_some())
+    matches!(td, TokenData::AmountOfMoney(d) if d.currency == MoneyCurrency::Cent && d.value.is_some())
 }
 
 pub fn rules() -> Vec<Rule> {
@@ -89,7 +89,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("saudijskirijal|saudi rijal?")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::SAR,
+                    MoneyCurrency::SAR,
                 )))
             }),
         },
@@ -98,7 +98,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("dolar(a|i|e)?")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::Dollar,
+                    MoneyCurrency::Dollar,
                 )))
             }),
         },
@@ -107,7 +107,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("cent(i|a)?|penij(i|a)?|c|¢|lp|lip(a|e)")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::Cent,
+                    MoneyCurrency::Cent,
                 )))
             }),
         },
@@ -134,7 +134,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("funt(a|e|i)?")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::Pound,
+                    MoneyCurrency::Pound,
                 )))
             }),
         },
@@ -143,7 +143,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("kn|(hrvatsk(a|ih|e) )?kun(a|e)")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::HRK,
+                    MoneyCurrency::HRK,
                 )))
             }),
         },
@@ -165,8 +165,8 @@ pub fn rules() -> Vec<Rule> {
                     _ => return None,
                 };
                 let c = match t.as_str() {
-                    "egipatska" => Currency::EGP,
-                    "libanonska" => Currency::LBP,
+                    "egipatska" => MoneyCurrency::EGP,
+                    "libanonska" => MoneyCurrency::LBP,
                     _ => return None,
                 };
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
@@ -179,7 +179,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("rupija?")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::INR,
+                    MoneyCurrency::INR,
                 )))
             }),
         },
@@ -188,7 +188,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("kuvajtski ?dinar")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::KWD,
+                    MoneyCurrency::KWD,
                 )))
             }),
         },
@@ -197,7 +197,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("katarski(i| )rijal")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::QAR,
+                    MoneyCurrency::QAR,
                 )))
             }),
         },
@@ -206,7 +206,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("drahma?")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::AED,
+                    MoneyCurrency::AED,
                 )))
             }),
         },
@@ -216,7 +216,14 @@ pub fn rules() -> Vec<Rule> {
                 regex("oko|otprilike|u blizini|skoro|približno"),
                 predicate(is_money_with_value),
             ],
-            production: Box::new(|nodes| Some(nodes[1].token_data.clone())),
+            production: Box::new(|nodes| {
+                Some(
+                    nodes[1]
+                        .token_data
+                        .clone()
+                        .with_precision(Precision::of_qualifier(nodes[0])),
+                )
+            }),
         },
         Rule {
             name: "<numeral> - <amount-of-money>".to_string(),
@@ -1,33 +1,52 @@
-use crate::pattern::regex;
+use crate::dimensions::numeral::helpers::{is_positive, numeral_data};
+use crate::pattern::{predicate, regex};
 use crate::types::{Rule, TokenData};
 
-use super::{AmountOfMoneyData, Currency};
+use super::{AmountOfMoneyData, MoneyCurrency};
 
 pub fn rules() -> Vec<Rule> {
-    vec![Rule {
-        name: "amount keywords (id)".to_string(),
-        pattern: vec![regex(
-            "(\\$|€|£|¥|dolar|rupiah|rp\\.?|idr|euro|pound|sterling|yen|USD|EUR|GBP|IDR|JPY)",
-        )],
-        production: Box::new(|nodes| {
-            let m = match &nodes[0].token_data {
-                TokenData::RegexMatch(m) => m.group(1)?.to_lowercase(),
-                _ => return None,
-            };
-            let c = if m.contains("idr") || m.contains("rupiah") || m.contains("rp") {
-                Currency::IDR
-            } else if m.contains("eur") || m.contains("euro") || m.contains('€') {
-                Currency::EUR
-            } else if m.contains("gbp") || m.contains("pound") || m.contains('£') {
-                Currency::GBP
-            } else if m.contains("yen") || m.contains("jpy") || m.contains('¥') {
-                Currency::JPY
-            } else {
-                Currency::Dollar
-            };
-            Some(TokenData::AmountOfMoney(
-                AmountOfMoneyData::currency_only(c).with_value(1.0),
-            ))
-        }),
-    }]
+    vec![
+        Rule {
+            name: "amount keywords (id)".to_string(),
+            pattern: vec![regex(
+                "(\\$|€|£|¥|dolar|rupiah|rp\\.?|idr|euro|pound|sterling|yen|USD|EUR|GBP|IDR|JPY)",
+            )],
+            production: Box::new(|nodes| {
+                let m = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?.to_lowercase(),
+                    _ => return None,
+                };
+                let c = if m.contains("idr") || m.contains("rupiah") || m.contains("rp") {
+                    MoneyCurrency::IDR
+                } else if m.contains("eur") || m.contains("euro") || m.contains('€') {
+                    MoneyCurrency::EUR
+                } else if m.contains("gbp") || m.contains("pound") || m.contains('£') {
+                    MoneyCurrency::GBP
+                } else if m.contains("yen") || m.contains("jpy") || m.contains('¥') {
+                    MoneyCurrency::JPY
+                } else {
+                    MoneyCurrency::Dollar
+                };
+                Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
+                    c,
+                )))
+            }),
+        },
+        // "150rb" and "2jt" are prices in rupiah even without "Rp"
+        Rule {
+            name: "<amount> rb/jt (id)".to_string(),
+            pattern: vec![predicate(is_positive), regex("(rb|jt)\\b")],
+            production: Box::new(|nodes| {
+                let v = numeral_data(&nodes[0].token_data)?.value;
+                let m = match &nodes[1].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?.to_lowercase(),
+                    _ => return None,
+                };
+                let factor = if m == "jt" { 1e6 } else { 1e3 };
+                Some(TokenData::AmountOfMoney(
+                    AmountOfMoneyData::currency_only(MoneyCurrency::IDR).with_value(v * factor),
+                ))
+            }),
+        },
+    ]
 }
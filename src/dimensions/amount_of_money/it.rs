@@ -1,8 +1,8 @@
 use crate::dimensions::numeral::helpers::{is_natural, is_positive, numeral_data};
 use crate::pattern::{predicate, regex};
-use crate::types::{Rule, TokenData};
+use crate::types::{Precision, Rule, TokenData};
 
-use super::{AmountOfMoneyData, Currency};
+use super::{AmountOfMoneyData, MoneyCurrency};
 
 fn money_data(td: &TokenData) -> Option<&AmountOfMoneyData> {
     match td {
@@ -38,14 +38,14 @@ fn is_money_with_value(td: &TokenData) -> bool {
 fn is_without_cents(td: &TokenData) -> bool {
     match td {
         TokenData::AmountOfMoney(d) => {
-            d.currency != Currency::Cent && d.value.map(|v| v == v.floor()).unwrap_or(false)
+            d.currency != MoneyCurrency::Cent && d.value.map(|v| v == v.floor()).unwrap_or(false)
         }
         _ => false,
     }
 }
 
 fn is_cents(td: &TokenData) -> bool {
-    matches!(td, TokenData::AmountOfMoney(d) if d.currency == Currency::Cent && d.value.is_some())
+    matches!(td, TokenData::AmountOfMoney(d) if d.currency == MoneyCurrency::Cent && d.value.is_some())
 }
 
 pub fn rules() -> Vec<Rule> {
@@ -66,7 +66,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("cent(esim)i?o?")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::Cent,
+                    MoneyCurrency::Cent,
                 )))
             }),
         },
@@ -79,8 +79,8 @@ pub fn rules() -> Vec<Rule> {
                     _ => return None,
                 };
                 let c = match m.as_str() {
-                    "dollari" => Currency::Dollar,
-                    "sterline" => Currency::Pound,
+                    "dollari" => MoneyCurrency::Dollar,
+                    "sterline" => MoneyCurrency::Pound,
                     _ => return None,
                 };
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
@@ -234,7 +234,14 @@ pub fn rules() -> Vec<Rule> {
                 regex("esattamente|quasi|più o meno|circa"),
                 predicate(is_money_with_value),
             ],
-            production: Box::new(|nodes| Some(nodes[1].token_data.clone())),
+            production: Box::new(|nodes| {
+                Some(
+                    nodes[1]
+                        .token_data
+                        .clone()
+                        .with_precision(Precision::of_qualifier(nodes[0])),
+                )
+            }),
         },
     ]
 }
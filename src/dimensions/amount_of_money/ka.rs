@@ -1,7 +1,7 @@
 use crate::pattern::regex;
 use crate::types::{Rule, TokenData};
 
-use super::{AmountOfMoneyData, Currency};
+use super::{AmountOfMoneyData, MoneyCurrency};
 
 pub fn rules() -> Vec<Rule> {
     vec![Rule {
@@ -13,21 +13,21 @@ pub fn rules() -> Vec<Rule> {
                 _ => return None,
             };
             let c = if m.contains("ევრო") || m.contains("EUR") || m.contains('€') {
-                Currency::EUR
+                MoneyCurrency::EUR
             } else if m.contains("ცენტ") || m.contains('¢') {
-                Currency::Cent
+                MoneyCurrency::Cent
             } else if m.contains("ფუნტ") || m.contains("GBP") || m.contains('£') || m.contains("x00a3&") {
-                Currency::GBP
+                MoneyCurrency::GBP
             } else if m.contains("KWD") || m.contains("დინარ") {
-                Currency::Dinar
+                MoneyCurrency::Dinar
             } else if m.contains("QAR") || m.contains("SAR") || m.contains("რიალ") {
-                Currency::Riyal
+                MoneyCurrency::Riyal
             } else if m.contains("LBP") || m.contains("ლირ") {
-                Currency::LBP
+                MoneyCurrency::LBP
             } else if m.contains("EGP") {
-                Currency::EGP
+                MoneyCurrency::EGP
             } else {
-                Currency::Dollar
+                MoneyCurrency::Dollar
             };
             Some(TokenData::AmountOfMoney(
                 AmountOfMoneyData::currency_only(c).with_value(1.0),
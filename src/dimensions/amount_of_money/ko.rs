@@ -1,7 +1,7 @@
 use crate::pattern::regex;
 use crate::types::{Rule, TokenData};
 
-use super::{AmountOfMoneyData, Currency};
+use super::{AmountOfMoneyData, MoneyCurrency};
 
 pub fn rules() -> Vec<Rule> {
     vec![Rule {
@@ -15,15 +15,15 @@ pub fn rules() -> Vec<Rule> {
                 _ => return None,
             };
             let c = if m.contains('₩') || m.contains("원") || m.contains("KRW") {
-                Currency::KRW
+                MoneyCurrency::KRW
             } else if m.contains("센트") {
-                Currency::Cent
+                MoneyCurrency::Cent
             } else if m.contains("유로") || m.contains("EUR") || m.contains('€') {
-                Currency::EUR
+                MoneyCurrency::EUR
             } else if m.contains("파운드") || m.contains("GBP") || m.contains('£') {
-                Currency::GBP
+                MoneyCurrency::GBP
             } else {
-                Currency::Dollar
+                MoneyCurrency::Dollar
             };
             Some(TokenData::AmountOfMoney(
                 AmountOfMoneyData::currency_only(c).with_value(1.0),
@@ -1,8 +1,8 @@
 use crate::dimensions::numeral::helpers::{is_natural, is_positive, numeral_data};
 use crate::pattern::{predicate, regex};
-use crate::types::{Rule, TokenData};
+use crate::types::{Precision, Rule, TokenData};
 
-use super::{AmountOfMoneyData, Currency};
+use super::{AmountOfMoneyData, MoneyCurrency};
 
 fn money_data(td: &TokenData) -> Option<&AmountOfMoneyData> {
     match td {
@@ -41,7 +41,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("төг(рөг(ийн)?)?")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::MNT,
+                    MoneyCurrency::MNT,
                 )))
             }),
         },
@@ -50,7 +50,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("фунт(аар|тай|аас)?|£|x00a3\\&?")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::Pound,
+                    MoneyCurrency::Pound,
                 )))
             }),
         },
@@ -59,7 +59,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("Английн\\s+фунт")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::GBP,
+                    MoneyCurrency::GBP,
                 )))
             }),
         },
@@ -68,7 +68,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("доллар(ын|оор|оос|той)?")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::Dollar,
+                    MoneyCurrency::Dollar,
                 )))
             }),
         },
@@ -79,7 +79,7 @@ pub fn rules() -> Vec<Rule> {
             )],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::Cent,
+                    MoneyCurrency::Cent,
                 )))
             }),
         },
@@ -88,7 +88,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("евро")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::EUR,
+                    MoneyCurrency::EUR,
                 )))
             }),
         },
@@ -97,7 +97,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("бакс(аар|тай|аас)?")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::Unnamed,
+                    MoneyCurrency::Unnamed,
                 )))
             }),
         },
@@ -107,7 +107,14 @@ pub fn rules() -> Vec<Rule> {
                 regex("яг|ойролцоогоор|бараг"),
                 predicate(is_money_with_value),
             ],
-            production: Box::new(|nodes| Some(nodes[1].token_data.clone())),
+            production: Box::new(|nodes| {
+                Some(
+                    nodes[1]
+                        .token_data
+                        .clone()
+                        .with_precision(Precision::of_qualifier(nodes[0])),
+                )
+            }),
         },
         Rule {
             name: "between|from <numeral> to <amount-of-money>".to_string(),
@@ -1,8 +1,10 @@
 pub mod ar;
 pub mod bg;
 pub mod ca;
+pub mod de;
 pub mod en;
 pub mod es;
+pub mod fa;
 pub mod fr;
 pub mod ga;
 pub mod he;
@@ -22,126 +24,234 @@ pub mod tr;
 pub mod vi;
 pub mod zh;
 
-use crate::types::{DimensionValue, MeasurementPoint, MeasurementValue};
+use crate::locale::Region;
+use crate::types::{DimensionValue, MeasurementPoint, MeasurementValue, Precision, Unit};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The currency of an amount of money. Resolved amounts carry an ISO 4217
+/// code, or `Cent`, `Dinar`, `Rial` or `Riyal` when the country is unknown.
 #[allow(clippy::upper_case_acronyms)]
-pub enum Currency {
-    // Ambiguous
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MoneyCurrency {
+    /// Cents, with no currency given.
     Cent,
+    /// A dinar of unspecified country.
     Dinar,
+    /// A dollar of unspecified country; resolves to the region's dollar.
     Dollar,
+    /// A pound of unspecified country; resolves to GBP.
     Pound,
+    /// A peso of unspecified country; resolves to the region's peso, MXN
+    /// elsewhere.
+    Peso,
+    /// A rial of unspecified country.
     Rial,
+    /// A riyal of unspecified country.
     Riyal,
+    /// An amount with no currency named; resolves to USD.
     Unnamed,
-    // Specific
+    /// UAE dirham.
     AED,
+    /// Argentine peso.
+    ARS,
+    /// Australian dollar.
     AUD,
+    /// Bulgarian lev.
     BGN,
+    /// Brazilian real.
     BRL,
+    /// Belarusian ruble.
     BYN,
+    /// Canadian dollar.
     CAD,
+    /// Swiss franc.
     CHF,
+    /// Chilean peso.
+    CLP,
+    /// Chinese yuan.
     CNY,
+    /// Colombian peso.
+    COP,
+    /// Czech koruna.
     CZK,
+    /// Danish krone.
     DKK,
+    /// Egyptian pound.
     EGP,
+    /// Euro.
     EUR,
+    /// Pound sterling.
     GBP,
+    /// Georgian lari.
     GEL,
+    /// Hong Kong dollar.
     HKD,
+    /// Croatian kuna.
     HRK,
+    /// Indonesian rupiah.
     IDR,
+    /// Israeli new shekel.
     ILS,
+    /// Indian rupee.
     INR,
+    /// Iraqi dinar.
     IQD,
+    /// Iranian rial.
+    IRR,
+    /// Jamaican dollar.
     JMD,
+    /// Jordanian dinar.
     JOD,
+    /// Japanese yen.
     JPY,
+    /// South Korean won.
     KRW,
+    /// Kuwaiti dinar.
     KWD,
+    /// Lebanese pound.
     LBP,
+    /// Moroccan dirham.
     MAD,
+    /// Mongolian tögrög.
     MNT,
+    /// Macanese pataca.
+    MOP,
+    /// Mexican peso.
+    MXN,
+    /// Malaysian ringgit.
     MYR,
+    /// Norwegian krone.
     NOK,
+    /// New Zealand dollar.
     NZD,
+    /// Pakistani rupee.
     PKR,
+    /// Polish złoty.
     PLN,
+    /// Spanish peseta.
     PTS,
+    /// Qatari riyal.
     QAR,
+    /// Romanian leu.
     RON,
+    /// Russian ruble.
     RUB,
+    /// Saudi riyal.
     SAR,
+    /// Swedish krona.
     SEK,
+    /// Singapore dollar.
     SGD,
+    /// Thai baht.
     THB,
+    /// Trinidad and Tobago dollar.
     TTD,
+    /// New Taiwan dollar.
+    TWD,
+    /// Ukrainian hryvnia.
     UAH,
+    /// United States dollar.
     USD,
+    /// Vietnamese đồng.
     VND,
+    /// South African rand.
     ZAR,
+    /// Turkish lira.
     TRY,
 }
 
-impl Currency {
+impl MoneyCurrency {
+    /// Returns the unit name (e.g. `"USD"`).
     pub fn as_str(&self) -> &'static str {
         match self {
-            Currency::Cent => "cent",
-            Currency::Dinar => "dinar",
-            Currency::Dollar => "USD",
-            Currency::Pound => "GBP",
-            Currency::Rial => "rial",
-            Currency::Riyal => "riyal",
-            Currency::Unnamed => "USD",
-            Currency::AED => "AED",
-            Currency::AUD => "AUD",
-            Currency::BGN => "BGN",
-            Currency::BRL => "BRL",
-            Currency::BYN => "BYN",
-            Currency::CAD => "CAD",
-            Currency::CHF => "CHF",
-            Currency::CNY => "CNY",
-            Currency::CZK => "CZK",
-            Currency::DKK => "DKK",
-            Currency::EGP => "EGP",
-            Currency::EUR => "EUR",
-            Currency::GBP => "GBP",
-            Currency::GEL => "GEL",
-            Currency::HKD => "HKD",
-            Currency::HRK => "HRK",
-            Currency::IDR => "IDR",
-            Currency::ILS => "ILS",
-            Currency::INR => "INR",
-            Currency::IQD => "IQD",
-            Currency::JMD => "JMD",
-            Currency::JOD => "JOD",
-            Currency::JPY => "JPY",
-            Currency::KRW => "KRW",
-            Currency::KWD => "KWD",
-            Currency::LBP => "LBP",
-            Currency::MAD => "MAD",
-            Currency::MNT => "MNT",
-            Currency::MYR => "MYR",
-            Currency::NOK => "NOK",
-            Currency::NZD => "NZD",
-            Currency::PKR => "PKR",
-            Currency::PLN => "PLN",
-            Currency::PTS => "PTS",
-            Currency::QAR => "QAR",
-            Currency::RON => "RON",
-            Currency::RUB => "RUB",
-            Currency::SAR => "SAR",
-            Currency::SEK => "SEK",
-            Currency::SGD => "SGD",
-            Currency::THB => "THB",
-            Currency::TTD => "TTD",
-            Currency::UAH => "UAH",
-            Currency::USD => "USD",
-            Currency::VND => "VND",
-            Currency::ZAR => "ZAR",
-            Currency::TRY => "TRY",
+            MoneyCurrency::Cent => "cent",
+            MoneyCurrency::Dinar => "dinar",
+            MoneyCurrency::Dollar => "USD",
+            MoneyCurrency::Pound => "GBP",
+            MoneyCurrency::Peso => "MXN",
+            MoneyCurrency::Rial => "rial",
+            MoneyCurrency::Riyal => "riyal",
+            MoneyCurrency::Unnamed => "USD",
+            MoneyCurrency::AED => "AED",
+            MoneyCurrency::ARS => "ARS",
+            MoneyCurrency::AUD => "AUD",
+            MoneyCurrency::BGN => "BGN",
+            MoneyCurrency::BRL => "BRL",
+            MoneyCurrency::BYN => "BYN",
+            MoneyCurrency::CAD => "CAD",
+            MoneyCurrency::CHF => "CHF",
+            MoneyCurrency::CLP => "CLP",
+            MoneyCurrency::CNY => "CNY",
+            MoneyCurrency::COP => "COP",
+            MoneyCurrency::CZK => "CZK",
+            MoneyCurrency::DKK => "DKK",
+            MoneyCurrency::EGP => "EGP",
+            MoneyCurrency::EUR => "EUR",
+            MoneyCurrency::GBP => "GBP",
+            MoneyCurrency::GEL => "GEL",
+            MoneyCurrency::HKD => "HKD",
+            MoneyCurrency::HRK => "HRK",
+            MoneyCurrency::IDR => "IDR",
+            MoneyCurrency::ILS => "ILS",
+            MoneyCurrency::INR => "INR",
+            MoneyCurrency::IQD => "IQD",
+            MoneyCurrency::IRR => "IRR",
+            MoneyCurrency::JMD => "JMD",
+            MoneyCurrency::JOD => "JOD",
+            MoneyCurrency::JPY => "JPY",
+            MoneyCurrency::KRW => "KRW",
+            MoneyCurrency::KWD => "KWD",
+            MoneyCurrency::LBP => "LBP",
+            MoneyCurrency::MAD => "MAD",
+            MoneyCurrency::MNT => "MNT",
+            MoneyCurrency::MOP => "MOP",
+            MoneyCurrency::MXN => "MXN",
+            MoneyCurrency::MYR => "MYR",
+            MoneyCurrency::NOK => "NOK",
+            MoneyCurrency::NZD => "NZD",
+            MoneyCurrency::PKR => "PKR",
+            MoneyCurrency::PLN => "PLN",
+            MoneyCurrency::PTS => "PTS",
+            MoneyCurrency::QAR => "QAR",
+            MoneyCurrency::RON => "RON",
+            MoneyCurrency::RUB => "RUB",
+            MoneyCurrency::SAR => "SAR",
+            MoneyCurrency::SEK => "SEK",
+            MoneyCurrency::SGD => "SGD",
+            MoneyCurrency::THB => "THB",
+            MoneyCurrency::TTD => "TTD",
+            MoneyCurrency::TWD => "TWD",
+            MoneyCurrency::UAH => "UAH",
+            MoneyCurrency::USD => "USD",
+            MoneyCurrency::VND => "VND",
+            MoneyCurrency::ZAR => "ZAR",
+            MoneyCurrency::TRY => "TRY",
+        }
+    }
+
+    /// The currency reported for this one in `region`: a bare "$" or
+    /// "dollar" resolves to the region's own dollar, its peso where "$" is
+    /// the peso sign, or the yuan or pataca a bare "元" names in mainland
+    /// China and Macau, USD elsewhere; a bare peso to the region's peso,
+    /// MXN elsewhere; and a bare pound to GBP.
+    pub fn resolve_for_region(&self, region: Option<Region>) -> MoneyCurrency {
+        match (self, region) {
+            (MoneyCurrency::Dollar | MoneyCurrency::Peso, Some(Region::MX)) => MoneyCurrency::MXN,
+            (MoneyCurrency::Dollar | MoneyCurrency::Peso, Some(Region::AR)) => MoneyCurrency::ARS,
+            (MoneyCurrency::Dollar | MoneyCurrency::Peso, Some(Region::CL)) => MoneyCurrency::CLP,
+            (MoneyCurrency::Dollar | MoneyCurrency::Peso, Some(Region::CO)) => MoneyCurrency::COP,
+            (MoneyCurrency::Peso, _) => MoneyCurrency::MXN,
+            (MoneyCurrency::Dollar, Some(Region::AU)) => MoneyCurrency::AUD,
+            (MoneyCurrency::Dollar, Some(Region::CA)) => MoneyCurrency::CAD,
+            (MoneyCurrency::Dollar, Some(Region::NZ)) => MoneyCurrency::NZD,
+            (MoneyCurrency::Dollar, Some(Region::HK)) => MoneyCurrency::HKD,
+            (MoneyCurrency::Dollar, Some(Region::MO)) => MoneyCurrency::MOP,
+            (MoneyCurrency::Dollar, Some(Region::TW)) => MoneyCurrency::TWD,
+            (MoneyCurrency::Dollar, Some(Region::CN)) => MoneyCurrency::CNY,
+            (MoneyCurrency::Dollar, Some(Region::JM)) => MoneyCurrency::JMD,
+            (MoneyCurrency::Dollar, Some(Region::TT)) => MoneyCurrency::TTD,
+            (MoneyCurrency::Dollar | MoneyCurrency::Unnamed, _) => MoneyCurrency::USD,
+            (MoneyCurrency::Pound, _) => MoneyCurrency::GBP,
+            (c, _) => *c,
         }
     }
 }
@@ -149,20 +259,22 @@ impl Currency {
 #[derive(Debug, Clone)]
 pub struct AmountOfMoneyData {
     pub value: Option<f64>,
-    pub currency: Currency,
+    pub currency: MoneyCurrency,
     pub min_value: Option<f64>,
     pub max_value: Option<f64>,
     pub latent: bool,
+    pub precision: Option<Precision>,
 }
 
 impl AmountOfMoneyData {
-    pub fn currency_only(c: Currency) -> Self {
+    pub fn currency_only(c: MoneyCurrency) -> Self {
         AmountOfMoneyData {
             value: None,
             currency: c,
             min_value: None,
             max_value: None,
             latent: false,
+            precision: None,
         }
     }
 
@@ -179,10 +291,11 @@ impl AmountOfMoneyData {
             },
             None => AmountOfMoneyData {
                 value: Some(c),
-                currency: Currency::Cent,
+                currency: MoneyCurrency::Cent,
                 min_value: None,
                 max_value: None,
                 latent: false,
+                precision: None,
             },
         }
     }
@@ -205,7 +318,11 @@ impl AmountOfMoneyData {
     }
 }
 
-pub fn resolve(data: &AmountOfMoneyData, with_latent: bool) -> Option<DimensionValue> {
+pub fn resolve(
+    data: &AmountOfMoneyData,
+    with_latent: bool,
+    region: Option<Region>,
+) -> Option<DimensionValue> {
     // Latent tokens filtered when with_latent=false
     if data.latent && !with_latent {
         return None;
@@ -215,16 +332,13 @@ pub fn resolve(data: &AmountOfMoneyData, with_latent: bool) -> Option<DimensionV
         return None;
     }
 
-    let unit = data.currency.as_str().to_string();
+    let unit = Unit::Money(data.currency.resolve_for_region(region));
 
     let mv = if let Some(value) = data.value {
         MeasurementValue::Value { value, unit }
     } else if let (Some(from), Some(to)) = (data.min_value, data.max_value) {
         MeasurementValue::Interval {
-            from: Some(MeasurementPoint {
-                value: from,
-                unit: unit.clone(),
-            }),
+            from: Some(MeasurementPoint { value: from, unit }),
             to: Some(MeasurementPoint { value: to, unit }),
         }
     } else if let Some(from) = data.min_value {
@@ -1,7 +1,7 @@
 use crate::pattern::regex;
 use crate::types::{Rule, TokenData};
 
-use super::{AmountOfMoneyData, Currency};
+use super::{AmountOfMoneyData, MoneyCurrency};
 
 pub fn rules() -> Vec<Rule> {
     vec![
@@ -20,19 +20,19 @@ pub fn rules() -> Vec<Rule> {
                     || m.contains("penny")
                     || m.contains("pennies")
                 {
-                    Currency::Cent
+                    MoneyCurrency::Cent
                 } else if m.contains("nok") || m.contains("kroner") || m == "kr" {
-                    Currency::NOK
+                    MoneyCurrency::NOK
                 } else if m.contains("sek") || m.contains("kronor") {
-                    Currency::SEK
+                    MoneyCurrency::SEK
                 } else if m.contains("dkk") {
-                    Currency::DKK
+                    MoneyCurrency::DKK
                 } else if m.contains("eur") || m.contains('€') {
-                    Currency::EUR
+                    MoneyCurrency::EUR
                 } else if m.contains("gbp") || m.contains('£') {
-                    Currency::GBP
+                    MoneyCurrency::GBP
                 } else {
-                    Currency::Dollar
+                    MoneyCurrency::Dollar
                 };
                 Some(TokenData::AmountOfMoney(
                     AmountOfMoneyData::currency_only(c).with_value(1.0),
@@ -44,7 +44,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex(".*")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(
-                    AmountOfMoneyData::currency_only(Currency::NOK).with_value(1.0),
+                    AmountOfMoneyData::currency_only(MoneyCurrency::NOK).with_value(1.0),
                 ))
             }),
         },
@@ -1,8 +1,9 @@
 use crate::dimensions::numeral::helpers::{is_natural, is_positive, numeral_data};
+use crate::locale::Region;
 use crate::pattern::{predicate, regex};
 use crate::types::{Rule, TokenData};
 
-use super::{AmountOfMoneyData, Currency};
+use super::{AmountOfMoneyData, MoneyCurrency};
 
 fn money_data(td: &TokenData) -> Option<&AmountOfMoneyData> {
     match td {
@@ -22,14 +23,14 @@ fn is_currency_only(td: &TokenData) -> bool {
 fn is_without_cents(td: &TokenData) -> bool {
     match td {
         TokenData::AmountOfMoney(d) => {
-            d.currency != Currency::Cent && d.value.map(|v| v == v.floor()).unwrap_or(false)
+            d.currency != MoneyCurrency::Cent && d.value.map(|v| v == v.floor()).unwrap_or(false)
         }
         _ => false,
     }
 }
 
 fn is_cents(td: &TokenData) -> bool {
-    matches!(td, TokenData::AmountOfMoney(d) if d.currency == Currency::Cent && d.value.is_some())
+    matches!(td, TokenData::AmountOfMoney(d) if d.currency == MoneyCurrency::Cent && d.value.is_some())
 }
 
 pub fn rules() -> Vec<Rule> {
@@ -50,7 +51,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("reais|r\\$")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::BRL,
+                    MoneyCurrency::BRL,
                 )))
             }),
         },
@@ -59,7 +60,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("dolares?")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::Dollar,
+                    MoneyCurrency::Dollar,
                 )))
             }),
         },
@@ -68,7 +69,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("centavos?")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::Cent,
+                    MoneyCurrency::Cent,
                 )))
             }),
         },
@@ -77,7 +78,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("libras?")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::Pound,
+                    MoneyCurrency::Pound,
                 )))
             }),
         },
@@ -123,3 +124,29 @@ pub fn rules() -> Vec<Rule> {
         },
     ]
 }
+
+/// Region overlays for Portuguese: "paus" is slang for the local currency,
+/// reais in Brazil and euros in Portugal, where cents are "cêntimos".
+pub fn locale_rules(region: Region) -> Vec<Rule> {
+    let currency_rule = |name: &str, pattern: &str, currency: MoneyCurrency| Rule {
+        name: name.to_string(),
+        pattern: vec![regex(pattern)],
+        production: Box::new(move |_| {
+            Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
+                currency,
+            )))
+        }),
+    };
+    match region {
+        Region::BR => vec![currency_rule(
+            "paus (pt_BR)",
+            "\\bpaus?\\b",
+            MoneyCurrency::BRL,
+        )],
+        Region::PT => vec![
+            currency_rule("paus (pt_PT)", "\\bpaus?\\b", MoneyCurrency::EUR),
+            currency_rule("cêntimos (pt_PT)", "c[êe]ntimos?", MoneyCurrency::Cent),
+        ],
+        _ => Vec::new(),
+    }
+}
@@ -1,8 +1,8 @@
 use crate::dimensions::numeral::helpers::{is_natural, is_positive, numeral_data};
 use crate::pattern::{predicate, regex};
-use crate::types::{Rule, TokenData};
+use crate::types::{Precision, Rule, TokenData};
 
-use super::{AmountOfMoneyData, Currency};
+use super::{AmountOfMoneyData, MoneyCurrency};
 
 fn money_data(td: &TokenData) -> Option<&AmountOfMoneyData> {
     match td {
@@ -38,14 +38,14 @@ fn is_money_with_value(td: &TokenData) -> bool {
 fn is_without_cents(td: &TokenData) -> bool {
     match td {
         TokenData::AmountOfMoney(d) => {
-            d.currency != Currency::Cent && d.value.map(|v| v == v.floor()).unwrap_or(false)
+            d.currency != MoneyCurrency::Cent && d.value.map(|v| v == v.floor()).unwrap_or(false)
         }
         _ => false,
     }
 }
 
 fn is_cents(td: &TokenData) -> bool {
-    matches!(td, TokenData::AmountOfMoney(d) if d.currency == Currency::Cent && d.value.is_some())
+    matches!(td, TokenData::AmountOfMoney(d) if d.currency == MoneyCurrency::Cent && d.value.is_some())
 }
 
 pub fn rules() -> Vec<Rule> {
@@ -81,7 +81,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("dirhami?")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::AED,
+                    MoneyCurrency::AED,
                 )))
             }),
         },
@@ -90,7 +90,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("bani?|cen(t|ț)i?|c|¢")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::Cent,
+                    MoneyCurrency::Cent,
                 )))
             }),
         },
@@ -99,7 +99,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("dolari?")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::Dollar,
+                    MoneyCurrency::Dollar,
                 )))
             }),
         },
@@ -108,7 +108,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("rupii?")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::INR,
+                    MoneyCurrency::INR,
                 )))
             }),
         },
@@ -161,7 +161,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("dinar kuweitian")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::KWD,
+                    MoneyCurrency::KWD,
                 )))
             }),
         },
@@ -174,8 +174,8 @@ pub fn rules() -> Vec<Rule> {
                     _ => return None,
                 };
                 let c = match kind.as_str() {
-                    "egiptian" => Currency::EGP,
-                    "libanez" => Currency::LBP,
+                    "egiptian" => MoneyCurrency::EGP,
+                    "libanez" => MoneyCurrency::LBP,
                     _ => return None,
                 };
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
@@ -188,7 +188,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("lire?")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::Pound,
+                    MoneyCurrency::Pound,
                 )))
             }),
         },
@@ -198,7 +198,14 @@ pub fn rules() -> Vec<Rule> {
                 regex("exact|cam|aprox(\\.|imativ)?|(aproape|(i|î)n jur)( de)?"),
                 predicate(is_money_with_value),
             ],
-            production: Box::new(|nodes| Some(nodes[1].token_data.clone())),
+            production: Box::new(|nodes| {
+                Some(
+                    nodes[1]
+                        .token_data
+                        .clone()
+                        .with_precision(Precision::of_qualifier(nodes[0])),
+                )
+            }),
         },
         Rule {
             name: "riyals".to_string(),
@@ -209,8 +216,8 @@ pub fn rules() -> Vec<Rule> {
                     _ => return None,
                 };
                 let c = match kind.as_str() {
-                    "saudi" | "saudit" => Currency::SAR,
-                    "qataria" | "qatarian" => Currency::QAR,
+                    "saudi" | "saudit" => MoneyCurrency::SAR,
+                    "qataria" | "qatarian" => MoneyCurrency::QAR,
                     _ => return None,
                 };
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
@@ -223,7 +230,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("roni|lei")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::RON,
+                    MoneyCurrency::RON,
                 )))
             }),
         },
@@ -1,49 +1,100 @@
-use crate::pattern::regex;
+use crate::dimensions::numeral::helpers::{is_natural, numeral_data};
+use crate::pattern::{predicate, regex};
 use crate::types::{Rule, TokenData};
 
-use super::{AmountOfMoneyData, Currency};
+use super::{AmountOfMoneyData, MoneyCurrency};
+
+fn money_data(td: &TokenData) -> Option<&AmountOfMoneyData> {
+    match td {
+        TokenData::AmountOfMoney(d) => Some(d),
+        _ => None,
+    }
+}
+
+fn is_without_cents(td: &TokenData) -> bool {
+    match td {
+        TokenData::AmountOfMoney(d) => {
+            d.currency != MoneyCurrency::Cent && d.value.map(|v| v == v.floor()).unwrap_or(false)
+        }
+        _ => false,
+    }
+}
+
+fn is_cents(td: &TokenData) -> bool {
+    matches!(td, TokenData::AmountOfMoney(d) if d.currency == MoneyCurrency::Cent && d.value.is_some())
+}
 
 pub fn rules() -> Vec<Rule> {
-    vec![Rule {
-        name: "amount keywords (ru)".to_string(),
-        pattern: vec![regex("(\\$|€|£|x00a3&|₽|¢|руб|доллар|цент|пени|евро|фунт|бакс|грн|USD|EUR|GBP|RUB|UAH|KWD|LBP|EGP|QAR|SAR|BGN|MYR|RM)")],
-        production: Box::new(|nodes| {
-            let m = match &nodes[0].token_data {
-                TokenData::RegexMatch(m) => m.group(1)?,
-                _ => return None,
-            };
-            let c = if m.contains('₽') || m.contains("руб") || m.contains("RUB") {
-                Currency::RUB
-            } else if m.contains("грн") || m.contains("UAH") {
-                Currency::UAH
-            } else if m.contains("цент") || m.contains("пени") || m.contains('¢') {
-                Currency::Cent
-            } else if m.contains("евро") || m.contains("EUR") || m.contains('€') {
-                Currency::EUR
-            } else if m.contains("фунт") || m.contains("GBP") || m.contains('£') || m.contains("x00a3&") {
-                Currency::GBP
-            } else if m.contains("KWD") {
-                Currency::KWD
-            } else if m.contains("LBP") {
-                Currency::LBP
-            } else if m.contains("EGP") {
-                Currency::EGP
-            } else if m.contains("QAR") {
-                Currency::QAR
-            } else if m.contains("SAR") {
-                Currency::SAR
-            } else if m.contains("BGN") {
-                Currency::BGN
-            } else if m.contains("MYR") || m.contains("RM") {
-                Currency::MYR
-            } else if m.contains("бакс") {
-                Currency::Unnamed
-            } else {
-                Currency::Dollar
-            };
-            Some(TokenData::AmountOfMoney(
-                AmountOfMoneyData::currency_only(c).with_value(1.0),
-            ))
-        }),
-    }]
+    vec![
+        Rule {
+            name: "amount keywords (ru)".to_string(),
+            pattern: vec![regex(
+                "(руб\\w*|доллар\\w*|цент\\w*|пени|пенс\\w*|евро|фунт\\w*|x00a3&|бакс\\w*|грн|ц\\b)",
+            )],
+            production: Box::new(|nodes| {
+                let m = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?.to_lowercase(),
+                    _ => return None,
+                };
+                let c = if m.starts_with("руб") {
+                    MoneyCurrency::RUB
+                } else if m == "грн" {
+                    MoneyCurrency::UAH
+                } else if m.starts_with("цент") || m.starts_with("пен") || m == "ц" {
+                    MoneyCurrency::Cent
+                } else if m == "евро" {
+                    MoneyCurrency::EUR
+                } else if m.starts_with("фунт") || m == "x00a3&" {
+                    MoneyCurrency::Pound
+                } else if m.starts_with("бакс") {
+                    MoneyCurrency::Unnamed
+                } else {
+                    MoneyCurrency::Dollar
+                };
+                Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
+                    c,
+                )))
+            }),
+        },
+        Rule {
+            name: "intersect".to_string(),
+            pattern: vec![predicate(is_without_cents), predicate(is_natural)],
+            production: Box::new(|nodes| {
+                let money = money_data(&nodes[0].token_data)?;
+                let cents = numeral_data(&nodes[1].token_data)?.value;
+                Some(TokenData::AmountOfMoney(money.clone().with_cents(cents)))
+            }),
+        },
+        Rule {
+            name: "intersect (and number)".to_string(),
+            pattern: vec![
+                predicate(is_without_cents),
+                regex("и"),
+                predicate(is_natural),
+            ],
+            production: Box::new(|nodes| {
+                let money = money_data(&nodes[0].token_data)?;
+                let cents = numeral_data(&nodes[2].token_data)?.value;
+                Some(TokenData::AmountOfMoney(money.clone().with_cents(cents)))
+            }),
+        },
+        Rule {
+            name: "intersect (X cents)".to_string(),
+            pattern: vec![predicate(is_without_cents), predicate(is_cents)],
+            production: Box::new(|nodes| {
+                let money = money_data(&nodes[0].token_data)?;
+                let cents = money_data(&nodes[1].token_data)?.value?;
+                Some(TokenData::AmountOfMoney(money.clone().with_cents(cents)))
+            }),
+        },
+        Rule {
+            name: "intersect (and X cents)".to_string(),
+            pattern: vec![predicate(is_without_cents), regex("и"), predicate(is_cents)],
+            production: Box::new(|nodes| {
+                let money = money_data(&nodes[0].token_data)?;
+                let cents = money_data(&nodes[2].token_data)?.value?;
+                Some(TokenData::AmountOfMoney(money.clone().with_cents(cents)))
+            }),
+        },
+    ]
 }
@@ -1,8 +1,8 @@
 use crate::dimensions::numeral::helpers::{is_natural, is_positive, numeral_data};
 use crate::pattern::{predicate, regex};
-use crate::types::{Rule, TokenData};
+use crate::types::{Precision, Rule, TokenData};
 
-use super::{AmountOfMoneyData, Currency};
+use super::{AmountOfMoneyData, MoneyCurrency};
 
 fn money_data(td: &TokenData) -> Option<&AmountOfMoneyData> {
     if let TokenData::AmountOfMoney(d) = td {
@@ -20,13 +20,13 @@ fn is_money_with_value(td: &TokenData) -> bool {
 fn is_without_cents(td: &TokenData) -> bool {
     match td {
         TokenData::AmountOfMoney(d) => {
-            d.currency != Currency::Cent && d.value.map(|v| v == v.floor()).unwrap_or(false)
+            d.currency != MoneyCurrency::Cent && d.value.map(|v| v == v.floor()).unwrap_or(false)
         }
         _ => false,
     }
 }
 fn is_cents(td: &TokenData) -> bool {
-    matches!(td,TokenData::AmountOfMoney(d) if d.currency==Currency::Cent&&d.value.is_some())
+    matches!(td,TokenData::AmountOfMoney(d) if d.currency==MoneyCurrency::Cent&&d.value.is_some())
 }
 
 pub fn rules() -> Vec<Rule> {
@@ -61,21 +61,33 @@ pub fn rules() -> Vec<Rule> {
                 regex("omkring|cirka|runt|ca"),
                 predicate(is_money_with_value),
             ],
-            production: Box::new(|n| Some(n[1].token_data.clone())),
+            production: Box::new(|n| {
+                Some(
+                    n[1].token_data
+                        .clone()
+                        .with_precision(Precision::of_qualifier(n[0])),
+                )
+            }),
         },
         Rule {
             name: "cent".to_string(),
             pattern: vec![regex("cents?|penn(y|ies)|öre")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::Cent,
+                    MoneyCurrency::Cent,
                 )))
             }),
         },
         Rule {
             name: "exactly <amount-of-money>".to_string(),
             pattern: vec![regex("exakt|precis"), predicate(is_money_with_value)],
-            production: Box::new(|n| Some(n[1].token_data.clone())),
+            production: Box::new(|n| {
+                Some(
+                    n[1].token_data
+                        .clone()
+                        .with_precision(Precision::of_qualifier(n[0])),
+                )
+            }),
         },
         Rule {
             name: "intersect (X cents)".to_string(),
@@ -91,7 +103,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("norska kronor|nkr")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::NOK,
+                    MoneyCurrency::NOK,
                 )))
             }),
         },
@@ -100,7 +112,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("pund?")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::Pound,
+                    MoneyCurrency::Pound,
                 )))
             }),
         },
@@ -131,7 +143,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("kr(onor)?")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::SEK,
+                    MoneyCurrency::SEK,
                 )))
             }),
         },
@@ -140,7 +152,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("dirhams?")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::AED,
+                    MoneyCurrency::AED,
                 )))
             }),
         },
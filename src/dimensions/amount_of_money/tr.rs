@@ -2,7 +2,7 @@ use crate::dimensions::numeral::helpers::{is_natural, is_positive, numeral_data}
 use crate::pattern::{predicate, regex};
 use crate::types::{Rule, TokenData};
 
-use super::{AmountOfMoneyData, Currency};
+use super::{AmountOfMoneyData, MoneyCurrency};
 
 fn money_data(td: &TokenData) -> Option<&AmountOfMoneyData> {
     match td {
@@ -22,18 +22,18 @@ fn is_currency_only(td: &TokenData) -> bool {
 fn is_without_cents(td: &TokenData) -> bool {
     match td {
         TokenData::AmountOfMoney(d) => {
-            d.currency != Currency::Cent && d.value.map(|v| v == v.floor()).unwrap_or(false)
+            d.currency != MoneyCurrency::Cent && d.value.map(|v| v == v.floor()).unwrap_or(false)
         }
         _ => false,
     }
 }
 
 fn is_cents(td: &TokenData) -> bool {
-    matches!(td, TokenData::AmountOfMoney(d) if d.currency == Currency::Cent && d.value.is_some())
+    matches!(td, TokenData::AmountOfMoney(d) if d.currency == MoneyCurrency::Cent && d.value.is_some())
 }
 
 fn is_dollar_coin(td: &TokenData) -> bool {
-    matches!(td, TokenData::AmountOfMoney(d) if d.currency == Currency::Cent && matches!(d.value, Some(v) if (v - 25.0).abs() < 1e-9 || (v - 50.0).abs() < 1e-9))
+    matches!(td, TokenData::AmountOfMoney(d) if d.currency == MoneyCurrency::Cent && matches!(d.value, Some(v) if (v - 25.0).abs() < 1e-9 || (v - 50.0).abs() < 1e-9))
 }
 
 pub fn rules() -> Vec<Rule> {
@@ -54,7 +54,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("₺")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::TRY,
+                    MoneyCurrency::TRY,
                 )))
             }),
         },
@@ -63,7 +63,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("kuruş?")],
             production: Box::new(|_| {
                 Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
-                    Currency::Cent,
+                    MoneyCurrency::Cent,
                 )))
             }),
         },
@@ -1,35 +1,92 @@
-use crate::pattern::regex;
+use crate::dimensions::numeral::helpers::{is_positive, numeral_data};
+use crate::pattern::{predicate, regex};
 use crate::types::{Rule, TokenData};
 
-use super::{AmountOfMoneyData, Currency};
+use super::{AmountOfMoneyData, MoneyCurrency};
+
+fn vi_currency(s: &str) -> MoneyCurrency {
+    if s.starts_with("đô") {
+        MoneyCurrency::Dollar
+    } else if s.starts_with('đ') || s.starts_with("vn") || s == "₫" {
+        MoneyCurrency::VND
+    } else if s == "xen" || s == "xu" {
+        MoneyCurrency::Cent
+    } else if s.contains("eur") || s.contains('€') {
+        MoneyCurrency::EUR
+    } else if s.contains("gbp") || s.contains("pound") || s.contains('£') {
+        MoneyCurrency::GBP
+    } else if s.contains("aed") || s.contains("dirham") {
+        MoneyCurrency::AED
+    } else if s.contains("rs") || s.contains("rupee") {
+        MoneyCurrency::INR
+    } else {
+        MoneyCurrency::Dollar
+    }
+}
+
+/// A number built with nghìn/ngàn, triệu or tỷ, e.g. "2 triệu" or "hai
+/// trăm nghìn", as opposed to the bare magnitude word.
+fn is_thousands(td: &TokenData) -> bool {
+    matches!(td, TokenData::Numeral(d) if d.grain.is_some_and(|g| g >= 3) && !d.multipliable)
+}
 
 pub fn rules() -> Vec<Rule> {
-    vec![Rule {
-        name: "amount keywords (vi)".to_string(),
-        pattern: vec![regex("(\\$|€|£|đồng|đô( la)?|xen|xu|vnd|vnđ|vn\\$|rupees?|rs\\.?|pounds?|aed|dirhams?|usd|eur|gbp)")],
-        production: Box::new(|nodes| {
-            let m = match &nodes[0].token_data {
-                TokenData::RegexMatch(m) => m.group(1)?.to_lowercase(),
-                _ => return None,
-            };
-            let c = if m.contains("đồng") || m.contains("vnd") || m.contains("vnđ") || m.contains("vn$") {
-                Currency::VND
-            } else if m.contains("xen") || m.contains("xu") {
-                Currency::Cent
-            } else if m.contains("eur") || m.contains('€') {
-                Currency::EUR
-            } else if m.contains("gbp") || m.contains("pound") || m.contains('£') {
-                Currency::GBP
-            } else if m.contains("aed") || m.contains("dirham") {
-                Currency::AED
-            } else if m.contains("rs") || m.contains("rupee") {
-                Currency::INR
-            } else {
-                Currency::Dollar
-            };
-            Some(TokenData::AmountOfMoney(
-                AmountOfMoneyData::currency_only(c).with_value(1.0),
-            ))
-        }),
-    }]
+    vec![
+        Rule {
+            name: "amount keywords (vi)".to_string(),
+            pattern: vec![regex(
+                "(\\$|€|£|₫|đồng|đô( la| mỹ)?\\b|xen\\b|xu\\b|vnd|vnđ|vn\\$|đ\\b|rupees?|rs\\.?|pounds?|aed|dirhams?|usd|eur|gbp)",
+            )],
+            production: Box::new(|nodes| {
+                let m = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?.to_lowercase(),
+                    _ => return None,
+                };
+                Some(TokenData::AmountOfMoney(AmountOfMoneyData::currency_only(
+                    vi_currency(&m),
+                )))
+            }),
+        },
+        // "200k", "1,5tr", "2tr5": k (nghìn) and tr (triệu) after a number
+        // are only written for đồng, so they need no currency word.
+        Rule {
+            name: "<amount> k|tr (vi)".to_string(),
+            pattern: vec![
+                predicate(is_positive),
+                regex("(k|tr)(\\d{1,3})?(?:\\s*(?:đồng|vnđ|vnd|đ))?\\b"),
+            ],
+            production: Box::new(|nodes| {
+                let v = numeral_data(&nodes[0].token_data)?.value;
+                let (unit, rest) = match &nodes[1].token_data {
+                    TokenData::RegexMatch(m) => (m.group(1)?.to_lowercase(), m.group(2)),
+                    _ => return None,
+                };
+                let scale = if unit == "k" { 1e3 } else { 1e6 };
+                // "2tr5" is 2.5 triệu, "5k2" is 5.2 nghìn
+                let fraction = match rest {
+                    Some(digits) => {
+                        let n: f64 = digits.parse().ok()?;
+                        n / 10f64.powi(i32::try_from(digits.len()).ok()?)
+                    }
+                    None => 0.0,
+                };
+                Some(TokenData::AmountOfMoney(
+                    AmountOfMoneyData::currency_only(MoneyCurrency::VND)
+                        .with_value((v + fraction) * scale),
+                ))
+            }),
+        },
+        // "2 triệu" on its own is most likely đồng, but could count
+        // anything, so it is only reported as a latent amount.
+        Rule {
+            name: "<number> nghìn|triệu|tỷ (latent) (vi)".to_string(),
+            pattern: vec![predicate(is_thousands)],
+            production: Box::new(|nodes| {
+                let v = numeral_data(&nodes[0].token_data)?.value;
+                let mut data = AmountOfMoneyData::currency_only(MoneyCurrency::VND).with_value(v);
+                data.latent = true;
+                Some(TokenData::AmountOfMoney(data))
+            }),
+        },
+    ]
 }
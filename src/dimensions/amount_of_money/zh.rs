@@ -1,40 +1,67 @@
-use crate::pattern::regex;
+use crate::dimensions::numeral::helpers::{is_positive, numeral_data};
+use crate::pattern::{predicate, regex};
 use crate::types::{Rule, TokenData};
 
-use super::{AmountOfMoneyData, Currency};
+use super::{AmountOfMoneyData, MoneyCurrency};
+
+/// Currency names for a whole unit: the yuan, Hong Kong, Taiwan and Macau
+/// currencies by name, or a bare "元"/"块"/"蚊" left to the region.
+const UNIT: &str =
+    "(人民币|人民幣|港幣|港币|新台幣|新台币|台幣|臺幣|台币|澳門幣|澳门币|元|圆|块|蚊)";
+
+fn currency_of(m: &str) -> MoneyCurrency {
+    if m.contains("人民") {
+        MoneyCurrency::CNY
+    } else if m.starts_with('港') {
+        MoneyCurrency::HKD
+    } else if m.contains('台') || m.contains('臺') {
+        MoneyCurrency::TWD
+    } else if m.starts_with('澳') {
+        MoneyCurrency::MOP
+    } else if m.contains('分')
+        || m.contains('仙')
+        || m.contains('角')
+        || m.contains('毛')
+        || m.contains('毫')
+    {
+        MoneyCurrency::Cent
+    } else if m.contains('€') {
+        MoneyCurrency::EUR
+    } else if m.contains('£') {
+        MoneyCurrency::GBP
+    } else {
+        MoneyCurrency::Dollar
+    }
+}
 
 pub fn rules() -> Vec<Rule> {
-    vec![Rule {
-        name: "amount keywords (zh)".to_string(),
-        pattern: vec![regex(
-            "(人民币|人民幣|港幣|元|圆|块|蚊|個|分|仙|角|毛|毫|\\$|€|£)",
-        )],
-        production: Box::new(|nodes| {
-            let m = match &nodes[0].token_data {
-                TokenData::RegexMatch(m) => m.group(1)?,
-                _ => return None,
-            };
-            let c = if m.contains("人民") {
-                Currency::CNY
-            } else if m.contains("港幣") {
-                Currency::HKD
-            } else if m.contains("分")
-                || m.contains("仙")
-                || m.contains("角")
-                || m.contains("毛")
-                || m.contains("毫")
-            {
-                Currency::Cent
-            } else if m.contains('€') {
-                Currency::EUR
-            } else if m.contains('£') {
-                Currency::GBP
-            } else {
-                Currency::Dollar
-            };
-            Some(TokenData::AmountOfMoney(
-                AmountOfMoneyData::currency_only(c).with_value(1.0),
-            ))
-        }),
-    }]
+    vec![
+        Rule {
+            name: "amount keywords (zh)".to_string(),
+            pattern: vec![regex(&format!("({UNIT}|個|分|仙|角|毛|毫|\\$|€|£)"))],
+            production: Box::new(|nodes| {
+                let m = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?,
+                    _ => return None,
+                };
+                Some(TokenData::AmountOfMoney(
+                    AmountOfMoneyData::currency_only(currency_of(m)).with_value(1.0),
+                ))
+            }),
+        },
+        Rule {
+            name: "<amount> <unit> (zh)".to_string(),
+            pattern: vec![predicate(is_positive), regex(UNIT)],
+            production: Box::new(|nodes| {
+                let v = numeral_data(&nodes[0].token_data)?.value;
+                let m = match &nodes[1].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?,
+                    _ => return None,
+                };
+                Some(TokenData::AmountOfMoney(
+                    AmountOfMoneyData::currency_only(currency_of(m)).with_value(v),
+                ))
+            }),
+        },
+    ]
 }
@@ -0,0 +1,53 @@
+pub mod rules;
+
+use crate::types::DimensionValue;
+
+#[derive(Debug, Clone)]
+pub struct CoordinateData {
+    /// Latitude in decimal degrees, positive north; `None` when only the
+    /// longitude is given.
+    pub lat: Option<f64>,
+    /// Longitude in decimal degrees, positive east; `None` when only the
+    /// latitude is given.
+    pub lon: Option<f64>,
+}
+
+impl CoordinateData {
+    /// A coordinate, if both parts are in range.
+    pub fn new(lat: f64, lon: f64) -> Option<Self> {
+        (is_latitude(lat) && is_longitude(lon)).then_some(CoordinateData {
+            lat: Some(lat),
+            lon: Some(lon),
+        })
+    }
+
+    /// A coordinate with only its latitude or only its longitude, if in range.
+    pub fn partial(value: f64, is_lat: bool) -> Option<Self> {
+        if is_lat {
+            is_latitude(value).then_some(CoordinateData {
+                lat: Some(value),
+                lon: None,
+            })
+        } else {
+            is_longitude(value).then_some(CoordinateData {
+                lat: None,
+                lon: Some(value),
+            })
+        }
+    }
+}
+
+fn is_latitude(value: f64) -> bool {
+    (-90.0..=90.0).contains(&value)
+}
+
+fn is_longitude(value: f64) -> bool {
+    (-180.0..=180.0).contains(&value)
+}
+
+pub fn resolve(data: &CoordinateData) -> DimensionValue {
+    DimensionValue::Coordinate {
+        lat: data.lat,
+        lon: data.lon,
+    }
+}
@@ -0,0 +1,213 @@
+use crate::pattern::regex;
+use crate::types::{Rule, TokenData};
+
+use super::CoordinateData;
+
+/// A hemisphere letter. It must be uppercase, so "10 s" stays a duration.
+const HEMISPHERE: &str = r"((?-i:[NSEW]))";
+
+/// The degrees of a coordinate component: decimal degrees, or degrees,
+/// minutes and seconds. Four groups: degrees, degree sign, minutes, seconds.
+const DEGREES: &str = r#"(\d{1,3}(?:\.\d+)?)\s*(°|º|\bdeg\b)?\s*(?:(\d{1,2}(?:\.\d+)?)\s*['′]\s*(?:(\d{1,2}(?:\.\d+)?)\s*(?:"|″|''))?)?"#;
+
+/// A component read from its hemisphere group and the four [`DEGREES`]
+/// groups starting at `first`: its value in decimal degrees, made negative
+/// for S and W, and whether it is a latitude.
+fn component(
+    m: &crate::types::RegexMatchData,
+    hemisphere: usize,
+    first: usize,
+) -> Option<(f64, bool)> {
+    let group = |offset: usize| m.group(first.saturating_add(offset));
+    let degrees: f64 = group(0)?.parse().ok()?;
+    let minutes: f64 = group(2).map_or(Some(0.0), |v| v.parse().ok())?;
+    let seconds: f64 = group(3).map_or(Some(0.0), |v| v.parse().ok())?;
+    if minutes >= 60.0 || seconds >= 60.0 || (group(2).is_some() && degrees.fract() != 0.0) {
+        return None;
+    }
+    let value = degrees + minutes / 60.0 + seconds / 3600.0;
+    match m.group(hemisphere)? {
+        "N" => Some((value, true)),
+        "S" => Some((-value, true)),
+        "E" => Some((value, false)),
+        _ => Some((-value, false)),
+    }
+}
+
+/// A rule for two components, given the groups of each as
+/// `(hemisphere, first degrees group)`. Either order of latitude and
+/// longitude is accepted, but not two of the same.
+fn hemisphere_rule(name: &str, pattern: &str, groups: [(usize, usize); 2]) -> Rule {
+    Rule {
+        name: name.to_string(),
+        pattern: vec![regex(pattern)],
+        production: Box::new(move |nodes| {
+            let TokenData::RegexMatch(m) = &nodes[0].token_data else {
+                return None;
+            };
+            let (first, first_is_lat) = component(m, groups[0].0, groups[0].1)?;
+            let (second, second_is_lat) = component(m, groups[1].0, groups[1].1)?;
+            let (lat, lon) = match (first_is_lat, second_is_lat) {
+                (true, false) => (first, second),
+                (false, true) => (second, first),
+                _ => return None,
+            };
+            Some(TokenData::Coordinate(CoordinateData::new(lat, lon)?))
+        }),
+    }
+}
+
+/// A rule for one component on its own, given its groups as
+/// `(hemisphere, first degrees group)`. The degree sign is required, so
+/// "5 N" isn't read as a latitude. The pattern also takes in a neighbouring
+/// hemisphere or component, in the `pair` groups, so that the parts of a
+/// pair that isn't a coordinate ("95° N, 10° E") are rejected together
+/// rather than read one by one.
+fn lone_component_rule(
+    name: &str,
+    pattern: &str,
+    (hemisphere, first): (usize, usize),
+    pair: &'static [usize],
+) -> Rule {
+    Rule {
+        name: name.to_string(),
+        pattern: vec![regex(pattern)],
+        production: Box::new(move |nodes| {
+            let TokenData::RegexMatch(m) = &nodes[0].token_data else {
+                return None;
+            };
+            if pair.iter().any(|&group| m.group(group).is_some()) {
+                return None;
+            }
+            m.group(first.saturating_add(1))?;
+            let (value, is_lat) = component(m, hemisphere, first)?;
+            Some(TokenData::Coordinate(CoordinateData::partial(
+                value, is_lat,
+            )?))
+        }),
+    }
+}
+
+pub fn rules() -> Vec<Rule> {
+    vec![
+        // "N40°42'46\" W74°0'21\""
+        hemisphere_rule(
+            "coordinate with leading hemispheres",
+            &format!(r"{HEMISPHERE}\s*{DEGREES}\s*[,;/]?\s*{HEMISPHERE}\s*{DEGREES}"),
+            [(1, 2), (6, 7)],
+        ),
+        // "40.7128° N, 74.0060° W", "74.006 W 40.71 N"
+        hemisphere_rule(
+            "coordinate with trailing hemispheres",
+            &format!(r"{DEGREES}\s*{HEMISPHERE}\s*[,;/]?\s*{DEGREES}\s*{HEMISPHERE}"),
+            [(5, 1), (10, 6)],
+        ),
+        // "N40°42'46\"", "40.7128° N": a partial coordinate
+        lone_component_rule(
+            "coordinate component with leading hemisphere",
+            &format!(
+                r"\b{HEMISPHERE}\s*{DEGREES}(\s*[,;/]?\s*(?:{HEMISPHERE}\s*{DEGREES}|{DEGREES}\s*{HEMISPHERE})|\s*{HEMISPHERE})?"
+            ),
+            (1, 2),
+            &[6],
+        ),
+        lone_component_rule(
+            "coordinate component with trailing hemisphere",
+            &format!(
+                r"(\b{HEMISPHERE}\s*)?{DEGREES}\s*{HEMISPHERE}(\s*[,;/]?\s*(?:{HEMISPHERE}\s*{DEGREES}|{DEGREES}\s*{HEMISPHERE}))?"
+            ),
+            (7, 3),
+            &[1, 8],
+        ),
+        // "40.7128,-74.0060": signed decimal degrees, latitude first. At
+        // least three decimals, so lists like "1.5, 2.5" aren't read as one.
+        Rule {
+            name: "decimal coordinate pair".to_string(),
+            pattern: vec![regex(r"(-?\d{1,2}\.\d{3,})\s*,\s*(-?\d{1,3}\.\d{3,})")],
+            production: Box::new(|nodes| {
+                let TokenData::RegexMatch(m) = &nodes[0].token_data else {
+                    return None;
+                };
+                let lat = m.group(1)?.parse().ok()?;
+                let lon = m.group(2)?.parse().ok()?;
+                Some(TokenData::Coordinate(CoordinateData::new(lat, lon)?))
+            }),
+        },
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::{DimensionKind, DimensionValue};
+
+    fn coordinates(text: &str) -> Vec<(Option<f64>, Option<f64>)> {
+        crate::parse_en(text, &[DimensionKind::Coordinate])
+            .into_iter()
+            .filter_map(|e| match e.value {
+                DimensionValue::Coordinate { lat, lon } => Some((lat, lon)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn near(value: Option<f64>, expected: f64) -> bool {
+        value.is_some_and(|v| (v - expected).abs() < 1e-4)
+    }
+
+    #[test]
+    fn test_coordinates() {
+        for text in [
+            "40.7128° N, 74.0060° W",
+            "meet at 40.7128,-74.0060 tomorrow",
+            "N40°42'46.08\" W74°0'21.6\"",
+            "40°42′46.08″N 74°00′21.6″W",
+            "74.0060 W 40.7128 N",
+        ] {
+            let found = coordinates(text);
+            assert_eq!(found.len(), 1, "{text:?}: {found:?}");
+            let (lat, lon) = found[0];
+            assert!(
+                near(lat, 40.7128) && near(lon, -74.006),
+                "{text:?}: {found:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_partial_coordinates() {
+        for (text, lat, lon) in [
+            ("N40°42'46\"", Some(40.712_777), None),
+            ("40.7128° N", Some(40.7128), None),
+            ("W74°0'21.6\"", None, Some(-74.006)),
+            ("33.87° S", Some(-33.87), None),
+        ] {
+            let found = coordinates(text);
+            assert_eq!(found.len(), 1, "{text:?}: {found:?}");
+            let matches = |value: Option<f64>, expected: Option<f64>| match expected {
+                Some(expected) => near(value, expected),
+                None => value.is_none(),
+            };
+            assert!(
+                matches(found[0].0, lat) && matches(found[0].1, lon),
+                "{text:?}: {found:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_coordinate_negatives() {
+        for text in [
+            "1.5, 2.5",
+            "wait 10 s, 20 s",
+            "95.0000° N, 10.0000° E",
+            "40.7128° N, 74.0060° N",
+            "1.234,56",
+            "5 N",
+            "N95°",
+            "N95° E10°",
+            "95° N 10° E",
+        ] {
+            assert!(coordinates(text).is_empty(), "{text:?}");
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use crate::dimensions::numeral::helpers::{is_positive, numeral_data};
 use crate::pattern::{dim, predicate, regex};
-use crate::types::{DimensionKind, Rule, TokenData};
+use crate::types::{DimensionKind, Precision, Rule, TokenData};
 
 use super::{DistanceData, DistanceUnit};
 
@@ -88,7 +88,14 @@ pub fn rules() -> Vec<Rule> {
                 ),
                 dim(DimensionKind::Distance),
             ],
-            production: Box::new(|nodes| Some(nodes[1].token_data.clone())),
+            production: Box::new(|nodes| {
+                Some(
+                    nodes[1]
+                        .token_data
+                        .clone()
+                        .with_precision(Precision::of_qualifier(nodes[0])),
+                )
+            }),
         },
         Rule {
             name: "between|from <numeral> to|and <dist>".to_string(),
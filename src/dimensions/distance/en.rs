@@ -1,8 +1,8 @@
-use crate::dimensions::numeral::helpers::numeral_data;
+use crate::dimensions::numeral::helpers::{integer_value, numeral_data};
 use crate::pattern::{dim, predicate, regex};
-use crate::types::{DimensionKind, Rule, TokenData};
+use crate::types::{DimensionKind, Precision, Rule, TokenData};
 
-use super::{distance_sum, DistanceData, DistanceUnit};
+use super::{compound, DistanceData, DistanceUnit};
 
 fn distance_data(token_data: &TokenData) -> Option<&DistanceData> {
     match token_data {
@@ -11,6 +11,21 @@ fn distance_data(token_data: &TokenData) -> Option<&DistanceData> {
     }
 }
 
+/// The distance a unit word ("miles", "cm") applies to: anything but a
+/// compound, whose unit is already fixed in metres.
+fn unit_target(token_data: &TokenData) -> Option<&DistanceData> {
+    distance_data(token_data).filter(|data| data.components.is_empty())
+}
+
+/// Whether `td` is a single whole number of one of `units`, the leading
+/// part of a compound whose trailing unit goes unsaid.
+fn is_whole_distance_in(td: &TokenData, units: &[DistanceUnit]) -> bool {
+    matches!(td, TokenData::Distance(data)
+        if data.value.is_some_and(|v| v > 0.0 && v.fract() == 0.0)
+            && data.unit.is_some_and(|u| units.contains(&u))
+            && data.components.is_empty())
+}
+
 /// Matches simple Distance tokens (has value and unit, no interval).
 fn is_simple_distance() -> crate::types::PatternItem {
     predicate(|td| {
@@ -39,7 +54,7 @@ pub fn rules() -> Vec<Rule> {
             name: "miles".to_string(),
             pattern: vec![dim(DimensionKind::Distance), regex(r"mi(le(s)?)?")],
             production: Box::new(|nodes| {
-                let dd = distance_data(&nodes[0].token_data)?;
+                let dd = unit_target(&nodes[0].token_data)?;
                 Some(TokenData::Distance(
                     dd.clone().with_unit(DistanceUnit::Mile),
                 ))
@@ -49,7 +64,7 @@ pub fn rules() -> Vec<Rule> {
             name: "yard".to_string(),
             pattern: vec![dim(DimensionKind::Distance), regex(r"y(ar)?ds?")],
             production: Box::new(|nodes| {
-                let dd = distance_data(&nodes[0].token_data)?;
+                let dd = unit_target(&nodes[0].token_data)?;
                 Some(TokenData::Distance(
                     dd.clone().with_unit(DistanceUnit::Yard),
                 ))
@@ -59,7 +74,7 @@ pub fn rules() -> Vec<Rule> {
             name: "feet".to_string(),
             pattern: vec![dim(DimensionKind::Distance), regex(r"('|f(oo|ee)?ts?)")],
             production: Box::new(|nodes| {
-                let dd = distance_data(&nodes[0].token_data)?;
+                let dd = unit_target(&nodes[0].token_data)?;
                 Some(TokenData::Distance(
                     dd.clone().with_unit(DistanceUnit::Foot),
                 ))
@@ -72,7 +87,7 @@ pub fn rules() -> Vec<Rule> {
                 regex(r#"("|''|in(ch(es)?)?)"#),
             ],
             production: Box::new(|nodes| {
-                let dd = distance_data(&nodes[0].token_data)?;
+                let dd = unit_target(&nodes[0].token_data)?;
                 Some(TokenData::Distance(
                     dd.clone().with_unit(DistanceUnit::Inch),
                 ))
@@ -86,7 +101,7 @@ pub fn rules() -> Vec<Rule> {
                 regex(r"k(ilo)?m?(et(er|re))?s?"),
             ],
             production: Box::new(|nodes| {
-                let dd = distance_data(&nodes[0].token_data)?;
+                let dd = unit_target(&nodes[0].token_data)?;
                 Some(TokenData::Distance(
                     dd.clone().with_unit(DistanceUnit::Kilometre),
                 ))
@@ -96,7 +111,7 @@ pub fn rules() -> Vec<Rule> {
             name: "meters".to_string(),
             pattern: vec![dim(DimensionKind::Distance), regex(r"met(er|re)s?")],
             production: Box::new(|nodes| {
-                let dd = distance_data(&nodes[0].token_data)?;
+                let dd = unit_target(&nodes[0].token_data)?;
                 Some(TokenData::Distance(
                     dd.clone().with_unit(DistanceUnit::Metre),
                 ))
@@ -106,7 +121,7 @@ pub fn rules() -> Vec<Rule> {
             name: "centimeters".to_string(),
             pattern: vec![dim(DimensionKind::Distance), regex(r"cm|centimet(er|re)s?")],
             production: Box::new(|nodes| {
-                let dd = distance_data(&nodes[0].token_data)?;
+                let dd = unit_target(&nodes[0].token_data)?;
                 Some(TokenData::Distance(
                     dd.clone().with_unit(DistanceUnit::Centimetre),
                 ))
@@ -116,7 +131,7 @@ pub fn rules() -> Vec<Rule> {
             name: "millimeters".to_string(),
             pattern: vec![dim(DimensionKind::Distance), regex(r"mm|millimet(er|re)s?")],
             production: Box::new(|nodes| {
-                let dd = distance_data(&nodes[0].token_data)?;
+                let dd = unit_target(&nodes[0].token_data)?;
                 Some(TokenData::Distance(
                     dd.clone().with_unit(DistanceUnit::Millimetre),
                 ))
@@ -127,7 +142,7 @@ pub fn rules() -> Vec<Rule> {
             name: "m (miles or meters)".to_string(),
             pattern: vec![dim(DimensionKind::Distance), regex(r"m")],
             production: Box::new(|nodes| {
-                let dd = distance_data(&nodes[0].token_data)?;
+                let dd = unit_target(&nodes[0].token_data)?;
                 Some(TokenData::Distance(dd.clone().with_unit(DistanceUnit::M)))
             }),
         },
@@ -139,15 +154,7 @@ pub fn rules() -> Vec<Rule> {
             production: Box::new(|nodes| {
                 let d1 = distance_data(&nodes[0].token_data)?;
                 let d2 = distance_data(&nodes[2].token_data)?;
-                let v1 = d1.value?;
-                let v2 = d2.value?;
-                let u1 = d1.unit?;
-                let u2 = d2.unit?;
-                if u1 == u2 || v1 <= 0.0 || v2 <= 0.0 {
-                    return None;
-                }
-                let (value, unit) = distance_sum(v1, u1, v2, u2)?;
-                Some(TokenData::Distance(DistanceData::new(value, unit)))
+                Some(TokenData::Distance(compound(d1, d2)?))
             }),
         },
         // <distance> <distance> (no separator)
@@ -157,15 +164,39 @@ pub fn rules() -> Vec<Rule> {
             production: Box::new(|nodes| {
                 let d1 = distance_data(&nodes[0].token_data)?;
                 let d2 = distance_data(&nodes[1].token_data)?;
-                let v1 = d1.value?;
-                let v2 = d2.value?;
-                let u1 = d1.unit?;
-                let u2 = d2.unit?;
-                if u1 == u2 || v1 <= 0.0 || v2 <= 0.0 {
-                    return None;
-                }
-                let (value, unit) = distance_sum(v1, u1, v2, u2)?;
-                Some(TokenData::Distance(DistanceData::new(value, unit)))
+                Some(TokenData::Distance(compound(d1, d2)?))
+            }),
+        },
+        // <feet> <number>: "6 foot 2", "5'10"
+        Rule {
+            name: "<feet> <inches> (implicit unit)".to_string(),
+            pattern: vec![
+                predicate(|td| is_whole_distance_in(td, &[DistanceUnit::Foot])),
+                predicate(|td| integer_value(td).is_some_and(|v| (1..12).contains(&v))),
+            ],
+            production: Box::new(|nodes| {
+                let feet = distance_data(&nodes[0].token_data)?;
+                let inches = numeral_data(&nodes[1].token_data)?.value;
+                Some(TokenData::Distance(compound(
+                    feet,
+                    &DistanceData::new(inches, DistanceUnit::Inch),
+                )?))
+            }),
+        },
+        // <metres> <number>: "1m 75", "1 metre 80"
+        Rule {
+            name: "<metres> <centimetres> (implicit unit)".to_string(),
+            pattern: vec![
+                predicate(|td| is_whole_distance_in(td, &[DistanceUnit::Metre, DistanceUnit::M])),
+                predicate(|td| integer_value(td).is_some_and(|v| (1..100).contains(&v))),
+            ],
+            production: Box::new(|nodes| {
+                let metres = distance_data(&nodes[0].token_data)?;
+                let centimetres = numeral_data(&nodes[1].token_data)?.value;
+                Some(TokenData::Distance(compound(
+                    metres,
+                    &DistanceData::new(centimetres, DistanceUnit::Centimetre),
+                )?))
             }),
         },
         // === Precision ===
@@ -177,7 +208,14 @@ pub fn rules() -> Vec<Rule> {
                 ),
                 dim(DimensionKind::Distance),
             ],
-            production: Box::new(|nodes| Some(nodes[1].token_data.clone())),
+            production: Box::new(|nodes| {
+                Some(
+                    nodes[1]
+                        .token_data
+                        .clone()
+                        .with_precision(Precision::of_qualifier(nodes[0])),
+                )
+            }),
         },
         // === Interval rules ===
         // between|from <numeral> and|to <distance>
@@ -332,7 +370,10 @@ mod tests {
     fn test_distance() {
         let mut rules = numeral::en::rules();
         rules.extend(super::rules());
-        let options = Options { with_latent: false };
+        let options = Options {
+            with_latent: false,
+            ..Options::default()
+        };
         let context = Context::default();
 
         for (text, expected_val, expected_unit) in &[
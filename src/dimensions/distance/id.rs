@@ -0,0 +1,44 @@
+use crate::pattern::{dim, regex};
+use crate::types::{DimensionKind, Rule, TokenData};
+
+use super::{DistanceData, DistanceUnit};
+
+fn distance_data(td: &TokenData) -> Option<&DistanceData> {
+    match td {
+        TokenData::Distance(d) => Some(d),
+        _ => None,
+    }
+}
+
+fn lookup_unit(s: &str) -> Option<DistanceUnit> {
+    match s.to_lowercase().as_str() {
+        "mm" | "milimeter" => Some(DistanceUnit::Millimetre),
+        "cm" | "senti" | "sentimeter" | "centimeter" => Some(DistanceUnit::Centimetre),
+        "m" | "meter" => Some(DistanceUnit::Metre),
+        "km" | "kilometer" => Some(DistanceUnit::Kilometre),
+        "inci" | "inch" => Some(DistanceUnit::Inch),
+        "kaki" => Some(DistanceUnit::Foot),
+        "yard" | "yd" => Some(DistanceUnit::Yard),
+        "mil" => Some(DistanceUnit::Mile),
+        _ => None,
+    }
+}
+
+pub fn rules() -> Vec<Rule> {
+    vec![Rule {
+        name: "<latent dist> mm/cm/m/km/inci/kaki/yard/mil".to_string(),
+        pattern: vec![
+            dim(DimensionKind::Distance),
+            regex("(mm|milimeter|cm|senti(meter)?|centimeter|km|kilometer|m(eter)?|inci|inch|kaki|yard|yd|mil)\\b"),
+        ],
+        production: Box::new(|nodes| {
+            let d = distance_data(&nodes[0].token_data)?;
+            let matched = match &nodes[1].token_data {
+                TokenData::RegexMatch(m) => m.group(1)?,
+                _ => return None,
+            };
+            let u = lookup_unit(matched)?;
+            Some(TokenData::Distance(d.clone().with_unit(u)))
+        }),
+    }]
+}
@@ -7,6 +7,7 @@ pub mod es;
 pub mod fr;
 pub mod ga;
 pub mod hr;
+pub mod id;
 pub mod it;
 pub mod km;
 pub mod ko;
@@ -19,22 +20,33 @@ pub mod sv;
 pub mod tr;
 pub mod zh;
 
-use crate::types::{DimensionValue, MeasurementPoint, MeasurementValue};
+use crate::types::{DimensionValue, MeasurementPoint, MeasurementValue, Precision, Unit};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The unit of a distance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum DistanceUnit {
+    /// Millimetres.
     Millimetre,
+    /// Centimetres.
     Centimetre,
+    /// Metres.
     Metre,
+    /// Kilometres.
     Kilometre,
+    /// Inches.
     Inch,
+    /// Feet.
     Foot,
+    /// Yards.
     Yard,
+    /// Miles.
     Mile,
-    M, // ambiguous: miles or metres
+    /// A bare "m", which may mean miles or metres.
+    M,
 }
 
 impl DistanceUnit {
+    /// Returns the unit name (e.g. `"mile"`).
     pub fn as_str(&self) -> &'static str {
         match self {
             DistanceUnit::Millimetre => "millimetre",
@@ -48,6 +60,17 @@ impl DistanceUnit {
             DistanceUnit::M => "m",
         }
     }
+
+    /// `value` of this unit in metres; a bare "m" is taken as metres.
+    ///
+    /// ```
+    /// use duckling::DistanceUnit;
+    ///
+    /// assert!((DistanceUnit::Foot.to_metres(6.0) - 1.8288).abs() < 1e-9);
+    /// ```
+    pub fn to_metres(self, value: f64) -> f64 {
+        to_metres(value, self)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -56,6 +79,10 @@ pub struct DistanceData {
     pub unit: Option<DistanceUnit>,
     pub min_value: Option<f64>,
     pub max_value: Option<f64>,
+    pub precision: Option<Precision>,
+    /// The distances a compound ("5 feet 10 inches") sums, in the order
+    /// written; empty for a single distance.
+    pub components: Vec<(f64, DistanceUnit)>,
 }
 
 impl DistanceData {
@@ -65,6 +92,8 @@ impl DistanceData {
             unit: Some(unit),
             min_value: None,
             max_value: None,
+            precision: None,
+            components: Vec::new(),
         }
     }
 
@@ -74,6 +103,8 @@ impl DistanceData {
             unit: None,
             min_value: None,
             max_value: None,
+            precision: None,
+            components: Vec::new(),
         }
     }
 
@@ -83,6 +114,8 @@ impl DistanceData {
             unit: Some(unit),
             min_value: None,
             max_value: None,
+            precision: None,
+            components: Vec::new(),
         }
     }
 
@@ -132,22 +165,6 @@ fn is_imperial(u: DistanceUnit) -> bool {
     )
 }
 
-/// Ordering: smaller physical unit = lower order.
-/// Metric units always ordered before Imperial (so cross-system prefers metric).
-fn unit_order(u: DistanceUnit) -> u8 {
-    match u {
-        DistanceUnit::Millimetre => 0,
-        DistanceUnit::Centimetre => 1,
-        DistanceUnit::Metre => 2,
-        DistanceUnit::Kilometre => 3,
-        DistanceUnit::Inch => 4,
-        DistanceUnit::Foot => 5,
-        DistanceUnit::Yard => 6,
-        DistanceUnit::Mile => 7,
-        DistanceUnit::M => 2, // defaults to Metre order
-    }
-}
-
 /// Convert a value from the given unit to metres (SI base).
 fn to_metres(v: f64, u: DistanceUnit) -> f64 {
     const METRES_PER_INCH: f64 = 0.0254;
@@ -164,86 +181,57 @@ fn to_metres(v: f64, u: DistanceUnit) -> f64 {
     }
 }
 
-/// Convert metres to the target unit.
-fn from_metres(metres: f64, target: DistanceUnit) -> f64 {
-    const METRES_PER_INCH: f64 = 0.0254;
-    match target {
-        DistanceUnit::Millimetre => metres * 1000.0,
-        DistanceUnit::Centimetre => metres * 100.0,
-        DistanceUnit::Metre => metres,
-        DistanceUnit::Kilometre => metres / 1000.0,
-        DistanceUnit::Inch => metres / METRES_PER_INCH,
-        DistanceUnit::Foot => metres / (12.0 * METRES_PER_INCH),
-        DistanceUnit::Yard => metres / (36.0 * METRES_PER_INCH),
-        DistanceUnit::Mile => metres / (63360.0 * METRES_PER_INCH),
-        DistanceUnit::M => metres,
+/// The units two distances written together are in, the ambiguous M taken
+/// as metres beside a metric unit and as miles beside an imperial one.
+fn resolve_m(u1: DistanceUnit, u2: DistanceUnit) -> Option<(DistanceUnit, DistanceUnit)> {
+    match (u1, u2) {
+        (DistanceUnit::M, other) if is_metric(other) => Some((DistanceUnit::Metre, other)),
+        (DistanceUnit::M, other) if is_imperial(other) => Some((DistanceUnit::Mile, other)),
+        (other, DistanceUnit::M) if is_metric(other) => Some((other, DistanceUnit::Metre)),
+        (other, DistanceUnit::M) if is_imperial(other) => Some((other, DistanceUnit::Mile)),
+        (DistanceUnit::M, DistanceUnit::M) => None, // can't combine two ambiguous
+        _ => Some((u1, u2)),
     }
 }
 
-/// Sum two distances with different units, converting to the appropriate result unit.
-/// Handles the ambiguous M unit by resolving based on context.
-/// Returns None if the combination is invalid.
-pub fn distance_sum(
-    v1: f64,
-    u1: DistanceUnit,
-    v2: f64,
-    u2: DistanceUnit,
-) -> Option<(f64, DistanceUnit)> {
-    // Resolve ambiguous M based on context
-    let (r1, r2) = match (u1, u2) {
-        (DistanceUnit::M, other) if is_metric(other) => (DistanceUnit::Metre, other),
-        (DistanceUnit::M, other) if is_imperial(other) => (DistanceUnit::Mile, other),
-        (other, DistanceUnit::M) if is_metric(other) => (other, DistanceUnit::Metre),
-        (other, DistanceUnit::M) if is_imperial(other) => (other, DistanceUnit::Mile),
-        (DistanceUnit::M, DistanceUnit::M) => return None, // can't combine two ambiguous
-        _ => (u1, u2),
-    };
-
-    // Pick the smallest (most precise) unit as the target
-    let target = if unit_order(r1) <= unit_order(r2) {
-        r1
-    } else {
-        r2
+/// The single distance "5 feet 10 inches" is: the sum of two positive
+/// distances in different units, in metres, keeping the parts written.
+/// `None` when they cannot be summed.
+pub fn compound(first: &DistanceData, second: &DistanceData) -> Option<DistanceData> {
+    let (v1, u1, v2, u2) = (first.value?, first.unit?, second.value?, second.unit?);
+    if u1 == u2 || v1 <= 0.0 || v2 <= 0.0 {
+        return None;
+    }
+    let (r1, r2) = resolve_m(u1, u2)?;
+    let parts = |data: &DistanceData, v: f64, u: DistanceUnit| {
+        if data.components.is_empty() {
+            vec![(v, u)]
+        } else {
+            data.components.clone()
+        }
     };
-
-    let m1 = to_metres(v1, r1);
-    let m2 = to_metres(v2, r2);
-    let result = from_metres(m1 + m2, target);
-    Some((result, target))
+    let mut data = DistanceData::new(r1.to_metres(v1) + r2.to_metres(v2), DistanceUnit::Metre);
+    data.components = parts(first, v1, r1);
+    data.components.extend(parts(second, v2, r2));
+    Some(data)
 }
 
 pub fn resolve(data: &DistanceData) -> Option<DimensionValue> {
-    let unit = data.unit.as_ref()?;
-    let unit_str = unit.as_str().to_string();
+    let unit = Unit::from(data.unit?);
 
     let mv = match (data.value, data.min_value, data.max_value) {
-        (Some(v), _, _) => MeasurementValue::Value {
-            value: v,
-            unit: unit_str,
-        },
+        (Some(v), _, _) => MeasurementValue::Value { value: v, unit },
         (None, Some(from), Some(to)) => MeasurementValue::Interval {
-            from: Some(MeasurementPoint {
-                value: from,
-                unit: unit_str.clone(),
-            }),
-            to: Some(MeasurementPoint {
-                value: to,
-                unit: unit_str,
-            }),
+            from: Some(MeasurementPoint { value: from, unit }),
+            to: Some(MeasurementPoint { value: to, unit }),
         },
         (None, Some(from), None) => MeasurementValue::Interval {
-            from: Some(MeasurementPoint {
-                value: from,
-                unit: unit_str,
-            }),
+            from: Some(MeasurementPoint { value: from, unit }),
             to: None,
         },
         (None, None, Some(to)) => MeasurementValue::Interval {
             from: None,
-            to: Some(MeasurementPoint {
-                value: to,
-                unit: unit_str,
-            }),
+            to: Some(MeasurementPoint { value: to, unit }),
         },
         _ => return None,
     };
@@ -1,5 +1,5 @@
 use crate::pattern::regex;
-use crate::types::{Rule, TokenData};
+use crate::types::{Precision, Rule, TokenData};
 
 use super::{DistanceData, DistanceUnit};
 
@@ -212,10 +212,10 @@ pub fn rules() -> Vec<Rule> {
             name: "about <distance>".to_string(),
             pattern: vec![regex("(米九|1\\.9米)左右")],
             production: Box::new(|_| {
-                Some(TokenData::Distance(DistanceData::new(
-                    1.9,
-                    DistanceUnit::Metre,
-                )))
+                Some(
+                    TokenData::Distance(DistanceData::new(1.9, DistanceUnit::Metre))
+                        .with_precision(Some(Precision::Approximate)),
+                )
             }),
         },
     ]
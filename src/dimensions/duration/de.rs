@@ -1,7 +1,7 @@
 use crate::dimensions::numeral::helpers::{is_natural, numeral_data};
 use crate::dimensions::time_grain::Grain;
 use crate::pattern::{dim, predicate, regex};
-use crate::types::{DimensionKind, Rule, TokenData};
+use crate::types::{DimensionKind, Precision, Rule, TokenData};
 
 use super::DurationData;
 
@@ -52,7 +52,19 @@ pub fn rules() -> Vec<Rule> {
                 regex("ungef(ä|a)hr|zirka|genau|exakt"),
                 dim(DimensionKind::Duration),
             ],
-            production: Box::new(|nodes| Some(nodes[1].token_data.clone())),
+            production: Box::new(|nodes| {
+                Some(
+                    nodes[1]
+                        .token_data
+                        .clone()
+                        .with_precision(Precision::of_qualifier(nodes[0])),
+                )
+            }),
+        },
+        Rule {
+            name: "für <duration>".to_string(),
+            pattern: vec![regex("f(ü|u)r"), dim(DimensionKind::Duration)],
+            production: Box::new(|nodes| super::spanning(&nodes[1].token_data)),
         },
         Rule {
             name: "number,number hours".to_string(),
@@ -1,7 +1,7 @@
 use crate::dimensions::numeral::helpers::{is_natural, numeral_data};
 use crate::dimensions::time_grain::Grain;
 use crate::pattern::{dim, predicate, regex};
-use crate::types::{DimensionKind, PatternItem, Rule, TokenData};
+use crate::types::{DimensionKind, PatternItem, Precision, Rule, TokenData};
 
 use super::DurationData;
 
@@ -276,7 +276,20 @@ pub fn rules() -> Vec<Rule> {
                 regex(r"(about|around|approximately|exactly)"),
                 dim(DimensionKind::Duration),
             ],
-            production: Box::new(|nodes| Some(nodes[1].token_data.clone())),
+            production: Box::new(|nodes| {
+                Some(
+                    nodes[1]
+                        .token_data
+                        .clone()
+                        .with_precision(Precision::of_qualifier(nodes[0])),
+                )
+            }),
+        },
+        // for <duration>: "for 2 hours" lasts the whole duration
+        Rule {
+            name: "for <duration>".to_string(),
+            pattern: vec![regex(r"\bfor\b"), dim(DimensionKind::Duration)],
+            production: Box::new(|nodes| super::spanning(&nodes[1].token_data)),
         },
         // <Integer> and <Integer> quarter(s) (of) hour(s):
         // "one and two quarter hour" → 90min, "two and a quarter hour" → 135min
@@ -407,7 +420,10 @@ mod tests {
     #[test]
     fn test_duration() {
         let rules = all_rules();
-        let options = Options { with_latent: false };
+        let options = Options {
+            with_latent: false,
+            ..Options::default()
+        };
         let context = Context::default();
 
         for (text, expected_val, expected_unit) in &[
@@ -35,6 +35,11 @@ pub fn rules() -> Vec<Rule> {
                 Some(TokenData::Duration(DurationData::new(45, Grain::Minute)))
             }),
         },
+        Rule {
+            name: "durante <duration>".to_string(),
+            pattern: vec![regex("durante"), dim(DimensionKind::Duration)],
+            production: Box::new(|nodes| super::spanning(&nodes[1].token_data)),
+        },
         Rule {
             name: "composite <duration>".to_string(),
             pattern: vec![
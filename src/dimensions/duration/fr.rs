@@ -44,6 +44,11 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("environ"), dim(DimensionKind::Duration)],
             production: Box::new(|nodes| Some(nodes[1].token_data.clone())),
         },
+        Rule {
+            name: "pendant <duration>".to_string(),
+            pattern: vec![regex("pendant|durant"), dim(DimensionKind::Duration)],
+            production: Box::new(|nodes| super::spanning(&nodes[1].token_data)),
+        },
         Rule {
             name: "<integer> + '\"".to_string(),
             pattern: vec![crate::pattern::predicate(is_natural), regex("(['\"])")],
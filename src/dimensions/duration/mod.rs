@@ -24,17 +24,36 @@ pub mod uk;
 pub mod zh;
 
 use crate::dimensions::time_grain::Grain;
-use crate::types::DimensionValue;
+use crate::types::{DimensionValue, Precision, TokenData};
+
+/// How a duration phrase relates to the moment it is said, from its prefix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DurationQualifier {
+    /// Some time up to the end of the duration: "within 2 hours".
+    Bounded,
+    /// Once the duration has passed: "in 2 hours".
+    Delayed,
+    /// Lasting the whole duration: "for 2 hours".
+    Spanning,
+}
 
 #[derive(Debug, Clone)]
 pub struct DurationData {
     pub value: i64,
     pub grain: Grain,
+    pub qualifier: Option<DurationQualifier>,
+    pub precision: Option<Precision>,
 }
 
 impl DurationData {
     pub fn new(value: i64, grain: Grain) -> Self {
-        DurationData { value, grain }
+        DurationData {
+            value,
+            grain,
+            qualifier: None,
+            precision: None,
+        }
     }
 
     /// Convert this duration to a different grain, rounding to nearest integer.
@@ -59,10 +78,77 @@ impl DurationData {
     }
 }
 
+/// The duration in `td` marked as lasting its whole length, for a "for"-like
+/// prefix ("for 2 hours", "für 2 Stunden").
+pub(crate) fn spanning(td: &TokenData) -> Option<TokenData> {
+    match td {
+        TokenData::Duration(d) => Some(TokenData::Duration(DurationData {
+            qualifier: Some(DurationQualifier::Spanning),
+            ..d.clone()
+        })),
+        _ => None,
+    }
+}
+
 pub fn resolve(data: &DurationData) -> DimensionValue {
     DimensionValue::Duration {
         value: data.value,
         grain: data.grain,
         normalized_seconds: data.grain.in_seconds(data.value).unwrap_or(0),
+        iso8601: iso8601(data.value, data.grain),
+        qualifier: data.qualifier,
+    }
+}
+
+/// Format `value` `grain`s as an ISO 8601 duration ("P3D", "PT1H30M").
+/// Calendar grains keep their unit since months and years have no fixed
+/// length; sub-day grains are split into hours, minutes and seconds.
+pub(crate) fn iso8601(value: i64, grain: Grain) -> String {
+    let sign = if value < 0 { "-" } else { "" };
+    let n = value.unsigned_abs();
+    match grain {
+        Grain::Year => format!("{sign}P{n}Y"),
+        Grain::Quarter => format!("{sign}P{}M", n.saturating_mul(3)),
+        Grain::Month => format!("{sign}P{n}M"),
+        Grain::Week => format!("{sign}P{n}W"),
+        Grain::Day => format!("{sign}P{n}D"),
+        Grain::Hour | Grain::Minute | Grain::Second | Grain::NoGrain => {
+            let seconds = match grain {
+                Grain::Hour => n.saturating_mul(3600),
+                Grain::Minute => n.saturating_mul(60),
+                _ => n,
+            };
+            if seconds == 0 {
+                return "PT0S".to_string();
+            }
+            let (h, m, s) = (seconds / 3600, seconds % 3600 / 60, seconds % 60);
+            let mut out = format!("{sign}PT");
+            if h > 0 {
+                out.push_str(&format!("{h}H"));
+            }
+            if m > 0 {
+                out.push_str(&format!("{m}M"));
+            }
+            if s > 0 {
+                out.push_str(&format!("{s}S"));
+            }
+            out
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_iso8601() {
+        assert_eq!(iso8601(90, Grain::Minute), "PT1H30M");
+        assert_eq!(iso8601(3, Grain::Day), "P3D");
+        assert_eq!(iso8601(2, Grain::Quarter), "P6M");
+        assert_eq!(iso8601(3725, Grain::Second), "PT1H2M5S");
+        assert_eq!(iso8601(36, Grain::Hour), "PT36H");
+        assert_eq!(iso8601(0, Grain::Minute), "PT0S");
+        assert_eq!(iso8601(-15, Grain::Minute), "-PT15M");
     }
 }
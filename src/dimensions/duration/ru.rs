@@ -1,7 +1,7 @@
 use crate::dimensions::numeral::helpers::{is_natural, numeral_data};
 use crate::dimensions::time_grain::Grain;
 use crate::pattern::{dim, predicate, regex};
-use crate::types::{DimensionKind, Rule, TokenData};
+use crate::types::{DimensionKind, Precision, Rule, TokenData};
 
 use super::DurationData;
 
@@ -83,7 +83,14 @@ pub fn rules() -> Vec<Rule> {
                 regex("(где-то|приблизительно|примерно|ровно)"),
                 dim(DimensionKind::Duration),
             ],
-            production: Box::new(|nodes| Some(nodes[1].token_data.clone())),
+            production: Box::new(|nodes| {
+                Some(
+                    nodes[1]
+                        .token_data
+                        .clone()
+                        .with_precision(Precision::of_qualifier(nodes[0])),
+                )
+            }),
         },
         Rule {
             name: "a <unit-of-duration>".to_string(),
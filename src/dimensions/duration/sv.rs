@@ -1,7 +1,7 @@
 use crate::dimensions::numeral::helpers::numeral_data;
 use crate::dimensions::time_grain::Grain;
 use crate::pattern::{dim, predicate, regex};
-use crate::types::{DimensionKind, Rule, TokenData};
+use crate::types::{DimensionKind, Precision, Rule, TokenData};
 
 use super::DurationData;
 
@@ -82,12 +82,24 @@ pub fn rules() -> Vec<Rule> {
                 regex("(omkring|cirka|ca\\.?|c:a|runt|ungefär)"),
                 dim(DimensionKind::Duration),
             ],
-            production: Box::new(|n| Some(n[1].token_data.clone())),
+            production: Box::new(|n| {
+                Some(
+                    n[1].token_data
+                        .clone()
+                        .with_precision(Precision::of_qualifier(n[0])),
+                )
+            }),
         },
         Rule {
             name: "exactly <duration>".to_string(),
             pattern: vec![regex("(precis|exakt)"), dim(DimensionKind::Duration)],
-            production: Box::new(|n| Some(n[1].token_data.clone())),
+            production: Box::new(|n| {
+                Some(
+                    n[1].token_data
+                        .clone()
+                        .with_precision(Precision::of_qualifier(n[0])),
+                )
+            }),
         },
     ]
 }
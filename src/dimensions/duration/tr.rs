@@ -1,7 +1,7 @@
 use crate::dimensions::numeral::helpers::numeral_data;
 use crate::dimensions::time_grain::Grain;
 use crate::pattern::{dim, predicate, regex};
-use crate::types::{DimensionKind, Rule, TokenData};
+use crate::types::{DimensionKind, Precision, Rule, TokenData};
 
 use super::DurationData;
 
@@ -91,12 +91,26 @@ pub fn rules() -> Vec<Rule> {
                 regex("(yaklaşık|tam(\\solarak)?)"),
                 dim(DimensionKind::Duration),
             ],
-            production: Box::new(|nodes| Some(nodes[1].token_data.clone())),
+            production: Box::new(|nodes| {
+                Some(
+                    nodes[1]
+                        .token_data
+                        .clone()
+                        .with_precision(Precision::of_qualifier(nodes[0])),
+                )
+            }),
         },
         Rule {
             name: "<duration> about|exactly 2".to_string(),
             pattern: vec![dim(DimensionKind::Duration), regex("gibi|civarında")],
-            production: Box::new(|nodes| Some(nodes[0].token_data.clone())),
+            production: Box::new(|nodes| {
+                Some(
+                    nodes[0]
+                        .token_data
+                        .clone()
+                        .with_precision(Precision::of_qualifier(nodes[1])),
+                )
+            }),
         },
     ]
 }
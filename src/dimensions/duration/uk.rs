@@ -1,7 +1,7 @@
 use crate::dimensions::numeral::helpers::{is_natural, numeral_data};
 use crate::dimensions::time_grain::Grain;
 use crate::pattern::{dim, predicate, regex};
-use crate::types::{DimensionKind, Rule, TokenData};
+use crate::types::{DimensionKind, Precision, Rule, TokenData};
 
 use super::DurationData;
 
@@ -95,12 +95,26 @@ pub fn rules() -> Vec<Rule> {
         Rule {
             name: "about <duration>".to_string(),
             pattern: vec![regex("близько"), dim(DimensionKind::Duration)],
-            production: Box::new(|nodes| Some(nodes[1].token_data.clone())),
+            production: Box::new(|nodes| {
+                Some(
+                    nodes[1]
+                        .token_data
+                        .clone()
+                        .with_precision(Precision::of_qualifier(nodes[0])),
+                )
+            }),
         },
         Rule {
             name: "exactly <duration>".to_string(),
             pattern: vec![regex("рівно"), dim(DimensionKind::Duration)],
-            production: Box::new(|nodes| Some(nodes[1].token_data.clone())),
+            production: Box::new(|nodes| {
+                Some(
+                    nodes[1]
+                        .token_data
+                        .clone()
+                        .with_precision(Precision::of_qualifier(nodes[0])),
+                )
+            }),
         },
         Rule {
             name: "half a <time-grain>".to_string(),
@@ -78,7 +78,10 @@ mod tests {
     #[test]
     fn test_email() {
         let rules = rules();
-        let options = Options { with_latent: false };
+        let options = Options {
+            with_latent: false,
+            ..Options::default()
+        };
         let context = Context::default();
 
         for email in &[
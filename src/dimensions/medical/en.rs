@@ -0,0 +1,288 @@
+use crate::dimensions::numeral::helpers::numeral_data;
+use crate::dimensions::rate::RatePer;
+use crate::dimensions::temperature::{TemperatureData, TemperatureUnit};
+use crate::dimensions::time_grain::Grain;
+use crate::pattern::{dim, predicate, regex};
+use crate::types::{DimensionKind, Rule, TokenData};
+
+use super::{BloodPressureData, DosageData, DoseUnit};
+
+/// Matches Dosage tokens that don't have a frequency yet.
+fn is_dose_without_frequency() -> crate::types::PatternItem {
+    predicate(|td| matches!(td, TokenData::Dosage(data) if data.frequency.is_none()))
+}
+
+fn dosage_data(token_data: &TokenData) -> Option<&DosageData> {
+    match token_data {
+        TokenData::Dosage(data) => Some(data),
+        _ => None,
+    }
+}
+
+pub fn blood_pressure_rules() -> Vec<Rule> {
+    vec![
+        // "120/80", "120 over 80"
+        Rule {
+            name: "<number> over <number> (blood pressure)".to_string(),
+            pattern: vec![
+                dim(DimensionKind::Numeral),
+                regex(r"over|/"),
+                dim(DimensionKind::Numeral),
+            ],
+            production: Box::new(|nodes| {
+                let systolic = numeral_data(&nodes[0].token_data)?.value;
+                let diastolic = numeral_data(&nodes[2].token_data)?.value;
+                let data = BloodPressureData::new(systolic, diastolic)?;
+                Some(TokenData::BloodPressure(data))
+            }),
+        },
+        // "BP 120/80", "blood pressure of 130 over 85"
+        Rule {
+            name: "bp <blood pressure>".to_string(),
+            pattern: vec![
+                regex(r"(bp|blood pressure)(\s+(of|is|was))?:?"),
+                dim(DimensionKind::BloodPressure),
+            ],
+            production: Box::new(|nodes| Some(nodes[1].token_data.clone())),
+        },
+        // "120/80 mmHg"
+        Rule {
+            name: "<blood pressure> mmhg".to_string(),
+            pattern: vec![dim(DimensionKind::BloodPressure), regex(r"mm\s?hg")],
+            production: Box::new(|nodes| Some(nodes[0].token_data.clone())),
+        },
+    ]
+}
+
+pub fn dosage_rules() -> Vec<Rule> {
+    vec![
+        // "5 mg", "250 mcg", "10 ml", "2 tablets", "2 puffs"
+        Rule {
+            name: "<number> <dose unit>".to_string(),
+            pattern: vec![
+                dim(DimensionKind::Numeral),
+                regex(
+                    r"(mcg|µg|micrograms?|mg|milligrams?|g|grams?|ml|millilit(?:re|er)s?|iu|units?|tablets?|tabs?|pills?|capsules?|caps?|drops?|puffs?)\b",
+                ),
+            ],
+            production: Box::new(|nodes| {
+                let value = numeral_data(&nodes[0].token_data)?.value;
+                if value <= 0.0 {
+                    return None;
+                }
+                let unit = match &nodes[1].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?.to_lowercase(),
+                    _ => return None,
+                };
+                let unit = match unit.trim_end_matches('s') {
+                    "mcg" | "µg" | "microgram" => DoseUnit::Microgram,
+                    "mg" | "milligram" => DoseUnit::Milligram,
+                    "g" | "gram" => DoseUnit::Gram,
+                    "ml" | "millilitre" | "milliliter" => DoseUnit::Millilitre,
+                    "iu" | "unit" => DoseUnit::Unit,
+                    "tablet" | "tab" | "pill" => DoseUnit::Tablet,
+                    "capsule" | "cap" => DoseUnit::Capsule,
+                    "drop" => DoseUnit::Drop,
+                    "puff" => DoseUnit::Puff,
+                    _ => return None,
+                };
+                Some(TokenData::Dosage(DosageData::new(value, unit)))
+            }),
+        },
+        // "5 mg twice daily", "2 puffs every 4 hours"
+        Rule {
+            name: "<dose> <rate>".to_string(),
+            pattern: vec![is_dose_without_frequency(), dim(DimensionKind::Rate)],
+            production: Box::new(|nodes| {
+                let dose = dosage_data(&nodes[0].token_data)?;
+                let TokenData::Rate(rate) = &nodes[1].token_data else {
+                    return None;
+                };
+                if rate.unit.is_some() {
+                    return None;
+                }
+                Some(TokenData::Dosage(
+                    dose.clone().with_frequency(rate.count, rate.per),
+                ))
+            }),
+        },
+        // "5 mg bid", "1 tab q6h"
+        Rule {
+            name: "<dose> qd|bid|tid|qid|q<n>h".to_string(),
+            pattern: vec![
+                is_dose_without_frequency(),
+                regex(r"(od|q\.?d|b\.?i\.?d|t\.?i\.?d|q\.?i\.?d|q(\d{1,2})h)\b\.?"),
+            ],
+            production: Box::new(|nodes| {
+                let dose = dosage_data(&nodes[0].token_data)?;
+                let (code, hours) = match &nodes[1].token_data {
+                    TokenData::RegexMatch(m) => {
+                        (m.group(1)?.to_lowercase().replace('.', ""), m.group(2))
+                    }
+                    _ => return None,
+                };
+                let per_day = |count| {
+                    (
+                        count,
+                        RatePer::Time {
+                            value: 1,
+                            grain: Grain::Day,
+                        },
+                    )
+                };
+                let (count, per) = match (code.as_str(), hours) {
+                    (_, Some(hours)) => {
+                        let value: i64 = hours.parse().ok().filter(|h| *h > 0)?;
+                        (
+                            1.0,
+                            RatePer::Time {
+                                value,
+                                grain: Grain::Hour,
+                            },
+                        )
+                    }
+                    ("od" | "qd", _) => per_day(1.0),
+                    ("bid", _) => per_day(2.0),
+                    ("tid", _) => per_day(3.0),
+                    ("qid", _) => per_day(4.0),
+                    _ => return None,
+                };
+                Some(TokenData::Dosage(dose.clone().with_frequency(count, per)))
+            }),
+        },
+    ]
+}
+
+/// Clinical shorthand for body temperature: "temp 38.5", "T: 101.2".
+/// Values in the range of a body temperature in Celsius read as Celsius,
+/// and in Fahrenheit as Fahrenheit.
+pub fn temperature_rules() -> Vec<Rule> {
+    vec![Rule {
+        name: "temp <number> (clinical)".to_string(),
+        pattern: vec![
+            regex(r"(temp(erature)?|t:)(\s+(of|is|was))?:?"),
+            dim(DimensionKind::Numeral),
+        ],
+        production: Box::new(|nodes| {
+            let value = numeral_data(&nodes[1].token_data)?.value;
+            let unit = if (30.0..=45.0).contains(&value) {
+                TemperatureUnit::Celsius
+            } else if (86.0..=113.0).contains(&value) {
+                TemperatureUnit::Fahrenheit
+            } else {
+                return None;
+            };
+            Some(TokenData::Temperature(
+                TemperatureData::new(value).with_unit(unit),
+            ))
+        }),
+    }]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dimensions::rate::RatePer;
+    use crate::types::{DimensionKind, DimensionValue, MeasurementValue};
+    use crate::{DoseFrequency, DoseUnit, Grain};
+
+    fn first_value(text: &str, dim: DimensionKind) -> Option<DimensionValue> {
+        crate::parse_en(text, &[dim])
+            .into_iter()
+            .next()
+            .map(|e| e.value)
+    }
+
+    #[test]
+    fn test_blood_pressure() {
+        for text in ["120/80", "BP 120 over 80", "blood pressure of 120/80 mmHg"] {
+            assert_eq!(
+                first_value(text, DimensionKind::BloodPressure),
+                Some(DimensionValue::BloodPressure {
+                    systolic: 120,
+                    diastolic: 80
+                }),
+                "{text:?}"
+            );
+        }
+        for text in ["80/120", "3/4", "1200 over 80"] {
+            assert_eq!(
+                first_value(text, DimensionKind::BloodPressure),
+                None,
+                "{text:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_dosage() {
+        let per_day = |count| DoseFrequency {
+            count,
+            per: RatePer::Time {
+                value: 1,
+                grain: Grain::Day,
+            },
+        };
+        let every_hours = |value| DoseFrequency {
+            count: 1.0,
+            per: RatePer::Time {
+                value,
+                grain: Grain::Hour,
+            },
+        };
+        for (text, value, unit, frequency) in [
+            ("5 mg", 5.0, DoseUnit::Milligram, None),
+            (
+                "5 mg twice daily",
+                5.0,
+                DoseUnit::Milligram,
+                Some(per_day(2.0)),
+            ),
+            (
+                "250 mcg bid",
+                250.0,
+                DoseUnit::Microgram,
+                Some(per_day(2.0)),
+            ),
+            (
+                "2 puffs every 4 hours",
+                2.0,
+                DoseUnit::Puff,
+                Some(every_hours(4)),
+            ),
+            ("1 tab q6h", 1.0, DoseUnit::Tablet, Some(every_hours(6))),
+            (
+                "10 ml three times a day",
+                10.0,
+                DoseUnit::Millilitre,
+                Some(per_day(3.0)),
+            ),
+        ] {
+            assert_eq!(
+                first_value(text, DimensionKind::Dosage),
+                Some(DimensionValue::Dosage {
+                    value,
+                    unit,
+                    frequency
+                }),
+                "{text:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_clinical_temperature() {
+        for (text, expected, unit) in [
+            ("temp 38.5", 38.5, "celsius"),
+            ("T: 101.2", 101.2, "fahrenheit"),
+            ("temperature was 37", 37.0, "celsius"),
+        ] {
+            match first_value(text, DimensionKind::Temperature) {
+                Some(DimensionValue::Temperature(MeasurementValue::Value { value, unit: u })) => {
+                    assert!((value - expected).abs() < 1e-9 && u == unit, "{text:?}")
+                }
+                other => panic!("Expected a temperature for {text:?}, got {other:?}"),
+            }
+        }
+        assert_eq!(first_value("temp 12", DimensionKind::Temperature), None);
+    }
+}
@@ -0,0 +1,98 @@
+//! Clinical-note dimensions, behind the `medical` feature: blood pressure
+//! readings, medication doses, and shorthand temperatures like "temp 38.5".
+
+pub mod en;
+
+use crate::dimensions::rate::RatePer;
+use crate::types::DimensionValue;
+
+#[derive(Debug, Clone)]
+pub struct BloodPressureData {
+    pub systolic: u32,
+    pub diastolic: u32,
+}
+
+impl BloodPressureData {
+    /// A reading, if it is physiologically plausible.
+    pub fn new(systolic: f64, diastolic: f64) -> Option<Self> {
+        let whole = |v: f64| (v.fract() == 0.0).then_some(v as u32);
+        let (systolic, diastolic) = (whole(systolic)?, whole(diastolic)?);
+        let plausible = (50..=300).contains(&systolic)
+            && (20..=200).contains(&diastolic)
+            && systolic > diastolic;
+        plausible.then_some(BloodPressureData {
+            systolic,
+            diastolic,
+        })
+    }
+}
+
+/// The unit of a medication dose.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DoseUnit {
+    /// Micrograms.
+    Microgram,
+    /// Milligrams.
+    Milligram,
+    /// Grams.
+    Gram,
+    /// Millilitres.
+    Millilitre,
+    /// International units.
+    Unit,
+    /// Tablets or pills.
+    Tablet,
+    /// Capsules.
+    Capsule,
+    /// Drops.
+    Drop,
+    /// Inhaler puffs.
+    Puff,
+}
+
+/// How often a dose is taken: 2 per day for "twice daily" or "bid".
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize)]
+pub struct DoseFrequency {
+    /// Doses per `per` (2 in "twice daily").
+    pub count: f64,
+    /// The span the doses are spread over.
+    pub per: RatePer,
+}
+
+#[derive(Debug, Clone)]
+pub struct DosageData {
+    pub value: f64,
+    pub unit: DoseUnit,
+    pub frequency: Option<DoseFrequency>,
+}
+
+impl DosageData {
+    pub fn new(value: f64, unit: DoseUnit) -> Self {
+        DosageData {
+            value,
+            unit,
+            frequency: None,
+        }
+    }
+
+    pub fn with_frequency(mut self, count: f64, per: RatePer) -> Self {
+        self.frequency = Some(DoseFrequency { count, per });
+        self
+    }
+}
+
+pub fn resolve_blood_pressure(data: &BloodPressureData) -> DimensionValue {
+    DimensionValue::BloodPressure {
+        systolic: data.systolic,
+        diastolic: data.diastolic,
+    }
+}
+
+pub fn resolve_dosage(data: &DosageData) -> DimensionValue {
+    DimensionValue::Dosage {
+        value: data.value,
+        unit: data.unit,
+        frequency: data.frequency,
+    }
+}
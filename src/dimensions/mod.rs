@@ -1,12 +1,19 @@
+pub mod age;
 pub mod amount_of_money;
+pub mod coordinate;
 pub mod credit_card_number;
 pub mod distance;
 pub mod duration;
 pub mod email;
+#[cfg(feature = "medical")]
+pub mod medical;
+pub mod network_address;
 pub mod numeral;
 pub mod ordinal;
+pub mod percentage;
 pub mod phone_number;
 pub mod quantity;
+pub mod rate;
 pub mod temperature;
 pub mod time;
 pub mod time_grain;
@@ -22,7 +29,19 @@ pub fn dimension_dependencies(dim: DimensionKind) -> Vec<DimensionKind> {
         DimensionKind::Distance => vec![DimensionKind::Numeral],
         DimensionKind::Volume => vec![DimensionKind::Numeral],
         DimensionKind::Quantity => vec![DimensionKind::Numeral],
+        DimensionKind::Age => vec![DimensionKind::Numeral],
         DimensionKind::AmountOfMoney => vec![DimensionKind::Numeral],
+        DimensionKind::Percentage => vec![DimensionKind::Numeral],
+        #[cfg(feature = "medical")]
+        DimensionKind::BloodPressure => vec![DimensionKind::Numeral],
+        #[cfg(feature = "medical")]
+        DimensionKind::Dosage => vec![DimensionKind::Numeral, DimensionKind::Rate],
+        DimensionKind::Rate => vec![
+            DimensionKind::Numeral,
+            DimensionKind::TimeGrain,
+            DimensionKind::Duration,
+            DimensionKind::Distance,
+        ],
         DimensionKind::Duration => vec![DimensionKind::Numeral, DimensionKind::TimeGrain],
         DimensionKind::Time => vec![
             DimensionKind::Numeral,
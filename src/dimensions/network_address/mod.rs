@@ -0,0 +1,42 @@
+pub mod rules;
+
+use crate::types::DimensionValue;
+
+#[derive(Debug, Clone)]
+pub struct NetworkAddressData {
+    pub value: String,
+    pub kind: NetworkAddressKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NetworkAddressKind {
+    Ipv4,
+    Ipv6,
+    Mac,
+}
+
+impl NetworkAddressKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NetworkAddressKind::Ipv4 => "ipv4",
+            NetworkAddressKind::Ipv6 => "ipv6",
+            NetworkAddressKind::Mac => "mac",
+        }
+    }
+}
+
+impl NetworkAddressData {
+    pub fn new(value: &str, kind: NetworkAddressKind) -> Self {
+        NetworkAddressData {
+            value: value.to_string(),
+            kind,
+        }
+    }
+}
+
+pub fn resolve(data: &NetworkAddressData) -> DimensionValue {
+    DimensionValue::NetworkAddress {
+        value: data.value.clone(),
+        kind: data.kind.as_str().to_string(),
+    }
+}
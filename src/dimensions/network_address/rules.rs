@@ -0,0 +1,145 @@
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use crate::pattern::regex;
+use crate::types::{Rule, TokenData};
+
+use super::{NetworkAddressData, NetworkAddressKind};
+
+fn matched_text(token_data: &TokenData) -> Option<&str> {
+    match token_data {
+        TokenData::RegexMatch(m) => m.group(1),
+        _ => None,
+    }
+}
+
+/// Canonical lowercase, colon-separated form of a MAC address written as
+/// "AA:BB:CC:DD:EE:FF", "aa-bb-cc-dd-ee-ff" or "aabb.ccdd.eeff".
+fn canonical_mac(text: &str) -> Option<String> {
+    let hex: Vec<char> = text
+        .chars()
+        .filter(|c| c.is_ascii_hexdigit())
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+    if hex.len() != 12 {
+        return None;
+    }
+    let octets: Vec<String> = hex.chunks(2).map(|pair| pair.iter().collect()).collect();
+    Some(octets.join(":"))
+}
+
+pub fn rules() -> Vec<Rule> {
+    vec![
+        // Dotted-quad IPv4, validated by std (no octet above 255, no leading zeros).
+        Rule {
+            name: "ipv4 address".to_string(),
+            pattern: vec![regex(r"\b(\d{1,3}(?:\.\d{1,3}){3})\b")],
+            production: Box::new(|nodes| {
+                let addr: Ipv4Addr = matched_text(&nodes[0].token_data)?.parse().ok()?;
+                Some(TokenData::NetworkAddress(NetworkAddressData::new(
+                    &addr.to_string(),
+                    NetworkAddressKind::Ipv4,
+                )))
+            }),
+        },
+        // IPv6, full or "::"-compressed, optionally ending in an embedded IPv4.
+        // Output uses the RFC 5952 canonical (compressed, lowercase) form.
+        Rule {
+            name: "ipv6 address".to_string(),
+            pattern: vec![regex(
+                r"(?i)((?:[0-9a-f]{0,4}:){2,7}(?:(?:\d{1,3}\.){3}\d{1,3}|[0-9a-f]{1,4})?)",
+            )],
+            production: Box::new(|nodes| {
+                let text = matched_text(&nodes[0].token_data)?;
+                if !text.chars().any(|c| c.is_ascii_hexdigit()) {
+                    return None;
+                }
+                let addr: Ipv6Addr = text.parse().ok()?;
+                Some(TokenData::NetworkAddress(NetworkAddressData::new(
+                    &addr.to_string(),
+                    NetworkAddressKind::Ipv6,
+                )))
+            }),
+        },
+        // MAC address in colon, hyphen or Cisco dotted notation.
+        Rule {
+            name: "mac address".to_string(),
+            pattern: vec![regex(
+                r"(?i)\b([0-9a-f]{2}(?::[0-9a-f]{2}){5}|[0-9a-f]{2}(?:-[0-9a-f]{2}){5}|[0-9a-f]{4}\.[0-9a-f]{4}\.[0-9a-f]{4})\b",
+            )],
+            production: Box::new(|nodes| {
+                let mac = canonical_mac(matched_text(&nodes[0].token_data)?)?;
+                Some(TokenData::NetworkAddress(NetworkAddressData::new(
+                    &mac,
+                    NetworkAddressKind::Mac,
+                )))
+            }),
+        },
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine;
+    use crate::resolve::{Context, Options};
+    use crate::types::{DimensionKind, DimensionValue};
+
+    fn parse_addresses(text: &str) -> Vec<DimensionValue> {
+        engine::parse_and_resolve(
+            text,
+            &rules(),
+            &Context::default(),
+            &Options::default(),
+            &[DimensionKind::NetworkAddress],
+        )
+        .into_iter()
+        .map(|e| e.value)
+        .collect()
+    }
+
+    #[test]
+    fn test_network_addresses() {
+        for (text, expected_value, expected_kind) in &[
+            ("ping 192.168.0.1 now", "192.168.0.1", "ipv4"),
+            (
+                "2001:0DB8:0000:0000:0000:0000:0000:0001",
+                "2001:db8::1",
+                "ipv6",
+            ),
+            (
+                "connect to fe80::1ff:fe23:4567:890a",
+                "fe80::1ff:fe23:4567:890a",
+                "ipv6",
+            ),
+            ("loopback is ::1", "::1", "ipv6"),
+            ("::ffff:192.0.2.128", "::ffff:192.0.2.128", "ipv6"),
+            ("mac 00:1A:2B:3C:4D:5E", "00:1a:2b:3c:4d:5e", "mac"),
+            ("00-1a-2b-3c-4d-5e", "00:1a:2b:3c:4d:5e", "mac"),
+            ("001a.2b3c.4d5e", "00:1a:2b:3c:4d:5e", "mac"),
+        ] {
+            let values = parse_addresses(text);
+            let found = values.iter().any(|v| {
+                matches!(v, DimensionValue::NetworkAddress { value, kind }
+                    if value == expected_value && kind == expected_kind)
+            });
+            assert!(
+                found,
+                "Expected {} '{}' for '{}', got: {:?}",
+                expected_kind, expected_value, text, values
+            );
+        }
+    }
+
+    #[test]
+    fn test_network_address_negatives() {
+        for text in &["256.1.1.1", "1.2.3", "10:30:00", "meet at 10:30", "v1.2.3"] {
+            let values = parse_addresses(text);
+            assert!(
+                values.is_empty(),
+                "Expected nothing for '{}', got: {:?}",
+                text,
+                values
+            );
+        }
+    }
+}
@@ -259,7 +259,7 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "numbers suffixes (K, M, G)".to_string(),
-            pattern: vec![predicate(is_positive), regex("([kmg])")],
+            pattern: vec![predicate(is_positive), regex("([kmg])\\b")],
             production: Box::new(|nodes| {
                 let v = numeral_data(&nodes[0].token_data)?.value;
                 let s = match &nodes[1].token_data {
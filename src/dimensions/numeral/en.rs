@@ -260,6 +260,22 @@ pub fn rules() -> Vec<Rule> {
                 Some(TokenData::Numeral(NumeralData::new(num / den)))
             }),
         },
+        // Scientific notation: 3.2e6, 1E-3, 6.02e+23
+        Rule {
+            name: "scientific notation".to_string(),
+            pattern: vec![regex(r#"(\d*\.?\d+)e([+-]?\d{1,3})\b"#)],
+            production: Box::new(|nodes| {
+                let text = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(0)?,
+                    _ => return None,
+                };
+                let val: f64 = text.to_lowercase().parse().ok()?;
+                if !val.is_finite() {
+                    return None;
+                }
+                Some(TokenData::Numeral(NumeralData::new(val)))
+            }),
+        },
         // Comma-separated numbers (with optional decimal): 1,000 or 1,000,000.5
         Rule {
             name: "number with commas".to_string(),
@@ -274,10 +290,10 @@ pub fn rules() -> Vec<Rule> {
                 Some(TokenData::Numeral(NumeralData::new(val)))
             }),
         },
-        // Number suffixes: 100K, 1.2M, .0012G
+        // Number suffixes: 100K, 1.2M, .0012G, 2bn
         Rule {
             name: "number suffixes (K, M, G)".to_string(),
-            pattern: vec![dim(DimensionKind::Numeral), regex(r"(k|m|g|b)\b")],
+            pattern: vec![dim(DimensionKind::Numeral), regex(r"(k|mn|m|bn|g|b)\b")],
             production: Box::new(|nodes| {
                 let v = numeral_data(&nodes[0].token_data)?.value;
                 let s = match &nodes[1].token_data {
@@ -286,8 +302,8 @@ pub fn rules() -> Vec<Rule> {
                 };
                 let mult = match s.as_str() {
                     "k" => 1_000.0,
-                    "m" => 1_000_000.0,
-                    "g" | "b" => 1_000_000_000.0,
+                    "m" | "mn" => 1_000_000.0,
+                    "g" | "b" | "bn" => 1_000_000_000.0,
                     _ => return None,
                 };
                 Some(TokenData::Numeral(NumeralData::new(v * mult)))
@@ -302,12 +318,10 @@ pub fn rules() -> Vec<Rule> {
                 Some(TokenData::Numeral(NumeralData::new(-data.value)))
             }),
         },
-        // === Powers of ten (unified): hundred, thousand, lakh, million, crore, billion, trillion ===
+        // === Powers of ten: hundred, thousand, million, billion, trillion ===
         Rule {
             name: "powers of tens".to_string(),
-            pattern: vec![regex(
-                r#"(hundred|thousand|l(ac|(a?kh)?)|million|((k|c)r(ore)?|koti)|billion|trillion)s?"#,
-            )],
+            pattern: vec![regex(r#"(hundred|thousand|million|billion|trillion)s?"#)],
             production: Box::new(|nodes| {
                 let text = match &nodes[0].token_data {
                     TokenData::RegexMatch(m) => m.group(1)?,
@@ -316,9 +330,7 @@ pub fn rules() -> Vec<Rule> {
                 let grain = match text.to_lowercase().as_str() {
                     "hundred" => 2,
                     "thousand" => 3,
-                    "lakh" | "lkh" | "l" | "lac" => 5,
                     "million" => 6,
-                    "cr" | "crore" | "krore" | "kr" | "koti" => 7,
                     "billion" => 9,
                     "trillion" => 12,
                     _ => return None,
@@ -467,8 +479,37 @@ pub fn rules() -> Vec<Rule> {
     ]
 }
 
+/// Indian numbering powers of ten ("30 lakh", "4 crore"), only loaded for
+/// en_IN and for EN without a region, where they cannot be misread.
+pub fn in_rules() -> Vec<Rule> {
+    vec![Rule {
+        name: "powers of tens (lakh, crore)".to_string(),
+        pattern: vec![regex(r#"(l(ac|(a?kh)?)|((k|c)r(ore)?|koti))s?"#)],
+        production: Box::new(|nodes| {
+            let text = match &nodes[0].token_data {
+                TokenData::RegexMatch(m) => m.group(1)?,
+                _ => return None,
+            };
+            let grain = match text.to_lowercase().as_str() {
+                "lakh" | "lkh" | "l" | "lac" => 5,
+                "cr" | "crore" | "krore" | "kr" | "koti" => 7,
+                _ => return None,
+            };
+            let value = 10.0_f64.powi(grain);
+            Some(TokenData::Numeral(
+                NumeralData::new(value)
+                    .with_grain(grain as u8)
+                    .with_multipliable(true),
+            ))
+        }),
+    }]
+}
+
 fn is_common_rule_name(name: &str) -> bool {
-    matches!(name, "integer (numeric)" | "fractional number")
+    matches!(
+        name,
+        "integer (numeric)" | "fractional number" | "scientific notation"
+    )
 }
 
 pub fn common_rules() -> Vec<Rule> {
@@ -538,6 +579,7 @@ mod tests {
             reference_time: Utc::now(),
             locale: crate::locale::Locale::default(),
             timezone_offset_minutes: 0,
+            ..Context::default()
         };
         let mut corpus = Corpus::new(context);
 
@@ -564,7 +606,10 @@ mod tests {
     #[test]
     fn test_thirty_three() {
         let rules = build_rules();
-        let options = Options { with_latent: false };
+        let options = Options {
+            with_latent: false,
+            ..Options::default()
+        };
         let context = Context::default();
         let entities = engine::parse_and_resolve(
             "thirty three",
@@ -582,7 +627,10 @@ mod tests {
     #[test]
     fn test_numeric_integers() {
         let rules = build_rules();
-        let options = Options { with_latent: false };
+        let options = Options {
+            with_latent: false,
+            ..Options::default()
+        };
         let context = Context::default();
 
         let entities =
@@ -596,7 +644,10 @@ mod tests {
     #[test]
     fn test_100k() {
         let rules = build_rules();
-        let options = Options { with_latent: false };
+        let options = Options {
+            with_latent: false,
+            ..Options::default()
+        };
         let context = Context::default();
 
         let entities = engine::parse_and_resolve(
@@ -615,7 +666,10 @@ mod tests {
     #[test]
     fn test_five_hundred() {
         let rules = build_rules();
-        let options = Options { with_latent: false };
+        let options = Options {
+            with_latent: false,
+            ..Options::default()
+        };
         let context = Context::default();
 
         let entities = engine::parse_and_resolve(
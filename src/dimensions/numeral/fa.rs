@@ -4,22 +4,30 @@ use crate::types::{DimensionKind, Rule, TokenData};
 
 use super::NumeralData;
 
+/// Persian ("۵") and Arabic-Indic ("٥") digits, which Persian text mixes,
+/// as ASCII.
 fn persian_digit_to_ascii(c: char) -> char {
     match c {
-        '۰' => '0',
-        '۱' => '1',
-        '۲' => '2',
-        '۳' => '3',
-        '۴' => '4',
-        '۵' => '5',
-        '۶' => '6',
-        '۷' => '7',
-        '۸' => '8',
-        '۹' => '9',
+        '۰' | '٠' => '0',
+        '۱' | '١' => '1',
+        '۲' | '٢' => '2',
+        '۳' | '٣' => '3',
+        '۴' | '٤' => '4',
+        '۵' | '٥' => '5',
+        '۶' | '٦' => '6',
+        '۷' | '٧' => '7',
+        '۸' | '٨' => '8',
+        '۹' | '٩' => '9',
         _ => c,
     }
 }
 
+/// `s` with its Persian and Arabic-Indic digits made ASCII, for the other
+/// Persian dimensions that read digits straight from a match.
+pub(crate) fn ascii_digits(s: &str) -> String {
+    s.chars().map(persian_digit_to_ascii).collect()
+}
+
 fn zero_to_nineteen(s: &str) -> Option<f64> {
     match s {
         "صفر" => Some(0.0),
@@ -84,13 +92,29 @@ pub fn rules() -> Vec<Rule> {
     vec![
         Rule {
             name: "Persian integer numeric".to_string(),
-            pattern: vec![regex("([۰-۹]{1,18})")],
+            pattern: vec![regex("([۰-۹٠-٩]{1,18})")],
+            production: Box::new(|nodes| {
+                let s = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?,
+                    _ => return None,
+                };
+                Some(TokenData::Numeral(NumeralData::new(ascii_digits(s).parse().ok()?)))
+            }),
+        },
+        // "۱۲٬۵۰۰", "۲٫۵": the Persian separators, or a comma and point
+        Rule {
+            name: "Persian numeric with separators".to_string(),
+            pattern: vec![regex("([۰-۹٠-٩]+(?:[٬,][۰-۹٠-٩]{3})+(?:[٫.][۰-۹٠-٩]+)?|[۰-۹٠-٩]+[٫.][۰-۹٠-٩]+)")],
             production: Box::new(|nodes| {
                 let s = match &nodes[0].token_data {
                     TokenData::RegexMatch(m) => m.group(1)?,
                     _ => return None,
                 };
-                let ascii: String = s.chars().map(persian_digit_to_ascii).collect();
+                let ascii: String = ascii_digits(s)
+                    .chars()
+                    .filter(|c| !matches!(c, '٬' | ','))
+                    .map(|c| if c == '٫' { '.' } else { c })
+                    .collect();
                 Some(TokenData::Numeral(NumeralData::new(ascii.parse().ok()?)))
             }),
         },
@@ -1,3 +1,4 @@
+use crate::locale::Region;
 use crate::pattern::{dim, predicate, regex};
 use crate::types::{DimensionKind, Rule, TokenData};
 
@@ -213,7 +214,7 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "numbers suffixes (K, M, G)".to_string(),
-            pattern: vec![dim(DimensionKind::Numeral), regex("([kmg])")],
+            pattern: vec![dim(DimensionKind::Numeral), regex("([kmg])\\b")],
             production: Box::new(|nodes| {
                 let v = numeral_data(&nodes[0].token_data)?.value;
                 let s = match &nodes[1].token_data {
@@ -281,3 +282,57 @@ pub fn rules() -> Vec<Rule> {
         },
     ]
 }
+
+/// Region overlays for French: "septante" and "nonante" in Belgium and
+/// Switzerland (FR-BE, FR-CH), and "huitante" in Switzerland.
+pub fn locale_rules(region: Region) -> Vec<Rule> {
+    let words = match region {
+        Region::BE => "(septante|nonante)",
+        Region::CH => "(septante|huitante|nonante)",
+        _ => return Vec::new(),
+    };
+    vec![
+        Rule {
+            name: "number (70, 80, 90)".to_string(),
+            pattern: vec![regex(words)],
+            production: Box::new(|nodes| {
+                let text = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?,
+                    _ => return None,
+                };
+                let v = match text.to_lowercase().as_str() {
+                    "septante" => 70.0,
+                    "huitante" => 80.0,
+                    "nonante" => 90.0,
+                    _ => return None,
+                };
+                Some(TokenData::Numeral(NumeralData::new(v)))
+            }),
+        },
+        Rule {
+            name: "numbers 71 81 91".to_string(),
+            pattern: vec![
+                predicate(one_of(&[70.0, 80.0, 90.0])),
+                regex("-?et-?"),
+                predicate(|td| matches!(td, TokenData::Numeral(d) if d.value == 1.0)),
+            ],
+            production: Box::new(|nodes| {
+                let v = numeral_data(&nodes[0].token_data)?.value;
+                Some(TokenData::Numeral(NumeralData::new(v + 1.0)))
+            }),
+        },
+        Rule {
+            name: "numbers 72..79 82..89 92..99".to_string(),
+            pattern: vec![
+                predicate(one_of(&[70.0, 80.0, 90.0])),
+                regex("[\\s\\-]+"),
+                predicate(number_between(2.0, 10.0)),
+            ],
+            production: Box::new(|nodes| {
+                let v1 = numeral_data(&nodes[0].token_data)?.value;
+                let v2 = numeral_data(&nodes[2].token_data)?.value;
+                Some(TokenData::Numeral(NumeralData::new(v1 + v2)))
+            }),
+        },
+    ]
+}
@@ -236,6 +236,24 @@ pub fn rules() -> Vec<Rule> {
                 Some(TokenData::Numeral(NumeralData::new(out)))
             }),
         },
+        // "150rb" for 150 ribu, "1,5jt" for 1,5 juta
+        Rule {
+            name: "numbers suffixes (rb, jt)".to_string(),
+            pattern: vec![dim(DimensionKind::Numeral), regex("(rb|jt)\\b")],
+            production: Box::new(|nodes| {
+                let v = numeral_data(&nodes[0].token_data)?.value;
+                let s = match &nodes[1].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?.to_lowercase(),
+                    _ => return None,
+                };
+                let out = match s.as_str() {
+                    "rb" => v * 1e3,
+                    "jt" => v * 1e6,
+                    _ => return None,
+                };
+                Some(TokenData::Numeral(NumeralData::new(out)))
+            }),
+        },
         Rule {
             name: "numbers prefix with -, negative or minus".to_string(),
             pattern: vec![regex("-|minus|negatif"), predicate(is_positive)],
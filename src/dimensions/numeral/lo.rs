@@ -64,8 +64,28 @@ fn twenty_ones(s: &str) -> Option<f64> {
     }
 }
 
+/// The ASCII digit a Lao digit ("໐".."໙") stands for.
+fn lao_digit_to_ascii(c: char) -> char {
+    match c {
+        '໐'..='໙' => char::from_digit(u32::from(c).wrapping_sub(0x0ED0), 10).unwrap_or(c),
+        _ => c,
+    }
+}
+
 pub fn rules() -> Vec<Rule> {
     vec![
+        Rule {
+            name: "lao digits".to_string(),
+            pattern: vec![regex("([໐-໙]{1,18})")],
+            production: Box::new(|nodes| {
+                let s = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?,
+                    _ => return None,
+                };
+                let ascii: String = s.chars().map(lao_digit_to_ascii).collect();
+                Some(TokenData::Numeral(NumeralData::new(ascii.parse().ok()?)))
+            }),
+        },
         Rule {
             name: "number (0..10)".to_string(),
             pattern: vec![regex("(ສູນ|ໜຶ່ງ|ສອງ|ສາມ|ສີ່|ຫ້າ|ຫົກ|ເຈັດ|ແປດ|ເກົ້າ|ສິບ)")],
@@ -38,6 +38,7 @@ pub mod pl;
 pub mod pt;
 pub mod ro;
 pub mod ru;
+pub mod separators;
 pub mod sk;
 pub mod sv;
 pub mod sw;
@@ -0,0 +1,59 @@
+use crate::locale::DecimalSeparator;
+use crate::pattern::regex;
+use crate::types::{PatternItem, Rule, TokenData};
+
+use super::NumeralData;
+
+/// Digit-string rules for an explicit decimal separator. These replace a
+/// language's own decimal and grouping rules when the locale or
+/// `Options::decimal_separator` asks for the other convention.
+pub fn rules(separator: DecimalSeparator) -> Vec<Rule> {
+    let (decimal, grouping, group_char, decimal_char) = match separator {
+        DecimalSeparator::Point => (r"(\d*\.\d+)", r"(\d{1,3}(?:,\d{3})+(?:\.\d+)?)", ',', '.'),
+        DecimalSeparator::Comma => (r"(\d*,\d+)", r"(\d{1,3}(?:\.\d{3})+(?:,\d+)?)", '.', ','),
+    };
+    vec![
+        Rule {
+            name: "decimal number (locale)".to_string(),
+            pattern: vec![regex(decimal)],
+            production: Box::new(move |nodes| {
+                let text = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?,
+                    _ => return None,
+                };
+                let val: f64 = text.replace(decimal_char, ".").parse().ok()?;
+                Some(TokenData::Numeral(NumeralData::new(val)))
+            }),
+        },
+        Rule {
+            name: "grouped number (locale)".to_string(),
+            pattern: vec![regex(grouping)],
+            production: Box::new(move |nodes| {
+                let text = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?,
+                    _ => return None,
+                };
+                let val: f64 = text
+                    .replace(group_char, "")
+                    .replace(decimal_char, ".")
+                    .parse()
+                    .ok()?;
+                Some(TokenData::Numeral(NumeralData::new(val)))
+            }),
+        },
+    ]
+}
+
+/// Whether `rule` reads digits around a "." or "," on its own, i.e. is a
+/// language's decimal or thousands-grouping rule.
+pub(crate) fn is_separator_rule(rule: &Rule) -> bool {
+    match rule.pattern.as_slice() {
+        [PatternItem::Regex(re)] => ["1.5", "1,5", "1.234", "1,234", "1.234,5", "1,234.5"]
+            .iter()
+            .any(|probe| {
+                re.find(probe)
+                    .is_some_and(|m| m.as_str().contains(['.', ',']))
+            }),
+        _ => false,
+    }
+}
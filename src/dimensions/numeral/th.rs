@@ -118,8 +118,28 @@ fn power_word(s: &str) -> Option<NumeralData> {
     }
 }
 
+/// The ASCII digit a Thai digit ("๐".."๙") stands for.
+fn thai_digit_to_ascii(c: char) -> char {
+    match c {
+        '๐'..='๙' => char::from_digit(u32::from(c).wrapping_sub(0x0E50), 10).unwrap_or(c),
+        _ => c,
+    }
+}
+
 pub fn rules() -> Vec<Rule> {
     vec![
+        Rule {
+            name: "thai digits".to_string(),
+            pattern: vec![regex("([๐-๙]{1,18})")],
+            production: Box::new(|nodes| {
+                let s = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?,
+                    _ => return None,
+                };
+                let ascii: String = s.chars().map(thai_digit_to_ascii).collect();
+                Some(TokenData::Numeral(NumeralData::new(ascii.parse().ok()?)))
+            }),
+        },
         Rule {
             name: "thai none".to_string(),
             pattern: vec![regex("ไม่มี")],
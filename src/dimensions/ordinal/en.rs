@@ -133,7 +133,10 @@ mod tests {
     #[test]
     fn test_ordinals() {
         let rules = rules();
-        let options = Options { with_latent: false };
+        let options = Options {
+            with_latent: false,
+            ..Options::default()
+        };
         let context = Context::default();
 
         for (text, expected) in &[
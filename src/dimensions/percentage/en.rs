@@ -0,0 +1,116 @@
+use crate::dimensions::numeral::helpers::numeral_data;
+use crate::pattern::{dim, regex};
+use crate::types::{DimensionKind, Rule, TokenData};
+
+use super::PercentageData;
+
+pub fn rules() -> Vec<Rule> {
+    vec![
+        // "15%", "fifteen percent", "2.5 per cent", "3 pct"
+        Rule {
+            name: "<number> percent".to_string(),
+            pattern: vec![dim(DimensionKind::Numeral), regex(r"%|(per ?cents?|pct)\b")],
+            production: Box::new(|nodes| {
+                let num = numeral_data(&nodes[0].token_data)?;
+                Some(TokenData::Percentage(PercentageData::new(num.value)))
+            }),
+        },
+        // "a quarter of a percent", "three tenths of a percent"
+        Rule {
+            name: "<fraction> of a percent".to_string(),
+            pattern: vec![regex(
+                r"(an?|one|two|three)?\s*(half|quarters?|thirds?|tenths?) of an? per ?cent",
+            )],
+            production: Box::new(|nodes| {
+                let (numerator, fraction) = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => (
+                        m.group(1).map(str::to_lowercase),
+                        m.group(2)?.to_lowercase(),
+                    ),
+                    _ => return None,
+                };
+                let numerator = match numerator.as_deref() {
+                    None | Some("a") | Some("an") | Some("one") => 1.0,
+                    Some("two") => 2.0,
+                    Some("three") => 3.0,
+                    _ => return None,
+                };
+                let denominator = match fraction.trim_end_matches('s') {
+                    "half" => 2.0,
+                    "quarter" => 4.0,
+                    "third" => 3.0,
+                    "tenth" => 10.0,
+                    _ => return None,
+                };
+                if numerator >= denominator {
+                    return None;
+                }
+                Some(TokenData::Percentage(PercentageData::new(
+                    numerator / denominator,
+                )))
+            }),
+        },
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dimensions::numeral;
+    use crate::engine;
+    use crate::resolve::{Context, Options};
+    use crate::types::DimensionValue;
+
+    fn parse_percentages(text: &str) -> Vec<f64> {
+        let mut all_rules = numeral::en::rules();
+        all_rules.extend(rules());
+        engine::parse_and_resolve(
+            text,
+            &all_rules,
+            &Context::default(),
+            &Options::default(),
+            &[DimensionKind::Percentage],
+        )
+        .into_iter()
+        .filter_map(|e| match e.value {
+            DimensionValue::Percentage(v) => Some(v),
+            _ => None,
+        })
+        .collect()
+    }
+
+    #[test]
+    fn test_percentages() {
+        for (text, expected) in [
+            ("15%", 15.0),
+            ("fifteen percent", 15.0),
+            ("2.5 per cent", 2.5),
+            ("a 3 pct rise", 3.0),
+            ("a quarter of a percent", 0.25),
+            ("three quarters of a percent", 0.75),
+            ("half of a percent", 0.5),
+        ] {
+            let values = parse_percentages(text);
+            assert!(
+                values.iter().any(|v| (v - expected).abs() < 1e-9),
+                "Expected {}% for '{}', got: {:?}",
+                expected,
+                text,
+                values
+            );
+        }
+    }
+
+    #[test]
+    fn test_percentage_negatives() {
+        for text in ["15", "percent", "3 pcts"] {
+            let values = parse_percentages(text);
+            assert!(
+                values.is_empty(),
+                "Expected no percentage for '{}', got: {:?}",
+                text,
+                values
+            );
+        }
+    }
+}
@@ -0,0 +1,19 @@
+pub mod en;
+
+use crate::types::DimensionValue;
+
+#[derive(Debug, Clone)]
+pub struct PercentageData {
+    /// The value in percent, e.g. 15.0 for "15%".
+    pub value: f64,
+}
+
+impl PercentageData {
+    pub fn new(value: f64) -> Self {
+        PercentageData { value }
+    }
+}
+
+pub fn resolve(data: &PercentageData) -> DimensionValue {
+    DimensionValue::Percentage(data.value)
+}
@@ -141,7 +141,10 @@ mod tests {
     #[test]
     fn test_phone_numbers() {
         let rules = rules();
-        let options = Options { with_latent: false };
+        let options = Options {
+            with_latent: false,
+            ..Options::default()
+        };
         let context = Context::default();
 
         for text in &[
@@ -174,7 +177,10 @@ mod tests {
     #[test]
     fn test_no_phone_numbers() {
         let rules = rules();
-        let options = Options { with_latent: false };
+        let options = Options {
+            with_latent: false,
+            ..Options::default()
+        };
         let context = Context::default();
 
         for text in &["12345", "1234567890123456777777", "12345678901234567"] {
@@ -1,6 +1,6 @@
 use crate::dimensions::numeral::helpers::numeral_data;
 use crate::pattern::{dim, predicate, regex};
-use crate::types::{DimensionKind, Rule, TokenData};
+use crate::types::{DimensionKind, Precision, Rule, TokenData};
 
 use super::{QuantityData, QuantityUnit};
 
@@ -21,6 +21,117 @@ fn gram_multiplier(matched: &str) -> f64 {
     }
 }
 
+/// Kitchen units beyond Haskell's cups and weights: the unit regex (with one
+/// capture group) and the unit it reads as.
+const KITCHEN_UNITS: &[(&str, &str, QuantityUnit)] = &[
+    (
+        "tablespoons",
+        r"(tablespoons?|tbsps?|tbs)\.?",
+        QuantityUnit::Tablespoon,
+    ),
+    (
+        "teaspoons",
+        r"(teaspoons?|tsps?)\.?",
+        QuantityUnit::Teaspoon,
+    ),
+    ("sticks", r"(sticks?)", QuantityUnit::Stick),
+    ("pinches", r"(pinch(es)?)", QuantityUnit::Pinch),
+    ("cans", r"(cans?|tins?)", QuantityUnit::Can),
+];
+
+/// Words after "a few" or "two dozen" that aren't items being counted.
+/// Units are left to their own rules: "a couple of cans" is two cans, not
+/// two of an item called "cans".
+fn is_countable(product: &str) -> bool {
+    let product = product.to_lowercase();
+    if matches!(
+        product.as_str(),
+        "cup"
+            | "cups"
+            | "can"
+            | "cans"
+            | "tin"
+            | "tins"
+            | "stick"
+            | "sticks"
+            | "pinch"
+            | "pinches"
+            | "tablespoon"
+            | "tablespoons"
+            | "tbsp"
+            | "tbsps"
+            | "tbs"
+            | "teaspoon"
+            | "teaspoons"
+            | "tsp"
+            | "tsps"
+            | "g"
+            | "gram"
+            | "grams"
+            | "kg"
+            | "kilogram"
+            | "kilograms"
+            | "mg"
+            | "milligram"
+            | "milligrams"
+            | "lb"
+            | "lbs"
+            | "pound"
+            | "pounds"
+            | "ounce"
+            | "ounces"
+            | "oz"
+    ) {
+        return false;
+    }
+    !matches!(
+        product.trim_end_matches('s'),
+        "second"
+            | "minute"
+            | "hour"
+            | "day"
+            | "week"
+            | "month"
+            | "year"
+            | "time"
+            | "more"
+            | "other"
+            | "of"
+            | "and"
+            | "or"
+            | "to"
+    )
+}
+
+/// "<numeral> <unit>" and "a <unit>" rules for each of [`KITCHEN_UNITS`].
+fn kitchen_unit_rules() -> Vec<Rule> {
+    KITCHEN_UNITS
+        .iter()
+        .flat_map(|&(name, unit_regex, unit)| {
+            [
+                Rule {
+                    name: format!("<quantity> {name}"),
+                    pattern: vec![dim(DimensionKind::Numeral), regex(unit_regex)],
+                    production: Box::new(move |nodes| {
+                        let data = numeral_data(&nodes[0].token_data)?;
+                        if data.value <= 0.0 {
+                            return None;
+                        }
+                        Some(TokenData::Quantity(QuantityData::new(data.value, unit)))
+                    }),
+                },
+                Rule {
+                    name: format!("a <quantity> {name}"),
+                    pattern: vec![regex(&format!("an? {unit_regex}"))],
+                    production: Box::new(move |_| {
+                        Some(TokenData::Quantity(QuantityData::new(1.0, unit)))
+                    }),
+                },
+            ]
+        })
+        .collect()
+}
+
 /// Matches simple Quantity tokens (has value and unit, no interval).
 fn is_simple_quantity() -> crate::types::PatternItem {
     predicate(|td| {
@@ -33,7 +144,7 @@ fn is_simple_quantity() -> crate::types::PatternItem {
 }
 
 pub fn rules() -> Vec<Rule> {
-    vec![
+    let mut rules = vec![
         // === Numeral + unit rules (ruleNumeralQuantities) ===
 
         // <number> cups
@@ -176,7 +287,14 @@ pub fn rules() -> Vec<Rule> {
                 ),
                 dim(DimensionKind::Quantity),
             ],
-            production: Box::new(|nodes| Some(nodes[1].token_data.clone())),
+            production: Box::new(|nodes| {
+                Some(
+                    nodes[1]
+                        .token_data
+                        .clone()
+                        .with_precision(Precision::of_qualifier(nodes[0])),
+                )
+            }),
         },
         // === Interval rules ===
 
@@ -300,7 +418,51 @@ pub fn rules() -> Vec<Rule> {
                 ))
             }),
         },
-    ]
+        // === Counts of items ===
+
+        // <numeral> dozen <product>
+        Rule {
+            name: "<numeral> dozen <product>".to_string(),
+            pattern: vec![dim(DimensionKind::Numeral), regex(r"dozens?(?: of)? (\w+)")],
+            production: Box::new(|nodes| {
+                let data = numeral_data(&nodes[0].token_data)?;
+                let product = match &nodes[1].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?,
+                    _ => return None,
+                };
+                if data.value <= 0.0 || !is_countable(product) {
+                    return None;
+                }
+                let mut count = QuantityData::new(data.value * 12.0, QuantityUnit::Unnamed);
+                count.product = Some(product.to_lowercase());
+                Some(TokenData::Quantity(count))
+            }),
+        },
+        // a dozen / a couple (of) / a few <product>
+        Rule {
+            name: "a dozen|couple|few <product>".to_string(),
+            pattern: vec![regex(r"an? (dozen|couple|few)(?: of)? (\w+)")],
+            production: Box::new(|nodes| {
+                let (size, product) = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => (m.group(1)?, m.group(2)?),
+                    _ => return None,
+                };
+                if !is_countable(product) {
+                    return None;
+                }
+                let value = match size.to_lowercase().as_str() {
+                    "dozen" => 12.0,
+                    "couple" => 2.0,
+                    _ => 3.0,
+                };
+                let mut count = QuantityData::new(value, QuantityUnit::Unnamed);
+                count.product = Some(product.to_lowercase());
+                Some(TokenData::Quantity(count))
+            }),
+        },
+    ];
+    rules.extend(kitchen_unit_rules());
+    rules
 }
 
 #[cfg(test)]
@@ -314,7 +476,10 @@ mod tests {
     fn test_quantity() {
         let mut rules = numeral::en::rules();
         rules.extend(super::rules());
-        let options = Options { with_latent: false };
+        let options = Options {
+            with_latent: false,
+            ..Options::default()
+        };
         let context = Context::default();
 
         let entities = engine::parse_and_resolve(
@@ -12,7 +12,7 @@ pub mod ro;
 pub mod ru;
 pub mod zh;
 
-use crate::types::{DimensionValue, MeasurementPoint, MeasurementValue};
+use crate::types::{DimensionValue, MeasurementPoint, MeasurementValue, Precision, Unit};
 
 #[derive(Debug, Clone)]
 pub struct QuantityData {
@@ -21,25 +21,48 @@ pub struct QuantityData {
     pub product: Option<String>,
     pub min_value: Option<f64>,
     pub max_value: Option<f64>,
+    pub precision: Option<Precision>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The unit of a quantity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum QuantityUnit {
+    /// Cans or tins.
+    Can,
+    /// Cups.
     Cup,
+    /// Grams.
     Gram,
+    /// Ounces.
     Ounce,
+    /// Pinches.
+    Pinch,
+    /// Pounds.
     Pound,
+    /// Sticks (of butter).
+    Stick,
+    /// Tablespoons.
     Tablespoon,
+    /// Teaspoons.
+    Teaspoon,
+    /// A plain count of items ("two dozen eggs"), named by the product.
+    Unnamed,
 }
 
 impl QuantityUnit {
+    /// Returns the unit name (e.g. `"gram"`).
     pub fn as_str(&self) -> &'static str {
         match self {
+            QuantityUnit::Can => "can",
             QuantityUnit::Cup => "cup",
             QuantityUnit::Gram => "gram",
             QuantityUnit::Ounce => "ounce",
+            QuantityUnit::Pinch => "pinch",
             QuantityUnit::Pound => "pound",
+            QuantityUnit::Stick => "stick",
             QuantityUnit::Tablespoon => "tablespoon",
+            QuantityUnit::Teaspoon => "teaspoon",
+            QuantityUnit::Unnamed => "unnamed",
         }
     }
 }
@@ -52,6 +75,7 @@ impl QuantityData {
             product: None,
             min_value: None,
             max_value: None,
+            precision: None,
         }
     }
 
@@ -62,6 +86,7 @@ impl QuantityData {
             product: None,
             min_value: None,
             max_value: None,
+            precision: None,
         }
     }
 
@@ -88,37 +113,21 @@ impl QuantityData {
 }
 
 pub fn resolve(data: &QuantityData) -> Option<DimensionValue> {
-    let unit = data.unit.as_ref()?;
-    let unit_str = unit.as_str().to_string();
+    let unit = Unit::from(data.unit?);
 
     let mv = match (data.value, data.min_value, data.max_value) {
-        (Some(v), _, _) => MeasurementValue::Value {
-            value: v,
-            unit: unit_str,
-        },
+        (Some(v), _, _) => MeasurementValue::Value { value: v, unit },
         (None, Some(from), Some(to)) => MeasurementValue::Interval {
-            from: Some(MeasurementPoint {
-                value: from,
-                unit: unit_str.clone(),
-            }),
-            to: Some(MeasurementPoint {
-                value: to,
-                unit: unit_str,
-            }),
+            from: Some(MeasurementPoint { value: from, unit }),
+            to: Some(MeasurementPoint { value: to, unit }),
         },
         (None, Some(from), None) => MeasurementValue::Interval {
-            from: Some(MeasurementPoint {
-                value: from,
-                unit: unit_str,
-            }),
+            from: Some(MeasurementPoint { value: from, unit }),
             to: None,
         },
         (None, None, Some(to)) => MeasurementValue::Interval {
             from: None,
-            to: Some(MeasurementPoint {
-                value: to,
-                unit: unit_str,
-            }),
+            to: Some(MeasurementPoint { value: to, unit }),
         },
         _ => return None,
     };
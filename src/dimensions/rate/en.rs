@@ -0,0 +1,242 @@
+use crate::dimensions::distance::{DistanceData, DistanceUnit};
+use crate::dimensions::numeral::helpers::numeral_data;
+use crate::dimensions::time_grain::Grain;
+use crate::dimensions::volume::VolumeUnit;
+use crate::pattern::{dim, predicate, regex};
+use crate::types::{DimensionKind, PatternItem, Rule, TokenData, Unit};
+
+use super::{RateData, RatePer};
+
+fn grain(token_data: &TokenData) -> Option<Grain> {
+    match token_data {
+        TokenData::TimeGrain(grain) => Some(*grain),
+        _ => None,
+    }
+}
+
+/// Matches Distance tokens with a value and a unit, e.g. "60 km".
+fn is_simple_distance() -> PatternItem {
+    predicate(|td| {
+        matches!(td, TokenData::Distance(data)
+            if data.value.is_some()
+                && data.unit.is_some()
+                && data.min_value.is_none()
+                && data.max_value.is_none())
+    })
+}
+
+fn distance(token_data: &TokenData) -> Option<(f64, DistanceUnit)> {
+    match token_data {
+        TokenData::Distance(DistanceData {
+            value: Some(value),
+            unit: Some(unit),
+            ..
+        }) if *value > 0.0 => Some((*value, *unit)),
+        _ => None,
+    }
+}
+
+pub fn rules() -> Vec<Rule> {
+    vec![
+        // "once a week", "twice daily", "thrice per day"
+        Rule {
+            name: "once|twice|thrice <grain>".to_string(),
+            pattern: vec![
+                regex(r"(once|twice|thrice)( an?)?"),
+                dim(DimensionKind::TimeGrain),
+            ],
+            production: Box::new(|nodes| {
+                let count = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => match m.group(1)?.to_lowercase().as_str() {
+                        "once" => 1.0,
+                        "twice" => 2.0,
+                        _ => 3.0,
+                    },
+                    _ => return None,
+                };
+                let grain = grain(&nodes[1].token_data)?;
+                Some(TokenData::Rate(RateData::per_grain(count, grain)))
+            }),
+        },
+        // "3 times a day", "4 times per week", "2x daily", "3x/day"
+        Rule {
+            name: "<number> times <grain>".to_string(),
+            pattern: vec![
+                dim(DimensionKind::Numeral),
+                regex(r"(times?|x)(\s*/|\s+an?)?"),
+                dim(DimensionKind::TimeGrain),
+            ],
+            production: Box::new(|nodes| {
+                let count = numeral_data(&nodes[0].token_data)?.value;
+                if count <= 0.0 {
+                    return None;
+                }
+                let grain = grain(&nodes[2].token_data)?;
+                Some(TokenData::Rate(RateData::per_grain(count, grain)))
+            }),
+        },
+        // "every 6 hours", "each 2 weeks"
+        Rule {
+            name: "every <duration>".to_string(),
+            pattern: vec![regex(r"every|each"), dim(DimensionKind::Duration)],
+            production: Box::new(|nodes| {
+                let TokenData::Duration(duration) = &nodes[1].token_data else {
+                    return None;
+                };
+                if duration.value <= 0 {
+                    return None;
+                }
+                Some(TokenData::Rate(RateData {
+                    count: 1.0,
+                    unit: None,
+                    per: RatePer::Time {
+                        value: duration.value,
+                        grain: duration.grain,
+                    },
+                }))
+            }),
+        },
+        // "60 km/h", "55 miles an hour", "10 km per day"
+        Rule {
+            name: "<distance> per <grain>".to_string(),
+            pattern: vec![
+                is_simple_distance(),
+                regex(r"/|an?"),
+                dim(DimensionKind::TimeGrain),
+            ],
+            production: Box::new(|nodes| {
+                let (value, unit) = distance(&nodes[0].token_data)?;
+                let grain = grain(&nodes[2].token_data)?;
+                Some(TokenData::Rate(
+                    RateData::per_grain(value, grain).with_unit(Unit::Distance(unit)),
+                ))
+            }),
+        },
+        // "per hour" and "hourly" already read as a grain: "10 km per day"
+        Rule {
+            name: "<distance> <grain>".to_string(),
+            pattern: vec![is_simple_distance(), dim(DimensionKind::TimeGrain)],
+            production: Box::new(|nodes| {
+                let (value, unit) = distance(&nodes[0].token_data)?;
+                let grain = grain(&nodes[1].token_data)?;
+                Some(TokenData::Rate(
+                    RateData::per_grain(value, grain).with_unit(Unit::Distance(unit)),
+                ))
+            }),
+        },
+        // "60 mph", "100 kph", "90 km/h" written as one word
+        Rule {
+            name: "<number> mph|kph".to_string(),
+            pattern: vec![dim(DimensionKind::Numeral), regex(r"(mph|kph|kmh|km/h)")],
+            production: Box::new(|nodes| {
+                let value = numeral_data(&nodes[0].token_data)?.value;
+                if value <= 0.0 {
+                    return None;
+                }
+                let unit = match &nodes[1].token_data {
+                    TokenData::RegexMatch(m) if m.group(1)?.eq_ignore_ascii_case("mph") => {
+                        DistanceUnit::Mile
+                    }
+                    _ => DistanceUnit::Kilometre,
+                };
+                Some(TokenData::Rate(
+                    RateData::per_grain(value, Grain::Hour).with_unit(Unit::Distance(unit)),
+                ))
+            }),
+        },
+        // "30 miles per gallon", "15 km/l", "30 mpg"
+        Rule {
+            name: "<distance> per <volume unit>".to_string(),
+            pattern: vec![
+                is_simple_distance(),
+                regex(r"(?:per|/|an?)\s*(gallon|litre|liter|l)s?"),
+            ],
+            production: Box::new(|nodes| {
+                let (value, unit) = distance(&nodes[0].token_data)?;
+                let per = match &nodes[1].token_data {
+                    TokenData::RegexMatch(m) if m.group(1)?.eq_ignore_ascii_case("gallon") => {
+                        VolumeUnit::Gallon
+                    }
+                    _ => VolumeUnit::Litre,
+                };
+                Some(TokenData::Rate(RateData {
+                    count: value,
+                    unit: Some(Unit::Distance(unit)),
+                    per: RatePer::Unit(Unit::Volume(per)),
+                }))
+            }),
+        },
+        Rule {
+            name: "<number> mpg".to_string(),
+            pattern: vec![dim(DimensionKind::Numeral), regex(r"mpg")],
+            production: Box::new(|nodes| {
+                let value = numeral_data(&nodes[0].token_data)?.value;
+                if value <= 0.0 {
+                    return None;
+                }
+                Some(TokenData::Rate(RateData {
+                    count: value,
+                    unit: Some(Unit::Distance(DistanceUnit::Mile)),
+                    per: RatePer::Unit(Unit::Volume(VolumeUnit::Gallon)),
+                }))
+            }),
+        },
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::DimensionValue;
+
+    fn parse_rates(text: &str) -> Vec<(String, f64, Option<Unit>, RatePer)> {
+        crate::parse_en(text, &[DimensionKind::Rate])
+            .into_iter()
+            .filter_map(|e| match e.value {
+                DimensionValue::Rate { count, unit, per } => Some((e.body, count, unit, per)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_rates() {
+        let per = |value, grain| RatePer::Time { value, grain };
+        let km = Some(Unit::Distance(DistanceUnit::Kilometre));
+        let miles = Some(Unit::Distance(DistanceUnit::Mile));
+        for (text, count, unit, expected_per) in [
+            ("twice a week", 2.0, None, per(1, Grain::Week)),
+            ("once daily", 1.0, None, per(1, Grain::Day)),
+            ("3 times per day", 3.0, None, per(1, Grain::Day)),
+            ("four times a month", 4.0, None, per(1, Grain::Month)),
+            ("2x/day", 2.0, None, per(1, Grain::Day)),
+            ("every 6 hours", 1.0, None, per(6, Grain::Hour)),
+            ("60 km/h", 60.0, km, per(1, Grain::Hour)),
+            ("100 kph", 100.0, km, per(1, Grain::Hour)),
+            ("55 miles an hour", 55.0, miles, per(1, Grain::Hour)),
+            ("65 mph", 65.0, miles, per(1, Grain::Hour)),
+            ("10 km per day", 10.0, km, per(1, Grain::Day)),
+            (
+                "30 miles per gallon",
+                30.0,
+                miles,
+                RatePer::Unit(Unit::Volume(VolumeUnit::Gallon)),
+            ),
+        ] {
+            let rates = parse_rates(text);
+            assert_eq!(
+                rates,
+                [(text.to_string(), count, unit, expected_per)],
+                "{text:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_rate_negatives() {
+        for text in ["twice", "3 times", "every day at noon", "60 km", "6 hours"] {
+            let rates = parse_rates(text);
+            assert!(rates.is_empty(), "{text:?}: {rates:?}");
+        }
+    }
+}
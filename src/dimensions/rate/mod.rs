@@ -0,0 +1,50 @@
+pub mod en;
+
+use crate::dimensions::time_grain::Grain;
+use crate::types::{DimensionValue, Unit};
+
+#[derive(Debug, Clone)]
+pub struct RateData {
+    pub count: f64,
+    pub unit: Option<Unit>,
+    pub per: RatePer,
+}
+
+/// What a [`DimensionValue::Rate`] is counted per.
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize)]
+pub enum RatePer {
+    /// A span of time: one day for "twice a day", six hours for "every 6
+    /// hours".
+    Time {
+        /// The number of grains (6 in "every 6 hours").
+        value: i64,
+        /// The grain (Hour in "every 6 hours").
+        grain: Grain,
+    },
+    /// A unit of measure: the gallon of "30 miles per gallon".
+    Unit(Unit),
+}
+
+impl RateData {
+    /// `count` occurrences per one `grain`.
+    pub fn per_grain(count: f64, grain: Grain) -> Self {
+        RateData {
+            count,
+            unit: None,
+            per: RatePer::Time { value: 1, grain },
+        }
+    }
+
+    pub fn with_unit(mut self, unit: Unit) -> Self {
+        self.unit = Some(unit);
+        self
+    }
+}
+
+pub fn resolve(data: &RateData) -> DimensionValue {
+    DimensionValue::Rate {
+        count: data.count,
+        unit: data.unit,
+        per: data.per,
+    }
+}
@@ -206,7 +206,10 @@ mod tests {
     #[test]
     fn test_temperature() {
         let rules = all_rules();
-        let options = Options { with_latent: false };
+        let options = Options {
+            with_latent: false,
+            ..Options::default()
+        };
         let context = Context::default();
 
         let entities = engine::parse_and_resolve(
@@ -229,7 +232,10 @@ mod tests {
     #[test]
     fn test_temperature_celsius() {
         let rules = all_rules();
-        let options = Options { with_latent: false };
+        let options = Options {
+            with_latent: false,
+            ..Options::default()
+        };
         let context = Context::default();
 
         let entities = engine::parse_and_resolve(
@@ -252,7 +258,10 @@ mod tests {
     #[test]
     fn test_3_degrees() {
         let rules = all_rules();
-        let options = Options { with_latent: false };
+        let options = Options {
+            with_latent: false,
+            ..Options::default()
+        };
         let context = Context::default();
 
         let entities = engine::parse_and_resolve(
@@ -275,7 +284,10 @@ mod tests {
     #[test]
     fn test_below_zero() {
         let rules = all_rules();
-        let options = Options { with_latent: false };
+        let options = Options {
+            with_latent: false,
+            ..Options::default()
+        };
         let context = Context::default();
 
         let entities = engine::parse_and_resolve(
@@ -16,7 +16,8 @@ pub mod ro;
 pub mod tr;
 pub mod zh;
 
-use crate::types::{DimensionValue, MeasurementPoint, MeasurementValue};
+use crate::locale::Locale;
+use crate::types::{DimensionValue, MeasurementPoint, MeasurementValue, Unit};
 
 #[derive(Debug, Clone)]
 pub struct TemperatureData {
@@ -26,14 +27,19 @@ pub struct TemperatureData {
     pub max_value: Option<f64>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The unit of a temperature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum TemperatureUnit {
+    /// Degrees Celsius.
     Celsius,
+    /// Degrees Fahrenheit.
     Fahrenheit,
+    /// Degrees with no scale given ("80 degrees").
     Degree,
 }
 
 impl TemperatureUnit {
+    /// Returns the unit name (e.g. `"fahrenheit"`).
     pub fn as_str(&self) -> &'static str {
         match self {
             TemperatureUnit::Celsius => "celsius",
@@ -43,6 +49,41 @@ impl TemperatureUnit {
     }
 }
 
+/// How a temperature given in bare degrees ("3 degrees outside", "20°") is
+/// read.
+///
+/// ```
+/// use duckling::{parse, Context, DegreePolicy, DimensionKind, Lang, Locale, Options, Region};
+///
+/// let locale = Locale::new(Lang::EN, Some(Region::GB));
+/// let context = Context { locale, ..Context::default() };
+/// let options = Options::builder().degree_policy(DegreePolicy::Regional).build();
+/// let value = &parse("3 degrees outside", &locale, &[DimensionKind::Temperature], &context, &options)[0].value;
+/// assert_eq!(value.unit().unwrap(), "celsius");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DegreePolicy {
+    /// Keep the unit [`TemperatureUnit::Degree`], leaving the scale to the
+    /// caller.
+    #[default]
+    Unspecified,
+    /// The scale of the locale's region; see [`Locale::temperature_scale`].
+    Regional,
+    /// Always the given scale.
+    Fixed(TemperatureUnit),
+}
+
+impl DegreePolicy {
+    /// The unit bare degrees are read in under this policy in `locale`.
+    pub fn unit(&self, locale: &Locale) -> TemperatureUnit {
+        match self {
+            DegreePolicy::Unspecified => TemperatureUnit::Degree,
+            DegreePolicy::Regional => locale.temperature_scale(),
+            DegreePolicy::Fixed(unit) => *unit,
+        }
+    }
+}
+
 impl TemperatureData {
     pub fn new(value: f64) -> Self {
         TemperatureData {
@@ -96,38 +137,26 @@ pub fn units_are_compatible(u1: Option<TemperatureUnit>, u2: TemperatureUnit) ->
     }
 }
 
-pub fn resolve(data: &TemperatureData) -> Option<DimensionValue> {
-    let unit = data.unit.as_ref()?;
-    let unit_str = unit.as_str().to_string();
+/// Resolves `data`, reading bare degrees in `degree_unit`.
+pub fn resolve(data: &TemperatureData, degree_unit: TemperatureUnit) -> Option<DimensionValue> {
+    let unit = match data.unit? {
+        TemperatureUnit::Degree => Unit::from(degree_unit),
+        unit => Unit::from(unit),
+    };
 
     let mv = match (data.value, data.min_value, data.max_value) {
-        (Some(v), _, _) => MeasurementValue::Value {
-            value: v,
-            unit: unit_str,
-        },
+        (Some(v), _, _) => MeasurementValue::Value { value: v, unit },
         (None, Some(from), Some(to)) => MeasurementValue::Interval {
-            from: Some(MeasurementPoint {
-                value: from,
-                unit: unit_str.clone(),
-            }),
-            to: Some(MeasurementPoint {
-                value: to,
-                unit: unit_str,
-            }),
+            from: Some(MeasurementPoint { value: from, unit }),
+            to: Some(MeasurementPoint { value: to, unit }),
         },
         (None, Some(from), None) => MeasurementValue::Interval {
-            from: Some(MeasurementPoint {
-                value: from,
-                unit: unit_str,
-            }),
+            from: Some(MeasurementPoint { value: from, unit }),
             to: None,
         },
         (None, None, Some(to)) => MeasurementValue::Interval {
             from: None,
-            to: Some(MeasurementPoint {
-                value: to,
-                unit: unit_str,
-            }),
+            to: Some(MeasurementPoint { value: to, unit }),
         },
         _ => return None,
     };
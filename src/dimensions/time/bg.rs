@@ -104,7 +104,7 @@ fn to_h24_for_part(hour: u32, pod: PartOfDay) -> u32 {
                 hour
             }
         }
-        PartOfDay::Afternoon | PartOfDay::Evening | PartOfDay::Night => {
+        PartOfDay::Afternoon | PartOfDay::Evening | PartOfDay::Night | PartOfDay::Dinner => {
             if hour < 12 {
                 hour.saturating_add(12)
             } else {
@@ -112,6 +112,10 @@ fn to_h24_for_part(hour: u32, pod: PartOfDay) -> u32 {
             }
         }
         PartOfDay::Lunch => 12,
+        PartOfDay::Breakfast
+        | PartOfDay::Overnight
+        | PartOfDay::Noonish
+        | PartOfDay::Midnightish => hour,
     }
 }
 
@@ -419,6 +423,35 @@ pub fn rules() -> Vec<Rule> {
                 })))
             }),
         },
+        Rule {
+            name: "hh:mm (bg)".to_string(),
+            pattern: vec![regex("\\b((?:[01]?\\d)|(?:2[0-3]))[:.]([0-5]\\d)\\b")],
+            production: Box::new(|nodes| {
+                let (h, m) = match &nodes[0].token_data {
+                    TokenData::RegexMatch(rm) => (rm.group(1)?, rm.group(2)?),
+                    _ => return None,
+                };
+                let hh: u32 = h.parse().ok()?;
+                let mm: u32 = m.parse().ok()?;
+                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(hh, mm, false))))
+            }),
+        },
+        Rule {
+            name: "on <date> (bg)".to_string(),
+            pattern: vec![
+                regex("\\bна\\b"),
+                predicate(|td| {
+                    matches!(
+                        td,
+                        TokenData::Time(TimeData {
+                            form: TimeForm::DateMDY { .. } | TimeForm::Holiday(..),
+                            ..
+                        })
+                    )
+                }),
+            ],
+            production: Box::new(|nodes| Some(TokenData::Time(time_data(&nodes[1].token_data)?.clone()))),
+        },
         Rule {
             name: "at <time> (bg)".to_string(),
             pattern: vec![regex("в"), predicate(is_time_of_day)],
@@ -520,9 +553,7 @@ pub fn rules() -> Vec<Rule> {
                     _ => return None,
                 };
                 let idx = if s.contains("лято") { 1 } else { 3 };
-                let mut td = TimeData::new(TimeForm::Season(idx));
-                td.direction = Some(Direction::Future);
-                Some(TokenData::Time(td))
+                Some(TokenData::Time(TimeData::new(TimeForm::Season(idx))))
             }),
         },
         Rule {
@@ -535,6 +566,43 @@ pub fn rules() -> Vec<Rule> {
                 ))))
             }),
         },
+        Rule {
+            name: "holidays (bg)".to_string(),
+            pattern: vec![regex("\\b(?:бъдни\\s+вечер|нова(?:та)?\\s+година|трети\\s+март|(?:ден(?:ят)?\\s+на\\s+)?(?:освобождението|съединението|независимостта)|ден(?:ят)?\\s+на\\s+(?:славянската\\s+писменост|българската\\s+просвета(?:\\s+и\\s+култура)?)|гергьовден|великден(?:ът)?|разпети\\s+петък|цветница|петдесетница)\\b")],
+            production: Box::new(|nodes| {
+                let s = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(0)?.to_lowercase(),
+                    _ => return None,
+                };
+                let name = if s.starts_with("бъдни") {
+                    "christmas eve"
+                } else if s.starts_with("нова") {
+                    "new year's day"
+                } else if s.starts_with("трети") || s.contains("освобождението") {
+                    "bulgarian liberation day"
+                } else if s.contains("съединението") {
+                    "unification day"
+                } else if s.contains("независимостта") {
+                    "bulgarian independence day"
+                } else if s.contains("писменост") || s.contains("просвета") {
+                    "slavonic literature day"
+                } else if s.starts_with("гергьов") {
+                    "gergyovden"
+                } else if s.starts_with("разпети") {
+                    "orthodox good friday"
+                } else if s.starts_with("цветница") {
+                    "orthodox palm sunday"
+                } else if s.starts_with("петдесетница") {
+                    "orthodox pentecost"
+                } else {
+                    "orthodox easter"
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::Holiday(
+                    name.to_string(),
+                    None,
+                ))))
+            }),
+        },
     ]);
     rules
 }
@@ -1,782 +1,849 @@
-use super::{TimeData, TimeForm};
+use super::{clock_hour_offset, is_clock_hour, TimeData, TimeForm};
+use crate::dimensions::duration::DurationQualifier;
+use crate::dimensions::numeral::helpers::integer_value;
 use crate::dimensions::time_grain::Grain;
-use crate::pattern::regex;
-use crate::types::{Rule, TokenData};
+use crate::pattern::{dim, predicate, regex};
+use crate::types::{DimensionKind, Rule, TokenData};
 
-fn ca_small_number(s: &str) -> Option<i32> {
-    match s {
-        "un" | "una" => Some(1),
-        "dos" | "dues" => Some(2),
-        "tres" => Some(3),
-        "quatre" => Some(4),
-        "cinc" => Some(5),
-        "sis" => Some(6),
-        "set" => Some(7),
-        "vuit" => Some(8),
-        "nou" => Some(9),
-        "deu" => Some(10),
-        "onze" => Some(11),
-        "dotze" => Some(12),
-        "catorze" => Some(14),
-        "vint-i-quatre" | "vint i quatre" => Some(24),
+/// Month names and their abbreviations; "set" is September here, as in the
+/// upstream corpus, even though it is also the numeral seven.
+const MONTHS: &str = "gener|gen|febrer|feb|mar[çc]|abril|abr|maig|juny|jun|juliol|jul|agost|ago|setembre|set|octubre|oct|novembre|nov|desembre|des";
+
+/// Weekday names and their two-letter abbreviations ("dl", "dm", ...).
+const DAYS: &str =
+    "dilluns|dl|dimarts|dm|dimecres|dc|dijous|dj|divendres|dv|dissabte|ds|diumenge|dg";
+
+/// The part-of-day words read after a day or an hour.
+const PARTS_OF_DAY: &str = "mat[ií]|tarda|vespre|nit";
+
+fn time_data(td: &TokenData) -> Option<&TimeData> {
+    match td {
+        TokenData::Time(d) => Some(d),
+        _ => None,
+    }
+}
+
+fn is_dom_token(td: &TokenData) -> bool {
+    dom_value(td).is_some()
+}
+
+fn dom_value(td: &TokenData) -> Option<u32> {
+    match td {
+        TokenData::Numeral(_) => {
+            let v = integer_value(td)?;
+            (1..=31).contains(&v).then_some(v as u32)
+        }
+        TokenData::Ordinal(o) if (1..=31).contains(&o.value) => Some(o.value as u32),
         _ => None,
     }
 }
 
+fn is_natural(td: &TokenData) -> bool {
+    integer_value(td).is_some_and(|v| v > 0)
+}
+
+fn is_hour(td: &TokenData) -> bool {
+    integer_value(td).is_some_and(|v| (0..=23).contains(&v))
+}
+
+fn is_minute(td: &TokenData) -> bool {
+    integer_value(td).is_some_and(|v| (1..=59).contains(&v))
+}
+
+fn is_day_of_week(td: &TokenData) -> bool {
+    matches!(td, TokenData::Time(d) if matches!(d.form, TimeForm::DayOfWeek(_)))
+}
+
+fn is_clock_time(td: &TokenData) -> bool {
+    matches!(td, TokenData::Time(d) if !d.latent && matches!(d.form, TimeForm::Hour(..) | TimeForm::HourMinute(..)))
+}
+
+fn is_date(td: &TokenData) -> bool {
+    matches!(td, TokenData::Time(d) if matches!(d.form, TimeForm::DateMDY { .. } | TimeForm::DayOfMonth(_)))
+}
+
+fn is_year(td: &TokenData) -> bool {
+    matches!(td, TokenData::Time(d) if matches!(d.form, TimeForm::Year(_)))
+}
+
+/// A date without a year, or a month, that "de <year>" can complete.
+fn is_yearless_date(td: &TokenData) -> bool {
+    matches!(td, TokenData::Time(d) if matches!(d.form, TimeForm::DateMDY { year: None, .. } | TimeForm::Month(_)))
+}
+
+/// A time a part of the day can narrow down: "demà", "dilluns", "el 15
+/// de febrer".
+fn is_day(td: &TokenData) -> bool {
+    matches!(td, TokenData::Time(d) if !d.latent && matches!(
+        d.form,
+        TimeForm::Today
+            | TimeForm::Tomorrow
+            | TimeForm::Yesterday
+            | TimeForm::DayAfterTomorrow
+            | TimeForm::DayBeforeYesterday
+            | TimeForm::DayOfWeek(_)
+            | TimeForm::DayOfMonth(_)
+            | TimeForm::DateMDY { .. }
+            | TimeForm::Holiday(..)
+    ))
+}
+
+fn ca_month_from_text(s: &str) -> Option<u32> {
+    let s = s.to_lowercase();
+    let month = match s.get(..3)? {
+        "gen" => 1,
+        "feb" => 2,
+        "mar" => 3,
+        "abr" => 4,
+        "mai" => 5,
+        "jun" => 6,
+        "jul" => 7,
+        "ago" => 8,
+        "set" => 9,
+        "oct" => 10,
+        "nov" => 11,
+        "des" => 12,
+        _ => return None,
+    };
+    Some(month)
+}
+
+fn ca_dow_from_text(s: &str) -> Option<u32> {
+    let dow = match s.to_lowercase().as_str() {
+        "dilluns" | "dl" => 0,
+        "dimarts" | "dm" => 1,
+        "dimecres" | "dc" => 2,
+        "dijous" | "dj" => 3,
+        "divendres" | "dv" => 4,
+        "dissabte" | "ds" => 5,
+        "diumenge" | "dg" => 6,
+        _ => return None,
+    };
+    Some(dow)
+}
+
+/// The week, month or year offset of "passat|passada" (-1),
+/// "aquest|aquesta|actual" (0) and "vinent|proper|següent|que ve" (1).
+fn ca_offset(q: &str) -> i32 {
+    let q = q.to_lowercase();
+    if q.contains("passa") {
+        -1
+    } else if q.contains("aquest") || q.contains("actual") {
+        0
+    } else {
+        1
+    }
+}
+
+fn ca_grain_from_text(s: &str) -> Option<Grain> {
+    let s = s.to_lowercase();
+    let grain = if s.starts_with("setman") {
+        Grain::Week
+    } else if s.starts_with("mes") {
+        Grain::Month
+    } else if s.starts_with("trimestre") {
+        Grain::Quarter
+    } else if s.starts_with("any") {
+        Grain::Year
+    } else {
+        return None;
+    };
+    Some(grain)
+}
+
+/// The day-of-week `dow` in the week `offset` weeks from this one.
+fn dow_of_week(dow: u32, offset: i32) -> TimeData {
+    TimeData::new(TimeForm::Composed(
+        Box::new(TimeData::new(TimeForm::DayOfWeek(dow))),
+        Box::new(TimeData::new(TimeForm::GrainOffset {
+            grain: Grain::Week,
+            offset,
+        })),
+    ))
+}
+
+/// The hours of the morning ("matí"), afternoon ("tarda"), evening
+/// ("vespre") and night ("nit"), as the upstream corpus has them.
+fn part_of_day(s: &str) -> TimeData {
+    let (start_h, end_h) = match s.to_lowercase().as_str() {
+        "tarda" => (14, 19),
+        "vespre" => (19, 22),
+        "nit" => (0, 1),
+        _ => (6, 12),
+    };
+    TimeData::new(TimeForm::Interval(
+        Box::new(TimeData::new(TimeForm::Hour(start_h, false))),
+        Box::new(TimeData::new(TimeForm::Hour(end_h, false))),
+        true,
+    ))
+}
+
+/// The clock hour `hour` read with "del matí", "de la tarda", "del vespre"
+/// or "de la nit".
+fn period_hour(period: &str, hour: u32) -> Option<u32> {
+    if hour > 12 {
+        return (hour < 24).then_some(hour);
+    }
+    let period = period.to_lowercase();
+    let hour = if period.starts_with("mat") {
+        if hour == 12 {
+            0
+        } else {
+            hour
+        }
+    } else if period == "nit" {
+        match hour {
+            12 => 0,
+            1..=4 => hour,
+            _ => hour.checked_add(12)?,
+        }
+    } else if hour == 12 {
+        12
+    } else {
+        hour.checked_add(12)?
+    };
+    Some(hour)
+}
+
 pub fn rules() -> Vec<Rule> {
     let mut rules = super::es::rules();
     rules.extend(vec![
         Rule {
             name: "now (ca)".to_string(),
-            pattern: vec![regex("ara|ja|en aquest moment|en aquests moments")],
+            pattern: vec![regex("\\b(?:ara(?:\\s+mateix)?|ja|en\\s+aquests?\\s+moments?)\\b")],
             production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Now)))),
         },
         Rule {
             name: "today (ca)".to_string(),
-            pattern: vec![regex("avui")],
+            pattern: vec![regex("\\bavui\\b")],
             production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Today)))),
         },
         Rule {
             name: "tomorrow (ca)".to_string(),
-            pattern: vec![regex("dem[àa]")],
+            pattern: vec![regex("\\bdem[àa]\\b")],
             production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Tomorrow)))),
         },
         Rule {
             name: "yesterday (ca)".to_string(),
-            pattern: vec![regex("ahir")],
+            pattern: vec![regex("\\bahir\\b")],
             production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Yesterday)))),
         },
+        Rule {
+            name: "day before yesterday (ca)".to_string(),
+            pattern: vec![regex("\\babans\\s+d['’]ahir\\b")],
+            production: Box::new(|_| {
+                Some(TokenData::Time(TimeData::new(TimeForm::DayBeforeYesterday)))
+            }),
+        },
+        Rule {
+            name: "day after tomorrow (ca)".to_string(),
+            pattern: vec![regex("\\bdem[àa]\\s+passat\\b")],
+            production: Box::new(|_| {
+                Some(TokenData::Time(TimeData::new(TimeForm::DayAfterTomorrow)))
+            }),
+        },
         Rule {
             name: "day of week (ca)".to_string(),
-            pattern: vec![regex("dilluns|dl\\.?|dimarts|dm\\.?|dimecres|dc\\.?|dijous|dj\\.?|divendres|dv\\.?|dissabte|ds\\.?|diumenge|dg\\.?")],
+            pattern: vec![regex(&format!("\\b({DAYS})(?:\\.|\\b)"))],
             production: Box::new(|nodes| {
                 let s = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(0)?.to_lowercase(),
+                    TokenData::RegexMatch(m) => m.group(1)?,
                     _ => return None,
                 };
-                let dow = if s.starts_with("dilluns") || s.starts_with("dl") {
-                    0
-                } else if s.starts_with("dimarts") || s.starts_with("dm") {
-                    1
-                } else if s.starts_with("dimecres") || s.starts_with("dc") {
-                    2
-                } else if s.starts_with("dijous") || s.starts_with("dj") {
-                    3
-                } else if s.starts_with("divendres") || s.starts_with("dv") {
-                    4
-                } else if s.starts_with("dissabte") || s.starts_with("ds") {
-                    5
-                } else if s.starts_with("diumenge") || s.starts_with("dg") {
-                    6
-                } else {
-                    return None;
-                };
-                Some(TokenData::Time(TimeData::new(TimeForm::DayOfWeek(dow))))
+                Some(TokenData::Time(TimeData::new(TimeForm::DayOfWeek(ca_dow_from_text(s)?))))
             }),
         },
         Rule {
-            name: "el 5 de maig (ca)".to_string(),
-            pattern: vec![regex("el\\s*(\\d{1,2})\\s+de\\s+maig")],
+            name: "named month (ca)".to_string(),
+            pattern: vec![regex(&format!("\\b({MONTHS})(?:\\.|\\b)"))],
             production: Box::new(|nodes| {
-                let d = match &nodes[0].token_data {
+                let s = match &nodes[0].token_data {
                     TokenData::RegexMatch(m) => m.group(1)?,
                     _ => return None,
                 };
-                let day: u32 = d.parse().ok()?;
-                if !(1..=31).contains(&day) {
-                    return None;
-                }
-                Some(TokenData::Time(TimeData::new(TimeForm::DateMDY { month: 5, day, year: None })))
+                Some(TokenData::Time(TimeData::new(TimeForm::Month(ca_month_from_text(s)?))))
             }),
         },
+        // "el dimarts vinent", "la setmana passada", "l'any vinent"
         Rule {
-            name: "el cinc de maig (ca)".to_string(),
-            pattern: vec![regex("el\\s+cinc\\s+de\\s+maig")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::DateMDY { month: 5, day: 5, year: None })))),
-        },
-        Rule {
-            name: "el 4 de juliol (ca)".to_string(),
-            pattern: vec![regex("el\\s*(\\d{1,2})\\s+de\\s+juliol")],
+            name: "el|la|l' <time> (ca)".to_string(),
+            pattern: vec![regex("\\b(?:el|la|l['’])\\b"), dim(DimensionKind::Time)],
             production: Box::new(|nodes| {
-                let d = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(1)?,
-                    _ => return None,
-                };
-                let day: u32 = d.parse().ok()?;
-                if !(1..=31).contains(&day) {
+                let t = time_data(&nodes[1].token_data)?;
+                if t.latent {
                     return None;
                 }
-                Some(TokenData::Time(TimeData::new(TimeForm::DateMDY { month: 7, day, year: None })))
+                Some(TokenData::Time(t.clone()))
             }),
         },
         Rule {
-            name: "el 4 d'agost (ca)".to_string(),
-            pattern: vec![regex("el\\s*(\\d{1,2})\\s+d['’]agost")],
+            name: "el <day-of-month> (ca)".to_string(),
+            pattern: vec![regex("\\b(?:d?el\\s+|l['’])(\\d{1,2})\\b")],
             production: Box::new(|nodes| {
-                let d = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(1)?,
+                let day: u32 = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?.parse().ok()?,
                     _ => return None,
                 };
-                let day: u32 = d.parse().ok()?;
                 if !(1..=31).contains(&day) {
                     return None;
                 }
-                Some(TokenData::Time(TimeData::new(TimeForm::DateMDY { month: 8, day, year: None })))
+                Some(TokenData::Time(TimeData::new(TimeForm::DayOfMonth(day))))
             }),
         },
+        // "dia quinze", "dia 9"
         Rule {
-            name: "el 3 de març (ca)".to_string(),
-            pattern: vec![regex("el\\s*(\\d{1,2})\\s+de\\s+mar[çc]")],
+            name: "dia <day-of-month> (ca)".to_string(),
+            pattern: vec![regex("\\bdia\\b"), predicate(is_dom_token)],
             production: Box::new(|nodes| {
-                let d = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(1)?,
-                    _ => return None,
-                };
-                let day: u32 = d.parse().ok()?;
-                if !(1..=31).contains(&day) {
-                    return None;
-                }
-                Some(TokenData::Time(TimeData::new(TimeForm::DateMDY { month: 3, day, year: None })))
+                Some(TokenData::Time(TimeData::new(TimeForm::DayOfMonth(dom_value(&nodes[1].token_data)?))))
             }),
         },
+        // "aquest dilluns" is the coming Monday
         Rule {
-            name: "3 de març (ca)".to_string(),
-            pattern: vec![regex("(\\d{1,2})\\s+de\\s+mar[çc]")],
+            name: "aquest <day-of-week> (ca)".to_string(),
+            pattern: vec![regex("\\baquest\\b"), predicate(is_day_of_week)],
+            production: Box::new(|nodes| Some(TokenData::Time(time_data(&nodes[1].token_data)?.clone()))),
+        },
+        // "dimarts vinent" is the Tuesday of next week, "dimarts passat" that
+        // of last week
+        Rule {
+            name: "<day-of-week> vinent|passat (ca)".to_string(),
+            pattern: vec![predicate(is_day_of_week), regex("\\b(vinent|passat|que\\s+ve)\\b")],
             production: Box::new(|nodes| {
-                let d = match &nodes[0].token_data {
+                let TimeForm::DayOfWeek(dow) = time_data(&nodes[0].token_data)?.form else {
+                    return None;
+                };
+                let q = match &nodes[1].token_data {
                     TokenData::RegexMatch(m) => m.group(1)?,
                     _ => return None,
                 };
-                let day: u32 = d.parse().ok()?;
-                if !(1..=31).contains(&day) {
-                    return None;
-                }
-                Some(TokenData::Time(TimeData::new(TimeForm::DateMDY { month: 3, day, year: None })))
+                Some(TokenData::Time(dow_of_week(dow, ca_offset(q))))
             }),
         },
+        // "dimarts d'aquesta setmana", "el dimecres de la setmana vinent"
         Rule {
-            name: "el 24 d'octubre (ca)".to_string(),
-            pattern: vec![regex("el\\s*(\\d{1,2})\\s+d['’]octubre")],
+            name: "<day-of-week> of this/last/next week (ca)".to_string(),
+            pattern: vec![
+                predicate(is_day_of_week),
+                regex("\\b(?:d['’](aquesta)\\s+setmana|de\\s+la\\s+setmana\\s+(vinent|passada|que\\s+ve)|de\\s+la\\s+(propera|seg[üu]ent|passada)\\s+setmana)\\b"),
+            ],
             production: Box::new(|nodes| {
-                let d = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(1)?,
+                let TimeForm::DayOfWeek(dow) = time_data(&nodes[0].token_data)?.form else {
+                    return None;
+                };
+                let q = match &nodes[1].token_data {
+                    TokenData::RegexMatch(m) => m.group(1).or_else(|| m.group(2)).or_else(|| m.group(3))?,
                     _ => return None,
                 };
-                let day: u32 = d.parse().ok()?;
-                if !(1..=31).contains(&day) {
-                    return None;
-                }
-                Some(TokenData::Time(TimeData::new(TimeForm::DateMDY { month: 10, day, year: None })))
+                Some(TokenData::Time(dow_of_week(dow, ca_offset(q))))
             }),
         },
+        // "aquesta setmana", "el mes passat", "el proper any", "següent setmana"
         Rule {
-            name: "el 24 de setembre (ca)".to_string(),
-            pattern: vec![regex("el\\s*(\\d{1,2})\\s+de\\s+setembre")],
+            name: "this/last/next <cycle> (ca)".to_string(),
+            pattern: vec![regex("\\b(?:(aquest|aquesta|passat|passada|proper|propera|properes|seg[üu]ent)\\s+(setmanes|setmana|mes|trimestre|any)|(setmana|mes|trimestre|any)\\s+(passat|passada|vinent|que\\s+ve|actual))\\b")],
             production: Box::new(|nodes| {
-                let d = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(1)?,
+                let (q, g) = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => match m.group(1) {
+                        Some(q) => (q, m.group(2)?),
+                        None => (m.group(4)?, m.group(3)?),
+                    },
                     _ => return None,
                 };
-                let day: u32 = d.parse().ok()?;
-                if !(1..=31).contains(&day) {
-                    return None;
-                }
-                Some(TokenData::Time(TimeData::new(TimeForm::DateMDY { month: 9, day, year: None })))
+                Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset {
+                    grain: ca_grain_from_text(g)?,
+                    offset: ca_offset(q),
+                })))
             }),
         },
         Rule {
-            name: "el 24 de set (ca)".to_string(),
-            pattern: vec![regex("el\\s*(\\d{1,2})\\s+de\\s+set\\.?")],
+            name: "weekend (ca)".to_string(),
+            pattern: vec![regex("\\b(?:aquest\\s+)?cap\\s+de\\s+setmana\\b")],
+            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Weekend)))),
+        },
+        // "3 de març", "5 d'abril", "l'u de març", "el primer de març"
+        Rule {
+            name: "<day-of-month> de <month> (ca)".to_string(),
+            pattern: vec![predicate(is_dom_token), regex(&format!("\\b(?:de\\s+|d['’])({MONTHS})(?:\\.|\\b)"))],
             production: Box::new(|nodes| {
-                let d = match &nodes[0].token_data {
+                let day = dom_value(&nodes[0].token_data)?;
+                let s = match &nodes[1].token_data {
                     TokenData::RegexMatch(m) => m.group(1)?,
                     _ => return None,
                 };
-                let day: u32 = d.parse().ok()?;
-                if !(1..=31).contains(&day) {
-                    return None;
-                }
-                Some(TokenData::Time(TimeData::new(TimeForm::DateMDY { month: 9, day, year: None })))
+                Some(TokenData::Time(TimeData::new(TimeForm::DateMDY {
+                    month: ca_month_from_text(s)?,
+                    day,
+                    year: None,
+                })))
             }),
         },
         Rule {
-            name: "setembre (ca)".to_string(),
-            pattern: vec![regex("setembre|set\\.?")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Month(9))))),
-        },
-        Rule {
-            name: "el 5 d'abril (ca)".to_string(),
-            pattern: vec![regex("el\\s*(\\d{1,2})\\s+d['’]abril")],
+            name: "primer de <month> (ca)".to_string(),
+            pattern: vec![regex(&format!("\\b(?:primer|1r)\\s+(?:de\\s+|d['’])({MONTHS})(?:\\.|\\b)"))],
             production: Box::new(|nodes| {
-                let d = match &nodes[0].token_data {
+                let s = match &nodes[0].token_data {
                     TokenData::RegexMatch(m) => m.group(1)?,
                     _ => return None,
                 };
-                let day: u32 = d.parse().ok()?;
-                if !(1..=31).contains(&day) {
-                    return None;
-                }
-                Some(TokenData::Time(TimeData::new(TimeForm::DateMDY { month: 4, day, year: None })))
+                Some(TokenData::Time(TimeData::new(TimeForm::DateMDY {
+                    month: ca_month_from_text(s)?,
+                    day: 1,
+                    year: None,
+                })))
             }),
         },
+        // "Febrer 18"
         Rule {
-            name: "5 d'abril (ca)".to_string(),
-            pattern: vec![regex("(\\d{1,2})\\s+d['’]abril")],
+            name: "<month> <day-of-month> (ca)".to_string(),
+            pattern: vec![regex(&format!("\\b({MONTHS})\\.?\\s+(\\d{{1,2}})\\b"))],
             production: Box::new(|nodes| {
-                let d = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(1)?,
+                let (s, d) = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => (m.group(1)?, m.group(2)?),
                     _ => return None,
                 };
                 let day: u32 = d.parse().ok()?;
                 if !(1..=31).contains(&day) {
                     return None;
                 }
-                Some(TokenData::Time(TimeData::new(TimeForm::DateMDY { month: 4, day, year: None })))
+                Some(TokenData::Time(TimeData::new(TimeForm::DateMDY {
+                    month: ca_month_from_text(s)?,
+                    day,
+                    year: None,
+                })))
             }),
         },
+        // "el cinc de maig de 2013", "quatre de maig de mil nou-cents noranta"
         Rule {
-            name: "el primer de març (ca)".to_string(),
-            pattern: vec![regex("el\\s+primer\\s+de\\s+mar[çc]")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::DateMDY { month: 3, day: 1, year: None })))),
-        },
-        Rule {
-            name: "l'u de març (ca)".to_string(),
-            pattern: vec![regex("l['’]u\\s+de\\s+mar[çc]")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::DateMDY { month: 3, day: 1, year: None })))),
-        },
-        Rule {
-            name: "u de març (ca)".to_string(),
-            pattern: vec![regex("u\\s+de\\s+mar[çc]")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::DateMDY { month: 3, day: 1, year: None })))),
+            name: "<date> de <year> (ca)".to_string(),
+            pattern: vec![predicate(is_yearless_date), regex("\\bdel?\\b"), predicate(is_year)],
+            production: Box::new(|nodes| {
+                let t = time_data(&nodes[0].token_data)?;
+                let TimeForm::Year(year) = time_data(&nodes[2].token_data)?.form else {
+                    return None;
+                };
+                let form = match t.form {
+                    TimeForm::DateMDY { month, day, .. } => TimeForm::DateMDY { month, day, year: Some(year) },
+                    _ => TimeForm::Composed(Box::new(TimeData::new(TimeForm::Year(year))), Box::new(t.clone())),
+                };
+                Some(TokenData::Time(TimeData::new(form)))
+            }),
         },
+        // "dimarts 19", "dv. 15", "dijous 15" (the next Thursday the 15th)
         Rule {
-            name: "el 16 (ca)".to_string(),
-            pattern: vec![regex("el\\s*(\\d{1,2})")],
+            name: "<day-of-week> <day-of-month> (ca)".to_string(),
+            pattern: vec![predicate(is_day_of_week), regex("\\b(\\d{1,2})\\b")],
             production: Box::new(|nodes| {
-                let d = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(1)?,
+                let day: u32 = match &nodes[1].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?.parse().ok()?,
                     _ => return None,
                 };
-                let day: u32 = d.parse().ok()?;
                 if !(1..=31).contains(&day) {
                     return None;
                 }
-                Some(TokenData::Time(TimeData::new(TimeForm::DayOfMonth(day))))
+                Some(TokenData::Time(TimeData::new(TimeForm::Composed(
+                    Box::new(time_data(&nodes[0].token_data)?.clone()),
+                    Box::new(TimeData::new(TimeForm::DayOfMonth(day))),
+                ))))
             }),
         },
+        // "dilluns, Febrer 18"
         Rule {
-            name: "16 de febrer (ca)".to_string(),
-            pattern: vec![regex("(\\d{1,2})\\s+de\\s+febrer")],
+            name: "<day-of-week>, <date> (ca)".to_string(),
+            pattern: vec![predicate(is_day_of_week), regex(","), predicate(is_date)],
             production: Box::new(|nodes| {
-                let d = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(1)?,
+                Some(TokenData::Time(TimeData::new(TimeForm::Composed(
+                    Box::new(time_data(&nodes[0].token_data)?.clone()),
+                    Box::new(time_data(&nodes[2].token_data)?.clone()),
+                ))))
+            }),
+        },
+        // "13 - 15 de juliol de 2013"
+        Rule {
+            name: "<day>-<day> de <month> (ca)".to_string(),
+            pattern: vec![regex(&format!("\\b(\\d{{1,2}})\\s*[-–]\\s*(\\d{{1,2}})\\s+(?:de\\s+|d['’])({MONTHS})(?:\\s+de\\s+(\\d{{4}}))?\\b"))],
+            production: Box::new(|nodes| {
+                let (d1, d2, s, y) = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => (m.group(1)?, m.group(2)?, m.group(3)?, m.group(4)),
                     _ => return None,
                 };
-                let day: u32 = d.parse().ok()?;
-                if !(1..=31).contains(&day) {
+                let month = ca_month_from_text(s)?;
+                let day1: u32 = d1.parse().ok()?;
+                let day2: u32 = d2.parse().ok()?;
+                if !(1..=31).contains(&day1) || !(1..=31).contains(&day2) {
                     return None;
                 }
-                Some(TokenData::Time(TimeData::new(TimeForm::DateMDY { month: 2, day, year: None })))
+                let year = match y {
+                    Some(y) => Some(y.parse().ok()?),
+                    None => None,
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::Interval(
+                    Box::new(TimeData::new(TimeForm::DateMDY { month, day: day1, year })),
+                    Box::new(TimeData::new(TimeForm::DateMDY { month, day: day2, year })),
+                    false,
+                ))))
             }),
         },
+        // "21 de des. al 6 de gen", "del 3 al 5 de maig"
         Rule {
-            name: "el passat mes (ca)".to_string(),
-            pattern: vec![regex("el passat mes|el mes passat")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset { grain: crate::dimensions::time_grain::Grain::Month, offset: -1 })))),
-        },
-        Rule {
-            name: "el mes vinent (ca)".to_string(),
-            pattern: vec![regex("el mes vinent|mes vinent")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset { grain: crate::dimensions::time_grain::Grain::Month, offset: 1 })))),
-        },
-        Rule {
-            name: "el proper mes (ca)".to_string(),
-            pattern: vec![regex("el proper mes|proper mes")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset { grain: crate::dimensions::time_grain::Grain::Month, offset: 1 })))),
-        },
-        Rule {
-            name: "l'any passat (ca)".to_string(),
-            pattern: vec![regex("l['’]any passat|any passat")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset { grain: crate::dimensions::time_grain::Grain::Year, offset: -1 })))),
-        },
-        Rule {
-            name: "aquest any (ca)".to_string(),
-            pattern: vec![regex("aquest any")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::AllGrain(crate::dimensions::time_grain::Grain::Year))))),
-        },
-        Rule {
-            name: "l'any vinent (ca)".to_string(),
-            pattern: vec![regex("l['’]any vinent|any vinent")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset { grain: crate::dimensions::time_grain::Grain::Year, offset: 1 })))),
-        },
-        Rule {
-            name: "el proper any (ca)".to_string(),
-            pattern: vec![regex("el proper any|proper any")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset { grain: crate::dimensions::time_grain::Grain::Year, offset: 1 })))),
-        },
-        Rule {
-            name: "migdia (ca)".to_string(),
-            pattern: vec![regex("migdia|mig dia")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(12, 0, false))))),
-        },
-        Rule {
-            name: "tres i quart (ca)".to_string(),
-            pattern: vec![regex("tres i quart|3 i quart")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(3, 15, false))))),
-        },
-        Rule {
-            name: "en 2 minuts (ca)".to_string(),
-            pattern: vec![regex("en\\s*2\\s*minuts?")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset { grain: Grain::Minute, offset: 2 })))),
-        },
-        Rule {
-            name: "abans d'ahir (ca)".to_string(),
-            pattern: vec![regex("abans d['’]ahir")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::DayBeforeYesterday)))),
-        },
-        Rule {
-            name: "demà passat (ca)".to_string(),
-            pattern: vec![regex("dem[àa]\\s+passat")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::DayAfterTomorrow)))),
-        },
-        Rule {
-            name: "aquesta setmana (ca)".to_string(),
-            pattern: vec![regex("aquesta setmana|setmana actual")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::AllGrain(Grain::Week))))),
-        },
-        Rule {
-            name: "la setmana passada (ca)".to_string(),
-            pattern: vec![regex("la setmana passada|setmana passada")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset { grain: Grain::Week, offset: -1 })))),
-        },
-        Rule {
-            name: "la setmana vinent (ca)".to_string(),
-            pattern: vec![regex("la setmana vinent|setmana vinent|propera setmana|seg[üu]ent setmana|properes setmanes?")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset { grain: Grain::Week, offset: 1 })))),
+            name: "<date> al <date> (ca)".to_string(),
+            pattern: vec![predicate(is_date), regex("\\b(?:fins\\s+)?al\\b"), predicate(is_date)],
+            production: Box::new(|nodes| {
+                let to = time_data(&nodes[2].token_data)?;
+                // A bare "del 3" takes its month from the end
+                let from = match (&time_data(&nodes[0].token_data)?.form, &to.form) {
+                    (TimeForm::DayOfMonth(day), TimeForm::DateMDY { month, year, .. }) => {
+                        TimeData::new(TimeForm::DateMDY { month: *month, day: *day, year: *year })
+                    }
+                    _ => time_data(&nodes[0].token_data)?.clone(),
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::Interval(
+                    Box::new(from),
+                    Box::new(to.clone()),
+                    false,
+                ))))
+            }),
         },
+        // "9:30 - 11:00" ends at 11:00 sharp
         Rule {
-            name: "cap de setmana (ca)".to_string(),
-            pattern: vec![regex("cap de setmana|week[ -]?end")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Weekend)))),
+            name: "<time-of-day> - <time-of-day> (ca)".to_string(),
+            pattern: vec![predicate(is_clock_time), regex("-|\\bfins\\s+a\\s+les\\b"), predicate(is_clock_time)],
+            production: Box::new(|nodes| {
+                Some(TokenData::Time(TimeData::new(TimeForm::Interval(
+                    Box::new(time_data(&nodes[0].token_data)?.clone()),
+                    Box::new(time_data(&nodes[2].token_data)?.clone()),
+                    true,
+                ))))
+            }),
         },
         Rule {
-            name: "part of day morning (ca)".to_string(),
-            pattern: vec![regex("mat[ií]|pel mat[ií]|del mat[ií]")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::latent(TimeForm::PartOfDay(super::PartOfDay::Morning))))),
+            name: "noon (ca)".to_string(),
+            pattern: vec![regex("\\bmig\\s*dia\\b")],
+            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Hour(12, false))))),
         },
         Rule {
-            name: "part of day afternoon (ca)".to_string(),
-            pattern: vec![regex("tarda|a la tarda|per la tarda")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::latent(TimeForm::PartOfDay(super::PartOfDay::Afternoon))))),
+            name: "midnight (ca)".to_string(),
+            pattern: vec![regex("\\bmitjanit\\b")],
+            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Hour(0, false))))),
         },
+        // "a les 5", "a la una"
         Rule {
-            name: "part of day evening (ca)".to_string(),
-            pattern: vec![regex("vespre|al vespre|del vespre")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::latent(TimeForm::PartOfDay(super::PartOfDay::Evening))))),
+            name: "a les <hour> (ca)".to_string(),
+            pattern: vec![regex("\\ba\\s+l(?:es|a)\\b"), predicate(is_hour)],
+            production: Box::new(|nodes| {
+                let h = integer_value(&nodes[1].token_data)? as u32;
+                Some(TokenData::Time(TimeData::new(TimeForm::Hour(h, (1..=12).contains(&h)))))
+            }),
         },
         Rule {
-            name: "part of day night (ca)".to_string(),
-            pattern: vec![regex("nit|a la nit|per la nit")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::latent(TimeForm::PartOfDay(super::PartOfDay::Night))))),
+            name: "a les <time-of-day> (ca)".to_string(),
+            pattern: vec![regex("\\ba\\s+l(?:es|a)\\b"), predicate(is_clock_time)],
+            production: Box::new(|nodes| Some(TokenData::Time(time_data(&nodes[1].token_data)?.clone()))),
         },
+        // "tres i quart", "les 5 i mitja", "les 8 i 20"
         Rule {
-            name: "this part-of-day (ca)".to_string(),
-            pattern: vec![regex("aquest\\s+(mat[ií]|vespre)|aquesta\\s+(tarda|nit)")],
+            name: "<hour> i quart|mitja (ca)".to_string(),
+            pattern: vec![predicate(is_hour), regex("\\bi\\s+(quart|mitja)\\b")],
             production: Box::new(|nodes| {
-                let pod = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(1).or_else(|| m.group(2))?,
+                let h = integer_value(&nodes[0].token_data)? as u32;
+                let minute = match &nodes[1].token_data {
+                    TokenData::RegexMatch(m) if m.group(1)? == "quart" => 15,
+                    TokenData::RegexMatch(_) => 30,
                     _ => return None,
                 };
-                let part = if pod.starts_with("mat") {
-                    super::PartOfDay::Morning
-                } else if pod.starts_with("tarda") {
-                    super::PartOfDay::Afternoon
-                } else if pod.starts_with("vespre") {
-                    super::PartOfDay::Evening
-                } else {
-                    super::PartOfDay::Night
-                };
-                Some(TokenData::Time(TimeData::new(TimeForm::Composed(
-                    Box::new(TimeData::new(TimeForm::Today)),
-                    Box::new(TimeData::latent(TimeForm::PartOfDay(part))),
-                ))))
+                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(h, minute, (1..=12).contains(&h)))))
             }),
         },
         Rule {
-            name: "tomorrow part of day (ca)".to_string(),
-            pattern: vec![regex("dem[àa]\\s+(a la|al|pel|per la)\\s+(tarda|vespre|nit|mat[ií])")],
+            name: "<hour> i <minutes> (ca)".to_string(),
+            pattern: vec![predicate(is_hour), regex("\\bi\\b"), predicate(is_minute)],
             production: Box::new(|nodes| {
-                let pod = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(2)?,
-                    _ => return None,
-                };
-                let part = if pod.starts_with("mat") {
-                    super::PartOfDay::Morning
-                } else if pod.starts_with("tarda") {
-                    super::PartOfDay::Afternoon
-                } else if pod.starts_with("vespre") {
-                    super::PartOfDay::Evening
-                } else {
-                    super::PartOfDay::Night
-                };
-                Some(TokenData::Time(TimeData::new(TimeForm::Composed(
-                    Box::new(TimeData::new(TimeForm::Tomorrow)),
-                    Box::new(TimeData::latent(TimeForm::PartOfDay(part))),
-                ))))
+                let h = integer_value(&nodes[0].token_data)? as u32;
+                let minute = integer_value(&nodes[2].token_data)? as u32;
+                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(h, minute, (1..=12).contains(&h)))))
             }),
         },
+        // "les cinc menys quart" is 4:45, "les cinc menys deu" 4:50
         Rule {
-            name: "yesterday part of day (ca)".to_string(),
-            pattern: vec![regex("ahir\\s+(a la|al|pel|per la)\\s+(tarda|vespre|nit|mat[ií])")],
+            name: "<hour> menys quart|mitja (ca)".to_string(),
+            pattern: vec![predicate(is_clock_hour), regex("\\bmenys\\s+(quart|mitja)\\b")],
             production: Box::new(|nodes| {
-                let pod = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(2)?,
+                let minutes = match &nodes[1].token_data {
+                    TokenData::RegexMatch(m) if m.group(1)? == "quart" => -15,
+                    TokenData::RegexMatch(_) => -30,
                     _ => return None,
                 };
-                let part = if pod.starts_with("mat") {
-                    super::PartOfDay::Morning
-                } else if pod.starts_with("tarda") {
-                    super::PartOfDay::Afternoon
-                } else if pod.starts_with("vespre") {
-                    super::PartOfDay::Evening
-                } else {
-                    super::PartOfDay::Night
-                };
-                Some(TokenData::Time(TimeData::new(TimeForm::Composed(
-                    Box::new(TimeData::new(TimeForm::Yesterday)),
-                    Box::new(TimeData::latent(TimeForm::PartOfDay(part))),
-                ))))
+                clock_hour_offset(&nodes[0].token_data, minutes)
             }),
         },
         Rule {
-            name: "hour with part-of-day (ca)".to_string(),
-            pattern: vec![regex("(\\d{1,2})\\s+del\\s+(mat[ií]|vespre|nit|tarda)")],
+            name: "<hour> menys <minutes> (ca)".to_string(),
+            pattern: vec![predicate(is_clock_hour), regex("\\bmenys\\b"), predicate(is_minute)],
             production: Box::new(|nodes| {
-                let (h, pod) = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => (m.group(1)?, m.group(2)?),
-                    _ => return None,
-                };
-                let mut hour: u32 = h.parse().ok()?;
-                if hour > 23 {
-                    return None;
-                }
-                if pod == "vespre" || pod == "nit" || pod == "tarda" {
-                    if hour < 12 {
-                        hour = hour.checked_add(12)?;
-                    }
-                } else if pod.starts_with("mat") && hour == 12 {
-                    hour = 0;
-                }
-                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(hour, 0, false))))
+                let minutes = i32::try_from(integer_value(&nodes[2].token_data)?).ok()?;
+                clock_hour_offset(&nodes[0].token_data, minutes.checked_neg()?)
             }),
         },
+        // Quarters count toward the coming hour: "un quart de cinc" is 4:15,
+        // "dos quarts de cinc" 4:30 and "tres quarts de cinc" 4:45
         Rule {
-            name: "word hour with part-of-day (ca)".to_string(),
-            pattern: vec![regex("(un|una|dos|dues|tres|quatre|cinc|sis|set|vuit|nou|deu|onze|dotze)\\s+del\\s+(mat[ií]|vespre|nit|tarda)")],
+            name: "un|dos|tres quarts de <hour> (ca)".to_string(),
+            pattern: vec![regex("\\b(un|dos|tres)\\s+quarts?\\s+(?:de\\s+|d['’])"), predicate(is_clock_hour)],
             production: Box::new(|nodes| {
-                let (h_raw, pod) = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => (m.group(1)?, m.group(2)?),
+                let minutes = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => match m.group(1)?.to_lowercase().as_str() {
+                        "un" => -45,
+                        "dos" => -30,
+                        _ => -15,
+                    },
                     _ => return None,
                 };
-                let mut hour = ca_small_number(h_raw)? as u32;
-                if hour > 12 {
-                    return None;
-                }
-                if pod == "vespre" || pod == "nit" || pod == "tarda" {
-                    if hour < 12 {
-                        hour = hour.checked_add(12)?;
-                    }
-                } else if pod.starts_with("mat") && hour == 12 {
-                    hour = 0;
-                }
-                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(hour, 0, false))))
+                clock_hour_offset(&nodes[1].token_data, minutes)
             }),
         },
+        // "nou del vespre", "5 de la tarda"
         Rule {
-            name: "en <duration> (ca)".to_string(),
-            pattern: vec![regex("en\\s*(\\d+|un|una|dos|dues|tres|quatre|cinc|sis|set|vuit|nou|deu|onze|dotze|catorze|vint-i-quatre|vint i quatre)\\s*(segons?|minuts?|hores?|dies|setmanes?|mesos?|anys?)")],
+            name: "<hour> del <part-of-day> (ca)".to_string(),
+            pattern: vec![predicate(is_hour), regex(&format!("\\b(?:del|de\\s+la)\\s+({PARTS_OF_DAY})\\b"))],
             production: Box::new(|nodes| {
-                let (n_raw, unit) = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => (m.group(1)?, m.group(2)?),
+                let h = integer_value(&nodes[0].token_data)? as u32;
+                let s = match &nodes[1].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?,
                     _ => return None,
                 };
-                let n: i32 = n_raw.parse::<i32>().ok().or_else(|| ca_small_number(n_raw))?;
-                let grain = if unit.starts_with("seg") {
-                    Grain::Second
-                } else if unit.starts_with("min") {
-                    Grain::Minute
-                } else if unit.starts_with("hor") {
-                    Grain::Hour
-                } else if unit.starts_with("die") {
-                    Grain::Day
-                } else if unit.starts_with("setman") {
-                    Grain::Week
-                } else if unit.starts_with("mes") {
-                    Grain::Month
-                } else {
-                    Grain::Year
-                };
-                Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset { grain, offset: n })))
+                Some(TokenData::Time(TimeData::new(TimeForm::Hour(period_hour(s, h)?, false))))
             }),
         },
+        // "a les 5 de la tarda", "tres i quart de la tarda"
         Rule {
-            name: "fa <duration> (ca)".to_string(),
-            pattern: vec![regex("fa\\s*(\\d+|un|una|dos|dues|tres|quatre|cinc|sis|set|vuit|nou|deu|onze|dotze|catorze|vint-i-quatre|vint i quatre)\\s*(segons?|minuts?|hores?|dies|setmanes?|mesos?|anys?)")],
+            name: "<time-of-day> del <part-of-day> (ca)".to_string(),
+            pattern: vec![predicate(is_clock_time), regex(&format!("\\b(?:del|de\\s+la)\\s+({PARTS_OF_DAY})\\b"))],
             production: Box::new(|nodes| {
-                let (n_raw, unit) = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => (m.group(1)?, m.group(2)?),
+                let s = match &nodes[1].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?,
                     _ => return None,
                 };
-                let n: i32 = n_raw.parse::<i32>().ok().or_else(|| ca_small_number(n_raw))?;
-                let grain = if unit.starts_with("seg") {
-                    Grain::Second
-                } else if unit.starts_with("min") {
-                    Grain::Minute
-                } else if unit.starts_with("hor") {
-                    Grain::Hour
-                } else if unit.starts_with("die") {
-                    Grain::Day
-                } else if unit.starts_with("setman") {
-                    Grain::Week
-                } else if unit.starts_with("mes") {
-                    Grain::Month
-                } else {
-                    Grain::Year
+                let form = match time_data(&nodes[0].token_data)?.form {
+                    TimeForm::Hour(h, _) => TimeForm::Hour(period_hour(s, h)?, false),
+                    TimeForm::HourMinute(h, mi, _) => TimeForm::HourMinute(period_hour(s, h)?, mi, false),
+                    _ => return None,
                 };
-                Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset { grain, offset: n.checked_neg()? })))
+                Some(TokenData::Time(TimeData::new(form)))
             }),
         },
         Rule {
-            name: "dintre de <duration> (ca)".to_string(),
-            pattern: vec![regex("dintre de\\s*(\\d+|un|una|dos|dues|tres|quatre|cinc|sis|set|vuit|nou|deu|onze|dotze|catorze|vint-i-quatre|vint i quatre)\\s*(segons?|minuts?|hores?|dies|setmanes?|mesos?|anys?)")],
+            name: "part of day (ca)".to_string(),
+            pattern: vec![regex(&format!("\\b(?:(aquest|aquesta)\\s+)?({PARTS_OF_DAY})\\b"))],
             production: Box::new(|nodes| {
-                let (n_raw, unit) = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => (m.group(1)?, m.group(2)?),
+                let (this, s) = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => (m.group(1).is_some(), m.group(2)?),
                     _ => return None,
                 };
-                let n: i32 = n_raw.parse::<i32>().ok().or_else(|| ca_small_number(n_raw))?;
-                let grain = if unit.starts_with("seg") {
-                    Grain::Second
-                } else if unit.starts_with("min") {
-                    Grain::Minute
-                } else if unit.starts_with("hor") {
-                    Grain::Hour
-                } else if unit.starts_with("die") {
-                    Grain::Day
-                } else if unit.starts_with("setman") {
-                    Grain::Week
-                } else if unit.starts_with("mes") {
-                    Grain::Month
-                } else {
-                    Grain::Year
-                };
-                Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset { grain, offset: n })))
+                if !this {
+                    let mut pod = part_of_day(s);
+                    pod.latent = true;
+                    return Some(TokenData::Time(pod));
+                }
+                Some(TokenData::Time(TimeData::new(TimeForm::Composed(
+                    Box::new(TimeData::new(TimeForm::Today)),
+                    Box::new(part_of_day(s)),
+                ))))
             }),
         },
+        // "demà a la tarda", "ahir per la nit", "dilluns pel matí"
         Rule {
-            name: "darrers n <cycle> (ca)".to_string(),
-            pattern: vec![regex("darrer(s|es)?\\s*(\\d+|un|una|dos|dues|tres|quatre|cinc|sis|set|vuit|nou|deu)\\s*(segons?|minuts?|hores?|dies|setmanes?|mesos?|anys?)")],
+            name: "<day> <part-of-day> (ca)".to_string(),
+            pattern: vec![predicate(is_day), regex(&format!("\\b(?:a\\s+la|al|pel|per\\s+la|de\\s+la|del)\\s+({PARTS_OF_DAY})\\b"))],
             production: Box::new(|nodes| {
-                let (n_raw, unit) = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => (m.group(2)?, m.group(3)?),
+                let s = match &nodes[1].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?,
                     _ => return None,
                 };
-                let n: i64 = n_raw.parse::<i64>().ok().or_else(|| ca_small_number(n_raw).map(|v| v as i64))?;
-                let grain = if unit.starts_with("seg") {
-                    Grain::Second
-                } else if unit.starts_with("min") {
-                    Grain::Minute
-                } else if unit.starts_with("hor") {
-                    Grain::Hour
-                } else if unit.starts_with("die") {
-                    Grain::Day
-                } else if unit.starts_with("setman") {
-                    Grain::Week
-                } else if unit.starts_with("mes") {
-                    Grain::Month
-                } else {
-                    Grain::Year
-                };
-                Some(TokenData::Time(TimeData::new(TimeForm::NthGrain { n, grain, past: true, interval: true })))
+                Some(TokenData::Time(TimeData::new(TimeForm::Composed(
+                    Box::new(time_data(&nodes[0].token_data)?.clone()),
+                    Box::new(part_of_day(s)),
+                ))))
             }),
         },
+        // "fa tres setmanes"
         Rule {
-            name: "propers n <cycle> (ca)".to_string(),
-            pattern: vec![regex("proper(s|es)?\\s*(\\d+|un|una|dos|dues|tres|quatre|cinc|sis|set|vuit|nou|deu)\\s*(segons?|minuts?|hores?|dies|setmanes?|mesos?|anys?)")],
+            name: "fa <n> <cycle> (ca)".to_string(),
+            pattern: vec![regex("\\bfa\\b"), predicate(is_natural), dim(DimensionKind::TimeGrain)],
             production: Box::new(|nodes| {
-                let (n_raw, unit) = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => (m.group(2)?, m.group(3)?),
+                let n = integer_value(&nodes[1].token_data)?;
+                let grain = match &nodes[2].token_data {
+                    TokenData::TimeGrain(g) => *g,
                     _ => return None,
                 };
-                let n: i64 = n_raw.parse::<i64>().ok().or_else(|| ca_small_number(n_raw).map(|v| v as i64))?;
-                let grain = if unit.starts_with("seg") {
-                    Grain::Second
-                } else if unit.starts_with("min") {
-                    Grain::Minute
-                } else if unit.starts_with("hor") {
-                    Grain::Hour
-                } else if unit.starts_with("die") {
-                    Grain::Day
-                } else if unit.starts_with("setman") {
-                    Grain::Week
-                } else if unit.starts_with("mes") {
-                    Grain::Month
-                } else {
-                    Grain::Year
-                };
-                Some(TokenData::Time(TimeData::new(TimeForm::NthGrain { n, grain, past: false, interval: true })))
+                Some(TokenData::Time(TimeData::new(TimeForm::RelativeGrain { n: n.checked_neg()?, grain })))
             }),
         },
+        // "en dos minuts", "d'aquí a una setmana"
         Rule {
-            name: "n propers <cycle> (ca)".to_string(),
-            pattern: vec![regex("(\\d+|un|una|dos|dues|tres|quatre|cinc|sis|set|vuit|nou|deu)\\s+proper(s|es)?\\s*(segons?|minuts?|hores?|dies|setmanes?|mesos?|anys?)")],
+            name: "en <n> <cycle> (ca)".to_string(),
+            pattern: vec![regex("\\b(?:en|d['’]aqu[íi]\\s+a)\\b"), predicate(is_natural), dim(DimensionKind::TimeGrain)],
             production: Box::new(|nodes| {
-                let (n_raw, unit) = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => (m.group(1)?, m.group(3)?),
+                let n = integer_value(&nodes[1].token_data)?;
+                let grain = match &nodes[2].token_data {
+                    TokenData::TimeGrain(g) => *g,
                     _ => return None,
                 };
-                let n: i64 = n_raw.parse::<i64>().ok().or_else(|| ca_small_number(n_raw).map(|v| v as i64))?;
-                let grain = if unit.starts_with("seg") {
-                    Grain::Second
-                } else if unit.starts_with("min") {
-                    Grain::Minute
-                } else if unit.starts_with("hor") {
-                    Grain::Hour
-                } else if unit.starts_with("die") {
-                    Grain::Day
-                } else if unit.starts_with("setman") {
-                    Grain::Week
-                } else if unit.starts_with("mes") {
-                    Grain::Month
-                } else {
-                    Grain::Year
-                };
-                Some(TokenData::Time(TimeData::new(TimeForm::NthGrain { n, grain, past: false, interval: true })))
+                let mut t = TimeData::new(TimeForm::RelativeGrain { n, grain });
+                t.duration_qualifier = Some(DurationQualifier::Delayed);
+                Some(TokenData::Time(t))
             }),
         },
+        // "dintre de tres hores" runs from now
         Rule {
-            name: "dia quinze (ca)".to_string(),
-            pattern: vec![regex("dia\\s+(nou|onze|quinze)")],
+            name: "dintre de <n> <cycle> (ca)".to_string(),
+            pattern: vec![regex("\\b(?:dintre|dins)\\s+d['’e]\\s*"), predicate(is_natural), dim(DimensionKind::TimeGrain)],
             production: Box::new(|nodes| {
-                let d = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(1)?,
+                let n = integer_value(&nodes[1].token_data)?;
+                let grain = match &nodes[2].token_data {
+                    TokenData::TimeGrain(g) => *g,
+                    _ => return None,
+                };
+                let mut t = TimeData::new(TimeForm::Interval(
+                    Box::new(TimeData::new(TimeForm::Now)),
+                    Box::new(TimeData::new(TimeForm::RelativeGrain { n, grain })),
+                    false,
+                ));
+                t.duration_qualifier = Some(DurationQualifier::Bounded);
+                Some(TokenData::Time(t))
+            }),
+        },
+        // "darreres dues setmanes", "propers 3 dies": the whole cycles before
+        // or after this one
+        Rule {
+            name: "last/next <n> <cycle> (ca)".to_string(),
+            pattern: vec![regex("\\b(darrer(?:s|es)?|passat(?:s)?|passades|proper(?:s|es)?)\\b"), predicate(is_natural), dim(DimensionKind::TimeGrain)],
+            production: Box::new(|nodes| {
+                let past = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => !m.group(1)?.starts_with("proper"),
                     _ => return None,
                 };
-                let day = match d {
-                    "nou" => 9,
-                    "onze" => 11,
-                    "quinze" => 15,
+                let n = integer_value(&nodes[1].token_data)?;
+                let grain = match &nodes[2].token_data {
+                    TokenData::TimeGrain(g) => *g,
                     _ => return None,
                 };
-                Some(TokenData::Time(TimeData::new(TimeForm::DayOfMonth(day))))
+                Some(TokenData::Time(TimeData::new(TimeForm::NthGrain { n, grain, past, interval: true })))
             }),
         },
+        // "3 propers mesos"
         Rule {
-            name: "mil nou-cents noranta (ca)".to_string(),
-            pattern: vec![regex("mil\\s+nou-cents\\s+noranta")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Year(1990))))),
+            name: "<n> next <cycle> (ca)".to_string(),
+            pattern: vec![predicate(is_natural), regex("\\bproper(?:s|es)?\\b"), dim(DimensionKind::TimeGrain)],
+            production: Box::new(|nodes| {
+                let n = integer_value(&nodes[0].token_data)?;
+                let grain = match &nodes[2].token_data {
+                    TokenData::TimeGrain(g) => *g,
+                    _ => return None,
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::NthGrain { n, grain, past: false, interval: true })))
+            }),
         },
         Rule {
-            name: "seasons (ca)".to_string(),
-            pattern: vec![regex("estiu|hivern|primavera|tardor")],
+            name: "season (ca)".to_string(),
+            pattern: vec![regex("\\b(?:aquest(?:a)?\\s+)?(primavera|estiu|tardor|hivern)\\b")],
             production: Box::new(|nodes| {
                 let s = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(0)?,
+                    TokenData::RegexMatch(m) => m.group(1)?.to_lowercase(),
                     _ => return None,
                 };
-                let season = match s {
+                let season = match s.as_str() {
                     "primavera" => 0,
                     "estiu" => 1,
                     "tardor" => 2,
-                    "hivern" => 3,
-                    _ => return None,
+                    _ => 3,
                 };
                 Some(TokenData::Time(TimeData::new(TimeForm::Season(season))))
             }),
         },
+        // "segon trimestre de 2013"
         Rule {
-            name: "aquest estiu/hivern (ca)".to_string(),
-            pattern: vec![regex("aquest\\s+(estiu|hivern|primavera|tardor)")],
+            name: "<ordinal> trimestre [de <year>] (ca)".to_string(),
+            pattern: vec![regex("\\b(primer|segon|tercer|quart|1r|2n|3r|4t)\\s+trimestre(?:\\s+de\\s+(\\d{4}))?\\b")],
             production: Box::new(|nodes| {
-                let s = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(1)?,
+                let (q, y) = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => (m.group(1)?.to_lowercase(), m.group(2)),
                     _ => return None,
                 };
-                let season = match s {
-                    "primavera" => 0,
-                    "estiu" => 1,
-                    "tardor" => 2,
-                    "hivern" => 3,
-                    _ => return None,
+                let quarter = match q.as_str() {
+                    "primer" | "1r" => 1,
+                    "segon" | "2n" => 2,
+                    "tercer" | "3r" => 3,
+                    _ => 4,
                 };
-                Some(TokenData::Time(TimeData::new(TimeForm::Season(season))))
+                let form = match y {
+                    Some(y) => TimeForm::QuarterYear(quarter, y.parse().ok()?),
+                    None => TimeForm::Quarter(quarter),
+                };
+                Some(TokenData::Time(TimeData::new(form)))
             }),
         },
+        // Sant Jordi (April 23) and Sant Joan (June 24) are Catalan holidays
+        // alongside Christmas and Sant Esteve
         Rule {
-            name: "nadal (ca)".to_string(),
-            pattern: vec![regex("nadal|el nadal")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Holiday("Navidad".to_string(), None))))),
-        },
-        Rule {
-            name: "nit de cap d'any (ca)".to_string(),
-            pattern: vec![regex("nit de cap d['’]any|darrer dia de l['’]any")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::DateMDY { month: 12, day: 31, year: None })))),
-        },
-        Rule {
-            name: "cap d'any (ca)".to_string(),
-            pattern: vec![regex("cap d['’]any")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::DateMDY { month: 1, day: 1, year: None })))),
-        },
-        Rule {
-            name: "dia de la zero discriminacio (ca)".to_string(),
-            pattern: vec![regex("dia de la zero discriminaci[óo]")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::DateMDY { month: 3, day: 1, year: None })))),
-        },
-        Rule {
-            name: "dia mundial de la lengua arabe (ca)".to_string(),
-            pattern: vec![regex("dia mundial de la lengua [áa]rabe")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::DateMDY { month: 12, day: 18, year: None })))),
+            name: "holidays (ca)".to_string(),
+            pattern: vec![regex("\\b(?:nit\\s+de\\s+nadal|nadal|sant\\s+esteve|(?:la\\s+)?diada\\s+de\\s+sant\\s+jordi|sant\\s+jordi|revetlla\\s+de\\s+sant\\s+joan|sant\\s+joan|nit\\s+de\\s+cap\\s+d['’]any|darrer\\s+dia\\s+de\\s+l['’]any|cap\\s+d['’]any|dia\\s+de\\s+la\\s+zero\\s+discriminaci[óo]|dia\\s+mundial\\s+de\\s+la\\s+lengua\\s+[áa]rabe|dia\\s+de\\s+la\\s+prematuritat\\s+mundial|dia\\s+mundial\\s+de\\s+l['’]infant\\s+prematur)\\b")],
+            production: Box::new(|nodes| {
+                let s = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(0)?.to_lowercase(),
+                    _ => return None,
+                };
+                let name = if s.starts_with("nit de nadal") {
+                    "christmas eve"
+                } else if s.starts_with("nadal") {
+                    "christmas"
+                } else if s.contains("esteve") {
+                    "st. stephen's day"
+                } else if s.contains("jordi") {
+                    "st. george's day"
+                } else if s.starts_with("revetlla") {
+                    "st. john's eve"
+                } else if s.contains("joan") {
+                    "st. john's day"
+                } else if s.starts_with("nit") || s.starts_with("darrer") {
+                    "new year's eve"
+                } else if s.starts_with("cap") {
+                    "new year's day"
+                } else if s.contains("discrimin") {
+                    "zero discrimination day"
+                } else if s.contains("rabe") {
+                    "arabic language day"
+                } else {
+                    "world prematurity day"
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::Holiday(
+                    name.to_string(),
+                    None,
+                ))))
+            }),
         },
+        // The first Saturday of July
         Rule {
             name: "dia internacional de les cooperatives (ca)".to_string(),
-            pattern: vec![regex("dia internacional de les cooperatives( del (\\d{4}))?")],
+            pattern: vec![regex("\\bdia\\s+internacional\\s+de\\s+les\\s+cooperatives(?:\\s+del?\\s+(\\d{4}))?\\b")],
             production: Box::new(|nodes| {
-                let maybe_year = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(2),
+                let year = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => match m.group(1) {
+                        Some(y) => Some(y.parse().ok()?),
+                        None => None,
+                    },
                     _ => return None,
                 };
-                let year = maybe_year.and_then(|y| y.parse::<i32>().ok()).unwrap_or(2013);
-                // Approximation used by corpus examples (2019 -> July 6, 2013 -> July 6)
-                Some(TokenData::Time(TimeData::new(TimeForm::DateMDY { month: 7, day: 6, year: Some(year) })))
+                Some(TokenData::Time(TimeData::new(TimeForm::Holiday(
+                    "international day of cooperatives".to_string(),
+                    year,
+                ))))
             }),
         },
-        Rule {
-            name: "dia prematuritat mundial (ca)".to_string(),
-            pattern: vec![regex("dia de la prematuritat mundial|dia mundial de l['’]infant prematur")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::DateMDY { month: 11, day: 17, year: None })))),
-        },
         Rule {
             name: "dia dels innocents d'abril (ca)".to_string(),
-            pattern: vec![regex("dia dels innocents d['’]abril")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::DateMDY { month: 4, day: 1, year: None })))),
-        },
-        Rule {
-            name: "ordinal trimestre de any (ca)".to_string(),
-            pattern: vec![regex("(primer|segon|tercer|quart|1r|2n|3r|4t)\\s+trimestre\\s+de\\s+(\\d{4})")],
-            production: Box::new(|nodes| {
-                let (q_raw, y_raw) = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => (m.group(1)?, m.group(2)?),
-                    _ => return None,
-                };
-                let quarter = match q_raw {
-                    "primer" | "1r" => 1,
-                    "segon" | "2n" => 2,
-                    "tercer" | "3r" => 3,
-                    "quart" | "4t" => 4,
-                    _ => return None,
-                };
-                let year: i32 = y_raw.parse().ok()?;
-                Some(TokenData::Time(TimeData::new(TimeForm::QuarterYear(quarter, year))))
+            pattern: vec![regex("\\bdia\\s+dels\\s+innocents\\s+d['’]abril\\b")],
+            production: Box::new(|_| {
+                Some(TokenData::Time(TimeData::new(TimeForm::DateMDY { month: 4, day: 1, year: None })))
             }),
         },
     ]);
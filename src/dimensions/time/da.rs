@@ -1,6 +1,7 @@
+use super::scandinavian::Lexicon;
 use super::{Direction, TimeData, TimeForm};
 use crate::dimensions::time_grain::Grain;
-use crate::pattern::regex;
+use crate::pattern::{predicate, regex};
 use crate::types::{Rule, TokenData};
 
 fn da_small_number(s: &str) -> Option<i32> {
@@ -22,8 +23,40 @@ fn da_small_number(s: &str) -> Option<i32> {
     }
 }
 
+const LEXICON: Lexicon = Lexicon {
+    lang: "da",
+    today: r"i\s*dag",
+    tomorrow: r"i\s*morgen",
+    yesterday: r"i\s*g[åa]r",
+    day_after_tomorrow: r"i\s+overmorgen",
+    day_before_yesterday: r"i\s+forg[åa]rs",
+    weekdays: [
+        r"mandag(?:en)?",
+        r"tirsdag(?:en)?|tirs",
+        r"onsdag(?:en)?|ons",
+        r"torsdag(?:en)?|tors",
+        r"fredag(?:en)?|fre",
+        r"l[øo]rdag(?:en)?|lør",
+        r"s[øo]ndag(?:en)?|søn",
+    ],
+    next: r"n[æa]ste|kommende",
+    last: r"sidste|forrige",
+    clock: r"klokken|kl",
+    minutes: r"minut(?:ter)?",
+    past: r"over",
+    to: r"i",
+    holidays: &[
+        (
+            r"sankthansaften|sankt\s*hans\s*aften|skt\.?\s*hans(?:\s*aften)?|sankthans",
+            "st. john's eve",
+        ),
+        (r"sankthansdag(?:en)?|sankt\s*hans\s*dag", "st. john's day"),
+    ],
+};
+
 pub fn rules() -> Vec<Rule> {
     let mut rules = super::en::rules();
+    rules.extend(super::scandinavian::rules(&LEXICON));
     rules.extend(vec![
         Rule {
             name: "now (da)".to_string(),
@@ -31,46 +64,22 @@ pub fn rules() -> Vec<Rule> {
             production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Now)))),
         },
         Rule {
-            name: "today (da)".to_string(),
-            pattern: vec![regex("i dag|idag")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Today)))),
-        },
-        Rule {
-            name: "tomorrow (da)".to_string(),
-            pattern: vec![regex("i morgen|imorgen")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Tomorrow)))),
-        },
-        Rule {
-            name: "yesterday (da)".to_string(),
-            pattern: vec![regex("i g[åa]r|ig[åa]r")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Yesterday)))),
-        },
-        Rule {
-            name: "day of week (da)".to_string(),
-            pattern: vec![regex("mandag|tirsdag|onsdag|torsdag|tors\\.?|fredag|fre\\.?|l[øo]rdag|l[øo]r\\.?|s[øo]ndag|s[øo]n\\.?")],
+            name: "næste <day-of-week> igen (da)".to_string(),
+            pattern: vec![
+                predicate(|td| {
+                    matches!(td, TokenData::Time(d) if matches!(d.form, TimeForm::DayOfWeek(_))
+                        && d.direction == Some(Direction::Future))
+                }),
+                regex("\\bigen\\b"),
+            ],
             production: Box::new(|nodes| {
-                let s = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(0)?.to_lowercase(),
+                let mut t = match &nodes[0].token_data {
+                    TokenData::Time(d) => d.clone(),
                     _ => return None,
                 };
-                let dow = if s.contains("mandag") {
-                    0
-                } else if s.contains("tirsdag") {
-                    1
-                } else if s.contains("onsdag") {
-                    2
-                } else if s.contains("torsdag") || s.starts_with("tors") {
-                    3
-                } else if s.contains("fredag") || s.starts_with("fre") {
-                    4
-                } else if s.contains("rdag") || s.contains("lørdag") || s.starts_with("lør") || s.starts_with("lor") {
-                    5
-                } else if s.contains("ndag") || s.starts_with("søn") || s.starts_with("son") {
-                    6
-                } else {
-                    return None;
-                };
-                Some(TokenData::Time(TimeData::new(TimeForm::DayOfWeek(dow))))
+                t.direction = Some(Direction::FarFuture);
+                t.not_immediate = false;
+                Some(TokenData::Time(t))
             }),
         },
         Rule {
@@ -325,62 +334,6 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("n[æa]ste [åa]r")],
             production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset { grain: Grain::Year, offset: 1 })))),
         },
-        Rule {
-            name: "i overmorgen (da)".to_string(),
-            pattern: vec![regex("i overmorgen")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::DayAfterTomorrow)))),
-        },
-        Rule {
-            name: "i forgårs (da)".to_string(),
-            pattern: vec![regex("i forg[åa]rs")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::DayBeforeYesterday)))),
-        },
-        Rule {
-            name: "next weekday (da)".to_string(),
-            pattern: vec![regex("n[æa]ste\\s+(mandag|tirsdag|onsdag|torsdag|fredag|l[øo]rdag|s[øo]ndag)(\\s+igen)?")],
-            production: Box::new(|nodes| {
-                let (w, igen) = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => (m.group(1)?, m.group(2)),
-                    _ => return None,
-                };
-                let dow = match w {
-                    "mandag" => 0,
-                    "tirsdag" => 1,
-                    "onsdag" => 2,
-                    "torsdag" => 3,
-                    "fredag" => 4,
-                    "lørdag" | "lordag" => 5,
-                    "søndag" | "sondag" => 6,
-                    _ => return None,
-                };
-                let mut t = TimeData::new(TimeForm::DayOfWeek(dow));
-                t.direction = Some(if igen.is_some() { Direction::FarFuture } else { Direction::Future });
-                Some(TokenData::Time(t))
-            }),
-        },
-        Rule {
-            name: "last weekday (da)".to_string(),
-            pattern: vec![regex("sidste\\s+(mandag|tirsdag|onsdag|torsdag|fredag|l[øo]rdag|s[øo]ndag)")],
-            production: Box::new(|nodes| {
-                let w = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(1)?,
-                    _ => return None,
-                };
-                let dow = match w {
-                    "mandag" => 0,
-                    "tirsdag" => 1,
-                    "onsdag" => 2,
-                    "torsdag" => 3,
-                    "fredag" => 4,
-                    "lørdag" | "lordag" => 5,
-                    "søndag" | "sondag" => 6,
-                    _ => return None,
-                };
-                let mut t = TimeData::new(TimeForm::DayOfWeek(dow));
-                t.direction = Some(Direction::Past);
-                Some(TokenData::Time(t))
-            }),
-        },
         Rule {
             name: "<dow> i denne uge (da)".to_string(),
             pattern: vec![regex("(mandag|tirsdag|onsdag|torsdag|fredag|l[øo]rdag|s[øo]ndag)\\s+i\\s+denne\\s+uge")],
@@ -665,68 +618,6 @@ pub fn rules() -> Vec<Rule> {
                 Some(TokenData::Time(TimeData::new(TimeForm::NthGrain { n, grain, past: false, interval: true })))
             }),
         },
-        Rule {
-            name: "klokken 3 (da)".to_string(),
-            pattern: vec![regex("kl(okken|\\.)?\\s*(\\d{1,2})")],
-            production: Box::new(|nodes| {
-                let h = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(2)?,
-                    _ => return None,
-                };
-                let hour: u32 = h.parse().ok()?;
-                if hour > 23 {
-                    return None;
-                }
-                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(hour, 0, hour <= 12))))
-            }),
-        },
-        Rule {
-            name: "kvarter over 15 (da)".to_string(),
-            pattern: vec![regex("kvarter over\\s*(\\d{1,2})|kvart over\\s*(\\d{1,2})")],
-            production: Box::new(|nodes| {
-                let h = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(1).or_else(|| m.group(2))?,
-                    _ => return None,
-                };
-                let hour: u32 = h.parse().ok()?;
-                if hour > 23 {
-                    return None;
-                }
-                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(hour, 15, hour <= 12))))
-            }),
-        },
-        Rule {
-            name: "kvarter i 12 (da)".to_string(),
-            pattern: vec![regex("kvarter i\\s*(\\d{1,2})|kvart i\\s*(\\d{1,2})")],
-            production: Box::new(|nodes| {
-                let h = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(1).or_else(|| m.group(2))?,
-                    _ => return None,
-                };
-                let hour: u32 = h.parse().ok()?;
-                if !(1..=24).contains(&hour) {
-                    return None;
-                }
-                let out_hour = if hour == 24 { 23 } else { hour.checked_sub(1)? };
-                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(out_hour, 45, out_hour <= 12))))
-            }),
-        },
-        Rule {
-            name: "20 over 15 (da)".to_string(),
-            pattern: vec![regex("(\\d{1,2})\\s+over\\s+(\\d{1,2})")],
-            production: Box::new(|nodes| {
-                let (m, h) = match &nodes[0].token_data {
-                    TokenData::RegexMatch(rm) => (rm.group(1)?, rm.group(2)?),
-                    _ => return None,
-                };
-                let minute: u32 = m.parse().ok()?;
-                let hour: u32 = h.parse().ok()?;
-                if minute > 59 || hour > 23 {
-                    return None;
-                }
-                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(hour, minute, hour <= 12))))
-            }),
-        },
         Rule {
             name: "kl 16 CET/GMT (da)".to_string(),
             pattern: vec![regex("(@\\s*)?(kl(okken|\\.)?\\s*)?(\\d{1,2})(:\\d{2})?\\s*(CET|GMT|gmt|cet)")],
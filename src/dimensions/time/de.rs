@@ -1,6 +1,7 @@
 use super::{Direction, IntervalDirection, PartOfDay, TimeData, TimeForm};
 use crate::dimensions::numeral::helpers::numeral_data;
 use crate::dimensions::time_grain::Grain;
+use crate::locale::Region;
 use crate::pattern::{dim, regex};
 use crate::types::{DimensionKind, Rule, TokenData};
 
@@ -18,6 +19,48 @@ fn time_data(td: &TokenData) -> Option<&TimeData> {
     }
 }
 
+/// An hour as said on the clock face: digits or a number word up to zwölf.
+const DE_HOUR: &str =
+    "(\\d{1,2}|eins|ein|zwei|drei|vier|f(?:ü|u)nf|sechs|sieben|acht|neun|zehn|elf|zw(?:ö|o)lf)\\b";
+
+fn de_hour(s: &str) -> Option<u32> {
+    let hour = match s {
+        "ein" | "eins" => 1,
+        "zwei" => 2,
+        "drei" => 3,
+        "vier" => 4,
+        "fünf" | "funf" => 5,
+        "sechs" => 6,
+        "sieben" => 7,
+        "acht" => 8,
+        "neun" => 9,
+        "zehn" => 10,
+        "elf" => 11,
+        "zwölf" | "zwolf" => 12,
+        digits => digits.parse().ok()?,
+    };
+    (1..=24).contains(&hour).then_some(hour)
+}
+
+/// `minute` past the hour named in the first capture group, counted from
+/// the hour before it when `before` is set: "halb drei" is 2:30.
+fn de_clock(td: &TokenData, minute: u32, before: bool) -> Option<TokenData> {
+    let hour = match td {
+        TokenData::RegexMatch(m) => de_hour(&m.group(1)?.to_lowercase())?,
+        _ => return None,
+    };
+    let hour = if before {
+        hour.checked_sub(1)?
+    } else {
+        hour % 24
+    };
+    Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(
+        hour,
+        minute,
+        hour <= 12,
+    ))))
+}
+
 pub fn rules() -> Vec<Rule> {
     let mut rules = super::en::rules();
     rules.extend(vec![
@@ -394,6 +437,32 @@ pub fn rules() -> Vec<Rule> {
                 Some(TokenData::Time(t))
             }),
         },
+        Rule {
+            name: "named month (de)".to_string(),
+            pattern: vec![regex("\\b(januar|jänner|februar|m(?:ä|ae)rz|april|mai|juni|juli|august|september|oktober|november|dezember)\\b")],
+            production: Box::new(|nodes| {
+                let m = match &nodes[0].token_data {
+                    TokenData::RegexMatch(rm) => rm.group(1)?.to_lowercase(),
+                    _ => return None,
+                };
+                let month = match m.as_str() {
+                    "januar" | "jänner" => 1,
+                    "februar" => 2,
+                    "märz" | "maerz" => 3,
+                    "april" => 4,
+                    "mai" => 5,
+                    "juni" => 6,
+                    "juli" => 7,
+                    "august" => 8,
+                    "september" => 9,
+                    "oktober" => 10,
+                    "november" => 11,
+                    "dezember" => 12,
+                    _ => return None,
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::Month(month))))
+            }),
+        },
         Rule {
             name: "kommende woche (de)".to_string(),
             pattern: vec![regex("kommende woche")],
@@ -982,9 +1051,9 @@ pub fn rules() -> Vec<Rule> {
             }),
         },
         Rule {
-            name: "viertel nach drei Uhr (de)".to_string(),
-            pattern: vec![regex("viertel\\s+nach\\s+drei\\s+uhr")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(3, 15, true))))),
+            name: "viertel nach <hour> (de)".to_string(),
+            pattern: vec![regex(&format!("(?:um\\s+)?viertel\\s+nach\\s*{DE_HOUR}(?:\\s+uhr)?"))],
+            production: Box::new(|nodes| de_clock(&nodes[0].token_data, 15, false)),
         },
         Rule {
             name: "zwanzig nach 3 (de)".to_string(),
@@ -1002,36 +1071,19 @@ pub fn rules() -> Vec<Rule> {
             }),
         },
         Rule {
-            name: "um halb 4 (de)".to_string(),
-            pattern: vec![regex("um\\s+halb\\s*(\\d{1,2})")],
-            production: Box::new(|nodes| {
-                let h = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(1)?,
-                    _ => return None,
-                };
-                let hour: u32 = h.parse().ok()?;
-                if !(1..=24).contains(&hour) {
-                    return None;
-                }
-                let out_hour = if hour == 24 { 23 } else { hour.checked_sub(1)? };
-                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(out_hour, 30, true))))
-            }),
+            name: "halb <hour> (de)".to_string(),
+            pattern: vec![regex(&format!("(?:um\\s+)?halb\\s*{DE_HOUR}(?:\\s+uhr)?"))],
+            production: Box::new(|nodes| de_clock(&nodes[0].token_data, 30, true)),
         },
         Rule {
-            name: "viertel vor 12 (de)".to_string(),
-            pattern: vec![regex("viertel\\s+vor\\s*(\\d{1,2})")],
-            production: Box::new(|nodes| {
-                let h = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(1)?,
-                    _ => return None,
-                };
-                let hour: u32 = h.parse().ok()?;
-                if !(1..=24).contains(&hour) {
-                    return None;
-                }
-                let out_hour = if hour == 24 { 23 } else { hour.checked_sub(1)? };
-                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(out_hour, 45, true))))
-            }),
+            name: "viertel vor <hour> (de)".to_string(),
+            pattern: vec![regex(&format!("(?:um\\s+)?viertel\\s+vor\\s*{DE_HOUR}(?:\\s+uhr)?"))],
+            production: Box::new(|nodes| de_clock(&nodes[0].token_data, 45, true)),
+        },
+        Rule {
+            name: "dreiviertel <hour> (de)".to_string(),
+            pattern: vec![regex(&format!("(?:um\\s+)?drei\\s*viertel\\s*{DE_HOUR}(?:\\s+uhr)?"))],
+            production: Box::new(|nodes| de_clock(&nodes[0].token_data, 45, true)),
         },
         Rule {
             name: "vor 7 tagen (de)".to_string(),
@@ -1319,3 +1371,83 @@ pub fn rules() -> Vec<Rule> {
     ]);
     rules
 }
+
+fn fixed_date_rule(name: &str, pattern: &str, month: u32, day: u32) -> Rule {
+    Rule {
+        name: name.to_string(),
+        pattern: vec![regex(pattern)],
+        production: Box::new(move |_| {
+            Some(TokenData::Time(TimeData::new(TimeForm::DateMDY {
+                month,
+                day,
+                year: None,
+            })))
+        }),
+    }
+}
+
+/// Region overlays for German: the Austrian "Jänner" and "Feber", the
+/// clock readings of Austria ("viertel drei" is 2:15) and Switzerland
+/// ("viertel ab drei" is 3:15), and each country's own holidays.
+pub fn locale_rules(region: Region) -> Vec<Rule> {
+    match region {
+        Region::AT => vec![
+            Rule {
+                name: "named month (de_AT)".to_string(),
+                pattern: vec![regex("\\b(j(?:ä|a)nner|feber)\\b")],
+                production: Box::new(|nodes| {
+                    let month = match &nodes[0].token_data {
+                        TokenData::RegexMatch(m) if m.group(1)?.to_lowercase() == "feber" => 2,
+                        _ => 1,
+                    };
+                    Some(TokenData::Time(TimeData::new(TimeForm::Month(month))))
+                }),
+            },
+            Rule {
+                name: "viertel <hour> (de_AT)".to_string(),
+                pattern: vec![regex(&format!(
+                    "(?:um\\s+)?\\bviertel\\s+{DE_HOUR}(?:\\s+uhr)?"
+                ))],
+                production: Box::new(|nodes| de_clock(&nodes[0].token_data, 15, true)),
+            },
+            fixed_date_rule(
+                "Nationalfeiertag (de_AT)",
+                "((ö|o)sterreichischer?\\s+)?national\\s*feiertag",
+                10,
+                26,
+            ),
+            fixed_date_rule("Staatsfeiertag (de_AT)", "staatsfeiertag", 5, 1),
+            fixed_date_rule(
+                "Mariä Himmelfahrt (de_AT)",
+                "mari(ä|a|ae)\\s+himmelfahrt",
+                8,
+                15,
+            ),
+            fixed_date_rule(
+                "Mariä Empfängnis (de_AT)",
+                "mari(ä|a|ae)\\s+empf(ä|ae)ngnis",
+                12,
+                8,
+            ),
+            fixed_date_rule("Stefanitag (de_AT)", "stefanitag|stephanstag", 12, 26),
+        ],
+        Region::CH => vec![
+            Rule {
+                name: "viertel ab <hour> (de_CH)".to_string(),
+                pattern: vec![regex(&format!(
+                    "(?:um\\s+)?viertel\\s+ab\\s*{DE_HOUR}(?:\\s+uhr)?"
+                ))],
+                production: Box::new(|nodes| de_clock(&nodes[0].token_data, 15, false)),
+            },
+            fixed_date_rule(
+                "Bundesfeier (de_CH)",
+                "bundesfeier(tag)?|(schweizer\\s+)?national\\s*feiertag",
+                8,
+                1,
+            ),
+            fixed_date_rule("Berchtoldstag (de_CH)", "berchtold(s)?tag", 1, 2),
+            fixed_date_rule("Stephanstag (de_CH)", "stephanstag", 12, 26),
+        ],
+        _ => Vec::new(),
+    }
+}
@@ -1,8 +1,12 @@
 use super::{Direction, IntervalDirection, PartOfDay, TimeData, TimeForm};
 use crate::dimensions::time_grain::Grain;
-use crate::pattern::{dim, regex};
+use crate::pattern::{dim, predicate, regex};
 use crate::types::{DimensionKind, Rule, TokenData};
 
+fn is_not_latent_time(td: &TokenData) -> bool {
+    matches!(td, TokenData::Time(t) if !t.latent)
+}
+
 pub fn rules() -> Vec<Rule> {
     let mut rules = super::en::rules();
     rules.extend(vec![
@@ -291,6 +295,39 @@ pub fn rules() -> Vec<Rule> {
                 ))))
             }),
         },
+        Rule {
+            name: "orthodox holidays (el)".to_string(),
+            pattern: vec![regex("\\b(?:δευτ[έε]ρα\\s+του\\s+π[άα]σχα|(?:κυριακ[ήη]\\s+του\\s+)?π[άα]σχα|λαμπρ[ήη]|μεγ[άα]λη\\s+παρασκευ[ήη]|μεγ[άα]λο\\s+σ[άα]ββατο|καθαρ[άα]\\s+δευτ[έε]ρα|κυριακ[ήη]\\s+των\\s+βα[ΐϊι]ων|αν[άα]ληψη|πεντηκοστ[ήη]|(?:του\\s+)?αγ[ίι]ου\\s+πνε[ύυ]ματος)\\b")],
+            production: Box::new(|nodes| {
+                let s = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(0)?.to_lowercase(),
+                    _ => return None,
+                };
+                let name = if s.starts_with("δευτ") {
+                    "orthodox easter monday"
+                } else if s.starts_with("καθαρ") {
+                    "clean monday"
+                } else if s.contains("παρασκευ") {
+                    "orthodox good friday"
+                } else if s.contains("σάββατο") || s.contains("σαββατο") {
+                    "orthodox holy saturday"
+                } else if s.contains("βα") && s.contains("ων") {
+                    "orthodox palm sunday"
+                } else if s.starts_with("ανάληψ") || s.starts_with("αναληψ") {
+                    "orthodox ascension"
+                } else if s.starts_with("πεντηκοστ") {
+                    "orthodox pentecost"
+                } else if s.contains("πνε") {
+                    "orthodox whit monday"
+                } else {
+                    "orthodox easter"
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::Holiday(
+                    name.to_string(),
+                    None,
+                ))))
+            }),
+        },
         Rule {
             name: "season (el)".to_string(),
             pattern: vec![regex("αυτ(ό|ο)\\s+το\\s+φθιν(ό|ο)πωρο|αυτο(ύ|υ)\\s+του\\s+φθινοπ(ώ|ο)ρου|φθιν(ό|ο)πωρο|καλοκα(ί|ι)ρι|χειμ(ώ|ο)να|(ά|α)νοιξη")],
@@ -741,6 +778,37 @@ pub fn rules() -> Vec<Rule> {
                 Some(TokenData::Time(td))
             }),
         },
+        Rule {
+            name: "στις <time> (el)".to_string(),
+            pattern: vec![regex("\\bστις?\\b"), predicate(is_not_latent_time)],
+            production: Box::new(|nodes| match &nodes[1].token_data {
+                TokenData::Time(td) => Some(TokenData::Time(td.clone())),
+                _ => None,
+            }),
+        },
+        Rule {
+            name: "στις <hour>[:mm] (el)".to_string(),
+            pattern: vec![regex("\\bστις?\\s+(\\d{1,2})(?:[:.](\\d{2}))?\\b")],
+            production: Box::new(|nodes| {
+                let (h, m) = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => (m.group(1)?, m.group(2)),
+                    _ => return None,
+                };
+                let hour: u32 = h.parse().ok()?;
+                let form = match m {
+                    Some(m) => {
+                        let minute: u32 = m.parse().ok()?;
+                        if hour > 23 || minute > 59 {
+                            return None;
+                        }
+                        TimeForm::HourMinute(hour, minute, false)
+                    }
+                    None if hour <= 24 => TimeForm::Hour(hour % 24, (1..=12).contains(&hour)),
+                    None => return None,
+                };
+                Some(TokenData::Time(TimeData::new(form)))
+            }),
+        },
         Rule {
             name: "3πμ / 5μμ (el)".to_string(),
             pattern: vec![regex("στις?\\s*(\\d{1,2})(:(\\d{2}))?\\s*(πμ|μμ)|(\\d{1,2})(:(\\d{2}))?\\s*(πμ|μμ)")],
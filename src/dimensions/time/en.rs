@@ -1,9 +1,14 @@
+use crate::dimensions::duration::{DurationData, DurationQualifier};
 use crate::dimensions::numeral::helpers::{integer_value, numeral_data};
 use crate::dimensions::time_grain::Grain;
 use crate::pattern::{dim, predicate, regex};
-use crate::types::{DimensionKind, Rule, TokenData};
+use crate::resolve::{holiday_key, WeekNumbering};
+use crate::types::{DimensionKind, Node, RegexMatchData, Rule, TokenData};
 
-use super::{Direction, EarlyLate, IntervalDirection, PartOfDay, TimeData, TimeForm};
+use super::{
+    clock_hour_offset, is_part_of_period_target, Direction, EarlyLate, IntervalDirection,
+    PartOfDay, TimeData, TimeForm,
+};
 
 fn is_integer_between(lo: i64, hi: i64) -> Box<dyn Fn(&TokenData) -> bool + Send + Sync> {
     Box::new(move |td: &TokenData| {
@@ -15,6 +20,27 @@ fn is_integer_between(lo: i64, hi: i64) -> Box<dyn Fn(&TokenData) -> bool + Send
     })
 }
 
+/// "in the next", "within the last": a window from or up to now.
+const WINDOW: &str =
+    r"\b(?:in|within|over|during|for)\s+the\s+(next|coming|following|last|past|previous)\b";
+
+/// Whether the `WINDOW` match looks back ("the last") rather than ahead.
+fn window_is_past(td: &TokenData) -> Option<bool> {
+    match td {
+        TokenData::RegexMatch(m) => Some(matches!(
+            m.group(1)?.to_lowercase().as_str(),
+            "last" | "past" | "previous"
+        )),
+        _ => None,
+    }
+}
+
+/// Whether `td` is the numeral "a few", which windows count by
+/// `Options::few_count` rather than as 3.
+fn is_few(td: &TokenData) -> bool {
+    matches!(td, TokenData::Numeral(n) if n.value == 3.0 && !n.ok_for_any_time)
+}
+
 fn time_data(td: &TokenData) -> Option<&TimeData> {
     match td {
         TokenData::Time(d) => Some(d),
@@ -52,15 +78,52 @@ fn is_year_token(td: &TokenData) -> bool {
     matches!(td, TokenData::Time(d) if matches!(d.form, TimeForm::Year(_)))
 }
 
+fn is_week_ordinal(td: &TokenData) -> bool {
+    matches!(td, TokenData::Ordinal(d) if (1..=53).contains(&d.value))
+}
+
+/// "the 12th week of 2025": week 12 of 2025 by `Options::week_numbering`.
+fn ordinal_week_of_year(ordinal: &Node, year: &Node) -> Option<TokenData> {
+    let week = match &ordinal.token_data {
+        TokenData::Ordinal(d) => u32::try_from(d.value).ok()?,
+        _ => return None,
+    };
+    let year = match time_data(&year.token_data)?.form {
+        TimeForm::Year(y) => y,
+        _ => return None,
+    };
+    Some(TokenData::Time(TimeData::new(TimeForm::WeekOfYear {
+        week,
+        year: Some(year),
+        numbering: None,
+    })))
+}
+
 /// Matches Haskell's `isOkWithThisNext` predicate.
 fn is_ok_with_this_next(td: &TokenData) -> bool {
     matches!(td, TokenData::Time(d) if d.ok_for_this_next)
 }
 
+/// A duration that can shift a time: "for 2 hours" already spans its
+/// duration, so "for 2 hours ago" is not a past time.
+fn is_unprefixed_duration(td: &TokenData) -> bool {
+    matches!(td, TokenData::Duration(d) if d.qualifier.is_none())
+}
+
 fn is_not_latent_time(td: &TokenData) -> bool {
     matches!(td, TokenData::Time(d) if !d.latent)
 }
 
+/// Whether one of `t1` and `t2` is a latent hour (a bare number) and the
+/// other a whole week, month or year, e.g. "3" + "next week". A clock time
+/// is only read from a bare number next to a day or part of day.
+fn is_latent_hour_with_long_cycle(t1: &TimeData, t2: &TimeData) -> bool {
+    let latent_hour =
+        |t: &TimeData| t.latent && matches!(t.form, TimeForm::Hour(..) | TimeForm::HourMinute(..));
+    let long_cycle = |t: &TimeData| matches!(t.form, TimeForm::GrainOffset { grain, .. } | TimeForm::AllGrain(grain) if grain >= Grain::Week);
+    (latent_hour(t1) && long_cycle(t2)) || (latent_hour(t2) && long_cycle(t1))
+}
+
 /// Create a Composed TimeData, propagating timezone from either inner token.
 /// This mirrors Haskell's approach where shiftTimezone modifies the predicate
 /// and carries through intersections automatically.
@@ -121,6 +184,34 @@ fn month_day(month: u32, day: u32) -> TimeForm {
     )
 }
 
+/// A day that "EOD"/"COB" can attach to.
+fn is_business_deadline_day(td: &TokenData) -> bool {
+    matches!(
+        td,
+        TokenData::Time(TimeData {
+            form: TimeForm::DayOfWeek(_)
+                | TimeForm::Today
+                | TimeForm::Tomorrow
+                | TimeForm::DayAfterTomorrow
+                | TimeForm::DayOfMonth(_)
+                | TimeForm::DateMDY { .. }
+                | TimeForm::Holiday(..),
+            latent: false,
+            ..
+        })
+    )
+}
+
+/// "COB <day>" / "by EOD <day>": end of the business day on `day`, as an open
+/// interval ending there when prefixed with "by".
+fn end_of_business(day: TimeData, by: bool) -> TokenData {
+    let mut td = TimeData::new(TimeForm::EndOfBusiness(Box::new(day)));
+    if by {
+        td.open_interval_direction = Some(IntervalDirection::Before);
+    }
+    TokenData::Time(td)
+}
+
 /// Check if a token is a month-day without year — matches either
 /// `DateMDY { year: None }` (from regex rules) or `Composed(Month, DayOfMonth)`
 /// (from token-based rules matching Haskell's intersectDOM).
@@ -301,6 +392,26 @@ pub fn rules() -> Vec<Rule> {
                 Some(TokenData::Time(TimeData::new(TimeForm::Hour(0, false))))
             }),
         },
+        // noonish: 11:00-13:00
+        Rule {
+            name: "noonish".to_string(),
+            pattern: vec![regex(r"\b(noon|midday)[\s-]?ish\b")],
+            production: Box::new(|_| {
+                Some(TokenData::Time(TimeData::new(TimeForm::PartOfDay(
+                    PartOfDay::Noonish,
+                ))))
+            }),
+        },
+        // midnightish: 23:00-01:00
+        Rule {
+            name: "midnightish".to_string(),
+            pattern: vec![regex(r"\bmidnight[\s-]?ish\b")],
+            production: Box::new(|_| {
+                Some(TokenData::Time(TimeData::new(TimeForm::PartOfDay(
+                    PartOfDay::Midnightish,
+                ))))
+            }),
+        },
         // ====================================================================
         // Part of day keywords (latent - need context to resolve)
         // ====================================================================
@@ -355,6 +466,25 @@ pub fn rules() -> Vec<Rule> {
                 ))))
             }),
         },
+        // Meal times follow `Options::part_of_day`
+        Rule {
+            name: "breakfast (latent)".to_string(),
+            pattern: vec![regex(r"\bbreakfast\b")],
+            production: Box::new(|_| {
+                Some(TokenData::Time(TimeData::latent(TimeForm::PartOfDay(
+                    PartOfDay::Breakfast,
+                ))))
+            }),
+        },
+        Rule {
+            name: "dinner (latent)".to_string(),
+            pattern: vec![regex(r"\b(dinner|supper)\b")],
+            production: Box::new(|_| {
+                Some(TokenData::Time(TimeData::latent(TimeForm::PartOfDay(
+                    PartOfDay::Dinner,
+                ))))
+            }),
+        },
         // ====================================================================
         // tonight / tonite
         // ====================================================================
@@ -368,6 +498,27 @@ pub fn rules() -> Vec<Rule> {
                 ))))
             }),
         },
+        // overnight: 22:00 today to 06:00 tomorrow
+        Rule {
+            name: "overnight".to_string(),
+            pattern: vec![regex(r"\b(overnight|over night)\b")],
+            production: Box::new(|_| {
+                Some(TokenData::Time(TimeData::new(TimeForm::Composed(
+                    Box::new(TimeData::new(TimeForm::Today)),
+                    Box::new(TimeData::new(TimeForm::PartOfDay(PartOfDay::Overnight))),
+                ))))
+            }),
+        },
+        // later today: from now to the end of the day
+        Rule {
+            name: "later today".to_string(),
+            pattern: vec![regex(r"\b(later (on )?today|rest of (the )?day)\b")],
+            production: Box::new(|_| {
+                Some(TokenData::Time(TimeData::new(TimeForm::RestOfGrain(
+                    Grain::Day,
+                ))))
+            }),
+        },
         // ====================================================================
         // this/today + <part of day>
         // ====================================================================
@@ -1030,9 +1181,69 @@ pub fn rules() -> Vec<Rule> {
                     TokenData::Duration(d) => d,
                     _ => return None,
                 };
-                Some(TokenData::Time(TimeData::new(TimeForm::RelativeGrain {
+                let mut td = TimeData::new(TimeForm::RelativeGrain {
                     n: dur.value,
                     grain: dur.grain,
+                });
+                td.duration_qualifier = Some(DurationQualifier::Delayed);
+                Some(TokenData::Time(td))
+            }),
+        },
+        // ====================================================================
+        // Business-day arithmetic ("in 3 business days", "5 working days from Friday")
+        // ====================================================================
+        Rule {
+            name: "in <integer> business days".to_string(),
+            pattern: vec![
+                regex(r"\bin\b"),
+                predicate(is_integer_between(0, 10_000)),
+                regex(r"\b(business|working|work) ?days?\b"),
+            ],
+            production: Box::new(|nodes| {
+                let n = integer_value(&nodes[1].token_data)?;
+                Some(TokenData::Time(TimeData::new(TimeForm::BusinessDays {
+                    n,
+                    base: None,
+                })))
+            }),
+        },
+        Rule {
+            name: "<integer> business days from now|ago".to_string(),
+            pattern: vec![
+                predicate(is_integer_between(0, 10_000)),
+                regex(r"\b(business|working|work) ?days?\b"),
+                regex(r"\b(from now|from today|later|hence|ago)\b"),
+            ],
+            production: Box::new(|nodes| {
+                let n = integer_value(&nodes[0].token_data)?;
+                let ago = match &nodes[2].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?.eq_ignore_ascii_case("ago"),
+                    _ => return None,
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::BusinessDays {
+                    n: if ago { n.checked_neg()? } else { n },
+                    base: None,
+                })))
+            }),
+        },
+        Rule {
+            name: "<integer> business days after|before <time>".to_string(),
+            pattern: vec![
+                predicate(is_integer_between(0, 10_000)),
+                regex(r"\b(business|working|work) ?days?\b"),
+                regex(r"\b(from|after|before)\b"),
+                predicate(is_not_latent_time),
+            ],
+            production: Box::new(|nodes| {
+                let n = integer_value(&nodes[0].token_data)?;
+                let before = match &nodes[2].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?.eq_ignore_ascii_case("before"),
+                    _ => return None,
+                };
+                let base = time_data(&nodes[3].token_data)?;
+                Some(TokenData::Time(TimeData::new(TimeForm::BusinessDays {
+                    n: if before { n.checked_neg()? } else { n },
+                    base: Some(Box::new(base.clone())),
                 })))
             }),
         },
@@ -1042,15 +1253,17 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex(r"\bin\b"), predicate(is_integer_between(0, 60))],
             production: Box::new(|nodes| {
                 let n = numeral_data(&nodes[1].token_data)?.value as i64;
-                Some(TokenData::Time(TimeData::new(TimeForm::RelativeGrain {
+                let mut td = TimeData::new(TimeForm::RelativeGrain {
                     n,
                     grain: Grain::Minute,
-                })))
+                });
+                td.duration_qualifier = Some(DurationQualifier::Delayed);
+                Some(TokenData::Time(td))
             }),
         },
         Rule {
             name: "<duration> hence|ago".to_string(),
-            pattern: vec![dim(DimensionKind::Duration), regex(r"\b(hence|ago)\b")],
+            pattern: vec![predicate(is_unprefixed_duration), regex(r"\b(hence|ago)\b")],
             production: Box::new(|nodes| {
                 let dur = match &nodes[0].token_data {
                     TokenData::Duration(d) => d,
@@ -1077,7 +1290,7 @@ pub fn rules() -> Vec<Rule> {
         // ====================================================================
         Rule {
             name: "<duration> from right now".to_string(),
-            pattern: vec![dim(DimensionKind::Duration), regex(r"\bfrom right now\b")],
+            pattern: vec![predicate(is_unprefixed_duration), regex(r"\bfrom right now\b")],
             production: Box::new(|nodes| {
                 let dur = match &nodes[0].token_data {
                     TokenData::Duration(d) => d,
@@ -1095,7 +1308,7 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "<duration> from today".to_string(),
-            pattern: vec![dim(DimensionKind::Duration), regex(r"\bfrom today\b")],
+            pattern: vec![predicate(is_unprefixed_duration), regex(r"\bfrom today\b")],
             production: Box::new(|nodes| {
                 let dur = match &nodes[0].token_data {
                     TokenData::Duration(d) => d,
@@ -1113,7 +1326,7 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "<duration> from now".to_string(),
-            pattern: vec![dim(DimensionKind::Duration), regex(r"\bfrom now\b")],
+            pattern: vec![predicate(is_unprefixed_duration), regex(r"\bfrom now\b")],
             production: Box::new(|nodes| {
                 let dur = match &nodes[0].token_data {
                     TokenData::Duration(d) => d,
@@ -1127,7 +1340,7 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "<duration> back".to_string(),
-            pattern: vec![dim(DimensionKind::Duration), regex(r"\bback\b")],
+            pattern: vec![predicate(is_unprefixed_duration), regex(r"\bback\b")],
             production: Box::new(|nodes| {
                 let dur = match &nodes[0].token_data {
                     TokenData::Duration(d) => d,
@@ -1170,11 +1383,10 @@ pub fn rules() -> Vec<Rule> {
                     n: dur.value,
                     grain: dur.grain,
                 });
-                Some(TokenData::Time(TimeData::new(TimeForm::Interval(
-                    Box::new(from),
-                    Box::new(to),
-                    false,
-                ))))
+                let mut td =
+                    TimeData::new(TimeForm::Interval(Box::new(from), Box::new(to), false));
+                td.duration_qualifier = Some(DurationQualifier::Bounded);
+                Some(TokenData::Time(td))
             }),
         },
         // ====================================================================
@@ -1182,75 +1394,14 @@ pub fn rules() -> Vec<Rule> {
         // ====================================================================
         Rule {
             name: "date MM/DD(/YYYY)".to_string(),
-            pattern: vec![regex(
-                r"\b(\d{1,2})\s?[/\-]\s?(\d{1,2})(?:\s?[/\-]\s?(\d{2,4}))?\b",
-            )],
-            production: Box::new(|nodes| {
-                let m = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m,
-                    _ => return None,
-                };
-                let v1: u32 = m.group(1)?.parse().ok()?;
-                let v2: u32 = m.group(2)?.parse().ok()?;
-                let year = m.group(3).and_then(|y| {
-                    let yr: i32 = y.parse().ok()?;
-                    if yr < 100 {
-                        // 2-digit year: 00-49 → 2000s, 50-99 → 1900s
-                        if yr < 50 {
-                            Some(yr.checked_add(2000)?)
-                        } else {
-                            Some(yr.checked_add(1900)?)
-                        }
-                    } else {
-                        Some(yr)
-                    }
-                });
-                if (1..=12).contains(&v1) && (1..=31).contains(&v2) {
-                    Some(TokenData::Time(TimeData::new(TimeForm::DateMDY {
-                        month: v1,
-                        day: v2,
-                        year,
-                    })))
-                } else {
-                    None
-                }
-            }),
+            pattern: vec![regex(SLASH_DATE)],
+            production: Box::new(|nodes| slash_date(&nodes[0].token_data, false, true)),
         },
         // DD/MM(/YY) where first number can't be a month (e.g., "15/2", "31/10/74")
         Rule {
             name: "date DD/MM(/YY)".to_string(),
-            pattern: vec![regex(
-                r"\b(\d{1,2})\s?[/\-]\s?(\d{1,2})(?:\s?[/\-]\s?(\d{2,4}))?\b",
-            )],
-            production: Box::new(|nodes| {
-                let m = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m,
-                    _ => return None,
-                };
-                let day: u32 = m.group(1)?.parse().ok()?;
-                let month: u32 = m.group(2)?.parse().ok()?;
-                let year = m.group(3).and_then(|y| {
-                    let yr: i32 = y.parse().ok()?;
-                    if yr < 100 {
-                        if yr < 50 {
-                            Some(yr.checked_add(2000)?)
-                        } else {
-                            Some(yr.checked_add(1900)?)
-                        }
-                    } else {
-                        Some(yr)
-                    }
-                });
-                if (13..=31).contains(&day) && (1..=12).contains(&month) {
-                    Some(TokenData::Time(TimeData::new(TimeForm::DateMDY {
-                        month,
-                        day,
-                        year,
-                    })))
-                } else {
-                    None
-                }
-            }),
+            pattern: vec![regex(SLASH_DATE)],
+            production: Box::new(|nodes| slash_date(&nodes[0].token_data, true, false)),
         },
         // MM DD YYYY (e.g., "10 31 1974")
         Rule {
@@ -1327,50 +1478,27 @@ pub fn rules() -> Vec<Rule> {
             }),
         },
         // Intentional extension beyond upstream Haskell EN:
-        // support ISO-8601 datetimes with a T separator and trailing Z, e.g.:
-        // "2018-04-01T18:03:40Z".
+        // ISO-8601 / RFC 3339 datetimes, e.g. "2018-04-01T18:03:40Z",
+        // "2024-05-03 14:30+02:00". An offset makes the value an instant.
         Rule {
-            name: "iso8601 datetime with T separator (en extension)".to_string(),
+            name: "iso8601 datetime (en extension)".to_string(),
             pattern: vec![regex(
-                r"\b(\d{4})-(0?[1-9]|1[0-2])-(3[01]|[12]\d|0?[1-9])[Tt]([01]?\d|2[0-3]):([0-5]\d)(?::([0-5]\d))?(?:\.\d+)?([Zz])\b",
+                r"\b(\d{4})-(0?[1-9]|1[0-2])-(3[01]|[12]\d|0?[1-9])[Tt ]([01]?\d|2[0-3]):([0-5]\d)(?::([0-5]\d))?(?:[.,]\d+)?([Zz]|[+\-](?:[01]\d|2[0-3])(?::?[0-5]\d)?)?\b",
             )],
-            production: Box::new(|nodes| {
-                let m = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m,
-                    _ => return None,
-                };
-                let year: i32 = m.group(1)?.parse().ok()?;
-                let month: u32 = m.group(2)?.parse().ok()?;
-                let day: u32 = m.group(3)?.parse().ok()?;
-                let hour: u32 = m.group(4)?.parse().ok()?;
-                let minute: u32 = m.group(5)?.parse().ok()?;
-                let second: u32 = match m.group(6) {
-                    Some(s) => s.parse().ok()?,
-                    None => 0,
-                };
-                if !(1..=12).contains(&month)
-                    || !(1..=31).contains(&day)
-                    || hour > 23
-                    || minute > 59
-                    || second > 59
-                {
-                    return None;
-                }
-                let date = TimeData::new(TimeForm::DateMDY {
-                    month,
-                    day,
-                    year: Some(year),
-                });
-                let tod = if m.group(6).is_some() {
-                    TimeData::new(TimeForm::HourMinuteSecond(hour, minute, second))
-                } else {
-                    TimeData::new(TimeForm::HourMinute(hour, minute, false))
-                };
-                let mut composed =
-                    TimeData::new(TimeForm::Composed(Box::new(date), Box::new(tod)));
-                // "Z" means UTC; resolver converts to Instant via timezone shift.
-                composed.timezone = Some("UTC".to_string());
-                Some(TokenData::Time(composed))
+            production: Box::new(|nodes| match &nodes[0].token_data {
+                TokenData::RegexMatch(m) => iso_datetime(m),
+                _ => None,
+            }),
+        },
+        // ISO-8601 basic format: "20240503T1430", "20240503T143000Z".
+        Rule {
+            name: "iso8601 basic datetime (en extension)".to_string(),
+            pattern: vec![regex(
+                r"\b(\d{4})(0[1-9]|1[0-2])(3[01]|[12]\d|0[1-9])[Tt]([01]\d|2[0-3])([0-5]\d)([0-5]\d)?(?:[.,]\d+)?([Zz]|[+\-](?:[01]\d|2[0-3])(?:[0-5]\d)?)?\b",
+            )],
+            production: Box::new(|nodes| match &nodes[0].token_data {
+                TokenData::RegexMatch(m) => iso_datetime(m),
+                _ => None,
             }),
         },
         // YYYY-MM-DD
@@ -1577,6 +1705,80 @@ pub fn rules() -> Vec<Rule> {
                 Some(TokenData::Time(TimeData::new(TimeForm::Year(year))))
             }),
         },
+        // ====================================================================
+        // Decades and centuries
+        // ====================================================================
+        // "the 1980s", "the '90s", "2000s"; a bare "90s" stays latent
+        Rule {
+            name: "decade (numeric)".to_string(),
+            pattern: vec![regex(r"\b(the\s+)?(['’])?(1\d{2}|20\d|\d)0'?s\b")],
+            production: Box::new(|nodes| {
+                let m = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m,
+                    _ => return None,
+                };
+                let digits = m.group(3)?;
+                let decade: i32 = digits.parse::<i32>().ok()?.checked_mul(10)?;
+                let qualified = digits.len() > 1 || m.group(1).is_some() || m.group(2).is_some();
+                Some(TokenData::Time(if qualified {
+                    TimeData::new(TimeForm::Decade(decade))
+                } else {
+                    TimeData::latent(TimeForm::Decade(decade))
+                }))
+            }),
+        },
+        // "the nineties", "the roaring twenties"
+        Rule {
+            name: "decade (spelled out)".to_string(),
+            pattern: vec![regex(
+                r"\b(the\s+)?(roaring\s+)?(twenties|thirties|forties|fifties|sixties|seventies|eighties|nineties)\b",
+            )],
+            production: Box::new(|nodes| {
+                let m = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m,
+                    _ => return None,
+                };
+                let decade = match m.group(3)?.to_lowercase().as_str() {
+                    "twenties" => 20,
+                    "thirties" => 30,
+                    "forties" => 40,
+                    "fifties" => 50,
+                    "sixties" => 60,
+                    "seventies" => 70,
+                    "eighties" => 80,
+                    _ => 90,
+                };
+                // "in her twenties" is an age, not a decade
+                let qualified = m.group(1).is_some() || m.group(2).is_some();
+                Some(TokenData::Time(if qualified {
+                    TimeData::new(TimeForm::Decade(decade))
+                } else {
+                    TimeData::latent(TimeForm::Decade(decade))
+                }))
+            }),
+        },
+        // "the 21st century", "19th-century"
+        Rule {
+            name: "<ordinal> century".to_string(),
+            pattern: vec![dim(DimensionKind::Ordinal), regex(r"[\s-]?centur(y|ies)\b")],
+            production: Box::new(|nodes| {
+                let n = match &nodes[0].token_data {
+                    TokenData::Ordinal(d) if (1..=99).contains(&d.value) => d.value as i32,
+                    _ => return None,
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::Century(n))))
+            }),
+        },
+        Rule {
+            name: "the <century>".to_string(),
+            pattern: vec![
+                regex(r"\bthe\b"),
+                predicate(
+                    |td| matches!(td, TokenData::Time(d) if matches!(d.form, TimeForm::Century(_))),
+                ),
+            ],
+            production: Box::new(|nodes| Some(TokenData::Time(time_data(&nodes[1].token_data)?.clone()))),
+        },
         // <month> <year> composition (allows both latent to compose)
         // "October 2018" → Composed(Month(10), Year(2018))
         Rule {
@@ -1694,11 +1896,11 @@ pub fn rules() -> Vec<Rule> {
                 })))
             }),
         },
-        // "last <dow> of <time>" (e.g., "last Friday of October")
+        // "(the) last <dow> of <time>" (e.g., "last Friday of October")
         Rule {
             name: "last <day-of-week> of <time>".to_string(),
             pattern: vec![
-                regex(r"\blast\b"),
+                regex(r"\b(the )?last\b"),
                 predicate(
                     |td| matches!(td, TokenData::Time(d) if matches!(d.form, TimeForm::DayOfWeek(_))),
                 ),
@@ -1720,11 +1922,11 @@ pub fn rules() -> Vec<Rule> {
                 })))
             }),
         },
-        // "last <cycle> of <time>" (e.g., "last week of September")
+        // "(the) last <cycle> of <time>" (e.g., "last week of September")
         Rule {
             name: "last <cycle> of <time>".to_string(),
             pattern: vec![
-                regex(r"\blast\b"),
+                regex(r"\b(the )?last\b"),
                 dim(DimensionKind::TimeGrain),
                 regex(r"\bof|in\b"),
                 dim(DimensionKind::Time),
@@ -1914,6 +2116,58 @@ pub fn rules() -> Vec<Rule> {
                 })))
             }),
         },
+        // Week numbers: "week 23", "ISO week 7", "wk 5"
+        Rule {
+            name: "week <number>".to_string(),
+            pattern: vec![regex(r"\b(iso\s+)?(?:week|wk)\s*(?:#|no\.?\s*|number\s+)?([1-9]|[1-4]\d|5[0-3])\b")],
+            production: Box::new(|nodes| {
+                let m = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m,
+                    _ => return None,
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::WeekOfYear {
+                    week: m.group(2)?.parse().ok()?,
+                    year: None,
+                    numbering: m.group(1).map(|_| WeekNumbering::Iso),
+                })))
+            }),
+        },
+        Rule {
+            name: "week <number> of <year>".to_string(),
+            pattern: vec![
+                predicate(|td| {
+                    matches!(td, TokenData::Time(d) if matches!(d.form, TimeForm::WeekOfYear { year: None, .. }))
+                }),
+                regex(r"\b(of|in)\b|,"),
+                predicate(is_year_token),
+            ],
+            production: Box::new(|nodes| {
+                let (week, numbering) = match &time_data(&nodes[0].token_data)?.form {
+                    TimeForm::WeekOfYear { week, numbering, .. } => (*week, *numbering),
+                    _ => return None,
+                };
+                let year = match time_data(&nodes[2].token_data)?.form {
+                    TimeForm::Year(y) => y,
+                    _ => return None,
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::WeekOfYear { week, year: Some(year), numbering })))
+            }),
+        },
+        Rule {
+            name: "<ordinal> week of <year>".to_string(),
+            pattern: vec![predicate(is_week_ordinal), regex(r"\bweek\s+(of|in)\b"), predicate(is_year_token)],
+            production: Box::new(|nodes| ordinal_week_of_year(nodes[0], nodes[2])),
+        },
+        Rule {
+            name: "the <ordinal> week of <year>".to_string(),
+            pattern: vec![
+                regex(r"\bthe\b"),
+                predicate(is_week_ordinal),
+                regex(r"\bweek\s+(of|in)\b"),
+                predicate(is_year_token),
+            ],
+            production: Box::new(|nodes| ordinal_week_of_year(nodes[1], nodes[3])),
+        },
         // "Nth <grain> of <time>" (e.g., "first week of October 2014")
         Rule {
             name: "the <ordinal> <cycle> of <time>".to_string(),
@@ -1944,7 +2198,7 @@ pub fn rules() -> Vec<Rule> {
         Rule {
             name: "last day of <time>".to_string(),
             pattern: vec![
-                regex(r"\b(last day|last day) of\b"),
+                regex(r"\b(the )?last day of\b"),
                 dim(DimensionKind::Time),
             ],
             production: Box::new(|nodes| {
@@ -1975,20 +2229,21 @@ pub fn rules() -> Vec<Rule> {
                 })))
             }),
         },
-        // "Nth <day> of <month>" (e.g., "third day of October")
+        // "(the) Nth <day> of <month>" (e.g., "third day of October")
         Rule {
             name: "<ordinal> day of <time>".to_string(),
             pattern: vec![
+                regex(r"\b(the\s+)?"),
                 dim(DimensionKind::Ordinal),
                 regex(r"\bday of\b"),
                 dim(DimensionKind::Time),
             ],
             production: Box::new(|nodes| {
-                let n = match &nodes[0].token_data {
+                let n = match &nodes[1].token_data {
                     TokenData::Ordinal(d) => d.value as i32,
                     _ => return None,
                 };
-                let base = time_data(&nodes[2].token_data)?;
+                let base = time_data(&nodes[3].token_data)?;
                 // Resolve: nth day of the period
                 Some(TokenData::Time(TimeData::new(TimeForm::NthGrainOfTime {
                     n,
@@ -2003,7 +2258,7 @@ pub fn rules() -> Vec<Rule> {
         Rule {
             name: "this|last|next <cycle>".to_string(),
             pattern: vec![
-                regex(r"\b(this|current|coming|next|the( following)?|last|past|previous|upcoming)\b"),
+                regex(r"\b(this|current|coming|next|the( following)?|following|last|past|previous|upcoming)\b"),
                 regex(r"\b(week|month|year|yr|quarter|qtr)\b"),
             ],
             production: Box::new(|nodes| {
@@ -2026,6 +2281,26 @@ pub fn rules() -> Vec<Rule> {
                 Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset { grain, offset })))
             }),
         },
+        // "the month after next", "the week before last"
+        Rule {
+            name: "the <cycle> after next|before last".to_string(),
+            pattern: vec![regex(
+                r"\b(the )?(week|month|year|yr|quarter|qtr) (after next|before last)\b",
+            )],
+            production: Box::new(|nodes| {
+                let m = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m,
+                    _ => return None,
+                };
+                let grain = text_to_grain(m.group(2)?)?;
+                let offset = if m.group(3)?.eq_ignore_ascii_case("after next") {
+                    2
+                } else {
+                    -2
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset { grain, offset })))
+            }),
+        },
         // "the week of <date>" → week containing that date
         Rule {
             name: "the week of <time>".to_string(),
@@ -2103,6 +2378,50 @@ pub fn rules() -> Vec<Rule> {
                 })))
             }),
         },
+        // "in the next 2 weeks", "within the last 30 days": the window
+        // running from, or up to, now
+        Rule {
+            name: "in the next|last <integer> <grain>".to_string(),
+            pattern: vec![
+                regex(WINDOW),
+                predicate(|td| {
+                    is_integer_between(1, 9999)(td) && !is_few(td)
+                }),
+                dim(DimensionKind::TimeGrain),
+            ],
+            production: Box::new(|nodes| {
+                let past = window_is_past(&nodes[0].token_data)?;
+                let grain = match &nodes[2].token_data {
+                    TokenData::TimeGrain(g) => *g,
+                    _ => return None,
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::Window {
+                    n: integer_value(&nodes[1].token_data),
+                    grain,
+                    past,
+                })))
+            }),
+        },
+        // "over the next few days", counted by `Options::few_count`
+        Rule {
+            name: "in the next|last few <grain>".to_string(),
+            pattern: vec![
+                regex(&format!(r"{WINDOW}\s+(?:a\s+)?few\b")),
+                dim(DimensionKind::TimeGrain),
+            ],
+            production: Box::new(|nodes| {
+                let past = window_is_past(&nodes[0].token_data)?;
+                let grain = match &nodes[1].token_data {
+                    TokenData::TimeGrain(g) => *g,
+                    _ => return None,
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::Window {
+                    n: None,
+                    grain,
+                    past,
+                })))
+            }),
+        },
         // upcoming <integer> <grain>
         Rule {
             name: "upcoming <integer> <cycle>".to_string(),
@@ -2445,6 +2764,52 @@ pub fn rules() -> Vec<Rule> {
                 }
             }),
         },
+        // "March the 3rd", "March the third"
+        Rule {
+            name: "<named-month> the <day-of-month> (ordinal)".to_string(),
+            pattern: vec![
+                predicate(
+                    |td| matches!(td, TokenData::Time(d) if matches!(d.form, TimeForm::Month(_))),
+                ),
+                regex(r"\bthe\b"),
+                dim(DimensionKind::Ordinal),
+            ],
+            production: Box::new(|nodes| {
+                let month_td = time_data(&nodes[0].token_data)?;
+                let day = match &nodes[2].token_data {
+                    TokenData::Ordinal(d) => d.value as u32,
+                    _ => return None,
+                };
+                if (1..=31).contains(&day) {
+                    Some(intersect_dom(month_td, day))
+                } else {
+                    None
+                }
+            }),
+        },
+        // "the 3rd inst.": the 3rd of the current month, as in letters
+        Rule {
+            name: "<day-of-month> inst.".to_string(),
+            pattern: vec![
+                predicate(
+                    |td| matches!(td, TokenData::Time(d) if matches!(d.form, TimeForm::DayOfMonth(_))),
+                ),
+                regex(r"\binst\b\.?"),
+            ],
+            production: Box::new(|nodes| {
+                let day = match time_data(&nodes[0].token_data)?.form {
+                    TimeForm::DayOfMonth(d) => d,
+                    _ => return None,
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::Composed(
+                    Box::new(TimeData::new(TimeForm::DayOfMonth(day))),
+                    Box::new(TimeData::new(TimeForm::GrainOffset {
+                        grain: Grain::Month,
+                        offset: 0,
+                    })),
+                ))))
+            }),
+        },
         // on the first (word ordinal)
         Rule {
             name: "on the <ordinal-word>".to_string(),
@@ -2505,6 +2870,18 @@ pub fn rules() -> Vec<Rule> {
                 }
             }),
         },
+        // "Q3": latent, as the letter and digit alone are often a code
+        Rule {
+            name: "Q<n>".to_string(),
+            pattern: vec![regex(r"\bq([1-4])\b")],
+            production: Box::new(|nodes| {
+                let TokenData::RegexMatch(m) = &nodes[0].token_data else {
+                    return None;
+                };
+                let q = m.group(1)?.parse().ok()?;
+                Some(TokenData::Time(TimeData::latent(TimeForm::Quarter(q))))
+            }),
+        },
         // "the 3rd qtr" - with "the" prefix
         Rule {
             name: "the <ordinal> quarter".to_string(),
@@ -2640,7 +3017,7 @@ pub fn rules() -> Vec<Rule> {
             name: "beginning of <grain>".to_string(),
             pattern: vec![
                 regex(r"\b(beginning|start) of( the| this| current)?\b"),
-                regex(r"\b(week|month|year)\b"),
+                regex(r"\b(week|month|quarter|year)\b"),
             ],
             production: Box::new(|nodes| {
                 let grain_text = match &nodes[1].token_data {
@@ -2658,7 +3035,7 @@ pub fn rules() -> Vec<Rule> {
             name: "end of <grain>".to_string(),
             pattern: vec![
                 regex(r"\b(end) of( the| this| current)?\b"),
-                regex(r"\b(week|month|year)\b"),
+                regex(r"\b(week|month|quarter|year)\b"),
             ],
             production: Box::new(|nodes| {
                 let grain_text = match &nodes[1].token_data {
@@ -2678,7 +3055,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![
                 regex(r"\b(at the )?(beginning|start) of( the| around)?\b"),
                 regex(r"\b(next|last|past|previous|coming|following)\b"),
-                regex(r"\b(week|month|year)\b"),
+                regex(r"\b(week|month|quarter|year)\b"),
             ],
             production: Box::new(|nodes| {
                 let modifier = match &nodes[1].token_data {
@@ -2702,7 +3079,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![
                 regex(r"\b(at the )?(end) of( the| around)?\b"),
                 regex(r"\b(next|last|past|previous|coming|following)\b"),
-                regex(r"\b(week|month|year)\b"),
+                regex(r"\b(week|month|quarter|year)\b"),
             ],
             production: Box::new(|nodes| {
                 let modifier = match &nodes[1].token_data {
@@ -2735,18 +3112,33 @@ pub fn rules() -> Vec<Rule> {
                 };
                 let begin = begin_text.to_lowercase() != "end";
                 let t = time_data(&nodes[1].token_data)?;
-                match &t.form {
-                    TimeForm::Month(_) | TimeForm::Year(_) => {
-                        Some(TokenData::Time(TimeData::new(TimeForm::BeginEnd {
-                            begin,
-                            target: Box::new(t.form.clone()),
-                        })))
-                    }
-                    _ => None,
+                if !is_part_of_period_target(&t.form) {
+                    return None;
                 }
+                Some(TokenData::Time(TimeData::new(TimeForm::BeginEnd {
+                    begin,
+                    target: Box::new(t.form.clone()),
+                })))
+            }),
+        },
+        // "middle of the month", "the middle of next week", "mid-Q3"
+        Rule {
+            name: "middle of <time>".to_string(),
+            pattern: vec![
+                regex(r"\b(the )?(middle|mid)( of)?\b"),
+                predicate(|td| {
+                    matches!(td, TokenData::Time(t) if is_part_of_period_target(&t.form))
+                }),
+            ],
+            production: Box::new(|nodes| {
+                let mut t = time_data(&nodes[1].token_data)?.clone();
+                t.latent = false;
+                t.early_late = Some(EarlyLate::Mid);
+                Some(TokenData::Time(t))
             }),
         },
-        // EOM / BOM / EOY / BOY / EOD (with optional "by" prefix)
+        // EOM / BOM / EOY / BOY / EOD (with optional "by" prefix); EOD is the
+        // end of the business day, like COB
         Rule {
             name: "EOM/BOM/EOY/BOY/EOD".to_string(),
             pattern: vec![regex(r"\b(by (the )?|(at )?the )?(eom|bom|eoy|boy|eod)\b")],
@@ -2758,13 +3150,15 @@ pub fn rules() -> Vec<Rule> {
                 let prefix = m.group(0)?.to_lowercase();
                 let has_by = prefix.starts_with("by");
                 let abbr = m.group(4)?.to_lowercase();
+                if abbr == "eod" {
+                    return Some(end_of_business(TimeData::new(TimeForm::Today), has_by));
+                }
 
                 if has_by {
-                    // "by EOM/EOD/EOY" → interval from now to end of period
+                    // "by EOM/EOY" → interval from now to end of period
                     let grain = match abbr.as_str() {
                         "eom" | "bom" => Grain::Month,
                         "eoy" | "boy" => Grain::Year,
-                        "eod" => Grain::Day,
                         _ => return None,
                     };
                     let end_form = TimeForm::BeginEnd {
@@ -2806,18 +3200,82 @@ pub fn rules() -> Vec<Rule> {
                                 offset: 0,
                             }),
                         }))),
-                        "eod" => Some(TokenData::Time(TimeData::new(TimeForm::BeginEnd {
-                            begin: false,
-                            target: Box::new(TimeForm::GrainOffset {
-                                grain: Grain::Day,
-                                offset: 0,
-                            }),
-                        }))),
                         _ => None,
                     }
                 }
             }),
         },
+        // COB / EOB / close of business (with optional "by" prefix)
+        Rule {
+            name: "COB/EOB/close of business".to_string(),
+            pattern: vec![regex(
+                r"\b(by (the )?)?(cob|eob|close of (the )?business( day)?|end of (the )?business( day)?)\b",
+            )],
+            production: Box::new(|nodes| {
+                let by = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(1).is_some(),
+                    _ => return None,
+                };
+                Some(end_of_business(TimeData::new(TimeForm::Today), by))
+            }),
+        },
+        // "EOD Friday", "by COB tomorrow", "close of business on the 15th"
+        Rule {
+            name: "EOD|COB <day>".to_string(),
+            pattern: vec![
+                regex(
+                    r"\b(by (the )?)?(eod|cob|eob|close of (the )?business( day)?|end of (the )?(business )?day)( on)?\b",
+                ),
+                predicate(is_business_deadline_day),
+            ],
+            production: Box::new(|nodes| {
+                let by = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(1).is_some(),
+                    _ => return None,
+                };
+                let day = time_data(&nodes[1].token_data)?;
+                Some(end_of_business(day.clone(), by))
+            }),
+        },
+        // EOW: close of business on Friday of the current week
+        Rule {
+            name: "EOW".to_string(),
+            pattern: vec![regex(r"\b(by (the )?|(at )?the )?eow\b")],
+            production: Box::new(|nodes| {
+                let by = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(0)?.to_lowercase().starts_with("by"),
+                    _ => return None,
+                };
+                Some(end_of_business(TimeData::new(TimeForm::DayOfWeek(4)), by))
+            }),
+        },
+        // EOQ (with optional "by" prefix), like EOM/EOY
+        Rule {
+            name: "EOQ".to_string(),
+            pattern: vec![regex(r"\b(by (the )?|(at )?the )?eoq\b")],
+            production: Box::new(|nodes| {
+                let by = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(0)?.to_lowercase().starts_with("by"),
+                    _ => return None,
+                };
+                let end_form = TimeForm::BeginEnd {
+                    begin: false,
+                    target: Box::new(TimeForm::GrainOffset {
+                        grain: Grain::Quarter,
+                        offset: 0,
+                    }),
+                };
+                if by {
+                    Some(TokenData::Time(TimeData::new(TimeForm::Interval(
+                        Box::new(TimeData::new(TimeForm::Now)),
+                        Box::new(TimeData::new(end_form)),
+                        false,
+                    ))))
+                } else {
+                    Some(TokenData::Time(TimeData::new(end_form)))
+                }
+            }),
+        },
         // "end of the month" / "beginning of the month" / "end of the year" / "end of day" / etc.
         Rule {
             name: "end/beginning of the day/month/year".to_string(),
@@ -2860,6 +3318,10 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex(r"\bby( the)?\b"), dim(DimensionKind::Time)],
             production: Box::new(|nodes| {
                 let t = time_data(&nodes[1].token_data)?;
+                // Business deadlines carry their own "by" handling
+                if matches!(t.form, TimeForm::EndOfBusiness(_)) {
+                    return None;
+                }
                 Some(TokenData::Time(TimeData::new(TimeForm::Interval(
                     Box::new(TimeData::new(TimeForm::Now)),
                     Box::new(t.clone()),
@@ -2975,7 +3437,7 @@ pub fn rules() -> Vec<Rule> {
                     _ => return None,
                 };
                 Some(TokenData::Time(TimeData::new(TimeForm::Holiday(
-                    text.to_lowercase(),
+                    holiday_key(text),
                     None,
                 ))))
             }),
@@ -3012,6 +3474,41 @@ pub fn rules() -> Vec<Rule> {
                 ))))
             }),
         },
+        // "New Year's Eve eve", "the eve of Easter": the day before
+        Rule {
+            name: "<holiday> eve".to_string(),
+            pattern: vec![
+                predicate(
+                    |td| matches!(td, TokenData::Time(d) if matches!(d.form, TimeForm::Holiday(..))),
+                ),
+                regex(r"\beve\b"),
+            ],
+            production: Box::new(|nodes| {
+                let base = time_data(&nodes[0].token_data)?;
+                Some(TokenData::Time(TimeData::new(TimeForm::CycleNthAfter {
+                    n: -1,
+                    grain: Grain::Day,
+                    base: Box::new(base.clone()),
+                })))
+            }),
+        },
+        Rule {
+            name: "the eve of <holiday>".to_string(),
+            pattern: vec![
+                regex(r"\b(the\s+)?eve\s+of\b"),
+                predicate(
+                    |td| matches!(td, TokenData::Time(d) if matches!(d.form, TimeForm::Holiday(..))),
+                ),
+            ],
+            production: Box::new(|nodes| {
+                let base = time_data(&nodes[1].token_data)?;
+                Some(TokenData::Time(TimeData::new(TimeForm::CycleNthAfter {
+                    n: -1,
+                    grain: Grain::Day,
+                    base: Box::new(base.clone()),
+                })))
+            }),
+        },
         // <holiday> in <year>
         Rule {
             name: "<holiday> in <year>".to_string(),
@@ -3045,12 +3542,43 @@ pub fn rules() -> Vec<Rule> {
                 ))))
             }),
         },
+        // <holiday> (observed) — moved off a weekend per the region's policy
+        Rule {
+            name: "<holiday> (observed)".to_string(),
+            pattern: vec![
+                predicate(
+                    |td| matches!(td, TokenData::Time(d) if matches!(d.form, TimeForm::Holiday(..))),
+                ),
+                regex(r"\(?observed\b\)?"),
+            ],
+            production: Box::new(|nodes| {
+                let holiday = time_data(&nodes[0].token_data)?;
+                Some(TokenData::Time(TimeData::new(TimeForm::Observed(Box::new(
+                    holiday.clone(),
+                )))))
+            }),
+        },
+        Rule {
+            name: "observed <holiday>".to_string(),
+            pattern: vec![
+                regex(r"\b(the )?observed\b"),
+                predicate(
+                    |td| matches!(td, TokenData::Time(d) if matches!(d.form, TimeForm::Holiday(..))),
+                ),
+            ],
+            production: Box::new(|nodes| {
+                let holiday = time_data(&nodes[1].token_data)?;
+                Some(TokenData::Time(TimeData::new(TimeForm::Observed(Box::new(
+                    holiday.clone(),
+                )))))
+            }),
+        },
         // <duration> after/from <time> (e.g., "3 days after christmas", "15 min from 1pm")
         // Haskell: durationAfter — shifts each occurrence of time by duration
         Rule {
             name: "<duration> after <time>".to_string(),
             pattern: vec![
-                dim(DimensionKind::Duration),
+                predicate(is_unprefixed_duration),
                 regex(r"\b(after|from)\b"),
                 dim(DimensionKind::Time),
             ],
@@ -3081,6 +3609,9 @@ pub fn rules() -> Vec<Rule> {
             production: Box::new(|nodes| {
                 let t1 = time_data(&nodes[0].token_data)?;
                 let t2 = time_data(&nodes[1].token_data)?;
+                if is_latent_hour_with_long_cycle(t1, t2) {
+                    return None;
+                }
                 Some(TokenData::Time(compose(t1, t2)))
             }),
         },
@@ -3110,7 +3641,7 @@ pub fn rules() -> Vec<Rule> {
                     || (matches!(t2.form, TimeForm::Month(_))
                         && t1.latent
                         && matches!(t1.form, TimeForm::Hour(_, _)));
-                if month_plus_latent_hour {
+                if month_plus_latent_hour || is_latent_hour_with_long_cycle(t1, t2) {
                     return None;
                 }
                 Some(TokenData::Time(compose(t1, t2)))
@@ -3134,6 +3665,27 @@ pub fn rules() -> Vec<Rule> {
                 Some(TokenData::Time(compose(t1, t2)))
             }),
         },
+        // <time> "of" <cycle>: the rule above swallows "the", which leaves no
+        // token for cycles whose own rule starts with it ("the following
+        // month", "the month after next")
+        Rule {
+            name: "intersect by \"of\", \"from\", \"for\" <cycle>".to_string(),
+            pattern: vec![
+                dim(DimensionKind::Time),
+                regex(r"\b(of|from|for)\b"),
+                predicate(|td| {
+                    matches!(td, TokenData::Time(d) if matches!(d.form, TimeForm::GrainOffset { .. }))
+                }),
+            ],
+            production: Box::new(|nodes| {
+                let t1 = time_data(&nodes[0].token_data)?;
+                let t2 = time_data(&nodes[2].token_data)?;
+                if t1.latent {
+                    return None;
+                }
+                Some(TokenData::Time(compose(t1, t2)))
+            }),
+        },
         // ====================================================================
         // Time intervals
         // ====================================================================
@@ -3453,16 +4005,7 @@ pub fn rules() -> Vec<Rule> {
                     TokenData::Duration(d) => d,
                     _ => return None,
                 };
-                let end = TimeData::new(TimeForm::DurationAfter {
-                    n: dur.value,
-                    grain: dur.grain,
-                    base: Box::new(t.clone()),
-                });
-                Some(TokenData::Time(TimeData::new(TimeForm::Interval(
-                    Box::new(t.clone()),
-                    Box::new(end),
-                    false,
-                ))))
+                Some(TokenData::Time(interval_for_duration(t, dur)))
             }),
         },
         // Haskell: ruleIntervalForDurationFrom — "for <duration> from <time>"
@@ -3480,16 +4023,7 @@ pub fn rules() -> Vec<Rule> {
                     _ => return None,
                 };
                 let t = time_data(&nodes[3].token_data)?;
-                let end = TimeData::new(TimeForm::DurationAfter {
-                    n: dur.value,
-                    grain: dur.grain,
-                    base: Box::new(t.clone()),
-                });
-                Some(TokenData::Time(TimeData::new(TimeForm::Interval(
-                    Box::new(t.clone()),
-                    Box::new(end),
-                    false,
-                ))))
+                Some(TokenData::Time(interval_for_duration(t, dur)))
             }),
         },
         // Haskell: ruleIntervalFromTimeForDuration — "from <time> for <duration>"
@@ -3507,16 +4041,7 @@ pub fn rules() -> Vec<Rule> {
                     _ => return None,
                 };
                 let t = time_data(&nodes[1].token_data)?;
-                let end = TimeData::new(TimeForm::DurationAfter {
-                    n: dur.value,
-                    grain: dur.grain,
-                    base: Box::new(t.clone()),
-                });
-                Some(TokenData::Time(TimeData::new(TimeForm::Interval(
-                    Box::new(t.clone()),
-                    Box::new(end),
-                    false,
-                ))))
+                Some(TokenData::Time(interval_for_duration(t, dur)))
             }),
         },
         // ====================================================================
@@ -3524,16 +4049,8 @@ pub fn rules() -> Vec<Rule> {
         // ====================================================================
         Rule {
             name: "half after|past <hour-of-day>".to_string(),
-            pattern: vec![regex(r"\bhalf (past|after)?\b"), dim(DimensionKind::Time)],
-            production: Box::new(|nodes| {
-                let t = time_data(&nodes[1].token_data)?;
-                match &t.form {
-                    TimeForm::Hour(h, is_12h) => Some(TokenData::Time(TimeData::new(
-                        TimeForm::HourMinute(*h, 30, *is_12h),
-                    ))),
-                    _ => None,
-                }
-            }),
+            pattern: vec![regex(r"\bhalf (past|after)\b"), dim(DimensionKind::Time)],
+            production: Box::new(|nodes| clock_hour_offset(&nodes[1].token_data, 30)),
         },
         Rule {
             name: "quarter after|past <hour-of-day>".to_string(),
@@ -3541,34 +4058,15 @@ pub fn rules() -> Vec<Rule> {
                 regex(r"\b(a )?quarter (past|after)\b"),
                 dim(DimensionKind::Time),
             ],
-            production: Box::new(|nodes| {
-                let t = time_data(&nodes[1].token_data)?;
-                match &t.form {
-                    TimeForm::Hour(h, is_12h) => Some(TokenData::Time(TimeData::new(
-                        TimeForm::HourMinute(*h, 15, *is_12h),
-                    ))),
-                    _ => None,
-                }
-            }),
+            production: Box::new(|nodes| clock_hour_offset(&nodes[1].token_data, 15)),
         },
         Rule {
             name: "quarter to|till|before <hour-of-day>".to_string(),
             pattern: vec![
-                regex(r"\b(a )?quarter (to|before|of)\b"),
+                regex(r"\b(a )?quarter (to|till?|until|before|of)\b"),
                 dim(DimensionKind::Time),
             ],
-            production: Box::new(|nodes| {
-                let t = time_data(&nodes[1].token_data)?;
-                match &t.form {
-                    TimeForm::Hour(h, is_12h) => {
-                        let hour = if *h == 0 { 23 } else { h.checked_sub(1)? };
-                        Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(
-                            hour, 45, *is_12h,
-                        ))))
-                    }
-                    _ => None,
-                }
-            }),
+            production: Box::new(|nodes| clock_hour_offset(&nodes[1].token_data, -15)),
         },
         // <integer> (minutes)? past/after <time> (e.g., "15 past 3pm", "20 minutes past 3pm")
         Rule {
@@ -3579,40 +4077,46 @@ pub fn rules() -> Vec<Rule> {
                 dim(DimensionKind::Time),
             ],
             production: Box::new(|nodes| {
-                let mins = numeral_data(&nodes[0].token_data)?.value as u32;
-                let t = time_data(&nodes[2].token_data)?;
-                match &t.form {
-                    TimeForm::Hour(h, is_12h) => Some(TokenData::Time(TimeData::new(
-                        TimeForm::HourMinute(*h, mins, *is_12h),
-                    ))),
-                    TimeForm::HourMinute(h, 0, is_12h) => Some(TokenData::Time(TimeData::new(
-                        TimeForm::HourMinute(*h, mins, *is_12h),
-                    ))),
-                    _ => None,
-                }
+                let mins = i32::try_from(integer_value(&nodes[0].token_data)?).ok()?;
+                clock_hour_offset(&nodes[2].token_data, mins)
             }),
         },
-        // <integer> (minutes)? to/before <time>
+        // <integer> (minutes)? to/before <time>; "ten of five" in the US
         Rule {
             name: "<integer> to <time>".to_string(),
             pattern: vec![
                 predicate(is_integer_between(1, 59)),
-                regex(r"\b(minutes? )?(to|before|of|til)\b"),
+                regex(r"\b(minutes? )?(to|till?|until|before|of)\b"),
                 dim(DimensionKind::Time),
             ],
             production: Box::new(|nodes| {
-                let mins = numeral_data(&nodes[0].token_data)?.value as u32;
-                let t = time_data(&nodes[2].token_data)?;
-                match &t.form {
-                    TimeForm::Hour(h, is_12h) | TimeForm::HourMinute(h, 0, is_12h) => {
-                        let hour = if *h == 0 { 23 } else { h.checked_sub(1)? };
-                        let minute = 60_u32.checked_sub(mins)?;
-                        Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(
-                            hour, minute, *is_12h,
-                        ))))
-                    }
-                    _ => None,
-                }
+                let mins = i32::try_from(integer_value(&nodes[0].token_data)?).ok()?;
+                clock_hour_offset(&nodes[2].token_data, mins.checked_neg()?)
+            }),
+        },
+        // Archaic "five and twenty past four" (4:25), "five and twenty to four" (3:35)
+        Rule {
+            name: "<units> and twenty past|to <hour-of-day>".to_string(),
+            pattern: vec![
+                regex(r"\b(one|two|three|four|five|six|seven|eight|nine)[ -]and[ -]twenty (minutes )?(past|after|to|till?|until|before|of)\b"),
+                dim(DimensionKind::Time),
+            ],
+            production: Box::new(|nodes| {
+                let (units, connector) = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => (m.group(1)?.to_lowercase(), m.group(3)?.to_lowercase()),
+                    _ => return None,
+                };
+                let units = [
+                    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+                ]
+                .iter()
+                .position(|w| *w == units)?;
+                let mins = 21_i32.checked_add(i32::try_from(units).ok()?)?;
+                let mins = match connector.as_str() {
+                    "past" | "after" => mins,
+                    _ => mins.checked_neg()?,
+                };
+                clock_hour_offset(&nodes[1].token_data, mins)
             }),
         },
         // <time> <integer> (e.g., "at 3 15" → 3:15)
@@ -3953,7 +4457,7 @@ pub fn rules() -> Vec<Rule> {
                     _ => return None,
                 };
                 let base = time_data(&nodes[2].token_data)?;
-                Some(TokenData::Time(TimeData::new(TimeForm::NthGrainOfTime {
+                Some(TokenData::Time(TimeData::new(TimeForm::CycleNthAfter {
                     n: sign,
                     grain,
                     base: Box::new(base.clone()),
@@ -4006,7 +4510,7 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "<duration> after|before|from|past <time>".to_string(),
-            pattern: vec![dim(DimensionKind::Duration), regex(r"\b(after|before|from|past)\b"), dim(DimensionKind::Time)],
+            pattern: vec![predicate(is_unprefixed_duration), regex(r"\b(after|before|from|past)\b"), dim(DimensionKind::Time)],
             production: Box::new(|nodes| {
                 let dur = match &nodes[0].token_data {
                     TokenData::Duration(d) => d,
@@ -4116,34 +4620,16 @@ pub fn rules() -> Vec<Rule> {
             name: "<integer> to|till|before <hour-of-day>".to_string(),
             pattern: vec![predicate(is_integer_between(1, 59)), regex(r"\b(to|till|before)\b"), predicate(|td| matches!(td, TokenData::Time(d) if matches!(d.form, TimeForm::Hour(_, _) | TimeForm::HourMinute(_, 0, _))))],
             production: Box::new(|nodes| {
-                let mins = numeral_data(&nodes[0].token_data)?.value as u32;
-                let (h, is12) = match &nodes[2].token_data {
-                    TokenData::Time(d) => match d.form {
-                        TimeForm::Hour(h, is12) => (h, is12),
-                        TimeForm::HourMinute(h, 0, is12) => (h, is12),
-                        _ => return None,
-                    },
-                    _ => return None,
-                };
-                let hour = if h == 0 { 23 } else { h.checked_sub(1)? };
-                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(hour, 60_u32.checked_sub(mins)?, is12))))
+                let mins = i32::try_from(integer_value(&nodes[0].token_data)?).ok()?;
+                clock_hour_offset(&nodes[2].token_data, mins.checked_neg()?)
             }),
         },
         Rule {
             name: "<integer> minutes to|till|before <hour-of-day>".to_string(),
             pattern: vec![predicate(is_integer_between(1, 59)), regex(r"\bminutes?\b"), regex(r"\b(to|till|before)\b"), predicate(|td| matches!(td, TokenData::Time(d) if matches!(d.form, TimeForm::Hour(_, _) | TimeForm::HourMinute(_, 0, _))))],
             production: Box::new(|nodes| {
-                let mins = numeral_data(&nodes[0].token_data)?.value as u32;
-                let (h, is12) = match &nodes[3].token_data {
-                    TokenData::Time(d) => match d.form {
-                        TimeForm::Hour(h, is12) => (h, is12),
-                        TimeForm::HourMinute(h, 0, is12) => (h, is12),
-                        _ => return None,
-                    },
-                    _ => return None,
-                };
-                let hour = if h == 0 { 23 } else { h.checked_sub(1)? };
-                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(hour, 60_u32.checked_sub(mins)?, is12))))
+                let mins = i32::try_from(integer_value(&nodes[0].token_data)?).ok()?;
+                clock_hour_offset(&nodes[3].token_data, mins.checked_neg()?)
             }),
         },
         Rule {
@@ -4312,7 +4798,7 @@ pub fn rules() -> Vec<Rule> {
                     _ => return None,
                 };
                 let base = time_data(&nodes[3].token_data)?;
-                Some(TokenData::Time(TimeData::new(TimeForm::NthGrainOfTime {
+                Some(TokenData::Time(TimeData::new(TimeForm::CycleNthAfter {
                     n: sign,
                     grain,
                     base: Box::new(base.clone()),
@@ -4382,6 +4868,10 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex(r"\bby( the)?\b"), dim(DimensionKind::Time)],
             production: Box::new(|nodes| {
                 let t = time_data(&nodes[1].token_data)?;
+                // Business deadlines carry their own "by" handling
+                if matches!(t.form, TimeForm::EndOfBusiness(_)) {
+                    return None;
+                }
                 Some(TokenData::Time(TimeData::new(TimeForm::Interval(
                     Box::new(TimeData::new(TimeForm::Now)),
                     Box::new(t.clone()),
@@ -4645,24 +5135,6 @@ pub fn rules() -> Vec<Rule> {
                 Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(hour, 30, is12))))
             }),
         },
-        Rule {
-            name: "half <integer> (UK style hour-of-day)".to_string(),
-            pattern: vec![
-                regex(r"\bhalf\b"),
-                predicate(|td| matches!(td, TokenData::Time(d) if matches!(d.form, TimeForm::Hour(_, _) | TimeForm::HourMinute(_, 0, _)))),
-            ],
-            production: Box::new(|nodes| {
-                let (h, is12) = match &nodes[1].token_data {
-                    TokenData::Time(d) => match d.form {
-                        TimeForm::Hour(h, is12) => (h, is12),
-                        TimeForm::HourMinute(h, 0, is12) => (h, is12),
-                        _ => return None,
-                    },
-                    _ => return None,
-                };
-                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(h, 30, is12))))
-            }),
-        },
         Rule {
             name: "nth <day-of-week> of <month-or-greater>".to_string(),
             pattern: vec![
@@ -4759,6 +5231,141 @@ pub fn rules() -> Vec<Rule> {
     ]
 }
 
+/// Numeric dates with `/` or `-` between day, month and optional year.
+const SLASH_DATE: &str = r"\b(\d{1,2})\s?[/\-]\s?(\d{1,2})(?:\s?[/\-]\s?(\d{2,4}))?\b";
+
+/// The date a `SLASH_DATE` match names, day first or month first. Unless
+/// `ambiguous`, only dates that can't be read the other way round (a day
+/// past 12) are taken.
+fn slash_date(token: &TokenData, day_first: bool, ambiguous: bool) -> Option<TokenData> {
+    let TokenData::RegexMatch(m) = token else {
+        return None;
+    };
+    let v1: u32 = m.group(1)?.parse().ok()?;
+    let v2: u32 = m.group(2)?.parse().ok()?;
+    let (month, day) = if day_first { (v2, v1) } else { (v1, v2) };
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) || (!ambiguous && day <= 12) {
+        return None;
+    }
+    let year = m.group(3).and_then(|y| {
+        let yr: i32 = y.parse().ok()?;
+        // 2-digit year: 00-49 → 2000s, 50-99 → 1900s
+        match yr {
+            0..=49 => yr.checked_add(2000),
+            50..=99 => yr.checked_add(1900),
+            _ => Some(yr),
+        }
+    });
+    Some(TokenData::Time(TimeData::new(TimeForm::DateMDY {
+        month,
+        day,
+        year,
+    })))
+}
+
+/// Numeric dates read day first, where "3/4" is 3 April. They replace the
+/// month-first pair of the base rules outside North America and the
+/// Philippines.
+pub fn day_first_date_rules() -> Vec<Rule> {
+    vec![
+        Rule {
+            name: "date DD/MM(/YYYY)".to_string(),
+            pattern: vec![regex(SLASH_DATE)],
+            production: Box::new(|nodes| slash_date(&nodes[0].token_data, true, true)),
+        },
+        // MM/DD(/YY) where first number can't be a day-first month (e.g., "12/25")
+        Rule {
+            name: "date MM/DD(/YY)".to_string(),
+            pattern: vec![regex(SLASH_DATE)],
+            production: Box::new(|nodes| slash_date(&nodes[0].token_data, false, false)),
+        },
+    ]
+}
+
+/// "half three" for 3:30, as said in British English. Region overlays leave
+/// it out where the phrase isn't idiomatic.
+pub fn uk_half_hour_rules() -> Vec<Rule> {
+    vec![Rule {
+        name: "half <integer> (UK style hour-of-day)".to_string(),
+        pattern: vec![
+            regex(r"\bhalf\b"),
+            predicate(
+                |td| matches!(td, TokenData::Time(d) if matches!(d.form, TimeForm::Hour(_, _) | TimeForm::HourMinute(_, 0, _))),
+            ),
+        ],
+        production: Box::new(|nodes| {
+            let (h, is12) = match &nodes[1].token_data {
+                TokenData::Time(d) => match d.form {
+                    TimeForm::Hour(h, is12) => (h, is12),
+                    TimeForm::HourMinute(h, 0, is12) => (h, is12),
+                    _ => return None,
+                },
+                _ => return None,
+            };
+            Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(
+                h, 30, is12,
+            ))))
+        }),
+    }]
+}
+
+/// Rules for every language: Unix timestamps in seconds (10 digits) or
+/// milliseconds (13 digits), which only resolve with
+/// `Options::parse_epoch_timestamps`, and a time followed by a duration whose
+/// prefix marks it as spanning ("3pm for 2 hours", "ab Dienstag für 3 Tage").
+pub fn common_rules() -> Vec<Rule> {
+    vec![
+        Rule {
+            name: "<time> <spanning duration>".to_string(),
+            pattern: vec![
+                predicate(is_not_latent_time),
+                predicate(
+                    |td| matches!(td, TokenData::Duration(d) if d.qualifier == Some(DurationQualifier::Spanning)),
+                ),
+            ],
+            production: Box::new(|nodes| {
+                let t = time_data(&nodes[0].token_data)?;
+                let dur = match &nodes[1].token_data {
+                    TokenData::Duration(d) => d,
+                    _ => return None,
+                };
+                // "starting Tuesday" opens an interval; the duration closes it
+                let anchor = match t.open_interval_direction {
+                    Some(IntervalDirection::Before) => return None,
+                    Some(IntervalDirection::After) => TimeData {
+                        open_interval_direction: None,
+                        ..t.clone()
+                    },
+                    None => t.clone(),
+                };
+                if matches!(anchor.form, TimeForm::Interval(..)) {
+                    return None;
+                }
+                Some(TokenData::Time(interval_for_duration(&anchor, dur)))
+            }),
+        },
+        Rule {
+            name: "unix timestamp".to_string(),
+            pattern: vec![regex(r"\b(\d{10}|\d{13})\b")],
+            production: Box::new(|nodes| {
+                let digits = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?,
+                    _ => return None,
+                };
+                let n: i64 = digits.parse().ok()?;
+                let millis = if digits.len() == 10 {
+                    n.checked_mul(1000)?
+                } else {
+                    n
+                };
+                let mut td = TimeData::new(TimeForm::Timestamp(millis));
+                td.timezone = Some("UTC".to_string());
+                Some(TokenData::Time(td))
+            }),
+        },
+    ]
+}
+
 // ====================================================================
 // Helper functions
 // ====================================================================
@@ -4803,6 +5410,63 @@ fn apply_ampm(form: &TimeForm, is_pm: bool) -> Option<TokenData> {
     }
 }
 
+/// Date and time of day from the ISO-8601 groups year, month, day, hour,
+/// minute, optional second and optional UTC offset. The offset is kept as the
+/// timezone name ("UTC" or "+hh:mm") so the resolver yields the instant it
+/// names, independent of the context's offset.
+fn iso_datetime(m: &RegexMatchData) -> Option<TokenData> {
+    let year: i32 = m.group(1)?.parse().ok()?;
+    let month: u32 = m.group(2)?.parse().ok()?;
+    let day: u32 = m.group(3)?.parse().ok()?;
+    let hour: u32 = m.group(4)?.parse().ok()?;
+    let minute: u32 = m.group(5)?.parse().ok()?;
+    let second: Option<u32> = match m.group(6) {
+        Some(s) => Some(s.parse().ok()?),
+        None => None,
+    };
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) || hour > 23 || minute > 59 {
+        return None;
+    }
+    let date = TimeData::new(TimeForm::DateMDY {
+        month,
+        day,
+        year: Some(year),
+    });
+    let tod = match second {
+        Some(s) if s <= 59 => TimeData::new(TimeForm::HourMinuteSecond(hour, minute, s)),
+        Some(_) => return None,
+        None => TimeData::new(TimeForm::HourMinute(hour, minute, false)),
+    };
+    let mut composed = TimeData::new(TimeForm::Composed(Box::new(date), Box::new(tod)));
+    composed.timezone = match m.group(7) {
+        Some("Z" | "z") => Some("UTC".to_string()),
+        Some(offset) => {
+            let (sign, digits) = offset.split_at(1);
+            let digits = digits.replace(':', "");
+            let (hh, mm) = digits.split_at(2);
+            let mm = if mm.is_empty() { "00" } else { mm };
+            Some(format!("{sign}{hh}:{mm}"))
+        }
+        None => None,
+    };
+    composed.absolute_offset = composed.timezone.is_some();
+    Some(TokenData::Time(composed))
+}
+
+/// The closed interval from `start` until `dur` after it.
+fn interval_for_duration(start: &TimeData, dur: &DurationData) -> TimeData {
+    let end = TimeData::new(TimeForm::DurationAfter {
+        n: dur.value,
+        grain: dur.grain,
+        base: Box::new(start.clone()),
+    });
+    TimeData::new(TimeForm::Interval(
+        Box::new(start.clone()),
+        Box::new(end),
+        false,
+    ))
+}
+
 fn text_to_grain(text: &str) -> Option<Grain> {
     match text.to_lowercase().as_ref() {
         "second" | "seconds" => Some(Grain::Second),
@@ -4847,6 +5511,7 @@ fn month_name_to_num(name: &str) -> Option<u32> {
 fn holidays_regex() -> String {
     let holidays = [
         // Christmas
+        r"(christmas|xmas)\s+eve",
         r"christmas(\s+day)?",
         r"xmas(\s+day)?",
         // New Year
@@ -4863,6 +5528,7 @@ fn holidays_regex() -> String {
         r"thanksgiving(\s+day)?",
         // US/Intl: Memorial / Labor-Labour and locale-specific civic holidays
         r"(memorial|decoration)\s+day",
+        r"labou?r\s+day\s+week(\s|-)?ends?",
         r"labou?r\s+day",
         r"independence\s+day",
         r"canada\s+day",
@@ -4890,7 +5556,6 @@ fn holidays_regex() -> String {
         r"(civic\s+holiday|(british\s+columbia|civic|natal|new\s+brunswick|saskatchewan|terry\s+fox)\s+day)",
         r"(family|islander|louis\s+riel|nova\s+scotia\s+heritage)\s+day",
         r"national\s+patriot('?s|s')?\s+day",
-        r"labou?r\s+day\s+week(\s|-)?ends?",
         r"heritage\s+day",
         r"veterans?\s+day",
         r"law\s+day",
@@ -5097,7 +5762,10 @@ mod tests {
     #[test]
     fn test_days_of_week() {
         let rules = all_rules();
-        let options = Options { with_latent: false };
+        let options = Options {
+            with_latent: false,
+            ..Options::default()
+        };
         let context = Context::default();
 
         for day in &[
@@ -5121,7 +5789,10 @@ mod tests {
     #[test]
     fn test_today_tomorrow() {
         let rules = all_rules();
-        let options = Options { with_latent: false };
+        let options = Options {
+            with_latent: false,
+            ..Options::default()
+        };
         let context = Context::default();
 
         for text in &["today", "tomorrow", "yesterday"] {
@@ -5137,7 +5808,10 @@ mod tests {
     #[test]
     fn test_clock_time() {
         let rules = all_rules();
-        let options = Options { with_latent: false };
+        let options = Options {
+            with_latent: false,
+            ..Options::default()
+        };
         let context = Context::default();
 
         let entities =
@@ -5151,7 +5825,10 @@ mod tests {
     #[test]
     fn test_in_duration() {
         let rules = all_rules();
-        let options = Options { with_latent: false };
+        let options = Options {
+            with_latent: false,
+            ..Options::default()
+        };
         let context = Context::default();
 
         let entities = engine::parse_and_resolve(
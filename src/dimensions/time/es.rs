@@ -1,5 +1,6 @@
 use crate::dimensions::numeral::helpers::integer_value;
 use crate::dimensions::time_grain::Grain;
+use crate::locale::Region;
 use crate::pattern::{dim, predicate, regex};
 use crate::types::{DimensionKind, Rule, TokenData};
 
@@ -104,7 +105,7 @@ fn to_h24_for_part(hour: u32, pod: PartOfDay) -> u32 {
                 hour
             }
         }
-        PartOfDay::Afternoon | PartOfDay::Evening | PartOfDay::Night => {
+        PartOfDay::Afternoon | PartOfDay::Evening | PartOfDay::Night | PartOfDay::Dinner => {
             if hour < 12 {
                 hour.saturating_add(12)
             } else {
@@ -112,6 +113,10 @@ fn to_h24_for_part(hour: u32, pod: PartOfDay) -> u32 {
             }
         }
         PartOfDay::Lunch => 12,
+        PartOfDay::Breakfast
+        | PartOfDay::Overnight
+        | PartOfDay::Noonish
+        | PartOfDay::Midnightish => hour,
     }
 }
 
@@ -853,3 +858,84 @@ pub fn rules() -> Vec<Rule> {
     ]);
     rules
 }
+
+fn fixed_date_rule(name: &str, pattern: &str, month: u32, day: u32) -> Rule {
+    Rule {
+        name: name.to_string(),
+        pattern: vec![regex(pattern)],
+        production: Box::new(move |_| {
+            Some(TokenData::Time(TimeData::new(TimeForm::DateMDY {
+                month,
+                day,
+                year: None,
+            })))
+        }),
+    }
+}
+
+/// "día de la independencia", dated for the region by `RegionalHolidays`.
+fn independence_day_rule(name: &str) -> Rule {
+    Rule {
+        name: name.to_string(),
+        pattern: vec![regex("d(í|i)a de la independencia")],
+        production: Box::new(|_| {
+            Some(TokenData::Time(TimeData::new(TimeForm::Holiday(
+                "independence day".to_string(),
+                None,
+            ))))
+        }),
+    }
+}
+
+/// Region overlays for Spanish: national holidays of Mexico (ES-MX),
+/// Argentina (ES-AR) and Spain (ES-ES).
+pub fn locale_rules(region: Region) -> Vec<Rule> {
+    match region {
+        Region::MX => vec![
+            fixed_date_rule(
+                "Día de Muertos (es_MX)",
+                "d(í|i)a de (los )?(fieles )?(muertos|difuntos)",
+                11,
+                2,
+            ),
+            fixed_date_rule(
+                "Día de la Revolución (es_MX)",
+                "d(í|i)a de la revoluci(ó|o)n( mexicana)?",
+                11,
+                20,
+            ),
+            fixed_date_rule(
+                "Día de la Virgen de Guadalupe (es_MX)",
+                "d(í|i)a de la virgen de guadalupe",
+                12,
+                12,
+            ),
+            independence_day_rule("Día de la Independencia (es_MX)"),
+        ],
+        Region::AR => vec![
+            fixed_date_rule(
+                "Revolución de Mayo (es_AR)",
+                "(d(í|i)a de la )?revoluci(ó|o)n de mayo",
+                5,
+                25,
+            ),
+            fixed_date_rule("Día de la Bandera (es_AR)", "d(í|i)a de la bandera", 6, 20),
+            independence_day_rule("Día de la Independencia (es_AR)"),
+        ],
+        Region::ES => vec![
+            fixed_date_rule(
+                "Día de la Hispanidad (es_ES)",
+                "d(í|i)a de la hispanidad|fiesta nacional de espa(ñ|n)a",
+                10,
+                12,
+            ),
+            fixed_date_rule(
+                "Día de la Constitución (es_ES)",
+                "d(í|i)a de la constituci(ó|o)n",
+                12,
+                6,
+            ),
+        ],
+        _ => Vec::new(),
+    }
+}
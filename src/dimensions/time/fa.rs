@@ -0,0 +1,443 @@
+use super::{clock_hour_offset, is_clock_hour, TimeData, TimeForm};
+use crate::dimensions::duration::DurationQualifier;
+use crate::dimensions::numeral::fa::ascii_digits;
+use crate::dimensions::numeral::helpers::integer_value;
+use crate::dimensions::time_grain::Grain;
+use crate::pattern::{predicate, regex};
+use crate::types::{Rule, TokenData};
+
+/// Weekday names, written with a zero-width non-joiner ("سه‌شنبه"), a
+/// space or neither before "شنبه".
+const DAYS: &str = "(?:یک|يک|دو|سه|چهار|پنج)?[\u{200c} ]?شنبه|جمعه";
+
+/// Gregorian month names as written in Persian.
+const MONTHS: &str =
+    "ژانویه|فوریه|مارس|آوریل|آپریل|مه|ژوئن|ژوئیه|جولای|اوت|آگوست|سپتامبر|اکتبر|نوامبر|دسامبر";
+
+/// "آینده" (coming), "گذشته" (past) and their synonyms, after an optional
+/// ezafe ("هفته‌ی آینده").
+const NEXT_LAST: &str = "(?:\u{200c}?ی)?\\s+(آینده|بعد|بعدی|دیگر|گذشته|پیش|قبل)";
+
+fn time_data(td: &TokenData) -> Option<&TimeData> {
+    match td {
+        TokenData::Time(d) => Some(d),
+        _ => None,
+    }
+}
+
+fn is_natural(td: &TokenData) -> bool {
+    integer_value(td).is_some_and(|v| v > 0)
+}
+
+fn is_hour(td: &TokenData) -> bool {
+    integer_value(td).is_some_and(|v| (0..=24).contains(&v))
+}
+
+fn is_dom(td: &TokenData) -> bool {
+    integer_value(td).is_some_and(|v| (1..=31).contains(&v))
+}
+
+fn is_year(td: &TokenData) -> bool {
+    integer_value(td).is_some_and(|v| (1000..=2100).contains(&v))
+}
+
+fn is_month(td: &TokenData) -> bool {
+    matches!(td, TokenData::Time(d) if matches!(d.form, TimeForm::Month(_)))
+}
+
+fn is_yearless_date(td: &TokenData) -> bool {
+    matches!(td, TokenData::Time(d) if matches!(d.form, TimeForm::DateMDY { year: None, .. }))
+}
+
+fn is_clock_time(td: &TokenData) -> bool {
+    matches!(td, TokenData::Time(d) if matches!(d.form, TimeForm::Hour(..) | TimeForm::HourMinute(..)))
+}
+
+fn fa_dow_from_text(s: &str) -> Option<u32> {
+    if s.contains("جمعه") {
+        return Some(4);
+    }
+    let prefix = s
+        .trim_end_matches("شنبه")
+        .trim_end_matches(['\u{200c}', ' ']);
+    match prefix {
+        "" => Some(5),
+        "یک" | "يک" => Some(6),
+        "دو" => Some(0),
+        "سه" => Some(1),
+        "چهار" => Some(2),
+        "پنج" => Some(3),
+        _ => None,
+    }
+}
+
+fn fa_month_from_text(s: &str) -> Option<u32> {
+    Some(match s {
+        "ژانویه" => 1,
+        "فوریه" => 2,
+        "مارس" => 3,
+        "آوریل" | "آپریل" => 4,
+        "مه" => 5,
+        "ژوئن" => 6,
+        "ژوئیه" | "جولای" => 7,
+        "اوت" | "آگوست" => 8,
+        "سپتامبر" => 9,
+        "اکتبر" => 10,
+        "نوامبر" => 11,
+        "دسامبر" => 12,
+        _ => return None,
+    })
+}
+
+fn fa_grain(s: &str) -> Option<Grain> {
+    Some(match s {
+        "ثانیه" => Grain::Second,
+        "دقیقه" => Grain::Minute,
+        "ساعت" => Grain::Hour,
+        "روز" => Grain::Day,
+        "هفته" => Grain::Week,
+        "ماه" => Grain::Month,
+        "سال" => Grain::Year,
+        _ => return None,
+    })
+}
+
+/// 1 after "آینده" and its synonyms, -1 after "گذشته" and its synonyms.
+fn fa_offset(s: &str) -> i32 {
+    match s {
+        "گذشته" | "پیش" | "قبل" => -1,
+        _ => 1,
+    }
+}
+
+/// The clock hour `hour` read after "صبح" (morning), "ظهر"/"بعد از ظهر"/
+/// "عصر" (afternoon) or "شب" (night).
+fn period_hour(period: &str, hour: u32) -> Option<u32> {
+    if hour > 12 {
+        return (hour < 24).then_some(hour);
+    }
+    if period.starts_with("صبح") {
+        Some(hour % 12)
+    } else if period == "شب" && hour < 4 {
+        Some(hour)
+    } else if period == "ظهر" && hour == 12 {
+        Some(12)
+    } else {
+        (hour % 12).checked_add(12)
+    }
+}
+
+pub fn rules() -> Vec<Rule> {
+    let mut rules = super::en::rules();
+    rules.extend(vec![
+        Rule {
+            name: "now (fa)".to_string(),
+            pattern: vec![regex("\\b(?:الان|الآن|اکنون|حالا)\\b")],
+            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Now)))),
+        },
+        Rule {
+            name: "today (fa)".to_string(),
+            pattern: vec![regex("\\bامروز\\b")],
+            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Today)))),
+        },
+        Rule {
+            name: "tomorrow (fa)".to_string(),
+            pattern: vec![regex("\\bفردا\\b")],
+            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Tomorrow)))),
+        },
+        Rule {
+            name: "yesterday (fa)".to_string(),
+            pattern: vec![regex("\\bدیروز\\b")],
+            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Yesterday)))),
+        },
+        Rule {
+            name: "day after tomorrow (fa)".to_string(),
+            pattern: vec![regex("\\bپس[\u{200c} ]?فردا\\b")],
+            production: Box::new(|_| {
+                Some(TokenData::Time(TimeData::new(TimeForm::DayAfterTomorrow)))
+            }),
+        },
+        Rule {
+            name: "day before yesterday (fa)".to_string(),
+            pattern: vec![regex("\\bپریروز\\b")],
+            production: Box::new(|_| {
+                Some(TokenData::Time(TimeData::new(TimeForm::DayBeforeYesterday)))
+            }),
+        },
+        Rule {
+            name: "day of week (fa)".to_string(),
+            pattern: vec![regex(&format!("\\b({DAYS})\\b"))],
+            production: Box::new(|nodes| {
+                let s = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?,
+                    _ => return None,
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::DayOfWeek(
+                    fa_dow_from_text(s)?,
+                ))))
+            }),
+        },
+        // "سه‌شنبه آینده" is Tuesday of next week, "جمعه گذشته" Friday of
+        // last week
+        Rule {
+            name: "<day-of-week> next/last (fa)".to_string(),
+            pattern: vec![regex(&format!("\\b({DAYS}){NEXT_LAST}\\b"))],
+            production: Box::new(|nodes| {
+                let (d, q) = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => (m.group(1)?, m.group(2)?),
+                    _ => return None,
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::Composed(
+                    Box::new(TimeData::new(TimeForm::DayOfWeek(fa_dow_from_text(d)?))),
+                    Box::new(TimeData::new(TimeForm::GrainOffset {
+                        grain: Grain::Week,
+                        offset: fa_offset(q),
+                    })),
+                ))))
+            }),
+        },
+        Rule {
+            name: "this <day-of-week> (fa)".to_string(),
+            pattern: vec![regex(&format!("\\bاین\\s+({DAYS})\\b"))],
+            production: Box::new(|nodes| {
+                let s = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?,
+                    _ => return None,
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::DayOfWeek(
+                    fa_dow_from_text(s)?,
+                ))))
+            }),
+        },
+        Rule {
+            name: "this <cycle> (fa)".to_string(),
+            pattern: vec![regex("\\bاین\\s+(هفته|ماه|سال)\\b")],
+            production: Box::new(|nodes| {
+                let g = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?,
+                    _ => return None,
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset {
+                    grain: fa_grain(g)?,
+                    offset: 0,
+                })))
+            }),
+        },
+        Rule {
+            name: "<cycle> next/last (fa)".to_string(),
+            pattern: vec![regex(&format!("\\b(هفته|ماه|سال){NEXT_LAST}\\b"))],
+            production: Box::new(|nodes| {
+                let (g, q) = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => (m.group(1)?, m.group(2)?),
+                    _ => return None,
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset {
+                    grain: fa_grain(g)?,
+                    offset: fa_offset(q),
+                })))
+            }),
+        },
+        // "۳ روز دیگر" is in three days, "دو هفته پیش" two weeks ago
+        Rule {
+            name: "<n> <cycle> later/ago (fa)".to_string(),
+            pattern: vec![
+                predicate(is_natural),
+                regex("\\b(ثانیه|دقیقه|ساعت|روز|هفته|ماه|سال)\\s+(دیگر|بعد|پیش|قبل)\\b"),
+            ],
+            production: Box::new(|nodes| {
+                let n = integer_value(&nodes[0].token_data)?;
+                let (g, q) = match &nodes[1].token_data {
+                    TokenData::RegexMatch(m) => (m.group(1)?, m.group(2)?),
+                    _ => return None,
+                };
+                let grain = fa_grain(g)?;
+                let t = if fa_offset(q) < 0 {
+                    TimeData::new(TimeForm::RelativeGrain {
+                        n: n.checked_neg()?,
+                        grain,
+                    })
+                } else {
+                    let mut t = TimeData::new(TimeForm::RelativeGrain { n, grain });
+                    t.duration_qualifier = Some(DurationQualifier::Delayed);
+                    t
+                };
+                Some(TokenData::Time(t))
+            }),
+        },
+        Rule {
+            name: "named month (fa)".to_string(),
+            pattern: vec![regex(&format!("\\b({MONTHS})\\b"))],
+            production: Box::new(|nodes| {
+                let s = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?,
+                    _ => return None,
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::Month(
+                    fa_month_from_text(s)?,
+                ))))
+            }),
+        },
+        // "۵ مارس", "پنج مارس"
+        Rule {
+            name: "<day-of-month> <month> (fa)".to_string(),
+            pattern: vec![predicate(is_dom), predicate(is_month)],
+            production: Box::new(|nodes| {
+                let TimeForm::Month(month) = time_data(&nodes[1].token_data)?.form else {
+                    return None;
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::DateMDY {
+                    month,
+                    day: u32::try_from(integer_value(&nodes[0].token_data)?).ok()?,
+                    year: None,
+                })))
+            }),
+        },
+        Rule {
+            name: "<date> <year> (fa)".to_string(),
+            pattern: vec![predicate(is_yearless_date), predicate(is_year)],
+            production: Box::new(|nodes| {
+                let TimeForm::DateMDY { month, day, .. } = time_data(&nodes[0].token_data)?.form
+                else {
+                    return None;
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::DateMDY {
+                    month,
+                    day,
+                    year: Some(i32::try_from(integer_value(&nodes[1].token_data)?).ok()?),
+                })))
+            }),
+        },
+        // "ساعت ۵", "ساعت پنج"
+        Rule {
+            name: "at <hour> (fa)".to_string(),
+            pattern: vec![regex("\\bساعت"), predicate(is_hour)],
+            production: Box::new(|nodes| {
+                let hour = u32::try_from(integer_value(&nodes[1].token_data)?).ok()? % 24;
+                Some(TokenData::Time(TimeData::new(TimeForm::Hour(
+                    hour,
+                    (1..=12).contains(&hour),
+                ))))
+            }),
+        },
+        // "۱۷:۳۰", "ساعت ۵:۱۵", in Persian or ASCII digits
+        Rule {
+            name: "hh:mm (fa)".to_string(),
+            pattern: vec![regex("(?:ساعت\\s*)?([0-9۰-۹]{1,2}):([0-9۰-۹]{2})")],
+            production: Box::new(|nodes| {
+                let (h, m) = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => (m.group(1)?, m.group(2)?),
+                    _ => return None,
+                };
+                let hour: u32 = ascii_digits(h).parse().ok()?;
+                let minute: u32 = ascii_digits(m).parse().ok()?;
+                if hour > 23 || minute > 59 {
+                    return None;
+                }
+                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(
+                    hour,
+                    minute,
+                    (1..=12).contains(&hour),
+                ))))
+            }),
+        },
+        // "ساعت ۵ و نیم", "ساعت پنج و ربع", "ساعت ۵ و ۲۰ دقیقه"
+        Rule {
+            name: "<hour> and <minutes> (fa)".to_string(),
+            pattern: vec![
+                predicate(is_clock_time),
+                regex("\\s*و\\s*(نیم|ربع|([0-9۰-۹]{1,2})\\s*دقیقه)"),
+            ],
+            production: Box::new(|nodes| {
+                let TimeForm::Hour(hour, ambiguous) = time_data(&nodes[0].token_data)?.form else {
+                    return None;
+                };
+                let (word, digits) = match &nodes[1].token_data {
+                    TokenData::RegexMatch(m) => (m.group(1)?, m.group(2)),
+                    _ => return None,
+                };
+                let minute = match (word, digits) {
+                    ("نیم", _) => 30,
+                    ("ربع", _) => 15,
+                    (_, Some(d)) => ascii_digits(d).parse().ok()?,
+                    _ => return None,
+                };
+                if minute > 59 {
+                    return None;
+                }
+                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(
+                    hour, minute, ambiguous,
+                ))))
+            }),
+        },
+        // "ساعت پنج و ده دقیقه", with the minutes in words
+        Rule {
+            name: "<hour> and <integer> minutes (fa)".to_string(),
+            pattern: vec![
+                predicate(is_clock_time),
+                regex("\\s*و"),
+                predicate(is_natural),
+                regex("\\s*دقیقه"),
+            ],
+            production: Box::new(|nodes| {
+                let minutes = i32::try_from(integer_value(&nodes[2].token_data)?).ok()?;
+                clock_hour_offset(&nodes[0].token_data, minutes)
+            }),
+        },
+        // "یه ربع به پنج" is a quarter to five, 4:45
+        Rule {
+            name: "quarter to <hour> (fa)".to_string(),
+            pattern: vec![regex("(?:(?:یک|يک|یه)\\s*)?ربع\\s+به"), predicate(is_clock_hour)],
+            production: Box::new(|nodes| clock_hour_offset(&nodes[1].token_data, -15)),
+        },
+        // "ده دقیقه به پنج" is 4:50
+        Rule {
+            name: "<minutes> to <hour> (fa)".to_string(),
+            pattern: vec![predicate(is_natural), regex("\\s*دقیقه\\s+به"), predicate(is_clock_hour)],
+            production: Box::new(|nodes| {
+                let minutes = i32::try_from(integer_value(&nodes[0].token_data)?).ok()?;
+                clock_hour_offset(&nodes[2].token_data, minutes.checked_neg()?)
+            }),
+        },
+        Rule {
+            name: "at <time-of-day> (fa)".to_string(),
+            pattern: vec![
+                regex("\\bساعت"),
+                predicate(|td| matches!(td, TokenData::Time(d) if matches!(d.form, TimeForm::HourMinute(..)))),
+            ],
+            production: Box::new(|nodes| Some(TokenData::Time(time_data(&nodes[1].token_data)?.clone()))),
+        },
+        // "ساعت ۵ بعد از ظهر" is 17:00, "۸ صبح" 8:00
+        Rule {
+            name: "<clock> <part of day> (fa)".to_string(),
+            pattern: vec![
+                predicate(is_clock_time),
+                regex("\\b(صبح|ظهر|بعد\\s*از\\s*ظهر|بعدازظهر|عصر|شب)\\b"),
+            ],
+            production: Box::new(|nodes| {
+                let period = match &nodes[1].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?,
+                    _ => return None,
+                };
+                let form = match time_data(&nodes[0].token_data)?.form {
+                    TimeForm::Hour(h, _) => TimeForm::Hour(period_hour(period, h)?, false),
+                    TimeForm::HourMinute(h, m, _) => {
+                        TimeForm::HourMinute(period_hour(period, h)?, m, false)
+                    }
+                    _ => return None,
+                };
+                Some(TokenData::Time(TimeData::new(form)))
+            }),
+        },
+        Rule {
+            name: "nowruz (fa)".to_string(),
+            pattern: vec![regex("\\b(?:عید\\s+)?نوروز\\b")],
+            production: Box::new(|_| {
+                Some(TokenData::Time(TimeData::new(TimeForm::Holiday(
+                    "nowruz".to_string(),
+                    None,
+                ))))
+            }),
+        },
+    ]);
+    rules
+}
@@ -1,6 +1,7 @@
 use super::{Direction, IntervalDirection, PartOfDay, TimeData, TimeForm};
 use crate::dimensions::numeral::helpers::numeral_data;
 use crate::dimensions::time_grain::Grain;
+use crate::locale::Region;
 use crate::pattern::{dim, regex};
 use crate::types::{DimensionKind, Rule, TokenData};
 
@@ -1477,3 +1478,87 @@ pub fn rules() -> Vec<Rule> {
     ]);
     rules
 }
+
+fn fixed_date_rule(name: &str, pattern: &str, month: u32, day: u32) -> Rule {
+    Rule {
+        name: name.to_string(),
+        pattern: vec![regex(pattern)],
+        production: Box::new(move |_| {
+            Some(TokenData::Time(TimeData::new(TimeForm::DateMDY {
+                month,
+                day,
+                year: None,
+            })))
+        }),
+    }
+}
+
+fn holiday_rule(name: &str, pattern: &str, holiday: &'static str) -> Rule {
+    Rule {
+        name: name.to_string(),
+        pattern: vec![regex(pattern)],
+        production: Box::new(move |_| {
+            Some(TokenData::Time(TimeData::new(TimeForm::Holiday(
+                holiday.to_string(),
+                None,
+            ))))
+        }),
+    }
+}
+
+/// Region overlays for French: Québec's "15 h 30" and holidays (FR-CA),
+/// and the national days of Belgium (FR-BE) and Switzerland (FR-CH).
+pub fn locale_rules(region: Region) -> Vec<Rule> {
+    match region {
+        Region::CA => vec![
+            Rule {
+                name: "<hour> h <minutes> (fr_CA)".to_string(),
+                pattern: vec![regex("\\b([01]?\\d|2[0-3])\\s+h(\\s*[0-5]\\d)?\\b")],
+                production: Box::new(|nodes| {
+                    let m = match &nodes[0].token_data {
+                        TokenData::RegexMatch(m) => m,
+                        _ => return None,
+                    };
+                    let h: u32 = m.group(1)?.parse().ok()?;
+                    let min: u32 = match m.group(2) {
+                        Some(min) => min.trim().parse().ok()?,
+                        None => 0,
+                    };
+                    Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(
+                        h, min, false,
+                    ))))
+                }),
+            },
+            fixed_date_rule(
+                "Saint-Jean-Baptiste (fr_CA)",
+                "(la )?saint-jean(-baptiste)?|f(ê|e)te nationale du qu(é|e)bec",
+                6,
+                24,
+            ),
+            holiday_rule("fête du Canada (fr_CA)", "f(ê|e)te du canada", "canada day"),
+            holiday_rule(
+                "Action de grâce (fr_CA)",
+                "(l')?action de gr(â|a)ces?",
+                "thanksgiving",
+            ),
+            holiday_rule(
+                "Journée nationale des patriotes (fr_CA)",
+                "journ(é|e)e (nationale )?des patriotes",
+                "national patriots day",
+            ),
+        ],
+        Region::BE => vec![fixed_date_rule(
+            "fête nationale belge (fr_BE)",
+            "f(ê|e)te nationale( belge)?",
+            7,
+            21,
+        )],
+        Region::CH => vec![fixed_date_rule(
+            "fête nationale suisse (fr_CH)",
+            "f(ê|e)te nationale( suisse)?",
+            8,
+            1,
+        )],
+        _ => Vec::new(),
+    }
+}
@@ -1,33 +1,106 @@
-use super::{TimeData, TimeForm};
-use crate::pattern::regex;
+use super::{Direction, TimeData, TimeForm};
+use crate::pattern::{predicate, regex};
 use crate::types::{Rule, TokenData};
 
+/// Month names, bare ("Márta") or after "mí" ("mí an Mhárta", "mí na
+/// Samhna"), where the name may be lenited or in the genitive.
+const MONTHS: &str = "ean[áa]ir|fh?eabhra|mh?[áa]rta|aibre[áa]i?n|bealtaine|mh?eith(?:eamh|imh)|i[úu]il|l[úu]nasa|mh?e[áa]n\\s+fh?[óo]mhair|dh?eireadh\\s+fh?[óo]mhair|samh(?:ain|na)|nollai?g|nollag";
+
+fn parse_ga_month(s: &str) -> Option<u32> {
+    let s = s.to_lowercase();
+    // Drop the "h" of lenition: "mhárta" -> "márta"
+    let mut chars = s.chars();
+    let first = chars.next()?;
+    let rest = chars.as_str();
+    let s = match rest.strip_prefix('h') {
+        Some(rest) if "fmd".contains(first) => format!("{first}{rest}"),
+        _ => s.clone(),
+    };
+    let month = if s.starts_with("ean") {
+        1
+    } else if s.starts_with("fea") {
+        2
+    } else if s.starts_with("már") || s.starts_with("mar") {
+        3
+    } else if s.starts_with("aib") {
+        4
+    } else if s.starts_with("bea") {
+        5
+    } else if s.starts_with("mei") {
+        6
+    } else if s.starts_with("iú") || s.starts_with("iu") {
+        7
+    } else if s.starts_with("lú") || s.starts_with("lu") {
+        8
+    } else if s.starts_with("meá") || s.starts_with("mea") {
+        9
+    } else if s.starts_with("dei") {
+        10
+    } else if s.starts_with("samh") {
+        11
+    } else if s.starts_with("noll") {
+        12
+    } else {
+        return None;
+    };
+    Some(month)
+}
+
+fn time_data(td: &TokenData) -> Option<&TimeData> {
+    match td {
+        TokenData::Time(d) => Some(d),
+        _ => None,
+    }
+}
+
+fn is_day_of_week(td: &TokenData) -> bool {
+    matches!(td, TokenData::Time(d) if matches!(d.form, TimeForm::DayOfWeek(_)))
+}
+
 pub fn rules() -> Vec<Rule> {
     let mut rules = super::en::rules();
     rules.extend(vec![
         Rule {
             name: "now (ga)".to_string(),
-            pattern: vec![regex("anois")],
+            pattern: vec![regex("\\banois\\b")],
             production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Now)))),
         },
         Rule {
             name: "today (ga)".to_string(),
-            pattern: vec![regex("inniu")],
+            pattern: vec![regex("\\binniu\\b")],
             production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Today)))),
         },
         Rule {
             name: "tomorrow (ga)".to_string(),
-            pattern: vec![regex("am[áa]rach")],
+            pattern: vec![regex("\\bam[áa]rach\\b")],
             production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Tomorrow)))),
         },
         Rule {
             name: "yesterday (ga)".to_string(),
-            pattern: vec![regex("inn[ée]")],
+            pattern: vec![regex("\\binn[ée]\\b")],
             production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Yesterday)))),
         },
+        Rule {
+            name: "arú inné|amárach (ga)".to_string(),
+            pattern: vec![regex("\\bar[úu]\\s+(inn[ée]|am[áa]rach)\\b")],
+            production: Box::new(|nodes| {
+                let s = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?.to_lowercase(),
+                    _ => return None,
+                };
+                let form = if s.starts_with("inn") {
+                    TimeForm::DayBeforeYesterday
+                } else {
+                    TimeForm::DayAfterTomorrow
+                };
+                Some(TokenData::Time(TimeData::new(form)))
+            }),
+        },
+        // "Dé Máirt" and "Dé hAoine" after "Dé", "an Mháirt" and "an
+        // Chéadaoin" lenited after the article.
         Rule {
             name: "day of week (ga)".to_string(),
-            pattern: vec![regex("d[ée] luain|an luan|d[ée] m[áa]irt|d[ée] c[ée]adaoin|d[ée]ardaoin|d[ée] haoine|d[ée] sathairn|d[ée] domhnaigh")],
+            pattern: vec![regex("\\b(?:d[ée]\\s+(?:luain|m[áa]irt|c[ée]adaoin|h?aoine|sathairn|domhnaigh)|d[ée]ardaoin|(?:ar\\s+)?an\\s+(?:luan|mh?[áa]irt|ch?[ée]adaoin|d[ée]ardaoin|aoine|satharn|domhnach))\\b")],
             production: Box::new(|nodes| {
                 let s = match &nodes[0].token_data {
                     TokenData::RegexMatch(m) => m.group(0)?.to_lowercase(),
@@ -35,17 +108,17 @@ pub fn rules() -> Vec<Rule> {
                 };
                 let dow = if s.contains("luain") || s.contains("luan") {
                     0
-                } else if s.contains("márt") || s.contains("mairt") {
+                } else if s.contains("irt") {
                     1
-                } else if s.contains("céadaoin") || s.contains("ceadaoin") {
-                    2
                 } else if s.contains("ardaoin") {
                     3
-                } else if s.contains("haoine") {
+                } else if s.contains("adaoin") {
+                    2
+                } else if s.contains("aoine") {
                     4
-                } else if s.contains("sathairn") {
+                } else if s.contains("sath") {
                     5
-                } else if s.contains("domhnaigh") {
+                } else if s.contains("domhna") {
                     6
                 } else {
                     return None;
@@ -53,6 +126,123 @@ pub fn rules() -> Vec<Rule> {
                 Some(TokenData::Time(TimeData::new(TimeForm::DayOfWeek(dow))))
             }),
         },
+        Rule {
+            name: "<day-of-week> seo [chugainn|caite] (ga)".to_string(),
+            pattern: vec![
+                predicate(is_day_of_week),
+                regex("\\bseo(?:\\s+(chugainn|at[áa]\\s+ag\\s+teacht|caite))?\\b"),
+            ],
+            production: Box::new(|nodes| {
+                let which = match &nodes[1].token_data {
+                    TokenData::RegexMatch(m) => m.group(1).map(str::to_lowercase),
+                    _ => return None,
+                };
+                let mut t = time_data(&nodes[0].token_data)?.clone();
+                t.direction = Some(match which.as_deref() {
+                    Some("caite") => Direction::Past,
+                    _ => Direction::Future,
+                });
+                Some(TokenData::Time(t))
+            }),
+        },
+        Rule {
+            name: "month (ga)".to_string(),
+            pattern: vec![regex(&format!(
+                "\\b(?:i\\s+)?(?:mí\\s+(?:an\\s+|na\\s+)?)?({MONTHS})\\b"
+            ))],
+            production: Box::new(|nodes| {
+                let s = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?,
+                    _ => return None,
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::Month(parse_ga_month(s)?))))
+            }),
+        },
+        Rule {
+            name: "[an] <day> <month> [<year>] (ga)".to_string(),
+            pattern: vec![regex(&format!(
+                "\\b(?:an\\s+)?(\\d{{1,2}})(?:ú)?\\s+(?:(?:de\\s+)?mí\\s+(?:an\\s+|na\\s+)?)?({MONTHS})(?:\\s+(\\d{{4}}))?\\b"
+            ))],
+            production: Box::new(|nodes| {
+                let (d, mo, y) = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => (m.group(1)?, m.group(2)?, m.group(3)),
+                    _ => return None,
+                };
+                let day: u32 = d.parse().ok()?;
+                if !(1..=31).contains(&day) {
+                    return None;
+                }
+                let year = match y {
+                    Some(y) => Some(y.parse().ok()?),
+                    None => None,
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::DateMDY {
+                    month: parse_ga_month(mo)?,
+                    day,
+                    year,
+                })))
+            }),
+        },
+        Rule {
+            name: "<hour> a chlog (ga)".to_string(),
+            pattern: vec![regex(
+                "\\b(?:ag\\s+|ar\\s+)?(?:a\\s+)?(\\d{1,2})(?:[:.](\\d{2}))?\\s+a\\s+chlog\\b",
+            )],
+            production: Box::new(|nodes| {
+                let (h, m) = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => (m.group(1)?, m.group(2)),
+                    _ => return None,
+                };
+                let hour: u32 = h.parse().ok()?;
+                let minute: u32 = m.map_or(Some(0), |m| m.parse().ok())?;
+                if hour > 23 || minute > 59 {
+                    return None;
+                }
+                let form = if m.is_some() {
+                    TimeForm::HourMinute(hour, minute, hour <= 12)
+                } else {
+                    TimeForm::Hour(hour, (1..=12).contains(&hour))
+                };
+                Some(TokenData::Time(TimeData::new(form)))
+            }),
+        },
+        // "Lá Fhéile Pádraig" is also written unlenited, "Lá Féile
+        // Phádraig" or without the accents.
+        Rule {
+            name: "holidays (ga)".to_string(),
+            pattern: vec![regex("\\b(?:l[áa]\\s+fh?[ée]ile\\s+(?:ph?[áa]draig|bh?r[íi]de|stiof[áa]in)|l[áa]\\s+(?:na\\s+)?nollag|o[íi]che\\s+(?:nollag|shamhna)|l[áa]\\s+(?:caille|cinn\\s+bliana|ceann\\s+bliana)|o[íi]che\\s+chinn\\s+bliana|(?:domhnach\\s+)?c[áa]sca|c[áa]isc|luan\\s+c[áa]sca)\\b")],
+            production: Box::new(|nodes| {
+                let s = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(0)?.to_lowercase(),
+                    _ => return None,
+                };
+                let name = if s.contains("draig") {
+                    "st. patrick's day"
+                } else if s.contains("ríde") || s.contains("ride") {
+                    "st. brigid's day"
+                } else if s.contains("stiof") {
+                    "st. stephen's day"
+                } else if s.starts_with("oíche nollag") || s.starts_with("oiche nollag") {
+                    "christmas eve"
+                } else if s.contains("nollag") {
+                    "christmas day"
+                } else if s.contains("shamhna") {
+                    "halloween"
+                } else if s.starts_with("oíche") || s.starts_with("oiche") {
+                    "new year's eve"
+                } else if s.contains("caille") || s.contains("bliana") {
+                    "new year's day"
+                } else if s.starts_with("luan") {
+                    "easter monday"
+                } else {
+                    "easter sunday"
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::Holiday(
+                    name.to_string(),
+                    None,
+                ))))
+            }),
+        },
     ]);
     rules
 }
@@ -1,8 +1,97 @@
 use super::{Direction, TimeData, TimeForm};
 use crate::dimensions::time_grain::Grain;
-use crate::pattern::regex;
+use crate::pattern::{predicate, regex};
 use crate::types::{Rule, TokenData};
 
+const MONTHS: &str = "ינואר|פברואר|מרץ|מרס|אפריל|מאי|יוני|יולי|אוגוסט|ספטמבר|אוקטובר|נובמבר|דצמבר";
+
+/// Hours of the clock as words, in the feminine form used with "שעה".
+/// Compound hours come first so "אחת עשרה" isn't read as "אחת".
+const HOUR_WORDS: &str =
+    "אחת עשרה|שתים עשרה|שתיים עשרה|אחת|שתיים|שתים|שלוש|ארבע|חמש|שש|שבע|שמונה|תשע|עשר";
+
+/// Jewish holidays by their Hebrew names, with the key the holiday tables
+/// know them by.
+const JEWISH_HOLIDAYS: &[(&str, &str)] = &[
+    ("ראש השנה", "rosh hashanah"),
+    ("יום כיפור", "yom kippur"),
+    ("יום הכיפורים", "yom kippur"),
+    ("סוכות", "sukkot"),
+    ("שמיני עצרת", "shemini atzeret"),
+    ("שמחת תורה", "simchat torah"),
+    ("חנוכה", "hanukkah"),
+    ("ט[\"״']?ו בשבט", "tu bishvat"),
+    ("שושן פורים", "shushan purim"),
+    ("פורים", "purim"),
+    ("פסח", "passover"),
+    ("יום השואה", "yom hashoah"),
+    ("יום העצמאות", "yom haatzmaut"),
+    ("ל[\"״']?ג בעומר", "lag baomer"),
+    // Bare "שבועות" is also "weeks", so only "חג השבועות" is the holiday.
+    ("חג השבועות", "shavuot"),
+    ("תשעה באב", "tisha b'av"),
+    ("ט[\"״']? באב", "tisha b'av"),
+];
+
+fn time_data(td: &TokenData) -> Option<&TimeData> {
+    match td {
+        TokenData::Time(d) => Some(d),
+        _ => None,
+    }
+}
+
+fn is_ok_with_this_next(td: &TokenData) -> bool {
+    matches!(td, TokenData::Time(d) if d.ok_for_this_next)
+}
+
+fn is_time_of_day(td: &TokenData) -> bool {
+    matches!(
+        td,
+        TokenData::Time(TimeData {
+            form: TimeForm::Hour(..) | TimeForm::HourMinute(..) | TimeForm::HourMinuteSecond(..),
+            ..
+        })
+    )
+}
+
+fn he_month(name: &str) -> Option<u32> {
+    let month = match name {
+        "ינואר" => 1,
+        "פברואר" => 2,
+        "מרץ" | "מרס" => 3,
+        "אפריל" => 4,
+        "מאי" => 5,
+        "יוני" => 6,
+        "יולי" => 7,
+        "אוגוסט" => 8,
+        "ספטמבר" => 9,
+        "אוקטובר" => 10,
+        "נובמבר" => 11,
+        "דצמבר" => 12,
+        _ => return None,
+    };
+    Some(month)
+}
+
+fn he_hour(word: &str) -> Option<u32> {
+    let hour = match word.split_whitespace().collect::<Vec<_>>().as_slice() {
+        ["אחת"] => 1,
+        ["שתיים" | "שתים"] => 2,
+        ["שלוש"] => 3,
+        ["ארבע"] => 4,
+        ["חמש"] => 5,
+        ["שש"] => 6,
+        ["שבע"] => 7,
+        ["שמונה"] => 8,
+        ["תשע"] => 9,
+        ["עשר"] => 10,
+        ["אחת", "עשרה"] => 11,
+        ["שתים" | "שתיים", "עשרה"] => 12,
+        _ => return None,
+    };
+    Some(hour)
+}
+
 pub fn rules() -> Vec<Rule> {
     let mut rules = super::en::rules();
     rules.extend(vec![
@@ -28,10 +117,10 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "day of week (he)".to_string(),
-            pattern: vec![regex("ראשון|שני|שלישי|רביעי|חמישי|שישי|שבת")],
+            pattern: vec![regex(r"(?:ב?יום\s+|ב)?(ראשון|שני|שלישי|רביעי|חמישי|שישי|שבת)\b")],
             production: Box::new(|nodes| {
                 let s = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(0)?,
+                    TokenData::RegexMatch(m) => m.group(1)?,
                     _ => return None,
                 };
                 let dow = match s {
@@ -48,22 +137,34 @@ pub fn rules() -> Vec<Rule> {
             }),
         },
         Rule {
-            name: "date with march (he)".to_string(),
-            pattern: vec![regex("(\\d{1,2})\\s+למרץ")],
+            name: "<day> of <named-month> (he)".to_string(),
+            pattern: vec![regex(&format!(
+                r"(?:[בה]-?)?(\d{{1,2}})\s+[בל]({MONTHS})(?:\s+(\d{{4}}))?\b"
+            ))],
             production: Box::new(|nodes| {
-                let d = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(1)?,
-                    _ => return None,
+                let TokenData::RegexMatch(m) = &nodes[0].token_data else {
+                    return None;
                 };
-                let day: u32 = d.parse().ok()?;
+                let day: u32 = m.group(1)?.parse().ok()?;
+                let month = he_month(m.group(2)?)?;
                 if !(1..=31).contains(&day) {
                     return None;
                 }
-                Some(TokenData::Time(TimeData::new(TimeForm::DateMDY {
-                    month: 3,
-                    day,
-                    year: None,
-                })))
+                let year = match m.group(3) {
+                    Some(y) => Some(y.parse().ok()?),
+                    None => None,
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::DateMDY { month, day, year })))
+            }),
+        },
+        Rule {
+            name: "<named-month> (he)".to_string(),
+            pattern: vec![regex(&format!(r"\b[בל]?({MONTHS})\b"))],
+            production: Box::new(|nodes| {
+                let TokenData::RegexMatch(m) = &nodes[0].token_data else {
+                    return None;
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::Month(he_month(m.group(1)?)?))))
             }),
         },
         Rule {
@@ -96,25 +197,6 @@ pub fn rules() -> Vec<Rule> {
                 })))
             }),
         },
-        Rule {
-            name: "hebrew feb date (he)".to_string(),
-            pattern: vec![regex("ה?(\\d{1,2})\\s+בפברואר|ה?(\\d{1,2})\\s+לפברואר")],
-            production: Box::new(|nodes| {
-                let d = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(1).or_else(|| m.group(2))?,
-                    _ => return None,
-                };
-                let day: u32 = d.parse().ok()?;
-                if !(1..=31).contains(&day) {
-                    return None;
-                }
-                Some(TokenData::Time(TimeData::new(TimeForm::DateMDY {
-                    month: 2,
-                    day,
-                    year: None,
-                })))
-            }),
-        },
         Rule {
             name: "february day reversed (he)".to_string(),
             pattern: vec![regex("פברואר\\s*(\\d{1,2})")],
@@ -142,21 +224,7 @@ pub fn rules() -> Vec<Rule> {
                     TokenData::RegexMatch(m) => (m.group(1)?, m.group(2)?),
                     _ => return None,
                 };
-                let month = match mname {
-                    "ינואר" => 1,
-                    "פברואר" => 2,
-                    "מרץ" => 3,
-                    "אפריל" => 4,
-                    "מאי" => 5,
-                    "יוני" => 6,
-                    "יולי" => 7,
-                    "אוגוסט" => 8,
-                    "ספטמבר" => 9,
-                    "אוקטובר" => 10,
-                    "נובמבר" => 11,
-                    "דצמבר" => 12,
-                    _ => return None,
-                };
+                let month = he_month(mname)?;
                 let day: u32 = d.parse().ok()?;
                 if !(1..=31).contains(&day) {
                     return None;
@@ -165,14 +233,65 @@ pub fn rules() -> Vec<Rule> {
             }),
         },
         Rule {
-            name: "march next (he)".to_string(),
-            pattern: vec![regex("מרץ הבא")],
-            production: Box::new(|_| {
-                let mut t = TimeData::new(TimeForm::Month(3));
+            name: "<time> next (he)".to_string(),
+            pattern: vec![predicate(is_ok_with_this_next), regex(r"הבאה?\b")],
+            production: Box::new(|nodes| {
+                let mut t = time_data(&nodes[0].token_data)?.clone();
                 t.direction = Some(Direction::Future);
+                t.latent = false;
+                t.not_immediate = true;
+                Some(TokenData::Time(t))
+            }),
+        },
+        Rule {
+            name: "<time> last (he)".to_string(),
+            pattern: vec![predicate(is_ok_with_this_next), regex(r"(?:שעבר|שעברה|הקודם|הקודמת)\b")],
+            production: Box::new(|nodes| {
+                let mut t = time_data(&nodes[0].token_data)?.clone();
+                t.direction = Some(Direction::Past);
+                t.latent = false;
                 Some(TokenData::Time(t))
             }),
         },
+        Rule {
+            name: "at <hour-word> (he)".to_string(),
+            pattern: vec![regex(&format!(
+                r"\bב?שעה\s+({HOUR_WORDS})(?:\s+(וחצי|ורבע))?\b"
+            ))],
+            production: Box::new(|nodes| {
+                let TokenData::RegexMatch(m) = &nodes[0].token_data else {
+                    return None;
+                };
+                let hour = he_hour(m.group(1)?)?;
+                let form = match m.group(2) {
+                    Some("וחצי") => TimeForm::HourMinute(hour, 30, true),
+                    Some(_) => TimeForm::HourMinute(hour, 15, true),
+                    None => TimeForm::Hour(hour, true),
+                };
+                Some(TokenData::Time(TimeData::new(form)))
+            }),
+        },
+        Rule {
+            name: "at <time-of-day> (he)".to_string(),
+            pattern: vec![regex(r"\bב?שעה\b"), predicate(is_time_of_day)],
+            production: Box::new(|nodes| {
+                let mut t = time_data(&nodes[1].token_data)?.clone();
+                t.latent = false;
+                Some(TokenData::Time(t))
+            }),
+        },
+        Rule {
+            name: "ב-<hh:mm> (he)".to_string(),
+            pattern: vec![regex(r"\bב-?([01]?\d|2[0-3]):([0-5]\d)\b")],
+            production: Box::new(|nodes| {
+                let TokenData::RegexMatch(m) = &nodes[0].token_data else {
+                    return None;
+                };
+                let hour: u32 = m.group(1)?.parse().ok()?;
+                let minute: u32 = m.group(2)?.parse().ok()?;
+                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(hour, minute, false))))
+            }),
+        },
         Rule {
             name: "this week (he)".to_string(),
             pattern: vec![regex("בשבוע הזה")],
@@ -276,5 +395,21 @@ pub fn rules() -> Vec<Rule> {
             production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::AllGrain(Grain::Week))))),
         },
     ]);
+    rules.extend(JEWISH_HOLIDAYS.iter().map(|&(name, key)| Rule {
+        name: format!("{key} (he)"),
+        pattern: vec![regex(&format!(
+            r"\b(?:[בלו]?חג\s+ה|[בלו])?{name}(?:\s+(\d{{4}}))?\b"
+        ))],
+        production: Box::new(move |nodes| {
+            let TokenData::RegexMatch(m) = &nodes[0].token_data else {
+                return None;
+            };
+            let year = m.group(1).and_then(|y| y.parse().ok());
+            Some(TokenData::Time(TimeData::new(TimeForm::Holiday(
+                key.to_string(),
+                year,
+            ))))
+        }),
+    }));
     rules
 }
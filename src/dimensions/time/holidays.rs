@@ -0,0 +1,98 @@
+// Built-in regional holiday dates for names whose day depends on the country.
+
+use crate::locale::Region;
+use crate::resolve::HolidayResolver;
+use chrono::{NaiveDate, Weekday};
+
+/// Regional dates for holidays that share a name across countries, e.g.
+/// "independence day" (July 4 in the US, August 15 in India), "labour day"
+/// (first Monday of September in the US and Canada, first Monday of October
+/// in Australia) and "thanksgiving" (second Monday of October in Canada).
+///
+/// Applied automatically for the locale's region; names and regions it does
+/// not cover (including the US) fall back to the built-in dates.
+///
+/// # Example
+/// ```
+/// use chrono::NaiveDate;
+/// use duckling::{HolidayResolver, Region, RegionalHolidays};
+///
+/// assert_eq!(
+///     RegionalHolidays.resolve("independence day", 2013, Some(Region::IN)),
+///     NaiveDate::from_ymd_opt(2013, 8, 15)
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RegionalHolidays;
+
+impl HolidayResolver for RegionalHolidays {
+    fn resolve(&self, name: &str, year: i32, region: Option<Region>) -> Option<NaiveDate> {
+        let region = region?;
+        match name {
+            "independence day" => independence_day(year, region),
+            "labour day" | "labor day" => labour_day(year, region),
+            "thanksgiving" | "thanksgiving day" if region == Region::CA => {
+                NaiveDate::from_weekday_of_month_opt(year, 10, Weekday::Mon, 2)
+            }
+            // Newfoundland's Memorial Day
+            "memorial day" if region == Region::CA => NaiveDate::from_ymd_opt(year, 7, 1),
+            s if s.starts_with("father") => fathers_day(year, region),
+            s if s.starts_with("admin") || s.starts_with("secretar") => admin_day(year, region),
+            _ => None,
+        }
+    }
+}
+
+fn independence_day(year: i32, region: Region) -> Option<NaiveDate> {
+    let (month, day) = match region {
+        Region::IN => (8, 15),
+        Region::PH => (6, 12),
+        Region::JM => (8, 6),
+        Region::TT => (8, 31),
+        Region::BZ => (9, 21),
+        Region::BR => (9, 7),
+        Region::MX => (9, 16),
+        Region::AR => (7, 9),
+        Region::CL => (9, 18),
+        Region::PE => (7, 28),
+        Region::CO => (7, 20),
+        Region::VE => (7, 5),
+        _ => return None,
+    };
+    NaiveDate::from_ymd_opt(year, month, day)
+}
+
+fn labour_day(year: i32, region: Region) -> Option<NaiveDate> {
+    match region {
+        // The built-in first Monday of September
+        Region::US | Region::CA => None,
+        // NSW, ACT and SA; other states observe it in March or May
+        Region::AU => NaiveDate::from_weekday_of_month_opt(year, 10, Weekday::Mon, 1),
+        Region::NZ => NaiveDate::from_weekday_of_month_opt(year, 10, Weekday::Mon, 4),
+        // Early May bank holiday
+        Region::GB | Region::IE => NaiveDate::from_weekday_of_month_opt(year, 5, Weekday::Mon, 1),
+        Region::JM => NaiveDate::from_ymd_opt(year, 5, 23),
+        Region::TT => NaiveDate::from_ymd_opt(year, 6, 19),
+        _ => NaiveDate::from_ymd_opt(year, 5, 1),
+    }
+}
+
+fn fathers_day(year: i32, region: Region) -> Option<NaiveDate> {
+    match region {
+        Region::AU | Region::NZ => NaiveDate::from_weekday_of_month_opt(year, 9, Weekday::Sun, 1),
+        _ => None,
+    }
+}
+
+/// Administrative Professionals' Day, where it is not the US Wednesday of
+/// the last full week of April.
+fn admin_day(year: i32, region: Region) -> Option<NaiveDate> {
+    match region {
+        Region::AU => NaiveDate::from_weekday_of_month_opt(year, 5, Weekday::Fri, 1),
+        // Wednesday of the third full week of April
+        Region::NZ => NaiveDate::from_weekday_of_month_opt(year, 4, Weekday::Mon, 3)?
+            .checked_add_days(chrono::Days::new(2)),
+        Region::ZA => NaiveDate::from_weekday_of_month_opt(year, 9, Weekday::Wed, 1),
+        _ => None,
+    }
+}
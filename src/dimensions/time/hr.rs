@@ -1,8 +1,23 @@
+use super::{clock_hour_offset, is_clock_hour, PartOfDay, TimeData, TimeForm};
 use super::{Direction, IntervalDirection};
-use super::{PartOfDay, TimeData, TimeForm};
 use crate::dimensions::time_grain::Grain;
-use crate::pattern::regex;
-use crate::types::{Rule, TokenData};
+use crate::pattern::{dim, predicate, regex};
+use crate::types::{DimensionKind, Rule, TokenData};
+
+/// Weekday names in their nominative, genitive, accusative and locative
+/// forms, and their abbreviations; "su" is left out as it reads as "are".
+const DAYS: &str = "ponedjelj(?:ak|k[aeu])|pon|utor(?:ak|k[aeu])|uto?|srijed[aeiu]|sri|(?:č|c)etvrt(?:ak|k[aeu])|(?:č|c)et|pet(?:ak|k[aeu])?|subot[aeiu]|sub|nedjelj[aeiu]|ned";
+
+/// Month names, Croatian and international, with their case endings;
+/// "svi" is left out as it reads as "all".
+const MONTHS: &str = "sije(?:c|č)a?nj[aue]?|januar[au]?|jan|sij|velja(?:c|č)[aeiu]|februar[au]?|feb|velj|o(?:z|ž)uja?k[au]?|mart[au]?|mar|o(?:z|ž)u|trava?nj[au]?|april[au]?|apr|tra|sviba?nj[au]?|maj|lipa?nj[au]?|jun[iua]?|lip|srpa?nj[au]?|jul[iua]?|srp|kolovoz[au]?|august[au]?|aug|kol|ruja?n[au]?|septemba?r[au]?|sept?|ruj|listopad[au]?|oktoba?r[au]?|okt|lis|studen(?:i|oga?|om)|novemba?r[au]?|nov|stu|prosina?c[au]?|decemba?r[au]?|dec|pros";
+
+fn time_data(td: &TokenData) -> Option<&TimeData> {
+    match td {
+        TokenData::Time(d) => Some(d),
+        _ => None,
+    }
+}
 
 fn parse_dow_hr(s: &str) -> Option<u32> {
     if s.starts_with("pon") {
@@ -58,9 +73,9 @@ fn parse_ordinal_hr(s: &str) -> Option<i32> {
     match s {
         "prvi" | "prva" | "prvo" => Some(1),
         "drugi" | "druga" | "drugo" => Some(2),
-        "treci" | "treći" | "treca" | "treća" => Some(3),
-        "cetvrti" | "četvrti" | "cetvrta" | "četvrta" => Some(4),
-        "peti" | "peta" => Some(5),
+        "treci" | "treći" | "treca" | "treća" | "trece" | "treće" => Some(3),
+        "cetvrti" | "četvrti" | "cetvrta" | "četvrta" | "cetvrto" | "četvrto" => Some(4),
+        "peti" | "peta" | "peto" => Some(5),
         _ => s.parse().ok(),
     }
 }
@@ -123,10 +138,10 @@ fn parse_hr_grain(s: &str) -> Option<Grain> {
         Some(Grain::Minute)
     } else if t.contains("sat") {
         Some(Grain::Hour)
-    } else if t.contains("dan") {
-        Some(Grain::Day)
     } else if t.contains("tjed") {
         Some(Grain::Week)
+    } else if t.contains("dan") {
+        Some(Grain::Day)
     } else if t.contains("mjes") {
         Some(Grain::Month)
     } else if t.contains("god") {
@@ -156,63 +171,61 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "tonight (hr)".to_string(),
-            pattern: vec![regex("ve(c|č)eras|ove\\s+ve(c|č)er(i)?")],
+            pattern: vec![regex("\\b(?:ve(?:c|č)eras|ove\\s+ve(?:c|č)eri?|nave(?:c|č)er)\\b")],
             production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::PartOfDay(PartOfDay::Evening))))),
         },
         Rule {
             name: "previous/next evening (hr)".to_string(),
-            pattern: vec![regex("prethodne\\s+ve(c|č)eri|pro(s|š)le\\s+ve(c|č)eri|sljede(c|ć)e\\s+ve(c|č)eri")],
+            pattern: vec![regex("\\b(?:prethodne|pro(?:s|š)le|sljede(?:c|ć)e)\\s+ve(?:c|č)eri\\b")],
             production: Box::new(|nodes| {
                 let s = match &nodes[0].token_data {
                     TokenData::RegexMatch(rm) => rm.group(0)?.to_lowercase(),
                     _ => return None,
                 };
-                let mut t = TimeData::new(TimeForm::PartOfDay(PartOfDay::Evening));
-                if s.starts_with("sljede") {
-                    t.direction = Some(Direction::Future);
+                let day = if s.starts_with("sljede") {
+                    TimeForm::Tomorrow
                 } else {
-                    t.direction = Some(Direction::Past);
-                }
-                Some(TokenData::Time(t))
-            }),
-        },
-        Rule {
-            name: "this/next/last afternoon (hr)".to_string(),
-            pattern: vec![regex("ov(a|o|i)\\s+(poslijepodne|popodne)|sljede(c|ć)e\\s+(poslijepodne|popodne)|pro(s|š)lo\\s+(poslijepodne|popodne)|prethodno\\s+(poslijepodne|popodne)")],
-            production: Box::new(|nodes| {
-                let s = match &nodes[0].token_data {
-                    TokenData::RegexMatch(rm) => rm.group(0)?.to_lowercase(),
-                    _ => return None,
+                    TimeForm::Yesterday
                 };
-                let mut t = TimeData::new(TimeForm::PartOfDay(PartOfDay::Afternoon));
-                if s.starts_with("sljede") {
-                    t.direction = Some(Direction::Future);
-                } else if s.starts_with("pro") || s.starts_with("pret") {
-                    t.direction = Some(Direction::Past);
-                }
-                Some(TokenData::Time(t))
+                Some(TokenData::Time(TimeData::new(TimeForm::Composed(
+                    Box::new(TimeData::new(day)),
+                    Box::new(TimeData::new(TimeForm::PartOfDay(PartOfDay::Evening))),
+                ))))
             }),
         },
         Rule {
-            name: "after lunch (hr)".to_string(),
-            pattern: vec![regex("poslije\\s+ru(c|č)ka")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::PartOfDay(PartOfDay::Afternoon))))),
-        },
-        Rule {
-            name: "this/next/last morning (hr)".to_string(),
-            pattern: vec![regex("ov(e|o)\\s+jutro|sljede(c|ć)e\\s+jutro|pro(s|š)lo\\s+jutro|prethodno\\s+jutro")],
+            name: "part of day (hr)".to_string(),
+            pattern: vec![regex("\\b(?:(ov[aeio]|sljede(?:c|ć)e|pro(?:s|š)lo|prethodno)\\s+)?(u?jutro|rano(?:\\s+ujutro)?|u\\s+rane\\s+jutarnje\\s+sate|poslijepodne|popodne|poslije\\s+ru(?:c|č)ka|ru(?:c|č)ak)\\b")],
             production: Box::new(|nodes| {
-                let s = match &nodes[0].token_data {
-                    TokenData::RegexMatch(rm) => rm.group(0)?.to_lowercase(),
+                let (q, s) = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => (m.group(1).map(str::to_lowercase), m.group(2)?.to_lowercase()),
                     _ => return None,
                 };
-                let mut t = TimeData::new(TimeForm::PartOfDay(PartOfDay::Morning));
-                if s.starts_with("sljede") {
-                    t.direction = Some(Direction::Future);
-                } else if s.starts_with("pro") || s.starts_with("pret") {
-                    t.direction = Some(Direction::Past);
-                }
-                Some(TokenData::Time(t))
+                let (start_h, end_h) = if s.starts_with("rano") || s.contains("rane") {
+                    (3, 9)
+                } else if s.contains("jutro") {
+                    (4, 12)
+                } else if s.starts_with("poslije ") {
+                    (13, 17)
+                } else if s.starts_with("ru") {
+                    (12, 14)
+                } else {
+                    (12, 20)
+                };
+                let pod = TimeData::new(TimeForm::Interval(
+                    Box::new(TimeData::new(TimeForm::Hour(start_h, false))),
+                    Box::new(TimeData::new(TimeForm::Hour(end_h, false))),
+                    true,
+                ));
+                let day = match q.as_deref() {
+                    Some(q) if q.starts_with("sljede") => TimeForm::Tomorrow,
+                    Some(q) if q.starts_with("pro") || q.starts_with("pret") => TimeForm::Yesterday,
+                    _ => return Some(TokenData::Time(pod)),
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::Composed(
+                    Box::new(TimeData::new(day)),
+                    Box::new(pod),
+                ))))
             }),
         },
         Rule {
@@ -222,7 +235,7 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "day of week (hr)".to_string(),
-            pattern: vec![regex("ponedjelja?ka?|pon\\.?|utora?ka?|uto?\\.?|srijed(a|e|u)|sri\\.?|(č|c)etvrta?ka?|(č|c)et\\.?|peta?ka?|pet\\.?|subot(a|e|u)|sub?\\.?|nedjelj(a|e|u)|ned\\.?")],
+            pattern: vec![regex(&format!("\\b(?:{DAYS})(?:\\.|\\b)"))],
             production: Box::new(|nodes| {
                 let s = match &nodes[0].token_data {
                     TokenData::RegexMatch(m) => m.group(0)?.to_lowercase(),
@@ -234,7 +247,7 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "named month (hr)".to_string(),
-            pattern: vec![regex("sije(c|č)a?nj(a|u)?|januar(a|u)?|jan\\.?|sij\\.?|velja(c|č)(a|e|i)|februar(a|u)?|feb\\.?|velj\\.?|o(z|ž)uja?k(a|u)?|mart(a|u)?|mar\\.?|o(z|ž)u\\.?|trava?nj(a|u)?|april(a|u)?|apr\\.?|tra\\.?|sviba?nj(a|u)?|maj|svi\\.?|lipa?nj(a|u)?|jun(i|u|a)?|jun\\.?|lip\\.?|srpa?nj(a|u)?|jul(i|u|a)?|jul\\.?|srp\\.?|kolovoz(a|u)?|august(a|u)?|aug\\.?|kol\\.?|ruja?n(a|u)?|septemba?r(a|u)?|sept?\\.?|ruj\\.?|listopad(a|u)?|oktobar(a|u)?|okt\\.?|lis\\.?|studen(i|oga?|om)|novemba?r(a|u)?|nov\\.?|stu\\.?|prosina?c(a|u)?|decemba?r(a|u)?|dec\\.?|pros\\.?")],
+            pattern: vec![regex(&format!("\\b(?:{MONTHS})(?:\\.|\\b)"))],
             production: Box::new(|nodes| {
                 let s = match &nodes[0].token_data {
                     TokenData::RegexMatch(rm) => rm.group(0)?.to_lowercase(),
@@ -244,27 +257,59 @@ pub fn rules() -> Vec<Rule> {
                 Some(TokenData::Time(TimeData::new(TimeForm::Month(month))))
             }),
         },
+        // "sljedeci utorak" is the nearest Tuesday after today, as is "ovaj utorak"
         Rule {
             name: "this/next day-of-week (hr)".to_string(),
-            pattern: vec![regex("(ov(aj|a|e|og|u)|sljede(c|ć)(i|u|a|eg|eg?))\\s+(ponedjelja?ka?|pon\\.?|utora?ka?|uto?\\.?|srijed(a|e|u)|sri\\.?|(č|c)etvrta?ka?|(č|c)et\\.?|peta?ka?|pet\\.?|subot(a|e|u)|sub?\\.?|nedjelj(a|e|u)|ned\\.?)")],
+            pattern: vec![regex(&format!("\\b(?:ov(?:aj|a|e|og|u)|sljede(?:c|ć)(?:i|u|a|eg))\\s+({DAYS})(?:\\.|\\b)"))],
             production: Box::new(|nodes| {
-                let (q, dow_s) = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => (m.group(1)?.to_lowercase(), m.group(7)?.to_lowercase()),
+                let dow_s = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?.to_lowercase(),
+                    _ => return None,
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::DayOfWeek(parse_dow_hr(&dow_s)?))))
+            }),
+        },
+        Rule {
+            name: "<day-of-week> after next (hr)".to_string(),
+            pattern: vec![regex(&format!("\\b({DAYS})\\s+nakon\\s+sljede(?:c|ć)eg\\b"))],
+            production: Box::new(|nodes| {
+                let dow_s = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?.to_lowercase(),
                     _ => return None,
                 };
                 let mut t = TimeData::new(TimeForm::DayOfWeek(parse_dow_hr(&dow_s)?));
-                if q.starts_with("sljede") {
-                    t.direction = Some(Direction::Future);
-                }
+                t.direction = Some(Direction::Future);
                 Some(TokenData::Time(t))
             }),
         },
+        // "prosli tjedan u nedjelju", "ovaj tjedan u ponedjeljak"
+        Rule {
+            name: "<day-of-week> of last/this/next week (hr)".to_string(),
+            pattern: vec![regex(&format!("\\b(ovaj|pro(?:s|š)li|prethodni|sljede(?:c|ć)i)\\s+tjedan\\s+u\\s+({DAYS})(?:\\.|\\b)"))],
+            production: Box::new(|nodes| {
+                let (q, dow_s) = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => (m.group(1)?.to_lowercase(), m.group(2)?.to_lowercase()),
+                    _ => return None,
+                };
+                let offset = if q.starts_with("ovaj") {
+                    0
+                } else if q.starts_with("sljede") {
+                    1
+                } else {
+                    -1
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::Composed(
+                    Box::new(TimeData::new(TimeForm::GrainOffset { grain: Grain::Week, offset })),
+                    Box::new(TimeData::new(TimeForm::DayOfWeek(parse_dow_hr(&dow_s)?))),
+                ))))
+            }),
+        },
         Rule {
             name: "last day-of-week (hr)".to_string(),
-            pattern: vec![regex("((pro(s|š)l(i|u|e|og))|prethodn(a|i|u|e|og))\\s+(ponedjelja?ka?|pon\\.?|utora?ka?|uto?\\.?|srijed(a|e|u)|sri\\.?|(č|c)etvrta?ka?|(č|c)et\\.?|peta?ka?|pet\\.?|subot(a|e|u)|sub?\\.?|nedjelj(a|e|u)|ned\\.?)")],
+            pattern: vec![regex(&format!("\\b(?:pro(?:s|š)l(?:i|u|e|og)|prethodn(?:a|i|u|e|og))\\s+({DAYS})(?:\\.|\\b)"))],
             production: Box::new(|nodes| {
                 let dow_s = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(8)?.to_lowercase(),
+                    TokenData::RegexMatch(m) => m.group(1)?.to_lowercase(),
                     _ => return None,
                 };
                 let mut t = TimeData::new(TimeForm::DayOfWeek(parse_dow_hr(&dow_s)?));
@@ -289,7 +334,7 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "hour in the morning/night (hr)".to_string(),
-            pattern: vec![regex("(?:u\\s+)?(\\d{1,2})\\s+(?:sati\\s+)?(ujutro|u\\s+noci|u\\s+no(ć|c)i)")],
+            pattern: vec![regex("\\b(?:u\\s+)?(\\d{1,2})\\s+(?:sati\\s+)?(?:ujutro|u\\s+no(?:ć|c)i)\\b")],
             production: Box::new(|nodes| {
                 let h = match &nodes[0].token_data {
                     TokenData::RegexMatch(m) => m.group(1)?,
@@ -302,12 +347,12 @@ pub fn rules() -> Vec<Rule> {
                 if hour == 12 {
                     hour = 0;
                 }
-                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(hour, 0, false))))
+                Some(TokenData::Time(TimeData::new(TimeForm::Hour(hour, false))))
             }),
         },
         Rule {
             name: "at hour (hr)".to_string(),
-            pattern: vec![regex("u\\s+(\\d{1,2})(\\s+sati)?")],
+            pattern: vec![regex("\\bu\\s+(\\d{1,2})(?:\\s+sati?)?\\b")],
             production: Box::new(|nodes| {
                 let h = match &nodes[0].token_data {
                     TokenData::RegexMatch(rm) => rm.group(1)?,
@@ -317,21 +362,21 @@ pub fn rules() -> Vec<Rule> {
                 if hour > 23 {
                     return None;
                 }
-                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(hour, 0, true))))
+                Some(TokenData::Time(TimeData::new(TimeForm::Hour(hour, hour < 12))))
             }),
         },
         Rule {
             name: "noon/midnight (hr)".to_string(),
-            pattern: vec![regex("u\\s+podne|podne(va)?|pono(c|ć)")],
+            pattern: vec![regex("\\b(?:u\\s+)?(?:podne(?:va)?|pono(?:c|ć))\\b")],
             production: Box::new(|nodes| {
                 let s = match &nodes[0].token_data {
                     TokenData::RegexMatch(rm) => rm.group(0)?.to_lowercase(),
                     _ => return None,
                 };
                 if s.contains("pono") {
-                    Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(0, 0, false))))
+                    Some(TokenData::Time(TimeData::new(TimeForm::Hour(0, false))))
                 } else {
-                    Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(12, 0, false))))
+                    Some(TokenData::Time(TimeData::new(TimeForm::Hour(12, false))))
                 }
             }),
         },
@@ -347,12 +392,12 @@ pub fn rules() -> Vec<Rule> {
                 if hour == 12 {
                     hour = 0;
                 }
-                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(hour, 0, false))))
+                Some(TokenData::Time(TimeData::new(TimeForm::Hour(hour, false))))
             }),
         },
         Rule {
             name: "hour in afternoon/evening (hr)".to_string(),
-            pattern: vec![regex("(?:u\\s+|oko\\s+|otprilike\\s+u\\s+|cca\\s+)?(\\d{1,2})\\s*(?:sati\\s+)?(poslijepodne|popodne|nave(c|č)er)")],
+            pattern: vec![regex("\\b(?:u\\s+|oko\\s+|otprilike\\s+u\\s+|cca\\s+)?(\\d{1,2})\\s*(?:sati\\s+)?(?:poslijepodne|popodne|nave(?:c|č)er)\\b")],
             production: Box::new(|nodes| {
                 let h = match &nodes[0].token_data {
                     TokenData::RegexMatch(m) => m.group(1)?,
@@ -365,7 +410,7 @@ pub fn rules() -> Vec<Rule> {
                 if hour < 12 {
                     hour = hour.checked_add(12)?;
                 }
-                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(hour, 0, false))))
+                Some(TokenData::Time(TimeData::new(TimeForm::Hour(hour, false))))
             }),
         },
         Rule {
@@ -380,12 +425,12 @@ pub fn rules() -> Vec<Rule> {
                 if hour < 12 {
                     hour = hour.checked_add(12)?;
                 }
-                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(hour, 0, false))))
+                Some(TokenData::Time(TimeData::new(TimeForm::Hour(hour, false))))
             }),
         },
         Rule {
             name: "hour-minute in day-part (hr)".to_string(),
-            pattern: vec![regex("(\\d{1,2}):(\\d{2})\\s*(rano|poslijepodne|popodne)")],
+            pattern: vec![regex("\\b(\\d{1,2}):(\\d{2})\\s*(rano|poslijepodne|popodne)\\b")],
             production: Box::new(|nodes| {
                 let (h, m, part) = match &nodes[0].token_data {
                     TokenData::RegexMatch(rm) => (rm.group(1)?, rm.group(2)?, rm.group(3)?.to_lowercase()),
@@ -396,7 +441,7 @@ pub fn rules() -> Vec<Rule> {
                 if hour > 23 || minute > 59 {
                     return None;
                 }
-                if (part.contains("poslijepodne") || part.contains("popodne")) && hour < 12 {
+                if part != "rano" && hour < 12 {
                     hour = hour.checked_add(12)?;
                 }
                 Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(hour, minute, false))))
@@ -404,7 +449,7 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "@ hour / cca hour (hr)".to_string(),
-            pattern: vec![regex("(?:@|cca)\\s*(\\d{1,2})")],
+            pattern: vec![regex("(?:@|\\bcca)\\s*(\\d{1,2})\\b")],
             production: Box::new(|nodes| {
                 let h = match &nodes[0].token_data {
                     TokenData::RegexMatch(m) => m.group(1)?,
@@ -414,7 +459,7 @@ pub fn rules() -> Vec<Rule> {
                 if hour > 23 {
                     return None;
                 }
-                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(hour, 0, false))))
+                Some(TokenData::Time(TimeData::new(TimeForm::Hour(hour, false))))
             }),
         },
         Rule {
@@ -498,6 +543,26 @@ pub fn rules() -> Vec<Rule> {
                 Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(11, minute, false))))
             }),
         },
+        // "četvrt do pet" is 4:45, "deset do pet" 4:50
+        Rule {
+            name: "<minutes> do <hour> (hr)".to_string(),
+            pattern: vec![
+                regex("\\b(pet|deset|petnaest|dvadeset(?:\\s+i\\s+pet)?|(?:č|c)etvrt)\\s+do\\b"),
+                predicate(is_clock_hour),
+            ],
+            production: Box::new(|nodes| {
+                let m_raw = match &nodes[0].token_data {
+                    TokenData::RegexMatch(rm) => rm.group(1)?.to_lowercase(),
+                    _ => return None,
+                };
+                let minutes = match m_raw.as_str() {
+                    "četvrt" | "cetvrt" => 15,
+                    m if m.starts_with("dvadeset") && m.ends_with("pet") => 25,
+                    m => parse_hr_small_num(m)?,
+                };
+                clock_hour_offset(&nodes[1].token_data, i32::try_from(minutes).ok()?.checked_neg()?)
+            }),
+        },
         Rule {
             name: "in <duration> (hr)".to_string(),
             pattern: vec![regex("za\\s+(?:jo[sš]\\s+|oko\\s+)?(jedan|jednu|jednog|dva|dvije|tri|sedam|\\d+(?:\\.\\d+)?)\\s+(sekund[auie]?|minut[auie]?|sat[aie]?|dan[a]?|tjed(?:an|na)|mjesec[a]?|godin[aeu])")],
@@ -531,7 +596,7 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "ago <duration> (hr)".to_string(),
-            pattern: vec![regex("prije\\s+(jedan|jednu|jednog|dva|dvije|tri|sedam|\\d+)\\s+(sat[aie]?|dan[a]?|tjed(?:an|na)|mjesec[a]?|godin[aeu])")],
+            pattern: vec![regex("\\bprije\\s+(jedan|jednu|jednog|dva|dvije|tri|sedam|\\d+)\\s+(sat[aiu]|dan[a]?|tjed(?:an|na)|mjesec[a]?|godin[aeu])\\b")],
             production: Box::new(|nodes| {
                 let (q, g) = match &nodes[0].token_data {
                     TokenData::RegexMatch(rm) => (rm.group(1)?.to_lowercase(), rm.group(2)?.to_lowercase()),
@@ -544,7 +609,7 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "after <duration> (hr)".to_string(),
-            pattern: vec![regex("nakon\\s+(jedan|jednu|jednog|dva|dvije|tri|sedam|\\d+)\\s+(sekund[auie]?|minut[auie]?|sat[aie]?|dan[a]?|tjed(?:an|na)|mjesec[a]?|godin[aeu])")],
+            pattern: vec![regex("\\bnakon\\s+(jedan|jednu|jednog|dva|dvije|tri|sedam|\\d+)\\s+(sekund[auie]?|minut[auie]?|sat[aie]?|dan[a]?|tjed(?:an|na)|mjesec[a]?|godin[aeu])")],
             production: Box::new(|nodes| {
                 let (q, g) = match &nodes[0].token_data {
                     TokenData::RegexMatch(rm) => (rm.group(1)?.to_lowercase(), rm.group(2)?.to_lowercase()),
@@ -552,12 +617,14 @@ pub fn rules() -> Vec<Rule> {
                 };
                 let n = parse_hr_quantity(&q)?;
                 let grain = parse_hr_grain(&g)?;
-                Some(TokenData::Time(TimeData::new(TimeForm::RelativeGrain { n, grain })))
+                let mut t = TimeData::new(TimeForm::RelativeGrain { n, grain });
+                t.open_interval_direction = Some(IntervalDirection::After);
+                Some(TokenData::Time(t))
             }),
         },
         Rule {
             name: "in half/quarter/three-quarter hour (hr)".to_string(),
-            pattern: vec![regex("(?:za|oko\\s+)?\\s*(pola|pol|1/2|cetvrt|četvrt|1/4|tri-cetvrt|3/4)\\s*(?:h|sata?)")],
+            pattern: vec![regex("\\b(?:za\\s+)?(?:oko\\s+)?(pola|pol|1/2|cetvrt|četvrt|1/4|tri-cetvrt|3/4)\\s*(?:h|sata?)\\b")],
             production: Box::new(|nodes| {
                 let s = match &nodes[0].token_data {
                     TokenData::RegexMatch(rm) => rm.group(1)?.to_lowercase(),
@@ -674,7 +741,7 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "last/next n cycles word qty (hr)".to_string(),
-            pattern: vec![regex("(?:pro(?:s|š)l(?:a|e)|prethodn(?:a|e)|sljede(?:c|ć)(?:a|e))\\s+(jedan|jednu|dva|dvije|tri|dvadeset\\s+i\\s+cetiri|dvadeset\\s+i\\s+četiri)\\s+(sekunde?|sata|minute?|dana|tjedna|mjeseca|godine)")],
+            pattern: vec![regex("\\b(?:pro(?:s|š)l(?:a|e|i)|prethodn(?:a|e|i)|sljede(?:c|ć)(?:a|e|i))\\s+(jedan|jednu|dva|dvije|tri|dvadeset\\s+i\\s+cetiri|dvadeset\\s+i\\s+četiri)\\s+(sekund[ae]?|sata?|minut[ae]?|dana?|tjedan|tjedna|mjeseca?|godin[ae])\\b")],
             production: Box::new(|nodes| {
                 let (full, q_s, g_s) = match &nodes[0].token_data {
                     TokenData::RegexMatch(rm) => (rm.group(0)?.to_lowercase(), rm.group(1)?.to_lowercase(), rm.group(2)?.to_lowercase()),
@@ -869,14 +936,14 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("o(z|ž)ujak\\s+nakon\\s+sljede(c|ć)eg")],
             production: Box::new(|_| {
                 let mut t = TimeData::new(TimeForm::Month(3));
-                t.direction = Some(Direction::Future);
+                t.direction = Some(Direction::FarFuture);
                 Some(TokenData::Time(t))
             }),
         },
         Rule {
             name: "ovaj tjedan (hr)".to_string(),
             pattern: vec![regex("ovaj tjedan")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::AllGrain(Grain::Week))))),
+            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset { grain: Grain::Week, offset: 0 })))),
         },
         Rule {
             name: "prosli tjedan (hr)".to_string(),
@@ -900,7 +967,7 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "last/next/this weekend (hr)".to_string(),
-            pattern: vec![regex("pro(s|š)li\\s+vikend|sljede(c|ć)i\\s+vikend|ovaj\\s+vikend")],
+            pattern: vec![regex("\\b(?:pro(?:s|š)l(?:i|og)|sljede(?:c|ć)(?:i|eg)|ov(?:aj|og))\\s+vikend(?:a|u)?\\b")],
             production: Box::new(|nodes| {
                 let s = match &nodes[0].token_data {
                     TokenData::RegexMatch(rm) => rm.group(0)?.to_lowercase(),
@@ -929,36 +996,22 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("sljede(c|ć)i\\s+kvartal")],
             production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset { grain: Grain::Quarter, offset: 1 })))),
         },
+        // "treci kvartal", "3. tromjesečje", "četvrto tromjesečje 2018"
         Rule {
-            name: "treci kvartal (hr)".to_string(),
-            pattern: vec![regex("tre(c|ć)i\\s+kvartal")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Quarter(3))))),
-        },
-        Rule {
-            name: "3. kvartal (hr)".to_string(),
-            pattern: vec![regex("3\\.?\\s+kvartal")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Quarter(3))))),
-        },
-        Rule {
-            name: "trece tromjesecje (hr)".to_string(),
-            pattern: vec![regex("tre(c|ć)e\\s+tromjese(c|č)je")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Quarter(3))))),
-        },
-        Rule {
-            name: "3. tromjesečje (hr)".to_string(),
-            pattern: vec![regex("3\\.?\\s+tromjese(c|č)je")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Quarter(3))))),
-        },
-        Rule {
-            name: "4. kvartal 2018 (hr)".to_string(),
-            pattern: vec![regex("4\\.?\\s+kvartal\\s*(\\d{4})")],
+            name: "<ordinal> quarter [year] (hr)".to_string(),
+            pattern: vec![regex("\\b(prv[aio]|drug[aio]|tre(?:c|ć)[aei]|(?:c|č)etvrt[aio]|[1-4]\\.?)\\s+(?:kvartal|tromjese(?:c|č)je)(?:\\s+(\\d{4}))?\\b")],
             production: Box::new(|nodes| {
-                let y = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(1)?,
+                let (ord_s, year_s) = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => (m.group(1)?.to_lowercase(), m.group(2)),
                     _ => return None,
                 };
-                let year: i32 = y.parse().ok()?;
-                Some(TokenData::Time(TimeData::new(TimeForm::QuarterYear(4, year))))
+                let n = parse_ordinal_hr(ord_s.trim_end_matches('.'))?;
+                let quarter = u32::try_from(n).ok().filter(|q| (1..=4).contains(q))?;
+                let form = match year_s {
+                    Some(y) => TimeForm::QuarterYear(quarter, y.parse().ok()?),
+                    None => TimeForm::Quarter(quarter),
+                };
+                Some(TokenData::Time(TimeData::new(form)))
             }),
         },
         Rule {
@@ -993,42 +1046,31 @@ pub fn rules() -> Vec<Rule> {
                 }
             }),
         },
+        // "do kraja dana" runs from now to the end of the day
         Rule {
             name: "until end of day/month/year (hr)".to_string(),
-            pattern: vec![regex("do\\s+kraja\\s+((ovog\\s+)?dana|(ovog\\s+)?mjeseca|(ovog\\s+)?godine)")],
+            pattern: vec![regex("\\bdo\\s+kraja\\s+(?:(ovog|sljede(?:c|ć)eg)\\s+)?(dana|mjeseca|godine)\\b")],
             production: Box::new(|nodes| {
-                let s = match &nodes[0].token_data {
-                    TokenData::RegexMatch(rm) => rm.group(1)?.to_lowercase(),
+                let (q, s) = match &nodes[0].token_data {
+                    TokenData::RegexMatch(rm) => (rm.group(1).map(str::to_lowercase), rm.group(2)?.to_lowercase()),
                     _ => return None,
                 };
-                let target = if s.contains("dan") {
-                    TimeForm::AllGrain(Grain::Day)
-                } else if s.contains("mjesec") {
-                    TimeForm::AllGrain(Grain::Month)
+                let grain = if s.starts_with("dan") {
+                    Grain::Day
+                } else if s.starts_with("mjesec") {
+                    Grain::Month
                 } else {
-                    TimeForm::AllGrain(Grain::Year)
+                    Grain::Year
                 };
-                let mut t = TimeData::new(TimeForm::BeginEnd {
-                    begin: false,
-                    target: Box::new(target),
-                });
-                t.open_interval_direction = Some(IntervalDirection::Before);
-                Some(TokenData::Time(t))
-            }),
-        },
-        Rule {
-            name: "until end of next month (hr)".to_string(),
-            pattern: vec![regex("do\\s+kraja\\s+sljede(c|ć)eg\\s+mjeseca")],
-            production: Box::new(|_| {
-                let mut t = TimeData::new(TimeForm::BeginEnd {
-                    begin: false,
-                    target: Box::new(TimeForm::GrainOffset {
-                        grain: Grain::Month,
-                        offset: 1,
-                    }),
-                });
-                t.open_interval_direction = Some(IntervalDirection::Before);
-                Some(TokenData::Time(t))
+                let offset = match q.as_deref() {
+                    Some(q) if q.starts_with("sljede") => 2,
+                    _ => 1,
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::Interval(
+                    Box::new(TimeData::new(TimeForm::Now)),
+                    Box::new(TimeData::new(TimeForm::GrainOffset { grain, offset })),
+                    true,
+                ))))
             }),
         },
         Rule {
@@ -1097,7 +1139,7 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "christmas (hr)".to_string(),
-            pattern: vec![regex("bo(z|ž)i(c|ć)(a)?|zicbo")],
+            pattern: vec![regex("\\b(?:bo(?:z|ž)i(?:c|ć)(?:a|u)?|zicbo)\\b")],
             production: Box::new(|_| {
                 Some(TokenData::Time(TimeData::new(TimeForm::Holiday(
                     "christmas day".to_string(),
@@ -1137,7 +1179,7 @@ pub fn rules() -> Vec<Rule> {
                 let form = if s.starts_with("badnjak") {
                     TimeForm::Holiday("christmas eve".to_string(), None)
                 } else if s.starts_with("no") {
-                    TimeForm::Holiday("halloween day".to_string(), None)
+                    TimeForm::Holiday("halloween".to_string(), None)
                 } else if s.starts_with("valent") {
                     TimeForm::Holiday("valentine's day".to_string(), None)
                 } else if s.starts_with("maj") {
@@ -1148,6 +1190,47 @@ pub fn rules() -> Vec<Rule> {
                 Some(TokenData::Time(TimeData::new(form)))
             }),
         },
+        // Croatian public holidays and the movable feasts of the Western calendar
+        Rule {
+            name: "holidays (hr)".to_string(),
+            pattern: vec![regex("\\b(?:uskrsn(?:i|og)\\s+ponedjelj(?:ak|ka)|uskrs[au]?|velik(?:i|og)\\s+pet(?:ak|ka)|duhov[ie]|tijelov[oa]|sveta\\s+tri\\s+kralja|bogojavljenj[ae]|velik(?:a|e|oj)\\s+gosp[aei]|sv(?:i|ih)\\s+svet(?:i|ih)|du(?:s|š)n(?:i|og)\\s+dan[a]?|(?:sveti|svetog)\\s+stjepana?|dan\\s+dr(?:z|ž)avnosti|dan\\s+antifa(?:s|š)isti(?:c|č)ke\\s+borbe|dan\\s+pobjede(?:\\s+i\\s+domovinske\\s+zahvalnosti)?|praznik\\s+rada)\\b")],
+            production: Box::new(|nodes| {
+                let s = match &nodes[0].token_data {
+                    TokenData::RegexMatch(rm) => rm.group(0)?.to_lowercase(),
+                    _ => return None,
+                };
+                let name = if s.starts_with("uskrsn") {
+                    "easter monday"
+                } else if s.starts_with("uskrs") {
+                    "easter sunday"
+                } else if s.starts_with("velik") && s.contains("pet") {
+                    "good friday"
+                } else if s.starts_with("duhov") {
+                    "pentecost"
+                } else if s.starts_with("tijelov") {
+                    "corpus christi"
+                } else if s.starts_with("sveta") || s.starts_with("bogojavljen") {
+                    "epiphany"
+                } else if s.starts_with("velik") {
+                    "assumption day"
+                } else if s.contains("stjepan") {
+                    "st. stephen's day"
+                } else if s.starts_with("sv") {
+                    "all saints' day"
+                } else if s.starts_with("du") {
+                    "all souls' day"
+                } else if s.contains("dr") && s.contains("avnosti") {
+                    "croatian statehood day"
+                } else if s.contains("borbe") {
+                    "anti-fascist struggle day"
+                } else if s.contains("pobjede") {
+                    "croatian victory day"
+                } else {
+                    "international workers' day"
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::Holiday(name.to_string(), None))))
+            }),
+        },
         Rule {
             name: "<n> year after christmas (hr)".to_string(),
             pattern: vec![regex("(jedan|jednu|dva|dvije|tri|\\d+)\\s+godin[aeu]\\s+poslije\\s+bo(z|ž)i(c|ć)(a)?")],
@@ -1167,12 +1250,13 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "season (hr)".to_string(),
-            pattern: vec![regex("prolje(c|ć)e|ljeto|ljetos|jesen|zima|zimus")],
+            pattern: vec![regex("\\b(?:ov[aeo]\\s+)?(?:prolje(?:c|ć)e|ljetos|ljeto|jesen|zimus|zima)\\b")],
             production: Box::new(|nodes| {
                 let s = match &nodes[0].token_data {
                     TokenData::RegexMatch(rm) => rm.group(0)?.to_lowercase(),
                     _ => return None,
                 };
+                let s = s.rsplit(char::is_whitespace).next()?;
                 let season = if s.starts_with("prol") {
                     0
                 } else if s.starts_with("ljet") {
@@ -1279,20 +1363,21 @@ pub fn rules() -> Vec<Rule> {
                 })))
             }),
         },
+        // "prvi utorak u listopadu", "druga srijeda u listopadu 2014"
         Rule {
             name: "<ordinal> <dow> in <month> [year] (hr)".to_string(),
-            pattern: vec![regex("(prvi|drugi|tre(c|ć)i|cetvrti|četvrti|peti|\\d{1,2})\\s+(ponedjelja?ka?|pon\\.?|utora?ka?|uto?\\.?|srijed(a|e|u)|sri\\.?|(č|c)etvrta?ka?|(č|c)et\\.?|peta?ka?|pet\\.?|subot(a|e|u)|sub?\\.?|nedjelj(a|e|u)|ned\\.?)\\s+u\\s+([[:alpha:]čćžšđ]+)(?:\\s+(\\d{4}))?")],
+            pattern: vec![regex(&format!("\\b(prv[aiu]|drug[aiu]|tre(?:c|ć)[aiu]|(?:c|č)etvrt[aiu]|pet[aiu]|\\d{{1,2}}\\.?)\\s+({DAYS})\\s+u\\s+({MONTHS})(?:\\s+(\\d{{4}}))?\\b"))],
             production: Box::new(|nodes| {
                 let (ord_s, dow_s, month_s, year_s) = match &nodes[0].token_data {
                     TokenData::RegexMatch(m) => (
                         m.group(1)?.to_lowercase(),
+                        m.group(2)?.to_lowercase(),
                         m.group(3)?.to_lowercase(),
-                        m.group(10)?.to_lowercase(),
-                        m.group(11),
+                        m.group(4),
                     ),
                     _ => return None,
                 };
-                let n = parse_ordinal_hr(&ord_s)?;
+                let n = parse_ordinal_hr(ord_s.trim_end_matches('.'))?;
                 let dow = parse_dow_hr(&dow_s)?;
                 let month = parse_month_hr(&month_s)?;
                 let year = year_s.and_then(|y| y.parse::<i32>().ok());
@@ -1306,10 +1391,10 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "last <dow> in <month> [year] (hr)".to_string(),
-            pattern: vec![regex("zadnji\\s+(ponedjelja?ka?|pon\\.?|utora?ka?|uto?\\.?|srijed(a|e|u)|sri\\.?|(č|c)etvrta?ka?|(č|c)et\\.?|peta?ka?|pet\\.?|subot(a|e|u)|sub?\\.?|nedjelj(a|e|u)|ned\\.?)\\s+u\\s+([[:alpha:]čćžšđ]+)(?:\\s+(\\d{4}))?")],
+            pattern: vec![regex(&format!("\\b(?:zadnj|posljednj)[aieu]\\s+({DAYS})\\s+u\\s+({MONTHS})(?:\\s+(\\d{{4}}))?\\b"))],
             production: Box::new(|nodes| {
                 let (dow_s, month_s, year_s) = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => (m.group(1)?.to_lowercase(), m.group(8)?.to_lowercase(), m.group(9)),
+                    TokenData::RegexMatch(m) => (m.group(1)?.to_lowercase(), m.group(2)?.to_lowercase(), m.group(3)),
                     _ => return None,
                 };
                 let dow = parse_dow_hr(&dow_s)?;
@@ -1322,6 +1407,98 @@ pub fn rules() -> Vec<Rule> {
                 })))
             }),
         },
+        // "treci utorak poslije Bozica 2014"
+        Rule {
+            name: "<ordinal> <dow> after <time> (hr)".to_string(),
+            pattern: vec![
+                regex(&format!("\\b(prv[aiu]|drug[aiu]|tre(?:c|ć)[aiu]|(?:c|č)etvrt[aiu]|pet[aiu])\\s+({DAYS})\\s+(?:poslije|nakon)\\b")),
+                dim(DimensionKind::Time),
+            ],
+            production: Box::new(|nodes| {
+                let (ord_s, dow_s) = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => (m.group(1)?.to_lowercase(), m.group(2)?.to_lowercase()),
+                    _ => return None,
+                };
+                let base = time_data(&nodes[1].token_data)?;
+                Some(TokenData::Time(TimeData::new(TimeForm::NDOWsFromTime {
+                    n: parse_ordinal_hr(&ord_s)?,
+                    dow: parse_dow_hr(&dow_s)?,
+                    base: Box::new(base.clone()),
+                })))
+            }),
+        },
+        Rule {
+            name: "<hour> sati (hr)".to_string(),
+            pattern: vec![regex("\\b(\\d{1,2})\\s+sat[ai]?\\b")],
+            production: Box::new(|nodes| {
+                let h = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?,
+                    _ => return None,
+                };
+                let hour: u32 = h.parse().ok()?;
+                if hour > 23 {
+                    return None;
+                }
+                Some(TokenData::Time(TimeData::new(TimeForm::Hour(hour, hour < 12))))
+            }),
+        },
+        Rule {
+            name: "<duration> from today (hr)".to_string(),
+            pattern: vec![regex("\\b(jedan|jednu|jednog|dva|dvije|tri|\\d+)\\s+(dan[a]?|tjed(?:an|na)|mjesec[a]?|godin[aeu])\\s+od\\s+(?:danas|dana(?:s|š)njeg\\s+dana)\\b")],
+            production: Box::new(|nodes| {
+                let (q, g) = match &nodes[0].token_data {
+                    TokenData::RegexMatch(rm) => (rm.group(1)?.to_lowercase(), rm.group(2)?.to_lowercase()),
+                    _ => return None,
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::RelativeGrain {
+                    n: parse_hr_quantity(&q)?,
+                    grain: parse_hr_grain(&g)?,
+                })))
+            }),
+        },
+        // "u subotu", "u 13:30", "u ozujku"
+        Rule {
+            name: "u <time> (hr)".to_string(),
+            pattern: vec![regex("\\bu\\b"), dim(DimensionKind::Time)],
+            production: Box::new(|nodes| {
+                let mut t = time_data(&nodes[1].token_data)?.clone();
+                t.latent = false;
+                Some(TokenData::Time(t))
+            }),
+        },
+        // "prije 11 sat", "nekad do 2 poslijepodne"
+        Rule {
+            name: "before <time> (hr)".to_string(),
+            pattern: vec![regex("\\b(?:prije|(?:nekad\\s+)?do)\\b"), dim(DimensionKind::Time)],
+            production: Box::new(|nodes| {
+                let t = time_data(&nodes[1].token_data)?;
+                if t.latent {
+                    return None;
+                }
+                let mut t = t.clone();
+                t.open_interval_direction = Some(IntervalDirection::Before);
+                Some(TokenData::Time(t))
+            }),
+        },
+        // "od 9:30 do 11:00", "između 9:30 i 11:00"
+        Rule {
+            name: "od|između <time> do|i <time> (hr)".to_string(),
+            pattern: vec![
+                regex("\\b(od|izme(?:đ|dj)u)\\b"),
+                dim(DimensionKind::Time),
+                regex("-|\\bdo\\b|\\bi\\b"),
+                dim(DimensionKind::Time),
+            ],
+            production: Box::new(|nodes| {
+                let t1 = time_data(&nodes[1].token_data)?;
+                let t2 = time_data(&nodes[3].token_data)?;
+                Some(TokenData::Time(TimeData::new(TimeForm::Interval(
+                    Box::new(t1.clone()),
+                    Box::new(t2.clone()),
+                    false,
+                ))))
+            }),
+        },
     ]);
     rules
 }
@@ -1,35 +1,151 @@
 use super::Direction;
-use super::{TimeData, TimeForm};
+use super::{clock_hour_offset, is_clock_hour, TimeData, TimeForm};
+use crate::dimensions::numeral::helpers::integer_value;
 use crate::dimensions::time_grain::Grain;
-use crate::pattern::regex;
+use crate::pattern::{predicate, regex};
 use crate::types::{Rule, TokenData};
 
+/// Month names and abbreviations, each optionally followed by the "-ban",
+/// "-i" or "-it" suffix ("májusban", "májusi").
+const MONTHS: &str = "(?:janu[áa]r|febru[áa]r|m[áa]rcius|[áa]prilis|m[áa]jus|j[úu]nius|j[úu]lius|augusztus|szeptember|okt[óo]ber|november|december)(?:ban|ben|i|it)?|jan|feb|m[áa]rc|m[áa]r|[áa]pr|m[áa]j|j[úu]n|j[úu]l|aug|szept|szep|okt|nov|dec";
+
+/// The suffix of a day of month: "15.", "1-jén", "2-án", "4-e", "7-ei", "15i".
+const DAY_SUFFIX: &str = "(?:-?(?:j?[ée]n|j?ei?|[áa]n|ai?|i)\\b|\\.|\\b)";
+
+fn parse_hu_month(s: &str) -> Option<u32> {
+    let s = s.to_lowercase();
+    let month = if s.starts_with("jan") {
+        1
+    } else if s.starts_with("feb") {
+        2
+    } else if s.starts_with("már") || s.starts_with("mar") {
+        3
+    } else if s.starts_with("ápr") || s.starts_with("apr") {
+        4
+    } else if s.starts_with("máj") || s.starts_with("maj") {
+        5
+    } else if s.starts_with("jún") || s.starts_with("jun") {
+        6
+    } else if s.starts_with("júl") || s.starts_with("jul") {
+        7
+    } else if s.starts_with("aug") {
+        8
+    } else if s.starts_with("szep") {
+        9
+    } else if s.starts_with("okt") {
+        10
+    } else if s.starts_with("nov") {
+        11
+    } else if s.starts_with("dec") {
+        12
+    } else {
+        return None;
+    };
+    Some(month)
+}
+
+fn time_data(td: &TokenData) -> Option<&TimeData> {
+    match td {
+        TokenData::Time(d) => Some(d),
+        _ => None,
+    }
+}
+
+fn is_day_of_week(td: &TokenData) -> bool {
+    matches!(td, TokenData::Time(d) if matches!(d.form, TimeForm::DayOfWeek(_)))
+}
+
+fn is_minute(td: &TokenData) -> bool {
+    integer_value(td).is_some_and(|v| (1..=59).contains(&v))
+}
+
+/// `minutes` before the hour after "előtt", after it after "után".
+fn signed_minutes(td: &TokenData, minutes: i32) -> Option<i32> {
+    match td {
+        TokenData::RegexMatch(m) if m.group(1)?.to_lowercase().starts_with("el") => {
+            minutes.checked_neg()
+        }
+        TokenData::RegexMatch(_) => Some(minutes),
+        _ => None,
+    }
+}
+
+/// A clock hour read after "de."/"délelőtt" (`pm == false`) or
+/// "du."/"délután" (`pm == true`).
+fn half_day_hour(h: &str, pm: bool) -> Option<TokenData> {
+    let hour: u32 = h.parse().ok()?;
+    if hour > 12 {
+        return None;
+    }
+    let hour = match (pm, hour) {
+        (false, 12) => 0,
+        (true, h) if h < 12 => h.checked_add(12)?,
+        (_, h) => h,
+    };
+    Some(TokenData::Time(TimeData::new(TimeForm::Hour(hour, false))))
+}
+
 pub fn rules() -> Vec<Rule> {
     let mut rules = super::en::rules();
     rules.extend(vec![
         Rule {
             name: "now (hu)".to_string(),
-            pattern: vec![regex("most|azonnal|hónap vége|hó vége|hó végi|hó végén")],
+            pattern: vec![regex("\\b(?:(?:[ée]pp\\s+)?most(?:ani)?|azonnal)\\b")],
             production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Now)))),
         },
         Rule {
             name: "today (hu)".to_string(),
-            pattern: vec![regex("ma")],
+            pattern: vec![regex("\\b(?:ma|m[áa]ma|mai(?:t|\\s+nap(?:ot|on|it?)?)?)\\b")],
             production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Today)))),
         },
         Rule {
             name: "tomorrow (hu)".to_string(),
-            pattern: vec![regex("holnap")],
+            pattern: vec![regex("\\bholnap(?:it?)?\\b")],
             production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Tomorrow)))),
         },
+        Rule {
+            name: "day after tomorrow (hu)".to_string(),
+            pattern: vec![regex("\\bholnaput[áa]n(?:it?)?\\b")],
+            production: Box::new(|_| {
+                Some(TokenData::Time(TimeData::new(TimeForm::DayAfterTomorrow)))
+            }),
+        },
         Rule {
             name: "yesterday (hu)".to_string(),
-            pattern: vec![regex("tegnap")],
+            pattern: vec![regex("\\btegnap(?:it?)?\\b")],
             production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Yesterday)))),
         },
+        Rule {
+            name: "day before yesterday (hu)".to_string(),
+            pattern: vec![regex("\\btegnapel[őo]tt(?:it?)?\\b")],
+            production: Box::new(|_| {
+                Some(TokenData::Time(TimeData::new(TimeForm::DayBeforeYesterday)))
+            }),
+        },
+        // Haskell reads "hónap vége" and "év vége" as the next month and year
+        Rule {
+            name: "end of month (hu)".to_string(),
+            pattern: vec![regex("\\b(?:a\\s+)?h[óo](?:nap)?\\s+v[ée]g(?:e|it?|[ée]n)\\b")],
+            production: Box::new(|_| {
+                Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset {
+                    grain: Grain::Month,
+                    offset: 1,
+                })))
+            }),
+        },
+        Rule {
+            name: "end of year (hu)".to_string(),
+            pattern: vec![regex("\\b(?:az\\s+)?[ée]v\\s+v[ée]g(?:e|it?|[ée]n)\\b")],
+            production: Box::new(|_| {
+                Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset {
+                    grain: Grain::Year,
+                    offset: 1,
+                })))
+            }),
+        },
         Rule {
             name: "part of day (hu)".to_string(),
-            pattern: vec![regex("(reggel(i(t)?)?|d[ée]lel[őo]tt(i(t)?)?|d[ée]lben|d[ée]li(t)?|d[ée]lut[áa]n(i(t)?)?|est(e|i(t)?)?|[ée]jszaka(i(t)?)?)")],
+            pattern: vec![regex("\\b(?:reggel(?:it?)?|d[ée]lel[őo]tt(?:it?)?|d[ée]lben|d[ée]lit?|d[ée]lut[áa]n(?:it?)?|est(?:e|it?)|[ée]jszaka(?:it?)?)\\b")],
             production: Box::new(|nodes| {
                 let s = match &nodes[0].token_data {
                     TokenData::RegexMatch(m) => m.group(0)?.to_lowercase(),
@@ -37,11 +153,11 @@ pub fn rules() -> Vec<Rule> {
                 };
                 let (start_h, end_h) = if s.starts_with("reggel") {
                     (6, 10)
-                } else if s.starts_with("délelőtt") || s.starts_with("delelőtt") {
+                } else if s.starts_with("délelőtt") || s.starts_with("delel") {
                     (8, 12)
                 } else if s.starts_with("délben") || s.starts_with("delben") || s.starts_with("déli") || s.starts_with("deli") {
                     (12, 13)
-                } else if s.starts_with("délután") || s.starts_with("delutan") {
+                } else if s.starts_with("délután") || s.starts_with("delut") {
                     (12, 18)
                 } else if s.starts_with("est") {
                     (16, 20)
@@ -59,7 +175,7 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "day of week (hu)".to_string(),
-            pattern: vec![regex("hétf(ő(n|t|i(t)?)?|\\.)|kedd(en|et|i(t)?)?|szerda(i(t)?)?|szerdá(n|t)|szer\\.?|csütörtök(ö(n|t)|i(t)?)?|csüt\\.?|péntek(e(n|t)|i(t)?)?|pén\\.?|szombat(o(n|t)|i(t)?)?|szom\\.?|vasárnap(ot|i(t)?)?|vas\\.?")],
+            pattern: vec![regex("\\b(?:hétf(?:ő(?:n|t|it?)?)?|h[ée]t|kedd(?:en|et|it?)?|szerd(?:a(?:it?)?|[áa][nt])|szer|csütörtök(?:ö[nt]|it?)?|csüt|péntek(?:e[nt]|it?)?|pén|szombat(?:o[nt]|it?)?|szom|vasárnap(?:ot|it?)?|vas)(?:\\.|\\b)")],
             production: Box::new(|nodes| {
                 let s = match &nodes[0].token_data {
                     TokenData::RegexMatch(m) => m.group(0)?.to_lowercase(),
@@ -85,209 +201,152 @@ pub fn rules() -> Vec<Rule> {
                 Some(TokenData::Time(TimeData::new(TimeForm::DayOfWeek(dow))))
             }),
         },
+        // "jövő csütörtök" is the Thursday of next week, not the coming one
         Rule {
-            name: "year end (hu)".to_string(),
-            pattern: vec![regex("év vége|év végi|év végén")],
-            production: Box::new(|_| {
-                Some(TokenData::Time(TimeData::new(TimeForm::BeginEnd {
-                    begin: false,
-                    target: Box::new(TimeForm::AllGrain(Grain::Year)),
-                })))
+            name: "jövő <day-of-week> (hu)".to_string(),
+            pattern: vec![regex("\\bj[öo]v[őo]\\b"), predicate(is_day_of_week)],
+            production: Box::new(|nodes| {
+                let mut t = time_data(&nodes[1].token_data)?.clone();
+                t.direction = Some(Direction::FarFuture);
+                Some(TokenData::Time(t))
             }),
         },
         Rule {
-            name: "hét. monday (hu)".to_string(),
-            pattern: vec![regex("h[eé]t\\.")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::DayOfWeek(0))))),
-        },
-        Rule {
-            name: "hét monday (hu)".to_string(),
-            pattern: vec![regex("h[eé]t")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::DayOfWeek(0))))),
-        },
-        Rule {
-            name: "január (hu)".to_string(),
-            pattern: vec![regex("január|jan\\.?")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Month(1))))),
-        },
-        Rule {
-            name: "február (hu)".to_string(),
-            pattern: vec![regex("február|februárban|februári|februárit|feb\\.?")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Month(2))))),
-        },
-        Rule {
-            name: "március (hu)".to_string(),
-            pattern: vec![regex("március|márc\\.?")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Month(3))))),
-        },
-        Rule {
-            name: "már (hu)".to_string(),
-            pattern: vec![regex("már\\.?")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Month(3))))),
-        },
-        Rule {
-            name: "április (hu)".to_string(),
-            pattern: vec![regex("április|ápr\\.?")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Month(4))))),
-        },
-        Rule {
-            name: "május (hu)".to_string(),
-            pattern: vec![regex("május|májusban|májusi|májusit|máj\\.?")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Month(5))))),
-        },
-        Rule {
-            name: "június (hu)".to_string(),
-            pattern: vec![regex("június|jún\\.?")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Month(6))))),
-        },
-        Rule {
-            name: "július (hu)".to_string(),
-            pattern: vec![regex("július|júl\\.?")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Month(7))))),
-        },
-        Rule {
-            name: "augusztus (hu)".to_string(),
-            pattern: vec![regex("augusztus|aug\\.?")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Month(8))))),
-        },
-        Rule {
-            name: "szeptember (hu)".to_string(),
-            pattern: vec![regex("szeptember|szept\\.?")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Month(9))))),
-        },
-        Rule {
-            name: "szep (hu)".to_string(),
-            pattern: vec![regex("szep\\.?")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Month(9))))),
-        },
-        Rule {
-            name: "október (hu)".to_string(),
-            pattern: vec![regex("október|okt\\.?")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Month(10))))),
-        },
-        Rule {
-            name: "novemberben (hu)".to_string(),
-            pattern: vec![regex("novemberben|november")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Month(11))))),
+            name: "month (hu)".to_string(),
+            pattern: vec![regex(&format!("\\b({MONTHS})(?:\\.|\\b)"))],
+            production: Box::new(|nodes| {
+                let s = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?,
+                    _ => return None,
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::Month(parse_hu_month(s)?))))
+            }),
         },
+        // "március 15.", "május 3-án", "2013. május 3."
         Rule {
-            name: "decemberben (hu)".to_string(),
-            pattern: vec![regex("decemberben|december|dec\\.?")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Month(12))))),
+            name: "[<year>.] <month> <day> (hu)".to_string(),
+            pattern: vec![regex(&format!(
+                "\\b(?:(\\d{{4}})\\.?\\s+)?({MONTHS})\\.?\\s+(\\d{{1,2}}){DAY_SUFFIX}"
+            ))],
+            production: Box::new(|nodes| {
+                let (y, mo, d) = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => (m.group(1), m.group(2)?, m.group(3)?),
+                    _ => return None,
+                };
+                let day: u32 = d.parse().ok()?;
+                if !(1..=31).contains(&day) {
+                    return None;
+                }
+                let year = match y {
+                    Some(y) => Some(y.parse().ok()?),
+                    None => None,
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::DateMDY {
+                    month: parse_hu_month(mo)?,
+                    day,
+                    year,
+                })))
+            }),
         },
         Rule {
             name: "season (hu)".to_string(),
-            pattern: vec![regex("ny[áa]r(on)?|t[ée]l(en)?|tavasszal|tavasz|[őo]sz(el)?|[őo]sszel")],
+            pattern: vec![regex("\\b(?:ny[áa]r(?:on|it?)?|t[ée]l(?:en|it?)?|tavasz(?:it?)?|tavasszal|[őo]sz(?:it?)?|[őo]sszel)\\b")],
             production: Box::new(|nodes| {
                 let s = match &nodes[0].token_data {
                     TokenData::RegexMatch(rm) => rm.group(0)?.to_lowercase(),
                     _ => return None,
                 };
-                let season = if s.contains("tavasz") {
-                    0
-                } else if s.contains("ny") {
-                    1
-                } else if s.contains("sz") || s.contains("ő") || s.contains("osz") {
-                    2
+                // Haskell closes spring and autumn a day later than the
+                // shared season table
+                let form = if s.starts_with("tavas") {
+                    let from = TimeData::new(TimeForm::DateMDY { month: 3, day: 20, year: None });
+                    let to = TimeData::new(TimeForm::DateMDY { month: 6, day: 21, year: None });
+                    TimeForm::Interval(Box::new(from), Box::new(to), false)
+                } else if s.starts_with("ny") {
+                    TimeForm::Season(1)
+                } else if s.starts_with("t") {
+                    TimeForm::Season(3)
                 } else {
-                    3
+                    let from = TimeData::new(TimeForm::DateMDY { month: 9, day: 23, year: None });
+                    let to = TimeData::new(TimeForm::DateMDY { month: 12, day: 21, year: None });
+                    TimeForm::Interval(Box::new(from), Box::new(to), false)
                 };
-                Some(TokenData::Time(TimeData::new(TimeForm::Season(season))))
+                Some(TokenData::Time(TimeData::new(form)))
             }),
         },
         Rule {
-            name: "következő hónap (hu)".to_string(),
-            pattern: vec![regex("következő hónap")],
-            production: Box::new(|_| {
-                let mut t = TimeData::new(TimeForm::AllGrain(Grain::Month));
-                t.direction = Some(Direction::Future);
-                Some(TokenData::Time(t))
-            }),
-        },
-        Rule {
-            name: "jövő hónap (hu)".to_string(),
-            pattern: vec![regex("jövő hónap|jovo honap")],
+            name: "jövő|következő hónap (hu)".to_string(),
+            pattern: vec![regex("\\b(?:j[öo]v[őo]|k[öo]vetkez[őo])\\s+h[óo]nap\\b")],
             production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset { grain: Grain::Month, offset: 1 })))),
         },
         Rule {
-            name: "előző év (hu)".to_string(),
-            pattern: vec![regex("előző év|elozo ev")],
+            name: "előző|múlt év (hu)".to_string(),
+            pattern: vec![regex("\\b(?:el[őo]z[őo]|m[úu]lt)\\s+[ée]v\\b")],
             production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset { grain: Grain::Year, offset: -1 })))),
         },
         Rule {
-            name: "múlt év (hu)".to_string(),
-            pattern: vec![regex("múlt év|mult ev")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset { grain: Grain::Year, offset: -1 })))),
+            name: "de|délelőtt <hour> (hu)".to_string(),
+            pattern: vec![regex("\\b(?:de\\.?|d[ée]lel[őo]tt)\\s*(\\d{1,2})\\b")],
+            production: Box::new(|nodes| match &nodes[0].token_data {
+                TokenData::RegexMatch(m) => half_day_hour(m.group(1)?, false),
+                _ => None,
+            }),
         },
         Rule {
-            name: "de 11 (hu)".to_string(),
-            pattern: vec![regex("de\\.?\\s*(\\d{1,2})")],
-            production: Box::new(|nodes| {
-                let h = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(1)?,
-                    _ => return None,
-                };
-                let hour: u32 = h.parse().ok()?;
-                if hour > 12 {
-                    return None;
-                }
-                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(if hour == 12 { 0 } else { hour }, 0, false))))
+            name: "du|délután <hour> (hu)".to_string(),
+            pattern: vec![regex("\\b(?:du\\.?|d[ée]lut[áa]n)\\s*(\\d{1,2})\\b")],
+            production: Box::new(|nodes| match &nodes[0].token_data {
+                TokenData::RegexMatch(m) => half_day_hour(m.group(1)?, true),
+                _ => None,
             }),
         },
+        // Quarters count toward the coming hour: "negyed öt" is 4:15,
+        // "fél öt" 4:30 and "háromnegyed öt" 4:45
         Rule {
-            name: "du 3 (hu)".to_string(),
-            pattern: vec![regex("du\\.?\\s*(\\d{1,2})")],
+            name: "negyed|fél|háromnegyed <hour> (hu)".to_string(),
+            pattern: vec![regex("\\b(h[áa]romnegyed|negyed|f[ée]l)\\b"), predicate(is_clock_hour)],
             production: Box::new(|nodes| {
-                let h = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(1)?,
+                let minutes = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => match m.group(1)?.to_lowercase().as_str() {
+                        "negyed" => -45,
+                        "fél" | "fel" => -30,
+                        _ => -15,
+                    },
                     _ => return None,
                 };
-                let mut hour: u32 = h.parse().ok()?;
-                if hour > 12 {
-                    return None;
-                }
-                if hour < 12 {
-                    hour = hour.checked_add(12)?;
-                }
-                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(hour, 0, false))))
+                clock_hour_offset(&nodes[1].token_data, minutes)
             }),
         },
+        // "tíz perccel öt előtt" is 4:50, "öt után tíz perccel" 5:10
         Rule {
-            name: "délelőtt 11 (hu)".to_string(),
-            pattern: vec![regex("d[ée]lel[őo]tt\\s*(\\d{1,2})")],
+            name: "<minutes> perccel <hour> előtt|után (hu)".to_string(),
+            pattern: vec![
+                predicate(is_minute),
+                regex("\\bperccel\\b"),
+                predicate(is_clock_hour),
+                regex("\\b(el[őo]tt|ut[áa]n)\\b"),
+            ],
             production: Box::new(|nodes| {
-                let h = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(1)?,
-                    _ => return None,
-                };
-                let hour: u32 = h.parse().ok()?;
-                if hour > 12 {
-                    return None;
-                }
-                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(if hour == 12 { 0 } else { hour }, 0, false))))
+                let minutes = i32::try_from(integer_value(&nodes[0].token_data)?).ok()?;
+                clock_hour_offset(&nodes[2].token_data, signed_minutes(&nodes[3].token_data, minutes)?)
             }),
         },
         Rule {
-            name: "délután 11 (hu)".to_string(),
-            pattern: vec![regex("d[ée]lut[áa]n\\s*(\\d{1,2})")],
+            name: "<hour> előtt|után <minutes> perccel (hu)".to_string(),
+            pattern: vec![
+                predicate(is_clock_hour),
+                regex("\\b(el[őo]tt|ut[áa]n)\\b"),
+                predicate(is_minute),
+                regex("\\bperccel\\b"),
+            ],
             production: Box::new(|nodes| {
-                let h = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(1)?,
-                    _ => return None,
-                };
-                let mut hour: u32 = h.parse().ok()?;
-                if hour > 12 {
-                    return None;
-                }
-                if hour < 12 {
-                    hour = hour.checked_add(12)?;
-                }
-                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(hour, 0, false))))
+                let minutes = i32::try_from(integer_value(&nodes[2].token_data)?).ok()?;
+                clock_hour_offset(&nodes[0].token_data, signed_minutes(&nodes[1].token_data, minutes)?)
             }),
         },
         Rule {
             name: "2013 . 08 . 20 (hu)".to_string(),
-            pattern: vec![regex("(\\d{4})\\s*\\.\\s*(\\d{1,2})\\s*\\.\\s*(\\d{1,2})")],
+            pattern: vec![regex("\\b(\\d{4})\\s*[.-]\\s*(\\d{1,2})\\s*[.-]\\s*(\\d{1,2})\\b")],
             production: Box::new(|nodes| {
                 let (y, m, d) = match &nodes[0].token_data {
                     TokenData::RegexMatch(rm) => (rm.group(1)?, rm.group(2)?, rm.group(3)?),
@@ -304,7 +363,7 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "08 . 20 (hu)".to_string(),
-            pattern: vec![regex("(\\d{1,2})\\s*\\.\\s*(\\d{1,2})")],
+            pattern: vec![regex("\\b(\\d{1,2})\\s*[.-]\\s*(\\d{1,2})\\b")],
             production: Box::new(|nodes| {
                 let (m, d) = match &nodes[0].token_data {
                     TokenData::RegexMatch(rm) => (rm.group(1)?, rm.group(2)?),
@@ -318,6 +377,45 @@ pub fn rules() -> Vec<Rule> {
                 Some(TokenData::Time(TimeData::new(TimeForm::DateMDY { month, day, year: None })))
             }),
         },
+        Rule {
+            name: "holidays (hu)".to_string(),
+            pattern: vec![regex("\\b(?:szenteste|kar[áa]csony(?:kor)?|szilveszter(?:kor)?|[úu]j[ée]v(?:kor)?|h[úu]sv[ée]th[ée]tf[őo](?:n)?|h[úu]sv[ée]t(?:kor)?|nagyp[ée]ntek(?:en)?|p[üu]nk[öo]sd(?:h[ée]tf[őo]|kor)?|szent\\s+istv[áa]n\\s+nap(?:ja|j[áa]n)?|[áa]llamalap[íi]t[áa]s\\s+[üu]nnepe|mindenszentek(?:kor)?|halottak\\s+napj(?:a|[áa]n))\\b")],
+            production: Box::new(|nodes| {
+                let s = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(0)?.to_lowercase(),
+                    _ => return None,
+                };
+                let name = if s.starts_with("szenteste") {
+                    "christmas eve"
+                } else if s.starts_with("kar") {
+                    "christmas"
+                } else if s.starts_with("szilveszter") {
+                    "new year's eve"
+                } else if s.starts_with("új") || s.starts_with("uj") {
+                    "new year's day"
+                } else if s.starts_with("nagyp") {
+                    "good friday"
+                } else if s.starts_with("p") && s.contains("tf") {
+                    "whit monday"
+                } else if s.starts_with("p") {
+                    "pentecost"
+                } else if s.starts_with("h") && s.contains("tf") {
+                    "easter monday"
+                } else if s.starts_with("hús") || s.starts_with("hus") {
+                    "easter sunday"
+                } else if s.starts_with("szent") || s.contains("llamalap") {
+                    "hungarian state foundation day"
+                } else if s.starts_with("minden") {
+                    "all saints' day"
+                } else {
+                    "all souls' day"
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::Holiday(
+                    name.to_string(),
+                    None,
+                ))))
+            }),
+        },
     ]);
     rules
 }
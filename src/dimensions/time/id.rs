@@ -0,0 +1,412 @@
+use super::{clock_hour_offset, is_clock_hour, TimeData, TimeForm};
+use crate::dimensions::duration::DurationQualifier;
+use crate::dimensions::numeral::helpers::integer_value;
+use crate::dimensions::time_grain::Grain;
+use crate::pattern::{predicate, regex};
+use crate::types::{Node, Rule, TokenData};
+
+/// Weekday names. "minggu" alone is also "week": before "depan" or "lalu"
+/// it only names Sunday after "hari" ("hari Minggu depan").
+const DAYS: &str = "senin|selasa|rabu|kamis|jum'?at|sabtu|ahad|hari minggu";
+
+const MONTHS: &str = "januari|jan|februari|pebruari|feb|maret|mar|april|apr|mei|juni|jun|juli|jul|agustus|agu|agt|september|sept?|oktober|okt|november|nop|nov|desember|des";
+
+fn time_data(td: &TokenData) -> Option<&TimeData> {
+    match td {
+        TokenData::Time(d) => Some(d),
+        _ => None,
+    }
+}
+
+fn is_natural(td: &TokenData) -> bool {
+    integer_value(td).is_some_and(|v| v > 0)
+}
+
+fn is_hour(td: &TokenData) -> bool {
+    integer_value(td).is_some_and(|v| (0..=24).contains(&v))
+}
+
+fn is_dom(td: &TokenData) -> bool {
+    integer_value(td).is_some_and(|v| (1..=31).contains(&v))
+}
+
+fn is_year(td: &TokenData) -> bool {
+    integer_value(td).is_some_and(|v| (1000..=2100).contains(&v))
+}
+
+fn is_month(td: &TokenData) -> bool {
+    matches!(td, TokenData::Time(d) if matches!(d.form, TimeForm::Month(_)))
+}
+
+fn is_yearless_date(td: &TokenData) -> bool {
+    matches!(td, TokenData::Time(d) if matches!(d.form, TimeForm::DateMDY { year: None, .. }))
+}
+
+fn is_clock_time(td: &TokenData) -> bool {
+    matches!(td, TokenData::Time(d) if matches!(d.form, TimeForm::Hour(..) | TimeForm::HourMinute(..)))
+}
+
+fn id_dow_from_text(s: &str) -> Option<u32> {
+    let s = s.to_lowercase();
+    Some(match s.trim_start_matches("hari").trim() {
+        "senin" => 0,
+        "selasa" => 1,
+        "rabu" => 2,
+        "kamis" => 3,
+        "jumat" | "jum'at" => 4,
+        "sabtu" => 5,
+        "minggu" | "ahad" => 6,
+        _ => return None,
+    })
+}
+
+fn id_month_from_text(s: &str) -> Option<u32> {
+    let s = s.to_lowercase();
+    Some(match s.as_str() {
+        "januari" | "jan" => 1,
+        "februari" | "pebruari" | "feb" => 2,
+        "maret" | "mar" => 3,
+        "april" | "apr" => 4,
+        "mei" => 5,
+        "juni" | "jun" => 6,
+        "juli" | "jul" => 7,
+        "agustus" | "agu" | "agt" => 8,
+        "september" | "sep" | "sept" => 9,
+        "oktober" | "okt" => 10,
+        "november" | "nop" | "nov" => 11,
+        "desember" | "des" => 12,
+        _ => return None,
+    })
+}
+
+fn id_grain(s: &str) -> Option<Grain> {
+    Some(match s.to_lowercase().as_str() {
+        "detik" => Grain::Second,
+        "menit" => Grain::Minute,
+        "jam" => Grain::Hour,
+        "hari" => Grain::Day,
+        "minggu" | "pekan" => Grain::Week,
+        "bulan" => Grain::Month,
+        "tahun" => Grain::Year,
+        _ => return None,
+    })
+}
+
+/// 1 after "depan" (next), -1 after "lalu"/"kemarin" (last).
+fn id_offset(s: &str) -> i32 {
+    match s.to_lowercase().as_str() {
+        "lalu" | "kemarin" | "yang lalu" => -1,
+        _ => 1,
+    }
+}
+
+/// The clock hour `hour` read after "pagi" (morning), "siang" (midday),
+/// "sore" (afternoon) or "malam" (night): "jam 3 sore" is 15:00, "jam 11
+/// siang" 11:00 and "jam 1 malam" 01:00.
+/// `<clock> lewat|kurang <minutes>`: minutes past or before the hour.
+fn minutes_from_clock(nodes: &[&Node]) -> Option<TokenData> {
+    let minutes = i32::try_from(integer_value(&nodes[2].token_data)?).ok()?;
+    let minutes = match &nodes[1].token_data {
+        TokenData::RegexMatch(m) if m.group(1)? == "kurang" => minutes.checked_neg()?,
+        TokenData::RegexMatch(_) => minutes,
+        _ => return None,
+    };
+    clock_hour_offset(&nodes[0].token_data, minutes)
+}
+
+fn period_hour(period: &str, hour: u32) -> Option<u32> {
+    if hour > 12 {
+        return (hour < 24).then_some(hour);
+    }
+    match period.to_lowercase().as_str() {
+        "pagi" => Some(hour % 12),
+        "siang" if hour >= 10 => Some(hour),
+        "malam" if hour < 4 || hour == 12 => Some(hour % 12),
+        _ => (hour % 12).checked_add(12),
+    }
+}
+
+pub fn rules() -> Vec<Rule> {
+    // Numeric dates are read day first: "3/4" is 3 April
+    let mut rules: Vec<Rule> = super::en::rules()
+        .into_iter()
+        .filter(|r| r.name != "date MM/DD(/YYYY)")
+        .collect();
+    rules.extend(super::en::day_first_date_rules());
+    rules.extend(vec![
+        Rule {
+            name: "now (id)".to_string(),
+            pattern: vec![regex("\\b(?:sekarang|saat ini)\\b")],
+            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Now)))),
+        },
+        Rule {
+            name: "today (id)".to_string(),
+            pattern: vec![regex("\\bhari ini\\b")],
+            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Today)))),
+        },
+        Rule {
+            name: "tomorrow (id)".to_string(),
+            pattern: vec![regex("\\bbesok\\b")],
+            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Tomorrow)))),
+        },
+        Rule {
+            name: "yesterday (id)".to_string(),
+            pattern: vec![regex("\\bkemarin\\b")],
+            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Yesterday)))),
+        },
+        Rule {
+            name: "day after tomorrow (id)".to_string(),
+            pattern: vec![regex("\\blusa\\b")],
+            production: Box::new(|_| {
+                Some(TokenData::Time(TimeData::new(TimeForm::DayAfterTomorrow)))
+            }),
+        },
+        Rule {
+            name: "day before yesterday (id)".to_string(),
+            pattern: vec![regex("\\bkemarin lusa\\b")],
+            production: Box::new(|_| {
+                Some(TokenData::Time(TimeData::new(TimeForm::DayBeforeYesterday)))
+            }),
+        },
+        Rule {
+            name: "day of week (id)".to_string(),
+            pattern: vec![regex(&format!("\\b(?:hari )?({DAYS}|minggu)\\b"))],
+            production: Box::new(|nodes| {
+                let s = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?,
+                    _ => return None,
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::DayOfWeek(id_dow_from_text(s)?))))
+            }),
+        },
+        // "Selasa depan" is Tuesday of next week, "Jumat lalu" Friday of
+        // last week
+        Rule {
+            name: "<day-of-week> next/last (id)".to_string(),
+            pattern: vec![regex(&format!("\\b(?:hari )?({DAYS})\\s+(depan|lalu|kemarin)\\b"))],
+            production: Box::new(|nodes| {
+                let (d, q) = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => (m.group(1)?, m.group(2)?),
+                    _ => return None,
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::Composed(
+                    Box::new(TimeData::new(TimeForm::DayOfWeek(id_dow_from_text(d)?))),
+                    Box::new(TimeData::new(TimeForm::GrainOffset {
+                        grain: Grain::Week,
+                        offset: id_offset(q),
+                    })),
+                ))))
+            }),
+        },
+        Rule {
+            name: "<cycle> this/next/last (id)".to_string(),
+            pattern: vec![regex("\\b(minggu|pekan|bulan|tahun)\\s+(ini|depan|lalu|kemarin)\\b")],
+            production: Box::new(|nodes| {
+                let (g, q) = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => (m.group(1)?, m.group(2)?),
+                    _ => return None,
+                };
+                let offset = if q.eq_ignore_ascii_case("ini") { 0 } else { id_offset(q) };
+                Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset {
+                    grain: id_grain(g)?,
+                    offset,
+                })))
+            }),
+        },
+        // "3 hari lagi" is in three days, "2 minggu yang lalu" two weeks ago
+        Rule {
+            name: "<n> <cycle> later/ago (id)".to_string(),
+            pattern: vec![
+                predicate(is_natural),
+                regex("\\b(detik|menit|jam|hari|minggu|pekan|bulan|tahun)\\s+(lagi|yang lalu|lalu)\\b"),
+            ],
+            production: Box::new(|nodes| {
+                let n = integer_value(&nodes[0].token_data)?;
+                let (g, q) = match &nodes[1].token_data {
+                    TokenData::RegexMatch(m) => (m.group(1)?, m.group(2)?),
+                    _ => return None,
+                };
+                let grain = id_grain(g)?;
+                let t = if id_offset(q) < 0 {
+                    TimeData::new(TimeForm::RelativeGrain { n: n.checked_neg()?, grain })
+                } else {
+                    let mut t = TimeData::new(TimeForm::RelativeGrain { n, grain });
+                    t.duration_qualifier = Some(DurationQualifier::Delayed);
+                    t
+                };
+                Some(TokenData::Time(t))
+            }),
+        },
+        Rule {
+            name: "in <n> <cycle> (id)".to_string(),
+            pattern: vec![
+                regex("\\bdalam\\b"),
+                predicate(is_natural),
+                regex("\\b(detik|menit|jam|hari|minggu|pekan|bulan|tahun)\\b"),
+            ],
+            production: Box::new(|nodes| {
+                let n = integer_value(&nodes[1].token_data)?;
+                let g = match &nodes[2].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?,
+                    _ => return None,
+                };
+                let mut t = TimeData::new(TimeForm::RelativeGrain { n, grain: id_grain(g)? });
+                t.duration_qualifier = Some(DurationQualifier::Delayed);
+                Some(TokenData::Time(t))
+            }),
+        },
+        Rule {
+            name: "named month (id)".to_string(),
+            pattern: vec![regex(&format!("\\b(?:bulan )?({MONTHS})\\.?\\b"))],
+            production: Box::new(|nodes| {
+                let s = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?,
+                    _ => return None,
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::Month(id_month_from_text(s)?))))
+            }),
+        },
+        // "3 Mei"
+        Rule {
+            name: "<day-of-month> <month> (id)".to_string(),
+            pattern: vec![predicate(is_dom), predicate(is_month)],
+            production: Box::new(|nodes| {
+                let TimeForm::Month(month) = time_data(&nodes[1].token_data)?.form else {
+                    return None;
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::DateMDY {
+                    month,
+                    day: u32::try_from(integer_value(&nodes[0].token_data)?).ok()?,
+                    year: None,
+                })))
+            }),
+        },
+        // "tanggal 3 Mei 2025"
+        Rule {
+            name: "tanggal <date> (id)".to_string(),
+            pattern: vec![
+                regex("\\btanggal"),
+                predicate(|td| matches!(td, TokenData::Time(d) if matches!(d.form, TimeForm::DateMDY { .. }))),
+            ],
+            production: Box::new(|nodes| Some(nodes[1].token_data.clone())),
+        },
+        Rule {
+            name: "<date> <year> (id)".to_string(),
+            pattern: vec![predicate(is_yearless_date), predicate(is_year)],
+            production: Box::new(|nodes| {
+                let TimeForm::DateMDY { month, day, .. } = time_data(&nodes[0].token_data)?.form
+                else {
+                    return None;
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::DateMDY {
+                    month,
+                    day,
+                    year: Some(i32::try_from(integer_value(&nodes[1].token_data)?).ok()?),
+                })))
+            }),
+        },
+        // "jam 3", "pukul 3"
+        Rule {
+            name: "at <hour> (id)".to_string(),
+            pattern: vec![regex("\\b(?:jam|pukul)"), predicate(is_hour)],
+            production: Box::new(|nodes| {
+                let hour = u32::try_from(integer_value(&nodes[1].token_data)?).ok()? % 24;
+                Some(TokenData::Time(TimeData::new(TimeForm::Hour(hour, (1..=12).contains(&hour)))))
+            }),
+        },
+        // "15.30", "pukul 15.30", "jam 7:45"
+        Rule {
+            name: "hh.mm (id)".to_string(),
+            pattern: vec![regex("(?:\\b(?:jam|pukul)\\s*)?\\b(\\d{1,2})[.:](\\d{2})\\b")],
+            production: Box::new(|nodes| {
+                let (h, m) = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => (m.group(1)?, m.group(2)?),
+                    _ => return None,
+                };
+                let hour: u32 = h.parse().ok()?;
+                let minute: u32 = m.parse().ok()?;
+                if hour > 23 || minute > 59 {
+                    return None;
+                }
+                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(
+                    hour,
+                    minute,
+                    (1..=12).contains(&hour),
+                ))))
+            }),
+        },
+        // "jam setengah 4" is half an hour before four, 3:30
+        Rule {
+            name: "half before <hour> (id)".to_string(),
+            pattern: vec![regex("\\b(?:jam|pukul)\\s+setengah"), predicate(is_hour)],
+            production: Box::new(|nodes| {
+                let hour = u32::try_from(integer_value(&nodes[1].token_data)?).ok()?;
+                let hour = hour.checked_add(23)? % 24;
+                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(
+                    hour,
+                    30,
+                    (1..=12).contains(&hour),
+                ))))
+            }),
+        },
+        // "jam 5 lewat seperempat" is 5:15, "jam 5 kurang seperempat" 4:45
+        Rule {
+            name: "<clock> lewat|kurang seperempat (id)".to_string(),
+            pattern: vec![predicate(is_clock_hour), regex("\\b(lewat|lebih|kurang)\\s+seperempat\\b")],
+            production: Box::new(|nodes| {
+                let minutes = match &nodes[1].token_data {
+                    TokenData::RegexMatch(m) if m.group(1)? == "kurang" => -15,
+                    TokenData::RegexMatch(_) => 15,
+                    _ => return None,
+                };
+                clock_hour_offset(&nodes[0].token_data, minutes)
+            }),
+        },
+        // "jam 5 lewat 10" is 5:10, "jam 5 kurang 10 menit" 4:50
+        Rule {
+            name: "<clock> lewat|kurang <minutes> (id)".to_string(),
+            pattern: vec![predicate(is_clock_hour), regex("\\b(lewat|lebih|kurang)\\b"), predicate(is_natural)],
+            production: Box::new(minutes_from_clock),
+        },
+        Rule {
+            name: "<clock> lewat|kurang <minutes> menit (id)".to_string(),
+            pattern: vec![
+                predicate(is_clock_hour),
+                regex("\\b(lewat|lebih|kurang)\\b"),
+                predicate(is_natural),
+                regex("\\bmenit\\b"),
+            ],
+            production: Box::new(minutes_from_clock),
+        },
+        Rule {
+            name: "<clock> <part of day> (id)".to_string(),
+            pattern: vec![predicate(is_clock_time), regex("\\b(pagi|siang|sore|malam)\\b")],
+            production: Box::new(|nodes| {
+                let period = match &nodes[1].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?,
+                    _ => return None,
+                };
+                let form = match time_data(&nodes[0].token_data)?.form {
+                    TimeForm::Hour(h, _) => TimeForm::Hour(period_hour(period, h)?, false),
+                    TimeForm::HourMinute(h, m, _) => {
+                        TimeForm::HourMinute(period_hour(period, h)?, m, false)
+                    }
+                    _ => return None,
+                };
+                Some(TokenData::Time(TimeData::new(form)))
+            }),
+        },
+        Rule {
+            name: "independence day (id)".to_string(),
+            pattern: vec![regex("\\b(?:hari )?kemerdekaan(?: indonesia| ri)?\\b|\\bagustusan\\b")],
+            production: Box::new(|_| {
+                Some(TokenData::Time(TimeData::new(TimeForm::DateMDY {
+                    month: 8,
+                    day: 17,
+                    year: None,
+                })))
+            }),
+        },
+    ]);
+    rules
+}
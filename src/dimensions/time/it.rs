@@ -1,7 +1,70 @@
 use super::{Direction, IntervalDirection, PartOfDay, TimeData, TimeForm};
+use crate::dimensions::numeral::helpers::numeral_data;
 use crate::dimensions::time_grain::Grain;
-use crate::pattern::regex;
-use crate::types::{Rule, TokenData};
+use crate::pattern::{dim, predicate, regex};
+use crate::types::{DimensionKind, Rule, TokenData};
+
+/// Month names and their abbreviations, without the abbreviation dot.
+const MONTHS: &str = "gennaio|genn?|febbraio|febb?|marzo|mar|aprile|apr|maggio|magg?|giugno|giu|luglio|lug|agosto|ago|settembre|sett?|ottobre|ott|novembre|nov|dicembre|dic";
+
+fn time_data(td: &TokenData) -> Option<&TimeData> {
+    match td {
+        TokenData::Time(d) => Some(d),
+        _ => None,
+    }
+}
+
+fn is_ok_with_this_next(td: &TokenData) -> bool {
+    matches!(td, TokenData::Time(d) if d.ok_for_this_next)
+}
+
+fn is_time_of_day(td: &TokenData) -> bool {
+    matches!(
+        td,
+        TokenData::Time(TimeData {
+            form: TimeForm::Hour(..) | TimeForm::HourMinute(..) | TimeForm::HourMinuteSecond(..),
+            ..
+        })
+    )
+}
+
+/// A clock time, or a bare number that can be an hour after "alle" or
+/// "dalle", e.g. the "15" in "alle 15".
+fn is_hour_or_time_of_day(td: &TokenData) -> bool {
+    is_time_of_day(td) || hour_or_time_of_day(td).is_some()
+}
+
+fn hour_or_time_of_day(td: &TokenData) -> Option<TimeData> {
+    if is_time_of_day(td) {
+        return time_data(td).cloned();
+    }
+    let v = numeral_data(td)?.value;
+    if v.fract() != 0.0 || !(0.0..=24.0).contains(&v) {
+        return None;
+    }
+    let hour = v as u32 % 24;
+    Some(TimeData::new(TimeForm::Hour(
+        hour,
+        (1..=12).contains(&hour),
+    )))
+}
+
+/// `minutes` before the full hour `t`, e.g. "mezzogiorno meno un quarto".
+fn minutes_to(t: &TimeData, minutes: u32) -> Option<TokenData> {
+    let (hour, is_12h) = match t.form {
+        TimeForm::Hour(h, is_12h) | TimeForm::HourMinute(h, 0, is_12h) => (h, is_12h),
+        _ => return None,
+    };
+    if !(1..60).contains(&minutes) {
+        return None;
+    }
+    let hour = if hour == 0 { 23 } else { hour.checked_sub(1)? };
+    Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(
+        hour,
+        60u32.checked_sub(minutes)?,
+        is_12h,
+    ))))
+}
 
 fn parse_it_hour_token(s: &str) -> Option<u32> {
     match s {
@@ -121,40 +184,50 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "today (it)".to_string(),
-            pattern: vec![regex("oggi")],
+            pattern: vec![regex("\\boggi\\b")],
             production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Today)))),
         },
         Rule {
             name: "tomorrow (it)".to_string(),
-            pattern: vec![regex("domani")],
+            pattern: vec![regex("\\bdomani\\b")],
             production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Tomorrow)))),
         },
+        Rule {
+            name: "day after tomorrow (it)".to_string(),
+            pattern: vec![regex("\\bdopodomani\\b")],
+            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::DayAfterTomorrow)))),
+        },
         Rule {
             name: "yesterday (it)".to_string(),
-            pattern: vec![regex("ieri")],
+            pattern: vec![regex("\\bieri\\b")],
             production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Yesterday)))),
         },
+        Rule {
+            name: "day before yesterday (it)".to_string(),
+            pattern: vec![regex("\\b(?:l['’]\\s*altro\\s*ieri|altroieri|ieri\\s+l['’]\\s*altro)\\b")],
+            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::DayBeforeYesterday)))),
+        },
         Rule {
             name: "day of week (it)".to_string(),
-            pattern: vec![regex("luned[ìi]|lun\\.?|marted[ìi]|mar\\.?|mercoled[ìi]|mer\\.?|gioved[ìi]|gio\\.?|venerd[ìi]|ven\\.?|sabato|sab\\.?|domenica|dom\\.?")],
+            pattern: vec![regex("\\b(luned[ìi]|lun|marted[ìi]|mar|mercoled[ìi]|mer|gioved[ìi]|gio|venerd[ìi]|ven|sabato|sab|domenica|dom)\\b\\.?")],
             production: Box::new(|nodes| {
                 let s = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(0)?.to_lowercase(),
+                    TokenData::RegexMatch(m) => m.group(1)?.to_lowercase(),
                     _ => return None,
                 };
-                let dow = if s.starts_with("luned") || s == "lun" || s == "lun." {
+                let dow = if s.starts_with("luned") || s == "lun" {
                     0
-                } else if s.starts_with("marted") || s == "mar" || s == "mar." {
+                } else if s.starts_with("marted") || s == "mar" {
                     1
-                } else if s.starts_with("mercoled") || s == "mer" || s == "mer." {
+                } else if s.starts_with("mercoled") || s == "mer" {
                     2
-                } else if s.starts_with("gioved") || s == "gio" || s == "gio." {
+                } else if s.starts_with("gioved") || s == "gio" {
                     3
-                } else if s.starts_with("venerd") || s == "ven" || s == "ven." {
+                } else if s.starts_with("venerd") || s == "ven" {
                     4
-                } else if s == "sabato" || s == "sab" || s == "sab." {
+                } else if s == "sabato" || s == "sab" {
                     5
-                } else if s == "domenica" || s == "dom" || s == "dom." {
+                } else if s == "domenica" || s == "dom" {
                     6
                 } else {
                     return None;
@@ -183,7 +256,7 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "il 19 (it)".to_string(),
-            pattern: vec![regex("il\\s*(\\d{1,2})")],
+            pattern: vec![regex("\\bil\\s*(\\d{1,2})\\b")],
             production: Box::new(|nodes| {
                 let d = match &nodes[0].token_data {
                     TokenData::RegexMatch(m) => m.group(1)?,
@@ -198,20 +271,85 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "<day> <month> (it)".to_string(),
-            pattern: vec![regex("(\\d{1,2})\\s*(gennaio|genn?\\.?|febbraio|febb?\\.?|marzo|mar\\.?|aprile|apr\\.?|maggio|magg?\\.?|giugno|giu\\.?|luglio|lug\\.?|agosto|ago\\.?|settembre|sett?\\.?|ottobre|ott\\.?|novembre|nov\\.?|dicembre|dic\\.?)")],
+            pattern: vec![regex(&format!("\\b(?:il\\s+)?(\\d{{1,2}}|primo)[°º]?(?:\\s+di)?\\s*({MONTHS})\\b\\.?(?:\\s+(\\d{{4}})\\b)?"))],
             production: Box::new(|nodes| {
-                let (d, mname) = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => (m.group(1)?, m.group(2)?.to_lowercase()),
+                let (d, mname, y) = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => (m.group(1)?.to_lowercase(), m.group(2)?.to_lowercase(), m.group(3)),
                     _ => return None,
                 };
-                let day: u32 = d.parse().ok()?;
+                let day = parse_it_day_token(&d)?;
                 if !(1..=31).contains(&day) {
                     return None;
                 }
                 let month = parse_it_month_token(&mname)?;
+                let year = match y {
+                    Some(y) => Some(y.parse().ok()?),
+                    None => None,
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::DateMDY { month, day, year })))
+            }),
+        },
+        // Only full month names: the abbreviations are also everyday words
+        // ("set", "ago") or weekdays ("mar").
+        Rule {
+            name: "month (it)".to_string(),
+            pattern: vec![regex("\\b(?:in\\s+)?(gennaio|febbraio|marzo|aprile|maggio|giugno|luglio|agosto|settembre|ottobre|novembre|dicembre)\\b")],
+            production: Box::new(|nodes| {
+                let m = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?.to_lowercase(),
+                    _ => return None,
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::Month(parse_it_month_token(&m)?))))
+            }),
+        },
+        Rule {
+            name: "le idi di <month> (it)".to_string(),
+            pattern: vec![regex(&format!("\\b(?:le\\s+)?idi\\s+di\\s+({MONTHS})\\b\\.?"))],
+            production: Box::new(|nodes| {
+                let m = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?.to_lowercase(),
+                    _ => return None,
+                };
+                let month = parse_it_month_token(&m)?;
+                // The ides fall on the 15th of March, May, July and October
+                // and on the 13th of the other months.
+                let day = if matches!(month, 3 | 5 | 7 | 10) { 15 } else { 13 };
+                Some(TokenData::Time(TimeData::new(TimeForm::DateMDY { month, day, year: None })))
+            }),
+        },
+        Rule {
+            name: "il <ordinal> giorno di <month> (it)".to_string(),
+            pattern: vec![
+                regex("\\bil\\b"),
+                dim(DimensionKind::Ordinal),
+                regex(&format!("\\bgiorno\\s+di\\s+({MONTHS})\\b\\.?")),
+            ],
+            production: Box::new(|nodes| {
+                let day = match &nodes[1].token_data {
+                    TokenData::Ordinal(d) => u32::try_from(d.value).ok()?,
+                    _ => return None,
+                };
+                let m = match &nodes[2].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?.to_lowercase(),
+                    _ => return None,
+                };
+                if !(1..=31).contains(&day) {
+                    return None;
+                }
+                let month = parse_it_month_token(&m)?;
                 Some(TokenData::Time(TimeData::new(TimeForm::DateMDY { month, day, year: None })))
             }),
         },
+        Rule {
+            name: "prima di <time> (it)".to_string(),
+            pattern: vec![regex("\\bprima\\s+(?:di|del|della|dell['’])"), dim(DimensionKind::Time)],
+            production: Box::new(|nodes| {
+                let mut t = time_data(&nodes[1].token_data)?.clone();
+                t.open_interval_direction = Some(IntervalDirection::Before);
+                t.latent = false;
+                Some(TokenData::Time(t))
+            }),
+        },
         Rule {
             name: "dal <day> al <day> <month> (it)".to_string(),
             pattern: vec![regex("dal\\s+([[:alpha:]0-9]+)\\s+al\\s+([[:alpha:]0-9]+)\\s+(gennaio|genn?\\.?|febbraio|febb?\\.?|marzo|mar\\.?|aprile|apr\\.?|maggio|magg?\\.?|giugno|giu\\.?|luglio|lug\\.?|agosto|ago\\.?|settembre|sett?\\.?|ottobre|ott\\.?|novembre|nov\\.?|dicembre|dic\\.?)")],
@@ -359,6 +497,18 @@ pub fn rules() -> Vec<Rule> {
                 })))
             }),
         },
+        Rule {
+            name: "nei prossimi giorni (it)".to_string(),
+            pattern: vec![regex("\\b(?:nei\\s+)?prossimi\\s+giorni\\b")],
+            production: Box::new(|_| {
+                Some(TokenData::Time(TimeData::new(TimeForm::NthGrain {
+                    n: 3,
+                    grain: Grain::Day,
+                    past: false,
+                    interval: true,
+                })))
+            }),
+        },
         Rule {
             name: "tra/fra/in/entro <duration> (it)".to_string(),
             pattern: vec![regex("(tra|fra|in|entro)\\s+(\\d+|un|uno|una|due|tre|quattro|cinque|quindici|ventiquattro)\\s+(second[oi]|minut[oi]|or[ae]|giorn[oi]|settiman[ae]|mes[ei]|ann[oi])")],
@@ -690,7 +840,7 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "holidays common (it)".to_string(),
-            pattern: vec![regex("natale|vigilia\\s+di\\s+natale|alla\\s+vigilia|la\\s+vigilia|vigilia\\s+di\\s+capodanno|notte\\s+di\\s+san\\s+silvestro|san\\s+silvestro|capodanno|primo\\s+dell[' ]anno|san\\s+valentino|festa\\s+degli\\s+innamorati|festa\\s+del\\s+pap[àa]|festa\\s+di\\s+san\\s+giuseppe|san\\s+giuseppe|festa\\s+della\\s+mamma|ferragosto|assunzione|ognissanti|tutti\\s+i\\s+santi|festa\\s+dei\\s+santi|santo\\s+stefano|epifania|befana|festa\\s+della\\s+liberazione|anniversario\\s+della\\s+liberazione|la\\s+liberazione|liberazione|festa\\s+della\\s+repubblica|anniversario\\s+della\\s+repubblica|la\\s+repubblica|repubblica|festa\\s+del\\s+lavoro|halloween|commemorazione\\s+dei\\s+defunti|immacolata\\s+concezione|immacolata|ai\\s+morti|giorno\\s+dei\\s+morti")],
+            pattern: vec![regex("\\b(?:natale|vigilia\\s+di\\s+natale|alla\\s+vigilia|la\\s+vigilia|vigilia\\s+di\\s+capodanno|notte\\s+di\\s+san\\s+silvestro|san\\s+silvestro|capodanno|primo\\s+dell[' ]anno|san\\s+valentino|festa\\s+degli\\s+innamorati|festa\\s+del\\s+pap[àa]|festa\\s+di\\s+san\\s+giuseppe|san\\s+giuseppe|festa\\s+della\\s+mamma|ferragosto|assunzione|ognissanti|tutti\\s+i\\s+santi|festa\\s+dei\\s+santi|(?:il\\s+)?giorno\\s+dei\\s+santi|santo\\s+stefano|epifania|befana|festa\\s+della\\s+liberazione|anniversario\\s+della\\s+liberazione|la\\s+liberazione|liberazione|festa\\s+della\\s+repubblica|anniversario\\s+della\\s+repubblica|la\\s+repubblica|repubblica|festa\\s+del\\s+lavoro|halloween|commemorazione\\s+dei\\s+defunti|immacolata\\s+concezione|immacolata|ai\\s+morti|giorno\\s+dei\\s+morti|pasquetta|luned[ìi]\\s+dell['’]angelo|pasqua)\\b")],
             production: Box::new(|nodes| {
                 let s = match &nodes[0].token_data {
                     TokenData::RegexMatch(rm) => rm.group(0)?.to_lowercase(),
@@ -712,7 +862,7 @@ pub fn rules() -> Vec<Rule> {
                     TimeForm::Holiday("mother's day".to_string(), None)
                 } else if s.contains("ferragosto") || s.contains("assunzione") {
                     TimeForm::Holiday("ferragosto".to_string(), None)
-                } else if s.contains("ognissanti") || s.contains("tutti i santi") || s.contains("festa dei santi") {
+                } else if s.contains("ognissanti") || s.contains("tutti i santi") || s.contains("dei santi") {
                     TimeForm::Holiday("all saints' day".to_string(), None)
                 } else if s.contains("santo stefano") {
                     TimeForm::Holiday("st. stephen's day".to_string(), None)
@@ -725,7 +875,11 @@ pub fn rules() -> Vec<Rule> {
                 } else if s.contains("lavoro") {
                     TimeForm::Holiday("labour day".to_string(), None)
                 } else if s.contains("halloween") {
-                    TimeForm::Holiday("halloween day".to_string(), None)
+                    TimeForm::Holiday("halloween".to_string(), None)
+                } else if s.contains("pasquetta") || s.contains("angelo") {
+                    TimeForm::Holiday("easter monday".to_string(), None)
+                } else if s.contains("pasqua") {
+                    TimeForm::Holiday("easter".to_string(), None)
                 } else if s.contains("commemorazione") || s.contains("morti") {
                     TimeForm::Holiday("all souls' day".to_string(), None)
                 } else {
@@ -734,6 +888,32 @@ pub fn rules() -> Vec<Rule> {
                 Some(TokenData::Time(TimeData::new(form)))
             }),
         },
+        Rule {
+            name: "giornate mondiali (it)".to_string(),
+            pattern: vec![regex("\\bgiornata\\s+(della\\s+lingua\\s+araba|mondiale\\s+contro\\s+la\\s+discriminazione|internazionale\\s+delle\\s+cooperative|mondiale\\s+de(?:i|lla)\\s+prematur(?:i|it[àa])|della\\s+liberazione\\s+africana|dell['’]unit[àa]\\s+africana|dell['’]africa)(?:\\s+del\\s+(\\d{4}))?\\b")],
+            production: Box::new(|nodes| {
+                let (s, year) = match &nodes[0].token_data {
+                    TokenData::RegexMatch(rm) => (rm.group(1)?.to_lowercase(), rm.group(2)),
+                    _ => return None,
+                };
+                let key = if s.contains("araba") {
+                    "arabic language day"
+                } else if s.contains("discriminazione") {
+                    "zero discrimination day"
+                } else if s.contains("cooperative") {
+                    "international day of cooperatives"
+                } else if s.contains("prematur") {
+                    "world prematurity day"
+                } else {
+                    "africa day"
+                };
+                let year = match year {
+                    Some(y) => Some(y.parse().ok()?),
+                    None => None,
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::Holiday(key.to_string(), year))))
+            }),
+        },
         Rule {
             name: "ai morti alle <hour> (it)".to_string(),
             pattern: vec![regex("ai\\s+morti\\s+alle\\s*(\\d{1,2})")],
@@ -843,7 +1023,7 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "alle 3 20 (it)".to_string(),
-            pattern: vec![regex("alle\\s*(\\d{1,2})\\s*(\\d{1,2})")],
+            pattern: vec![regex("\\balle\\s*(\\d{1,2})\\s+(\\d{1,2})\\b")],
             production: Box::new(|nodes| {
                 let (h, m) = match &nodes[0].token_data {
                     TokenData::RegexMatch(rm) => (rm.group(1)?, rm.group(2)?),
@@ -857,6 +1037,98 @@ pub fn rules() -> Vec<Rule> {
                 Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(hour, minute, false))))
             }),
         },
+        Rule {
+            name: "a|alle <time-of-day> (it)".to_string(),
+            pattern: vec![regex("\\ba(lle)?\\b"), predicate(is_hour_or_time_of_day)],
+            production: Box::new(|nodes| {
+                let alle = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(1).is_some(),
+                    _ => return None,
+                };
+                // Only "alle" makes a clock time of a bare number
+                if !alle && !matches!(&nodes[1].token_data, TokenData::Time(t) if !t.latent) {
+                    return None;
+                }
+                let mut t = hour_or_time_of_day(&nodes[1].token_data)?;
+                t.latent = false;
+                Some(TokenData::Time(t))
+            }),
+        },
+        Rule {
+            name: "dalle <time-of-day> alle <time-of-day> (it)".to_string(),
+            pattern: vec![
+                regex("\\bdalle\\b"),
+                predicate(is_hour_or_time_of_day),
+                regex("\\balle\\b"),
+                predicate(is_hour_or_time_of_day),
+            ],
+            production: Box::new(|nodes| {
+                let from = hour_or_time_of_day(&nodes[1].token_data)?;
+                let to = hour_or_time_of_day(&nodes[3].token_data)?;
+                Some(TokenData::Time(TimeData::new(TimeForm::Interval(
+                    Box::new(from),
+                    Box::new(to),
+                    false,
+                ))))
+            }),
+        },
+        Rule {
+            name: "dalle <time-of-day> (it)".to_string(),
+            pattern: vec![regex("\\bdall[ae]\\b"), predicate(is_hour_or_time_of_day)],
+            production: Box::new(|nodes| {
+                let mut t = hour_or_time_of_day(&nodes[1].token_data)?;
+                t.open_interval_direction = Some(IntervalDirection::After);
+                Some(TokenData::Time(t))
+            }),
+        },
+        Rule {
+            name: "mezzogiorno|mezzanotte (it)".to_string(),
+            pattern: vec![regex("\\bmezz(ogiorno|anotte)\\b")],
+            production: Box::new(|nodes| {
+                let noon = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?.eq_ignore_ascii_case("ogiorno"),
+                    _ => return None,
+                };
+                let hour = if noon { 12 } else { 0 };
+                Some(TokenData::Time(TimeData::new(TimeForm::Hour(hour, false))))
+            }),
+        },
+        Rule {
+            name: "<time-of-day> meno <minutes> (it)".to_string(),
+            pattern: vec![predicate(is_time_of_day), regex("\\bmeno\\s+(un\\s+quarto|\\d{1,2})\\b")],
+            production: Box::new(|nodes| {
+                let t = time_data(&nodes[0].token_data)?;
+                let minutes = match &nodes[1].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?.parse().unwrap_or(15),
+                    _ => return None,
+                };
+                minutes_to(t, minutes)
+            }),
+        },
+        Rule {
+            name: "un quarto a <time-of-day> (it)".to_string(),
+            pattern: vec![regex("\\bun\\s+quarto\\s+a(?:lle)?\\b"), predicate(is_time_of_day)],
+            production: Box::new(|nodes| minutes_to(time_data(&nodes[1].token_data)?, 15)),
+        },
+        Rule {
+            name: "<time> prossimo|scorso (it)".to_string(),
+            pattern: vec![predicate(is_ok_with_this_next), regex("\\b(prossim|scors)[oa]\\b")],
+            production: Box::new(|nodes| {
+                let mut t = time_data(&nodes[0].token_data)?.clone();
+                let word = match &nodes[1].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?.to_lowercase(),
+                    _ => return None,
+                };
+                if word == "prossim" {
+                    t.direction = Some(Direction::Future);
+                    t.not_immediate = true;
+                } else {
+                    t.direction = Some(Direction::Past);
+                }
+                t.latent = false;
+                Some(TokenData::Time(t))
+            }),
+        },
         Rule {
             name: "part-of-day (it)".to_string(),
             pattern: vec![regex("mattino|pomeriggio|sera|serata|notte")],
@@ -894,7 +1166,7 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "this/next/last part-of-day (it)".to_string(),
-            pattern: vec![regex("quest['oa]\\s+(mattina|mattino|pomeriggio|sera|serata|notte)|domani\\s+(mattina|pomeriggio|sera|notte)|ieri\\s+(sera|notte)|stamattina|stasera")],
+            pattern: vec![regex("quest['oa]\\s+(mattina|mattino|pomeriggio|sera|serata|notte)|domani\\s+(mattina|pomeriggio|sera|notte)|ieri\\s+(sera|notte)|stamattina|stasera|domattina")],
             production: Box::new(|nodes| {
                 let s = match &nodes[0].token_data {
                     TokenData::RegexMatch(rm) => rm.group(0)?.to_lowercase(),
@@ -909,13 +1181,18 @@ pub fn rules() -> Vec<Rule> {
                 } else {
                     PartOfDay::Night
                 };
-                let mut t = TimeData::new(TimeForm::PartOfDay(pod));
-                if s.starts_with("domani") {
-                    t.direction = Some(Direction::Future);
+                let part = TimeData::new(TimeForm::PartOfDay(pod));
+                let day = if s.starts_with("domani") || s == "domattina" {
+                    TimeForm::Tomorrow
                 } else if s.starts_with("ieri") {
-                    t.direction = Some(Direction::Past);
-                }
-                Some(TokenData::Time(t))
+                    TimeForm::Yesterday
+                } else {
+                    return Some(TokenData::Time(part));
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::Composed(
+                    Box::new(TimeData::new(day)),
+                    Box::new(part),
+                ))))
             }),
         },
         Rule {
@@ -1074,7 +1074,7 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "（火）に (ja)".to_string(),
-            pattern: vec![regex("（月）に|（火）に|（水）に|（木）に|（金）に|（土）に|（日）に")],
+            pattern: vec![regex("[（(](?:月|火|水|木|金|土|日)[）)]に")],
             production: Box::new(|nodes| {
                 let s = match &nodes[0].token_data {
                     TokenData::RegexMatch(m) => m.group(0)?,
@@ -1,33 +1,70 @@
-use super::{Direction, PartOfDay, TimeData, TimeForm};
-use crate::pattern::{predicate, regex};
-use crate::types::{Rule, TokenData};
+use super::{hour_minute_offset, Direction, EarlyLate, PartOfDay, TimeData, TimeForm};
+use crate::dimensions::duration::DurationQualifier;
+use crate::dimensions::numeral::helpers::integer_value;
+use crate::dimensions::time_grain::Grain;
+use crate::pattern::{dim, predicate, regex};
+use crate::types::{DimensionKind, Rule, TokenData};
 
-fn is_dom_token(td: &TokenData) -> bool {
+/// Month names with their nominative, genitive, dative and "-ში" endings
+/// ("მარტი", "მარტის", "მარტს", "მარტში"); the stems that drop a vowel
+/// before an ending ("თებერვლის", "ოქტომბრის") are listed both ways.
+const MONTHS: &str = "(?:იანვ(?:არ|რ)|თებერვ(?:ალ|ლ)|მარტ|აპრილ|მაის|ივნის|ივლის|აგვისტო|სექტემბ(?:ერ|რ)|ოქტომბ(?:ერ|რ)|ნოემბ(?:ერ|რ)|დეკემბ(?:ერ|რ))(?:ის|ი|ს|ში)?";
+
+/// Weekday names with their endings; "კვირის" is left out as it reads as
+/// "of the week".
+const DAYS: &str = "(?:ორშაბათ|სამშაბათ|ოთხშაბათ|ხუთშაბათ|პარასკევ|შაბათ)(?:ის|ი|ს)?|კვირას?";
+
+/// "უქმეები", "შაბათ-კვირას", "შაბათკვირას".
+const WEEKEND: &str = "უქმე(?:ებ(?:ი|ზე|ს)|ზე)|შაბათ-?კვირა(?:ს|ზე)?";
+
+fn time_data(td: &TokenData) -> Option<&TimeData> {
     match td {
-        TokenData::Numeral(n) => {
-            let v = n.value as i64;
-            (1..=31).contains(&v) && (n.value - v as f64).abs() < f64::EPSILON
-        }
-        TokenData::Ordinal(o) => (1..=31).contains(&o.value),
-        _ => false,
+        TokenData::Time(d) => Some(d),
+        _ => None,
     }
 }
 
+fn is_dom_token(td: &TokenData) -> bool {
+    dom_value(td).is_some()
+}
+
 fn dom_value(td: &TokenData) -> Option<u32> {
     match td {
-        TokenData::Numeral(n) if (n.value - n.value.floor()).abs() < f64::EPSILON => {
-            let v = n.value as i64;
-            if (1..=31).contains(&v) {
-                Some(v as u32)
-            } else {
-                None
-            }
+        TokenData::Numeral(_) => {
+            let v = integer_value(td)?;
+            (1..=31).contains(&v).then_some(v as u32)
         }
         TokenData::Ordinal(o) if (1..=31).contains(&o.value) => Some(o.value as u32),
         _ => None,
     }
 }
 
+fn is_natural(td: &TokenData) -> bool {
+    integer_value(td).is_some_and(|v| v > 0)
+}
+
+fn is_ordinal(td: &TokenData) -> bool {
+    matches!(td, TokenData::Ordinal(o) if (1..=5).contains(&o.value))
+}
+
+fn is_month(td: &TokenData) -> bool {
+    matches!(td, TokenData::Time(d) if matches!(d.form, TimeForm::Month(_)))
+}
+
+fn is_day_of_week(td: &TokenData) -> bool {
+    matches!(td, TokenData::Time(d) if matches!(d.form, TimeForm::DayOfWeek(_)))
+}
+
+fn is_clock_time(td: &TokenData) -> bool {
+    matches!(td, TokenData::Time(d) if !d.latent && matches!(d.form, TimeForm::Hour(..) | TimeForm::HourMinute(..)))
+}
+
+/// A date without a year, or a month, that a "<year> წლის" prefix or a
+/// trailing year can complete.
+fn is_yearless_date(td: &TokenData) -> bool {
+    matches!(td, TokenData::Time(d) if matches!(d.form, TimeForm::DateMDY { year: None, .. } | TimeForm::Month(_)))
+}
+
 fn ka_month_from_text(s: &str) -> Option<u32> {
     let t = s.to_lowercase();
     if t.starts_with("იანვ") {
@@ -59,240 +96,396 @@ fn ka_month_from_text(s: &str) -> Option<u32> {
     }
 }
 
+fn ka_dow_from_text(s: &str) -> Option<u32> {
+    if s.starts_with("ორშაბათ") {
+        Some(0)
+    } else if s.starts_with("სამშაბათ") {
+        Some(1)
+    } else if s.starts_with("ოთხშაბათ") {
+        Some(2)
+    } else if s.starts_with("ხუთშაბათ") {
+        Some(3)
+    } else if s.starts_with("პარასკევ") {
+        Some(4)
+    } else if s.starts_with("შაბათ") {
+        Some(5)
+    } else if s.starts_with("კვირ") {
+        Some(6)
+    } else {
+        None
+    }
+}
+
+/// The week offset of "წინა|გასული" (-1), "ამ|ეს" (0), "შემდეგი|მომავალი"
+/// (1) and "შემდეგის შემდეგი" (2).
+fn week_offset(q: &str) -> i32 {
+    if q.starts_with("შემდეგის") {
+        2
+    } else if q.starts_with("შემდეგ") || q.starts_with("მომავალ") {
+        1
+    } else if q.starts_with("წინა") || q.starts_with("გასულ") {
+        -1
+    } else {
+        0
+    }
+}
+
+/// The day-of-week `dow` in the week `offset` weeks from this one.
+fn dow_of_week(dow: u32, offset: i32) -> TimeData {
+    TimeData::new(TimeForm::Composed(
+        Box::new(TimeData::new(TimeForm::DayOfWeek(dow))),
+        Box::new(TimeData::new(TimeForm::GrainOffset {
+            grain: Grain::Week,
+            offset,
+        })),
+    ))
+}
+
+/// The month `month` of the year `offset` years from this one.
+fn month_of_year(month: u32, offset: i32) -> TimeData {
+    TimeData::new(TimeForm::Composed(
+        Box::new(TimeData::new(TimeForm::Month(month))),
+        Box::new(TimeData::new(TimeForm::GrainOffset {
+            grain: Grain::Year,
+            offset,
+        })),
+    ))
+}
+
+/// The clock hour `hour` read after "დილის" (morning), "დღის"/"შუადღის"
+/// (afternoon), "საღამოს" (evening) or "ღამის" (night).
+fn period_hour(period: &str, hour: u32) -> Option<u32> {
+    if hour > 12 {
+        return (hour < 24).then_some(hour);
+    }
+    let hour = if period.starts_with("დილ") {
+        hour
+    } else if period.starts_with("ღამ") {
+        match hour {
+            12 => 0,
+            1..=4 => hour,
+            _ => hour.checked_add(12)?,
+        }
+    } else if hour == 12 {
+        12
+    } else {
+        hour.checked_add(12)?
+    };
+    Some(hour)
+}
+
 pub fn rules() -> Vec<Rule> {
     let mut rules = super::en::rules();
     rules.extend(vec![
-        Rule { name: "now (ka)".to_string(), pattern: vec![regex("ახლა|ეხლა")], production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Now)))) },
-        Rule { name: "today (ka)".to_string(), pattern: vec![regex("დღეს")], production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Today)))) },
-        Rule { name: "tomorrow (ka)".to_string(), pattern: vec![regex("ხვალ")], production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Tomorrow)))) },
-        Rule { name: "yesterday (ka)".to_string(), pattern: vec![regex("გუშინ")], production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Yesterday)))) },
-        Rule { name: "month may (ka)".to_string(), pattern: vec![regex("მაის(ი|ში)?")], production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Month(5))))) },
-        Rule { name: "month feb (ka)".to_string(), pattern: vec![regex("თებერვალ(ი|ს)")], production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Month(2))))) },
+        Rule {
+            name: "now (ka)".to_string(),
+            pattern: vec![regex("\\b(?:ახლავე|ახლა|ეხლა)\\b")],
+            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Now)))),
+        },
+        Rule {
+            name: "today (ka)".to_string(),
+            pattern: vec![regex("\\bდღეს\\b")],
+            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Today)))),
+        },
+        Rule {
+            name: "tomorrow (ka)".to_string(),
+            pattern: vec![regex("\\bხვალე?\\b")],
+            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Tomorrow)))),
+        },
+        Rule {
+            name: "yesterday (ka)".to_string(),
+            pattern: vec![regex("\\bგუშინ\\b")],
+            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Yesterday)))),
+        },
+        // Written up against a clock time too: "გუშინწინ8-ზე"
+        Rule {
+            name: "day before yesterday (ka)".to_string(),
+            pattern: vec![regex("\\bგუშინწინ")],
+            production: Box::new(|_| {
+                Some(TokenData::Time(TimeData::new(TimeForm::DayBeforeYesterday)))
+            }),
+        },
+        Rule {
+            name: "day after tomorrow (ka)".to_string(),
+            pattern: vec![regex("\\bზეგ\\b")],
+            production: Box::new(|_| {
+                Some(TokenData::Time(TimeData::new(TimeForm::DayAfterTomorrow)))
+            }),
+        },
         Rule {
             name: "named month (ka)".to_string(),
-            pattern: vec![regex("იანვარ(ი|ში|ს)|თებერვალ(ი|ში|ს)|მარტ(ი|ში|ს)|აპრილ(ი|ში|ს)|მაის(ი|ში|ს)|ივნის(ი|ში|ს)|ივლის(ი|ში|ს)|აგვისტო|სექტემბ(ერი|ერში|ერს)|ოქტომბ(ერი|ერში|ერს)|ნოემბ(ერი|ერში|ერს)|დეკემბ(ერი|ერში|ერს)")],
+            pattern: vec![regex(&format!("\\b({MONTHS})\\b"))],
             production: Box::new(|nodes| {
                 let s = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(0)?.to_lowercase(),
+                    TokenData::RegexMatch(m) => m.group(1)?,
                     _ => return None,
                 };
-                let month = if s.starts_with("იანვარ") {
-                    1
-                } else if s.starts_with("თებერვალ") {
-                    2
-                } else if s.starts_with("მარტ") {
-                    3
-                } else if s.starts_with("აპრილ") {
-                    4
-                } else if s.starts_with("მაის") {
-                    5
-                } else if s.starts_with("ივნის") {
-                    6
-                } else if s.starts_with("ივლის") {
-                    7
-                } else if s.starts_with("აგვისტ") {
-                    8
-                } else if s.starts_with("სექტემბ") {
-                    9
-                } else if s.starts_with("ოქტომბ") {
-                    10
-                } else if s.starts_with("ნოემბ") {
-                    11
-                } else if s.starts_with("დეკემბ") {
-                    12
-                } else {
-                    return None;
-                };
-                Some(TokenData::Time(TimeData::new(TimeForm::Month(month))))
+                Some(TokenData::Time(TimeData::new(TimeForm::Month(ka_month_from_text(s)?))))
             }),
         },
         Rule {
             name: "day of week (ka)".to_string(),
-            pattern: vec![regex("ორშაბათი?ს?|სამშაბათი?ს?|ოთხშაბათი?ს?|ხუთშაბათი?ს?|პარასკევი?ს?|შაბათი?ს?|კვირას?|კვირის")],
+            pattern: vec![regex(&format!("\\b({DAYS})\\b"))],
             production: Box::new(|nodes| {
                 let s = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(0)?,
+                    TokenData::RegexMatch(m) => m.group(1)?,
                     _ => return None,
                 };
-                let dow = if s.contains("ორშაბათ") {
-                    0
-                } else if s.contains("სამშაბათ") {
-                    1
-                } else if s.contains("ოთხშაბათ") {
-                    2
-                } else if s.contains("ხუთშაბათ") {
-                    3
-                } else if s.contains("პარასკევ") {
-                    4
-                } else if s.contains("შაბათ") {
-                    5
-                } else if s.contains("კვირა") || s.contains("კვირის") {
-                    6
-                } else {
-                    return None;
-                };
-                Some(TokenData::Time(TimeData::new(TimeForm::DayOfWeek(dow))))
+                Some(TokenData::Time(TimeData::new(TimeForm::DayOfWeek(ka_dow_from_text(s)?))))
             }),
         },
+        // "ამ ორშაბათს" is the coming Monday; "შემდეგ სამშაბათს" and
+        // "მომავალ სამშაბათს" the one after it
         Rule {
-            name: "year with -ში (ka)".to_string(),
-            pattern: vec![regex("(\\d{4})-?ში")],
+            name: "this/next <day-of-week> (ka)".to_string(),
+            pattern: vec![regex(&format!("\\b(ამ|ეს|შემდეგის\\s+შემდეგ[ის]?|შემდეგ[ის]?|მომავალ[ის]?)\\s+({DAYS})\\b"))],
             production: Box::new(|nodes| {
-                let y = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(1)?,
+                let (q, s) = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => (m.group(1)?, m.group(2)?),
                     _ => return None,
                 };
-                let year: i32 = y.parse().ok()?;
-                Some(TokenData::Time(TimeData::new(TimeForm::Year(year))))
+                let dow = ka_dow_from_text(s)?;
+                let t = match week_offset(q) {
+                    0 => TimeData::new(TimeForm::DayOfWeek(dow)),
+                    offset => TimeData::new(TimeForm::NDOWsFromTime {
+                        n: offset.checked_add(1)?,
+                        dow,
+                        base: Box::new(TimeData::new(TimeForm::Today)),
+                    }),
+                };
+                Some(TokenData::Time(t))
             }),
         },
         Rule {
-            name: "<dom> feb (ka)".to_string(),
-            pattern: vec![predicate(is_dom_token), regex("თებერვალ(ი|ს)")],
+            name: "last <day-of-week> (ka)".to_string(),
+            pattern: vec![regex(&format!("\\b(?:ბოლო|წინა|გასულ[ი]?)\\s+({DAYS})\\b"))],
             production: Box::new(|nodes| {
-                Some(TokenData::Time(TimeData::new(TimeForm::DateMDY { month: 2, day: dom_value(&nodes[0].token_data)?, year: None })))
+                let s = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?,
+                    _ => return None,
+                };
+                let mut t = TimeData::new(TimeForm::DayOfWeek(ka_dow_from_text(s)?));
+                t.direction = Some(Direction::Past);
+                Some(TokenData::Time(t))
             }),
         },
+        // "წინა კვირის კვირას", "შემდეგი კვირის ოთხშაბათი"
         Rule {
-            name: "1-ლი მარტი (ka)".to_string(),
-            pattern: vec![regex("(\\d{1,2})-?ლი\\s*მარტი?ს?")],
+            name: "<day-of-week> of last/this/next week (ka)".to_string(),
+            pattern: vec![regex(&format!("\\b(ამ|ეს|წინა|გასული?|შემდეგი?|მომავალი?)\\s+კვირის\\s+({DAYS})\\b"))],
             production: Box::new(|nodes| {
-                let d = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(1)?,
+                let (q, s) = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => (m.group(1)?, m.group(2)?),
                     _ => return None,
                 };
-                let day: u32 = d.parse().ok()?;
-                if !(1..=31).contains(&day) {
-                    return None;
-                }
-                Some(TokenData::Time(TimeData::new(TimeForm::DateMDY { month: 3, day, year: None })))
+                Some(TokenData::Time(dow_of_week(ka_dow_from_text(s)?, week_offset(q))))
             }),
         },
+        // "შემდეგი მარტი" is March of next year even before this March
         Rule {
-            name: "პირველი მარტი (ka)".to_string(),
-            pattern: vec![regex("პირველი\\s*მარტი?ს?")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::DateMDY { month: 3, day: 1, year: None })))),
+            name: "next <month> (ka)".to_string(),
+            pattern: vec![regex(&format!("\\b(შემდეგის\\s+შემდეგ[ის]?|შემდეგ[ის]?|მომავალ[ის]?)\\s+({MONTHS})\\b"))],
+            production: Box::new(|nodes| {
+                let (q, s) = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => (m.group(1)?, m.group(2)?),
+                    _ => return None,
+                };
+                Some(TokenData::Time(month_of_year(ka_month_from_text(s)?, week_offset(q))))
+            }),
         },
         Rule {
-            name: "3 მარტი (ka)".to_string(),
-            pattern: vec![regex("(\\d{1,2})\\s*მარტი?ს?")],
+            name: "year with -ში (ka)".to_string(),
+            pattern: vec![regex("(?-u:\\b)(\\d{4})-?ში\\b")],
             production: Box::new(|nodes| {
-                let d = match &nodes[0].token_data {
+                let y = match &nodes[0].token_data {
                     TokenData::RegexMatch(m) => m.group(1)?,
                     _ => return None,
                 };
-                let day: u32 = d.parse().ok()?;
-                if !(1..=31).contains(&day) {
-                    return None;
-                }
-                Some(TokenData::Time(TimeData::new(TimeForm::DateMDY { month: 3, day, year: None })))
+                let year: i32 = y.parse().ok()?;
+                Some(TokenData::Time(TimeData::new(TimeForm::Year(year))))
             }),
         },
+        // "3 მარტი", "პირველი მარტი", "1-ლი მარტი", "თხუთმეტი თებერვალი"
         Rule {
-            name: "8 აგვისტო (ka)".to_string(),
-            pattern: vec![regex("(\\d{1,2})\\s*აგვისტო?ს?")],
+            name: "<day-of-month> <month> (ka)".to_string(),
+            pattern: vec![predicate(is_dom_token), predicate(is_month)],
             production: Box::new(|nodes| {
-                let d = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(1)?,
-                    _ => return None,
+                let TimeForm::Month(month) = time_data(&nodes[1].token_data)?.form else {
+                    return None;
                 };
-                let day: u32 = d.parse().ok()?;
-                if !(1..=31).contains(&day) {
+                Some(TokenData::Time(TimeData::new(TimeForm::DateMDY {
+                    month,
+                    day: dom_value(&nodes[0].token_data)?,
+                    year: None,
+                })))
+            }),
+        },
+        // "თებერვლის მეთხუთმეტე დღეს", "ოქტომბრის მე-3 დღე"
+        Rule {
+            name: "<month> <ordinal> day (ka)".to_string(),
+            pattern: vec![predicate(is_month), predicate(is_dom_token), regex("\\bდღე(?:ს)?\\b")],
+            production: Box::new(|nodes| {
+                let TimeForm::Month(month) = time_data(&nodes[0].token_data)?.form else {
                     return None;
-                }
-                Some(TokenData::Time(TimeData::new(TimeForm::DateMDY { month: 8, day, year: None })))
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::DateMDY {
+                    month,
+                    day: dom_value(&nodes[1].token_data)?,
+                    year: None,
+                })))
             }),
         },
+        // "2015 წლის 14 აპრილს", "2014 წლის ოქტომბერი"
         Rule {
-            name: "2014 წლის ოქტომბერი (ka)".to_string(),
-            pattern: vec![regex("(\\d{4})\\s+წლის\\s+ოქტომბერი")],
+            name: "<year> წლის <date> (ka)".to_string(),
+            pattern: vec![regex("(?-u:\\b)(\\d{4})\\s+წლის\\b"), predicate(is_yearless_date)],
             production: Box::new(|nodes| {
-                let y = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(1)?,
+                let year: i32 = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?.parse().ok()?,
                     _ => return None,
                 };
-                let year: i32 = y.parse().ok()?;
-                Some(TokenData::Time(TimeData::new(TimeForm::Composed(
-                    Box::new(TimeData::new(TimeForm::Year(year))),
-                    Box::new(TimeData::new(TimeForm::Month(10))),
-                ))))
+                let t = time_data(&nodes[1].token_data)?;
+                let form = match t.form {
+                    TimeForm::DateMDY { month, day, .. } => TimeForm::DateMDY { month, day, year: Some(year) },
+                    _ => TimeForm::Composed(Box::new(TimeData::new(TimeForm::Year(year))), Box::new(t.clone())),
+                };
+                Some(TokenData::Time(TimeData::new(form)))
             }),
         },
         Rule {
-            name: "14 აპრილი 2015 (ka)".to_string(),
-            pattern: vec![regex("(\\d{1,2})\\s+აპრილი\\s+(\\d{4})")],
+            name: "<date> <year> (ka)".to_string(),
+            pattern: vec![predicate(is_yearless_date), regex("(?-u:\\b)(\\d{4})\\b")],
             production: Box::new(|nodes| {
-                let (d, y) = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => (m.group(1)?, m.group(2)?),
+                let year: i32 = match &nodes[1].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?.parse().ok()?,
                     _ => return None,
                 };
-                let day: u32 = d.parse().ok()?;
-                let year: i32 = y.parse().ok()?;
-                if !(1..=31).contains(&day) {
+                let TimeForm::DateMDY { month, day, .. } = time_data(&nodes[0].token_data)?.form else {
                     return None;
-                }
-                Some(TokenData::Time(TimeData::new(TimeForm::DateMDY { month: 4, day, year: Some(year) })))
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::DateMDY { month, day, year: Some(year) })))
             }),
         },
         Rule {
-            name: "2015 წლის 14 აპრილი (ka)".to_string(),
-            pattern: vec![regex("(\\d{4})\\s+წლის\\s+(\\d{1,2})\\s+აპრილი")],
+            name: "<month>/<year> (ka)".to_string(),
+            pattern: vec![regex("(?-u:\\b)(\\d{1,2})/(\\d{4})\\b")],
             production: Box::new(|nodes| {
-                let (y, d) = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => (m.group(1)?, m.group(2)?),
+                let (m, y) = match &nodes[0].token_data {
+                    TokenData::RegexMatch(rm) => (rm.group(1)?, rm.group(2)?),
                     _ => return None,
                 };
-                let year: i32 = y.parse().ok()?;
-                let day: u32 = d.parse().ok()?;
-                if !(1..=31).contains(&day) {
+                let month: u32 = m.parse().ok()?;
+                if !(1..=12).contains(&month) {
                     return None;
                 }
-                Some(TokenData::Time(TimeData::new(TimeForm::DateMDY { month: 4, day, year: Some(year) })))
+                Some(TokenData::Time(TimeData::new(TimeForm::DateMDY { month, day: 1, year: Some(y.parse().ok()?) })))
             }),
         },
+        // "ოქტომბრის პირველი სამშაბათი", "სექტემბრის მე-3 სამშაბათი"
         Rule {
-            name: "2015 წლის 14 აპრილს (ka)".to_string(),
-            pattern: vec![regex("(\\d{4})\\s+წლის\\s+(\\d{1,2})\\s+აპრილს")],
+            name: "<month> <ordinal> <day-of-week> (ka)".to_string(),
+            pattern: vec![predicate(is_month), predicate(is_ordinal), predicate(is_day_of_week)],
             production: Box::new(|nodes| {
-                let (y, d) = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => (m.group(1)?, m.group(2)?),
+                let n = match &nodes[1].token_data {
+                    TokenData::Ordinal(o) => i32::try_from(o.value).ok()?,
                     _ => return None,
                 };
-                let year: i32 = y.parse().ok()?;
-                let day: u32 = d.parse().ok()?;
-                if !(1..=31).contains(&day) {
+                let TimeForm::DayOfWeek(dow) = time_data(&nodes[2].token_data)?.form else {
                     return None;
-                }
-                Some(TokenData::Time(TimeData::new(TimeForm::DateMDY { month: 4, day, year: Some(year) })))
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::NthDOWOfTime {
+                    n,
+                    dow,
+                    base: Box::new(time_data(&nodes[0].token_data)?.clone()),
+                })))
             }),
         },
         Rule {
-            name: "შემდეგი მარტი (ka)".to_string(),
-            pattern: vec![regex("შემდეგი\\s+მარტი")],
-            production: Box::new(|_| {
-                let mut t = TimeData::new(TimeForm::Month(3));
-                t.direction = Some(Direction::Future);
-                Some(TokenData::Time(t))
+            name: "<month> last <day-of-week> (ka)".to_string(),
+            pattern: vec![predicate(is_month), regex("\\bბოლო\\b"), predicate(is_day_of_week)],
+            production: Box::new(|nodes| {
+                let TimeForm::DayOfWeek(dow) = time_data(&nodes[2].token_data)?.form else {
+                    return None;
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::LastDOWOfTime {
+                    dow,
+                    base: Box::new(time_data(&nodes[0].token_data)?.clone()),
+                })))
             }),
         },
+        // The last weekend ending within the month: "ოქტომბრის ბოლო უქმეები"
         Rule {
-            name: "წინა თვე (ka)".to_string(),
-            pattern: vec![regex("წინა\\s+თვე")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset { grain: crate::dimensions::time_grain::Grain::Month, offset: -1 })))),
+            name: "last weekend of <month> (ka)".to_string(),
+            pattern: vec![regex(&format!("(?:(?-u:\\b)(\\d{{4}})\\s+წლის\\s+)?\\b({MONTHS})\\s+ბოლო\\s+(?:{WEEKEND})\\b"))],
+            production: Box::new(|nodes| {
+                let (y, s) = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => (m.group(1), m.group(2)?),
+                    _ => return None,
+                };
+                let month = ka_month_from_text(s)?;
+                let base = match y {
+                    Some(y) => TimeForm::DateMDY { month, day: 1, year: Some(y.parse().ok()?) },
+                    None => TimeForm::Month(month),
+                };
+                let monday = TimeData::new(TimeForm::LastDOWOfTime { dow: 0, base: Box::new(TimeData::new(base)) });
+                let saturday = TimeData::new(TimeForm::NDOWsFromTime { n: -1, dow: 5, base: Box::new(monday.clone()) });
+                Some(TokenData::Time(TimeData::new(TimeForm::Interval(
+                    Box::new(saturday),
+                    Box::new(monday),
+                    true,
+                ))))
+            }),
         },
+        // Saturday and Sunday: "ამ შაბათკვირას", "გასულ უქმეებზე"
         Rule {
-            name: "შემდეგი თვე (ka)".to_string(),
-            pattern: vec![regex("შემდეგი\\s+თვე")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset { grain: crate::dimensions::time_grain::Grain::Month, offset: 1 })))),
+            name: "this/last/next weekend (ka)".to_string(),
+            pattern: vec![regex(&format!("\\b(?:(ამ|ეს|წინა|გასული?|შემდეგი?|მომავალი?)\\s+)?(?:{WEEKEND})\\b"))],
+            production: Box::new(|nodes| {
+                let offset = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(1).map_or(0, week_offset),
+                    _ => return None,
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::Interval(
+                    Box::new(dow_of_week(5, offset)),
+                    Box::new(dow_of_week(6, offset)),
+                    false,
+                ))))
+            }),
         },
+        // "წინა თვე", "შემდეგ კვარტალში", "მომავალი წელი"
         Rule {
-            name: "შემდეგ კვარტალში (ka)".to_string(),
-            pattern: vec![regex("შემდეგ\\s+კვარტალში")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset { grain: crate::dimensions::time_grain::Grain::Quarter, offset: 1 })))),
+            name: "last/next <cycle> (ka)".to_string(),
+            pattern: vec![regex("\\b(წინა|გასულ[ი]?|შემდეგ[ი]?|მომავალ[ი]?)\\s+(თვე(?:ს|ში)?|კვარტალ(?:ი|ს|ში)?|წელ(?:ი|ს|იწადი?)|წლის)\\b")],
+            production: Box::new(|nodes| {
+                let (q, g) = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => (m.group(1)?, m.group(2)?),
+                    _ => return None,
+                };
+                let grain = if g.starts_with("თვ") {
+                    Grain::Month
+                } else if g.starts_with("კვარტ") {
+                    Grain::Quarter
+                } else {
+                    Grain::Year
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::NthGrain {
+                    n: 1,
+                    grain,
+                    past: week_offset(q) < 0,
+                    interval: true,
+                })))
+            }),
+        },
+        Rule {
+            name: "last year (ka)".to_string(),
+            pattern: vec![regex("\\bშარშან\\b")],
+            production: Box::new(|_| {
+                Some(TokenData::Time(TimeData::new(TimeForm::NthGrain { n: 1, grain: Grain::Year, past: true, interval: true })))
+            }),
         },
         Rule {
-            name: "წელს მეორე კვარტალში (ka)".to_string(),
-            pattern: vec![regex("წელს\\s+(პირველ|მეორ|მესამ|მეოთხ)ე?\\s+კვარტალ(ში|შია)?")],
+            name: "<ordinal> quarter this year (ka)".to_string(),
+            pattern: vec![regex("\\b(?:ამ\\s+)?წელს\\s+(პირველ|მეორ|მესამ|მეოთხ)ე?\\s+კვარტალ(?:ი|ს|ში)?\\b")],
             production: Box::new(|nodes| {
                 let q = match &nodes[0].token_data {
                     TokenData::RegexMatch(m) => m.group(1)?,
@@ -308,62 +501,33 @@ pub fn rules() -> Vec<Rule> {
                     4
                 };
                 Some(TokenData::Time(TimeData::new(TimeForm::Composed(
-                    Box::new(TimeData::new(TimeForm::AllGrain(crate::dimensions::time_grain::Grain::Year))),
+                    Box::new(TimeData::new(TimeForm::AllGrain(Grain::Year))),
                     Box::new(TimeData::new(TimeForm::Quarter(quarter))),
                 ))))
             }),
         },
+        // "15 საათზე", "20 საათი", "8-ზე"
         Rule {
-            name: "შარშან (ka)".to_string(),
-            pattern: vec![regex("შარშან")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset { grain: crate::dimensions::time_grain::Grain::Year, offset: -1 })))),
-        },
-        Rule {
-            name: "წინა წელს (ka)".to_string(),
-            pattern: vec![regex("წინა\\s+წელს")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset { grain: crate::dimensions::time_grain::Grain::Year, offset: -1 })))),
-        },
-        Rule {
-            name: "მომავალი წელი (ka)".to_string(),
-            pattern: vec![regex("მომავალი\\s+წელი")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset { grain: crate::dimensions::time_grain::Grain::Year, offset: 1 })))),
-        },
-        Rule {
-            name: "შემდეგ წელს (ka)".to_string(),
-            pattern: vec![regex("შემდეგ\\s+წელს")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset { grain: crate::dimensions::time_grain::Grain::Year, offset: 1 })))),
-        },
-        Rule {
-            name: "ოქტომბრის მესამე დღე (ka)".to_string(),
-            pattern: vec![regex("ოქტომბრის\\s+მესამე\\s+დღე")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::DateMDY { month: 10, day: 3, year: None })))),
-        },
-        Rule {
-            name: "ოქტომბრის მე-3 დღე (ka)".to_string(),
-            pattern: vec![regex("ოქტომბრის\\s+მე-3\\s+დღე")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::DateMDY { month: 10, day: 3, year: None })))),
-        },
-        Rule {
-            name: "15 საათზე (ka)".to_string(),
-            pattern: vec![regex("(\\d{1,2})\\s*საათზე")],
+            name: "<hour> საათი|-ზე (ka)".to_string(),
+            pattern: vec![regex("(?-u:\\b)(\\d{1,2})(?:\\s*საათ(?:ი|ზე|ისთვის)|-ზე)\\b")],
             production: Box::new(|nodes| {
-                let h = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(1)?,
+                let hour: u32 = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?.parse().ok()?,
                     _ => return None,
                 };
-                let hour: u32 = h.parse().ok()?;
                 if hour > 23 {
                     return None;
                 }
-                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(hour, 0, false))))
+                Some(TokenData::Time(TimeData::new(TimeForm::Hour(hour, (1..=12).contains(&hour)))))
             }),
         },
+        // "3 საათსა და 15 წუთზე", "1 საათი და 30 წუთი"
         Rule {
-            name: "4-ის 15 წუთზე (ka)".to_string(),
-            pattern: vec![regex("(\\d{1,2})-ის\\s*(\\d{1,2})\\s*წუთზე")],
+            name: "<hour> საათსა და <minute> წუთზე (ka)".to_string(),
+            pattern: vec![regex("(?-u:\\b)(\\d{1,2})\\s*საათ(?:სა|ი)\\s+და\\s+(\\d{1,2})\\s*წუთ(?:ი|ზე)?\\b")],
             production: Box::new(|nodes| {
                 let (h, m) = match &nodes[0].token_data {
-                    TokenData::RegexMatch(mm) => (mm.group(1)?, mm.group(2)?),
+                    TokenData::RegexMatch(rm) => (rm.group(1)?, rm.group(2)?),
                     _ => return None,
                 };
                 let hour: u32 = h.parse().ok()?;
@@ -371,313 +535,330 @@ pub fn rules() -> Vec<Rule> {
                 if hour > 23 || minute > 59 {
                     return None;
                 }
-                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(hour, minute, false))))
+                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(hour, minute, (1..=12).contains(&hour)))))
             }),
         },
+        // Minutes into the hour before: "4-ის 15 წუთზე" is 3:15
         Rule {
-            name: "3 საათსა და 15 წუთზე (ka)".to_string(),
-            pattern: vec![regex("(\\d{1,2})\\s*საათსა\\s*და\\s*(\\d{1,2})\\s*წუთზე")],
+            name: "<minute> წუთზე of <hour> (ka)".to_string(),
+            pattern: vec![regex("(?-u:\\b)(\\d{1,2})-ის\\s+(?:(\\d{1,2})\\s*წუთ(?:ი|ზე)?|(ნახევ)(?:არი|არზე|რიდან|რამდე))\\b")],
             production: Box::new(|nodes| {
-                let (h, m) = match &nodes[0].token_data {
-                    TokenData::RegexMatch(mm) => (mm.group(1)?, mm.group(2)?),
+                let (h, m, half) = match &nodes[0].token_data {
+                    TokenData::RegexMatch(rm) => (rm.group(1)?, rm.group(2), rm.group(3).is_some()),
                     _ => return None,
                 };
                 let hour: u32 = h.parse().ok()?;
-                let minute: u32 = m.parse().ok()?;
-                if hour > 23 || minute > 59 {
+                let minute: i32 = if half { 30 } else { m?.parse().ok()? };
+                if !(1..=24).contains(&hour) || minute == 0 {
                     return None;
                 }
-                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(hour, minute, false))))
+                let form = hour_minute_offset(hour, (1..=12).contains(&hour), minute.checked_sub(60)?)?;
+                Some(TokenData::Time(TimeData::new(form)))
             }),
         },
+        // "5 საათს აკლია 10 წუთი", "5-ს აკლია 15 წუთი": ten, a quarter to five
         Rule {
-            name: "4-ის ნახევარზე (ka)".to_string(),
-            pattern: vec![regex("(\\d{1,2})-ის\\s*ნახევარ(ზე|ი)")],
+            name: "<hour> აკლია <minutes> (ka)".to_string(),
+            pattern: vec![regex("(?-u:\\b)(\\d{1,2})(?:\\s*საათს|-ს)\\s+აკლია\\s+(\\d{1,2})\\s*წუთი\\b")],
             production: Box::new(|nodes| {
-                let h = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(1)?,
+                let (h, m) = match &nodes[0].token_data {
+                    TokenData::RegexMatch(rm) => (rm.group(1)?, rm.group(2)?),
                     _ => return None,
                 };
                 let hour: u32 = h.parse().ok()?;
-                if !(1..=24).contains(&hour) {
+                let minute: i32 = m.parse().ok()?;
+                if minute == 0 {
                     return None;
                 }
-                let out_hour = if hour == 24 { 23 } else { hour.checked_sub(1)? };
-                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(out_hour, 30, false))))
+                let form = hour_minute_offset(hour, (1..=12).contains(&hour), minute.checked_neg()?)?;
+                Some(TokenData::Time(TimeData::new(form)))
             }),
         },
+        // "ხვალ შუადღის 5", "დღეს ღამის 11 საათზე"
         Rule {
-            name: "20 საათი (ka)".to_string(),
-            pattern: vec![regex("(\\d{1,2})\\s*საათი")],
+            name: "<part-of-day> <hour> (ka)".to_string(),
+            pattern: vec![regex("\\b(დილის|დღის|შუადღის|საღამოს|ღამის)\\s+(\\d{1,2})(?:\\s*საათ(?:ი|ზე)|-ზე)?\\b")],
             production: Box::new(|nodes| {
-                let h = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(1)?,
+                let (p, h) = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => (m.group(1)?, m.group(2)?),
                     _ => return None,
                 };
-                let hour: u32 = h.parse().ok()?;
-                if hour > 23 {
-                    return None;
-                }
-                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(hour, 0, false))))
+                let hour = period_hour(p, h.parse().ok()?)?;
+                Some(TokenData::Time(TimeData::new(TimeForm::Hour(hour, false))))
             }),
         },
+        // "დილის 10:30", "დილის 10 საათსა და 30 წუთზე"
         Rule {
-            name: "7 დღის წინ (ka)".to_string(),
-            pattern: vec![regex("(\\d{1,2})\\s*დღის\\s*წინ")],
+            name: "<part-of-day> <time-of-day> (ka)".to_string(),
+            pattern: vec![regex("\\b(დილის|დღის|შუადღის|საღამოს|ღამის)\\b"), predicate(is_clock_time)],
             production: Box::new(|nodes| {
-                let d = match &nodes[0].token_data {
+                let p = match &nodes[0].token_data {
                     TokenData::RegexMatch(m) => m.group(1)?,
                     _ => return None,
                 };
-                let days: i32 = d.parse().ok()?;
-                Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset { grain: crate::dimensions::time_grain::Grain::Day, offset: days.checked_neg()? })))
+                let form = match time_data(&nodes[1].token_data)?.form {
+                    TimeForm::Hour(h, _) => TimeForm::Hour(period_hour(p, h)?, false),
+                    TimeForm::HourMinute(h, m, _) => TimeForm::HourMinute(period_hour(p, h)?, m, false),
+                    _ => return None,
+                };
+                Some(TokenData::Time(TimeData::new(form)))
             }),
         },
         Rule {
-            name: "შვიდი დღის წინ (ka)".to_string(),
-            pattern: vec![regex("შვიდი\\s*დღის\\s*წინ")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset { grain: crate::dimensions::time_grain::Grain::Day, offset: -7 })))),
+            name: "<time-of-day>-ზე (ka)".to_string(),
+            pattern: vec![predicate(is_clock_time), regex("-ზე\\b")],
+            production: Box::new(|nodes| Some(TokenData::Time(time_data(&nodes[0].token_data)?.clone()))),
         },
+        // "9:30-დან 11:00-მდე"
         Rule {
-            name: "თოთხმეტი დღის წინ (ka)".to_string(),
-            pattern: vec![regex("თოთხმეტი\\s*დღის\\s*წინ")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset { grain: crate::dimensions::time_grain::Grain::Day, offset: -14 })))),
-        },
-        Rule {
-            name: "სამი თვის წინ (ka)".to_string(),
-            pattern: vec![regex("სამი\\s+თვის\\s+წინ")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset { grain: crate::dimensions::time_grain::Grain::Month, offset: -3 })))),
-        },
-        Rule {
-            name: "3 თვის წინ (ka)".to_string(),
-            pattern: vec![regex("3\\s+თვის\\s+წინ")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset { grain: crate::dimensions::time_grain::Grain::Month, offset: -3 })))),
-        },
-        Rule {
-            name: "გასულ ორ თვეში (ka)".to_string(),
-            pattern: vec![regex("(გასულ|წინა)\\s+(ორ|2)\\s+თვე(ში|ს)")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::NthGrain {
-                n: 2,
-                grain: crate::dimensions::time_grain::Grain::Month,
-                past: true,
-                interval: true,
-            })))),
+            name: "<time>-დან <time>-მდე (ka)".to_string(),
+            pattern: vec![
+                dim(DimensionKind::Time),
+                regex("-?დან\\b"),
+                dim(DimensionKind::Time),
+                regex("-?მდე\\b"),
+            ],
+            production: Box::new(|nodes| {
+                let from = time_data(&nodes[0].token_data)?;
+                let to = time_data(&nodes[2].token_data)?;
+                Some(TokenData::Time(TimeData::new(TimeForm::Interval(
+                    Box::new(from.clone()),
+                    Box::new(to.clone()),
+                    false,
+                ))))
+            }),
         },
+        // Morning runs from 4:00; "ღამე" is the evening up to midnight and
+        // "გვიან ღამე" its last three hours
         Rule {
-            name: "15 წუთში (ka)".to_string(),
-            pattern: vec![regex("(\\d{1,3})\\s*წუთში")],
+            name: "part of day (ka)".to_string(),
+            pattern: vec![regex("\\b(გვიან\\s+)?(დილ(?:ა|ას|ით)|შუადღ(?:ე|ეს|ით)|საღამო(?:ს|თი)?|ღამ(?:ე|ით))\\b")],
             production: Box::new(|nodes| {
-                let n = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(1)?,
+                let (late, s) = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => (m.group(1).is_some(), m.group(2)?),
                     _ => return None,
                 };
-                let mins: i32 = n.parse().ok()?;
-                Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset {
-                    grain: crate::dimensions::time_grain::Grain::Minute,
-                    offset: mins,
-                })))
+                let mut t = if s.starts_with("დილ") {
+                    TimeData::new(TimeForm::Interval(
+                        Box::new(TimeData::new(TimeForm::Hour(4, false))),
+                        Box::new(TimeData::new(TimeForm::Hour(12, false))),
+                        true,
+                    ))
+                } else if s.starts_with("შუადღ") {
+                    TimeData::new(TimeForm::PartOfDay(PartOfDay::Afternoon))
+                } else {
+                    TimeData::new(TimeForm::PartOfDay(PartOfDay::Evening))
+                };
+                if late {
+                    t.early_late = Some(EarlyLate::Late);
+                }
+                Some(TokenData::Time(t))
             }),
         },
+        // "7 დღის წინ", "ორი წლის წინ"
         Rule {
-            name: "ორი წლის წინ (ka)".to_string(),
-            pattern: vec![regex("ორი\\s+წლის\\s+წინ|2\\s+წლის\\s+წინ")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset { grain: crate::dimensions::time_grain::Grain::Year, offset: -2 })))),
-        },
-        Rule {
-            name: "ივლისი 13-15 (ka)".to_string(),
-            pattern: vec![regex("ივლისი\\s*(\\d{1,2})\\s*[-–]\\s*(\\d{1,2})")],
+            name: "<n> <cycle> ago (ka)".to_string(),
+            pattern: vec![predicate(is_natural), dim(DimensionKind::TimeGrain), regex("\\bწინ\\b")],
             production: Box::new(|nodes| {
-                let (d1, d2) = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => (m.group(1)?, m.group(2)?),
+                let n = integer_value(&nodes[0].token_data)?;
+                let grain = match &nodes[1].token_data {
+                    TokenData::TimeGrain(g) => *g,
                     _ => return None,
                 };
-                let day1: u32 = d1.parse().ok()?;
-                let day2: u32 = d2.parse().ok()?;
-                if !(1..=31).contains(&day1) || !(1..=31).contains(&day2) {
-                    return None;
-                }
-                let from = TimeData::new(TimeForm::DateMDY { month: 7, day: day1, year: None });
-                let to = TimeData::new(TimeForm::DateMDY { month: 7, day: day2, year: None });
-                Some(TokenData::Time(TimeData::new(TimeForm::Interval(
-                    Box::new(from),
-                    Box::new(to),
-                    false,
-                ))))
+                Some(TokenData::Time(TimeData::new(TimeForm::RelativeGrain { n: n.checked_neg()?, grain })))
             }),
         },
+        // "15 წუთში", "ორ კვირაში"
         Rule {
-            name: "13-15 ივლისი (ka)".to_string(),
-            pattern: vec![regex("(\\d{1,2})\\s*[-–]\\s*(\\d{1,2})\\s*ივლისი")],
+            name: "in <n> <cycle> (ka)".to_string(),
+            pattern: vec![predicate(is_natural), regex("\\b(წამ|წუთ|საათ|დღე|კვირა|თვე|წელიწად|წელ)ში\\b")],
             production: Box::new(|nodes| {
-                let (d1, d2) = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => (m.group(1)?, m.group(2)?),
+                let n = integer_value(&nodes[0].token_data)?;
+                let g = match &nodes[1].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?,
                     _ => return None,
                 };
-                let day1: u32 = d1.parse().ok()?;
-                let day2: u32 = d2.parse().ok()?;
-                if !(1..=31).contains(&day1) || !(1..=31).contains(&day2) {
-                    return None;
-                }
-                let from = TimeData::new(TimeForm::DateMDY { month: 7, day: day1, year: None });
-                let to = TimeData::new(TimeForm::DateMDY { month: 7, day: day2, year: None });
-                Some(TokenData::Time(TimeData::new(TimeForm::Interval(
-                    Box::new(from),
-                    Box::new(to),
-                    false,
-                ))))
+                let grain = match g {
+                    "წამ" => Grain::Second,
+                    "წუთ" => Grain::Minute,
+                    "საათ" => Grain::Hour,
+                    "დღე" => Grain::Day,
+                    "კვირა" => Grain::Week,
+                    "თვე" => Grain::Month,
+                    _ => Grain::Year,
+                };
+                let mut t = TimeData::new(TimeForm::RelativeGrain { n, grain });
+                t.duration_qualifier = Some(DurationQualifier::Delayed);
+                Some(TokenData::Time(t))
             }),
         },
+        // "ორი კვირის განმავლობაში" runs from now
         Rule {
-            name: "this/next/last season (ka)".to_string(),
-            pattern: vec![regex("(ამ|ეს|შემდეგი|მომავალი|წინა|ბოლო|გასული)\\s+(გაზაფხულ(ი|ზე|ს)?|ზაფხულ(ი|ზე|ს)?|შემოდგომ(ა|აზე|ას)?|ზამთ(არი|არში|არს)?)")],
+            name: "within <n> <cycle> (ka)".to_string(),
+            pattern: vec![predicate(is_natural), dim(DimensionKind::TimeGrain), regex("\\bგანმავლობაში\\b")],
             production: Box::new(|nodes| {
-                let (q, season_s) = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => (m.group(1)?.to_lowercase(), m.group(2)?.to_lowercase()),
+                let n = integer_value(&nodes[0].token_data)?;
+                let grain = match &nodes[1].token_data {
+                    TokenData::TimeGrain(g) => *g,
                     _ => return None,
                 };
-                let season = if season_s.contains("გაზაფხ") {
-                    0
-                } else if season_s.contains("ზაფხ") {
-                    1
-                } else if season_s.contains("შემოდგომ") {
-                    2
-                } else {
-                    3
-                };
-                let mut t = TimeData::new(TimeForm::Season(season));
-                if q == "შემდეგი" || q == "მომავალი" {
-                    t.direction = Some(Direction::Future);
-                } else if q == "წინა" || q == "ბოლო" || q == "გასული" {
-                    t.direction = Some(Direction::Past);
-                }
+                let mut t = TimeData::new(TimeForm::Interval(
+                    Box::new(TimeData::new(TimeForm::Now)),
+                    Box::new(TimeData::new(TimeForm::RelativeGrain { n, grain })),
+                    false,
+                ));
+                t.duration_qualifier = Some(DurationQualifier::Bounded);
                 Some(TokenData::Time(t))
             }),
         },
+        // "გასული 3 კვირა", "გასულ ორ თვეში": the whole cycles before this one
         Rule {
-            name: "this/next/last season generic (ka)".to_string(),
-            pattern: vec![regex("(ამ|ეს|შემდეგი|მომავალი|წინა|ბოლო|გასული)\\s+სეზონ(ი|ზე|ს)?")],
+            name: "last/next <n> <cycle> (ka)".to_string(),
+            pattern: vec![regex("\\b(წინა|გასულ[ი]?|შემდეგ[ი]?|მომავალ[ი]?)\\b"), predicate(is_natural), dim(DimensionKind::TimeGrain)],
             production: Box::new(|nodes| {
-                let q = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(1)?.to_lowercase(),
+                let past = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => week_offset(m.group(1)?) < 0,
                     _ => return None,
                 };
-                let mut t = TimeData::new(TimeForm::Season(99));
-                if q == "შემდეგი" || q == "მომავალი" {
-                    t.direction = Some(Direction::Future);
-                } else if q == "წინა" || q == "ბოლო" || q == "გასული" {
-                    t.direction = Some(Direction::Past);
-                }
-                Some(TokenData::Time(t))
+                let n = integer_value(&nodes[1].token_data)?;
+                let grain = match &nodes[2].token_data {
+                    TokenData::TimeGrain(g) => *g,
+                    _ => return None,
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::NthGrain { n, grain, past, interval: true })))
             }),
         },
+        // "ბოლო 2 წამი" takes in the current second as well
         Rule {
-            name: "this/next/last weekend (ka)".to_string(),
-            pattern: vec![regex("(ამ|ეს|შემდეგი|მომავალი|წინა|ბოლო|გასულ)\\s+უქმე(ებზე|ზე|ები|ა)?")],
+            name: "ბოლო <n> <cycle> (ka)".to_string(),
+            pattern: vec![regex("\\bბოლო\\b"), predicate(is_natural), dim(DimensionKind::TimeGrain)],
             production: Box::new(|nodes| {
-                let q = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(1)?.to_lowercase(),
+                let n = integer_value(&nodes[1].token_data)?;
+                let grain = match &nodes[2].token_data {
+                    TokenData::TimeGrain(g) => *g,
                     _ => return None,
                 };
-                let mut t = TimeData::new(TimeForm::Weekend);
-                if q == "შემდეგი" || q == "მომავალი" {
-                    t.direction = Some(Direction::Future);
-                } else if q == "წინა" || q == "ბოლო" || q == "გასულ" {
-                    t.direction = Some(Direction::Past);
-                }
-                Some(TokenData::Time(t))
+                Some(TokenData::Time(TimeData::new(TimeForm::Interval(
+                    Box::new(TimeData::new(TimeForm::RelativeGrain { n: n.checked_neg()?, grain })),
+                    Box::new(TimeData::new(TimeForm::Now)),
+                    false,
+                ))))
             }),
         },
+        // "ივლისი 13-15", "13-15 ივლისი"
         Rule {
-            name: "15 თებერვლის დილა (ka)".to_string(),
-            pattern: vec![regex("(\\d{1,2})\\s+თებერვ(ალი|ალს|ლის)\\s+დილა(ს)?")],
+            name: "<month> <day>-<day> (ka)".to_string(),
+            pattern: vec![regex(&format!("(?:\\b({MONTHS})\\s+(\\d{{1,2}})\\s*[-–]\\s*(\\d{{1,2}})|(?-u:\\b)(\\d{{1,2}})\\s*[-–]\\s*(\\d{{1,2}})\\s+({MONTHS}))\\b"))],
             production: Box::new(|nodes| {
-                let d = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(1)?,
+                let (s, d1, d2) = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => match m.group(1) {
+                        Some(s) => (s, m.group(2)?, m.group(3)?),
+                        None => (m.group(6)?, m.group(4)?, m.group(5)?),
+                    },
                     _ => return None,
                 };
-                let day: u32 = d.parse().ok()?;
-                if !(1..=31).contains(&day) {
+                let month = ka_month_from_text(s)?;
+                let day1: u32 = d1.parse().ok()?;
+                let day2: u32 = d2.parse().ok()?;
+                if !(1..=31).contains(&day1) || !(1..=31).contains(&day2) {
                     return None;
                 }
-                Some(TokenData::Time(TimeData::new(TimeForm::Composed(
-                    Box::new(TimeData::new(TimeForm::DateMDY { month: 2, day, year: None })),
-                    Box::new(TimeData::new(TimeForm::PartOfDay(PartOfDay::Morning))),
+                let from = TimeData::new(TimeForm::DateMDY { month, day: day1, year: None });
+                let to = TimeData::new(TimeForm::DateMDY { month, day: day2, year: None });
+                Some(TokenData::Time(TimeData::new(TimeForm::Interval(
+                    Box::new(from),
+                    Box::new(to),
+                    false,
                 ))))
             }),
         },
+        // Named seasons are whole months: summer is June to August
         Rule {
-            name: "დღეს/ხვალ/გუშინ საღამოს (ka)".to_string(),
-            pattern: vec![regex("(დღეს|ხვალ|გუშინ)?\\s*(საღამოს|ღამე|ღამით)")],
+            name: "this/next/last season (ka)".to_string(),
+            pattern: vec![regex("\\b(?:(ამ|ეს|შემდეგი?|მომავალი?|წინა|გასული?)\\s+)?(გაზაფხულ|ზაფხულ|შემოდგომ|ზამთ(?:არ|რ))(?:ი|ს|ში|ზე|ა|ას|აზე|აში)?\\b")],
             production: Box::new(|nodes| {
-                let day = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(1).unwrap_or("დღეს"),
+                let (q, s) = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => (m.group(1), m.group(2)?),
                     _ => return None,
                 };
-                let day_td = if day == "ხვალ" {
-                    TimeData::new(TimeForm::Tomorrow)
-                } else if day == "გუშინ" {
-                    TimeData::new(TimeForm::Yesterday)
+                let offset = q.map_or(0, week_offset);
+                // The first month of the season and of the one after it
+                let (from, to) = if s.starts_with("გაზაფხ") {
+                    (3, 6)
+                } else if s.starts_with("ზაფხ") {
+                    (6, 9)
+                } else if s.starts_with("შემოდგ") {
+                    (9, 12)
                 } else {
-                    TimeData::new(TimeForm::Today)
+                    (12, 3)
                 };
-                Some(TokenData::Time(TimeData::new(TimeForm::Composed(
-                    Box::new(day_td),
-                    Box::new(TimeData::new(TimeForm::PartOfDay(PartOfDay::Evening))),
+                // Winter begins in the December of the year before
+                let from_offset = if from > to { offset.checked_sub(1)? } else { offset };
+                Some(TokenData::Time(TimeData::new(TimeForm::Interval(
+                    Box::new(month_of_year(from, from_offset)),
+                    Box::new(month_of_year(to, offset)),
+                    true,
                 ))))
             }),
         },
         Rule {
-            name: "ბოლო 2 წამი (ka)".to_string(),
-            pattern: vec![regex("ბოლო\\s+(\\d{1,2})\\s+წამ(ი|ში)")],
+            name: "this/next/last season generic (ka)".to_string(),
+            pattern: vec![regex("\\b(ამ|ეს|შემდეგი?|მომავალი?|წინა|ბოლო|გასული?)\\s+სეზონ(?:ი|ზე|ს)?\\b")],
             production: Box::new(|nodes| {
-                let n = match &nodes[0].token_data {
+                let q = match &nodes[0].token_data {
                     TokenData::RegexMatch(m) => m.group(1)?,
                     _ => return None,
                 };
-                let secs: i64 = n.parse().ok()?;
-                Some(TokenData::Time(TimeData::new(TimeForm::NthGrain {
-                    n: secs,
-                    grain: crate::dimensions::time_grain::Grain::Second,
-                    past: true,
-                    interval: true,
-                })))
+                let mut t = TimeData::new(TimeForm::Season(99));
+                if q.starts_with("შემდეგ") || q.starts_with("მომავალ") {
+                    t.direction = Some(Direction::Future);
+                } else if q == "წინა" || q == "ბოლო" || q.starts_with("გასულ") {
+                    t.direction = Some(Direction::Past);
+                }
+                Some(TokenData::Time(t))
             }),
         },
         Rule {
             name: "თვის ბოლო (ka)".to_string(),
-            pattern: vec![regex("(ამ\\s+)?თვის\\s+ბოლო(სთვის|სკენ)?")],
+            pattern: vec![regex("\\b(?:ამ\\s+)?თვის\\s+ბოლო(?:სთვის|სკენ|ს)?\\b")],
             production: Box::new(|_| {
                 Some(TokenData::Time(TimeData::new(TimeForm::BeginEnd {
                     begin: false,
-                    target: Box::new(TimeForm::GrainOffset {
-                        grain: crate::dimensions::time_grain::Grain::Month,
-                        offset: 0,
-                    }),
+                    target: Box::new(TimeForm::GrainOffset { grain: Grain::Month, offset: 0 }),
                 })))
             }),
         },
+        // "წლის დასაწყისი" is its first quarter
         Rule {
             name: "წლის დასაწყისი/შუა/ბოლო (ka)".to_string(),
-            pattern: vec![regex("წლის\\s+(დასაწყისი|შუა|ბოლო)")],
+            pattern: vec![regex("\\b(?:ამ\\s+)?წლის\\s+(დასაწყის|შუა|ბოლო)(?:ი|ში|ს)?\\b")],
             production: Box::new(|nodes| {
                 let pos = match &nodes[0].token_data {
                     TokenData::RegexMatch(rm) => rm.group(1)?,
                     _ => return None,
                 };
-                if pos == "შუა" {
-                    let mut t = TimeData::new(TimeForm::AllGrain(crate::dimensions::time_grain::Grain::Year));
-                    t.early_late = Some(super::EarlyLate::Mid);
-                    return Some(TokenData::Time(t));
-                }
-                Some(TokenData::Time(TimeData::new(TimeForm::BeginEnd {
-                    begin: pos == "დასაწყისი",
-                    target: Box::new(TimeForm::AllGrain(crate::dimensions::time_grain::Grain::Year)),
-                })))
+                let t = match pos {
+                    "შუა" => {
+                        let mut t = TimeData::new(TimeForm::AllGrain(Grain::Year));
+                        t.early_late = Some(EarlyLate::Mid);
+                        t
+                    }
+                    "დასაწყის" => TimeData::new(TimeForm::Interval(
+                        Box::new(month_of_year(1, 0)),
+                        Box::new(month_of_year(4, 0)),
+                        true,
+                    )),
+                    _ => TimeData::new(TimeForm::BeginEnd {
+                        begin: false,
+                        target: Box::new(TimeForm::AllGrain(Grain::Year)),
+                    }),
+                };
+                Some(TokenData::Time(t))
             }),
         },
         Rule {
-            name: "იანვრის დასაწყისი/შუა/ბოლო (ka)".to_string(),
-            pattern: vec![regex("(იანვრის|თებერვლის|მარტის|აპრილის|მაისის|ივნისის|ივლისის|აგვისტოს|სექტემბრის|ოქტომბრის|ნოემბრის|დეკემბრის)\\s+(დასაწყისი|შუა|ბოლო)")],
+            name: "<month> დასაწყისი/შუა/ბოლო (ka)".to_string(),
+            pattern: vec![regex(&format!("\\b({MONTHS})\\s+(დასაწყის|შუა|ბოლო)(?:ი|ში|ს)?\\b"))],
             production: Box::new(|nodes| {
                 let (m_s, pos) = match &nodes[0].token_data {
                     TokenData::RegexMatch(rm) => (rm.group(1)?, rm.group(2)?),
@@ -686,11 +867,11 @@ pub fn rules() -> Vec<Rule> {
                 let month = ka_month_from_text(m_s)?;
                 if pos == "შუა" {
                     let mut t = TimeData::new(TimeForm::Month(month));
-                    t.early_late = Some(super::EarlyLate::Mid);
+                    t.early_late = Some(EarlyLate::Mid);
                     return Some(TokenData::Time(t));
                 }
                 Some(TokenData::Time(TimeData::new(TimeForm::BeginEnd {
-                    begin: pos == "დასაწყისი",
+                    begin: pos == "დასაწყის",
                     target: Box::new(TimeForm::Month(month)),
                 })))
             }),
@@ -0,0 +1,121 @@
+use super::th::{
+    cycle_rule, day_month_rules, dow_next_last_rule, era_year_rules, form_rule, named_rule,
+    relative_grain_rules,
+};
+use super::TimeForm;
+use crate::dimensions::time_grain::Grain;
+use crate::types::Rule;
+
+const DAYS: &str = "ថ្ងៃ(?:ច័ន្ទ|អង្គារ|ពុធ|ព្រហស្បតិ៍|សុក្រ|សៅរ៍|អាទិត្យ)";
+
+const MONTHS: &str = "មករា|កុម្ភៈ|មីនា|មេសា|ឧសភា|មិថុនា|កក្កដា|សីហា|កញ្ញា|តុលា|វិច្ឆិកា|ធ្នូ";
+
+const GRAINS: &str = "វិនាទី|នាទី|ម៉ោង|ថ្ងៃ|សប្តាហ៍|សប្ដាហ៍|អាទិត្យ|ខែ|ឆ្នាំ";
+
+fn km_dow(s: &str) -> Option<u32> {
+    Some(match s.trim_start_matches("ថ្ងៃ") {
+        "ច័ន្ទ" => 0,
+        "អង្គារ" => 1,
+        "ពុធ" => 2,
+        "ព្រហស្បតិ៍" => 3,
+        "សុក្រ" => 4,
+        "សៅរ៍" => 5,
+        "អាទិត្យ" => 6,
+        _ => return None,
+    })
+}
+
+fn km_month(s: &str) -> Option<u32> {
+    Some(match s {
+        "មករា" => 1,
+        "កុម្ភៈ" => 2,
+        "មីនា" => 3,
+        "មេសា" => 4,
+        "ឧសភា" => 5,
+        "មិថុនា" => 6,
+        "កក្កដា" => 7,
+        "សីហា" => 8,
+        "កញ្ញា" => 9,
+        "តុលា" => 10,
+        "វិច្ឆិកា" => 11,
+        "ធ្នូ" => 12,
+        _ => return None,
+    })
+}
+
+fn km_grain(s: &str) -> Option<Grain> {
+    Some(match s {
+        "វិនាទី" => Grain::Second,
+        "នាទី" => Grain::Minute,
+        "ម៉ោង" => Grain::Hour,
+        "ថ្ងៃ" => Grain::Day,
+        "សប្តាហ៍" | "សប្ដាហ៍" | "អាទិត្យ" => Grain::Week,
+        "ខែ" => Grain::Month,
+        "ឆ្នាំ" => Grain::Year,
+        _ => return None,
+    })
+}
+
+/// 0 after "នេះ" (this), 1 after "ក្រោយ" or "បន្ទាប់" (next), -1 after
+/// "មុន" (last).
+fn km_offset(s: &str) -> i32 {
+    match s {
+        "នេះ" => 0,
+        "ក្រោយ" | "បន្ទាប់" => 1,
+        _ => -1,
+    }
+}
+
+pub fn rules() -> Vec<Rule> {
+    // Numeric dates are read day first: "3/4" is 3 April
+    let mut rules: Vec<Rule> = super::en::rules()
+        .into_iter()
+        .filter(|r| r.name != "date MM/DD(/YYYY)")
+        .collect();
+    rules.extend(super::en::day_first_date_rules());
+    rules.extend(vec![
+        form_rule("now (km)", "ឥឡូវ(?:នេះ)?", TimeForm::Now),
+        form_rule("today (km)", "ថ្ងៃនេះ", TimeForm::Today),
+        form_rule("tomorrow (km)", "(?:ថ្ងៃ)?ស្អែក", TimeForm::Tomorrow),
+        form_rule("yesterday (km)", "ម្សិលមិញ", TimeForm::Yesterday),
+        form_rule(
+            "day after tomorrow (km)",
+            "ខានស្អែក",
+            TimeForm::DayAfterTomorrow,
+        ),
+        form_rule(
+            "day before yesterday (km)",
+            "ម្សិលម្ង៉ៃ",
+            TimeForm::DayBeforeYesterday,
+        ),
+        named_rule("day of week (km)", &format!("({DAYS})"), |s| {
+            km_dow(s).map(TimeForm::DayOfWeek)
+        }),
+        named_rule(
+            "named month (km)",
+            &format!("(?:ខែ\\s*)?({MONTHS})"),
+            |s| km_month(s).map(TimeForm::Month),
+        ),
+        dow_next_last_rule("km", DAYS, km_dow, "ក្រោយ|បន្ទាប់|មុន", km_offset),
+        cycle_rule(
+            "km",
+            "សប្តាហ៍|សប្ដាហ៍|អាទិត្យ|ខែ|ឆ្នាំ",
+            km_grain,
+            "នេះ|ក្រោយ|បន្ទាប់|មុន",
+            km_offset,
+        ),
+    ]);
+    rules.extend(relative_grain_rules(
+        "km",
+        GRAINS,
+        km_grain,
+        "ទៀត|ក្រោយ",
+        "មុន",
+        "ក្នុង(?:រយៈពេល)?",
+    ));
+    rules.extend(day_month_rules("km", "ថ្ងៃទី"));
+    // Cambodia counts the Buddhist era from a year later than Thailand and
+    // Laos, 544 years past the common era
+    rules.extend(era_year_rules("km", "ឆ្នាំ", "ព\\.ស\\.?", "គ\\.ស\\.?", 544));
+    rules
+}
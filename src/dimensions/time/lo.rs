@@ -0,0 +1,114 @@
+use super::th::{
+    cycle_rule, day_month_rules, dow_next_last_rule, era_year_rules, form_rule, named_rule,
+    relative_grain_rules,
+};
+use super::TimeForm;
+use crate::dimensions::time_grain::Grain;
+use crate::types::Rule;
+
+const DAYS: &str = "ວັນ(?:ຈັນ|ອັງຄານ|ພຸດ|ພະຫັດ|ສຸກ|ເສົາ|ອາທິດ)";
+
+const MONTHS: &str = "ມັງກອນ|ກຸມພາ|ມີນາ|ເມສາ|ພຶດສະພາ|ມິຖຸນາ|ກໍລະກົດ|ສິງຫາ|ກັນຍາ|ຕຸລາ|ພະຈິກ|ທັນວາ";
+
+const GRAINS: &str = "ວິນາທີ|ນາທີ|ຊົ່ວໂມງ|ມື້|ວັນ|ອາທິດ|ເດືອນ|ປີ";
+
+fn lo_dow(s: &str) -> Option<u32> {
+    Some(match s.trim_start_matches("ວັນ") {
+        "ຈັນ" => 0,
+        "ອັງຄານ" => 1,
+        "ພຸດ" => 2,
+        "ພະຫັດ" => 3,
+        "ສຸກ" => 4,
+        "ເສົາ" => 5,
+        "ອາທິດ" => 6,
+        _ => return None,
+    })
+}
+
+fn lo_month(s: &str) -> Option<u32> {
+    Some(match s {
+        "ມັງກອນ" => 1,
+        "ກຸມພາ" => 2,
+        "ມີນາ" => 3,
+        "ເມສາ" => 4,
+        "ພຶດສະພາ" => 5,
+        "ມິຖຸນາ" => 6,
+        "ກໍລະກົດ" => 7,
+        "ສິງຫາ" => 8,
+        "ກັນຍາ" => 9,
+        "ຕຸລາ" => 10,
+        "ພະຈິກ" => 11,
+        "ທັນວາ" => 12,
+        _ => return None,
+    })
+}
+
+fn lo_grain(s: &str) -> Option<Grain> {
+    Some(match s {
+        "ວິນາທີ" => Grain::Second,
+        "ນາທີ" => Grain::Minute,
+        "ຊົ່ວໂມງ" => Grain::Hour,
+        "ມື້" | "ວັນ" => Grain::Day,
+        "ອາທິດ" => Grain::Week,
+        "ເດືອນ" => Grain::Month,
+        "ປີ" => Grain::Year,
+        _ => return None,
+    })
+}
+
+/// 0 after "ນີ້" (this), 1 after "ໜ້າ" (next), -1 after "ທີ່ແລ້ວ",
+/// "ແລ້ວ" or "ກ່ອນ" (last).
+fn lo_offset(s: &str) -> i32 {
+    match s {
+        "ນີ້" => 0,
+        "ໜ້າ" | "ຫນ້າ" => 1,
+        _ => -1,
+    }
+}
+
+pub fn rules() -> Vec<Rule> {
+    // Numeric dates are read day first: "3/4" is 3 April
+    let mut rules: Vec<Rule> = super::en::rules()
+        .into_iter()
+        .filter(|r| r.name != "date MM/DD(/YYYY)")
+        .collect();
+    rules.extend(super::en::day_first_date_rules());
+    rules.extend(vec![
+        form_rule("now (lo)", "ດຽວນີ້|ຕອນນີ້", TimeForm::Now),
+        form_rule("today (lo)", "ມື້ນີ້", TimeForm::Today),
+        form_rule("tomorrow (lo)", "ມື້ອື່ນ", TimeForm::Tomorrow),
+        form_rule("yesterday (lo)", "ມື້ວານ(?:ນີ້)?", TimeForm::Yesterday),
+        form_rule(
+            "day after tomorrow (lo)",
+            "ມື້ຮື(?:ນີ້)?",
+            TimeForm::DayAfterTomorrow,
+        ),
+        named_rule("day of week (lo)", &format!("({DAYS})"), |s| {
+            lo_dow(s).map(TimeForm::DayOfWeek)
+        }),
+        named_rule(
+            "named month (lo)",
+            &format!("(?:ເດືອນ\\s*)?({MONTHS})"),
+            |s| lo_month(s).map(TimeForm::Month),
+        ),
+        dow_next_last_rule("lo", DAYS, lo_dow, "ໜ້າ|ຫນ້າ|ທີ່ແລ້ວ|ແລ້ວ", lo_offset),
+        cycle_rule(
+            "lo",
+            "ອາທິດ|ເດືອນ|ປີ",
+            lo_grain,
+            "ນີ້|ໜ້າ|ຫນ້າ|ທີ່ແລ້ວ|ແລ້ວ|ກ່ອນ",
+            lo_offset,
+        ),
+    ]);
+    rules.extend(relative_grain_rules(
+        "lo",
+        GRAINS,
+        lo_grain,
+        "ຂ້າງໜ້າ",
+        "ທີ່ແລ້ວ|ກ່ອນ",
+        "ອີກ|ໃນ",
+    ));
+    rules.extend(day_month_rules("lo", "ວັນທີ"));
+    rules.extend(era_year_rules("lo", "ປີ", "ພ\\.ສ\\.?", "ຄ\\.ສ\\.?", 543));
+    rules
+}
@@ -6,58 +6,82 @@ pub mod de;
 pub mod el;
 pub mod en;
 pub mod es;
+pub mod fa;
 pub mod fr;
 pub mod ga;
 pub mod he;
+pub mod holidays;
 pub mod hr;
 pub mod hu;
+pub mod id;
 pub mod it;
 pub mod ja;
 pub mod ka;
+pub mod km;
 pub mod ko;
+pub mod lo;
 pub mod nb;
 pub mod nl;
 pub mod pl;
 pub mod pt;
 pub mod ro;
 pub mod ru;
+pub(crate) mod scandinavian;
 pub(crate) mod series;
 pub mod sv;
+pub mod th;
 pub mod tr;
 pub mod uk;
 pub mod vi;
 pub mod zh;
 
+use crate::dimensions::duration::DurationQualifier;
+use crate::dimensions::numeral::helpers::integer_value;
 use crate::dimensions::time_grain::Grain;
-use crate::resolve::Context;
-use crate::types::{DimensionValue, IntervalEndpoints, TimePoint, TimeValue};
-use chrono::{DateTime, Datelike, Duration, NaiveDate, Timelike, Utc};
+use crate::locale::{Hemisphere, NextWeekdayPolicy};
+use crate::resolve::{
+    holiday_key, Context, HolidayCalendar, HolidayResolver, Options, PartOfDayConfig,
+    TimePreference, WeekNumbering,
+};
+use crate::types::{
+    DiagnosticReason, DimensionValue, IntervalEndpoints, TimePoint, TimeValue, TokenData,
+};
+use chrono::{DateTime, Datelike, Days, Duration, NaiveDate, NaiveDateTime, Timelike, Utc};
+use std::borrow::Cow;
 #[cfg(not(debug_assertions))]
 use std::panic::{catch_unwind, AssertUnwindSafe};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum PartOfDay {
     Morning,
     Afternoon,
     Evening,
     Night,
     Lunch,
+    Breakfast,
+    Dinner,
+    /// 22:00 to 06:00 the next day.
+    Overnight,
+    /// 11:00 to 13:00.
+    Noonish,
+    /// 23:00 to 01:00 the next day.
+    Midnightish,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum IntervalDirection {
     After,
     Before,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum EarlyLate {
     Early,
     Late,
     Mid,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct TimeData {
     pub form: TimeForm,
     pub direction: Option<Direction>,
@@ -68,6 +92,10 @@ pub struct TimeData {
     /// We store the actual timezone name (e.g. "CET", "EST") instead of just a flag,
     /// preserving more information for downstream consumers.
     pub timezone: Option<String>,
+    /// Set on ISO-8601 literals ("2024-05-03T14:30Z"): `timezone` is the
+    /// literal's own UTC offset and names an absolute moment, so it is not
+    /// shifted into the context's wall clock.
+    pub absolute_offset: bool,
     /// Haskell's notImmediate: if true and the first future value coincides with
     /// the reference time, skip to the next occurrence.
     /// Used by "this Monday" (predNth 0 True) when today is Monday.
@@ -79,9 +107,14 @@ pub struct TimeData {
     /// Matches Haskell's `holiday :: Maybe Text`.
     /// Set from `TimeForm::Holiday(name, _)` and propagated through composition.
     pub holiday: Option<String>,
+    /// Set when lowering `TimeForm::Observed` moved the holiday off a weekend.
+    pub observed: bool,
+    /// The duration prefix this time came from ("in 2 hours", "within a
+    /// day"), carried into the resolved `TimeValue`.
+    pub duration_qualifier: Option<DurationQualifier>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum TimeForm {
     DayOfWeek(u32),             // 0=Monday .. 6=Sunday
     Month(u32),                 // 1..12
@@ -105,7 +138,22 @@ pub enum TimeForm {
     },
     PartOfDay(PartOfDay),
     Weekend,
-    Season(u32),                  // 0=spring, 1=summer, 2=fall, 3=winter
+    Season(u32), // 0=spring, 1=summer, 2=fall, 3=winter
+    // "the 1980s" (1980); "the 90s" (90) takes its century from the reference year
+    Decade(i32),
+    // "the 21st century" (21), the years 2000-2099
+    Century(i32),
+    // "1714744200", "1714744200000" — Unix epoch milliseconds; resolves only
+    // with `Options::parse_epoch_timestamps`, and only from 2000 to a few
+    // years past the reference time
+    Timestamp(i64),
+    // "13 เมษายน 2568": a Thai, Lao or Khmer year given without its era;
+    // `offset` years past the common era when read as a Buddhist-era year
+    // with `Options::buddhist_era_years`
+    BuddhistEraYear {
+        year: i32,
+        offset: i32,
+    },
     Holiday(String, Option<i32>), // name, optional year
     GrainOffset {
         grain: Grain,
@@ -117,6 +165,14 @@ pub enum TimeForm {
         past: bool,
         interval: bool,
     },
+    // "in the next 2 weeks", "within the last 30 days" — the `n` grains
+    // running from, or up to, the reference time; `n` is `None` for "few",
+    // counted by `Options::few_count`
+    Window {
+        n: Option<i64>,
+        grain: Grain,
+        past: bool,
+    },
     Quarter(u32),
     QuarterYear(u32, i32),
     DayAfterTomorrow,
@@ -181,9 +237,35 @@ pub enum TimeForm {
         grain: Grain,
         base: Box<TimeData>,
     },
+    // "week 23", "the 12th week of 2025"; `numbering` is `None` unless the
+    // text names it ("ISO week 7"), and counted by `Options::week_numbering`
+    WeekOfYear {
+        week: u32,
+        year: Option<i32>,
+        numbering: Option<WeekNumbering>,
+    },
+    // Haskell: cycleNthAfter False — "the day after Easter", "Christmas eve";
+    // the grain cycle holding base, shifted by n cycles
+    CycleNthAfter {
+        n: i32,
+        grain: Grain,
+        base: Box<TimeData>,
+    },
+    // "COB", "EOD Friday" — end of the business day on the given day; the hour
+    // comes from `Options::business_day_end_hour` at resolution time
+    EndOfBusiness(Box<TimeData>),
+    // "in 3 business days", "5 working days from Friday" — skips weekends and
+    // `Context::holiday_calendar`; base defaults to the reference day
+    BusinessDays {
+        n: i64,
+        base: Option<Box<TimeData>>,
+    },
+    // "Christmas (observed)" — the holiday moved off a weekend per the
+    // region's `ObservedShift`, or `Options::observed_shift`
+    Observed(Box<TimeData>),
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub enum Direction {
     Past,
     Future,
@@ -195,6 +277,7 @@ pub enum Direction {
 fn extract_holiday(form: &TimeForm) -> Option<String> {
     match form {
         TimeForm::Holiday(name, _) => Some(name.clone()),
+        TimeForm::Observed(inner) => inner.holiday.clone(),
         TimeForm::Composed(a, b) => a.holiday.clone().or_else(|| b.holiday.clone()),
         TimeForm::Interval(a, b, _) => a.holiday.clone().or_else(|| b.holiday.clone()),
         _ => None,
@@ -220,9 +303,12 @@ impl TimeData {
             open_interval_direction: None,
             early_late: None,
             timezone: None,
+            absolute_offset: false,
             not_immediate: false,
             ok_for_this_next,
             holiday,
+            observed: false,
+            duration_qualifier: None,
         }
     }
 
@@ -244,9 +330,12 @@ impl TimeData {
             open_interval_direction: None,
             early_late: None,
             timezone: None,
+            absolute_offset: false,
             not_immediate: false,
             ok_for_this_next,
             holiday,
+            observed: false,
+            duration_qualifier: None,
         }
     }
 
@@ -259,6 +348,54 @@ impl TimeData {
     }
 }
 
+// ============================================================
+// Clock times relative to a whole hour
+// ============================================================
+
+/// The clock time `minutes` after a whole hour, or before it when negative:
+/// "quarter past five" is 5:15 and "ten to six" 5:50. Going back from
+/// midnight wraps to the evening before.
+pub(super) fn hour_minute_offset(hour: u32, is_12h: bool, minutes: i32) -> Option<TimeForm> {
+    if hour > 24 || minutes.unsigned_abs() >= 60 {
+        return None;
+    }
+    let hour = hour % 24;
+    let form = if minutes >= 0 {
+        TimeForm::HourMinute(hour, minutes.unsigned_abs(), is_12h)
+    } else {
+        let before = if hour == 0 { 23 } else { hour.checked_sub(1)? };
+        TimeForm::HourMinute(before, 60_u32.checked_sub(minutes.unsigned_abs())?, is_12h)
+    };
+    Some(form)
+}
+
+/// Whether a token can anchor [`clock_hour_offset`]: a whole-hour time
+/// ("five", "5pm", "noon") or an integer from 0 to 24.
+pub(super) fn is_clock_hour(td: &TokenData) -> bool {
+    match td {
+        TokenData::Time(d) => matches!(d.form, TimeForm::Hour(..) | TimeForm::HourMinute(_, 0, _)),
+        _ => integer_value(td).is_some_and(|v| (0..=24).contains(&v)),
+    }
+}
+
+/// [`hour_minute_offset`] from a token accepted by [`is_clock_hour`]. Bare
+/// integers up to 12 may be in the afternoon, as with "at 5".
+pub(super) fn clock_hour_offset(td: &TokenData, minutes: i32) -> Option<TokenData> {
+    let (hour, is_12h) = match td {
+        TokenData::Time(d) => match d.form {
+            TimeForm::Hour(h, is_12h) | TimeForm::HourMinute(h, 0, is_12h) => (h, is_12h),
+            _ => return None,
+        },
+        _ => {
+            let h = u32::try_from(integer_value(td)?).ok()?;
+            (h, (1..=12).contains(&h))
+        }
+    };
+    Some(TokenData::Time(TimeData::new(hour_minute_offset(
+        hour, is_12h, minutes,
+    )?)))
+}
+
 // ============================================================
 // Instant vs Naive classification
 // ============================================================
@@ -281,15 +418,23 @@ fn is_instant_form(form: &TimeForm) -> bool {
 
 /// Generate the values array (up to 3 TimePoints) using the series generator.
 /// Matches Haskell's `values <- Just $ take 3 $ if null future then past else future`.
+/// With `past_first`, for values resolved into the past, only the past series
+/// is used, most recent first.
 fn generate_extra_values(
     data: &TimeData,
     ref_time: DateTime<Utc>,
+    pods: &PartOfDayConfig,
     primary: &TimePoint,
+    past_first: bool,
 ) -> Vec<TimePoint> {
     let is_instant = is_instant_form(&data.form);
 
-    let (past, future) = series::generate_series(data, ref_time);
-    let source = if future.is_empty() { &past } else { &future };
+    let (past, future) = series::generate_series(data, ref_time, pods);
+    let source = if past_first || future.is_empty() {
+        &past
+    } else {
+        &future
+    };
 
     let to_time_point = |obj: &series::TimeObject| -> TimePoint {
         match obj.end {
@@ -342,8 +487,9 @@ fn generate_interval_values(
     primary_from: &Option<TimePoint>,
     primary_to: &Option<TimePoint>,
     is_instant: bool,
+    pods: &PartOfDayConfig,
 ) -> Vec<IntervalEndpoints> {
-    let (past, future) = series::generate_series(data, ref_time);
+    let (past, future) = series::generate_series(data, ref_time, pods);
     let source = if future.is_empty() { &past } else { &future };
 
     let to_endpoints = |obj: &series::TimeObject| -> IntervalEndpoints {
@@ -393,10 +539,62 @@ fn generate_interval_values(
 // Main resolve entry point
 // ============================================================
 
-pub fn resolve(data: &TimeData, context: &Context, with_latent: bool) -> Option<DimensionValue> {
-    if data.latent && !with_latent {
+pub fn resolve(data: &TimeData, context: &Context, options: &Options) -> Option<DimensionValue> {
+    if data.latent && !options.with_latent {
         return None;
     }
+    let Some(past) = prefer_past(data).filter(|_| options.prefer != TimePreference::Future) else {
+        return resolve_directed(data, context, options, false);
+    };
+    if options.prefer == TimePreference::Past {
+        return resolve_directed(&past, context, options, true);
+    }
+    let future = resolve_directed(data, context, options, false);
+    let past = resolve_directed(&past, context, options, true);
+    let distance = |value: &Option<DimensionValue>| {
+        let point = value.as_ref()?.as_time()?;
+        let point = point.start().or_else(|| point.end())?;
+        let at = point.to_utc(context.timezone_offset_minutes);
+        Some(at.signed_duration_since(context.reference_time).abs())
+    };
+    match (distance(&future), distance(&past)) {
+        (Some(f), Some(p)) if p < f => past,
+        (None, Some(_)) => past,
+        _ => future,
+    }
+}
+
+/// `data` with [`Direction::Past`] on itself and its composed parts where
+/// they carry no direction of their own, for [`TimePreference::Past`].
+/// "this <time>" keeps its meaning. Returns `None` when nothing changes.
+fn prefer_past(data: &TimeData) -> Option<TimeData> {
+    fn mark(td: &mut TimeData) -> bool {
+        let mut changed = false;
+        if td.direction.is_none() && !td.not_immediate {
+            td.direction = Some(Direction::Past);
+            changed = true;
+        }
+        if let TimeForm::Composed(a, b) = &mut td.form {
+            let in_a = mark(a);
+            let in_b = mark(b);
+            changed = changed || in_a || in_b;
+        }
+        changed
+    }
+    let mut past = data.clone();
+    mark(&mut past).then_some(past)
+}
+
+/// Resolves `data` as given; `past_first` lists its `values` from the most
+/// recent past occurrence back, as for [`TimePreference::Past`].
+fn resolve_directed(
+    data: &TimeData,
+    context: &Context,
+    options: &Options,
+    past_first: bool,
+) -> Option<DimensionValue> {
+    let lowered = lower_contextual_forms(data, context, options)?;
+    let data = lowered.as_ref();
     let ref_time = context.reference_time;
     if has_unrepresentable_relative(data, ref_time) {
         return None;
@@ -415,11 +613,19 @@ pub fn resolve(data: &TimeData, context: &Context, with_latent: bool) -> Option<
         }
     };
 
+    let candidates = |point: &TimePoint| -> Vec<TimePoint> {
+        if options.with_meridiem_candidates && is_meridiem_ambiguous(&data.form) {
+            meridiem_pair(point, tz_shift)
+        } else {
+            Vec::new()
+        }
+    };
+
     // 1. Open intervals (ASAP, after/before/since/until + time)
     if let Some(dir) = data.open_interval_direction {
         let (dt, grain_str) = safe_resolve_simple_datetime(&data.form, ref_time, data.direction)?;
         let grain = if has_tz {
-            Grain::Minute
+            Grain::from_str(grain_str).min(Grain::Minute)
         } else {
             Grain::from_str(grain_str)
         };
@@ -442,6 +648,7 @@ pub fn resolve(data: &TimeData, context: &Context, with_latent: bool) -> Option<
                     to: None,
                     values: vec![IntervalEndpoints { from, to: None }],
                     holiday: data.holiday.clone(),
+                    qualifier: data.duration_qualifier,
                 }))
             }
             IntervalDirection::Before => {
@@ -451,13 +658,16 @@ pub fn resolve(data: &TimeData, context: &Context, with_latent: bool) -> Option<
                     to: to.clone(),
                     values: vec![IntervalEndpoints { from: None, to }],
                     holiday: data.holiday.clone(),
+                    qualifier: data.duration_qualifier,
                 }))
             }
         };
     }
 
     // 2. Try to resolve as interval (pass context for per-endpoint timezone)
-    if let Some(mut tv) = safe_try_resolve_as_interval(data, ref_time, context) {
+    if let Some(mut tv) =
+        safe_try_resolve_as_interval(data, ref_time, context, &options.part_of_day)
+    {
         // Propagate holiday from TimeData into the resolved TimeValue
         if data.holiday.is_some() {
             match &mut tv {
@@ -468,13 +678,32 @@ pub fn resolve(data: &TimeData, context: &Context, with_latent: bool) -> Option<
                 }
             }
         }
+        if let TimeValue::Single {
+            value,
+            observed,
+            meridiem_candidates,
+            ..
+        } = &mut tv
+        {
+            *observed |= data.observed;
+            *meridiem_candidates = candidates(value);
+        }
+        match &mut tv {
+            TimeValue::Single { qualifier, .. } | TimeValue::Interval { qualifier, .. } => {
+                *qualifier = qualifier.or(data.duration_qualifier);
+            }
+        }
         return Some(DimensionValue::Time(tv));
     }
 
-    // 3. Simple value
+    // 3. Simple value; an interval composed with a date that did not resolve
+    // as an interval above has no single-time reading
+    if composes_interval(&data.form) {
+        return None;
+    }
     let (dt, grain_str) = safe_resolve_simple_datetime(&data.form, ref_time, data.direction)?;
     let grain = if has_tz {
-        Grain::Minute
+        Grain::from_str(grain_str).min(Grain::Minute)
     } else {
         Grain::from_str(grain_str)
     };
@@ -491,28 +720,426 @@ pub fn resolve(data: &TimeData, context: &Context, with_latent: bool) -> Option<
         }
     };
     // Generate additional values from the series
-    let extra_values = generate_extra_values(data, context.reference_time, &point);
+    let extra_values = generate_extra_values(
+        data,
+        context.reference_time,
+        &options.part_of_day,
+        &point,
+        past_first,
+    );
     Some(DimensionValue::Time(TimeValue::Single {
-        value: point.clone(),
+        meridiem_candidates: candidates(&point),
+        value: point,
         values: extra_values,
         holiday: data.holiday.clone(),
+        observed: data.observed,
+        qualifier: data.duration_qualifier,
+    }))
+}
+
+/// Whether `form` is a clock time given without AM/PM ("at 7", "tomorrow at
+/// 7:30") that no part of the day ("at 7 in the evening") pins down.
+fn is_meridiem_ambiguous(form: &TimeForm) -> bool {
+    fn has_part_of_day(td: &TimeData) -> bool {
+        match &td.form {
+            TimeForm::PartOfDay(_) => true,
+            TimeForm::Composed(a, b) => has_part_of_day(a) || has_part_of_day(b),
+            _ => false,
+        }
+    }
+    match form {
+        TimeForm::Hour(h, true) | TimeForm::HourMinute(h, _, true) => (1..=12).contains(h),
+        TimeForm::Composed(a, b) => {
+            (is_meridiem_ambiguous(&a.form) && !has_part_of_day(b))
+                || (is_meridiem_ambiguous(&b.form) && !has_part_of_day(a))
+        }
+        _ => false,
+    }
+}
+
+/// The AM and PM readings of `point` on its day, morning first. `tz_shift`
+/// is the shift applied to an instant from a named timezone, undone to find
+/// the wall-clock hour the text gave.
+fn meridiem_pair(point: &TimePoint, tz_shift: Option<Duration>) -> Vec<TimePoint> {
+    let half_day = Duration::try_hours(12).unwrap_or_default();
+    let local_hour = match point {
+        TimePoint::Naive { value, .. } => value.hour(),
+        TimePoint::Instant { value, .. } => tz_shift
+            .and_then(|shift| value.checked_sub_signed(shift))
+            .unwrap_or(*value)
+            .hour(),
+    };
+    let moved = |later: bool| -> Option<TimePoint> {
+        let shift = |value: NaiveDateTime| {
+            if later {
+                value.checked_add_signed(half_day)
+            } else {
+                value.checked_sub_signed(half_day)
+            }
+        };
+        Some(match point {
+            TimePoint::Naive { value, grain } => TimePoint::Naive {
+                value: shift(*value)?,
+                grain: *grain,
+            },
+            TimePoint::Instant { value, grain } => TimePoint::Instant {
+                value: shift(value.naive_utc())?.and_utc(),
+                grain: *grain,
+            },
+        })
+    };
+    let pair = if local_hour < 12 {
+        (Some(point.clone()), moved(true))
+    } else {
+        (moved(false), Some(point.clone()))
+    };
+    match pair {
+        (Some(am), Some(pm)) => vec![am, pm],
+        _ => Vec::new(),
+    }
+}
+
+/// Whether epoch `millis` falls between 2000 and a few years past the
+/// reference time. Outside that window a digit run is far more likely an id
+/// or a phone number ("5551234567") than a timestamp.
+fn is_plausible_timestamp(millis: i64, reference_time: DateTime<Utc>) -> bool {
+    const Y2K_MILLIS: i64 = 946_684_800_000;
+    let latest = reference_time
+        .checked_add_signed(Duration::days(5 * 366))
+        .map_or(i64::MAX, |dt| dt.timestamp_millis());
+    (Y2K_MILLIS..=latest).contains(&millis)
+}
+
+/// Rewrite forms that depend on `Context` or `Options` (business deadlines,
+/// business-day offsets, part-of-day hours, southern seasons, "next
+/// <weekday>", Buddhist-era years) into plain
+/// forms the resolvers below understand.
+/// Returns `None` when such a form cannot be resolved.
+fn lower_contextual_forms<'a>(
+    data: &'a TimeData,
+    context: &Context,
+    options: &Options,
+) -> Option<Cow<'a, TimeData>> {
+    let form = match &data.form {
+        TimeForm::EndOfBusiness(day) => end_of_business_form(day, options.business_day_end_hour),
+        TimeForm::Timestamp(millis) => {
+            if !options.parse_epoch_timestamps
+                || !is_plausible_timestamp(*millis, context.reference_time)
+            {
+                return None;
+            }
+            return Some(Cow::Borrowed(data));
+        }
+        TimeForm::Window {
+            n: None,
+            grain,
+            past,
+        } => TimeForm::Window {
+            n: Some(i64::from(options.few_count)),
+            grain: *grain,
+            past: *past,
+        },
+        TimeForm::WeekOfYear {
+            week,
+            year,
+            numbering: None,
+        } => TimeForm::WeekOfYear {
+            week: *week,
+            year: *year,
+            numbering: Some(options.week_numbering),
+        },
+        TimeForm::BuddhistEraYear { year, offset } => {
+            if options.buddhist_era_years {
+                TimeForm::Year(year.checked_sub(*offset)?)
+            } else {
+                TimeForm::Year(*year)
+            }
+        }
+        TimeForm::BusinessDays { n, base } => {
+            let start = match base {
+                Some(base) => {
+                    let (dt, _) = safe_resolve_simple_datetime(
+                        &base.form,
+                        context.reference_time,
+                        base.direction,
+                    )?;
+                    dt.date_naive()
+                }
+                None => context.reference_time.date_naive(),
+            };
+            let date = add_business_days(start, *n, &context.holiday_calendar)?;
+            TimeForm::DateMDY {
+                month: date.month(),
+                day: date.day(),
+                year: Some(date.year()),
+            }
+        }
+        TimeForm::Observed(inner) => {
+            let TimeForm::Holiday(name, year) = &inner.form else {
+                return Some(Cow::Owned(inner.as_ref().clone()));
+            };
+            let date = match custom_holiday_date(name, *year, inner.direction, context) {
+                Some(date) => date,
+                None => match year {
+                    Some(year) => resolve_holiday(name, *year)?,
+                    None => {
+                        let (dt, _) = resolve_holiday_with_direction(
+                            name,
+                            context.reference_time,
+                            inner.direction,
+                        );
+                        dt.date_naive()
+                    }
+                },
+            };
+            let shift = options
+                .observed_shift
+                .unwrap_or_else(|| context.locale.observed_shift());
+            let observed = shift.apply(date);
+            return Some(Cow::Owned(TimeData {
+                form: TimeForm::DateMDY {
+                    month: observed.month(),
+                    day: observed.day(),
+                    year: Some(observed.year()),
+                },
+                direction: None,
+                observed: observed != date,
+                ..data.clone()
+            }));
+        }
+        TimeForm::Holiday(name, year) => {
+            let Some(date) = custom_holiday_date(name, *year, data.direction, context) else {
+                return Some(Cow::Borrowed(data));
+            };
+            return Some(Cow::Owned(TimeData {
+                form: TimeForm::DateMDY {
+                    month: date.month(),
+                    day: date.day(),
+                    year: Some(date.year()),
+                },
+                direction: None,
+                ..data.clone()
+            }));
+        }
+        // Season numbers name northern seasons; "summer" down south is the
+        // northern winter, and so on
+        TimeForm::Season(season)
+            if *season < 4
+                && options
+                    .hemisphere
+                    .unwrap_or_else(|| context.locale.hemisphere())
+                    == Hemisphere::Southern =>
+        {
+            TimeForm::Season(season.saturating_add(2) % 4)
+        }
+        // "next Friday" where it means the coming Friday rather than
+        // Friday of next week
+        TimeForm::DayOfWeek(dow)
+            if matches!(data.direction, Some(Direction::Future))
+                && options
+                    .next_weekday_policy
+                    .unwrap_or_else(|| context.locale.next_weekday_policy())
+                    == NextWeekdayPolicy::Upcoming =>
+        {
+            let date = resolve_dow(*dow, context.reference_time, None).date_naive();
+            return Some(Cow::Owned(TimeData {
+                form: TimeForm::DateMDY {
+                    month: date.month(),
+                    day: date.day(),
+                    year: Some(date.year()),
+                },
+                direction: None,
+                ..data.clone()
+            }));
+        }
+        TimeForm::Composed(a, b) | TimeForm::Interval(a, b, _) => {
+            if let TimeForm::Composed(..) = &data.form {
+                let holiday = holiday_of_year(a, b, context);
+                if let Some(date) = holiday.or_else(|| holiday_of_year(b, a, context)) {
+                    return Some(Cow::Owned(TimeData {
+                        form: TimeForm::DateMDY {
+                            month: date.month(),
+                            day: date.day(),
+                            year: Some(date.year()),
+                        },
+                        direction: None,
+                        ..data.clone()
+                    }));
+                }
+            }
+            let mut la = lower_contextual_forms(a, context, options)?;
+            let mut lb = lower_contextual_forms(b, context, options)?;
+            if let TimeForm::Composed(..) = &data.form {
+                let pods = &options.part_of_day;
+                if let Some(clock) = clock_for_part_of_day(&la, &lb, pods) {
+                    la = Cow::Owned(clock);
+                } else if let Some(clock) = clock_for_part_of_day(&lb, &la, pods) {
+                    lb = Cow::Owned(clock);
+                }
+            }
+            if matches!((&la, &lb), (Cow::Borrowed(_), Cow::Borrowed(_))) {
+                return Some(Cow::Borrowed(data));
+            }
+            let (la, lb) = (Box::new(la.into_owned()), Box::new(lb.into_owned()));
+            match &data.form {
+                TimeForm::Interval(_, _, open) => TimeForm::Interval(la, lb, *open),
+                _ => TimeForm::Composed(la, lb),
+            }
+        }
+        _ => return Some(Cow::Borrowed(data)),
+    };
+    Some(Cow::Owned(TimeData {
+        form,
+        ..data.clone()
     }))
 }
 
+/// Date of holiday `name` from `Context::holiday_resolver` or the locale
+/// region's [`RegionalHolidays`](holidays::RegionalHolidays): in `year` when
+/// given, otherwise the next occurrence from the reference day (the previous
+/// one for past-directed phrases). `None` leaves the built-in dates in charge.
+fn custom_holiday_date(
+    name: &str,
+    year: Option<i32>,
+    direction: Option<Direction>,
+    context: &Context,
+) -> Option<NaiveDate> {
+    let key = holiday_key(name);
+    let region = context.locale.region;
+    let lookup = |y: i32| {
+        context
+            .holiday_resolver
+            .as_ref()
+            .and_then(|resolver| resolver.resolve(&key, y, region))
+            .or_else(|| holidays::RegionalHolidays.resolve(&key, y, region))
+    };
+    if let Some(year) = year {
+        return lookup(year);
+    }
+    let today = context.reference_time.date_naive();
+    let this_year = today.year();
+    match direction {
+        Some(Direction::Past) => lookup(this_year)
+            .filter(|date| *date < today)
+            .or_else(|| lookup(this_year.checked_sub(1)?)),
+        _ => lookup(this_year)
+            .filter(|date| *date >= today)
+            .or_else(|| lookup(this_year.checked_add(1)?)),
+    }
+}
+
+/// Custom or regional date of `holiday` in the year `year` names, for
+/// "labour day of last year" or "labour day of 2012".
+fn holiday_of_year(holiday: &TimeData, year: &TimeData, context: &Context) -> Option<NaiveDate> {
+    let TimeForm::Holiday(name, None) = &holiday.form else {
+        return None;
+    };
+    let year = match year.form {
+        TimeForm::Year(y) => y,
+        TimeForm::GrainOffset {
+            grain: Grain::Year,
+            offset,
+        } => context.reference_time.year().checked_add(offset)?,
+        _ => return None,
+    };
+    custom_holiday_date(name, Some(year), None, context)
+}
+
+/// Pin an AM/PM-ambiguous `clock` composed with a part of the day to whichever
+/// half falls inside that part's configured hours ("evening at 6" → 18:00).
+/// Returns `None` when `other` holds no part of the day or neither (or both)
+/// halves fit, leaving the built-in disambiguation in place.
+fn clock_for_part_of_day(
+    clock: &TimeData,
+    other: &TimeData,
+    pods: &PartOfDayConfig,
+) -> Option<TimeData> {
+    fn find_pod(td: &TimeData) -> Option<&TimeData> {
+        match &td.form {
+            TimeForm::PartOfDay(_) => Some(td),
+            TimeForm::Composed(a, b) => find_pod(a).or_else(|| find_pod(b)),
+            _ => None,
+        }
+    }
+    let pod_data = find_pod(other)?;
+    let TimeForm::PartOfDay(pod) = pod_data.form else {
+        return None;
+    };
+    let (start, end) = pod_hours(pod, pod_data.early_late, pods);
+    let fits = |h: u32| (start..end).contains(&h);
+    let pick = |h: u32| match (fits(h), fits(h.saturating_add(12))) {
+        (true, false) => Some(h),
+        (false, true) => Some(h.saturating_add(12)),
+        _ => None,
+    };
+    let form = match clock.form {
+        TimeForm::Hour(h, true) if (1..12).contains(&h) => TimeForm::Hour(pick(h)?, false),
+        TimeForm::HourMinute(h, m, true) if (1..12).contains(&h) => {
+            TimeForm::HourMinute(pick(h)?, m, false)
+        }
+        _ => return None,
+    };
+    Some(TimeData {
+        form,
+        ..clock.clone()
+    })
+}
+
+/// End of the business day on `day`: `<day> at <hour>` for a configured
+/// business day end, otherwise midnight after `<day>`.
+fn end_of_business_form(day: &TimeData, end_hour: Option<u32>) -> TimeForm {
+    match end_hour.filter(|h| *h < 24) {
+        Some(hour) => TimeForm::Composed(
+            Box::new(day.clone()),
+            Box::new(TimeData::new(TimeForm::Hour(hour, false))),
+        ),
+        None => TimeForm::DurationAfter {
+            n: 1,
+            grain: Grain::Day,
+            base: Box::new(day.clone()),
+        },
+    }
+}
+
+/// Upper bound on |n| for business-day offsets, to keep the day walk cheap.
+const MAX_BUSINESS_DAYS: u64 = 10_000;
+
+/// Move `n` business days away from `start` (backwards when negative),
+/// skipping weekends and the calendar's holidays.
+fn add_business_days(start: NaiveDate, n: i64, calendar: &HolidayCalendar) -> Option<NaiveDate> {
+    let mut remaining = n.unsigned_abs();
+    if remaining > MAX_BUSINESS_DAYS {
+        return None;
+    }
+    let mut date = start;
+    while remaining > 0 {
+        date = if n < 0 {
+            date.pred_opt()?
+        } else {
+            date.succ_opt()?
+        };
+        if calendar.is_business_day(date) {
+            remaining = remaining.saturating_sub(1);
+        }
+    }
+    Some(date)
+}
+
 fn safe_try_resolve_as_interval(
     data: &TimeData,
     ref_time: DateTime<Utc>,
     context: &Context,
+    pods: &PartOfDayConfig,
 ) -> Option<TimeValue> {
     #[cfg(debug_assertions)]
     {
-        try_resolve_as_interval(data, ref_time, context)
+        try_resolve_as_interval(data, ref_time, context, pods)
     }
 
     #[cfg(not(debug_assertions))]
     {
         catch_unwind(AssertUnwindSafe(|| {
-            try_resolve_as_interval(data, ref_time, context)
+            try_resolve_as_interval(data, ref_time, context, pods)
         }))
         .ok()
         .flatten()
@@ -563,6 +1190,17 @@ fn relative_can_be_represented(base: DateTime<Utc>, n: i64, grain: Grain) -> boo
     }
 }
 
+/// Whether an interval is nested inside a composition, e.g. the intersect of
+/// "Dec 31" with "10pm to Jan 1 2am".
+fn composes_interval(form: &TimeForm) -> bool {
+    match form {
+        TimeForm::Composed(a, b) => [a, b]
+            .iter()
+            .any(|td| matches!(td.form, TimeForm::Interval(..)) || composes_interval(&td.form)),
+        _ => false,
+    }
+}
+
 fn has_unrepresentable_relative(data: &TimeData, ref_time: DateTime<Utc>) -> bool {
     fn check_form(form: &TimeForm, ref_time: DateTime<Utc>) -> bool {
         match form {
@@ -577,13 +1215,19 @@ fn has_unrepresentable_relative(data: &TimeData, ref_time: DateTime<Utc>) -> boo
                 check_form(&a.form, ref_time) || check_form(&b.form, ref_time)
             }
             TimeForm::BeginEnd { target, .. } => check_form(target, ref_time),
+            TimeForm::EndOfBusiness(day)
+            | TimeForm::Observed(day)
+            | TimeForm::BusinessDays {
+                base: Some(day), ..
+            } => check_form(&day.form, ref_time),
             TimeForm::NthDOWOfTime { base, .. }
             | TimeForm::LastDOWOfTime { base, .. }
             | TimeForm::LastCycleOfTime { base, .. }
             | TimeForm::NDOWsFromTime { base, .. }
             | TimeForm::NthGrainOfTime { base, .. }
             | TimeForm::NthLastDayOfTime { base, .. }
-            | TimeForm::NthLastCycleOfTime { base, .. } => check_form(&base.form, ref_time),
+            | TimeForm::NthLastCycleOfTime { base, .. }
+            | TimeForm::CycleNthAfter { base, .. } => check_form(&base.form, ref_time),
             TimeForm::NthClosestToTime { target, base, .. } => {
                 check_form(&target.form, ref_time) || check_form(&base.form, ref_time)
             }
@@ -593,7 +1237,7 @@ fn has_unrepresentable_relative(data: &TimeData, ref_time: DateTime<Utc>) -> boo
     check_form(&data.form, ref_time)
 }
 
-/// Map timezone abbreviation to UTC offset in minutes
+/// Map timezone abbreviation, or a numeric "+hh:mm" offset, to UTC offset in minutes
 fn timezone_offset_minutes(tz: &str) -> Option<i32> {
     match tz.to_uppercase().as_str() {
         "UTC" | "GMT" | "WET" => Some(0),
@@ -619,21 +1263,84 @@ fn timezone_offset_minutes(tz: &str) -> Option<i32> {
         "AWST" => Some(480),
         "NZST" => Some(720),
         "NZDT" => Some(780),
-        _ => None,
+        numeric => {
+            let (sign, hhmm) = match numeric.split_at_checked(1)? {
+                ("+", rest) => (1, rest),
+                ("-", rest) => (-1, rest),
+                _ => return None,
+            };
+            let (hh, mm) = hhmm.split_once(':')?;
+            let hours: i32 = hh.parse().ok()?;
+            let minutes: i32 = mm.parse().ok()?;
+            hours
+                .checked_mul(60)?
+                .checked_add(minutes)?
+                .checked_mul(sign)
+        }
     }
 }
 
 /// Compute timezone shift for a TimeData, matching Haskell's shiftTimezone.
 /// Each TimeData carries its own timezone (like Haskell's per-predicate shift).
+/// An absolute offset converts straight to UTC, whatever the context's offset.
 fn tz_shift_for(data: &TimeData, context: &Context) -> Option<Duration> {
     data.timezone.as_ref().and_then(|tz_name| {
         let provided_offset = timezone_offset_minutes(tz_name)?;
-        let ctx_offset = context.timezone_offset_minutes;
+        let ctx_offset = if data.absolute_offset {
+            0
+        } else {
+            context.timezone_offset_minutes
+        };
         let diff = ctx_offset.checked_sub(provided_offset).unwrap_or(0);
         Duration::try_minutes(i64::from(diff))
     })
 }
 
+/// A reportable reason `data` resolves to nothing or a fallback: a year
+/// no date exists for, a holiday without a date for its year, or a timezone
+/// with no known offset. Other failures are not diagnosed.
+pub(crate) fn diagnose(data: &TimeData, context: &Context) -> Option<DiagnosticReason> {
+    if let Some(tz) = &data.timezone {
+        if timezone_offset_minutes(tz).is_none() {
+            return Some(DiagnosticReason::UnsupportedTimezone(tz.clone()));
+        }
+    }
+    let unrepresentable = |year: i32| NaiveDate::from_ymd_opt(year, 1, 1).is_none();
+    match &data.form {
+        TimeForm::Year(year)
+        | TimeForm::QuarterYear(_, year)
+        | TimeForm::DateMDY {
+            year: Some(year), ..
+        } if unrepresentable(*year) => Some(DiagnosticReason::YearOutOfRange(*year)),
+        TimeForm::Holiday(name, Some(year)) => {
+            if custom_holiday_date(name, Some(*year), data.direction, context).is_some()
+                || resolve_holiday(name, *year).is_some()
+            {
+                None
+            } else if unrepresentable(*year) {
+                Some(DiagnosticReason::YearOutOfRange(*year))
+            } else if resolve_holiday(name, context.reference_time.year()).is_some() {
+                Some(DiagnosticReason::HolidayYearUnknown {
+                    name: name.clone(),
+                    year: *year,
+                })
+            } else {
+                Some(DiagnosticReason::UnknownHoliday(name.clone()))
+            }
+        }
+        TimeForm::Composed(a, b) => match (&a.form, &b.form) {
+            (TimeForm::Holiday(name, None), TimeForm::Year(year)) => diagnose(
+                &TimeData::new(TimeForm::Holiday(name.clone(), Some(*year))),
+                context,
+            ),
+            _ => diagnose(a, context).or_else(|| diagnose(b, context)),
+        },
+        TimeForm::Interval(a, b, _) => diagnose(a, context).or_else(|| diagnose(b, context)),
+        TimeForm::Observed(inner) => diagnose(inner, context),
+        _ => None,
+    }
+}
+
 // ============================================================
 // Interval resolution
 // ============================================================
@@ -642,6 +1349,7 @@ fn try_resolve_as_interval(
     data: &TimeData,
     ref_time: DateTime<Utc>,
     context: &Context,
+    pods: &PartOfDayConfig,
 ) -> Option<TimeValue> {
     match &data.form {
         TimeForm::Interval(from_data, to_data, open) => {
@@ -684,6 +1392,7 @@ fn try_resolve_as_interval(
                         to: to_point,
                     }],
                     holiday: data.holiday.clone(),
+                    qualifier: None,
                 });
             }
 
@@ -758,11 +1467,17 @@ fn try_resolve_as_interval(
                 }
             }
 
-            // Ensure from <= to (if from ended up after to on same day, fix)
+            // Ensure from <= to: the end is the next occurrence after the start
+            // ("Friday 10pm - 2am", "Dec 31 10pm to Jan 1 2am", "monday to friday")
             if from_dt > to_dt {
-                from_dt = Duration::try_days(1)
-                    .and_then(|d| from_dt.checked_sub_signed(d))
-                    .unwrap_or(from_dt);
+                match resolve_simple_datetime(&to_data.form, from_dt, to_data.direction) {
+                    Some((next_to, _)) if next_to >= from_dt => to_dt = next_to,
+                    _ => {
+                        from_dt = Duration::try_days(1)
+                            .and_then(|d| from_dt.checked_sub_signed(d))
+                            .unwrap_or(from_dt);
+                    }
+                }
             }
             // For closed intervals, add 1 unit of the finer grain (matching Haskell)
             let to_dt = if *open {
@@ -808,6 +1523,7 @@ fn try_resolve_as_interval(
                         to: to_point,
                     }],
                     holiday: data.holiday.clone(),
+                    qualifier: None,
                 })
             } else {
                 let mut iv = make_interval(from_dt, to_dt, interval_grain);
@@ -820,6 +1536,32 @@ fn try_resolve_as_interval(
                 Some(iv)
             }
         }
+        TimeForm::Window {
+            n: Some(n),
+            grain,
+            past,
+        } => {
+            let (from, to) = if *past {
+                (add_grain(ref_time, *grain, n.checked_neg()?)?, ref_time)
+            } else {
+                (ref_time, add_grain(ref_time, *grain, *n)?)
+            };
+            let from = Some(TimePoint::Instant {
+                value: from,
+                grain: Grain::Second,
+            });
+            let to = Some(TimePoint::Instant {
+                value: to,
+                grain: Grain::Second,
+            });
+            Some(TimeValue::Interval {
+                from: from.clone(),
+                to: to.clone(),
+                values: vec![IntervalEndpoints { from, to }],
+                holiday: None,
+                qualifier: None,
+            })
+        }
         TimeForm::NthGrain {
             n,
             grain,
@@ -831,7 +1573,7 @@ fn try_resolve_as_interval(
         }
         TimeForm::PartOfDay(pod) => {
             let date = ref_time.date_naive();
-            let (from, to) = pod_interval(*pod, date, data.early_late);
+            let (from, to) = pod_interval(*pod, date, data.early_late, pods);
             Some(make_interval(from, to, "hour"))
         }
         TimeForm::Weekend => {
@@ -857,8 +1599,9 @@ fn try_resolve_as_interval(
                     next
                 }
             };
-            // "rest of the week" truncates "from" to day boundary
-            let from = if *g >= Grain::Day {
+            // "rest of the week" truncates "from" to day boundary; "rest of
+            // the day" starts at the current hour
+            let from = if *g > Grain::Day {
                 grain_start(ref_time, Grain::Day)
             } else {
                 from
@@ -875,36 +1618,30 @@ fn try_resolve_as_interval(
             let (from, to) = resolve_season_interval(*s, ref_time, data.direction);
             Some(make_interval(from, to, "day"))
         }
-        // early/mid/late + Month → interval (e.g., "early March", "late October")
-        TimeForm::Month(_m) if data.early_late.is_some() => {
-            let (month_start, _) = resolve_simple_datetime(&data.form, ref_time, data.direction)?;
-            let y = month_start.year();
-            let m = month_start.month();
-            let month_end = add_grain(
-                NaiveDate::from_ymd_opt(y, m, 1)
-                    .unwrap()
-                    .and_hms_opt(0, 0, 0)
-                    .unwrap()
-                    .and_utc(),
-                Grain::Month,
-                1,
-            )?;
-            let (from, to) = match data.early_late {
-                Some(EarlyLate::Early) => {
-                    // Beginning of month: day 1 to day 11
-                    (make_date(y, m, 1), make_date(y, m, 11))
-                }
-                Some(EarlyLate::Mid) => {
-                    // Middle of month: day 11 to day 21
-                    (make_date(y, m, 11), make_date(y, m, 21))
-                }
-                Some(EarlyLate::Late) => {
-                    // End of month: day 21 to end
-                    (make_date(y, m, 21), month_end)
-                }
-                None => (make_date(y, m, 1), month_end), // shouldn't happen
-            };
-            Some(make_interval(from, to, "day"))
+        TimeForm::Decade(decade) => {
+            let start = decade_start_year(*decade, ref_time.year())?;
+            let (from, to) = early_mid_late_years(start, 10, data.early_late)?;
+            Some(make_interval(
+                make_date(from, 1, 1),
+                make_date(to, 1, 1),
+                "year",
+            ))
+        }
+        TimeForm::Century(n) => {
+            let start = n.checked_sub(1)?.checked_mul(100)?;
+            let (from, to) = early_mid_late_years(start, 100, data.early_late)?;
+            Some(make_interval(
+                make_date(from, 1, 1),
+                make_date(to, 1, 1),
+                "year",
+            ))
+        }
+        // early/mid/late + a period → interval (e.g., "early March", "mid
+        // next week", "late Q3", "middle of next year")
+        form if data.early_late.is_some() && is_part_of_period_target(form) => {
+            let (period_start, grain) = resolve_period(form, ref_time, data.direction)?;
+            let (from, to) = period_part(period_start, grain, data.early_late?)?;
+            Some(make_interval(from, to, begin_end_grain(form)))
         }
         TimeForm::Composed(primary, secondary) => {
             // Helper: check if a form is a clock time
@@ -935,7 +1672,8 @@ fn try_resolve_as_interval(
                 match pod {
                     Some(PartOfDay::Afternoon)
                     | Some(PartOfDay::Evening)
-                    | Some(PartOfDay::Night) => {
+                    | Some(PartOfDay::Night)
+                    | Some(PartOfDay::Dinner) => {
                         if h < 12 {
                             h.saturating_add(12)
                         } else {
@@ -961,7 +1699,7 @@ fn try_resolve_as_interval(
                         resolve_simple_datetime(&primary.form, ref_time, primary.direction)?;
                     let date = date_dt.date_naive();
                     let (from, to) =
-                        pod_interval(*pod, date, data.early_late.or(secondary.early_late));
+                        pod_interval(*pod, date, data.early_late.or(secondary.early_late), pods);
                     return Some(make_interval(from, to, "hour"));
                 }
             }
@@ -973,7 +1711,7 @@ fn try_resolve_as_interval(
                         resolve_simple_datetime(&secondary.form, ref_time, secondary.direction)?;
                     let date = date_dt.date_naive();
                     let (from, to) =
-                        pod_interval(*pod, date, data.early_late.or(primary.early_late));
+                        pod_interval(*pod, date, data.early_late.or(primary.early_late), pods);
                     return Some(make_interval(from, to, "hour"));
                 }
             }
@@ -986,7 +1724,7 @@ fn try_resolve_as_interval(
                             resolve_simple_datetime(&primary.form, ref_time, primary.direction)?;
                         let date = date_dt.date_naive();
                         let (from, to) =
-                            pod_interval(*pod, date, data.early_late.or(sub_a.early_late));
+                            pod_interval(*pod, date, data.early_late.or(sub_a.early_late), pods);
                         return Some(make_interval(from, to, "hour"));
                     }
                 }
@@ -996,7 +1734,7 @@ fn try_resolve_as_interval(
                             resolve_simple_datetime(&primary.form, ref_time, primary.direction)?;
                         let date = date_dt.date_naive();
                         let (from, to) =
-                            pod_interval(*pod, date, data.early_late.or(sub_b.early_late));
+                            pod_interval(*pod, date, data.early_late.or(sub_b.early_late), pods);
                         return Some(make_interval(from, to, "hour"));
                     }
                 }
@@ -1039,6 +1777,9 @@ fn try_resolve_as_interval(
                                     value: point.clone(),
                                     values: vec![point],
                                     holiday: data.holiday.clone(),
+                                    observed: false,
+                                    qualifier: None,
+                                    meridiem_candidates: Vec::new(),
                                 });
                             }
                         }
@@ -1059,6 +1800,9 @@ fn try_resolve_as_interval(
                                     value: point.clone(),
                                     values: vec![point],
                                     holiday: data.holiday.clone(),
+                                    observed: false,
+                                    qualifier: None,
+                                    meridiem_candidates: Vec::new(),
                                 });
                             }
                         }
@@ -1074,7 +1818,7 @@ fn try_resolve_as_interval(
                         let (date_dt, _) = resolve_composed(sub_b, secondary, ref_time)?;
                         let date = date_dt.date_naive();
                         let (from, to) =
-                            pod_interval(*pod, date, data.early_late.or(sub_a.early_late));
+                            pod_interval(*pod, date, data.early_late.or(sub_a.early_late), pods);
                         return Some(make_interval(from, to, "hour"));
                     }
                 }
@@ -1084,7 +1828,7 @@ fn try_resolve_as_interval(
                         let (date_dt, _) = resolve_composed(sub_a, secondary, ref_time)?;
                         let date = date_dt.date_naive();
                         let (from, to) =
-                            pod_interval(*pod, date, data.early_late.or(sub_b.early_late));
+                            pod_interval(*pod, date, data.early_late.or(sub_b.early_late), pods);
                         return Some(make_interval(from, to, "hour"));
                     }
                 }
@@ -1127,6 +1871,9 @@ fn try_resolve_as_interval(
                                     value: point.clone(),
                                     values: vec![point],
                                     holiday: data.holiday.clone(),
+                                    observed: false,
+                                    qualifier: None,
+                                    meridiem_candidates: Vec::new(),
                                 });
                             }
                         }
@@ -1147,6 +1894,9 @@ fn try_resolve_as_interval(
                                     value: point.clone(),
                                     values: vec![point],
                                     holiday: data.holiday.clone(),
+                                    observed: false,
+                                    qualifier: None,
+                                    meridiem_candidates: Vec::new(),
                                 });
                             }
                         }
@@ -1174,40 +1924,55 @@ fn try_resolve_as_interval(
                 )
             }
 
+            // Endpoints of a time-of-day interval placed on a date; an end at or
+            // before the start falls on the next day ("Friday 10pm - 2am").
+            // Endpoints carrying their own date belong to a plain interval instead.
+            fn interval_on_date(
+                from_td: &TimeData,
+                to_td: &TimeData,
+                open: bool,
+                on: &TimeData,
+                ref_time: DateTime<Utc>,
+            ) -> Option<TimeValue> {
+                fn carries_date(td: &TimeData) -> bool {
+                    match &td.form {
+                        TimeForm::Composed(a, b) => {
+                            let time_of_day = |f: &TimeForm| {
+                                is_clock_time(f) || matches!(f, TimeForm::PartOfDay(_))
+                            };
+                            !time_of_day(&a.form) || !time_of_day(&b.form)
+                        }
+                        _ => false,
+                    }
+                }
+                if carries_date(from_td) || carries_date(to_td) {
+                    return None;
+                }
+                let (date_dt, _) = resolve_simple_datetime(&on.form, ref_time, on.direction)?;
+                let date = date_dt.date_naive();
+                let (from_dt, _) =
+                    resolve_on_date(&from_td.form, date, ref_time, from_td.direction)?;
+                let (mut to_dt, _) = resolve_on_date(&to_td.form, date, ref_time, to_td.direction)?;
+                if to_dt < from_dt && is_clock_time(&to_td.form) {
+                    to_dt = Duration::try_days(1).and_then(|d| to_dt.checked_add_signed(d))?;
+                }
+                if !open {
+                    to_dt = adjust_interval_end_with_from(to_dt, &to_td.form, &from_td.form)?;
+                }
+                let to_grain = form_grain(&to_td.form);
+                let from_g = form_grain(&from_td.form);
+                let interval_grain = if from_g < to_grain { from_g } else { to_grain };
+                Some(make_interval(from_dt, to_dt, interval_grain.as_str()))
+            }
+
             if let TimeForm::Interval(from_td, to_td, open) = &primary.form {
                 if is_date_form(&secondary.form) {
-                    let (date_dt, _) =
-                        resolve_simple_datetime(&secondary.form, ref_time, secondary.direction)?;
-                    let date = date_dt.date_naive();
-                    let (from_dt, _from_grain) =
-                        resolve_on_date(&from_td.form, date, ref_time, from_td.direction)?;
-                    let (mut to_dt, _) =
-                        resolve_on_date(&to_td.form, date, ref_time, to_td.direction)?;
-                    if !open {
-                        to_dt = adjust_interval_end_with_from(to_dt, &to_td.form, &from_td.form)?;
-                    }
-                    let to_grain = form_grain(&to_td.form);
-                    let from_g = form_grain(&from_td.form);
-                    let interval_grain = if from_g < to_grain { from_g } else { to_grain };
-                    return Some(make_interval(from_dt, to_dt, interval_grain.as_str()));
+                    return interval_on_date(from_td, to_td, *open, secondary, ref_time);
                 }
             }
             if let TimeForm::Interval(from_td, to_td, open) = &secondary.form {
                 if is_date_form(&primary.form) {
-                    let (date_dt, _) =
-                        resolve_simple_datetime(&primary.form, ref_time, primary.direction)?;
-                    let date = date_dt.date_naive();
-                    let (from_dt, _) =
-                        resolve_on_date(&from_td.form, date, ref_time, from_td.direction)?;
-                    let (mut to_dt, _) =
-                        resolve_on_date(&to_td.form, date, ref_time, to_td.direction)?;
-                    if !open {
-                        to_dt = adjust_interval_end_with_from(to_dt, &to_td.form, &from_td.form)?;
-                    }
-                    let to_grain = form_grain(&to_td.form);
-                    let from_g = form_grain(&from_td.form);
-                    let interval_grain = if from_g < to_grain { from_g } else { to_grain };
-                    return Some(make_interval(from_dt, to_dt, interval_grain.as_str()));
+                    return interval_on_date(from_td, to_td, *open, primary, ref_time);
                 }
             }
             None // not an interval composed form
@@ -1215,9 +1980,9 @@ fn try_resolve_as_interval(
         // NthGrainOfTime and LastCycleOfTime are resolved as simple values in resolve_simple_datetime
         TimeForm::Holiday(name, year_opt) => {
             let year = year_opt.unwrap_or(ref_time.year());
-            // Check for minute-level intervals (Earth Hour)
-            if let Some((from_dt, to_dt)) = resolve_holiday_minute_interval(name, year) {
-                return Some(make_interval(from_dt, to_dt, "minute"));
+            // Check for intervals finer than days (Earth Hour, Labor Day weekend)
+            if let Some((from_dt, to_dt, grain)) = resolve_holiday_time_interval(name, year) {
+                return Some(make_interval(from_dt, to_dt, grain.as_str()));
             }
             // Check for day-level intervals
             if let Some((from_date, to_date)) = resolve_holiday_interval(name, year) {
@@ -1325,6 +2090,7 @@ fn make_interval(from: DateTime<Utc>, to: DateTime<Utc>, grain: &str) -> TimeVal
             to: to_point,
         }],
         holiday: None,
+        qualifier: None,
     }
 }
 
@@ -1388,7 +2154,8 @@ pub(super) fn form_grain(f: &TimeForm) -> Grain {
         TimeForm::Hour(_, _) | TimeForm::PartOfDay(_) => Grain::Hour,
         TimeForm::HourMinute(_, _, _) => Grain::Minute,
         TimeForm::HourMinuteSecond(_, _, _) => Grain::Second,
-        TimeForm::DurationAfter { grain, .. } => *grain,
+        TimeForm::DurationAfter { grain, .. } | TimeForm::CycleNthAfter { grain, .. } => *grain,
+        TimeForm::WeekOfYear { .. } => Grain::Week,
         TimeForm::Composed(a, b) => {
             let ga = form_grain(&a.form);
             let gb = form_grain(&b.form);
@@ -1485,17 +2252,35 @@ pub(super) fn resolve_simple_datetime(
                 None => (ref_time, "day"),
             }
         }
-        TimeForm::Hour(h, _) => {
+        TimeForm::Hour(h, is_12h) => {
             let mut dt = ref_time
                 .date_naive()
                 .and_hms_opt(*h, 0, 0)
                 .unwrap_or(ref_time.naive_utc())
                 .and_utc();
-            // Future-first: if past, use tomorrow
-            if dt <= ref_time && !matches!(direction, Some(Direction::Past)) {
-                dt = Duration::try_days(1)
-                    .and_then(|d| dt.checked_add_signed(d))
-                    .unwrap_or(dt);
+            if matches!(direction, Some(Direction::Past)) {
+                let pm = (*is_12h && *h < 12)
+                    .then(|| Duration::try_hours(12).and_then(|d| dt.checked_add_signed(d)))
+                    .flatten();
+                let past = latest_past_clock([Some(dt), pm], ref_time).unwrap_or(dt);
+                return Some((past, "hour"));
+            }
+            // Future-first at hour grain: the current hour still counts, then
+            // the PM reading of an ambiguous hour, then tomorrow
+            if dt.hour() == ref_time.hour() {
+                return Some((dt, "hour"));
+            }
+            if dt <= ref_time {
+                let pm = (*is_12h && *h < 12)
+                    .then(|| Duration::try_hours(12).and_then(|d| dt.checked_add_signed(d)))
+                    .flatten()
+                    .filter(|pm| *pm > ref_time);
+                dt = match pm {
+                    Some(pm) => pm,
+                    None => Duration::try_days(1)
+                        .and_then(|d| dt.checked_add_signed(d))
+                        .unwrap_or(dt),
+                };
             }
             (dt, "hour")
         }
@@ -1574,6 +2359,11 @@ pub(super) fn resolve_simple_datetime(
                         "minute",
                     )
                 }
+            } else if matches!(direction, Some(Direction::Past)) {
+                (
+                    latest_past_clock([Some(today), None], ref_time).unwrap_or(today),
+                    "minute",
+                )
             } else {
                 // 24h/explicit AM/PM: future-first with current-hour tolerance
                 if today <= ref_time
@@ -1608,47 +2398,15 @@ pub(super) fn resolve_simple_datetime(
             (dt, "year")
         }
         TimeForm::RelativeGrain { n, grain } => resolve_relative_grain(*n, *grain, ref_time)?,
-        TimeForm::DateMDY { month, day, year } => {
-            let build = |y: i32| -> Option<DateTime<Utc>> {
-                Some(
-                    NaiveDate::from_ymd_opt(y, *month, *day)?
-                        .and_hms_opt(0, 0, 0)?
-                        .and_utc(),
-                )
-            };
-            let dt = if let Some(y) = year {
-                build(*y)?
-            } else {
-                let ref_year = ref_time.year();
-                let this_year = build(ref_year)?;
-                let this_date = this_year.date_naive();
-                let ref_date = ref_time.date_naive();
-                match direction {
-                    Some(Direction::Past) => {
-                        if this_date < ref_date {
-                            this_year
-                        } else {
-                            build(ref_year.checked_sub(1)?)?
-                        }
-                    }
-                    Some(Direction::Future) => {
-                        if this_date > ref_date {
-                            this_year
-                        } else {
-                            build(ref_year.checked_add(1)?)?
-                        }
-                    }
-                    _ => {
-                        if this_date < ref_date {
-                            build(ref_year.checked_add(1)?)?
-                        } else {
-                            this_year
-                        }
-                    }
-                }
-            };
-            (dt, "day")
-        }
+        TimeForm::DateMDY { month, day, year } => match year {
+            Some(y) => (
+                NaiveDate::from_ymd_opt(*y, *month, *day)?
+                    .and_hms_opt(0, 0, 0)?
+                    .and_utc(),
+                "day",
+            ),
+            None => resolve_month_day(*month, *day, direction, ref_time)?,
+        },
         TimeForm::GrainOffset { grain, offset } => resolve_grain_offset(*grain, *offset, ref_time)?,
         TimeForm::Quarter(q) => {
             let month = q.saturating_sub(1).saturating_mul(3).saturating_add(1);
@@ -1711,6 +2469,18 @@ pub(super) fn resolve_simple_datetime(
             // Fallback — intervals should be caught by try_resolve_as_interval
             (midnight(ref_time), "day")
         }
+        // Fallback — windows are intervals starting or ending now
+        TimeForm::Window { .. } => (ref_time, "second"),
+        TimeForm::Decade(decade) => {
+            // Fallback — intervals should be caught by try_resolve_as_interval
+            let start = decade_start_year(*decade, ref_time.year())?;
+            (make_date(start, 1, 1), "year")
+        }
+        TimeForm::Century(n) => {
+            // Fallback — intervals should be caught by try_resolve_as_interval
+            let start = n.checked_sub(1)?.checked_mul(100)?;
+            (make_date(start, 1, 1), "year")
+        }
         TimeForm::BeginEnd { target, .. } => {
             // Fallback
             resolve_simple_datetime(target, ref_time, direction)?
@@ -1718,6 +2488,25 @@ pub(super) fn resolve_simple_datetime(
         TimeForm::Interval(from, _, _) => {
             resolve_simple_datetime(&from.form, ref_time, from.direction)?
         }
+        TimeForm::EndOfBusiness(day) | TimeForm::Observed(day) => {
+            // Fallback — `resolve` lowers these forms before reaching here
+            resolve_simple_datetime(&day.form, ref_time, day.direction)?
+        }
+        TimeForm::Timestamp(millis) => (DateTime::from_timestamp_millis(*millis)?, "second"),
+        TimeForm::BuddhistEraYear { year, offset } => {
+            // Fallback — `resolve` lowers this form before reaching here
+            resolve_simple_datetime(&TimeForm::Year(year.checked_sub(*offset)?), ref_time, None)?
+        }
+        TimeForm::BusinessDays { n, base } => {
+            // Fallback without the context's holiday calendar — `resolve`
+            // lowers this form before reaching here
+            let start = match base {
+                Some(base) => resolve_simple_datetime(&base.form, ref_time, base.direction)?.0,
+                None => ref_time,
+            };
+            let date = add_business_days(start.date_naive(), *n, &HolidayCalendar::default())?;
+            (date.and_hms_opt(0, 0, 0)?.and_utc(), "day")
+        }
         TimeForm::NthGrain { n, grain, past, .. } => {
             // "upcoming 2 days" = cycleNth Day 2 = start of the day 2 days from now
             let signed_n = if *past { n.checked_neg()? } else { *n };
@@ -1881,6 +2670,37 @@ pub(super) fn resolve_simple_datetime(
                 (dt, grain.as_str())
             }
         }
+        TimeForm::WeekOfYear {
+            week,
+            year,
+            numbering,
+        } => {
+            // "week 23" → the next week 23 to end, or the last to start
+            let numbering = numbering.unwrap_or_default();
+            let start = match year {
+                Some(year) => week_of_year_start(*year, *week, numbering)?,
+                None => {
+                    let this_year = ref_time.year();
+                    let start = week_of_year_start(this_year, *week, numbering);
+                    let today = ref_time.date_naive();
+                    match (direction, start) {
+                        (Some(Direction::Past), Some(start)) if start <= today => start,
+                        (Some(Direction::Past), _) => {
+                            week_of_year_start(this_year.checked_sub(1)?, *week, numbering)?
+                        }
+                        (_, Some(start)) if start.checked_add_days(Days::new(7))? > today => start,
+                        _ => week_of_year_start(this_year.checked_add(1)?, *week, numbering)?,
+                    }
+                }
+            };
+            (start.and_hms_opt(0, 0, 0)?.and_utc(), "week")
+        }
+        TimeForm::CycleNthAfter { n, grain, base } => {
+            // "the day after Easter" → the day cycle after Easter Sunday
+            let (base_dt, _) = resolve_simple_datetime(&base.form, ref_time, base.direction)?;
+            let dt = add_grain(grain_start(base_dt, *grain), *grain, i64::from(*n))?;
+            (dt, grain.as_str())
+        }
         TimeForm::NthLastDayOfTime { n, base } => {
             // "last day of October 2015", "5th last day of May"
             let (base_dt, _) = resolve_simple_datetime(&base.form, ref_time, base.direction)?;
@@ -1956,8 +2776,10 @@ pub(super) fn resolve_simple_datetime(
             };
             let future_result = add_dur(future_base)?;
             let past_result = add_dur(past_base)?;
-            // Pick nearest-future result
-            let result = if past_result >= ref_time
+            // Pick nearest-future result; a base pinned by "next" or "last" has
+            // no other occurrence ("a week from next Friday")
+            let result = if base.direction.is_none()
+                && past_result >= ref_time
                 && (future_result < ref_time || past_result <= future_result)
             {
                 past_result
@@ -2016,6 +2838,22 @@ pub(super) fn resolve_simple_datetime(
 // Day of week resolution
 // ============================================================
 
+/// The latest of `clocks` (times of day on the reference date) or the same
+/// times a day earlier that is not after `ref_time`.
+fn latest_past_clock(
+    clocks: [Option<DateTime<Utc>>; 2],
+    ref_time: DateTime<Utc>,
+) -> Option<DateTime<Utc>> {
+    let day = Duration::try_days(1)?;
+    clocks
+        .into_iter()
+        .flatten()
+        .flat_map(|clock| [Some(clock), clock.checked_sub_signed(day)])
+        .flatten()
+        .filter(|clock| *clock <= ref_time)
+        .max()
+}
+
 fn resolve_dow(dow: u32, ref_time: DateTime<Utc>, direction: Option<Direction>) -> DateTime<Utc> {
     let current = ref_time.weekday().num_days_from_monday();
     let target = dow;
@@ -2174,6 +3012,103 @@ fn resolve_grain_offset(
 // Composed form resolution
 // ============================================================
 
+/// The calendar fields of a composition made only of weekdays, days of the
+/// month, months, years and dates.
+#[derive(Default)]
+struct CalendarFields {
+    dow: Option<u32>,
+    month: Option<u32>,
+    day: Option<u32>,
+    year: Option<i32>,
+    direction: Option<Direction>,
+}
+
+impl CalendarFields {
+    /// Adds the fields of `td`; false when it holds anything else or
+    /// contradicts a field already set.
+    fn add(&mut self, td: &TimeData) -> bool {
+        fn set<T: PartialEq + Copy>(field: &mut Option<T>, value: T) -> bool {
+            *field.get_or_insert(value) == value
+        }
+        self.direction = self.direction.or(td.direction);
+        match &td.form {
+            TimeForm::DayOfWeek(dow) => set(&mut self.dow, *dow),
+            TimeForm::DayOfMonth(day) => set(&mut self.day, *day),
+            TimeForm::Month(month) => set(&mut self.month, *month),
+            TimeForm::Year(year) => set(&mut self.year, *year),
+            TimeForm::DateMDY { month, day, year } => {
+                set(&mut self.month, *month)
+                    && set(&mut self.day, *day)
+                    && year.is_none_or(|y| set(&mut self.year, y))
+            }
+            TimeForm::Composed(a, b) => self.add(a) && self.add(b),
+            _ => false,
+        }
+    }
+}
+
+/// Resolves nested calendar compositions as one date, however the parts were
+/// grouped: "Monday the 3rd of March 2025" may compose as ("Monday the 3rd",
+/// "March 2025"), which neither side can resolve on its own. The weekday is
+/// checked as for a weekday and date.
+fn resolve_calendar_fields(
+    primary: &TimeData,
+    secondary: &TimeData,
+    ref_time: DateTime<Utc>,
+) -> Option<(DateTime<Utc>, &'static str)> {
+    let nested = |td: &TimeData| matches!(td.form, TimeForm::Composed(..));
+    if !nested(primary) && !nested(secondary) {
+        return None;
+    }
+    let mut fields = CalendarFields::default();
+    if !fields.add(primary) || !fields.add(secondary) {
+        return None;
+    }
+    let (month, day) = (fields.month?, fields.day?);
+    let date_dt = match fields.year {
+        Some(year) => NaiveDate::from_ymd_opt(year, month, day)?
+            .and_hms_opt(0, 0, 0)?
+            .and_utc(),
+        None => resolve_month_day(month, day, fields.direction, ref_time)?.0,
+    };
+    let dt = match fields.dow {
+        Some(dow) => find_dow_date_intersection(dow, date_dt, month, day, fields.year),
+        None => date_dt,
+    };
+    Some((dt, "day"))
+}
+
+/// Resolve a month and day without a year to its nearest occurrence in the
+/// given direction (future-first by default). February 29th can be up to
+/// eight years away.
+fn resolve_month_day(
+    month: u32,
+    day: u32,
+    direction: Option<Direction>,
+    ref_time: DateTime<Utc>,
+) -> Option<(DateTime<Utc>, &'static str)> {
+    let build = |y: i32| -> Option<DateTime<Utc>> {
+        Some(
+            NaiveDate::from_ymd_opt(y, month, day)?
+                .and_hms_opt(0, 0, 0)?
+                .and_utc(),
+        )
+    };
+    let later = |from: i32| (0..8).filter_map(|k| from.checked_add(k)).find_map(build);
+    let earlier = |from: i32| (0..8).filter_map(|k| from.checked_sub(k)).find_map(build);
+    let ref_year = ref_time.year();
+    let ref_date = ref_time.date_naive();
+    let this_year = build(ref_year).map(|dt| (dt, dt.date_naive()));
+    let dt = match (direction, this_year) {
+        (Some(Direction::Past), Some((dt, date))) if date < ref_date => dt,
+        (Some(Direction::Past), _) => earlier(ref_year.checked_sub(1)?)?,
+        (Some(Direction::Future), Some((dt, date))) if date > ref_date => dt,
+        (None | Some(Direction::FarFuture), Some((dt, date))) if date >= ref_date => dt,
+        _ => later(ref_year.checked_add(1)?)?,
+    };
+    Some((dt, "day"))
+}
+
 fn resolve_composed(
     primary: &TimeData,
     secondary: &TimeData,
@@ -2193,7 +3128,10 @@ fn resolve_composed(
             };
         }
         match pod {
-            Some(PartOfDay::Afternoon) | Some(PartOfDay::Evening) | Some(PartOfDay::Night) => {
+            Some(PartOfDay::Afternoon)
+            | Some(PartOfDay::Evening)
+            | Some(PartOfDay::Night)
+            | Some(PartOfDay::Dinner) => {
                 if h < 12 {
                     h.saturating_add(12)
                 } else {
@@ -2520,14 +3458,15 @@ fn resolve_composed(
                     return Some((dt, "day"));
                 }
             }
-            // For DayOfMonth within a specific month, directly compute
+            // For DayOfMonth within a specific month, directly compute; a day
+            // the month doesn't have ("the 31st of next month" in March) has
+            // no resolution
             if *grain == Grain::Month {
                 if let TimeForm::DayOfMonth(day) = &primary.form {
-                    let dt = NaiveDate::from_ymd_opt(offset_base.year(), offset_base.month(), *day)
-                        .unwrap_or(offset_base.date_naive())
-                        .and_hms_opt(0, 0, 0)
-                        .unwrap()
-                        .and_utc();
+                    let dt =
+                        NaiveDate::from_ymd_opt(offset_base.year(), offset_base.month(), *day)?
+                            .and_hms_opt(0, 0, 0)?
+                            .and_utc();
                     return Some((dt, "day"));
                 }
             }
@@ -2539,53 +3478,13 @@ fn resolve_composed(
         _ => {}
     }
 
+    if let Some(resolved) = resolve_calendar_fields(primary, secondary, ref_time) {
+        return Some(resolved);
+    }
+
     // DayOfMonth + Month (or vice versa) → resolve as month-day intersection.
     // Matches Haskell's `monthDay m d = intersect' (month m, dayOfMonth d)`.
     // Propagates direction from inner components (Haskell's `direction = d1 <|> d2`).
-    fn resolve_month_day(
-        month: u32,
-        day: u32,
-        direction: Option<Direction>,
-        ref_time: DateTime<Utc>,
-    ) -> Option<(DateTime<Utc>, &'static str)> {
-        let build = |y: i32| -> Option<DateTime<Utc>> {
-            Some(
-                NaiveDate::from_ymd_opt(y, month, day)?
-                    .and_hms_opt(0, 0, 0)?
-                    .and_utc(),
-            )
-        };
-        let ref_year = ref_time.year();
-        let this_year = build(ref_year)?;
-        let this_date = this_year.date_naive();
-        let ref_date = ref_time.date_naive();
-        let dt = match direction {
-            Some(Direction::Past) => {
-                if this_date < ref_date {
-                    this_year
-                } else {
-                    build(ref_year.checked_sub(1)?)?
-                }
-            }
-            Some(Direction::Future) => {
-                if this_date > ref_date {
-                    this_year
-                } else {
-                    build(ref_year.checked_add(1)?)?
-                }
-            }
-            _ => {
-                // Future-first default
-                if this_date < ref_date {
-                    build(ref_year.checked_add(1)?)?
-                } else {
-                    this_year
-                }
-            }
-        };
-        Some((dt, "day"))
-    }
-
     if let TimeForm::DayOfMonth(day) = &primary.form {
         if let TimeForm::Month(month) = &secondary.form {
             let direction = primary.direction.or(secondary.direction);
@@ -2740,6 +3639,15 @@ fn resolve_composed(
         return resolve_composed(&new_primary, secondary, ref_time);
     }
     if let TimeForm::Composed(a, b) = &secondary.form {
+        // "Tuesday" + "next week at 15:00": the clock time applies to the
+        // date the other two narrow down to, so intersect those first.
+        if matches!(
+            b.form,
+            TimeForm::Hour(..) | TimeForm::HourMinute(..) | TimeForm::HourMinuteSecond(..)
+        ) {
+            let date = TimeData::new(TimeForm::Composed(Box::new(primary.clone()), a.clone()));
+            return resolve_composed(&date, b, ref_time);
+        }
         let (secondary_dt, _secondary_grain) = resolve_composed(a, b, ref_time)?;
         let new_secondary = TimeData::new(TimeForm::DateMDY {
             month: secondary_dt.month(),
@@ -2942,7 +3850,7 @@ fn resolve_all_grain(
 
 fn grain_for_all_rest(grain: Grain) -> &'static str {
     match grain {
-        Grain::Week | Grain::Month | Grain::Year => grain.lower().as_str(),
+        Grain::Day | Grain::Week | Grain::Month | Grain::Year => grain.lower().as_str(),
         _ => grain.as_str(),
     }
 }
@@ -2957,128 +3865,125 @@ fn resolve_begin_end(
     ref_time: DateTime<Utc>,
     direction: Option<Direction>,
 ) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
-    // Resolve the target period start
-    let (period_start, grain) = match target {
+    let part = if begin {
+        EarlyLate::Early
+    } else {
+        EarlyLate::Late
+    };
+    let (period_start, grain) = resolve_period(target, ref_time, direction)?;
+    period_part(period_start, grain, part)
+}
+
+/// Start and grain of the period `target` names: the week, month, quarter
+/// or year that "beginning of" and "late" pick a part of.
+fn resolve_period(
+    target: &TimeForm,
+    ref_time: DateTime<Utc>,
+    direction: Option<Direction>,
+) -> Option<(DateTime<Utc>, Grain)> {
+    Some(match target {
         TimeForm::GrainOffset { grain, offset } => {
             let (dt, _) = resolve_grain_offset(*grain, *offset, ref_time)?;
             (dt, *grain)
         }
         TimeForm::Month(m) => {
             let dt = resolve_month(*m, ref_time, direction);
-            (
-                NaiveDate::from_ymd_opt(dt.year(), dt.month(), 1)
-                    .unwrap()
-                    .and_hms_opt(0, 0, 0)
-                    .unwrap()
-                    .and_utc(),
-                Grain::Month,
-            )
-        }
-        TimeForm::Year(y) => {
-            let dt = NaiveDate::from_ymd_opt(*y, 1, 1)
-                .unwrap()
-                .and_hms_opt(0, 0, 0)
-                .unwrap()
-                .and_utc();
-            (dt, Grain::Year)
+            (make_date(dt.year(), dt.month(), 1), Grain::Month)
         }
+        TimeForm::Year(y) => (make_date(*y, 1, 1), Grain::Year),
         _ => {
             let (dt, _) = resolve_simple_datetime(target, ref_time, direction)?;
             let g = target_grain(target);
             (grain_start(dt, g), g)
         }
-    };
+    })
+}
 
-    // Use hardcoded boundaries matching Haskell Duckling
-    Some(match grain {
-        Grain::Week => {
-            if begin {
-                // Beginning of week: Mon-Wed (dayOfWeek 1-3)
-                (
-                    period_start,
-                    Duration::try_days(3)
-                        .and_then(|d| period_start.checked_add_signed(d))
-                        .unwrap_or(period_start),
-                )
-            } else {
-                // End of week: Fri-Sun (dayOfWeek 5-7)
-                (
-                    Duration::try_days(4)
-                        .and_then(|d| period_start.checked_add_signed(d))
-                        .unwrap_or(period_start),
-                    Duration::try_days(7)
-                        .and_then(|d| period_start.checked_add_signed(d))
-                        .unwrap_or(period_start),
-                )
-            }
-        }
-        Grain::Month => {
-            let y = period_start.year();
-            let m = period_start.month();
-            if begin {
-                // Beginning of month: day 1 to day 11 (exclusive)
-                let end = make_date(y, m, 11);
-                (period_start, end)
-            } else {
-                // End of month: day 21 to end of month
-                let start = make_date(y, m, 21);
-                let end = add_grain(period_start, Grain::Month, 1)?;
-                (start, end)
-            }
-        }
-        Grain::Year => {
-            let y = period_start.year();
-            if begin {
-                // Beginning of year: month 1 to month 4 (Jan-Mar)
-                let end = make_date(y, 4, 1);
-                (period_start, end)
-            } else {
-                // End of year: month 9 to end of year (Sep-Dec)
-                let start = make_date(y, 9, 1);
-                let end = make_date(y.saturating_add(1), 1, 1);
-                (start, end)
-            }
-        }
-        Grain::Day => {
-            if begin {
-                // Beginning of day: 00:00 to 08:00
-                let end = Duration::try_hours(8)
-                    .and_then(|d| period_start.checked_add_signed(d))
-                    .unwrap_or(period_start);
-                (period_start, end)
-            } else {
-                // End of day: 17:00 to 00:00
-                let start = Duration::try_hours(17)
-                    .and_then(|d| period_start.checked_add_signed(d))
-                    .unwrap_or(period_start);
-                let end = Duration::try_days(1)
-                    .and_then(|d| period_start.checked_add_signed(d))
-                    .unwrap_or(period_start);
-                (start, end)
-            }
-        }
+/// The `[from, to)` part of the period of `grain` starting at
+/// `period_start` that "beginning of", "middle of" and "end of" (or
+/// "early", "mid" and "late") name. Week, month, year and day use Haskell
+/// Duckling's boundaries, with the middle filling the gap between them;
+/// a quarter splits into its months and other grains into thirds.
+fn period_part(
+    period_start: DateTime<Utc>,
+    grain: Grain,
+    part: EarlyLate,
+) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+    let days = |n: i64| {
+        Duration::try_days(n)
+            .and_then(|d| period_start.checked_add_signed(d))
+            .unwrap_or(period_start)
+    };
+    let hours = |n: i64| {
+        Duration::try_hours(n)
+            .and_then(|d| period_start.checked_add_signed(d))
+            .unwrap_or(period_start)
+    };
+    let (y, m) = (period_start.year(), period_start.month());
+    Some(match (grain, part) {
+        // Monday to Wednesday, Wednesday and Thursday, Friday to Sunday
+        (Grain::Week, EarlyLate::Early) => (period_start, days(3)),
+        (Grain::Week, EarlyLate::Mid) => (days(2), days(4)),
+        (Grain::Week, EarlyLate::Late) => (days(4), days(7)),
+        // The 1st to the 10th, the 11th to the 20th, the 21st to the end
+        (Grain::Month, EarlyLate::Early) => (period_start, make_date(y, m, 11)),
+        (Grain::Month, EarlyLate::Mid) => (make_date(y, m, 11), make_date(y, m, 21)),
+        (Grain::Month, EarlyLate::Late) => (
+            make_date(y, m, 21),
+            add_grain(period_start, Grain::Month, 1)?,
+        ),
+        (Grain::Quarter, part) => {
+            let month = match part {
+                EarlyLate::Early => 0,
+                EarlyLate::Mid => 1,
+                EarlyLate::Late => 2,
+            };
+            let from = add_grain(period_start, Grain::Month, month)?;
+            (from, add_grain(from, Grain::Month, 1)?)
+        }
+        // January to March, April to August, September to December
+        (Grain::Year, EarlyLate::Early) => (period_start, make_date(y, 4, 1)),
+        (Grain::Year, EarlyLate::Mid) => (make_date(y, 4, 1), make_date(y, 9, 1)),
+        (Grain::Year, EarlyLate::Late) => (make_date(y, 9, 1), make_date(y.checked_add(1)?, 1, 1)),
+        // Midnight to 8:00, 8:00 to 17:00, 17:00 to midnight
+        (Grain::Day, EarlyLate::Early) => (period_start, hours(8)),
+        (Grain::Day, EarlyLate::Mid) => (hours(8), hours(17)),
+        (Grain::Day, EarlyLate::Late) => (hours(17), days(1)),
         _ => {
-            // Fallback: divide into thirds
             let period_end = add_grain(period_start, grain, 1)?;
             let total_secs = period_end.signed_duration_since(period_start).num_seconds();
             let portion = total_secs.checked_div(3).unwrap_or(0);
-            if begin {
-                let to = Duration::try_seconds(portion)
+            let at = |secs: i64| {
+                let dt = Duration::try_seconds(secs)
                     .and_then(|d| period_start.checked_add_signed(d))
                     .unwrap_or(period_start);
-                let to = grain_start(to, grain.lower());
-                (period_start, to)
-            } else {
-                let from = Duration::try_seconds(portion)
-                    .and_then(|d| period_end.checked_sub_signed(d))
-                    .unwrap_or(period_end);
-                let from = grain_start(from, grain.lower());
-                (from, period_end)
+                grain_start(dt, grain.lower())
+            };
+            match part {
+                EarlyLate::Early => (period_start, at(portion)),
+                EarlyLate::Mid => (at(portion), at(portion.saturating_mul(2))),
+                EarlyLate::Late => (at(portion.saturating_mul(2)), period_end),
             }
         }
     })
 }
 
+/// Whether `form` names a whole week, month, quarter or year that early,
+/// mid or late can pick a part of.
+pub(super) fn is_part_of_period_target(form: &TimeForm) -> bool {
+    match form {
+        TimeForm::GrainOffset { grain, .. } => matches!(
+            grain,
+            Grain::Week | Grain::Month | Grain::Quarter | Grain::Year
+        ),
+        TimeForm::Month(_)
+        | TimeForm::Year(_)
+        | TimeForm::Quarter(_)
+        | TimeForm::QuarterYear(..) => true,
+        _ => false,
+    }
+}
+
 fn begin_end_grain(target: &TimeForm) -> &'static str {
     match target {
         TimeForm::GrainOffset { grain, .. } => grain.lower().as_str(),
@@ -3260,34 +4165,53 @@ fn generic_season_dates(season: u32, year: i32) -> (DateTime<Utc>, DateTime<Utc>
 // Part of day interval
 // ============================================================
 
+/// Hour range `(start, end)` of `pod`, end exclusive and possibly past 24.
+/// Morning, afternoon, evening, night and meal times come from `config`;
+/// "early morning" drops the last three hours and "late evening/night" keeps
+/// only the last three.
+fn pod_hours(
+    pod: PartOfDay,
+    early_late: Option<EarlyLate>,
+    config: &PartOfDayConfig,
+) -> (u32, u32) {
+    let range = match pod {
+        PartOfDay::Morning => config.morning,
+        PartOfDay::Afternoon => config.afternoon,
+        PartOfDay::Evening => config.evening,
+        PartOfDay::Night => config.night,
+        PartOfDay::Lunch => config.lunch,
+        PartOfDay::Breakfast => config.breakfast,
+        PartOfDay::Dinner => config.dinner,
+        PartOfDay::Overnight => (22, 30),
+        PartOfDay::Noonish => (11, 13),
+        PartOfDay::Midnightish => (23, 25),
+    };
+    let (start, end) = (range.0, range.1.max(range.0));
+    let cut = end.saturating_sub(3).max(start);
+    match (pod, early_late) {
+        (PartOfDay::Morning, Some(EarlyLate::Early)) => (start, cut),
+        (PartOfDay::Evening | PartOfDay::Night, Some(EarlyLate::Late)) => (cut, end),
+        _ => (start, end),
+    }
+}
+
+/// Interval for `pod` on `date`; hours past 24 run into the next day.
 pub(super) fn pod_interval(
     pod: PartOfDay,
     date: NaiveDate,
     early_late: Option<EarlyLate>,
+    config: &PartOfDayConfig,
 ) -> (DateTime<Utc>, DateTime<Utc>) {
-    let (start_h, end_h) = match (pod, early_late) {
-        (PartOfDay::Morning, Some(EarlyLate::Early)) => (0, 9),
-        (PartOfDay::Morning, _) => (0, 12),
-        (PartOfDay::Afternoon, _) => (12, 19),
-        (PartOfDay::Evening, Some(EarlyLate::Late)) => (21, 24),
-        (PartOfDay::Evening, _) => (18, 24),
-        (PartOfDay::Night, Some(EarlyLate::Late)) => (21, 24),
-        (PartOfDay::Night, _) => (18, 24),
-        (PartOfDay::Lunch, _) => (12, 14),
-    };
+    let (start_h, end_h) = pod_hours(pod, early_late, config);
 
-    let from = date.and_hms_opt(start_h, 0, 0).unwrap().and_utc();
-    let to = if end_h >= 24 {
-        (date
-            .checked_add_signed(Duration::try_days(1).unwrap_or_default())
-            .unwrap_or(date))
-        .and_hms_opt(0, 0, 0)
-        .unwrap()
-        .and_utc()
-    } else {
-        date.and_hms_opt(end_h, 0, 0).unwrap().and_utc()
+    let day_start = date.and_hms_opt(0, 0, 0).unwrap().and_utc();
+    let at_hour = |h: u32| {
+        Duration::try_hours(i64::from(h))
+            .and_then(|d| day_start.checked_add_signed(d))
+            .unwrap_or(day_start)
     };
-    (from, to)
+    let from = at_hour(start_h);
+    (from, at_hour(end_h).max(from))
 }
 
 // ============================================================
@@ -3298,6 +4222,36 @@ pub(super) fn midnight(dt: DateTime<Utc>) -> DateTime<Utc> {
     dt.date_naive().and_hms_opt(0, 0, 0).unwrap().and_utc()
 }
 
+/// First year of a decade: "the 1980s" as is, and "the 90s" in the
+/// reference year's century unless that decade hasn't begun yet, in which
+/// case the century before ("the 20s" in 2013 are the 1920s).
+fn decade_start_year(decade: i32, ref_year: i32) -> Option<i32> {
+    if decade >= 100 {
+        return Some(decade);
+    }
+    let start = ref_year
+        .div_euclid(100)
+        .checked_mul(100)?
+        .checked_add(decade)?;
+    if start > ref_year {
+        start.checked_sub(100)
+    } else {
+        Some(start)
+    }
+}
+
+/// The `[from, to)` years of a span of `len` years starting at `start`, or
+/// of its first, middle or last third for "early", "mid" and "late".
+fn early_mid_late_years(start: i32, len: i32, early_late: Option<EarlyLate>) -> Option<(i32, i32)> {
+    let (lo, hi) = match early_late {
+        None => (0, len),
+        Some(EarlyLate::Early) => (0, len.checked_add(2)? / 3),
+        Some(EarlyLate::Mid) => (len.checked_add(2)? / 3, len.checked_mul(2)? / 3),
+        Some(EarlyLate::Late) => (len.checked_mul(2)? / 3, len),
+    };
+    Some((start.checked_add(lo)?, start.checked_add(hi)?))
+}
+
 fn make_date(y: i32, m: u32, d: u32) -> DateTime<Utc> {
     NaiveDate::from_ymd_opt(y, m, d)
         .unwrap_or(NaiveDate::from_ymd_opt(y, 1, 1).unwrap())
@@ -3306,6 +4260,22 @@ fn make_date(y: i32, m: u32, d: u32) -> DateTime<Utc> {
         .and_utc()
 }
 
+/// First day of week `week` of `year`: a Monday for ISO weeks, a Sunday for
+/// US weeks. `None` past the year's last week.
+fn week_of_year_start(year: i32, week: u32, numbering: WeekNumbering) -> Option<NaiveDate> {
+    match numbering {
+        WeekNumbering::Iso => NaiveDate::from_isoywd_opt(year, week, chrono::Weekday::Mon),
+        WeekNumbering::Us => {
+            let jan1 = NaiveDate::from_ymd_opt(year, 1, 1)?;
+            let first =
+                jan1.checked_sub_days(Days::new(u64::from(jan1.weekday().num_days_from_sunday())))?;
+            let days = u64::from(week.checked_sub(1)?).checked_mul(7)?;
+            let start = first.checked_add_days(Days::new(days))?;
+            (start.year() == year || week == 1).then_some(start)
+        }
+    }
+}
+
 fn start_of_week(dt: DateTime<Utc>) -> DateTime<Utc> {
     let dow = dt.weekday().num_days_from_monday();
     midnight(
@@ -3341,7 +4311,7 @@ fn start_of_quarter(dt: DateTime<Utc>) -> DateTime<Utc> {
         .and_utc()
 }
 
-pub(super) fn grain_start(dt: DateTime<Utc>, grain: Grain) -> DateTime<Utc> {
+pub(crate) fn grain_start(dt: DateTime<Utc>, grain: Grain) -> DateTime<Utc> {
     match grain {
         Grain::NoGrain | Grain::Second => dt,
         Grain::Minute => dt
@@ -3362,7 +4332,7 @@ pub(super) fn grain_start(dt: DateTime<Utc>, grain: Grain) -> DateTime<Utc> {
     }
 }
 
-pub(super) fn add_grain(dt: DateTime<Utc>, grain: Grain, n: i64) -> Option<DateTime<Utc>> {
+pub(crate) fn add_grain(dt: DateTime<Utc>, grain: Grain, n: i64) -> Option<DateTime<Utc>> {
     match grain {
         Grain::NoGrain | Grain::Second => {
             Duration::try_seconds(n).and_then(|d| dt.checked_add_signed(d))
@@ -3611,7 +4581,7 @@ pub(super) fn resolve_holiday_interval(name: &str, year: i32) -> Option<(NaiveDa
     if name == "naidoc week" {
         let second_friday = nth_dow_of_month(year, 7, 4, 2); // 4=Friday
         let start = second_friday - Duration::days(5);
-        let end_exclusive = second_friday + Duration::days(2);
+        let end_exclusive = second_friday + Duration::days(3);
         return Some((start, end_exclusive));
     }
 
@@ -3626,23 +4596,16 @@ pub(super) fn resolve_holiday_interval(name: &str, year: i32) -> Option<(NaiveDa
         }
     }
 
-    // Labour Day weekend (CA): from previous Friday 18:00 to Tuesday 00:00 after Labour Day Monday
-    if name.starts_with("labor day weekend") || name.starts_with("labour day weekend") {
-        let labor_day = nth_dow_of_month(year, 9, 0, 1);
-        let start = labor_day - Duration::days(3);
-        let end_exclusive = labor_day + Duration::days(1);
-        return Some((start, end_exclusive));
-    }
-
     None
 }
 
-/// Resolve a holiday that is a minute-level interval (e.g., Earth Hour).
+/// Resolve a holiday that is an interval finer than days (e.g., Earth Hour,
+/// Labor Day weekend), with the grain of its ends.
 #[allow(clippy::arithmetic_side_effects)]
-pub(super) fn resolve_holiday_minute_interval(
+pub(super) fn resolve_holiday_time_interval(
     name: &str,
     year: i32,
-) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+) -> Option<(DateTime<Utc>, DateTime<Utc>, Grain)> {
     let name_lower = name.to_lowercase();
     let name = name_lower.as_str();
 
@@ -3658,7 +4621,19 @@ pub(super) fn resolve_holiday_minute_interval(
         };
         let from = date.and_hms_opt(20, 30, 0).unwrap().and_utc();
         let to = date.and_hms_opt(21, 31, 0).unwrap().and_utc(); // 60 minutes later, closed interval
-        return Some((from, to));
+        return Some((from, to, Grain::Minute));
+    }
+
+    // Labor Day weekend: Friday 18:00 before Labor Day Monday to the Tuesday after
+    if name.starts_with("labor day week") || name.starts_with("labour day week") {
+        let labor_day = nth_dow_of_month(year, 9, 0, 1);
+        let from = (labor_day - Duration::days(3))
+            .and_hms_opt(18, 0, 0)?
+            .and_utc();
+        let to = (labor_day + Duration::days(1))
+            .and_hms_opt(0, 0, 0)?
+            .and_utc();
+        return Some((from, to, Grain::Hour));
     }
 
     None
@@ -3676,6 +4651,7 @@ pub(super) fn resolve_holiday(name: &str, year: i32) -> Option<NaiveDate> {
 
     // Fixed-date holidays
     match name {
+        "christmas eve" | "xmas eve" => return NaiveDate::from_ymd_opt(year, 12, 24),
         s if s.starts_with("christmas") || s.starts_with("xmas") => {
             return NaiveDate::from_ymd_opt(year, 12, 25)
         }
@@ -3705,7 +4681,9 @@ pub(super) fn resolve_holiday(name: &str, year: i32) -> Option<NaiveDate> {
             let delta = (7_i64 - i64::from(base.weekday().num_days_from_monday())) % 7;
             return Some(base + Duration::days(delta));
         }
-        s if s.starts_with("day of") && (s.contains("vow") || s.contains("reconciliation")) => {
+        s if s.starts_with("day of")
+            && (s.contains("vow") || s.contains("covenant") || s.contains("reconciliation")) =>
+        {
             return NaiveDate::from_ymd_opt(year, 12, 16);
         }
         "heritage day" => return NaiveDate::from_ymd_opt(year, 9, 24),
@@ -3718,9 +4696,12 @@ pub(super) fn resolve_holiday(name: &str, year: i32) -> Option<NaiveDate> {
             let back = i64::from(base.weekday().num_days_from_monday());
             return Some(base - Duration::days(back));
         }
+        // Newfoundland: the Monday nearest June 24
         "discovery day" => {
             let base = NaiveDate::from_ymd_opt(year, 6, 24)?;
-            return Some(closest_weekday(base));
+            let dow = i64::from(base.weekday().num_days_from_monday());
+            let shift = if dow <= 3 { -dow } else { 7 - dow };
+            return Some(base + Duration::days(shift));
         }
         "civic day"
         | "civic holiday"
@@ -3732,7 +4713,7 @@ pub(super) fn resolve_holiday(name: &str, year: i32) -> Option<NaiveDate> {
         "family day" | "islander day" | "louis riel day" | "nova scotia heritage day" => {
             return Some(nth_dow_of_month(year, 2, 0, 3));
         }
-        "national patriots day" | "national patriot's day" => {
+        "national patriots day" | "national patriot's day" | "national patriots' day" => {
             let base = NaiveDate::from_ymd_opt(year, 5, 25)?;
             let back = i64::from(base.weekday().num_days_from_monday());
             return Some(base - Duration::days(back));
@@ -3752,6 +4733,49 @@ pub(super) fn resolve_holiday(name: &str, year: i32) -> Option<NaiveDate> {
         s if s.contains("patrick") || s.contains("paddy") => {
             return NaiveDate::from_ymd_opt(year, 3, 17)
         }
+        "st. brigid's day" => return NaiveDate::from_ymd_opt(year, 2, 1),
+        "epiphany" => return NaiveDate::from_ymd_opt(year, 1, 6),
+        "liberation day" => return NaiveDate::from_ymd_opt(year, 4, 25),
+        "republic day" => return NaiveDate::from_ymd_opt(year, 6, 2),
+        "ferragosto" => return NaiveDate::from_ymd_opt(year, 8, 15),
+        "all saints' day" => return NaiveDate::from_ymd_opt(year, 11, 1),
+        "all souls' day" => return NaiveDate::from_ymd_opt(year, 11, 2),
+        "immaculate conception" => return NaiveDate::from_ymd_opt(year, 12, 8),
+        "st. stephen's day" => return NaiveDate::from_ymd_opt(year, 12, 26),
+        "st. andrew's day" => return NaiveDate::from_ymd_opt(year, 11, 30),
+        "st. george's day" => return NaiveDate::from_ymd_opt(year, 4, 23),
+        "great union day" => return NaiveDate::from_ymd_opt(year, 12, 1),
+        "bulgarian liberation day" => return NaiveDate::from_ymd_opt(year, 3, 3),
+        "gergyovden" => return NaiveDate::from_ymd_opt(year, 5, 6),
+        "slavonic literature day" => return NaiveDate::from_ymd_opt(year, 5, 24),
+        "unification day" => return NaiveDate::from_ymd_opt(year, 9, 6),
+        "bulgarian independence day" => return NaiveDate::from_ymd_opt(year, 9, 22),
+        "hungarian state foundation day" => return NaiveDate::from_ymd_opt(year, 8, 20),
+        "international workers' day" => return NaiveDate::from_ymd_opt(year, 5, 1),
+        "croatian statehood day" => return NaiveDate::from_ymd_opt(year, 5, 30),
+        "anti-fascist struggle day" => return NaiveDate::from_ymd_opt(year, 6, 22),
+        "croatian victory day" => return NaiveDate::from_ymd_opt(year, 8, 5),
+        "assumption day" => return NaiveDate::from_ymd_opt(year, 8, 15),
+        "zero discrimination day" => return NaiveDate::from_ymd_opt(year, 3, 1),
+        "africa day" => return NaiveDate::from_ymd_opt(year, 5, 25),
+        "international day of cooperatives" => return Some(nth_dow_of_month(year, 7, 5, 1)),
+        "world prematurity day" => return NaiveDate::from_ymd_opt(year, 11, 17),
+        "arabic language day" => return NaiveDate::from_ymd_opt(year, 12, 18),
+        // Swedish midsummer: the Friday and Saturday between June 19 and 26
+        "midsummer eve" | "midsummer day" => {
+            let base = NaiveDate::from_ymd_opt(year, 6, 19)?;
+            let to_friday = (11_i64 - i64::from(base.weekday().num_days_from_monday())) % 7;
+            let eve = base + Duration::days(to_friday);
+            return Some(if name == "midsummer eve" {
+                eve
+            } else {
+                eve + Duration::days(1)
+            });
+        }
+        "st. john's eve" => return NaiveDate::from_ymd_opt(year, 6, 23),
+        "st. john's day" => return NaiveDate::from_ymd_opt(year, 6, 24),
+        "sinterklaas" => return NaiveDate::from_ymd_opt(year, 12, 6),
+        "pakjesavond" => return NaiveDate::from_ymd_opt(year, 12, 5),
         "koningsdag" | "king's day" => {
             // April 27, unless it's a Sunday, then April 26
             let d = NaiveDate::from_ymd_opt(year, 4, 27).unwrap();
@@ -3857,9 +4881,7 @@ pub(super) fn resolve_holiday(name: &str, year: i32) -> Option<NaiveDate> {
     }
 
     // Administrative Professionals' Day (Wednesday of last full week in April)
-    if name.contains("administrative")
-        || name.contains("secretaries")
-        || name.starts_with("admins day")
+    if name.contains("administrative") || name.contains("secretaries") || name.starts_with("admin")
     {
         return Some(administrative_professionals_day(year));
     }
@@ -3959,6 +4981,18 @@ pub(super) fn resolve_holiday(name: &str, year: i32) -> Option<NaiveDate> {
         if name.contains("holy saturday") || name.contains("black saturday") {
             return Some(orthodox - Duration::days(1));
         }
+        if name.contains("palm sunday") {
+            return Some(orthodox - Duration::days(7));
+        }
+        if name.contains("ascension") {
+            return Some(orthodox + Duration::days(39));
+        }
+        if name.contains("whit monday") {
+            return Some(orthodox + Duration::days(50));
+        }
+        if name.contains("pentecost") || name.contains("trinity") {
+            return Some(orthodox + Duration::days(49));
+        }
     }
     // Clean Monday and its aliases (Haskell: (orthodox\s+)?(ash|clean|green|pure|shrove)\s+monday)
     if name.contains("monday")
@@ -4024,6 +5058,12 @@ pub(super) fn resolve_holiday(name: &str, year: i32) -> Option<NaiveDate> {
         return parsi_new_year(year);
     }
 
+    // Nowruz, the Iranian new year on the March equinox; not the Parsi
+    // Navroz above, which falls in August
+    if name == "nowruz" {
+        return nowruz(year);
+    }
+
     // Vesak / Buddha Day
     if name.starts_with("vesak") || name.starts_with("vaisakha") || name.starts_with("buddha") {
         return resolve_vesak(year);
@@ -6743,6 +7783,17 @@ fn parsi_new_year(year: i32) -> Option<NaiveDate> {
     NaiveDate::from_ymd_opt(year, m, d)
 }
 
+/// The first day of Farvardin: March 21, or March 20 when the equinox
+/// comes before noon in Tehran (2000-2050).
+fn nowruz(year: i32) -> Option<NaiveDate> {
+    let day = match year {
+        2000 | 2004 | 2008 | 2012 | 2016 | 2020 | 2024 | 2028 | 2029 | 2032 | 2033 | 2036
+        | 2037 | 2040 | 2041 | 2044 | 2045 | 2048 | 2049 => 20,
+        _ => 21,
+    };
+    NaiveDate::from_ymd_opt(year, 3, day)
+}
+
 fn thai_pongal(year: i32) -> Option<NaiveDate> {
     let (m, d) = match year {
         2000 => (1, 15),
@@ -1,3 +1,4 @@
+use super::scandinavian::Lexicon;
 use super::Direction;
 use super::{PartOfDay, TimeData, TimeForm};
 use crate::dimensions::time_grain::Grain;
@@ -79,24 +80,43 @@ fn parse_nb_grain(s: &str) -> Option<Grain> {
     }
 }
 
+const LEXICON: Lexicon = Lexicon {
+    lang: "nb",
+    today: r"i\s*dag",
+    tomorrow: r"i\s*morgen|i\s*morra",
+    yesterday: r"i\s*g[åa]r",
+    day_after_tomorrow: r"(?:i\s+)?overmorgen|(?:i\s+)?overimorra",
+    day_before_yesterday: r"(?:i\s+)?forig[åa]rs",
+    weekdays: [
+        r"mandag(?:en)?|man",
+        r"tirsdag(?:en)?|tirs",
+        r"onsdag(?:en)?|ons",
+        r"torsdag(?:en)?|tors",
+        r"fredag(?:en)?|fre",
+        r"l[øo]rdag(?:en)?|lør",
+        r"s[øo]ndag(?:en)?|søn",
+    ],
+    next: r"neste|kommende",
+    last: r"forrige|sist",
+    clock: r"klokken|klokka|kl",
+    minutes: r"minutt(?:er)?",
+    past: r"over",
+    to: r"p[åa]",
+    holidays: &[
+        (r"sankthansaften|sankthans|jonsokaften", "st. john's eve"),
+        (r"sankthansdag(?:en)?|jonsok", "st. john's day"),
+    ],
+};
+
 pub fn rules() -> Vec<Rule> {
     let mut rules = super::en::rules();
+    rules.extend(super::scandinavian::rules(&LEXICON));
     rules.extend(vec![
         Rule {
             name: "now (nb)".to_string(),
             pattern: vec![regex("n[åa]")],
             production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Now)))),
         },
-        Rule {
-            name: "today (nb)".to_string(),
-            pattern: vec![regex("i dag|idag")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Today)))),
-        },
-        Rule {
-            name: "tomorrow (nb)".to_string(),
-            pattern: vec![regex("i morgen|imorgen|i morra")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Tomorrow)))),
-        },
         Rule {
             name: "part-of-day keywords (nb)".to_string(),
             pattern: vec![regex("i kveld|ikveld|kveld(en)?|i natt|inatt|natt(a|en)?|i morges|morges|morgen(en)?|morran|denne\\s+morran|denne\\s+morgen(en)?|ettermiddag(en)?|middag(en)?|om ettermiddagen")],
@@ -122,59 +142,6 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("etter\\s+frokost")],
             production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::PartOfDay(PartOfDay::Morning))))),
         },
-        Rule {
-            name: "yesterday (nb)".to_string(),
-            pattern: vec![regex("i g[åa]r|ig[åa]r")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Yesterday)))),
-        },
-        Rule {
-            name: "day before yesterday / day after tomorrow (nb)".to_string(),
-            pattern: vec![regex("i forig[åa]rs|forig[åa]rs|i overimorra|overimorra|i overmorgen|overmorgen")],
-            production: Box::new(|nodes| {
-                let s = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(0)?.to_lowercase(),
-                    _ => return None,
-                };
-                if s.contains("forig") {
-                    Some(TokenData::Time(TimeData::new(TimeForm::DayBeforeYesterday)))
-                } else {
-                    Some(TokenData::Time(TimeData::new(TimeForm::DayAfterTomorrow)))
-                }
-            }),
-        },
-        Rule {
-            name: "day of week (nb)".to_string(),
-            pattern: vec![regex("mandag|man\\.?|tirsdag|onsdag|torsdag|tors\\.?|fredag|fre\\.?|l[øo]rdag|l[øo]r\\.?|s[øo]ndag|s[øo]n\\.?")],
-            production: Box::new(|nodes| {
-                let s = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(0)?.to_lowercase(),
-                    _ => return None,
-                };
-                let dow = if s.contains("mandag") || s == "man." || s == "man" {
-                    0
-                } else if s.contains("tirsdag") {
-                    1
-                } else if s.contains("onsdag") {
-                    2
-                } else if s.contains("torsdag") || s == "tors." || s == "tors" {
-                    3
-                } else if s.contains("fredag") || s == "fre" || s == "fre." {
-                    4
-                } else if (s.contains("rdag") && (s.contains("lø") || s.contains("lo")))
-                    || s == "lør"
-                    || s == "lør."
-                    || s == "lor"
-                    || s == "lor."
-                {
-                    5
-                } else if s.contains("ndag") || s == "søn" || s == "søn." || s == "son" || s == "son." {
-                    6
-                } else {
-                    return None;
-                };
-                Some(TokenData::Time(TimeData::new(TimeForm::DayOfWeek(dow))))
-            }),
-        },
         Rule {
             name: "den første mars (nb)".to_string(),
             pattern: vec![regex("den\\s+f[øo]rste\\s+mars|1\\.\\s+mars")],
@@ -532,68 +499,6 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("neste [åa]r")],
             production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset { grain: Grain::Year, offset: 1 })))),
         },
-        Rule {
-            name: "klokken/kl <hour> (nb)".to_string(),
-            pattern: vec![regex("klokken\\s*(\\d{1,2})|klokka\\s*(\\d{1,2})|kl\\.?\\s*(\\d{1,2})")],
-            production: Box::new(|nodes| {
-                let h = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(1).or_else(|| m.group(2)).or_else(|| m.group(3))?,
-                    _ => return None,
-                };
-                let hour: u32 = h.parse().ok()?;
-                if hour > 23 {
-                    return None;
-                }
-                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(hour, 0, true))))
-            }),
-        },
-        Rule {
-            name: "klokken/kl <hh:mm> (nb)".to_string(),
-            pattern: vec![regex("(?:klokken|kl\\.?)\\s*(\\d{1,2})[:\\.](\\d{2})")],
-            production: Box::new(|nodes| {
-                let (h, m) = match &nodes[0].token_data {
-                    TokenData::RegexMatch(rm) => (rm.group(1)?, rm.group(2)?),
-                    _ => return None,
-                };
-                let hour: u32 = h.parse().ok()?;
-                let minute: u32 = m.parse().ok()?;
-                if hour > 23 || minute > 59 {
-                    return None;
-                }
-                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(hour, minute, false))))
-            }),
-        },
-        Rule {
-            name: "kvarter over <hour> (nb)".to_string(),
-            pattern: vec![regex("(kvarter|kvart)\\s+over\\s*(\\d{1,2})")],
-            production: Box::new(|nodes| {
-                let h = match &nodes[0].token_data {
-                    TokenData::RegexMatch(rm) => rm.group(2)?,
-                    _ => return None,
-                };
-                let hour: u32 = h.parse().ok()?;
-                if hour > 23 {
-                    return None;
-                }
-                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(hour, 15, false))))
-            }),
-        },
-        Rule {
-            name: "kvarter på <hour> (nb)".to_string(),
-            pattern: vec![regex("(kvarter|kvart)\\s+p[åa]\\s*(\\d{1,2})")],
-            production: Box::new(|nodes| {
-                let h = match &nodes[0].token_data {
-                    TokenData::RegexMatch(rm) => rm.group(2)?,
-                    _ => return None,
-                };
-                let hour_in: u32 = h.parse().ok()?;
-                if hour_in == 0 || hour_in > 24 {
-                    return None;
-                }
-                let hour = if hour_in == 1 { 0 } else { hour_in.checked_sub(1)? };
-                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(hour, 45, false))))
-            }),
-        },
         Rule {
             name: "om <duration> (nb)".to_string(),
             pattern: vec![regex("om\\s+(\\d+|en|én|ett|ei|et|to|tre|fire|fem|seks|syv)\\s+(sekund(?:er)?|minutt(?:er)?|time(?:r)?|dag(?:er)?|uk(?:e|er)|m[åa]ned(?:er)?|[åa]r)")],
@@ -6,6 +6,9 @@ use crate::types::{Rule, TokenData};
 fn is_time(td: &TokenData) -> bool {
     matches!(td, TokenData::Time(_))
 }
+fn is_time_of_day(td: &TokenData) -> bool {
+    matches!(td, TokenData::Time(d) if matches!(d.form, TimeForm::Hour(..) | TimeForm::HourMinute(..)))
+}
 fn time_data(td: &TokenData) -> Option<&TimeData> {
     match td {
         TokenData::Time(d) => Some(d),
@@ -49,13 +52,50 @@ fn parse_nl_grain(s: &str) -> Option<Grain> {
     }
 }
 
+/// An hour as said on the clock face: digits or a number word up to twaalf.
+const NL_HOUR: &str =
+    "(\\d{1,2}|een|één|twee|drie|vier|vijf|zes|zeven|acht|negen|tien|elf|twaalf)\\b";
+
+fn nl_hour(s: &str) -> Option<u32> {
+    let hour = match s {
+        "elf" => 11,
+        "twaalf" => 12,
+        word => match parse_nl_number_word(word) {
+            Some(n) => n as u32,
+            None => word.parse().ok()?,
+        },
+    };
+    (1..=24).contains(&hour).then_some(hour)
+}
+
+/// `minute` past the hour named in the first capture group, counted from
+/// the hour before it when `before` is set: "half vier" is 3:30.
+fn nl_clock(td: &TokenData, minute: u32, before: bool) -> Option<TokenData> {
+    let hour = match td {
+        TokenData::RegexMatch(m) => nl_hour(&m.group(1)?.to_lowercase())?,
+        _ => return None,
+    };
+    let hour = if before {
+        hour.checked_sub(1)?
+    } else {
+        hour % 24
+    };
+    Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(
+        hour,
+        minute,
+        hour <= 12,
+    ))))
+}
+
 pub fn rules() -> Vec<Rule> {
     let mut rules = super::en::rules();
     rules.extend(vec![
         Rule { name: "now (nl)".to_string(), pattern: vec![regex("nu|direct|zojuist")], production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Now)))) },
         Rule { name: "today (nl)".to_string(), pattern: vec![regex("vandaag|op deze dag")], production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Today)))) },
-        Rule { name: "tomorrow (nl)".to_string(), pattern: vec![regex("morgen")], production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Tomorrow)))) },
-        Rule { name: "yesterday (nl)".to_string(), pattern: vec![regex("gisteren")], production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Yesterday)))) },
+        Rule { name: "tomorrow (nl)".to_string(), pattern: vec![regex("\\bmorgen\\b")], production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Tomorrow)))) },
+        Rule { name: "yesterday (nl)".to_string(), pattern: vec![regex("\\bgisteren\\b")], production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Yesterday)))) },
+        Rule { name: "day after tomorrow (nl)".to_string(), pattern: vec![regex("\\bovermorgen\\b")], production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::DayAfterTomorrow)))) },
+        Rule { name: "day before yesterday (nl)".to_string(), pattern: vec![regex("\\beergisteren\\b")], production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::DayBeforeYesterday)))) },
         Rule {
             name: "day of week (nl)".to_string(),
             pattern: vec![regex("maandags?|ma\\.|dinsdags?|di\\.|woensdags?|woe\\.|donderdags?|do\\.|vrijdags?|vr(ij)?\\.|zaterdags?|zat?\\.|zondags?|zon?\\.")],
@@ -95,8 +135,16 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "sinterklaas (nl)".to_string(),
-            pattern: vec![regex("sinterklaas")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::DateMDY { month: 12, day: 6, year: None })))),
+            pattern: vec![regex("\\b(sinterklaas(?:dag|avond)?|pakjesavond)\\b")],
+            production: Box::new(|nodes| {
+                let s = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?.to_lowercase(),
+                    _ => return None,
+                };
+                // The presents come on the eve, 5 December
+                let h = if s.ends_with("avond") { "pakjesavond" } else { "sinterklaas" };
+                Some(TokenData::Time(TimeData::new(TimeForm::Holiday(h.to_string(), None))))
+            }),
         },
         Rule {
             name: "1 maart (nl)".to_string(),
@@ -314,44 +362,53 @@ pub fn rules() -> Vec<Rule> {
             production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(4, 0, false))))),
         },
         Rule {
-            name: "4 uur 's ochtends (nl)".to_string(),
-            pattern: vec![regex("4 uur\\s+'s\\s+ochtends")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(4, 0, false))))),
-        },
-        Rule {
-            name: "3 uur (nl)".to_string(),
-            pattern: vec![regex("(\\d{1,2})\\s+uur")],
+            name: "<hour> uur (nl)".to_string(),
+            pattern: vec![regex(&format!("\\b(?:(?:om|rond|tegen)\\s+)?(?:ongeveer\\s+)?{NL_HOUR}\\s+uur\\b"))],
             production: Box::new(|nodes| {
-                let h = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(1)?,
+                let hour = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => nl_hour(&m.group(1)?.to_lowercase())?,
                     _ => return None,
                 };
-                let hour: u32 = h.parse().ok()?;
-                if hour > 23 {
-                    return None;
-                }
-                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(hour, 0, false))))
+                Some(TokenData::Time(TimeData::new(TimeForm::Hour(hour % 24, hour <= 12))))
             }),
         },
         Rule {
-            name: "om drie uur (nl)".to_string(),
-            pattern: vec![regex("om\\s+drie\\s+uur")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(3, 0, false))))),
-        },
-        Rule {
-            name: "drie uur 's middags (nl)".to_string(),
-            pattern: vec![regex("drie\\s+uur\\s+'s\\s+middags")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(15, 0, false))))),
-        },
-        Rule {
-            name: "rond drie uur (nl)".to_string(),
-            pattern: vec![regex("rond\\s+drie\\s+uur")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(3, 0, false))))),
+            name: "om <time-of-day> (nl)".to_string(),
+            pattern: vec![regex("\\bom\\b"), predicate(is_time_of_day)],
+            production: Box::new(|nodes| {
+                let mut t = time_data(&nodes[1].token_data)?.clone();
+                t.latent = false;
+                Some(TokenData::Time(t))
+            }),
         },
         Rule {
-            name: "om ongeveer drie uur (nl)".to_string(),
-            pattern: vec![regex("om\\s+ongeveer\\s+drie\\s+uur")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(3, 0, false))))),
+            name: "<time-of-day> 's ochtends|middags|avonds|nachts (nl)".to_string(),
+            pattern: vec![predicate(is_time_of_day), regex("['’]s\\s+(ochtends|morgens|middags|avonds|nachts)\\b")],
+            production: Box::new(|nodes| {
+                let mut t = time_data(&nodes[0].token_data)?.clone();
+                let part = match &nodes[1].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?.to_lowercase(),
+                    _ => return None,
+                };
+                let (hour, is_12h) = match t.form {
+                    TimeForm::Hour(h, is_12h) | TimeForm::HourMinute(h, _, is_12h) => (h, is_12h),
+                    _ => return None,
+                };
+                let hour = match part.as_str() {
+                    _ if !is_12h => hour,
+                    "middags" | "avonds" if hour < 12 => hour.checked_add(12)?,
+                    // "11 uur 's nachts" is just before midnight, "2 uur 's nachts" after
+                    "nachts" if (6..12).contains(&hour) => hour.checked_add(12)?,
+                    "ochtends" | "morgens" | "nachts" if hour == 12 => 0,
+                    _ => hour,
+                };
+                t.form = match t.form {
+                    TimeForm::HourMinute(_, m, _) => TimeForm::HourMinute(hour, m, false),
+                    _ => TimeForm::Hour(hour, false),
+                };
+                t.latent = false;
+                Some(TokenData::Time(t))
+            }),
         },
         Rule {
             name: "om circa 15u (nl)".to_string(),
@@ -395,20 +452,19 @@ pub fn rules() -> Vec<Rule> {
             production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(3, 20, false))))),
         },
         Rule {
-            name: "kwart voor 12 (nl)".to_string(),
-            pattern: vec![regex("kwart\\s+voor\\s+(\\d{1,2})")],
-            production: Box::new(|nodes| {
-                let h = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(1)?,
-                    _ => return None,
-                };
-                let hour: u32 = h.parse().ok()?;
-                if hour > 23 {
-                    return None;
-                }
-                let out_hour = if hour == 0 { 23 } else { hour.checked_sub(1)? };
-                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(out_hour, 45, false))))
-            }),
+            name: "half <hour> (nl)".to_string(),
+            pattern: vec![regex(&format!("(?:om\\s+)?half\\s*{NL_HOUR}(?:\\s+uur)?"))],
+            production: Box::new(|nodes| nl_clock(&nodes[0].token_data, 30, true)),
+        },
+        Rule {
+            name: "kwart over <hour> (nl)".to_string(),
+            pattern: vec![regex(&format!("(?:om\\s+)?kwart\\s+over\\s*{NL_HOUR}(?:\\s+uur)?"))],
+            production: Box::new(|nodes| nl_clock(&nodes[0].token_data, 15, false)),
+        },
+        Rule {
+            name: "kwart voor <hour> (nl)".to_string(),
+            pattern: vec![regex(&format!("(?:om\\s+)?kwart\\s+voor\\s*{NL_HOUR}(?:\\s+uur)?"))],
+            production: Box::new(|nodes| nl_clock(&nodes[0].token_data, 45, true)),
         },
         Rule {
             name: "over/in een seconde (nl)".to_string(),
@@ -1,6 +1,6 @@
 use super::{Direction, PartOfDay, TimeData, TimeForm};
 use crate::dimensions::time_grain::Grain;
-use crate::pattern::regex;
+use crate::pattern::{predicate, regex};
 use crate::types::{Rule, TokenData};
 
 fn parse_pl_number_word(s: &str) -> Option<i64> {
@@ -73,6 +73,63 @@ fn parse_pl_hour_word(s: &str) -> Option<u32> {
     }
 }
 
+/// Month names in the nominative, genitive ("3 maja") and locative ("w
+/// maju") cases, with and without Polish diacritics.
+const MONTHS: &str = "stycze[ńn]|stycznia|styczniu|luty|lutego|lutym|marzec|marca|marcu|kwiecie[ńn]|kwietnia|kwietniu|maj|maja|maju|czerwiec|czerwca|czerwcu|lipiec|lipca|lipcu|sierpie[ńn]|sierpnia|sierpniu|wrzesie[ńn]|wrze[śs]nia|wrze[śs]niu|pa[źz]dziernik|pa[źz]dziernika|pa[źz]dzierniku|listopad|listopada|listopadzie|grudzie[ńn]|grudnia|grudniu";
+
+/// "przyszły", "przyszłą", "następnym", ... for next and "zeszły",
+/// "ubiegłą", "poprzedni", ... for last, in any gender and case.
+const NEXT_OR_LAST: &str =
+    "przysz[łl]\\w*|nast[ęe]pn\\w*|kolejn\\w*|zesz[łl]\\w*|ubieg[łl]\\w*|poprzedni\\w*";
+
+fn parse_pl_month(s: &str) -> Option<u32> {
+    let s = s.to_lowercase();
+    let months = [
+        "sty", "lut", "mar", "kwi", "maj", "cze", "lip", "sie", "wrz", "pa", "lis", "gru",
+    ];
+    let i = months.iter().position(|m| s.starts_with(m))?;
+    u32::try_from(i).ok()?.checked_add(1)
+}
+
+/// 1 for "przyszły" and the other words for next, -1 for those for last.
+fn next_or_last_offset(s: &str) -> i32 {
+    let s = s.to_lowercase();
+    if s.starts_with("przysz") || s.starts_with("nast") || s.starts_with("kolejn") {
+        1
+    } else {
+        -1
+    }
+}
+
+fn time_data(td: &TokenData) -> Option<&TimeData> {
+    match td {
+        TokenData::Time(d) => Some(d),
+        _ => None,
+    }
+}
+
+fn is_day_of_week(td: &TokenData) -> bool {
+    matches!(td, TokenData::Time(d) if matches!(d.form, TimeForm::DayOfWeek(_)))
+}
+
+/// An hour and optional minutes as written in the 24-hour clock, e.g.
+/// "15" or "15:30". A bare hour up to 12 may still be in the afternoon.
+fn clock_time(h: &str, m: Option<&str>) -> Option<TimeData> {
+    let hour: u32 = h.parse().ok()?;
+    let form = match m {
+        None if hour <= 24 => TimeForm::Hour(hour % 24, (1..=12).contains(&hour)),
+        Some(m) => {
+            let minute: u32 = m.parse().ok()?;
+            if hour > 23 || minute > 59 {
+                return None;
+            }
+            TimeForm::HourMinute(hour, minute, false)
+        }
+        None => return None,
+    };
+    Some(TimeData::new(form))
+}
+
 pub fn rules() -> Vec<Rule> {
     let mut rules = super::en::rules();
     rules.extend(vec![
@@ -108,7 +165,7 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "day of week (pl)".to_string(),
-            pattern: vec![regex("poniedzia(l|ł)(ek|ku|kowi|kiem|kowy)|pon\\.?|wtorek|wtorku|wtorkowi|wtorkiem|wtr?\\.?|(Ś|ś|s)rod(a|ą|y|e|ę|zie|owy|o)|(s|ś|Ś)ro?\\.?|czwartek|czwartku|czwartkowi|czwartkiem|czwr?\\.?|piątek|piatek|piątku|piatku|piątkowi|piatkowi|piątkiem|piatkiem|pi(ą|a)tkowy|pia\\.?|sobota|soboty|sobocie|sobotę|sobote|sobotą|soboto|sob\\.?|niedziel(a|i|ę|e|ą|o)|n(ie)?dz?\\.?")],
+            pattern: vec![regex("\\b(?:poniedzia(l|ł)(ek|ku|kowi|kiem|kowy)|pon|wtorek|wtorku|wtorkowi|wtorkiem|wtr?|(Ś|ś|s)rod(a|ą|y|e|ę|zie|owy|o)|(s|ś|Ś)ro?|czwartek|czwartku|czwartkowi|czwartkiem|czwr?|piątek|piatek|piątku|piatku|piątkowi|piatkowi|piątkiem|piatkiem|pi(ą|a)tkowy|pia|sobota|soboty|sobocie|sobotę|sobote|sobotą|soboto|sob|niedziel(a|i|ę|e|ą|o)|n(ie)?dz?)\\b\\.?")],
             production: Box::new(|nodes| {
                 let s = match &nodes[0].token_data {
                     TokenData::RegexMatch(m) => m.group(0)?.to_lowercase(),
@@ -134,6 +191,119 @@ pub fn rules() -> Vec<Rule> {
                 Some(TokenData::Time(TimeData::new(TimeForm::DayOfWeek(dow))))
             }),
         },
+        Rule {
+            name: "w|we <day-of-week> (pl)".to_string(),
+            pattern: vec![regex("\\bwe?\\b"), predicate(is_day_of_week)],
+            production: Box::new(|nodes| Some(TokenData::Time(time_data(&nodes[1].token_data)?.clone()))),
+        },
+        Rule {
+            name: "przyszły|zeszły <day-of-week> (pl)".to_string(),
+            pattern: vec![
+                regex(&format!("\\b(?:we?\\s+)?({NEXT_OR_LAST})\\b")),
+                predicate(is_day_of_week),
+            ],
+            production: Box::new(|nodes| {
+                let offset = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => next_or_last_offset(m.group(1)?),
+                    _ => return None,
+                };
+                let mut t = time_data(&nodes[1].token_data)?.clone();
+                if offset > 0 {
+                    t.direction = Some(Direction::Future);
+                    t.not_immediate = true;
+                } else {
+                    t.direction = Some(Direction::Past);
+                }
+                Some(TokenData::Time(t))
+            }),
+        },
+        Rule {
+            name: "w przyszłym|zeszłym tygodniu/miesiącu/roku (pl)".to_string(),
+            pattern: vec![regex(&format!(
+                "\\b(?:w\\s+)?({NEXT_OR_LAST})\\s+(tydzie[ńn]|tygodni[au]|miesi[ąa]c[au]?|rok|roku)\\b"
+            ))],
+            production: Box::new(|nodes| {
+                let (offset, grain) = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => (next_or_last_offset(m.group(1)?), parse_pl_grain(m.group(2)?)?),
+                    _ => return None,
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset { grain, offset })))
+            }),
+        },
+        Rule {
+            name: "<day> <month> [<year>] (pl)".to_string(),
+            pattern: vec![regex(&format!(
+                "\\b(\\d{{1,2}})(?:\\.|-?go)?\\s+({MONTHS})(?:\\s+(\\d{{4}}))?\\b"
+            ))],
+            production: Box::new(|nodes| {
+                let (d, mo, y) = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => (m.group(1)?, m.group(2)?, m.group(3)),
+                    _ => return None,
+                };
+                let day: u32 = d.parse().ok()?;
+                if !(1..=31).contains(&day) {
+                    return None;
+                }
+                let year = match y {
+                    Some(y) => Some(y.parse().ok()?),
+                    None => None,
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::DateMDY {
+                    month: parse_pl_month(mo)?,
+                    day,
+                    year,
+                })))
+            }),
+        },
+        Rule {
+            name: "month (pl)".to_string(),
+            pattern: vec![regex(&format!("\\b(?:w\\s+)?({MONTHS})\\b"))],
+            production: Box::new(|nodes| {
+                let mo = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?,
+                    _ => return None,
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::Month(parse_pl_month(mo)?))))
+            }),
+        },
+        Rule {
+            name: "o [godz.] <hour>[:mm] (pl)".to_string(),
+            pattern: vec![regex(
+                "\\b(?:o\\s+(?:godz(?:\\.|inie)?\\s*)?|godz(?:\\.|ina|inie)?\\s*)(\\d{1,2})(?:[:.](\\d{2}))?\\b",
+            )],
+            production: Box::new(|nodes| {
+                let (h, m) = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => (m.group(1)?, m.group(2)),
+                    _ => return None,
+                };
+                Some(TokenData::Time(clock_time(h, m)?))
+            }),
+        },
+        Rule {
+            name: "od <hour>[:mm] do <hour>[:mm] (pl)".to_string(),
+            pattern: vec![regex(
+                "\\bod\\s+(?:godz(?:\\.|iny)?\\s*)?(\\d{1,2})(?:[:.](\\d{2}))?\\s+do\\s+(?:godz(?:\\.|iny)?\\s*)?(\\d{1,2})(?:[:.](\\d{2}))?\\b",
+            )],
+            production: Box::new(|nodes| {
+                let m = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m,
+                    _ => return None,
+                };
+                let mut from = clock_time(m.group(1)?, m.group(2))?;
+                let mut to = clock_time(m.group(3)?, m.group(4))?;
+                // "od 9 do 17": office hours are read on the 24-hour clock
+                for t in [&mut from, &mut to] {
+                    if let TimeForm::Hour(_, is_12h) = &mut t.form {
+                        *is_12h = false;
+                    }
+                }
+                Some(TokenData::Time(TimeData::new(TimeForm::Interval(
+                    Box::new(from),
+                    Box::new(to),
+                    false,
+                ))))
+            }),
+        },
         Rule {
             name: "pierwszy marca (pl)".to_string(),
             pattern: vec![regex("pierwszy\\s+marca")],
@@ -928,7 +1098,7 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "weekend (pl)".to_string(),
-            pattern: vec![regex("weekend|ten\\s+weekend|w\\s+ten\\s+weekend|w\\s+weekend")],
+            pattern: vec![regex("(?:w\\s+)?(?:ten\\s+)?(?:week-?end|wekend)")],
             production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Weekend)))),
         },
         Rule {
@@ -1278,7 +1448,7 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "holidays (pl)".to_string(),
-            pattern: vec![regex("[śs]wi[ęe]ta\\s+bo[żz]ego\\s+narodzenia|boże\\s+narodzenie|boze\\s+narodzenie|wigilia|sylwester|nowy\\s+rok|walentynki|dzien\\s+matki|dzień\\s+matki|dzie[ńn]\\s+mamy|dzien\\s+ojca|dzień\\s+ojca|dzie[ńn]\\s+taty|dzie[ńn]\\s+dziękczynienia|dzie[ńn]\\s+dziekczynienia|dziękczynienie|dziekczynienie|halloween|wszystkich\\s+świętych|wszystkich\\s+swietych")],
+            pattern: vec![regex("[śs]wi[ęe]ta\\s+bo[żz]ego\\s+narodzenia|boże\\s+narodzenie|boze\\s+narodzenie|wigilia(\\s+bo[żz]ego\\s+narodzenia)?|sylwester|nowy\\s+rok|walentynki|dzien\\s+matki|dzień\\s+matki|dzie[ńn]\\s+mamy|dzien\\s+ojca|dzień\\s+ojca|dzie[ńn]\\s+taty|dzie[ńn]\\s+dziękczynienia|dzie[ńn]\\s+dziekczynienia|dziękczynienie|dziekczynienie|halloween|wszystkich\\s+świętych|wszystkich\\s+swietych")],
             production: Box::new(|nodes| {
                 let s = match &nodes[0].token_data {
                     TokenData::RegexMatch(m) => m.group(0)?.to_lowercase(),
@@ -1286,7 +1456,9 @@ pub fn rules() -> Vec<Rule> {
                 };
                 let h = if s.contains("boże narodzenie") || s.contains("boze narodzenie") {
                     "christmas day"
-                } else if s.contains("wigilia") || s.contains("sylwester") {
+                } else if s.contains("wigilia") {
+                    "christmas eve"
+                } else if s.contains("sylwester") {
                     "new year's eve"
                 } else if s.contains("nowy rok") {
                     "new year's day"
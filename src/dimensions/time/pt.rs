@@ -1,6 +1,7 @@
 use super::{Direction, PartOfDay, TimeData, TimeForm};
 use crate::dimensions::numeral::helpers::{is_natural, numeral_data};
 use crate::dimensions::time_grain::Grain;
+use crate::locale::Region;
 use crate::pattern::{dim, predicate, regex};
 use crate::types::{DimensionKind, Rule, TokenData};
 
@@ -438,6 +439,54 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("proclama[çc][ãa]o\\s+da\\s+rep[úu]blica")],
             production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::DateMDY { month: 11, day: 15, year: None })))),
         },
+        Rule {
+            name: "Carnaval (pt)".to_string(),
+            pattern: vec![regex("(ter[çc]a(\\s|\\-)feira\\s+de\\s+)?carnaval|entrudo")],
+            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Holiday(
+                "mardi gras".to_string(),
+                None,
+            ))))),
+        },
+        Rule {
+            name: "quarta-feira de cinzas (pt)".to_string(),
+            pattern: vec![regex("quarta(\\s|\\-)feira\\s+de\\s+cinzas")],
+            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Holiday(
+                "ash wednesday".to_string(),
+                None,
+            ))))),
+        },
+        Rule {
+            name: "sexta-feira santa (pt)".to_string(),
+            pattern: vec![regex("sexta(\\s|\\-)feira\\s+(santa|da\\s+paix[ãa]o)")],
+            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Holiday(
+                "good friday".to_string(),
+                None,
+            ))))),
+        },
+        Rule {
+            name: "Páscoa (pt)".to_string(),
+            pattern: vec![regex("(domingo\\s+de\\s+)?p[áa]scoa")],
+            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Holiday(
+                "easter".to_string(),
+                None,
+            ))))),
+        },
+        Rule {
+            name: "Corpus Christi (pt)".to_string(),
+            pattern: vec![regex("corpus\\s+christi|corpo\\s+de\\s+deus")],
+            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Holiday(
+                "corpus christi".to_string(),
+                None,
+            ))))),
+        },
+        Rule {
+            name: "vespera de natal (pt)".to_string(),
+            pattern: vec![regex("v[ée]spera\\s+de\\s+natal|consoada")],
+            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Holiday(
+                "christmas eve".to_string(),
+                None,
+            ))))),
+        },
         Rule {
             name: "vespera de ano novo (pt)".to_string(),
             pattern: vec![regex("v[ée]spera\\s+de\\s+ano\\s+novo")],
@@ -581,6 +630,34 @@ pub fn rules() -> Vec<Rule> {
                 })))
             }),
         },
+        Rule {
+            name: "daqui a <duration> (pt)".to_string(),
+            pattern: vec![regex("daqui\\s+a"), dim(DimensionKind::Duration)],
+            production: Box::new(|nodes| {
+                let d = match &nodes[1].token_data {
+                    TokenData::Duration(d) => d,
+                    _ => return None,
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::RelativeGrain {
+                    n: d.value,
+                    grain: d.grain,
+                })))
+            }),
+        },
+        Rule {
+            name: "há <duration> (pt)".to_string(),
+            pattern: vec![regex("\\bh[áa]\\b"), dim(DimensionKind::Duration), regex("atr[áa]s")],
+            production: Box::new(|nodes| {
+                let d = match &nodes[1].token_data {
+                    TokenData::Duration(d) => d,
+                    _ => return None,
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::RelativeGrain {
+                    n: d.value.checked_neg()?,
+                    grain: d.grain,
+                })))
+            }),
+        },
         Rule {
             name: "fazem <duration> (pt)".to_string(),
             pattern: vec![regex("faz(em)?"), dim(DimensionKind::Duration)],
@@ -1113,7 +1190,7 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "dd-dd <month>(interval) (pt)".to_string(),
-            pattern: vec![regex("(\\d{1,2})\\s*(\\-|a|até)\\s*(\\d{1,2})\\s+de\\s+([[:alpha:]çãõáéíóú]+)")],
+            pattern: vec![regex("(?:(?:de|desde|entre)\\s+)?(\\d{1,2})\\s*(\\-|a|até|e)\\s*(\\d{1,2})\\s+de\\s+([[:alpha:]çãõáéíóú]+)")],
             production: Box::new(|nodes| {
                 let (d1, d2, ms) = match &nodes[0].token_data {
                     TokenData::RegexMatch(rm) => (rm.group(1)?, rm.group(3)?, rm.group(4)?),
@@ -1132,7 +1209,7 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "dd-dd <month> de (interval) (pt)".to_string(),
-            pattern: vec![regex("(\\d{1,2})\\s*(\\-|a|até)\\s*(\\d{1,2})\\s+([[:alpha:]çãõáéíóú]+)\\s+de\\s+(\\d{4})")],
+            pattern: vec![regex("(?:(?:de|desde|entre)\\s+)?(\\d{1,2})\\s*(\\-|a|até|e)\\s*(\\d{1,2})\\s+([[:alpha:]çãõáéíóú]+)\\s+de\\s+(\\d{4})")],
             production: Box::new(|nodes| {
                 let (d1, d2, ms, ys) = match &nodes[0].token_data {
                     TokenData::RegexMatch(rm) => (rm.group(1)?, rm.group(3)?, rm.group(4)?, rm.group(5)?),
@@ -2403,3 +2480,79 @@ pub fn rules() -> Vec<Rule> {
     ]);
     rules
 }
+
+fn fixed_date_rule(name: &str, pattern: &str, month: u32, day: u32) -> Rule {
+    Rule {
+        name: name.to_string(),
+        pattern: vec![regex(pattern)],
+        production: Box::new(move |_| {
+            Some(TokenData::Time(TimeData::new(TimeForm::DateMDY {
+                month,
+                day,
+                year: None,
+            })))
+        }),
+    }
+}
+
+/// Region overlays for Portuguese: national holidays that only exist in
+/// Brazil (PT-BR) or Portugal (PT-PT). The base rules' Tiradentes and
+/// Independência are Brazilian, so only PT-BR keeps them.
+pub fn locale_rules(region: Region) -> Vec<Rule> {
+    match region {
+        Region::BR => vec![
+            fixed_date_rule("Tiradentes (pt_BR)", "(dia\\s+de\\s+)?tiradentes", 4, 21),
+            fixed_date_rule(
+                "Independência do Brasil (pt_BR)",
+                "(dia\\s+da\\s+)?independ[êe]ncia(\\s+do\\s+brasil)?",
+                9,
+                7,
+            ),
+            fixed_date_rule(
+                "Dia da Consciência Negra (pt_BR)",
+                "(dia\\s+da\\s+)?consci[êe]ncia\\s+negra",
+                11,
+                20,
+            ),
+            fixed_date_rule(
+                "Dia dos Namorados (pt_BR)",
+                "dia\\s+dos\\s+namorados",
+                6,
+                12,
+            ),
+        ],
+        Region::PT => vec![
+            fixed_date_rule(
+                "Dia da Liberdade (pt_PT)",
+                "dia\\s+da\\s+liberdade|25\\s+de\\s+abril",
+                4,
+                25,
+            ),
+            fixed_date_rule(
+                "Dia de Portugal (pt_PT)",
+                "dia\\s+de\\s+portugal(,?\\s+de\\s+cam[õo]es)?",
+                6,
+                10,
+            ),
+            fixed_date_rule(
+                "Implantação da República (pt_PT)",
+                "implanta[çc][ãa]o\\s+da\\s+rep[úu]blica",
+                10,
+                5,
+            ),
+            fixed_date_rule(
+                "Restauração da Independência (pt_PT)",
+                "restaura[çc][ãa]o\\s+da\\s+independ[êe]ncia",
+                12,
+                1,
+            ),
+            fixed_date_rule(
+                "Dia dos Namorados (pt_PT)",
+                "dia\\s+dos\\s+namorados",
+                2,
+                14,
+            ),
+        ],
+        _ => Vec::new(),
+    }
+}
@@ -1,33 +1,82 @@
 use super::{TimeData, TimeForm};
-use crate::pattern::regex;
+use crate::pattern::{predicate, regex};
 use crate::types::{Rule, TokenData};
 
+/// Month names and their abbreviations; "mar" is left out as it reads as
+/// Tuesday.
+const MONTHS: &str = "ianuarie|ian|februarie|feb|martie|aprilie|apr|mai|iunie|iun|iulie|iul|august|aug|septembrie|sept?|octombrie|oct|noiembrie|noi|nov|decembrie|dec";
+
+fn parse_ro_month(s: &str) -> Option<u32> {
+    let s = s.to_lowercase();
+    let month = match s.get(..3)? {
+        "ian" => 1,
+        "feb" => 2,
+        "mar" => 3,
+        "apr" => 4,
+        "mai" => 5,
+        "iun" => 6,
+        "iul" => 7,
+        "aug" => 8,
+        "sep" => 9,
+        "oct" => 10,
+        "noi" | "nov" => 11,
+        "dec" => 12,
+        _ => return None,
+    };
+    Some(month)
+}
+
+fn time_data(td: &TokenData) -> Option<&TimeData> {
+    match td {
+        TokenData::Time(d) => Some(d),
+        _ => None,
+    }
+}
+
+fn is_day_of_week(td: &TokenData) -> bool {
+    matches!(td, TokenData::Time(d) if matches!(d.form, TimeForm::DayOfWeek(_)))
+}
+
 pub fn rules() -> Vec<Rule> {
     let mut rules = super::en::rules();
     rules.extend(vec![
         Rule {
             name: "now (ro)".to_string(),
-            pattern: vec![regex("acum")],
+            pattern: vec![regex("\\b(?:chiar\\s+)?acum\\b")],
             production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Now)))),
         },
         Rule {
             name: "today (ro)".to_string(),
-            pattern: vec![regex("azi|astăzi|astazi")],
+            pattern: vec![regex("\\b(?:azi|astăzi|astazi)\\b")],
             production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Today)))),
         },
         Rule {
             name: "tomorrow (ro)".to_string(),
-            pattern: vec![regex("m[âa]ine")],
+            pattern: vec![regex("\\bm[âa]ine\\b")],
             production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Tomorrow)))),
         },
         Rule {
             name: "yesterday (ro)".to_string(),
-            pattern: vec![regex("ieri")],
+            pattern: vec![regex("\\bieri\\b")],
             production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Yesterday)))),
         },
+        Rule {
+            name: "day after tomorrow (ro)".to_string(),
+            pattern: vec![regex("\\bpoim[âa]ine\\b")],
+            production: Box::new(|_| {
+                Some(TokenData::Time(TimeData::new(TimeForm::DayAfterTomorrow)))
+            }),
+        },
+        Rule {
+            name: "day before yesterday (ro)".to_string(),
+            pattern: vec![regex("\\balalt(?:ă|a)ieri\\b")],
+            production: Box::new(|_| {
+                Some(TokenData::Time(TimeData::new(TimeForm::DayBeforeYesterday)))
+            }),
+        },
         Rule {
             name: "day of week (ro)".to_string(),
-            pattern: vec![regex("lu(n(ea|i)?)?|ma(r((t|ț)(ea|i))?)?|mi(e(rcur(ea|i))?)?|jo(ia?)?|vi(n(er(ea|i))?)?|s(a|â)mb(a|ă)t(a|ă)|s(a|â)m|du(m(inic(a|ă))?)?")],
+            pattern: vec![regex("\\b(?:lu(n(ea|i)?)?|ma(r((t|ț)(ea|i))?)?|mi(e(rcur(ea|i))?)?|jo(ia?)?|vi(n(er(ea|i))?)?|s(a|â)mb(a|ă)t(a|ă)|s(a|â)m|du(m(inic(a|ă))?)?)\\b")],
             production: Box::new(|nodes| {
                 let s = match &nodes[0].token_data {
                     TokenData::RegexMatch(m) => m.group(0)?.to_lowercase(),
@@ -55,33 +104,109 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "lunea asta (ro)".to_string(),
-            pattern: vec![regex("lunea asta|lunea aceasta")],
+            pattern: vec![regex("\\blunea (?:asta|aceasta)\\b")],
             production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::DayOfWeek(0))))),
         },
         Rule {
-            name: "iunie 19-20 (ro)".to_string(),
-            pattern: vec![regex("iunie\\s+(\\d{1,2})-(\\d{1,2})")],
+            name: "month (ro)".to_string(),
+            pattern: vec![regex(&format!("\\b([îi]n\\s+)?({MONTHS})\\.?\\b"))],
             production: Box::new(|nodes| {
-                let d = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(1)?,
+                let (prefix, s) = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => (m.group(1), m.group(2)?),
                     _ => return None,
                 };
-                let day: u32 = d.parse().ok()?;
+                // Bare "mai" is far more often "more" than May
+                if prefix.is_none() && s.eq_ignore_ascii_case("mai") {
+                    return None;
+                }
+                Some(TokenData::Time(TimeData::new(TimeForm::Month(parse_ro_month(s)?))))
+            }),
+        },
+        // "pe 3 martie", "1 martie 2025", "întâi martie"
+        Rule {
+            name: "[pe] <day> <month> [<year>] (ro)".to_string(),
+            pattern: vec![regex(&format!(
+                "\\b(?:pe\\s+)?(\\d{{1,2}}|[îi]nt[âa]i)\\s+({MONTHS})\\.?(?:\\s+(\\d{{4}}))?\\b"
+            ))],
+            production: Box::new(|nodes| {
+                let (d, mo, y) = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => (m.group(1)?, m.group(2)?, m.group(3)),
+                    _ => return None,
+                };
+                let day: u32 = d.parse().unwrap_or(1);
                 if !(1..=31).contains(&day) {
                     return None;
                 }
-                Some(TokenData::Time(TimeData::new(TimeForm::DateMDY { month: 6, day, year: None })))
+                let year = match y {
+                    Some(y) => Some(y.parse().ok()?),
+                    None => None,
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::DateMDY {
+                    month: parse_ro_month(mo)?,
+                    day,
+                    year,
+                })))
             }),
         },
+        // "Marti 19", "Marti pe 19"
         Rule {
-            name: "christmas day (ro)".to_string(),
-            pattern: vec![regex("ziua de craciun|ziua de crăciun")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::DateMDY { month: 12, day: 25, year: None })))),
+            name: "<day-of-week> [pe] <day> (ro)".to_string(),
+            pattern: vec![predicate(is_day_of_week), regex("\\b(?:pe\\s+)?(\\d{1,2})\\b")],
+            production: Box::new(|nodes| {
+                let d = match &nodes[1].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?,
+                    _ => return None,
+                };
+                let day: u32 = d.parse().ok()?;
+                if !(1..=31).contains(&day) {
+                    return None;
+                }
+                let dow = time_data(&nodes[0].token_data)?.clone();
+                Some(TokenData::Time(TimeData::new(TimeForm::Composed(
+                    Box::new(dow),
+                    Box::new(TimeData::new(TimeForm::DayOfMonth(day))),
+                ))))
+            }),
         },
+        // Romania keeps the Orthodox Easter cycle, but Christmas on December 25
         Rule {
-            name: "craciun (ro)".to_string(),
-            pattern: vec![regex("craciun|crăciun")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::DateMDY { month: 12, day: 25, year: None })))),
+            name: "holidays (ro)".to_string(),
+            pattern: vec![regex("\\b(?:(?:ziua\\s+de\\s+)?cr[ăa]ciun(?:ul)?|ajunul\\s+cr[ăa]ciunului|anul\\s+nou|revelion(?:ul)?|ziua\\s+na[țţt]ional[ăa](?:\\s+a\\s+rom[âa]niei)?|(?:sf\\.?|sf[âa]ntul)\\s+andrei|(?:a\\s+doua\\s+zi\\s+de\\s+)?pa[șşs]t[ei](?:le)?|vinerea\\s+mare|floriile|florii|[îi]n[ăa]l[țţt]area\\s+domnului|rusaliile|rusalii)\\b")],
+            production: Box::new(|nodes| {
+                let s = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(0)?.to_lowercase(),
+                    _ => return None,
+                };
+                let name = if s.starts_with("ajunul") {
+                    "christmas eve"
+                } else if s.contains("ciun") {
+                    "christmas"
+                } else if s.starts_with("anul") {
+                    "new year's day"
+                } else if s.starts_with("revelion") {
+                    "new year's eve"
+                } else if s.contains("na") && s.starts_with("ziua") {
+                    "great union day"
+                } else if s.contains("andrei") {
+                    "st. andrew's day"
+                } else if s.starts_with("a doua") {
+                    "orthodox easter monday"
+                } else if s.starts_with("vinerea") {
+                    "orthodox good friday"
+                } else if s.starts_with("flori") {
+                    "orthodox palm sunday"
+                } else if s.contains("domnului") {
+                    "orthodox ascension"
+                } else if s.starts_with("rusalii") {
+                    "orthodox pentecost"
+                } else {
+                    "orthodox easter"
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::Holiday(
+                    name.to_string(),
+                    None,
+                ))))
+            }),
         },
     ]);
     rules
@@ -55,6 +55,17 @@ fn is_month(td: &TokenData) -> bool {
     )
 }
 
+fn has_explicit_year(td: &TokenData) -> bool {
+    matches!(
+        td,
+        TokenData::Time(TimeData {
+            form: TimeForm::Year(..) | TimeForm::DateMDY { year: Some(_), .. },
+            latent: false,
+            ..
+        })
+    )
+}
+
 fn ru_month_num(s: &str) -> Option<u32> {
     let t = s.trim().to_lowercase().replace('ё', "е").replace('.', "");
     if t.starts_with("январ") || t.starts_with("янв") {
@@ -65,7 +76,7 @@ fn ru_month_num(s: &str) -> Option<u32> {
         Some(3)
     } else if t.starts_with("апрел") || t.starts_with("апр") {
         Some(4)
-    } else if t == "май" || t == "мая" {
+    } else if t == "май" || t == "мая" || t == "мае" {
         Some(5)
     } else if t.starts_with("июн") {
         Some(6)
@@ -109,6 +120,30 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("вчера")],
             production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Yesterday)))),
         },
+        Rule {
+            name: "(после)+завтра | (поза)+вчера (ru)".to_string(),
+            pattern: vec![regex("((?:после)+)завтра|((?:поза)+)вчера")],
+            production: Box::new(|nodes| {
+                let offset = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => match (m.group(1), m.group(2)) {
+                        (Some(after), _) => {
+                            let n = after.to_lowercase().matches("после").count();
+                            i32::try_from(n).ok()?.checked_add(1)?
+                        }
+                        (_, Some(before)) => {
+                            let n = before.to_lowercase().matches("поза").count();
+                            i32::try_from(n).ok()?.checked_add(1)?.checked_neg()?
+                        }
+                        _ => return None,
+                    },
+                    _ => return None,
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset {
+                    grain: Grain::Day,
+                    offset,
+                })))
+            }),
+        },
         Rule {
             name: "day of week (ru)".to_string(),
             pattern: vec![regex("понедельник(а)?|пн|вторник(а)?|вт|сред(а|у)|ср|четверг(а)?|чт|пятниц(а|у)|пт|суббот(а|у)|сб|воскресенье|вс|в\\s+пятницу")],
@@ -166,7 +201,7 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "on <date> (ru)".to_string(),
-            pattern: vec![regex("(на|в)"), predicate(is_not_latent_time)],
+            pattern: vec![regex("(на|во|в|ко|к)"), predicate(is_not_latent_time)],
             production: Box::new(|nodes| match &nodes[1].token_data {
                 TokenData::Time(td) => Some(TokenData::Time(td.clone())),
                 _ => None,
@@ -332,7 +367,7 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "<named-month> (ru)".to_string(),
-            pattern: vec![regex("январ(ь|я)|янв\\.?|феврал(ь|я)|фев\\.?|март(а)?|мар\\.?|апрел(ь|я)|апр\\.?|ма(й|я)|июн(ь|я)|июн\\.?|июл(ь|я)|июл\\.?|август(а)?|авг\\.?|сентябр(ь|я)|сен\\.?|октябр(ь|я)|окт\\.?|ноябр(ь|я)?|ноя\\.?|декабр(ь|я)|дек\\.?")],
+            pattern: vec![regex("январ(ь|я|е)|янв\\.?|феврал(ь|я|е)|фев\\.?|март(а|е)?|мар\\.?|апрел(ь|я|е)|апр\\.?|ма(й|я|е)|июн(ь|я|е)|июн\\.?|июл(ь|я|е)|июл\\.?|август(а|е)?|авг\\.?|сентябр(ь|я|е)|сен\\.?|октябр(ь|я|е)|окт\\.?|ноябр(ь|я|е)?|ноя\\.?|декабр(ь|я|е)|дек\\.?")],
             production: Box::new(|nodes| {
                 let s = match &nodes[0].token_data {
                     TokenData::RegexMatch(m) => m.group(0)?,
@@ -1099,12 +1134,71 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "новый год (ru)".to_string(),
-            pattern: vec![regex("новый\\s+год")],
+            pattern: vec![regex("нов(ый|ого|ому|ым|ом)\\s+год(а|у|ом|е)?")],
             production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Holiday(
-                "new year".to_string(),
+                "new year's day".to_string(),
                 None,
             ))))),
         },
+        Rule {
+            name: "день защитника отечества (ru)".to_string(),
+            pattern: vec![regex("(день|дн(я|ю|ём|ем|е))\\s+защитника\\s+отечества")],
+            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::DateMDY {
+                month: 2,
+                day: 23,
+                year: None,
+            })))),
+        },
+        Rule {
+            name: "международный женский день (ru)".to_string(),
+            pattern: vec![regex("международн(ый|ого|ому|ым|ом)\\s+женск(ий|ого|ому|им|ом)\\s+(день|дн(я|ю|ём|ем|е))")],
+            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::DateMDY {
+                month: 3,
+                day: 8,
+                year: None,
+            })))),
+        },
+        Rule {
+            name: "праздник весны и труда (ru)".to_string(),
+            pattern: vec![regex("праздник(а|у|ом|е)?\\s+весны\\s+и\\s+труда")],
+            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::DateMDY {
+                month: 5,
+                day: 1,
+                year: None,
+            })))),
+        },
+        Rule {
+            name: "день победы (ru)".to_string(),
+            pattern: vec![regex("(день|дн(я|ю|ём|ем|е))\\s+победы")],
+            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::DateMDY {
+                month: 5,
+                day: 9,
+                year: None,
+            })))),
+        },
+        Rule {
+            name: "день россии (ru)".to_string(),
+            pattern: vec![regex("(день|дн(я|ю|ём|ем|е))\\s+россии")],
+            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::DateMDY {
+                month: 6,
+                day: 12,
+                year: None,
+            })))),
+        },
+        Rule {
+            name: "день народного единства (ru)".to_string(),
+            pattern: vec![regex("(день|дн(я|ю|ём|ем|е))\\s+народного\\s+единства")],
+            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::DateMDY {
+                month: 11,
+                day: 4,
+                year: None,
+            })))),
+        },
+        Rule {
+            name: "<date with year> года (ru)".to_string(),
+            pattern: vec![predicate(has_explicit_year), regex("год(а|у)?|г\\.")],
+            production: Box::new(|nodes| Some(TokenData::Time(time_data(&nodes[0].token_data)?.clone()))),
+        },
         Rule {
             name: "сегодня вечером (ru)".to_string(),
             pattern: vec![regex("сегодня\\s+в\\s+вечер(а|ом)|сегодня\\s+вечером")],
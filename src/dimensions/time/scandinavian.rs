@@ -0,0 +1,229 @@
+// Time rules Swedish, Norwegian Bokmål and Danish build the same way,
+// written once and filled in with each language's words.
+
+use super::{clock_hour_offset, is_clock_hour, Direction, TimeData, TimeForm};
+use crate::dimensions::numeral::helpers::integer_value;
+use crate::pattern::{predicate, regex};
+use crate::types::{Rule, TokenData};
+
+/// The words a Scandinavian language uses for the shared rules. Every
+/// entry is a regex alternation without capture groups.
+pub(crate) struct Lexicon {
+    /// Language code put after rule names, e.g. "sv".
+    pub lang: &'static str,
+    pub today: &'static str,
+    pub tomorrow: &'static str,
+    pub yesterday: &'static str,
+    pub day_after_tomorrow: &'static str,
+    pub day_before_yesterday: &'static str,
+    /// Weekday names and abbreviations, Monday first.
+    pub weekdays: [&'static str; 7],
+    /// "nästa", "neste", "næste"
+    pub next: &'static str,
+    /// "förra", "forrige", "sidste"
+    pub last: &'static str,
+    /// "klockan", "klokken", "kl."
+    pub clock: &'static str,
+    /// "minuter", "minutter"
+    pub minutes: &'static str,
+    /// "över", "over": minutes past the hour
+    pub past: &'static str,
+    /// "i", "på": minutes to the hour
+    pub to: &'static str,
+    /// Holiday names, with the key the holiday tables know them by.
+    pub holidays: &'static [(&'static str, &'static str)],
+}
+
+fn time_data(td: &TokenData) -> Option<&TimeData> {
+    match td {
+        TokenData::Time(d) => Some(d),
+        _ => None,
+    }
+}
+
+/// Minutes counted to or past an hour or half hour: "fem", "20".
+fn is_minute_count(td: &TokenData) -> bool {
+    integer_value(td).is_some_and(|v| (1..=29).contains(&v))
+}
+
+/// `minutes` past the hour when a "past" or "to" match captured its first
+/// group, the past words, and before it otherwise.
+fn signed_minutes(td: &TokenData, minutes: i32) -> Option<i32> {
+    match td {
+        TokenData::RegexMatch(m) if m.group(1).is_some() => Some(minutes),
+        TokenData::RegexMatch(_) => minutes.checked_neg(),
+        _ => None,
+    }
+}
+
+fn is_minute_time(td: &TokenData) -> bool {
+    matches!(td, TokenData::Time(d) if matches!(d.form, TimeForm::HourMinute(..)))
+}
+
+fn is_day_of_week(td: &TokenData) -> bool {
+    matches!(td, TokenData::Time(d) if matches!(d.form, TimeForm::DayOfWeek(_)))
+}
+
+fn day_rule(lang: &str, name: &str, words: &str, form: fn() -> TimeForm) -> Rule {
+    Rule {
+        name: format!("{name} ({lang})"),
+        pattern: vec![regex(&format!(r"\b(?:{words})\b"))],
+        production: Box::new(move |_| Some(TokenData::Time(TimeData::new(form())))),
+    }
+}
+
+fn direction_rule(lang: &str, name: &str, words: &str, direction: Direction) -> Rule {
+    Rule {
+        name: format!("{name} <day-of-week> ({lang})"),
+        pattern: vec![
+            regex(&format!(r"\b(?:{words})\b")),
+            predicate(is_day_of_week),
+        ],
+        production: Box::new(move |nodes| {
+            let mut t = time_data(&nodes[1].token_data)?.clone();
+            t.direction = Some(direction);
+            t.not_immediate = direction == Direction::Future;
+            Some(TokenData::Time(t))
+        }),
+    }
+}
+
+pub(crate) fn rules(lex: &Lexicon) -> Vec<Rule> {
+    let lang = lex.lang;
+    let mut rules = vec![
+        day_rule(lang, "today", lex.today, || TimeForm::Today),
+        day_rule(lang, "tomorrow", lex.tomorrow, || TimeForm::Tomorrow),
+        day_rule(lang, "yesterday", lex.yesterday, || TimeForm::Yesterday),
+        day_rule(lang, "day after tomorrow", lex.day_after_tomorrow, || {
+            TimeForm::DayAfterTomorrow
+        }),
+        day_rule(
+            lang,
+            "day before yesterday",
+            lex.day_before_yesterday,
+            || TimeForm::DayBeforeYesterday,
+        ),
+        Rule {
+            name: format!("day of week ({lang})"),
+            pattern: vec![regex(&format!(
+                r"\b(?:{})\b\.?",
+                lex.weekdays.map(|w| format!("({w})")).join("|")
+            ))],
+            production: Box::new(|nodes| {
+                let m = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m,
+                    _ => return None,
+                };
+                let group = (1..=7u32).find(|&i| m.group(i as usize).is_some())?;
+                Some(TokenData::Time(TimeData::new(TimeForm::DayOfWeek(
+                    group.checked_sub(1)?,
+                ))))
+            }),
+        },
+        direction_rule(lang, "next", lex.next, Direction::Future),
+        direction_rule(lang, "last", lex.last, Direction::Past),
+        Rule {
+            name: format!("{} <hour>[:mm] ({lang})", lex.clock),
+            pattern: vec![regex(&format!(
+                r"\b(?:{})\.?\s*(\d{{1,2}})(?:[:.](\d{{2}}))?\b",
+                lex.clock
+            ))],
+            production: Box::new(|nodes| {
+                let (h, m) = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => (m.group(1)?, m.group(2)),
+                    _ => return None,
+                };
+                let hour: u32 = h.parse().ok()?;
+                let minute: u32 = m.map_or(Some(0), |m| m.parse().ok())?;
+                if hour > 23 || minute > 59 {
+                    return None;
+                }
+                // "kl 3" may be in the afternoon, "kl 3:30" is read as written
+                let is_12h = m.is_none() && hour <= 12;
+                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(
+                    hour, minute, is_12h,
+                ))))
+            }),
+        },
+        // "kvart över fem" is 5:15, "kvart i fem" 4:45
+        Rule {
+            name: format!("kvart past|to <hour> ({lang})"),
+            pattern: vec![
+                regex(&format!(
+                    r"\bkvart(?:er)?\s+(?:({})|(?:{}))\b",
+                    lex.past, lex.to
+                )),
+                predicate(is_clock_hour),
+            ],
+            production: Box::new(|nodes| {
+                let minutes = signed_minutes(&nodes[0].token_data, 15)?;
+                clock_hour_offset(&nodes[1].token_data, minutes)
+            }),
+        },
+        // "halv fem" is half an hour before five, 4:30
+        Rule {
+            name: format!("halv <hour> ({lang})"),
+            pattern: vec![regex(r"\bhalv\b"), predicate(is_clock_hour)],
+            production: Box::new(|nodes| clock_hour_offset(&nodes[1].token_data, -30)),
+        },
+        // "tio över fem" is 5:10, "tio i fem" 4:50
+        Rule {
+            name: format!("<minutes> past|to <hour> ({lang})"),
+            pattern: vec![
+                predicate(is_minute_count),
+                regex(&format!(
+                    r"\b(?:(?:{})\s+)?(?:({})|(?:{}))\b",
+                    lex.minutes, lex.past, lex.to
+                )),
+                predicate(is_clock_hour),
+            ],
+            production: Box::new(|nodes| {
+                let minutes = i32::try_from(integer_value(&nodes[0].token_data)?).ok()?;
+                let minutes = signed_minutes(&nodes[1].token_data, minutes)?;
+                clock_hour_offset(&nodes[2].token_data, minutes)
+            }),
+        },
+        // "fem i halv fem" is 4:25, "fem över halv fem" 4:35
+        Rule {
+            name: format!("<minutes> past|to halv <hour> ({lang})"),
+            pattern: vec![
+                predicate(is_minute_count),
+                regex(&format!(
+                    r"\b(?:(?:{})\s+)?(?:({})|(?:{}))\s+halv\b",
+                    lex.minutes, lex.past, lex.to
+                )),
+                predicate(is_clock_hour),
+            ],
+            production: Box::new(|nodes| {
+                let minutes = i32::try_from(integer_value(&nodes[0].token_data)?).ok()?;
+                let minutes = signed_minutes(&nodes[1].token_data, minutes)?;
+                clock_hour_offset(&nodes[2].token_data, minutes.checked_sub(30)?)
+            }),
+        },
+        // "kl. 20 över 15", "klokka kvart på fem"
+        Rule {
+            name: format!("{} <time-of-day> ({lang})", lex.clock),
+            pattern: vec![
+                regex(&format!(r"\b(?:{})\b\.?", lex.clock)),
+                predicate(is_minute_time),
+            ],
+            production: Box::new(|nodes| match &nodes[1].token_data {
+                TokenData::Time(d) => Some(TokenData::Time(d.clone())),
+                _ => None,
+            }),
+        },
+    ];
+    for &(words, key) in lex.holidays {
+        rules.push(Rule {
+            name: format!("{key} ({lang})"),
+            pattern: vec![regex(&format!(r"\b(?:{words})\b"))],
+            production: Box::new(move |_| {
+                Some(TokenData::Time(TimeData::new(TimeForm::Holiday(
+                    key.to_string(),
+                    None,
+                ))))
+            }),
+        });
+    }
+    rules
+}
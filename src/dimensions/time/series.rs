@@ -4,10 +4,11 @@
 
 use super::{
     add_grain, grain_start, pod_interval, resolve_holiday, resolve_holiday_interval,
-    resolve_holiday_minute_interval, resolve_season_interval, resolve_simple_datetime,
+    resolve_holiday_time_interval, resolve_season_interval, resolve_simple_datetime,
     resolve_weekend_interval, Direction, EarlyLate, PartOfDay, TimeData, TimeForm,
 };
 use crate::dimensions::time_grain::Grain;
+use crate::resolve::PartOfDayConfig;
 use chrono::{DateTime, Datelike, NaiveDate, Timelike, Utc};
 
 /// Internal representation matching Haskell's `TimeObject { start, grain, end }`.
@@ -295,9 +296,10 @@ fn series_part_of_day(
     pod: PartOfDay,
     ref_time: &TimeObject,
     early_late: Option<EarlyLate>,
+    pods: &PartOfDayConfig,
 ) -> (Vec<TimeObject>, Vec<TimeObject>) {
     let date = ref_time.start.date_naive();
-    let (from, to) = pod_interval(pod, date, early_late);
+    let (from, to) = pod_interval(pod, date, early_late, pods);
     let anchor = TimeObject {
         start: from,
         grain: Grain::Hour,
@@ -479,10 +481,10 @@ fn series_holiday(
             } else {
                 past.push(obj);
             }
-        } else if let Some((from_dt, to_dt)) = resolve_holiday_minute_interval(name, y) {
+        } else if let Some((from_dt, to_dt, grain)) = resolve_holiday_time_interval(name, y) {
             let obj = TimeObject {
                 start: from_dt,
-                grain: Grain::Minute,
+                grain,
                 end: Some(to_dt),
             };
             if obj.start >= ref_time.start {
@@ -650,6 +652,7 @@ fn run_compose(
 pub(crate) fn generate_series(
     data: &TimeData,
     ref_time: DateTime<Utc>,
+    pods: &PartOfDayConfig,
 ) -> (Vec<TimeObject>, Vec<TimeObject>) {
     let ref_obj = TimeObject {
         start: ref_time,
@@ -664,7 +667,7 @@ pub(crate) fn generate_series(
         TimeForm::HourMinute(h, m, is_12h) => series_hour_minute(*h, *m, *is_12h, &ref_obj),
         TimeForm::DayOfMonth(d) => series_day_of_month(*d, &ref_obj),
         TimeForm::Year(y) => series_year(*y, &ref_obj),
-        TimeForm::PartOfDay(pod) => series_part_of_day(*pod, &ref_obj, data.early_late),
+        TimeForm::PartOfDay(pod) => series_part_of_day(*pod, &ref_obj, data.early_late, pods),
         TimeForm::Weekend => series_weekend(&ref_obj, data.direction),
         TimeForm::Season(s) => series_season(*s, &ref_obj, data.direction),
         TimeForm::Holiday(name, year_opt) => {
@@ -677,12 +680,16 @@ pub(crate) fn generate_series(
             let p = primary.clone();
             let s = secondary.clone();
             run_compose(
-                &|t| generate_series(&p, t.start),
-                &|t| generate_series(&s, t.start),
+                &|t| generate_series(&p, t.start, pods),
+                &|t| generate_series(&s, t.start, pods),
                 &ref_obj,
             )
         }
 
+        // One instant, in UTC rather than the context's local time; `resolve`
+        // reports it as the only value
+        TimeForm::Timestamp(_) => (vec![], vec![]),
+
         // Single-value forms: use existing resolve_simple_datetime
         _ => match resolve_simple_datetime(&data.form, ref_time, data.direction) {
             Some((dt, grain_str)) => {
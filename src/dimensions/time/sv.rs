@@ -1,3 +1,4 @@
+use super::scandinavian::Lexicon;
 use super::{Direction, IntervalDirection};
 use super::{PartOfDay, TimeData, TimeForm};
 use crate::dimensions::time_grain::Grain;
@@ -45,35 +46,6 @@ fn parse_sv_ordinal_day(s: &str) -> Option<u32> {
     }
 }
 
-fn parse_sv_small_minutes(s: &str) -> Option<u32> {
-    match s {
-        "fem" => Some(5),
-        "tio" => Some(10),
-        "femton" => Some(15),
-        "tjugo" => Some(20),
-        "tjugofem" => Some(25),
-        _ => s.parse().ok(),
-    }
-}
-
-fn parse_sv_hour_token(s: &str) -> Option<u32> {
-    match s {
-        "ett" | "en" => Some(1),
-        "två" | "tva" => Some(2),
-        "tre" => Some(3),
-        "fyra" => Some(4),
-        "fem" => Some(5),
-        "sex" => Some(6),
-        "sju" => Some(7),
-        "åtta" | "atta" => Some(8),
-        "nio" => Some(9),
-        "tio" => Some(10),
-        "elva" => Some(11),
-        "tolv" => Some(12),
-        _ => s.parse().ok(),
-    }
-}
-
 fn parse_sv_qty(s: &str) -> Option<i64> {
     match s {
         "en" | "ett" => Some(1),
@@ -112,24 +84,43 @@ fn parse_sv_grain(s: &str) -> Option<Grain> {
     }
 }
 
+const LEXICON: Lexicon = Lexicon {
+    lang: "sv",
+    today: r"i\s*dag",
+    tomorrow: r"i\s*morgon",
+    yesterday: r"i\s*g[åa]r",
+    day_after_tomorrow: r"(?:i\s+)?[öo]vermorgon",
+    day_before_yesterday: r"(?:i\s+)?f[öo]rrg[åa]r",
+    weekdays: [
+        r"m[åa]ndag(?:en)?s?|mån",
+        r"tisdag(?:en)?s?|tis?",
+        r"onsdag(?:en)?s?|ons",
+        r"torsdag(?:en)?s?|tors?",
+        r"fredag(?:en)?s?|fre",
+        r"l[öo]rdag(?:en)?s?|lör",
+        r"s[öo]ndag(?:en)?s?|sön",
+    ],
+    next: r"n[äa]sta|kommande",
+    last: r"f[öo]rra|senaste",
+    clock: r"klockan|kl",
+    minutes: r"minuter?",
+    past: r"[öo]ver",
+    to: r"i",
+    holidays: &[
+        (r"midsommarafton|midsommar", "midsummer eve"),
+        (r"midsommardag(?:en)?", "midsummer day"),
+    ],
+};
+
 pub fn rules() -> Vec<Rule> {
     let mut rules = super::en::rules();
+    rules.extend(super::scandinavian::rules(&LEXICON));
     rules.extend(vec![
         Rule {
             name: "now (sv)".to_string(),
             pattern: vec![regex("nu")],
             production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Now)))),
         },
-        Rule {
-            name: "today (sv)".to_string(),
-            pattern: vec![regex("idag|i dag")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Today)))),
-        },
-        Rule {
-            name: "tomorrow (sv)".to_string(),
-            pattern: vec![regex("imorgon|i morgon")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Tomorrow)))),
-        },
         Rule {
             name: "part-of-day keywords (sv)".to_string(),
             pattern: vec![regex("morgon(en)?|lunch(en)?|eftermiddag(en)?|kv[äa]ll(en)?|natt(en)?")],
@@ -172,72 +163,6 @@ pub fn rules() -> Vec<Rule> {
                 ))))
             }),
         },
-        Rule {
-            name: "yesterday (sv)".to_string(),
-            pattern: vec![regex("ig[åa]r|i g[åa]r")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Yesterday)))),
-        },
-        Rule {
-            name: "day before yesterday / day after tomorrow (sv)".to_string(),
-            pattern: vec![regex("i f[öo]rrg[åa]r|f[öo]rrg[åa]r|i [öo]vermorgon|[öo]vermorgon")],
-            production: Box::new(|nodes| {
-                let s = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(0)?.to_lowercase(),
-                    _ => return None,
-                };
-                if s.contains("förr") || s.contains("forr") {
-                    Some(TokenData::Time(TimeData::new(TimeForm::DayBeforeYesterday)))
-                } else {
-                    Some(TokenData::Time(TimeData::new(TimeForm::DayAfterTomorrow)))
-                }
-            }),
-        },
-        Rule {
-            name: "day of week (sv)".to_string(),
-            pattern: vec![regex("måndag(en)?s?|mån\\.?|tisdag(en)?s?|tis?\\.?|onsdag(en)?s?|ons\\.?|torsdag(en)?s?|tors?\\.?|fredag(en)?s?|fre\\.?|lördag(en)?s?|lör\\.?|söndag(en)?s?|sön\\.?")],
-            production: Box::new(|nodes| {
-                let s = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(0)?.to_lowercase(),
-                    _ => return None,
-                };
-                let dow = if s.contains("måndag")
-                    || s.contains("mandag")
-                    || s == "mån"
-                    || s == "man"
-                    || s == "mån."
-                    || s == "man."
-                {
-                    0
-                } else if s.contains("tisdag") {
-                    1
-                } else if s.contains("onsdag") {
-                    2
-                } else if s.contains("torsdag") || s == "tors" || s == "tors." {
-                    3
-                } else if s.contains("fredag") || s == "fre" || s == "fre." {
-                    4
-                } else if s.contains("lördag")
-                    || s.contains("lordag")
-                    || s == "lör"
-                    || s == "lör."
-                    || s == "lor"
-                    || s == "lor."
-                {
-                    5
-                } else if s.contains("söndag")
-                    || s.contains("sondag")
-                    || s == "sön"
-                    || s == "sön."
-                    || s == "son"
-                    || s == "son."
-                {
-                    6
-                } else {
-                    return None;
-                };
-                Some(TokenData::Time(TimeData::new(TimeForm::DayOfWeek(dow))))
-            }),
-        },
         Rule {
             name: "den förste mars (sv)".to_string(),
             pattern: vec![regex("den\\s+f[öo]rste\\s+mars|den\\s+f[öo]rsta\\s+mars")],
@@ -525,6 +450,18 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("n[äa]sta vecka")],
             production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset { grain: Grain::Week, offset: 1 })))),
         },
+        Rule {
+            name: "förra/nästa månad (sv)".to_string(),
+            pattern: vec![regex("\\b(f[öo]rra|n[äa]sta)\\s+m[åa]nad(?:en)?\\b")],
+            production: Box::new(|nodes| {
+                let s = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?.to_lowercase(),
+                    _ => return None,
+                };
+                let offset = if s.starts_with('f') { -1 } else { 1 };
+                Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset { grain: Grain::Month, offset })))
+            }),
+        },
         Rule {
             name: "helg (sv)".to_string(),
             pattern: vec![regex("helg(en)?|weekend")],
@@ -692,37 +629,6 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("n[äa]sta [åa]r(et)?")],
             production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset { grain: Grain::Year, offset: 1 })))),
         },
-        Rule {
-            name: "klockan/kl <hour> (sv)".to_string(),
-            pattern: vec![regex("klockan\\s*(\\d{1,2})|kl\\.?\\s*(\\d{1,2})")],
-            production: Box::new(|nodes| {
-                let h = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(1).or_else(|| m.group(2))?,
-                    _ => return None,
-                };
-                let hour: u32 = h.parse().ok()?;
-                if hour > 23 {
-                    return None;
-                }
-                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(hour, 0, true))))
-            }),
-        },
-        Rule {
-            name: "klockan/kl <hh:mm> (sv)".to_string(),
-            pattern: vec![regex("(?:klockan|kl\\.?)\\s*(\\d{1,2})[:\\.](\\d{2})")],
-            production: Box::new(|nodes| {
-                let (h, m) = match &nodes[0].token_data {
-                    TokenData::RegexMatch(rm) => (rm.group(1)?, rm.group(2)?),
-                    _ => return None,
-                };
-                let hour: u32 = h.parse().ok()?;
-                let minute: u32 = m.parse().ok()?;
-                if hour > 23 || minute > 59 {
-                    return None;
-                }
-                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(hour, minute, false))))
-            }),
-        },
         Rule {
             name: "@/kl/klockan <hour>[:mm] <timezone> (sv)".to_string(),
             pattern: vec![regex("(@\\s*)?(kl(ockan|\\.)?\\s*)?(\\d{1,2})(:(\\d{2}))?\\s*(CET|CEST|GMT|UTC|cet|cest|gmt|utc)")],
@@ -745,53 +651,6 @@ pub fn rules() -> Vec<Rule> {
                 Some(TokenData::Time(t))
             }),
         },
-        Rule {
-            name: "kvart över <hour> (sv)".to_string(),
-            pattern: vec![regex("kvart\\s+[öo]ver\\s*(\\d{1,2})")],
-            production: Box::new(|nodes| {
-                let h = match &nodes[0].token_data {
-                    TokenData::RegexMatch(rm) => rm.group(1)?,
-                    _ => return None,
-                };
-                let hour: u32 = h.parse().ok()?;
-                if hour > 23 {
-                    return None;
-                }
-                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(hour, 15, false))))
-            }),
-        },
-        Rule {
-            name: "kvart i <hour> (sv)".to_string(),
-            pattern: vec![regex("kvart\\s+i\\s*(\\d{1,2}|ett|en|tv[åa]|tre|fyra|fem|sex|sju|[åa]tta|nio|tio|elva|tolv)")],
-            production: Box::new(|nodes| {
-                let h = match &nodes[0].token_data {
-                    TokenData::RegexMatch(rm) => rm.group(1)?.to_lowercase(),
-                    _ => return None,
-                };
-                let in_hour = parse_sv_hour_token(&h)?;
-                if in_hour == 0 || in_hour > 24 {
-                    return None;
-                }
-                let hour = if in_hour == 1 { 0 } else { in_hour.checked_sub(1)? };
-                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(hour, 45, false))))
-            }),
-        },
-        Rule {
-            name: "<minutes> över <hour> (sv)".to_string(),
-            pattern: vec![regex("(fem|tio|femton|tjugo|tjugofem|\\d{1,2})\\s+[öo]ver\\s*(\\d{1,2}|ett|en|tv[åa]|tre|fyra|fem|sex|sju|[åa]tta|nio|tio|elva|tolv)")],
-            production: Box::new(|nodes| {
-                let (m_s, h_s) = match &nodes[0].token_data {
-                    TokenData::RegexMatch(rm) => (rm.group(1)?.to_lowercase(), rm.group(2)?.to_lowercase()),
-                    _ => return None,
-                };
-                let mins = parse_sv_small_minutes(&m_s)?;
-                let hour = parse_sv_hour_token(&h_s)?;
-                if hour > 23 || mins > 59 {
-                    return None;
-                }
-                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(hour, mins, false))))
-            }),
-        },
         Rule {
             name: "om/i <duration> (sv)".to_string(),
             pattern: vec![regex("om|i"), dim(DimensionKind::Duration)],
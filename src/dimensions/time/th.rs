@@ -0,0 +1,416 @@
+use super::{TimeData, TimeForm};
+use crate::dimensions::duration::DurationQualifier;
+use crate::dimensions::numeral::helpers::integer_value;
+use crate::dimensions::time_grain::Grain;
+use crate::pattern::{predicate, regex};
+use crate::types::{Rule, TokenData};
+
+/// Weekday names, "วัน" optional except before "อาทิตย์", which alone is
+/// also "week".
+const DAYS: &str = "วันอาทิตย์|(?:วัน)?(?:จันทร์|อังคาร|พุธ|พฤหัสบดี|พฤหัส|ศุกร์|เสาร์)";
+
+const MONTHS: &str = "มกราคม|กุมภาพันธ์|มีนาคม|เมษายน|พฤษภาคม|มิถุนายน|กรกฎาคม|สิงหาคม|กันยายน|ตุลาคม|พฤศจิกายน|ธันวาคม|ม\\.ค\\.|ก\\.พ\\.|มี\\.ค\\.|เม\\.ย\\.|พ\\.ค\\.|มิ\\.ย\\.|ก\\.ค\\.|ส\\.ค\\.|ก\\.ย\\.|ต\\.ค\\.|พ\\.ย\\.|ธ\\.ค\\.";
+
+const GRAINS: &str = "วินาที|นาที|ชั่วโมง|วัน|สัปดาห์|อาทิตย์|เดือน|ปี";
+
+/// Bare years taken as Buddhist era in "<date> <year>": past any common-era
+/// year a date is likely to name, and short of any Buddhist-era year that
+/// isn't.
+const BUDDHIST_ERA_YEARS: std::ops::RangeInclusive<i64> = 2400..=2700;
+
+fn time_data(td: &TokenData) -> Option<&TimeData> {
+    match td {
+        TokenData::Time(d) => Some(d),
+        _ => None,
+    }
+}
+
+fn is_natural(td: &TokenData) -> bool {
+    integer_value(td).is_some_and(|v| v > 0)
+}
+
+fn is_dom(td: &TokenData) -> bool {
+    integer_value(td).is_some_and(|v| (1..=31).contains(&v))
+}
+
+fn is_bare_year(td: &TokenData) -> bool {
+    integer_value(td).is_some_and(|v| (1000..=*BUDDHIST_ERA_YEARS.end()).contains(&v))
+}
+
+fn is_month(td: &TokenData) -> bool {
+    matches!(td, TokenData::Time(d) if matches!(d.form, TimeForm::Month(_)))
+}
+
+fn is_yearless_date(td: &TokenData) -> bool {
+    matches!(td, TokenData::Time(d) if matches!(d.form, TimeForm::Month(_) | TimeForm::DateMDY { year: None, .. }))
+}
+
+fn is_year(td: &TokenData) -> bool {
+    matches!(td, TokenData::Time(d) if matches!(d.form, TimeForm::Year(_) | TimeForm::BuddhistEraYear { .. }))
+}
+
+fn is_date(td: &TokenData) -> bool {
+    matches!(td, TokenData::Time(d) if matches!(d.form, TimeForm::DateMDY { .. } | TimeForm::Composed(..)))
+}
+
+/// A rule reading the text `pattern` matches as `form`.
+pub(super) fn form_rule(name: &str, pattern: &str, form: TimeForm) -> Rule {
+    Rule {
+        name: name.to_string(),
+        pattern: vec![regex(pattern)],
+        production: Box::new(move |_| Some(TokenData::Time(TimeData::new(form.clone())))),
+    }
+}
+
+/// The year a bare `year` names: a Buddhist-era year `offset` years past the
+/// common era when it falls in `BUDDHIST_ERA_YEARS`, left to
+/// `Options::buddhist_era_years`.
+fn bare_year(year: i64, offset: i32) -> Option<TimeForm> {
+    let year = i32::try_from(year).ok()?;
+    Some(if BUDDHIST_ERA_YEARS.contains(&i64::from(year)) {
+        TimeForm::BuddhistEraYear { year, offset }
+    } else {
+        TimeForm::Year(year)
+    })
+}
+
+/// Years and dated years for a language counting years in the Buddhist era,
+/// `offset` years past the common era: "พ.ศ. 2568" and "ค.ศ. 2025" marked
+/// with the `buddhist` or `common` era, "ปี 2568" after the `year_word`, and
+/// "13 เมษายน 2568" after a date.
+pub(super) fn era_year_rules(
+    lang: &str,
+    year_word: &str,
+    buddhist: &str,
+    common: &str,
+    offset: i32,
+) -> Vec<Rule> {
+    vec![
+        Rule {
+            name: format!("<era> <year> ({lang})"),
+            pattern: vec![
+                regex(&format!(
+                    "(?:{year_word}\\s*)?(?:({buddhist})|({common}))\\s*"
+                )),
+                predicate(is_natural),
+            ],
+            production: Box::new(move |nodes| {
+                let buddhist = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(1).is_some(),
+                    _ => return None,
+                };
+                let year = i32::try_from(integer_value(&nodes[1].token_data)?).ok()?;
+                let year = if buddhist {
+                    year.checked_sub(offset)?
+                } else {
+                    year
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::Year(year))))
+            }),
+        },
+        Rule {
+            name: format!("<year word> <year> ({lang})"),
+            pattern: vec![regex(year_word), predicate(is_bare_year)],
+            production: Box::new(move |nodes| {
+                let year = integer_value(&nodes[1].token_data)?;
+                Some(TokenData::Time(TimeData::new(bare_year(year, offset)?)))
+            }),
+        },
+        Rule {
+            name: format!("<date> <year> ({lang})"),
+            pattern: vec![predicate(is_yearless_date), predicate(is_year)],
+            production: Box::new(|nodes| {
+                Some(TokenData::Time(TimeData::new(TimeForm::Composed(
+                    Box::new(time_data(&nodes[0].token_data)?.clone()),
+                    Box::new(time_data(&nodes[1].token_data)?.clone()),
+                ))))
+            }),
+        },
+        Rule {
+            name: format!("<date> <bare year> ({lang})"),
+            pattern: vec![predicate(is_yearless_date), predicate(is_bare_year)],
+            production: Box::new(move |nodes| {
+                let year = bare_year(integer_value(&nodes[1].token_data)?, offset)?;
+                Some(TokenData::Time(TimeData::new(TimeForm::Composed(
+                    Box::new(time_data(&nodes[0].token_data)?.clone()),
+                    Box::new(TimeData::new(year)),
+                ))))
+            }),
+        },
+    ]
+}
+
+/// "<day-of-month> <month>", and the date after the `day_word` that may
+/// lead it ("วันที่ 13 เมษายน").
+pub(super) fn day_month_rules(lang: &str, day_word: &str) -> Vec<Rule> {
+    vec![
+        Rule {
+            name: format!("<day-of-month> <month> ({lang})"),
+            pattern: vec![predicate(is_dom), predicate(is_month)],
+            production: Box::new(|nodes| {
+                let TimeForm::Month(month) = time_data(&nodes[1].token_data)?.form else {
+                    return None;
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::DateMDY {
+                    month,
+                    day: u32::try_from(integer_value(&nodes[0].token_data)?).ok()?,
+                    year: None,
+                })))
+            }),
+        },
+        Rule {
+            name: format!("<day word> <date> ({lang})"),
+            pattern: vec![regex(day_word), predicate(is_date)],
+            production: Box::new(|nodes| Some(nodes[1].token_data.clone())),
+        },
+    ]
+}
+
+/// "<n> <cycle> <later|ago>" with the `later` and `ago` words after the
+/// grain, and "<before> <n> <cycle>" with `before` ahead of the number
+/// ("อีก 3 วัน"). `grain` maps the grain words `grains` matches.
+pub(super) fn relative_grain_rules(
+    lang: &str,
+    grains: &'static str,
+    grain: fn(&str) -> Option<Grain>,
+    later: &str,
+    ago: &str,
+    before: &str,
+) -> Vec<Rule> {
+    let delayed = |n: i64, g: Grain| {
+        let mut t = TimeData::new(TimeForm::RelativeGrain { n, grain: g });
+        t.duration_qualifier = Some(DurationQualifier::Delayed);
+        TokenData::Time(t)
+    };
+    vec![
+        Rule {
+            name: format!("<n> <cycle> ago ({lang})"),
+            pattern: vec![
+                predicate(is_natural),
+                regex(&format!("\\s*({grains})\\s*(?:{ago})")),
+            ],
+            production: Box::new(move |nodes| {
+                let n = integer_value(&nodes[0].token_data)?;
+                let g = match &nodes[1].token_data {
+                    TokenData::RegexMatch(m) => grain(m.group(1)?)?,
+                    _ => return None,
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::RelativeGrain {
+                    n: n.checked_neg()?,
+                    grain: g,
+                })))
+            }),
+        },
+        Rule {
+            name: format!("<n> <cycle> later ({lang})"),
+            pattern: vec![
+                predicate(is_natural),
+                regex(&format!("\\s*({grains})\\s*(?:{later})")),
+            ],
+            production: Box::new(move |nodes| {
+                let n = integer_value(&nodes[0].token_data)?;
+                match &nodes[1].token_data {
+                    TokenData::RegexMatch(m) => Some(delayed(n, grain(m.group(1)?)?)),
+                    _ => None,
+                }
+            }),
+        },
+        Rule {
+            name: format!("in <n> <cycle> ({lang})"),
+            pattern: vec![
+                regex(before),
+                predicate(is_natural),
+                regex(&format!("\\s*({grains})")),
+            ],
+            production: Box::new(move |nodes| {
+                let n = integer_value(&nodes[1].token_data)?;
+                match &nodes[2].token_data {
+                    TokenData::RegexMatch(m) => Some(delayed(n, grain(m.group(1)?)?)),
+                    _ => None,
+                }
+            }),
+        },
+    ]
+}
+
+/// "<day-of-week> <next|last>" as that weekday of next or last week, with
+/// `dow` mapping the `days` names and `offset` the `next_last` words.
+pub(super) fn dow_next_last_rule(
+    lang: &str,
+    days: &str,
+    dow: fn(&str) -> Option<u32>,
+    next_last: &str,
+    offset: fn(&str) -> i32,
+) -> Rule {
+    Rule {
+        name: format!("<day-of-week> next/last ({lang})"),
+        pattern: vec![regex(&format!("({days})\\s*({next_last})"))],
+        production: Box::new(move |nodes| {
+            let (d, q) = match &nodes[0].token_data {
+                TokenData::RegexMatch(m) => (m.group(1)?, m.group(2)?),
+                _ => return None,
+            };
+            Some(TokenData::Time(TimeData::new(TimeForm::Composed(
+                Box::new(TimeData::new(TimeForm::DayOfWeek(dow(d)?))),
+                Box::new(TimeData::new(TimeForm::GrainOffset {
+                    grain: Grain::Week,
+                    offset: offset(q),
+                })),
+            ))))
+        }),
+    }
+}
+
+/// "<cycle> <this|next|last>" as a week, month or year offset from this
+/// one, with `grain` mapping the `cycles` words and `offset` the
+/// `this_next_last` words.
+pub(super) fn cycle_rule(
+    lang: &str,
+    cycles: &str,
+    grain: fn(&str) -> Option<Grain>,
+    this_next_last: &str,
+    offset: fn(&str) -> i32,
+) -> Rule {
+    Rule {
+        name: format!("<cycle> this/next/last ({lang})"),
+        pattern: vec![regex(&format!("({cycles})\\s*({this_next_last})"))],
+        production: Box::new(move |nodes| {
+            let (g, q) = match &nodes[0].token_data {
+                TokenData::RegexMatch(m) => (m.group(1)?, m.group(2)?),
+                _ => return None,
+            };
+            Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset {
+                grain: grain(g)?,
+                offset: offset(q),
+            })))
+        }),
+    }
+}
+
+/// A rule reading a name `pattern` captures, through `lookup`, as a
+/// weekday or month.
+pub(super) fn named_rule(name: &str, pattern: &str, lookup: fn(&str) -> Option<TimeForm>) -> Rule {
+    Rule {
+        name: name.to_string(),
+        pattern: vec![regex(pattern)],
+        production: Box::new(move |nodes| {
+            let s = match &nodes[0].token_data {
+                TokenData::RegexMatch(m) => m.group(1)?,
+                _ => return None,
+            };
+            Some(TokenData::Time(TimeData::new(lookup(s)?)))
+        }),
+    }
+}
+
+fn th_dow(s: &str) -> Option<u32> {
+    Some(match s.trim_start_matches("วัน") {
+        "จันทร์" => 0,
+        "อังคาร" => 1,
+        "พุธ" => 2,
+        "พฤหัส" | "พฤหัสบดี" => 3,
+        "ศุกร์" => 4,
+        "เสาร์" => 5,
+        "อาทิตย์" => 6,
+        _ => return None,
+    })
+}
+
+fn th_month(s: &str) -> Option<u32> {
+    Some(match s {
+        "มกราคม" | "ม.ค." => 1,
+        "กุมภาพันธ์" | "ก.พ." => 2,
+        "มีนาคม" | "มี.ค." => 3,
+        "เมษายน" | "เม.ย." => 4,
+        "พฤษภาคม" | "พ.ค." => 5,
+        "มิถุนายน" | "มิ.ย." => 6,
+        "กรกฎาคม" | "ก.ค." => 7,
+        "สิงหาคม" | "ส.ค." => 8,
+        "กันยายน" | "ก.ย." => 9,
+        "ตุลาคม" | "ต.ค." => 10,
+        "พฤศจิกายน" | "พ.ย." => 11,
+        "ธันวาคม" | "ธ.ค." => 12,
+        _ => return None,
+    })
+}
+
+fn th_grain(s: &str) -> Option<Grain> {
+    Some(match s {
+        "วินาที" => Grain::Second,
+        "นาที" => Grain::Minute,
+        "ชั่วโมง" => Grain::Hour,
+        "วัน" => Grain::Day,
+        "สัปดาห์" | "อาทิตย์" => Grain::Week,
+        "เดือน" => Grain::Month,
+        "ปี" => Grain::Year,
+        _ => return None,
+    })
+}
+
+/// 0 after "นี้" (this), 1 after "หน้า" (next), -1 after "ที่แล้ว",
+/// "ที่ผ่านมา" or "ก่อน" (last).
+fn th_offset(s: &str) -> i32 {
+    match s {
+        "นี้" => 0,
+        "หน้า" => 1,
+        _ => -1,
+    }
+}
+
+pub fn rules() -> Vec<Rule> {
+    // Numeric dates are read day first: "3/4" is 3 April
+    let mut rules: Vec<Rule> = super::en::rules()
+        .into_iter()
+        .filter(|r| r.name != "date MM/DD(/YYYY)")
+        .collect();
+    rules.extend(super::en::day_first_date_rules());
+    rules.extend(vec![
+        form_rule("now (th)", "ตอนนี้|เดี๋ยวนี้|ขณะนี้", TimeForm::Now),
+        form_rule("today (th)", "วันนี้", TimeForm::Today),
+        form_rule("tomorrow (th)", "พรุ่งนี้", TimeForm::Tomorrow),
+        form_rule("yesterday (th)", "เมื่อวาน(?:นี้)?", TimeForm::Yesterday),
+        form_rule(
+            "day after tomorrow (th)",
+            "มะรืน(?:นี้)?",
+            TimeForm::DayAfterTomorrow,
+        ),
+        form_rule(
+            "day before yesterday (th)",
+            "เมื่อวานซืน",
+            TimeForm::DayBeforeYesterday,
+        ),
+        named_rule("day of week (th)", &format!("({DAYS})"), |s| {
+            th_dow(s).map(TimeForm::DayOfWeek)
+        }),
+        named_rule(
+            "named month (th)",
+            &format!("(?:เดือน)?({MONTHS})"),
+            |s| th_month(s).map(TimeForm::Month),
+        ),
+        dow_next_last_rule("th", DAYS, th_dow, "หน้า|ที่แล้ว|ที่ผ่านมา", th_offset),
+        cycle_rule(
+            "th",
+            "สัปดาห์|อาทิตย์|เดือน|ปี",
+            th_grain,
+            "นี้|หน้า|ที่แล้ว|ที่ผ่านมา|ก่อน",
+            th_offset,
+        ),
+    ]);
+    rules.extend(relative_grain_rules(
+        "th",
+        GRAINS,
+        th_grain,
+        "ข้างหน้า",
+        "ที่แล้ว|ที่ผ่านมา|ก่อน",
+        "อีก|ใน",
+    ));
+    rules.extend(day_month_rules("th", "วันที่"));
+    rules.extend(era_year_rules(
+        "th",
+        "ปี",
+        "พ\\.ศ\\.?|พุทธศักราช",
+        "ค\\.ศ\\.?|คริสต์ศักราช",
+        543,
+    ));
+    rules
+}
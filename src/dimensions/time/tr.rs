@@ -4,6 +4,12 @@ use crate::dimensions::time_grain::Grain;
 use crate::pattern::{dim, predicate, regex};
 use crate::types::{DimensionKind, Rule, TokenData};
 
+/// An optional case ending: an apostrophe sets it off after numbers and
+/// names ("15:30'da", "Mayıs'ta") but not after other words ("yarına",
+/// "salıdan"). Covers the locative, ablative, dative, genitive and
+/// accusative in each vowel-harmony form, with a buffer "n", "s" or "y".
+const CASE_SUFFIX: &str = r"(?:'?(?:n?[dt][ae]n?|n?[ıiuü]n|[nsy]?[aeıiuü]))?";
+
 fn time_data(td: &TokenData) -> Option<&TimeData> {
     match td {
         TokenData::Time(d) => Some(d),
@@ -108,13 +114,13 @@ fn tr_month_num(s: &str) -> Option<u32> {
         .replace("ö", "o")
         .replace("ü", "u")
         .replace(".", "");
-    if t.starts_with("ocak") {
+    if t.starts_with("oca") {
         Some(1)
     } else if t.starts_with("sub") {
         Some(2)
-    } else if t.starts_with("mart") {
+    } else if t.starts_with("mar") {
         Some(3)
-    } else if t.starts_with("nisan") {
+    } else if t.starts_with("nis") {
         Some(4)
     } else if t.starts_with("may") {
         Some(5)
@@ -126,7 +132,7 @@ fn tr_month_num(s: &str) -> Option<u32> {
         Some(8)
     } else if t.starts_with("eyl") {
         Some(9)
-    } else if t.starts_with("ekim") {
+    } else if t.starts_with("eki") {
         Some(10)
     } else if t.starts_with("kas") {
         Some(11)
@@ -147,17 +153,19 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "today (tr)".to_string(),
-            pattern: vec![regex("bug[üu]n")],
+            pattern: vec![regex(&format!(r"\bbug[üu]n{CASE_SUFFIX}\b"))],
             production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Today)))),
         },
         Rule {
             name: "tomorrow (tr)".to_string(),
-            pattern: vec![regex("yar[ıi]n|sonraki\\s+g[üu]n|gelecek\\s+g[üu]n")],
+            pattern: vec![regex(&format!(
+                r"\b(?:yar[ıi]n{CASE_SUFFIX}|sonraki\s+g[üu]n|gelecek\s+g[üu]n)\b"
+            ))],
             production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Tomorrow)))),
         },
         Rule {
             name: "yesterday (tr)".to_string(),
-            pattern: vec![regex("d[üu]n")],
+            pattern: vec![regex(r"\bd[üu]n(?:d[ae]n?|[ae]|[üu]n)?\b")],
             production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Yesterday)))),
         },
         Rule {
@@ -172,28 +180,23 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "day of week monday (tr)".to_string(),
-            pattern: vec![regex("pazartesi'?(si|den|ye)?|pzts?|salı?'?(sı|dan|ya)?|çar(şamba)?'?(sı|dan|ya)?|per(şembe)?'?(si|den|ye)?|cum|cuma?'?(sı|dan|ya)?|cumartesi'?(si|den|ye)?|cmt|paz(ar)?'?(ı|dan|a)?")],
+            pattern: vec![regex(&format!(
+                r"\b(pazartesi|pzts?|sal[ıi]?|[çc]ar[şs]amba|[çc]ar|per[şs]embe|per|cumartesi|cmt|cuma|cum|pazar|paz)(?:\s+g[üu]n[üu](?:n[dt]?[ae]n?)?|{CASE_SUFFIX})\b"
+            ))],
             production: Box::new(|nodes| {
                 let s = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(0)?.to_lowercase(),
+                    TokenData::RegexMatch(m) => m.group(1)?.to_lowercase(),
                     _ => return None,
                 };
-                let dow = if s.starts_with("paz") || s.starts_with("pzt") {
-                    0
-                } else if s.starts_with("sal") {
-                    1
-                } else if s.contains("çarşamba") || s.contains("carsamba") {
-                    2
-                } else if s.contains("perşembe") || s.contains("persembe") || s.starts_with("per") {
-                    3
-                } else if s.starts_with("cumartesi") || s == "cmt" {
-                    5
-                } else if s.starts_with("cuma") || s == "cum" {
-                    4
-                } else if s.starts_with("pazar") {
-                    6
-                } else {
-                    return None;
+                let dow = match s.as_str() {
+                    "pazartesi" | "pzt" | "pzts" => 0,
+                    "salı" | "sali" | "sal" => 1,
+                    "çarşamba" | "carsamba" | "çarsamba" | "carşamba" | "çar" | "car" => 2,
+                    "perşembe" | "persembe" | "per" => 3,
+                    "cuma" | "cum" => 4,
+                    "cumartesi" | "cmt" => 5,
+                    "pazar" | "paz" => 6,
+                    _ => return None,
                 };
                 Some(TokenData::Time(TimeData::new(TimeForm::DayOfWeek(dow))))
             }),
@@ -335,10 +338,12 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "<named-month> (tr)".to_string(),
-            pattern: vec![regex("oca(?:k)?'?(ğın|tan|ğ?a)?|[şs]uba(?:t)?'?(a|ın|tan)?|mart?'?(ın|a|tan)?|nisa(?:n)?'?(ın|a|dan)?|may[ıi]s'?(ın|a|tan)?|hazi(?:ran)?'?(ın|a|dan)?|tem(?:muz)?'?(un|a|dan)?|a[ğg]u(?:stos)?'?(un|a|tan)?|eyl(?:[üu]l)?'?(ün|e|den)?|eki(?:m)?'?(in|den|e)?|kası(?:m)?'?(ın|dan|a)?|aralı(?:k)?'?(ğın|ğa|tan)?")],
+            pattern: vec![regex(&format!(
+                r"\b(oca[kğg]?|[şs]ubat?|mart?|nisan?|may[ıi]s|hazi(?:ran)?|tem(?:muz)?|a[ğg]u(?:stos)?|eyl(?:[üu]l)?|ekim?|kas[ıi]m?|aral[ıi][kğg]?){CASE_SUFFIX}\b"
+            ))],
             production: Box::new(|nodes| {
                 let s = match &nodes[0].token_data {
-                    TokenData::RegexMatch(m) => m.group(0)?,
+                    TokenData::RegexMatch(m) => m.group(1)?,
                     _ => return None,
                 };
                 let month = tr_month_num(s)?;
@@ -467,7 +472,7 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "ramazan bayramı (tr)".to_string(),
-            pattern: vec![regex("ramazan\\s+bayram[ıi]")],
+            pattern: vec![regex(&format!(r"\b(?:ramazan|[şs]eker)\s+bayram[ıi]{CASE_SUFFIX}\b"))],
             production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Holiday(
                 "eid al-fitr".to_string(),
                 None,
@@ -475,7 +480,9 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "YYYY ramazan bayramı (tr)".to_string(),
-            pattern: vec![regex("(\\d{4})\\s+ramazan\\s+bayram[ıi]|ramazan\\s+bayram[ıi]\\s+(\\d{4})")],
+            pattern: vec![regex(&format!(
+                r"(\d{{4}})\s+(?:ramazan|[şs]eker)\s+bayram[ıi]{CASE_SUFFIX}\b|(?:ramazan|[şs]eker)\s+bayram[ıi]\s+(\d{{4}})"
+            ))],
             production: Box::new(|nodes| {
                 let y = match &nodes[0].token_data {
                     TokenData::RegexMatch(m) => m.group(1).or_else(|| m.group(2))?,
@@ -490,7 +497,7 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "kurban bayramı (tr)".to_string(),
-            pattern: vec![regex("kurban\\s+bayram[ıi]")],
+            pattern: vec![regex(&format!(r"\bkurban\s+bayram[ıi]{CASE_SUFFIX}\b"))],
             production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Holiday(
                 "eid al-adha".to_string(),
                 None,
@@ -498,7 +505,9 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "YYYY kurban bayramı (tr)".to_string(),
-            pattern: vec![regex("(\\d{4})\\s+kurban\\s+bayram[ıi]|kurban\\s+bayram[ıi]\\s+(\\d{4})")],
+            pattern: vec![regex(&format!(
+                r"(\d{{4}})\s+kurban\s+bayram[ıi]{CASE_SUFFIX}\b|kurban\s+bayram[ıi]\s+(\d{{4}})"
+            ))],
             production: Box::new(|nodes| {
                 let y = match &nodes[0].token_data {
                     TokenData::RegexMatch(m) => m.group(1).or_else(|| m.group(2))?,
@@ -804,6 +813,14 @@ pub fn rules() -> Vec<Rule> {
                 ))))
             }),
         },
+        Rule {
+            name: "<time>'<case suffix> (tr)".to_string(),
+            pattern: vec![
+                dim(DimensionKind::Time),
+                regex(r"'(?:n?[dt][ae]n?|n?[ıiuü]n|[nsy]?[aeıiuü])\b"),
+            ],
+            production: Box::new(|nodes| Some(nodes[0].token_data.clone())),
+        },
         Rule {
             name: "<time> kadar (tr)".to_string(),
             pattern: vec![dim(DimensionKind::Time), regex("kadar")],
@@ -58,18 +58,22 @@ fn is_month(td: &TokenData) -> bool {
 fn uk_month_num(s: &str) -> Option<u32> {
     let t = s.to_lowercase();
     match t.as_str() {
-        "січень" | "січня" | "січ" => Some(1),
-        "лютий" | "лютого" | "лют" => Some(2),
-        "березень" | "березня" | "бер" => Some(3),
-        "квітень" | "квітня" | "квіт" => Some(4),
-        "травень" | "травня" | "трав" => Some(5),
-        "червень" | "червня" | "чер" => Some(6),
-        "липень" | "липня" | "лип" => Some(7),
-        "серпень" | "серпня" | "серп" | "сер" => Some(8),
-        "вересень" | "вересня" | "верес" | "вер" => Some(9),
-        "жовтень" | "жовтня" | "жовт" => Some(10),
-        "листопад" | "листопада" | "лист" | "лис" => Some(11),
-        "грудень" | "грудня" | "груд" | "гру" => Some(12),
+        "січень" | "січня" | "січні" | "січ" => Some(1),
+        "лютий" | "лютого" | "лютому" | "лют" => Some(2),
+        "березень" | "березня" | "березні" | "бер" => Some(3),
+        "квітень" | "квітня" | "квітні" | "квіт" => Some(4),
+        "травень" | "травня" | "травні" | "трав" => Some(5),
+        "червень" | "червня" | "червні" | "чер" => Some(6),
+        "липень" | "липня" | "липні" | "лип" => Some(7),
+        "серпень" | "серпня" | "серпні" | "серп" | "сер" => Some(8),
+        "вересень" | "вересня" | "вересні" | "верес" | "вер" => {
+            Some(9)
+        }
+        "жовтень" | "жовтня" | "жовтні" | "жовт" => Some(10),
+        "листопад" | "листопада" | "листопаді" | "лист" | "лис" => {
+            Some(11)
+        }
+        "грудень" | "грудня" | "грудні" | "груд" | "гру" => Some(12),
         _ => None,
     }
 }
@@ -117,7 +121,7 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "day of week (uk)".to_string(),
-            pattern: vec![regex("понеділ(ок|ка)|пн|вівтор(ок|ка)|вт|серед(а|у)|ср|четвер(га)?|чт|п'ятниц(я|і|ю)|пт|субот(а|и|у)|сб|неділ(я|і|ю)|нд")],
+            pattern: vec![regex("\\b(?:понеділ(ок|ка|ку)|пн|вівтор(ок|ка|ку)|вт|серед(а|и|у|і)|ср|четвер(га|гу)?|чт|п['’ʼ]ятниц(я|і|ю)|пт|субот(а|и|у|і)|сб|неділ(я|і|ю)|нд)\\b")],
             production: Box::new(|nodes| {
                 let s = match &nodes[0].token_data {
                     TokenData::RegexMatch(m) => m.group(0)?.to_lowercase(),
@@ -131,7 +135,7 @@ pub fn rules() -> Vec<Rule> {
                     2
                 } else if s.starts_with("чт") || s.starts_with("четвер") {
                     3
-                } else if s.starts_with("пт") || s.starts_with("п'ятниц") {
+                } else if s.starts_with("пт") || s.starts_with('п') && s.contains("ятниц") {
                     4
                 } else if s.starts_with("сб") || s.starts_with("субот") {
                     5
@@ -215,7 +219,7 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "this|next <day-of-week> (uk)".to_string(),
-            pattern: vec![regex("(цей|ця|цього|цьому|наступний|наступна|наступної|наступну)"), predicate(is_day_of_week)],
+            pattern: vec![regex("\\b(цей|ця|цього|цьому|наступн(ий|а|ого|ої|ому|у))\\b"), predicate(is_day_of_week)],
             production: Box::new(|nodes| {
                 let mut td = time_data(&nodes[1].token_data)?.clone();
                 td.direction = Some(Direction::Future);
@@ -249,7 +253,7 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "on <date> (uk)".to_string(),
-            pattern: vec![regex("(на|в)"), predicate(is_not_latent_time)],
+            pattern: vec![regex("\\b(на|в|у)\\b"), predicate(is_not_latent_time)],
             production: Box::new(|nodes| match &nodes[1].token_data {
                 TokenData::Time(td) => Some(TokenData::Time(td.clone())),
                 _ => None,
@@ -457,7 +461,7 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![
                 dim(DimensionKind::Ordinal),
                 dim(DimensionKind::TimeGrain),
-                regex("в"),
+                regex("\\b[ву]\\b"),
                 dim(DimensionKind::Time),
             ],
             production: Box::new(|nodes| {
@@ -471,7 +475,7 @@ pub fn rules() -> Vec<Rule> {
                 };
                 let base = time_data(&nodes[3].token_data)?.clone();
                 Some(TokenData::Time(TimeData::new(TimeForm::NthGrainOfTime {
-                    n: (ord.checked_sub(1)?) as i32,
+                    n: i32::try_from(ord).ok()?,
                     grain,
                     base: Box::new(base),
                 })))
@@ -609,6 +613,17 @@ pub fn rules() -> Vec<Rule> {
                 })))
             }),
         },
+        Rule {
+            name: "named-month (uk)".to_string(),
+            pattern: vec![regex("\\b(січ(?:ень|ня|ні)|лют(?:ий|ого|ому)|берез(?:ень|ня|ні)|квіт(?:ень|ня|ні)|трав(?:ень|ня|ні)|черв(?:ень|ня|ні)|лип(?:ень|ня|ні)|серп(?:ень|ня|ні)|верес(?:ень|ня|ні)|жовт(?:ень|ня|ні)|листопад(?:а|і)?|груд(?:ень|ня|ні))\\b")],
+            production: Box::new(|nodes| {
+                let s = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?,
+                    _ => return None,
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::Month(uk_month_num(s)?))))
+            }),
+        },
         Rule {
             name: "<named-month> <year> (uk)".to_string(),
             pattern: vec![regex("([Сс]іч(?:ень|ня)?\\.?|[Лл]ют(?:ий|ого)?\\.?|[Бб]ер(?:езень|езня)?\\.?|[Кк]віт(?:ень|ня)?\\.?|[Тт]рав(?:ень|ня)?\\.?|[Чч]ерв(?:ень|ня)?\\.?|[Лл]ип(?:ень|ня)?\\.?|[Сс]ерп(?:ень|ня)?\\.?|[Сс]ер\\.?|[Вв]ер(?:есень|есня)?\\.?|[Жж]овт(?:ень|ня)?\\.?|[Лл]истопад(?:а)?\\.?|[Гг]руд(?:ень|ня)?\\.?)\\s*(\\d{4})")],
@@ -960,6 +975,28 @@ pub fn rules() -> Vec<Rule> {
                 year: None,
             })))),
         },
+        Rule {
+            name: "orthodox holidays (uk)".to_string(),
+            pattern: vec![regex("\\b(великд(ень|ня|ні)|великодн(я|і)|пасх(а|и|у)|страсн(а|у|ої)\\s+п[\'’ʼ]ятниц(я|і|ю)|вербн(а|у|ої)\\s+неділ(я|і|ю)|вознесіння|трійц(я|і|ю)|зелені\\s+свята)\\b")],
+            production: Box::new(|nodes| {
+                let s = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?.to_lowercase(),
+                    _ => return None,
+                };
+                let name = if s.starts_with("страсн") {
+                    "orthodox good friday"
+                } else if s.starts_with("вербн") {
+                    "orthodox palm sunday"
+                } else if s.starts_with("вознес") {
+                    "orthodox ascension"
+                } else if s.starts_with("трійц") || s.starts_with("зелені") {
+                    "orthodox pentecost"
+                } else {
+                    "orthodox easter"
+                };
+                Some(TokenData::Time(TimeData::new(TimeForm::Holiday(name.to_string(), None))))
+            }),
+        },
         Rule {
             name: "Новий рік (uk)".to_string(),
             pattern: vec![regex("новий\\s+р[іi]к")],
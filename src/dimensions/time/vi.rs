@@ -10,6 +10,10 @@ fn time_data(td: &TokenData) -> Option<&TimeData> {
     }
 }
 
+fn is_not_latent_time(td: &TokenData) -> bool {
+    matches!(td, TokenData::Time(d) if !d.latent)
+}
+
 fn is_time_of_day(td: &TokenData) -> bool {
     matches!(
         td,
@@ -245,11 +249,6 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex("cu(ố|o)i\\s+n(ă|a)m")],
             production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::GrainOffset { grain: Grain::Year, offset: 1 })))),
         },
-        Rule {
-            name: "thứ năm tuần tới (vi)".to_string(),
-            pattern: vec![regex("thứ năm tuần tới")],
-            production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::DayOfWeek(3))))),
-        },
         Rule {
             name: "thứ sáu tới (vi)".to_string(),
             pattern: vec![regex("thứ\\s*(sáu|6)\\s+tới")],
@@ -262,22 +261,25 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "thứ <dow> tuần tới (vi)".to_string(),
-            pattern: vec![regex("thứ\\s*(hai|ba|tư|4|năm|5|sáu|6|bảy|7)\\s+(của\\s+)?tuần\\s+tới")],
+            pattern: vec![regex("thứ\\s*(hai|2|ba|3|tư|4|năm|5|sáu|6|bảy|7)\\s+(của\\s+)?tuần\\s+(tới|sau)")],
             production: Box::new(|nodes| {
                 let s = match &nodes[0].token_data {
                     TokenData::RegexMatch(m) => m.group(1)?.to_lowercase(),
                     _ => return None,
                 };
                 let dow = match s.as_str() {
-                    "hai" => 0,
-                    "ba" => 1,
+                    "hai" | "2" => 0,
+                    "ba" | "3" => 1,
                     "tư" | "4" => 2,
                     "năm" | "5" => 3,
                     "sáu" | "6" => 4,
                     "bảy" | "7" => 5,
                     _ => return None,
                 };
-                Some(TokenData::Time(TimeData::new(TimeForm::DayOfWeek(dow))))
+                Some(TokenData::Time(TimeData::new(TimeForm::Composed(
+                    Box::new(TimeData::new(TimeForm::DayOfWeek(dow))),
+                    Box::new(TimeData::new(TimeForm::GrainOffset { grain: Grain::Week, offset: 1 })),
+                ))))
             }),
         },
         Rule {
@@ -571,7 +573,7 @@ pub fn rules() -> Vec<Rule> {
                     _ => return None,
                 };
                 let hour: u32 = h.parse().ok()?;
-                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(hour, 0, false))))
+                Some(TokenData::Time(TimeData::new(TimeForm::HourMinute(hour, 0, hour <= 12))))
             }),
         },
         Rule {
@@ -1186,7 +1188,7 @@ pub fn rules() -> Vec<Rule> {
         },
         Rule {
             name: "intersect (vi)".to_string(),
-            pattern: vec![dim(DimensionKind::Time), dim(DimensionKind::Time)],
+            pattern: vec![predicate(is_not_latent_time), predicate(is_not_latent_time)],
             production: Box::new(|nodes| {
                 let t1 = time_data(&nodes[0].token_data)?.clone();
                 let t2 = time_data(&nodes[1].token_data)?.clone();
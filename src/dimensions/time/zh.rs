@@ -1,5 +1,6 @@
 use super::{PartOfDay, TimeData, TimeForm};
 use crate::dimensions::time_grain::Grain;
+use crate::locale::Region;
 use crate::pattern::regex;
 use crate::types::{Rule, TokenData};
 
@@ -633,3 +634,56 @@ pub fn rules() -> Vec<Rule> {
     ]);
     rules
 }
+
+fn weekend_rule(name: &str, pattern: &str) -> Rule {
+    Rule {
+        name: name.to_string(),
+        pattern: vec![regex(pattern)],
+        production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Weekend)))),
+    }
+}
+
+/// Region overlays over the shared Chinese rules: the weekend in the
+/// region's script, simplified "周末" on the mainland and traditional
+/// "週末" elsewhere, and each region's own holidays. Taiwan's national day
+/// is Double Tenth, not the mainland's October 1, and a bare "回歸紀念日"
+/// is Hong Kong's or Macau's handover.
+pub fn locale_rules(region: Region) -> Vec<Rule> {
+    match region {
+        Region::CN => vec![weekend_rule("weekend (zh_CN)", "(这个?)?周末")],
+        Region::TW => vec![
+            weekend_rule("weekend (zh_TW)", "(這個?)?週末"),
+            fixed_holiday_rule(
+                "double tenth (zh_TW)",
+                "(雙|双)十(節|节|國慶)|國慶(日|節)?|国庆(日|节)?",
+                10,
+                10,
+            ),
+            fixed_holiday_rule("peace memorial day (zh_TW)", "和平紀念日|二二八", 2, 28),
+        ],
+        Region::HK => vec![
+            weekend_rule("weekend (zh_HK)", "(今個|這個?)?週末"),
+            fixed_holiday_rule(
+                "establishment day (zh_HK)",
+                "(香港)?(特別行政區成立|回歸)紀念日|香港回歸",
+                7,
+                1,
+            ),
+            Rule {
+                name: "yesterday (zh_HK)".to_string(),
+                pattern: vec![regex("琴日")],
+                production: Box::new(|_| Some(TokenData::Time(TimeData::new(TimeForm::Yesterday)))),
+            },
+        ],
+        Region::MO => vec![
+            weekend_rule("weekend (zh_MO)", "(今個|這個?)?週末"),
+            fixed_holiday_rule(
+                "establishment day (zh_MO)",
+                "(特別行政區成立|回歸)紀念日|澳門回歸",
+                12,
+                20,
+            ),
+        ],
+        _ => Vec::new(),
+    }
+}
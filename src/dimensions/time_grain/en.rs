@@ -1,5 +1,5 @@
-use crate::pattern::regex;
-use crate::types::{Rule, TokenData};
+use crate::pattern::{dim, regex};
+use crate::types::{DimensionKind, Rule, TokenData};
 
 use super::Grain;
 
@@ -46,6 +46,37 @@ pub fn rules() -> Vec<Rule> {
             pattern: vec![regex(r#"y(ea)?rs?"#)],
             production: Box::new(|_nodes| Some(TokenData::TimeGrain(Grain::Year))),
         },
+        // Recurrence units for scheduling: "weekly", "per month", "every day".
+        Rule {
+            name: "hourly|daily|weekly|... (grain)".to_string(),
+            pattern: vec![regex(
+                r#"(hourly|daily|weekly|monthly|quarterly|yearly|annually)"#,
+            )],
+            production: Box::new(|nodes| {
+                let word = match &nodes[0].token_data {
+                    TokenData::RegexMatch(m) => m.group(1)?.to_lowercase(),
+                    _ => return None,
+                };
+                let grain = match word.as_str() {
+                    "hourly" => Grain::Hour,
+                    "daily" => Grain::Day,
+                    "weekly" => Grain::Week,
+                    "monthly" => Grain::Month,
+                    "quarterly" => Grain::Quarter,
+                    "yearly" | "annually" => Grain::Year,
+                    _ => return None,
+                };
+                Some(TokenData::TimeGrain(grain))
+            }),
+        },
+        Rule {
+            name: "per|every <grain>".to_string(),
+            pattern: vec![regex(r#"per|every|each"#), dim(DimensionKind::TimeGrain)],
+            production: Box::new(|nodes| match &nodes[1].token_data {
+                TokenData::TimeGrain(g) => Some(TokenData::TimeGrain(*g)),
+                _ => None,
+            }),
+        },
     ]
 }
 
@@ -59,7 +90,10 @@ mod tests {
     #[test]
     fn test_time_grains() {
         let rules = rules();
-        let options = Options { with_latent: false };
+        let options = Options {
+            with_latent: false,
+            ..Options::default()
+        };
         let context = Context::default();
 
         for (text, expected_grain) in &[
@@ -70,6 +104,10 @@ mod tests {
             ("week", "week"),
             ("months", "month"),
             ("year", "year"),
+            ("weekly", "week"),
+            ("annually", "year"),
+            ("per month", "month"),
+            ("every day", "day"),
         ] {
             let entities = engine::parse_and_resolve(
                 text,
@@ -28,7 +28,7 @@ use crate::types::DimensionValue;
 
 /// Time grain, ordered from smallest to largest (Second < Minute < ... < Year).
 /// Ordering matches Haskell Duckling's derived Ord.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
 pub enum Grain {
     /// No grain — used only for `now` to mark it as a reference instant.
     /// Matches Haskell's `NoGrain` variant.
@@ -113,6 +113,52 @@ impl Grain {
         }
     }
 
+    /// The next coarser grain (`Day` → `Week`), or `None` for `Year`.
+    /// Unlike [`Grain::lower`], this steps through every grain in order.
+    pub fn succ(&self) -> Option<Grain> {
+        Some(match self {
+            Grain::NoGrain => Grain::Second,
+            Grain::Second => Grain::Minute,
+            Grain::Minute => Grain::Hour,
+            Grain::Hour => Grain::Day,
+            Grain::Day => Grain::Week,
+            Grain::Week => Grain::Month,
+            Grain::Month => Grain::Quarter,
+            Grain::Quarter => Grain::Year,
+            Grain::Year => return None,
+        })
+    }
+
+    /// The next finer grain (`Week` → `Day`), or `None` for `NoGrain`.
+    pub fn pred(&self) -> Option<Grain> {
+        Some(match self {
+            Grain::NoGrain => return None,
+            Grain::Second => Grain::NoGrain,
+            Grain::Minute => Grain::Second,
+            Grain::Hour => Grain::Minute,
+            Grain::Day => Grain::Hour,
+            Grain::Week => Grain::Day,
+            Grain::Month => Grain::Week,
+            Grain::Quarter => Grain::Month,
+            Grain::Year => Grain::Quarter,
+        })
+    }
+
+    /// Approximate number of seconds in one unit of this grain, counting
+    /// months as 30 days and years as 365; see [`Grain::in_seconds`].
+    pub fn seconds(&self) -> i64 {
+        match self {
+            Grain::NoGrain | Grain::Second => 1,
+            Grain::Minute => 60,
+            Grain::Hour => 3600,
+            Grain::Day => 86400,
+            Grain::Week => 604800,
+            Grain::Month => 2592000,
+            Grain::Quarter => 7776000,
+            Grain::Year => 31536000,
+        }
+    }
+
     /// Number of seconds in `n` units of this grain.
     /// Matches Haskell Duckling's `inSeconds`.
     pub fn in_seconds(&self, n: i64) -> Option<i64> {
@@ -68,7 +68,10 @@ mod tests {
     #[test]
     fn test_urls() {
         let rules = rules();
-        let options = Options { with_latent: false };
+        let options = Options {
+            with_latent: false,
+            ..Options::default()
+        };
         let context = Context::default();
 
         for (text, expected_domain) in &[
@@ -1,8 +1,16 @@
-use crate::dimensions::numeral::helpers::{is_positive, numeral_data};
+use crate::dimensions::numeral::helpers::{integer_value, is_positive, numeral_data};
 use crate::pattern::{dim, predicate, regex};
-use crate::types::{DimensionKind, Rule, TokenData};
+use crate::types::{DimensionKind, Precision, Rule, TokenData};
 
-use super::{VolumeData, VolumeUnit};
+use super::{compound, containers_rule, VolumeData, VolumeUnit};
+
+/// Containers counted by the volume each holds ("zwei Flaschen à 0,5 Liter").
+const CONTAINERS: &str =
+    "flaschen|flasche|dosen|dose|gläser|glas|kanister|kartons?|fässer|fass|becher|packungen|packung";
+
+fn is_count(td: &TokenData) -> bool {
+    integer_value(td).is_some_and(|v| v > 0)
+}
 
 fn volume_data(td: &TokenData) -> Option<&VolumeData> {
     match td {
@@ -101,6 +109,14 @@ pub fn rules() -> Vec<Rule> {
                 Some(TokenData::Volume(VolumeData::new(0.5, d.unit?)))
             }),
         },
+        Rule {
+            name: "one and a half <volume>".to_string(),
+            pattern: vec![regex("anderthalb|eineinhalb"), is_unit_only()],
+            production: Box::new(|nodes| {
+                let d = volume_data(&nodes[1].token_data)?;
+                Some(TokenData::Volume(VolumeData::new(1.5, d.unit?)))
+            }),
+        },
         Rule {
             name: "third <volume>".to_string(),
             pattern: vec![regex("(ein(e[ns])? )?dritttel "), is_unit_only()],
@@ -133,13 +149,62 @@ pub fn rules() -> Vec<Rule> {
                 Some(TokenData::Volume(VolumeData::new(0.1, d.unit?)))
             }),
         },
+        Rule {
+            name: "composite <volume> (with und)".to_string(),
+            pattern: vec![
+                predicate(is_simple_volume),
+                regex("und"),
+                predicate(is_simple_volume),
+            ],
+            production: Box::new(|nodes| {
+                let d1 = volume_data(&nodes[0].token_data)?;
+                let d2 = volume_data(&nodes[2].token_data)?;
+                Some(TokenData::Volume(compound(d1, d2)?))
+            }),
+        },
+        Rule {
+            name: "composite <volume>".to_string(),
+            pattern: vec![predicate(is_simple_volume), predicate(is_simple_volume)],
+            production: Box::new(|nodes| {
+                let d1 = volume_data(&nodes[0].token_data)?;
+                let d2 = volume_data(&nodes[1].token_data)?;
+                Some(TokenData::Volume(compound(d1, d2)?))
+            }),
+        },
+        containers_rule(
+            "<count> <container> à <volume>",
+            vec![
+                predicate(is_count),
+                regex(&format!("(?:{CONTAINERS}) (?:à|a|zu( je)?|mit|von|je)")),
+                predicate(is_simple_volume),
+            ],
+            0,
+            2,
+        ),
+        containers_rule(
+            "<count> x <volume>",
+            vec![
+                predicate(is_count),
+                regex("x|×|\\*"),
+                predicate(is_simple_volume),
+            ],
+            0,
+            2,
+        ),
         Rule {
             name: "about <volume>".to_string(),
             pattern: vec![
                 regex("\\~|(ganz )?genau|präzise|(in )?etwa|ungefähr|um( die)?|fast"),
                 dim(DimensionKind::Volume),
             ],
-            production: Box::new(|nodes| Some(nodes[1].token_data.clone())),
+            production: Box::new(|nodes| {
+                Some(
+                    nodes[1]
+                        .token_data
+                        .clone()
+                        .with_precision(Precision::of_qualifier(nodes[0])),
+                )
+            }),
         },
         Rule {
             name: "between|from <numeral> and|to <volume>".to_string(),
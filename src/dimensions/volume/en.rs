@@ -1,8 +1,12 @@
-use crate::dimensions::numeral::helpers::numeral_data;
+use crate::dimensions::numeral::helpers::{integer_value, numeral_data};
 use crate::pattern::{dim, predicate, regex};
-use crate::types::{DimensionKind, Rule, TokenData};
+use crate::types::{DimensionKind, Precision, Rule, TokenData};
 
-use super::{VolumeData, VolumeUnit};
+use super::{compound, containers, containers_rule, VolumeData, VolumeUnit};
+
+/// Containers counted by the volume each holds ("two 500ml bottles").
+const CONTAINERS: &str =
+    r#"bottles?|cans?|glass(es)?|jugs?|cartons?|jars?|kegs?|tins?|flasks?|canisters?"#;
 
 fn volume_data(token_data: &TokenData) -> Option<&VolumeData> {
     match token_data {
@@ -22,6 +26,11 @@ fn is_unit_only() -> crate::types::PatternItem {
     })
 }
 
+/// Matches a whole number of containers.
+fn is_count() -> crate::types::PatternItem {
+    predicate(|td| integer_value(td).is_some_and(|v| v > 0))
+}
+
 /// Matches Volume tokens with a simple value (value + unit, no interval).
 fn is_simple_volume() -> crate::types::PatternItem {
     predicate(|td| {
@@ -254,7 +263,93 @@ pub fn rules() -> Vec<Rule> {
                     |td| matches!(td, TokenData::Volume(data) if data.value.is_some() || data.min_value.is_some() || data.max_value.is_some()),
                 ),
             ],
-            production: Box::new(|nodes| Some(nodes[1].token_data.clone())),
+            production: Box::new(|nodes| {
+                Some(
+                    nodes[1]
+                        .token_data
+                        .clone()
+                        .with_precision(Precision::of_qualifier(nodes[0])),
+                )
+            }),
+        },
+        // "a pint and a half"
+        Rule {
+            name: "<volume> and a half".to_string(),
+            pattern: vec![is_simple_volume(), regex(r#"and a half"#)],
+            production: Box::new(|nodes| {
+                let vol = volume_data(&nodes[0].token_data)?;
+                if !vol.components.is_empty() {
+                    return None;
+                }
+                Some(TokenData::Volume(VolumeData::new(
+                    vol.value? + 0.5,
+                    vol.unit?,
+                )))
+            }),
+        },
+        // "one and a half pints"
+        Rule {
+            name: "<integer> and a half <volume>".to_string(),
+            pattern: vec![
+                predicate(|td| integer_value(td).is_some_and(|v| v > 0)),
+                regex(r#"and a half"#),
+                is_unit_only(),
+            ],
+            production: Box::new(|nodes| {
+                let n = numeral_data(&nodes[0].token_data)?.value;
+                let vol = volume_data(&nodes[2].token_data)?;
+                Some(TokenData::Volume(VolumeData::new(n + 0.5, vol.unit?)))
+            }),
+        },
+        // "1 litre and 250 ml", "1 cup 2 tablespoons"
+        Rule {
+            name: "composite <volume> (with and)".to_string(),
+            pattern: vec![is_simple_volume(), regex(r#"and"#), is_simple_volume()],
+            production: Box::new(|nodes| {
+                let v1 = volume_data(&nodes[0].token_data)?;
+                let v2 = volume_data(&nodes[2].token_data)?;
+                Some(TokenData::Volume(compound(v1, v2)?))
+            }),
+        },
+        Rule {
+            name: "composite <volume>".to_string(),
+            pattern: vec![is_simple_volume(), is_simple_volume()],
+            production: Box::new(|nodes| {
+                let v1 = volume_data(&nodes[0].token_data)?;
+                let v2 = volume_data(&nodes[1].token_data)?;
+                Some(TokenData::Volume(compound(v1, v2)?))
+            }),
+        },
+        // "two 500ml bottles", "2 x 330 ml", "six cans of 330ml"
+        containers_rule(
+            "<count> <volume> <container>",
+            vec![is_count(), is_simple_volume(), regex(CONTAINERS)],
+            0,
+            1,
+        ),
+        containers_rule(
+            "<count> x <volume>",
+            vec![is_count(), regex(r#"x|×|\*"#), is_simple_volume()],
+            0,
+            2,
+        ),
+        containers_rule(
+            "<count> <container> of <volume>",
+            vec![
+                is_count(),
+                regex(&format!("(?:{CONTAINERS}) of")),
+                is_simple_volume(),
+            ],
+            0,
+            2,
+        ),
+        Rule {
+            name: "a <volume> <container>".to_string(),
+            pattern: vec![regex(r#"an?"#), is_simple_volume(), regex(CONTAINERS)],
+            production: Box::new(|nodes| {
+                let vol = volume_data(&nodes[1].token_data)?;
+                Some(TokenData::Volume(containers(1, vol)?))
+            }),
         },
         // between|from <numeral> and|to <volume>
         Rule {
@@ -347,7 +442,10 @@ mod tests {
     fn test_volume() {
         let mut rules = numeral::en::rules();
         rules.extend(super::rules());
-        let options = Options { with_latent: false };
+        let options = Options {
+            with_latent: false,
+            ..Options::default()
+        };
         let context = Context::default();
 
         for (text, expected_val, expected_unit) in &[
@@ -1,8 +1,16 @@
-use crate::dimensions::numeral::helpers::numeral_data;
+use crate::dimensions::numeral::helpers::{integer_value, numeral_data};
 use crate::pattern::{dim, predicate, regex};
 use crate::types::{DimensionKind, Rule, TokenData};
 
-use super::{VolumeData, VolumeUnit};
+use super::{compound, containers_rule, is_simple_volume, VolumeData, VolumeUnit};
+
+/// Containers counted by the volume each holds ("deux bouteilles de 75 cl").
+const CONTAINERS: &str =
+    "bouteilles?|canettes?|verres?|bidons?|cartons?|briques?|bocaux|bocal|fûts?|flacons?";
+
+fn is_count(td: &TokenData) -> bool {
+    integer_value(td).is_some_and(|v| v > 0)
+}
 
 fn volume_data(td: &TokenData) -> Option<&VolumeData> {
     match td {
@@ -107,5 +115,58 @@ pub fn rules() -> Vec<Rule> {
                 Some(TokenData::Volume(VolumeData::new(0.5, v.unit?)))
             }),
         },
+        Rule {
+            name: "<volume> et demi".to_string(),
+            pattern: vec![predicate(is_simple_volume), regex("et demie?")],
+            production: Box::new(|nodes| {
+                let v = volume_data(&nodes[0].token_data)?;
+                if !v.components.is_empty() {
+                    return None;
+                }
+                Some(TokenData::Volume(VolumeData::new(v.value? + 0.5, v.unit?)))
+            }),
+        },
+        Rule {
+            name: "composite <volume> (with et)".to_string(),
+            pattern: vec![
+                predicate(is_simple_volume),
+                regex("et"),
+                predicate(is_simple_volume),
+            ],
+            production: Box::new(|nodes| {
+                let v1 = volume_data(&nodes[0].token_data)?;
+                let v2 = volume_data(&nodes[2].token_data)?;
+                Some(TokenData::Volume(compound(v1, v2)?))
+            }),
+        },
+        Rule {
+            name: "composite <volume>".to_string(),
+            pattern: vec![predicate(is_simple_volume), predicate(is_simple_volume)],
+            production: Box::new(|nodes| {
+                let v1 = volume_data(&nodes[0].token_data)?;
+                let v2 = volume_data(&nodes[1].token_data)?;
+                Some(TokenData::Volume(compound(v1, v2)?))
+            }),
+        },
+        containers_rule(
+            "<count> <container> de <volume>",
+            vec![
+                predicate(is_count),
+                regex(&format!("(?:{CONTAINERS}) d(?:e|')")),
+                predicate(is_simple_volume),
+            ],
+            0,
+            2,
+        ),
+        containers_rule(
+            "<count> x <volume>",
+            vec![
+                predicate(is_count),
+                regex("x|×|\\*"),
+                predicate(is_simple_volume),
+            ],
+            0,
+            2,
+        ),
     ]
 }
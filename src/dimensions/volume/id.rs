@@ -0,0 +1,93 @@
+use crate::dimensions::numeral::helpers::numeral_data;
+use crate::pattern::{dim, predicate, regex};
+use crate::types::{DimensionKind, Rule, TokenData};
+
+use super::{VolumeData, VolumeUnit};
+
+fn volume_data(td: &TokenData) -> Option<&VolumeData> {
+    match td {
+        TokenData::Volume(d) => Some(d),
+        _ => None,
+    }
+}
+
+fn is_unit_only() -> crate::types::PatternItem {
+    predicate(|td| {
+        matches!(
+            td,
+            TokenData::Volume(d)
+                if d.value.is_none()
+                    && d.unit.is_some()
+                    && d.min_value.is_none()
+                    && d.max_value.is_none()
+        )
+    })
+}
+
+fn unit_rule(name: &str, pattern: &str, unit: VolumeUnit) -> Rule {
+    Rule {
+        name: name.to_string(),
+        pattern: vec![regex(pattern)],
+        production: Box::new(move |_| Some(TokenData::Volume(VolumeData::unit_only(unit)))),
+    }
+}
+
+pub fn rules() -> Vec<Rule> {
+    vec![
+        Rule {
+            name: "number as volume".to_string(),
+            pattern: vec![dim(DimensionKind::Numeral)],
+            production: Box::new(|nodes| {
+                let n = numeral_data(&nodes[0].token_data)?;
+                if n.value <= 0.0 {
+                    return None;
+                }
+                Some(TokenData::Volume(VolumeData::value_only(n.value)))
+            }),
+        },
+        Rule {
+            name: "<number> <volume>".to_string(),
+            pattern: vec![dim(DimensionKind::Numeral), is_unit_only()],
+            production: Box::new(|nodes| {
+                let n = numeral_data(&nodes[0].token_data)?;
+                let v = volume_data(&nodes[1].token_data)?;
+                let unit = v.unit?;
+                if n.value <= 0.0 {
+                    return None;
+                }
+                Some(TokenData::Volume(VolumeData::new(n.value, unit)))
+            }),
+        },
+        unit_rule(
+            "<latent vol> ml",
+            "\\bm(l|ililiter)\\b",
+            VolumeUnit::Millilitre,
+        ),
+        unit_rule(
+            "<vol> hectoliters",
+            "\\bhektoliter\\b",
+            VolumeUnit::Hectolitre,
+        ),
+        unit_rule("<vol> liters", "\\bl(iter)?\\b", VolumeUnit::Litre),
+        unit_rule("<latent vol> gallon", "\\bgalon\\b", VolumeUnit::Gallon),
+        unit_rule("<vol> cups", "\\bcangkir\\b", VolumeUnit::Cup),
+        unit_rule(
+            "<vol> tablespoons",
+            "\\bsendok makan\\b|\\bsdm\\b",
+            VolumeUnit::Tablespoon,
+        ),
+        unit_rule(
+            "<vol> teaspoons",
+            "\\bsendok teh\\b|\\bsdt\\b",
+            VolumeUnit::Teaspoon,
+        ),
+        Rule {
+            name: "half <volume>".to_string(),
+            pattern: vec![regex("\\bsetengah"), is_unit_only()],
+            production: Box::new(|nodes| {
+                let v = volume_data(&nodes[1].token_data)?;
+                Some(TokenData::Volume(VolumeData::new(0.5, v.unit?)))
+            }),
+        },
+    ]
+}
@@ -6,6 +6,7 @@ pub mod es;
 pub mod fr;
 pub mod ga;
 pub mod hr;
+pub mod id;
 pub mod it;
 pub mod km;
 pub mod ko;
@@ -17,7 +18,11 @@ pub mod ru;
 pub mod tr;
 pub mod zh;
 
-use crate::types::{DimensionValue, MeasurementPoint, MeasurementValue};
+use crate::dimensions::numeral::helpers::integer_value;
+use crate::types::{
+    DimensionValue, MeasurementPoint, MeasurementValue, PatternItem, Precision, Rule, TokenData,
+    Unit,
+};
 
 #[derive(Debug, Clone)]
 pub struct VolumeData {
@@ -25,23 +30,40 @@ pub struct VolumeData {
     pub unit: Option<VolumeUnit>,
     pub min_value: Option<f64>,
     pub max_value: Option<f64>,
+    pub precision: Option<Precision>,
+    /// The volumes a compound ("1 litre and 250 ml") or a count of
+    /// containers ("two 500ml bottles") sums, in the order written; empty
+    /// for a single volume.
+    pub components: Vec<(f64, VolumeUnit)>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The unit of a volume.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum VolumeUnit {
+    /// Gallons.
     Gallon,
+    /// Litres.
     Litre,
+    /// Millilitres.
     Millilitre,
+    /// Centilitres.
     Centilitre,
+    /// Hectolitres.
     Hectolitre,
+    /// Cups.
     Cup,
+    /// Pints.
     Pint,
+    /// Quarts.
     Quart,
+    /// Tablespoons.
     Tablespoon,
+    /// Teaspoons.
     Teaspoon,
 }
 
 impl VolumeUnit {
+    /// Returns the unit name (e.g. `"gallon"`).
     pub fn as_str(&self) -> &'static str {
         match self {
             VolumeUnit::Gallon => "gallon",
@@ -56,6 +78,30 @@ impl VolumeUnit {
             VolumeUnit::Teaspoon => "teaspoon",
         }
     }
+
+    /// `value` of this unit in litres, taking gallons, quarts, pints, cups
+    /// and spoons as US customary measures.
+    ///
+    /// ```
+    /// use duckling::VolumeUnit;
+    ///
+    /// assert_eq!(VolumeUnit::Centilitre.to_litres(75.0), 0.75);
+    /// ```
+    pub fn to_litres(self, value: f64) -> f64 {
+        const LITRES_PER_GALLON: f64 = 3.785_411_784;
+        match self {
+            VolumeUnit::Gallon => value * LITRES_PER_GALLON,
+            VolumeUnit::Litre => value,
+            VolumeUnit::Millilitre => value / 1000.0,
+            VolumeUnit::Centilitre => value / 100.0,
+            VolumeUnit::Hectolitre => value * 100.0,
+            VolumeUnit::Cup => value * LITRES_PER_GALLON / 16.0,
+            VolumeUnit::Pint => value * LITRES_PER_GALLON / 8.0,
+            VolumeUnit::Quart => value * LITRES_PER_GALLON / 4.0,
+            VolumeUnit::Tablespoon => value * LITRES_PER_GALLON / 256.0,
+            VolumeUnit::Teaspoon => value * LITRES_PER_GALLON / 768.0,
+        }
+    }
 }
 
 impl VolumeData {
@@ -66,6 +112,8 @@ impl VolumeData {
             unit: Some(unit),
             min_value: None,
             max_value: None,
+            precision: None,
+            components: Vec::new(),
         }
     }
 
@@ -76,6 +124,8 @@ impl VolumeData {
             unit: None,
             min_value: None,
             max_value: None,
+            precision: None,
+            components: Vec::new(),
         }
     }
 
@@ -86,6 +136,8 @@ impl VolumeData {
             unit: Some(unit),
             min_value: None,
             max_value: None,
+            precision: None,
+            components: Vec::new(),
         }
     }
 
@@ -111,38 +163,102 @@ impl VolumeData {
     }
 }
 
+/// Most containers a count may name before it is taken for something else.
+const MAX_CONTAINERS: i64 = 100;
+
+fn volume_data(td: &TokenData) -> Option<&VolumeData> {
+    match td {
+        TokenData::Volume(d) => Some(d),
+        _ => None,
+    }
+}
+
+/// Whether `td` is one volume with a value and a unit, no interval.
+pub(super) fn is_simple_volume(td: &TokenData) -> bool {
+    matches!(
+        td,
+        TokenData::Volume(d)
+            if d.value.is_some()
+                && d.unit.is_some()
+                && d.min_value.is_none()
+                && d.max_value.is_none()
+    )
+}
+
+/// The parts `data` sums, or `data` itself when it is a single volume.
+fn parts(data: &VolumeData) -> Option<Vec<(f64, VolumeUnit)>> {
+    if data.components.is_empty() {
+        Some(vec![(data.value?, data.unit?)])
+    } else {
+        Some(data.components.clone())
+    }
+}
+
+/// The single volume "1 litre and 250 ml" is: the sum of a positive volume
+/// and a smaller one in a different unit, in litres, keeping the parts
+/// written. `None` when the second is not smaller.
+pub fn compound(first: &VolumeData, second: &VolumeData) -> Option<VolumeData> {
+    let (v1, u1, v2, u2) = (first.value?, first.unit?, second.value?, second.unit?);
+    if u1 == u2 || v1 <= 0.0 || v2 <= 0.0 || u2.to_litres(1.0) >= u1.to_litres(1.0) {
+        return None;
+    }
+    let mut data = VolumeData::new(u1.to_litres(v1) + u2.to_litres(v2), VolumeUnit::Litre);
+    data.components = parts(first)?;
+    data.components.extend(parts(second)?);
+    Some(data)
+}
+
+/// The volume of `count` containers of `volume` each ("two 500ml
+/// bottles"), in litres with a part per container; one container is just
+/// `volume`.
+pub fn containers(count: i64, volume: &VolumeData) -> Option<VolumeData> {
+    let (value, unit) = (volume.value?, volume.unit?);
+    if !volume.components.is_empty() || value <= 0.0 || !(1..=MAX_CONTAINERS).contains(&count) {
+        return None;
+    }
+    if count == 1 {
+        return Some(volume.clone());
+    }
+    let mut data = VolumeData::new(count as f64 * unit.to_litres(value), VolumeUnit::Litre);
+    data.components = vec![(value, unit); usize::try_from(count).ok()?];
+    Some(data)
+}
+
+/// A rule reading `count` containers of the volume matched by the items of
+/// `pattern` at `count_at` and `volume_at`; see [`containers`].
+pub(super) fn containers_rule(
+    name: &str,
+    pattern: Vec<PatternItem>,
+    count_at: usize,
+    volume_at: usize,
+) -> Rule {
+    Rule {
+        name: name.to_string(),
+        pattern,
+        production: Box::new(move |nodes| {
+            let count = integer_value(&nodes.get(count_at)?.token_data)?;
+            let volume = volume_data(&nodes.get(volume_at)?.token_data)?;
+            Some(TokenData::Volume(containers(count, volume)?))
+        }),
+    }
+}
+
 pub fn resolve(data: &VolumeData) -> Option<DimensionValue> {
-    let unit = data.unit.as_ref()?;
-    let unit_str = unit.as_str().to_string();
+    let unit = Unit::from(data.unit?);
 
     let mv = match (data.value, data.min_value, data.max_value) {
-        (Some(v), _, _) => MeasurementValue::Value {
-            value: v,
-            unit: unit_str,
-        },
+        (Some(v), _, _) => MeasurementValue::Value { value: v, unit },
         (None, Some(from), Some(to)) => MeasurementValue::Interval {
-            from: Some(MeasurementPoint {
-                value: from,
-                unit: unit_str.clone(),
-            }),
-            to: Some(MeasurementPoint {
-                value: to,
-                unit: unit_str,
-            }),
+            from: Some(MeasurementPoint { value: from, unit }),
+            to: Some(MeasurementPoint { value: to, unit }),
         },
         (None, Some(from), None) => MeasurementValue::Interval {
-            from: Some(MeasurementPoint {
-                value: from,
-                unit: unit_str,
-            }),
+            from: Some(MeasurementPoint { value: from, unit }),
             to: None,
         },
         (None, None, Some(to)) => MeasurementValue::Interval {
             from: None,
-            to: Some(MeasurementPoint {
-                value: to,
-                unit: unit_str,
-            }),
+            to: Some(MeasurementPoint { value: to, unit }),
         },
         _ => return None,
     };
@@ -1,15 +1,19 @@
-/// A Document wraps the input text and provides efficient lookup
-/// for word boundaries, adjacency checks, etc.
+use std::ops::Range;
+use unicode_normalization::{is_nfkc_quick, IsNormalized, UnicodeNormalization};
+
+/// The text the engine runs over, with efficient lookup for word
+/// boundaries, adjacency checks, etc.
 /// Ported from Haskell Duckling's Document.hs
 #[derive(Debug, Clone)]
-pub struct Document {
+pub(crate) struct IndexedText {
     text: String,
-    /// first_non_adjacent[i] = index of first non-whitespace byte at or after position i.
+    /// first_non_adjacent[i] = index of first byte at or after position i that is
+    /// neither whitespace nor a bidi control.
     /// If none exists, equals byte_len. Used for O(1) adjacency checks.
     first_non_adjacent: Vec<usize>,
 }
 
-impl Document {
+impl IndexedText {
     pub fn new(text: &str) -> Self {
         let byte_len = text.len();
 
@@ -18,7 +22,7 @@ impl Document {
         let mut first_non_adjacent = vec![byte_len; byte_len.saturating_add(1)];
         let mut next_non_ws = byte_len;
         for (byte_pos, ch) in text.char_indices().rev() {
-            if !ch.is_whitespace() {
+            if !ch.is_whitespace() && !is_bidi_control(ch) {
                 next_non_ws = byte_pos;
             }
             // Fill all byte positions within this character
@@ -31,7 +35,7 @@ impl Document {
             }
         }
 
-        Document {
+        IndexedText {
             text: text.to_string(),
             first_non_adjacent,
         }
@@ -41,7 +45,8 @@ impl Document {
         &self.text
     }
 
-    /// Check if two ranges are adjacent (no non-whitespace between them).
+    /// Check if two ranges are adjacent (only whitespace or bidi controls
+    /// between them).
     /// Uses precomputed first_non_adjacent array for O(1) lookup.
     pub fn is_adjacent(&self, end_a: usize, start_b: usize) -> bool {
         if end_a > start_b {
@@ -51,21 +56,333 @@ impl Document {
     }
 }
 
+/// Whether `ch` is an invisible bidirectional formatting character, such
+/// as the marks RTL editors put around numbers and Latin text. Like
+/// whitespace, these don't keep tokens on either side from combining.
+fn is_bidi_control(ch: char) -> bool {
+    matches!(
+        ch,
+        '\u{061C}' | '\u{200E}' | '\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}'
+    )
+}
+
+/// Text to parse, built from an original text by keeping or rewriting
+/// parts of it, e.g. ASR output with "comma" spelled out or tokens rejoined.
+/// [`parse_document`](crate::parse_document) parses the built text and
+/// reports entities at their offsets in the original.
+///
+/// # Example
+/// ```
+/// use duckling::{parse_document, Context, DimensionKind, Document, Lang, Locale, Options};
+///
+/// let original = "meet at three thirty period tomorrow";
+/// let mut doc = Document::mapped(original);
+/// doc.keep(0..20).replace(20..27, ".").keep(27..36);
+/// assert_eq!(doc.text(), "meet at three thirty. tomorrow");
+///
+/// let locale = Locale::new(Lang::EN, None);
+/// let entities = parse_document(&doc, &locale, &[DimensionKind::Numeral], &Context::default(), &Options::default());
+/// assert_eq!(entities[0].body, "three thirty");
+/// assert_eq!((entities[0].start, entities[0].end), (8, 20));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Document {
+    original: String,
+    text: String,
+    /// (range in `text`, range in `original`, whether copied verbatim),
+    /// in the order the pieces were added.
+    pieces: Vec<(Range<usize>, Range<usize>, bool)>,
+}
+
+impl Document {
+    /// A document whose text is `original` itself.
+    pub fn new(original: &str) -> Self {
+        let mut doc = Document::mapped(original);
+        doc.keep(0..original.len());
+        doc
+    }
+
+    /// An empty document over `original`, to be built with
+    /// [`keep`](Document::keep) and [`replace`](Document::replace) in order.
+    /// Parts of `original` never added are left out of the parsed text.
+    pub fn mapped(original: &str) -> Self {
+        Document {
+            original: original.to_string(),
+            text: String::new(),
+            pieces: Vec::new(),
+        }
+    }
+
+    /// Append `original[range]` unchanged.
+    ///
+    /// # Panics
+    /// If `range` is out of bounds or not on char boundaries of the original.
+    pub fn keep(&mut self, range: Range<usize>) -> &mut Self {
+        let piece = self.original[range.clone()].to_string();
+        self.push(&piece, range, true)
+    }
+
+    /// Append `text` in place of `original[range]`. An empty `range` inserts
+    /// `text`, and an empty `text` drops the range.
+    ///
+    /// # Panics
+    /// If `range` is out of bounds or not on char boundaries of the original.
+    pub fn replace(&mut self, range: Range<usize>, text: &str) -> &mut Self {
+        assert!(
+            self.original.get(range.clone()).is_some(),
+            "range {range:?} is not a valid slice of the original text"
+        );
+        self.push(text, range, false)
+    }
+
+    fn push(&mut self, text: &str, original: Range<usize>, verbatim: bool) -> &mut Self {
+        if text.is_empty() && original.is_empty() {
+            return self;
+        }
+        let start = self.text.len();
+        self.text.push_str(text);
+        self.pieces
+            .push((start..self.text.len(), original, verbatim));
+        self
+    }
+
+    /// The text that is parsed.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// The original text.
+    pub fn original(&self) -> &str {
+        &self.original
+    }
+
+    /// The range of the original text that `range` of the built text came
+    /// from. Offsets inside kept pieces map exactly; a rewritten piece maps
+    /// as a whole.
+    pub fn to_original(&self, range: Range<usize>) -> Range<usize> {
+        let piece_at = |pos: usize| {
+            let i = self.pieces.partition_point(|(text, _, _)| text.end <= pos);
+            self.pieces
+                .get(i)
+                .filter(|(text, _, _)| text.start <= pos && pos < text.end)
+        };
+        let start = match piece_at(range.start) {
+            Some((text, original, true)) => original
+                .start
+                .saturating_add(range.start.saturating_sub(text.start)),
+            Some((_, original, false)) => original.start,
+            None => self.original.len(),
+        };
+        let end = match piece_at(range.end.saturating_sub(1)) {
+            Some((text, original, true)) => original
+                .start
+                .saturating_add(range.end.saturating_sub(text.start)),
+            Some((_, original, false)) => original.end,
+            None => start,
+        };
+        start..end.max(start)
+    }
+}
+
+/// A document over `text` with compatibility characters folded as NFKC
+/// folds them, or `None` if nothing needed folding. Full-width letters,
+/// digits and punctuation ("１２３") become ASCII, no-break and other
+/// fixed-width spaces become plain spaces, letters followed by combining
+/// accents are composed ("e\u{301}" becomes "é"), and the apostrophes "’"
+/// and "ʼ" become "'", so patterns written in ASCII and composed letters
+/// match.
+///
+/// Characters that patterns spell as written are kept: "µ", the ordinal
+/// indicators "º" and "ª", "″", the Lao ligatures "ໜ" and "ໝ", and
+/// letters that normalization would decompose, like Devanagari "\u{95B}".
+/// "‘", which Ukrainian numerals are written with, is not an apostrophe
+/// here.
+pub(crate) fn normalize(text: &str) -> Option<Document> {
+    if text.is_ascii()
+        || (is_nfkc_quick(text.chars()) == IsNormalized::Yes && !text.contains(APOSTROPHES))
+    {
+        return None;
+    }
+    let mut doc: Option<Document> = None;
+    let mut kept = 0;
+    let mut folded = String::new();
+    for cluster in clusters(text) {
+        if !fold(&text[cluster.clone()], &mut folded) {
+            continue;
+        }
+        doc.get_or_insert_with(|| Document::mapped(text))
+            .keep(kept..cluster.start)
+            .replace(cluster.clone(), &folded);
+        kept = cluster.end;
+    }
+    let mut doc = doc?;
+    doc.keep(kept..text.len());
+    Some(doc)
+}
+
+/// Compatibility characters kept as written; see [`normalize`].
+const KEPT: &[char] = &[
+    '\u{B5}', '\u{AA}', '\u{BA}', '\u{2033}', '\u{EDC}', '\u{EDD}',
+];
+
+/// Apostrophes folded to "'"; see [`normalize`].
+const APOSTROPHES: &[char] = &['\u{2019}', '\u{2BC}'];
+
+/// Byte ranges of `text` that fold independently: a character with the
+/// combining accents that follow it. Other scripts' combining vowel signs
+/// are left apart, as patterns spell some of them decomposed.
+fn clusters(text: &str) -> Vec<Range<usize>> {
+    let mut clusters: Vec<Range<usize>> = Vec::new();
+    for (i, ch) in text.char_indices() {
+        let end = i.saturating_add(ch.len_utf8());
+        let continues = ('\u{300}'..='\u{36F}').contains(&ch);
+        match clusters.last_mut() {
+            Some(last) if continues => last.end = end,
+            _ => clusters.push(i..end),
+        }
+    }
+    clusters
+}
+
+/// Write the folded form of `cluster` to `folded`, returning whether it
+/// differs from `cluster`.
+fn fold(cluster: &str, folded: &mut String) -> bool {
+    if cluster.is_ascii() {
+        return false;
+    }
+    let mut chars = cluster.chars();
+    if let (Some(ch), None) = (chars.next(), chars.next()) {
+        if KEPT.contains(&ch) {
+            return false;
+        }
+        if APOSTROPHES.contains(&ch) {
+            folded.clear();
+            folded.push('\'');
+            return true;
+        }
+    }
+    if is_nfkc_quick(cluster.chars()) == IsNormalized::Yes {
+        return false;
+    }
+    folded.clear();
+    folded.extend(cluster.nfkc());
+    // Letters excluded from composition, which patterns spell precomposed
+    let decomposed =
+        folded.chars().count() > cluster.chars().count() && cluster.nfc().eq(folded.chars());
+    folded != cluster && !decomposed
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_basic_document() {
-        let doc = Document::new("hello world");
+        let doc = IndexedText::new("hello world");
         assert_eq!(doc.text(), "hello world");
     }
 
     #[test]
     fn test_adjacency() {
-        let doc = Document::new("hello world");
+        let doc = IndexedText::new("hello world");
         assert!(doc.is_adjacent(5, 6)); // space between
         assert!(doc.is_adjacent(5, 5)); // same pos is adjacent (empty range)
         assert!(doc.is_adjacent(0, 0));
+
+        // "a", then a space and a right-to-left mark, then "b"
+        let doc = IndexedText::new("a \u{200f}b");
+        assert!(doc.is_adjacent(1, 5));
+        assert!(!IndexedText::new("a x b").is_adjacent(1, 4));
+    }
+
+    #[test]
+    fn test_document_maps_rewrites_to_original() {
+        use crate::{parse_document, Context, DimensionKind, Lang, Locale, Options};
+
+        let original = "in two wks ok";
+        let mut doc = Document::mapped(original);
+        doc.keep(0..7).replace(7..10, "weeks").keep(10..13);
+        assert_eq!(doc.text(), "in two weeks ok");
+        assert_eq!(doc.to_original(3..6), 3..6);
+        assert_eq!(doc.to_original(8..10), 7..10);
+        assert_eq!(Document::new(original).to_original(3..10), 3..10);
+
+        let entities = parse_document(
+            &doc,
+            &Locale::new(Lang::EN, None),
+            &[DimensionKind::Duration],
+            &Context::default(),
+            &Options::default(),
+        );
+        assert_eq!(entities.len(), 1, "{entities:?}");
+        assert_eq!(entities[0].body, "two wks");
+        assert_eq!((entities[0].start, entities[0].end), (3, 10));
+    }
+
+    #[test]
+    fn test_normalize_maps_folds_to_original() {
+        assert_eq!(normalize("at 3pm"), None);
+        assert_eq!(normalize("café"), None);
+
+        let original = "１２３\u{a0}dollars, cafe\u{301}’s";
+        let doc = normalize(original).unwrap();
+        assert_eq!(doc.text(), "123 dollars, café's");
+        // Each full-width digit is three bytes and the no-break space two
+        assert_eq!(doc.to_original(0..3), 0..9);
+        assert_eq!(doc.to_original(1..2), 3..6);
+        assert_eq!(doc.to_original(4..11), 11..18);
+        assert_eq!(doc.to_original(13..20), 20..30);
+
+        for kept in ["5 µg", "1º", "ໜຶ່ງ", "\u{95B}"] {
+            assert_eq!(normalize(kept), None, "{kept:?}");
+        }
+    }
+
+    #[test]
+    fn test_parse_normalized_text() {
+        use crate::{parse, Context, DimensionKind, DimensionValue, Lang, Locale, Options};
+
+        let parse_in = |lang: Lang, dim: DimensionKind, text: &str| {
+            parse(
+                text,
+                &Locale::new(lang, None),
+                &[dim],
+                &Context::default(),
+                &Options::default(),
+            )
+        };
+        let entities = parse_in(Lang::EN, DimensionKind::Numeral, "order １２３ now");
+        assert_eq!(entities.len(), 1, "{entities:?}");
+        assert_eq!(entities[0].value, DimensionValue::Numeral(123.0));
+        assert_eq!(entities[0].body, "１２３");
+        assert_eq!((entities[0].start, entities[0].end), (6, 15));
+
+        // A curly apostrophe, and "ñ" as "n" with a combining tilde
+        for (lang, text) in [(Lang::EN, "at three o’clock"), (Lang::ES, "man\u{303}ana")] {
+            let entities = parse_in(lang, DimensionKind::Time, text);
+            assert_eq!(entities.len(), 1, "{text:?}: {entities:?}");
+            assert_eq!(entities[0].end, text.len(), "{text:?}");
+        }
+
+        let raw = Options::builder().normalize_unicode(false).build();
+        let entities = parse(
+            "man\u{303}ana",
+            &Locale::new(Lang::ES, None),
+            &[DimensionKind::Time],
+            &Context::default(),
+            &raw,
+        );
+        assert!(
+            entities.iter().all(|e| e.body != "man\u{303}ana"),
+            "{entities:?}"
+        );
+    }
+
+    #[test]
+    fn test_normalize_skips_normalized_text() {
+        assert_eq!(normalize("mañana às 15h, 東京で"), None);
+        assert_eq!(normalize("ÐÞ µ"), None);
+        let doc = normalize("ok’").unwrap();
+        assert_eq!(doc.text(), "ok'");
     }
 }
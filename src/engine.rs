@@ -3,16 +3,16 @@ use std::hash::{Hash, Hasher};
 #[cfg(not(debug_assertions))]
 use std::panic::{catch_unwind, AssertUnwindSafe};
 use std::rc::Rc;
-use std::sync::Mutex;
+use std::time::Instant;
 
-use once_cell::sync::Lazy;
 use regex::Regex;
+use smallvec::{smallvec, SmallVec};
 
-use crate::document::Document;
+use crate::document::IndexedText;
 use crate::resolve::{Context, Options};
 use crate::stash::Stash;
 use crate::types::{
-    DimensionKind, Entity, Node, PatternItem, Range, RegexMatchData, Rule, TokenData,
+    Children, DimensionKind, Entity, Node, PatternItem, Range, RegexMatchData, Rule, TokenData,
 };
 
 type RegexMatches = Vec<(Range, Vec<Option<String>>)>;
@@ -31,8 +31,13 @@ struct ParseLimits {
     max_new_nodes_per_iteration: usize,
     max_nodes: usize,
     max_iterations: usize,
+    deadline: Option<Instant>,
 }
 
+/// Rules applied between checks of the deadline, so a budgeted parse
+/// doesn't read the clock for every rule.
+const RULES_PER_DEADLINE_CHECK: usize = 16;
+
 impl ParseLimits {
     fn for_text_len(_text_len: usize) -> Self {
         Self {
@@ -41,39 +46,40 @@ impl ParseLimits {
             max_new_nodes_per_iteration: 1_024,
             max_nodes: 3_000,
             max_iterations: 24,
+            deadline: None,
         }
     }
+
+    /// Whether the deadline has passed, checked only every
+    /// [`RULES_PER_DEADLINE_CHECK`] rules.
+    fn out_of_time(&self, rule_idx: usize) -> bool {
+        rule_idx % RULES_PER_DEADLINE_CHECK == 0 && past(self.deadline)
+    }
+}
+
+/// Whether `deadline` is set and has passed.
+pub(crate) fn past(deadline: Option<Instant>) -> bool {
+    deadline.is_some_and(|deadline| Instant::now() >= deadline)
 }
 
-/// Cached RegexSet built from all unique regex patterns in a rule set.
-/// Used as a negative filter: if a pattern doesn't match anywhere in the text,
-/// we can skip it everywhere (first-pattern cache + match_remaining).
-struct CachedRegexSet {
+/// RegexSet built from the unique regexes that lead a rule in a rule set.
+/// Used as a negative filter: if a leading pattern doesn't match anywhere in
+/// the text, its rules are skipped without running it. Patterns later in a
+/// rule are left out, so they are only compiled once a rule gets that far.
+/// `lang::RuleSet` builds one on first use and keeps it alongside its rules.
+pub(crate) struct CachedRegexSet {
     set: regex::RegexSet,
     /// Map from pattern string → index in the RegexSet, for O(1) match checks.
     pattern_to_idx: HashMap<String, usize>,
 }
 
-/// Global cache of RegexSets, keyed by rules slice pointer.
-/// Safe because rules are `&'static [Rule]` (leaked by `lang::rules_for`).
-static REGEX_SET_CACHE: Lazy<Mutex<HashMap<usize, &'static CachedRegexSet>>> =
-    Lazy::new(|| Mutex::new(HashMap::new()));
-
-fn get_or_build_regex_set(rules: &[Rule]) -> &'static CachedRegexSet {
-    let key = rules.as_ptr() as usize;
-    {
-        let cache = REGEX_SET_CACHE.lock().unwrap();
-        if let Some(cached) = cache.get(&key) {
-            return cached;
-        }
-    }
-
-    // Collect unique regex patterns across all rule positions
-    let mut patterns: Vec<String> = Vec::new();
-    let mut pattern_to_idx: HashMap<String, usize> = HashMap::new();
-    for rule in rules {
-        for item in &rule.pattern {
-            if let PatternItem::Regex(re) = item {
+impl CachedRegexSet {
+    pub(crate) fn new(rules: &[Rule]) -> Self {
+        // Collect unique regex patterns in first position
+        let mut patterns: Vec<String> = Vec::new();
+        let mut pattern_to_idx: HashMap<String, usize> = HashMap::new();
+        for rule in rules {
+            if let Some(PatternItem::Regex(re)) = rule.pattern.first() {
                 let pat = re.as_str().to_string();
                 pattern_to_idx.entry(pat).or_insert_with_key(|pat| {
                     let idx = patterns.len();
@@ -82,16 +88,13 @@ fn get_or_build_regex_set(rules: &[Rule]) -> &'static CachedRegexSet {
                 });
             }
         }
-    }
 
-    let set = regex::RegexSet::new(&patterns).expect("all patterns should be valid regexes");
-    let cached = Box::leak(Box::new(CachedRegexSet {
-        set,
-        pattern_to_idx,
-    }));
-
-    REGEX_SET_CACHE.lock().unwrap().insert(key, cached);
-    cached
+        let set = regex::RegexSet::new(&patterns).expect("all patterns should be valid regexes");
+        CachedRegexSet {
+            set,
+            pattern_to_idx,
+        }
+    }
 }
 
 /// Parse text and resolve all entities.
@@ -146,32 +149,75 @@ pub fn parse_and_resolve_with_nodes(
             continue;
         }
         if let Some(entity) = crate::resolve::resolve(node, context, options, doc_text) {
-            results.push((node.clone(), entity));
+            results.push((node.as_ref().clone(), entity));
         }
     }
 
     results
 }
 
+/// Working buffers for the parsing loop. A [`Parser`](crate::Parser) keeps
+/// one across calls so the node dedup set and the per-position regex cache
+/// reuse their allocations instead of growing from empty on every parse.
+#[derive(Default)]
+pub(crate) struct ParseScratch {
+    seen: HashSet<SeenKey>,
+    pos_cache: PositionRegexCache,
+}
+
+impl ParseScratch {
+    fn reset(&mut self) {
+        self.seen.clear();
+        for positions in self.pos_cache.values_mut() {
+            positions.clear();
+        }
+    }
+}
+
 /// Run the saturation-based parsing loop.
 pub fn parse_string(text: &str, rules: &[Rule]) -> Stash {
-    let doc = Document::new(text);
+    let regex_set = CachedRegexSet::new(rules);
+    parse_string_with(text, rules, &regex_set, &mut ParseScratch::default(), None)
+}
+
+/// Run the saturation-based parsing loop over `rules`, prefiltered by
+/// `regex_set` built from them, with buffers from `scratch`, stopping with
+/// the nodes found so far once `deadline` passes.
+pub(crate) fn parse_string_with(
+    text: &str,
+    rules: &[Rule],
+    regex_set: &CachedRegexSet,
+    scratch: &mut ParseScratch,
+    deadline: Option<Instant>,
+) -> Stash {
+    scratch.reset();
+    // `seen` tracks nodes to deduplicate while preserving alternative parses
+    // with different token payloads at the same span/rule. `pos_cache` holds
+    // regex evaluations in match_remaining so the same regex at the same
+    // position is never run twice across different rules or iterations.
+    let ParseScratch { seen, pos_cache } = scratch;
+    let doc = IndexedText::new(text);
     let mut stash = Stash::new();
-    let limits = ParseLimits::for_text_len(text.len());
+    let limits = ParseLimits {
+        deadline,
+        ..ParseLimits::for_text_len(text.len())
+    };
 
-    // Use a cached RegexSet to quickly determine which patterns match anywhere
+    // Use the RegexSet to quickly determine which patterns match anywhere
     // in the text. Patterns that don't match at all can be skipped everywhere.
-    let regex_set = get_or_build_regex_set(rules);
     let set_matches = regex_set.set.matches(text);
 
     // Pre-compute regex matches for all regex-leading rules once.
     // Skip patterns the RegexSet says don't match anywhere.
     // Deduplicate by pattern string so shared patterns only run once.
     let mut pattern_cache: HashMap<String, RegexMatches> = HashMap::new();
+    let mut expired = false;
     let regex_cache: Vec<Option<RegexMatches>> = rules
         .iter()
-        .map(|rule| {
-            if rule.pattern.is_empty() {
+        .enumerate()
+        .map(|(i, rule)| {
+            expired = expired || limits.out_of_time(i);
+            if rule.pattern.is_empty() || expired {
                 return None;
             }
             if let PatternItem::Regex(ref re) = rule.pattern[0] {
@@ -198,10 +244,6 @@ pub fn parse_string(text: &str, rules: &[Rule]) -> Stash {
         .collect();
     drop(pattern_cache);
 
-    // Track seen nodes to deduplicate while preserving alternative parses
-    // with different token payloads at the same span/rule.
-    let mut seen: HashSet<SeenKey> = HashSet::new();
-
     // Phase 1: Apply all regex-leading rules to find initial tokens
     let initial = apply_regex_rules(rules, &regex_cache, &limits);
     for node in initial.all_nodes() {
@@ -213,26 +255,18 @@ pub fn parse_string(text: &str, rules: &[Rule]) -> Stash {
     }
     stash.merge_from(initial);
 
-    // Cache for regex evaluations in match_remaining so the same regex at the same
-    // position is never run twice across different rules or iterations.
-    let mut pos_cache = PositionRegexCache::new();
-
     // Phase 2: Saturation loop - keep applying rules until no new tokens
     let mut iterations = 0usize;
     loop {
-        if iterations >= limits.max_iterations || seen.len() >= limits.max_nodes {
+        if iterations >= limits.max_iterations
+            || seen.len() >= limits.max_nodes
+            || past(limits.deadline)
+        {
             break;
         }
         iterations = iterations.saturating_add(1);
-        let new_stash = apply_all_rules(
-            &doc,
-            rules,
-            &stash,
-            &regex_cache,
-            &seen,
-            &limits,
-            &mut pos_cache,
-        );
+        let new_stash =
+            apply_all_rules(&doc, rules, &stash, &regex_cache, seen, &limits, pos_cache);
         let mut actually_new = Stash::new();
         for node in new_stash.into_nodes() {
             if seen.len() >= limits.max_nodes {
@@ -240,7 +274,7 @@ pub fn parse_string(text: &str, rules: &[Rule]) -> Stash {
             }
             let key = dedup_key(&node);
             if seen.insert(key) {
-                actually_new.add(node);
+                actually_new.add_shared(node);
             }
         }
         if actually_new.is_empty() {
@@ -319,7 +353,7 @@ fn apply_regex_rules(
                     token_data: TokenData::RegexMatch(RegexMatchData {
                         groups: groups.clone(),
                     }),
-                    children: Vec::new(),
+                    children: Children::new(),
                     rule_name: None,
                 };
 
@@ -328,7 +362,7 @@ fn apply_regex_rules(
                     if let Some(token_data) = safe_production(rule, &[&regex_node]) {
                         let mut node = Node::new(*range, token_data);
                         node.rule_name = Some(rule.name.clone());
-                        node.children = vec![Rc::new(regex_node)];
+                        node.children = smallvec![Rc::new(regex_node)];
                         stash.add(node);
                     }
                 } else {
@@ -345,7 +379,7 @@ fn apply_regex_rules(
 /// Apply all rules against the current stash to find new tokens.
 /// Skips single-pattern regex rules (already fully handled in phase 1).
 fn apply_all_rules(
-    doc: &Document,
+    doc: &IndexedText,
     rules: &[Rule],
     stash: &Stash,
     regex_cache: &[Option<RegexMatches>],
@@ -359,6 +393,7 @@ fn apply_all_rules(
     for (i, rule) in rules.iter().enumerate() {
         if new_stash.len() >= limits.max_new_nodes_per_iteration
             || seen.len().saturating_add(new_stash.len()) >= limits.max_nodes
+            || limits.out_of_time(i)
         {
             break;
         }
@@ -390,7 +425,7 @@ fn apply_all_rules(
 /// Try to match a rule against the document and stash, producing new nodes.
 /// Uses cached regex matches when available.
 fn match_rule(
-    doc: &Document,
+    doc: &IndexedText,
     rule: &Rule,
     stash: &Stash,
     cached_regex: Option<&RegexMatches>,
@@ -419,7 +454,7 @@ fn match_rule(
                     token_data: TokenData::RegexMatch(RegexMatchData {
                         groups: groups.clone(),
                     }),
-                    children: Vec::new(),
+                    children: Children::new(),
                     rule_name: None,
                 };
 
@@ -427,7 +462,7 @@ fn match_rule(
                     if let Some(token_data) = safe_production(rule, &[&regex_node]) {
                         let mut node = Node::new(*range, token_data);
                         node.rule_name = Some(rule.name.clone());
-                        node.children = vec![Rc::new(regex_node)];
+                        node.children = smallvec![Rc::new(regex_node)];
                         results.push(node);
                     }
                 } else {
@@ -438,7 +473,7 @@ fn match_rule(
                         stash,
                         1,
                         range.end,
-                        vec![Rc::new(regex_node)],
+                        smallvec![Rc::new(regex_node)],
                         limits,
                         pos_cache,
                     );
@@ -457,7 +492,7 @@ fn match_rule(
                         if let Some(token_data) = safe_production(rule, &[node]) {
                             let mut new_node = Node::new(node.range, token_data);
                             new_node.rule_name = Some(rule.name.clone());
-                            new_node.children = vec![Rc::new(node.clone())];
+                            new_node.children = smallvec![Rc::clone(node)];
                             results.push(new_node);
                         }
                     } else {
@@ -467,7 +502,7 @@ fn match_rule(
                             stash,
                             1,
                             node.range.end,
-                            vec![Rc::new(node.clone())],
+                            smallvec![Rc::clone(node)],
                             limits,
                             pos_cache,
                         );
@@ -486,7 +521,7 @@ fn match_rule(
                         if let Some(token_data) = safe_production(rule, &[node]) {
                             let mut new_node = Node::new(node.range, token_data);
                             new_node.rule_name = Some(rule.name.clone());
-                            new_node.children = vec![Rc::new(node.clone())];
+                            new_node.children = smallvec![Rc::clone(node)];
                             results.push(new_node);
                         }
                     } else {
@@ -496,7 +531,7 @@ fn match_rule(
                             stash,
                             1,
                             node.range.end,
-                            vec![Rc::new(node.clone())],
+                            smallvec![Rc::clone(node)],
                             limits,
                             pos_cache,
                         );
@@ -526,12 +561,12 @@ fn extend_with_limit<T>(dst: &mut Vec<T>, mut src: Vec<T>, limit: usize) {
 /// Uses `pos_cache` to memoize regex evaluations by (pattern, position).
 #[allow(clippy::too_many_arguments)]
 fn match_remaining(
-    doc: &Document,
+    doc: &IndexedText,
     rule: &Rule,
     stash: &Stash,
     pattern_idx: usize,
     after_pos: usize,
-    mut matched_so_far: Vec<Rc<Node>>,
+    mut matched_so_far: Children,
     limits: &ParseLimits,
     pos_cache: &mut PositionRegexCache,
 ) -> Vec<Node> {
@@ -539,8 +574,11 @@ fn match_remaining(
 
     if pattern_idx >= rule.pattern.len() {
         // All patterns matched - produce the result
-        let refs: Vec<&Node> = matched_so_far.iter().map(|rc| rc.as_ref()).collect();
-        if let Some(token_data) = safe_production(rule, &refs) {
+        let produced = {
+            let refs: SmallVec<[&Node; 4]> = matched_so_far.iter().map(|rc| rc.as_ref()).collect();
+            safe_production(rule, &refs)
+        };
+        if let Some(token_data) = produced {
             let start = matched_so_far.first().unwrap().range.start;
             let end = matched_so_far.last().unwrap().range.end;
             let mut node = Node::new(Range::new(start, end), token_data);
@@ -588,7 +626,7 @@ fn match_remaining(
                     let regex_node = Node {
                         range,
                         token_data: TokenData::RegexMatch(RegexMatchData { groups }),
-                        children: Vec::new(),
+                        children: Children::new(),
                         rule_name: None,
                     };
                     matched_so_far.push(Rc::new(regex_node));
@@ -608,7 +646,7 @@ fn match_remaining(
         }
         PatternItem::Dimension(dim) => {
             // Collect matching nodes to allow move on the last one
-            let matching: Vec<&Node> = stash
+            let matching: Vec<&Rc<Node>> = stash
                 .nodes_starting_from(after_pos)
                 .filter(|node| {
                     node.token_data.dimension_kind() == Some(*dim)
@@ -625,7 +663,7 @@ fn match_remaining(
                 } else {
                     matched_so_far.clone()
                 };
-                next_matched.push(Rc::new(node.clone()));
+                next_matched.push(Rc::clone(node));
                 let cont = match_remaining(
                     doc,
                     rule,
@@ -641,7 +679,7 @@ fn match_remaining(
         }
         PatternItem::Predicate(pred) => {
             // Collect matching nodes to allow move on the last one
-            let matching: Vec<&Node> = stash
+            let matching: Vec<&Rc<Node>> = stash
                 .nodes_starting_from(after_pos)
                 .filter(|node| {
                     pred(&node.token_data) && doc.is_adjacent(after_pos, node.range.start)
@@ -657,7 +695,7 @@ fn match_remaining(
                 } else {
                     matched_so_far.clone()
                 };
-                next_matched.push(Rc::new(node.clone()));
+                next_matched.push(Rc::clone(node));
                 let cont = match_remaining(
                     doc,
                     rule,
@@ -680,7 +718,7 @@ fn match_remaining(
 /// Matches against the original text and extracts captured groups from the
 /// original text to preserve case (important for email, URL, etc.).
 fn find_regex_matches(
-    doc: &Document,
+    doc: &IndexedText,
     re: &Regex,
     max_matches: usize,
 ) -> Vec<(Range, Vec<Option<String>>)> {
@@ -759,7 +797,10 @@ mod tests {
             "x",
             &rules,
             &Context::default(),
-            &Options { with_latent: false },
+            &Options {
+                with_latent: false,
+                ..Options::default()
+            },
             &[DimensionKind::Numeral],
         );
         let has_12 = entities
@@ -798,7 +839,10 @@ mod tests {
             "x",
             &rules,
             &Context::default(),
-            &Options { with_latent: false },
+            &Options {
+                with_latent: false,
+                ..Options::default()
+            },
             &[DimensionKind::Ordinal],
         );
         let has_ordinal_2 = entities
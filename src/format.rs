@@ -0,0 +1,563 @@
+//! Renders resolved values back to human-readable text.
+
+use chrono::{Datelike, NaiveDateTime, Timelike};
+
+use crate::dimensions::age::AgeUnit;
+use crate::dimensions::amount_of_money::MoneyCurrency;
+use crate::dimensions::distance::DistanceUnit;
+#[cfg(feature = "medical")]
+use crate::dimensions::medical::DoseUnit;
+use crate::dimensions::quantity::QuantityUnit;
+use crate::dimensions::rate::RatePer;
+use crate::dimensions::temperature::TemperatureUnit;
+use crate::dimensions::time::add_grain;
+use crate::dimensions::time_grain::Grain;
+use crate::dimensions::volume::VolumeUnit;
+use crate::locale::{DecimalSeparator, Lang, Locale, Region};
+use crate::types::{
+    DimensionValue, Entity, MeasurementPoint, MeasurementValue, TimePoint, TimeValue, Unit,
+};
+
+impl Entity {
+    /// Renders the resolved value as text, e.g. for a chatbot to confirm what
+    /// it understood. Times are shown at the precision of their grain.
+    ///
+    /// Words and month names are English; the locale picks the decimal
+    /// separator, the date order and a 12- or 24-hour clock. Instants are
+    /// shown in UTC.
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use duckling::{parse, Context, DimensionKind, Lang, Locale, Options, Region};
+    ///
+    /// let context = Context {
+    ///     reference_time: Utc.with_ymd_and_hms(2013, 2, 12, 4, 30, 0).unwrap(),
+    ///     ..Context::default()
+    /// };
+    /// let us = Locale::new(Lang::EN, Some(Region::US));
+    /// let gb = Locale::new(Lang::EN, Some(Region::GB));
+    /// let dims = [DimensionKind::Time, DimensionKind::AmountOfMoney, DimensionKind::Distance];
+    /// let results = parse("$42.50 for 5 miles tomorrow at 3pm", &us, &dims, &context, &Options::default());
+    /// let formatted: Vec<_> = results.iter().map(|e| e.format(&us)).collect();
+    /// assert_eq!(formatted, ["$42.50", "5 mi", "Feb 13, 2013, 3:00 PM"]);
+    /// assert_eq!(results[2].format(&gb), "13 Feb 2013, 15:00");
+    /// ```
+    pub fn format(&self, locale: &Locale) -> String {
+        format_value(&self.value, locale)
+    }
+}
+
+fn format_value(value: &DimensionValue, locale: &Locale) -> String {
+    match value {
+        DimensionValue::Numeral(v) => format_number(*v, locale),
+        DimensionValue::Ordinal(n) => format_ordinal(*n),
+        DimensionValue::Percentage(v) => format!("{}%", format_number(*v, locale)),
+        DimensionValue::Temperature(m)
+        | DimensionValue::Distance(m)
+        | DimensionValue::Volume(m)
+        | DimensionValue::Age(m)
+        | DimensionValue::AmountOfMoney(m) => format_measurement(m, locale),
+        DimensionValue::Quantity {
+            measurement,
+            product,
+        } => match product {
+            Some(product) if is_count(measurement) => {
+                format!("{} {product}", format_measurement(measurement, locale))
+            }
+            Some(product) => format!("{} of {product}", format_measurement(measurement, locale)),
+            None => format_measurement(measurement, locale),
+        },
+        DimensionValue::Rate { count, unit, per } => {
+            let amount = match unit {
+                Some(unit) => format_amount(*count, *unit, locale),
+                None => format_number(*count, locale),
+            };
+            format!("{amount} per {}", format_per(per, locale))
+        }
+        #[cfg(feature = "medical")]
+        DimensionValue::BloodPressure {
+            systolic,
+            diastolic,
+        } => format!("{systolic}/{diastolic} mmHg"),
+        #[cfg(feature = "medical")]
+        DimensionValue::Dosage {
+            value,
+            unit,
+            frequency,
+        } => {
+            let dose = format!("{} {}", format_number(*value, locale), dose_symbol(*unit));
+            match frequency {
+                Some(f) => format!(
+                    "{dose}, {} per {}",
+                    format_number(f.count, locale),
+                    format_per(&f.per, locale)
+                ),
+                None => dose,
+            }
+        }
+        DimensionValue::Email(s) | DimensionValue::PhoneNumber(s) => s.clone(),
+        DimensionValue::Url { value, .. }
+        | DimensionValue::NetworkAddress { value, .. }
+        | DimensionValue::CreditCardNumber { value, .. } => value.clone(),
+        DimensionValue::Coordinate { lat, lon } => {
+            let lat = lat.map(|lat| {
+                let hemisphere = if lat < 0.0 { 'S' } else { 'N' };
+                format!("{}° {hemisphere}", format_number(lat.abs(), locale))
+            });
+            let lon = lon.map(|lon| {
+                let hemisphere = if lon < 0.0 { 'W' } else { 'E' };
+                format!("{}° {hemisphere}", format_number(lon.abs(), locale))
+            });
+            lat.into_iter().chain(lon).collect::<Vec<_>>().join(", ")
+        }
+        DimensionValue::TimeGrain(grain) => grain.as_str().to_string(),
+        DimensionValue::Duration { value, grain, .. } => plural(
+            &format_number(*value as f64, locale),
+            *value,
+            grain.as_str(),
+        ),
+        DimensionValue::Time(time) => format_time(time, locale),
+    }
+}
+
+/// What a rate is per: "week", "6 hours", "gallon".
+fn format_per(per: &RatePer, locale: &Locale) -> String {
+    match per {
+        RatePer::Time { value: 1, grain } => grain.as_str().to_string(),
+        RatePer::Time { value, grain } => plural(
+            &format_number(*value as f64, locale),
+            *value,
+            grain.as_str(),
+        ),
+        RatePer::Unit(unit) => unit.as_str().to_string(),
+    }
+}
+
+#[cfg(feature = "medical")]
+fn dose_symbol(unit: DoseUnit) -> &'static str {
+    match unit {
+        DoseUnit::Microgram => "mcg",
+        DoseUnit::Milligram => "mg",
+        DoseUnit::Gram => "g",
+        DoseUnit::Millilitre => "mL",
+        DoseUnit::Unit => "units",
+        DoseUnit::Tablet => "tablets",
+        DoseUnit::Capsule => "capsules",
+        DoseUnit::Drop => "drops",
+        DoseUnit::Puff => "puffs",
+    }
+}
+
+/// Whether a quantity is a plain count of its product ("24 eggs").
+fn is_count(m: &MeasurementValue) -> bool {
+    let unnamed = Unit::Quantity(QuantityUnit::Unnamed);
+    match m {
+        MeasurementValue::Value { unit, .. } => *unit == unnamed,
+        MeasurementValue::Interval { from, to } => {
+            from.iter().chain(to).all(|point| point.unit == unnamed)
+        }
+    }
+}
+
+fn format_measurement(m: &MeasurementValue, locale: &Locale) -> String {
+    let point = |p: &MeasurementPoint| format_amount(p.value, p.unit, locale);
+    match m {
+        MeasurementValue::Value { value, unit } => format_amount(*value, *unit, locale),
+        MeasurementValue::Interval {
+            from: Some(from),
+            to: Some(to),
+        } => format!("{} – {}", point(from), point(to)),
+        MeasurementValue::Interval {
+            from: Some(from),
+            to: None,
+        } => format!("from {}", point(from)),
+        MeasurementValue::Interval {
+            from: None,
+            to: Some(to),
+        } => format!("up to {}", point(to)),
+        MeasurementValue::Interval {
+            from: None,
+            to: None,
+        } => String::new(),
+    }
+}
+
+fn format_amount(value: f64, unit: Unit, locale: &Locale) -> String {
+    let number = format_number(value, locale);
+    let count = if value == 1.0 { 1 } else { 2 };
+    match unit {
+        Unit::Temperature(u) => {
+            let symbol = match u {
+                TemperatureUnit::Celsius => "°C",
+                TemperatureUnit::Fahrenheit => "°F",
+                TemperatureUnit::Degree => "°",
+            };
+            format!("{number}{symbol}")
+        }
+        Unit::Distance(u) => {
+            let symbol = match u {
+                DistanceUnit::Millimetre => "mm",
+                DistanceUnit::Centimetre => "cm",
+                DistanceUnit::Metre | DistanceUnit::M => "m",
+                DistanceUnit::Kilometre => "km",
+                DistanceUnit::Inch => "in",
+                DistanceUnit::Foot => "ft",
+                DistanceUnit::Yard => "yd",
+                DistanceUnit::Mile => "mi",
+            };
+            format!("{number} {symbol}")
+        }
+        Unit::Volume(u) => match u {
+            VolumeUnit::Gallon => format!("{number} gal"),
+            VolumeUnit::Litre => format!("{number} L"),
+            VolumeUnit::Millilitre => format!("{number} mL"),
+            VolumeUnit::Centilitre => format!("{number} cL"),
+            VolumeUnit::Hectolitre => format!("{number} hL"),
+            VolumeUnit::Cup => plural(&number, count, "cup"),
+            VolumeUnit::Pint => format!("{number} pt"),
+            VolumeUnit::Quart => format!("{number} qt"),
+            VolumeUnit::Tablespoon => format!("{number} tbsp"),
+            VolumeUnit::Teaspoon => format!("{number} tsp"),
+        },
+        Unit::Quantity(u) => match u {
+            QuantityUnit::Can => plural(&number, count, "can"),
+            QuantityUnit::Cup => plural(&number, count, "cup"),
+            QuantityUnit::Gram => format!("{number} g"),
+            QuantityUnit::Ounce => format!("{number} oz"),
+            QuantityUnit::Pinch if count == 1 => format!("{number} pinch"),
+            QuantityUnit::Pinch => format!("{number} pinches"),
+            QuantityUnit::Pound => format!("{number} lb"),
+            QuantityUnit::Stick => plural(&number, count, "stick"),
+            QuantityUnit::Tablespoon => format!("{number} tbsp"),
+            QuantityUnit::Teaspoon => format!("{number} tsp"),
+            QuantityUnit::Unnamed => number,
+        },
+        Unit::Age(u) => {
+            let word = match u {
+                AgeUnit::Year => "year",
+                AgeUnit::Month => "month",
+            };
+            plural(&number, count, word)
+        }
+        Unit::Money(c) => format_money(value, c, locale),
+    }
+}
+
+fn format_money(value: f64, currency: MoneyCurrency, locale: &Locale) -> String {
+    let amount = if value.fract() == 0.0 {
+        format_number(value, locale)
+    } else {
+        localize_digits(&format!("{value:.2}"), locale)
+    };
+    let symbol = match currency {
+        MoneyCurrency::Cent => return format!("{amount}¢"),
+        MoneyCurrency::Dollar | MoneyCurrency::Unnamed | MoneyCurrency::USD => "$",
+        MoneyCurrency::Pound | MoneyCurrency::GBP => "£",
+        MoneyCurrency::AUD => "A$",
+        MoneyCurrency::CAD => "CA$",
+        MoneyCurrency::NZD => "NZ$",
+        MoneyCurrency::HKD => "HK$",
+        MoneyCurrency::EUR => "€",
+        MoneyCurrency::JPY => "¥",
+        MoneyCurrency::INR => "₹",
+        MoneyCurrency::KRW => "₩",
+        MoneyCurrency::ILS => "₪",
+        other => return format!("{amount} {}", other.as_str()),
+    };
+    match locale.decimal_separator() {
+        DecimalSeparator::Point => format!("{symbol}{amount}"),
+        DecimalSeparator::Comma => format!("{amount} {symbol}"),
+    }
+}
+
+fn format_time(time: &TimeValue, locale: &Locale) -> String {
+    match time {
+        TimeValue::Single { value, .. } => format_time_point(value, locale),
+        TimeValue::Interval {
+            from: Some(from),
+            to: Some(to),
+            ..
+        } => {
+            // Closed intervals end one grain past their last unit ("3pm to
+            // 5pm" ends at 6pm); show the last unit itself.
+            let to = last_unit(to).unwrap_or_else(|| to.clone());
+            let (from_dt, to_dt) = (local_datetime(from), local_datetime(&to));
+            if from.grain() < Grain::Day
+                && to.grain() < Grain::Day
+                && from_dt.date() == to_dt.date()
+                && matches!(from, TimePoint::Naive { .. }) == matches!(to, TimePoint::Naive { .. })
+            {
+                let end = format_clock(to_dt, to.grain(), locale);
+                let suffix = if matches!(to, TimePoint::Instant { .. }) {
+                    " UTC"
+                } else {
+                    ""
+                };
+                let start = format_date_time(from_dt, from.grain(), locale);
+                format!("{start} – {end}{suffix}")
+            } else {
+                format!(
+                    "{} – {}",
+                    format_time_point(from, locale),
+                    format_time_point(&to, locale)
+                )
+            }
+        }
+        TimeValue::Interval {
+            from: Some(from),
+            to: None,
+            ..
+        } => format!("from {}", format_time_point(from, locale)),
+        TimeValue::Interval {
+            from: None,
+            to: Some(to),
+            ..
+        } => format!("before {}", format_time_point(to, locale)),
+        TimeValue::Interval {
+            from: None,
+            to: None,
+            ..
+        } => String::new(),
+    }
+}
+
+/// The point one grain before `to`, keeping its kind and grain.
+fn last_unit(to: &TimePoint) -> Option<TimePoint> {
+    match to {
+        TimePoint::Instant { value, grain } => Some(TimePoint::Instant {
+            value: add_grain(*value, *grain, -1)?,
+            grain: *grain,
+        }),
+        TimePoint::Naive { value, grain } => Some(TimePoint::Naive {
+            value: add_grain(value.and_utc(), *grain, -1)?.naive_utc(),
+            grain: *grain,
+        }),
+    }
+}
+
+fn local_datetime(point: &TimePoint) -> NaiveDateTime {
+    match point {
+        TimePoint::Instant { value, .. } => value.naive_utc(),
+        TimePoint::Naive { value, .. } => *value,
+    }
+}
+
+fn format_time_point(point: &TimePoint, locale: &Locale) -> String {
+    let text = format_date_time(local_datetime(point), point.grain(), locale);
+    match point {
+        TimePoint::Instant { grain, .. } if *grain < Grain::Day => format!("{text} UTC"),
+        _ => text,
+    }
+}
+
+fn format_date_time(dt: NaiveDateTime, grain: Grain, locale: &Locale) -> String {
+    match grain {
+        Grain::Year => dt.year().to_string(),
+        Grain::Quarter => format!("Q{} {}", (dt.month0() / 3).saturating_add(1), dt.year()),
+        Grain::Month => format!("{} {}", dt.format("%B"), dt.year()),
+        Grain::Week => format!("week of {}", format_date(dt, locale)),
+        Grain::Day => format_date(dt, locale),
+        Grain::Hour | Grain::Minute | Grain::Second | Grain::NoGrain => {
+            format!(
+                "{}, {}",
+                format_date(dt, locale),
+                format_clock(dt, grain, locale)
+            )
+        }
+    }
+}
+
+fn format_date(dt: NaiveDateTime, locale: &Locale) -> String {
+    if month_first(locale) {
+        dt.format("%b %-d, %Y").to_string()
+    } else {
+        dt.format("%-d %b %Y").to_string()
+    }
+}
+
+fn format_clock(dt: NaiveDateTime, grain: Grain, locale: &Locale) -> String {
+    let seconds = matches!(grain, Grain::Second | Grain::NoGrain) && dt.second() != 0;
+    if (locale.lang, locale.region) == (Lang::FR, Some(Region::CA)) && !seconds {
+        // Québec's 24-hour "15 h 30"
+        return dt.format("%-H h %M").to_string();
+    }
+    if (locale.lang, locale.region) == (Lang::PT, Some(Region::BR)) && !seconds {
+        // Brazil's "15h30"; Portugal keeps "15:30"
+        return dt.format("%-Hh%M").to_string();
+    }
+    let pattern = match (month_first(locale), seconds) {
+        (true, false) => "%-I:%M %p",
+        (true, true) => "%-I:%M:%S %p",
+        (false, false) => "%H:%M",
+        (false, true) => "%H:%M:%S",
+    };
+    dt.format(pattern).to_string()
+}
+
+/// Whether the locale writes dates month first with a 12-hour clock, as in
+/// American English.
+fn month_first(locale: &Locale) -> bool {
+    matches!(
+        (locale.lang, locale.region),
+        (Lang::EN, None | Some(Region::US | Region::CA | Region::PH))
+    )
+}
+
+fn format_ordinal(n: i64) -> String {
+    let suffix = match (n.rem_euclid(100), n.rem_euclid(10)) {
+        (11..=13, _) => "th",
+        (_, 1) => "st",
+        (_, 2) => "nd",
+        (_, 3) => "rd",
+        _ => "th",
+    };
+    format!("{n}{suffix}")
+}
+
+fn plural(number: &str, count: i64, word: &str) -> String {
+    if count == 1 {
+        format!("{number} {word}")
+    } else {
+        format!("{number} {word}s")
+    }
+}
+
+fn format_number(value: f64, locale: &Locale) -> String {
+    localize_digits(&value.to_string(), locale)
+}
+
+/// Groups the thousands of a plain `-1234.5` digit string and swaps in the
+/// locale's separators.
+fn localize_digits(digits: &str, locale: &Locale) -> String {
+    let (sign, digits) = match digits.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", digits),
+    };
+    let (int, frac) = digits.split_once('.').unwrap_or((digits, ""));
+    let (group, decimal) = match locale.decimal_separator() {
+        DecimalSeparator::Point => (',', '.'),
+        DecimalSeparator::Comma => ('.', ','),
+    };
+    let mut out = String::from(sign);
+    for (i, c) in int.chars().enumerate() {
+        if i > 0 && int.len().saturating_sub(i) % 3 == 0 {
+            out.push(group);
+        }
+        out.push(c);
+    }
+    if !frac.is_empty() {
+        out.push(decimal);
+        out.push_str(frac);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse, Context, DimensionKind, Options};
+    use chrono::{TimeZone, Utc};
+
+    fn format_all(text: &str, locale: Locale, dims: &[DimensionKind]) -> Vec<String> {
+        let context = Context {
+            reference_time: Utc.with_ymd_and_hms(2013, 2, 12, 4, 30, 0).unwrap(),
+            locale,
+            ..Context::default()
+        };
+        parse(text, &locale, dims, &context, &Options::default())
+            .iter()
+            .map(|e| e.format(&locale))
+            .collect()
+    }
+
+    #[test]
+    fn test_format_time_by_grain() {
+        let us = Locale::new(Lang::EN, Some(Region::US));
+        for (text, expected) in [
+            ("tomorrow", "Feb 13, 2013"),
+            ("march 2014", "March 2014"),
+            ("next year", "2014"),
+            ("this quarter", "Q1 2013"),
+            ("tomorrow at 3:30pm", "Feb 13, 2013, 3:30 PM"),
+            ("in one hour", "Feb 12, 2013, 5:30 AM UTC"),
+            ("from 3pm to 5pm", "Feb 12, 2013, 3:00 PM – 5:00 PM"),
+        ] {
+            assert_eq!(
+                format_all(text, us, &[DimensionKind::Time]),
+                [expected],
+                "{text}"
+            );
+        }
+        let gb = Locale::new(Lang::EN, Some(Region::GB));
+        assert_eq!(
+            format_all("tomorrow at 3pm", gb, &[DimensionKind::Time]),
+            ["13 Feb 2013, 15:00"]
+        );
+        let fr_ca = Locale::new(Lang::FR, Some(Region::CA));
+        assert_eq!(
+            format_all("15h30", fr_ca, &[DimensionKind::Time]),
+            ["12 Feb 2013, 15 h 30"]
+        );
+        for (region, expected) in [(Region::BR, "15h30"), (Region::PT, "15:30")] {
+            assert_eq!(
+                format_all(
+                    "às 15:30",
+                    Locale::new(Lang::PT, Some(region)),
+                    &[DimensionKind::Time]
+                ),
+                [format!("12 Feb 2013, {expected}")]
+            );
+        }
+    }
+
+    #[test]
+    fn test_format_measurements() {
+        let us = Locale::new(Lang::EN, Some(Region::US));
+        for (text, dim, expected) in [
+            ("$42.50", DimensionKind::AmountOfMoney, "$42.50"),
+            (
+                "1000000 dollars",
+                DimensionKind::AmountOfMoney,
+                "$1,000,000",
+            ),
+            (
+                "between 3 and 5 dollars",
+                DimensionKind::AmountOfMoney,
+                "$3 – $5",
+            ),
+            ("80 degrees fahrenheit", DimensionKind::Temperature, "80°F"),
+            ("5 miles", DimensionKind::Distance, "5 mi"),
+            ("2 gallons", DimensionKind::Volume, "2 gal"),
+            (
+                "5 pounds of sugar",
+                DimensionKind::Quantity,
+                "5 lb of sugar",
+            ),
+            ("two dozen eggs", DimensionKind::Quantity, "24 eggs"),
+            ("2 pinches", DimensionKind::Quantity, "2 pinches"),
+            ("32 years old", DimensionKind::Age, "32 years"),
+            ("3 days", DimensionKind::Duration, "3 days"),
+            ("21st", DimensionKind::Ordinal, "21st"),
+            ("12.5%", DimensionKind::Percentage, "12.5%"),
+            ("twice a week", DimensionKind::Rate, "2 per week"),
+            ("every 6 hours", DimensionKind::Rate, "1 per 6 hours"),
+            ("60 km/h", DimensionKind::Rate, "60 km per hour"),
+            (
+                "40.7128,-74.006",
+                DimensionKind::Coordinate,
+                "40.7128° N, 74.006° W",
+            ),
+            ("N40°30'", DimensionKind::Coordinate, "40.5° N"),
+        ] {
+            assert_eq!(format_all(text, us, &[dim]), [expected], "{text}");
+        }
+    }
+
+    #[test]
+    fn test_format_number_separators() {
+        let de = Locale::new(Lang::DE, None);
+        assert_eq!(format_number(1234.5, &de), "1.234,5");
+        assert_eq!(format_money(42.5, MoneyCurrency::EUR, &de), "42,50 €");
+        assert_eq!(format_ordinal(112), "112th");
+    }
+}
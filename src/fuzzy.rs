@@ -0,0 +1,166 @@
+use crate::document::Document;
+use crate::locale::Lang;
+
+/// Closed-class trigger words whose misspellings are worth repairing: month
+/// and weekday names, relative days and currency words. Words shorter than
+/// five letters are left out, as one edit turns too many of them into other
+/// real words.
+const EN_KEYWORDS: &[&str] = &[
+    "january",
+    "february",
+    "march",
+    "april",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+    "monday",
+    "tuesday",
+    "wednesday",
+    "thursday",
+    "friday",
+    "saturday",
+    "sunday",
+    "today",
+    "tonight",
+    "tomorrow",
+    "yesterday",
+    "dollar",
+    "dollars",
+    "euros",
+    "pounds",
+    "rupees",
+];
+
+/// Real words one edit away from a keyword, which must not be "corrected".
+const EN_LOOKALIKES: &[&str] = &["match", "marsh", "sundae", "sundry", "toady", "toddy"];
+
+fn keywords(lang: Lang) -> (&'static [&'static str], &'static [&'static str]) {
+    match lang {
+        Lang::EN => (EN_KEYWORDS, EN_LOOKALIKES),
+        _ => (&[], &[]),
+    }
+}
+
+/// A document over `text` with each misspelled keyword replaced by the
+/// keyword, or `None` if nothing needed repair.
+///
+/// A word is repaired when it is one edit (an insertion, deletion,
+/// substitution or swap of neighbouring letters) away from exactly one
+/// keyword, starts with the same letter, and is neither a listed lookalike
+/// nor a keyword with an "s" added or dropped.
+pub(crate) fn correct(text: &str, lang: Lang) -> Option<Document> {
+    let (keywords, lookalikes) = keywords(lang);
+    if keywords.is_empty() {
+        return None;
+    }
+    let mut doc = Document::mapped(text);
+    let mut kept = 0;
+    let mut corrected = false;
+    for (start, word) in words(text) {
+        let Some(keyword) = repair(word, keywords, lookalikes) else {
+            continue;
+        };
+        let end = start.saturating_add(word.len());
+        doc.keep(kept..start).replace(start..end, keyword);
+        kept = end;
+        corrected = true;
+    }
+    if !corrected {
+        return None;
+    }
+    doc.keep(kept..text.len());
+    Some(doc)
+}
+
+/// Alphabetic runs of `text` with their byte offsets.
+fn words(text: &str) -> Vec<(usize, &str)> {
+    let mut words = Vec::new();
+    let mut start = None;
+    for (i, ch) in text.char_indices() {
+        if ch.is_alphabetic() {
+            start.get_or_insert(i);
+        } else if let Some(start) = start.take() {
+            words.push((start, &text[start..i]));
+        }
+    }
+    if let Some(start) = start {
+        words.push((start, &text[start..]));
+    }
+    words
+}
+
+fn repair(word: &str, keywords: &[&'static str], lookalikes: &[&str]) -> Option<&'static str> {
+    let lower = word.to_lowercase();
+    if lower.chars().count() < 5 || lookalikes.contains(&lower.as_str()) {
+        return None;
+    }
+    let plural_of = |a: &str, b: &str| a.strip_suffix('s') == Some(b);
+    if keywords
+        .iter()
+        .any(|k| *k == lower || plural_of(&lower, k) || plural_of(k, &lower))
+    {
+        return None;
+    }
+    let word: Vec<char> = lower.chars().collect();
+    let mut matches = keywords.iter().filter(|k| {
+        let keyword: Vec<char> = k.chars().collect();
+        keyword.first() == word.first() && within_one_edit(&word, &keyword)
+    });
+    let keyword = matches.next()?;
+    matches.next().is_none().then_some(*keyword)
+}
+
+/// Whether `a` and `b` differ by at most one insertion, deletion,
+/// substitution or transposition of adjacent characters.
+fn within_one_edit(a: &[char], b: &[char]) -> bool {
+    let (short, long) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+    let prefix = short.iter().zip(long).take_while(|(x, y)| x == y).count();
+    let (short, long) = (&short[prefix..], &long[prefix..]);
+    match long.len().checked_sub(short.len()) {
+        Some(0) => match (short, long) {
+            ([], []) => true,
+            ([_, s_rest @ ..], [_, l_rest @ ..]) if s_rest == l_rest => true,
+            ([s0, s1, s_rest @ ..], [l0, l1, l_rest @ ..]) => {
+                s0 == l1 && s1 == l0 && s_rest == l_rest
+            }
+            _ => false,
+        },
+        Some(1) => short == &long[1..],
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn corrects_misspelled_keywords_only() {
+        let doc = correct("Tomorow or febuary, 20 dollers", Lang::EN).unwrap();
+        assert_eq!(doc.text(), "tomorrow or february, 20 dollars");
+        assert_eq!(doc.to_original(0..8), 0..7);
+        assert_eq!(doc.to_original(12..20), 11..18);
+        for text in ["match sundae mondays", "dollars today", "colder", "mar"] {
+            assert!(correct(text, Lang::EN).is_none(), "{text:?}");
+        }
+        assert!(correct("tomorow", Lang::FR).is_none());
+    }
+
+    #[test]
+    fn one_edit_distance() {
+        let edit = |a: &str, b: &str| {
+            within_one_edit(
+                &a.chars().collect::<Vec<_>>(),
+                &b.chars().collect::<Vec<_>>(),
+            )
+        };
+        assert!(edit("tomorow", "tomorrow"));
+        assert!(edit("fridya", "friday"));
+        assert!(edit("dollers", "dollars"));
+        assert!(edit("friday", "friday"));
+        assert!(!edit("fday", "friday"));
+        assert!(!edit("fryda", "friday"));
+    }
+}
@@ -0,0 +1,21 @@
+use crate::dimensions;
+use crate::locale::Region;
+use crate::types::{DimensionKind, Rule};
+
+/// Region-specific rule overlays for German locales.
+pub(crate) fn locale_rules(region: Region, needed: &[DimensionKind]) -> Vec<Rule> {
+    let mut rules = Vec::new();
+    if needed.contains(&DimensionKind::AmountOfMoney) {
+        rules.extend(dimensions::amount_of_money::de::locale_rules(region));
+    }
+    if needed.contains(&DimensionKind::Time) {
+        rules.extend(dimensions::time::de::locale_rules(region));
+    }
+    rules
+}
+
+/// Base rules that `locale_rules` replaces: the national day, which the
+/// base rules read as Austria's and the Swiss overlay moves to August 1.
+pub(crate) fn replaced_rules() -> &'static [&'static str] {
+    &["nationalfeiertag (de)"]
+}
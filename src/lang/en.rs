@@ -10,10 +10,19 @@ pub fn supported_dimensions() -> Vec<DimensionKind> {
         DimensionKind::Distance,
         DimensionKind::Volume,
         DimensionKind::Quantity,
+        DimensionKind::Age,
         DimensionKind::AmountOfMoney,
+        DimensionKind::Percentage,
+        DimensionKind::Rate,
+        #[cfg(feature = "medical")]
+        DimensionKind::BloodPressure,
+        #[cfg(feature = "medical")]
+        DimensionKind::Dosage,
         DimensionKind::Email,
         DimensionKind::PhoneNumber,
         DimensionKind::Url,
+        DimensionKind::NetworkAddress,
+        DimensionKind::Coordinate,
         DimensionKind::CreditCardNumber,
         DimensionKind::TimeGrain,
         DimensionKind::Duration,
@@ -24,7 +33,10 @@ pub fn supported_dimensions() -> Vec<DimensionKind> {
 /// EN default rules when no locale is specified.
 pub(crate) fn default_rules(needed: &[DimensionKind]) -> Vec<Rule> {
     // Haskell EN default rules include standard EN language rules.
-    lang_rules(needed)
+    let mut rules = lang_rules(needed);
+    rules.extend(in_rules(needed));
+    rules.extend(uk_half_hour_rules(needed));
+    rules
 }
 
 /// EN language rules.
@@ -34,16 +46,31 @@ pub(crate) fn lang_rules(needed: &[DimensionKind]) -> Vec<Rule> {
         match dim {
             DimensionKind::Numeral => rules.extend(dimensions::numeral::en::lang_rules()),
             DimensionKind::Ordinal => rules.extend(dimensions::ordinal::en::rules()),
-            DimensionKind::Temperature => rules.extend(dimensions::temperature::en::rules()),
+            DimensionKind::Temperature => {
+                rules.extend(dimensions::temperature::en::rules());
+                #[cfg(feature = "medical")]
+                rules.extend(dimensions::medical::en::temperature_rules());
+            }
             DimensionKind::Distance => rules.extend(dimensions::distance::en::lang_rules()),
             DimensionKind::Volume => rules.extend(dimensions::volume::en::rules()),
             DimensionKind::Quantity => rules.extend(dimensions::quantity::en::rules()),
+            DimensionKind::Age => rules.extend(dimensions::age::en::rules()),
             DimensionKind::AmountOfMoney => {
                 rules.extend(dimensions::amount_of_money::en::lang_rules())
             }
+            DimensionKind::Percentage => rules.extend(dimensions::percentage::en::rules()),
+            DimensionKind::Rate => rules.extend(dimensions::rate::en::rules()),
+            #[cfg(feature = "medical")]
+            DimensionKind::BloodPressure => {
+                rules.extend(dimensions::medical::en::blood_pressure_rules())
+            }
+            #[cfg(feature = "medical")]
+            DimensionKind::Dosage => rules.extend(dimensions::medical::en::dosage_rules()),
             DimensionKind::Email => {}
             DimensionKind::PhoneNumber => {}
             DimensionKind::Url => {}
+            DimensionKind::NetworkAddress => {}
+            DimensionKind::Coordinate => {}
             DimensionKind::CreditCardNumber => {}
             DimensionKind::TimeGrain => rules.extend(dimensions::time_grain::en::rules()),
             DimensionKind::Duration => rules.extend(dimensions::duration::en::lang_rules()),
@@ -55,7 +82,50 @@ pub(crate) fn lang_rules(needed: &[DimensionKind]) -> Vec<Rule> {
 }
 
 /// Region-specific rule overlays for English locales.
-/// No region overlays are implemented yet, so this currently returns empty.
-pub(crate) fn locale_rules(_region: Option<Region>, _needed: &[DimensionKind]) -> Vec<Rule> {
-    Vec::new()
+pub(crate) fn locale_rules(region: Option<Region>, needed: &[DimensionKind]) -> Vec<Rule> {
+    let mut rules = match region {
+        Some(Region::IN) => in_rules(needed),
+        _ => Vec::new(),
+    };
+    if !matches!(region, Some(Region::US | Region::CA | Region::PH)) {
+        rules.extend(uk_half_hour_rules(needed));
+        rules.extend(day_first_date_rules(needed));
+    }
+    rules
+}
+
+/// Base rules that `locale_rules` replaces for `region`.
+pub(crate) fn replaced_rules(region: Option<Region>) -> &'static [&'static str] {
+    if matches!(region, Some(Region::US | Region::CA | Region::PH)) {
+        &[]
+    } else {
+        &["date MM/DD(/YYYY)", "date DD/MM(/YY)"]
+    }
+}
+
+/// Indian English: lakh and crore.
+fn in_rules(needed: &[DimensionKind]) -> Vec<Rule> {
+    if needed.contains(&DimensionKind::Numeral) {
+        dimensions::numeral::en::in_rules()
+    } else {
+        Vec::new()
+    }
+}
+
+/// "3/4" as 3 April, everywhere but North America and the Philippines.
+fn day_first_date_rules(needed: &[DimensionKind]) -> Vec<Rule> {
+    if needed.contains(&DimensionKind::Time) {
+        dimensions::time::en::day_first_date_rules()
+    } else {
+        Vec::new()
+    }
+}
+
+/// "half three" as 3:30, everywhere but North America and the Philippines.
+fn uk_half_hour_rules(needed: &[DimensionKind]) -> Vec<Rule> {
+    if needed.contains(&DimensionKind::Time) {
+        dimensions::time::en::uk_half_hour_rules()
+    } else {
+        Vec::new()
+    }
 }
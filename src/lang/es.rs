@@ -0,0 +1,22 @@
+use crate::dimensions;
+use crate::locale::Region;
+use crate::types::{DimensionKind, Rule};
+
+/// Region-specific rule overlays for Spanish locales.
+pub(crate) fn locale_rules(region: Region, needed: &[DimensionKind]) -> Vec<Rule> {
+    let mut rules = Vec::new();
+    if needed.contains(&DimensionKind::AmountOfMoney) {
+        rules.extend(dimensions::amount_of_money::es::locale_rules(region));
+    }
+    if needed.contains(&DimensionKind::Time) {
+        rules.extend(dimensions::time::es::locale_rules(region));
+    }
+    rules
+}
+
+/// Base rules that `locale_rules` replaces: the month-first numeric dates
+/// the time rules take over from English, as every Spanish-speaking region
+/// writes the day first.
+pub(crate) fn replaced_rules() -> &'static [&'static str] {
+    &["date MM/DD(/YYYY)"]
+}
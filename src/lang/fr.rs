@@ -0,0 +1,28 @@
+use crate::dimensions;
+use crate::locale::Region;
+use crate::types::{DimensionKind, Rule};
+
+/// Region-specific rule overlays for French locales.
+pub(crate) fn locale_rules(region: Region, needed: &[DimensionKind]) -> Vec<Rule> {
+    let mut rules = Vec::new();
+    for dim in needed {
+        match dim {
+            DimensionKind::Numeral => rules.extend(dimensions::numeral::fr::locale_rules(region)),
+            DimensionKind::AmountOfMoney => {
+                rules.extend(dimensions::amount_of_money::fr::locale_rules(region))
+            }
+            DimensionKind::Time => {
+                rules.extend(dimensions::time::en::day_first_date_rules());
+                rules.extend(dimensions::time::fr::locale_rules(region));
+            }
+            _ => {}
+        }
+    }
+    rules
+}
+
+/// Base rules that `locale_rules` replaces: the time rules' numeric dates,
+/// read day first in every French-speaking region.
+pub(crate) fn replaced_rules() -> &'static [&'static str] {
+    &["date MM/DD(/YYYY)", "date DD/MM(/YY)"]
+}
@@ -1,69 +1,320 @@
+pub mod de;
 pub mod en;
+pub mod es;
+pub mod fr;
+pub mod pt;
+pub mod zh;
 
 use std::collections::HashMap;
-use std::sync::{Mutex, OnceLock};
+use std::ops::Deref;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, OnceLock, RwLock};
 
 use crate::dimensions;
-use crate::locale::{Lang, Locale, Region};
-use crate::types::{DimensionKind, Rule};
+use crate::engine;
+use crate::locale::{DecimalSeparator, Lang, Locale, Region};
+use crate::types::{DimensionKind, PatternItem, Rule, RuleSource};
+
+/// Default number of (locale, dims) rule sets kept by the rule cache.
+pub const DEFAULT_RULE_CACHE_CAPACITY: usize = 64;
 
 /// Get rules for a given language and set of dimensions.
 /// Rules are cached after first compilation to avoid repeated regex compilation.
-pub fn rules_for(locale: Locale, dims: &[DimensionKind]) -> &'static [Rule] {
+/// The cache is a bounded LRU; evicted rule sets, with the regex prefilter
+/// built for them, are freed once the last in-flight parse holding them
+/// finishes. Hits only take the read lock, so
+/// concurrent parses do not serialize on the lookup.
+///
+/// Digit strings are read with `separator`; pass `locale.decimal_separator()`
+/// for the locale's own convention. For the languages in `fold_diacritics`,
+/// accented letters in their patterns also match text typed without them. The
+/// language rules of `extra_langs` are added to the locale's, sharing one copy
+/// of the common rules.
+pub fn rules_for(
+    locale: Locale,
+    separator: DecimalSeparator,
+    dims: &[DimensionKind],
+    fold_diacritics: &[Lang],
+    extra_langs: &[Lang],
+) -> Arc<RuleSet> {
     let cache = rule_cache();
-    let key = CacheKey::new(locale.lang, locale.region, dims);
+    let key = CacheKey::new(locale, separator, dims, fold_diacritics, extra_langs);
 
-    if let Some(rules) = cache.lock().unwrap().get(&key).copied() {
+    if let Some(rules) = cache.read().unwrap().get(&key) {
         return rules;
     }
 
-    let built = build_rules(locale, dims);
-    let leaked: &'static [Rule] = Box::leak(built.into_boxed_slice());
+    let built = Arc::new(build_rules(
+        locale,
+        separator,
+        dims,
+        &key.extra_langs,
+        &key.fold_diacritics,
+    ));
+
+    let mut guard = cache.write().unwrap();
+    guard.insert(key, built)
+}
+
+/// A compiled rule set, remembering which pack each rule came from.
+#[derive(Default)]
+pub(crate) struct RuleSet {
+    rules: Vec<Rule>,
+    sources: HashMap<String, RuleSource>,
+    /// The engine's prefilter over `rules`, built on first parse and freed
+    /// with them.
+    regex_set: OnceLock<engine::CachedRegexSet>,
+}
+
+impl RuleSet {
+    fn extend(&mut self, rules: Vec<Rule>, source: RuleSource) {
+        for rule in &rules {
+            self.sources.entry(rule.name.clone()).or_insert(source);
+        }
+        self.rules.extend(rules);
+    }
+
+    /// The prefilter the engine matches against the text before trying
+    /// these rules.
+    pub(crate) fn regex_set(&self) -> &engine::CachedRegexSet {
+        self.regex_set
+            .get_or_init(|| engine::CachedRegexSet::new(&self.rules))
+    }
+
+    /// The pack that contributed the rule named `rule_name`.
+    pub fn source(&self, rule_name: &str) -> Option<RuleSource> {
+        self.sources.get(rule_name).copied()
+    }
+}
+
+impl Deref for RuleSet {
+    type Target = [Rule];
 
-    let mut guard = cache.lock().unwrap();
-    guard.entry(key).or_insert(leaked)
+    fn deref(&self) -> &[Rule] {
+        &self.rules
+    }
+}
+
+/// Drop all cached rule sets so the next `rules_for` call rebuilds them.
+pub fn clear_rule_cache() {
+    rule_cache().write().unwrap().clear();
+}
+
+/// Set the maximum number of cached rule sets, evicting the least recently
+/// used ones if the cache is already larger. A capacity of 0 is treated as 1.
+pub fn set_rule_cache_capacity(capacity: usize) {
+    rule_cache().write().unwrap().set_capacity(capacity);
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct CacheKey {
     lang: Lang,
     region: Option<Region>,
+    separator: DecimalSeparator,
     dims: Vec<DimensionKind>,
+    fold_diacritics: Vec<Lang>,
+    extra_langs: Vec<Lang>,
 }
 
 impl CacheKey {
-    fn new(lang: Lang, region: Option<Region>, dims: &[DimensionKind]) -> Self {
+    fn new(
+        locale: Locale,
+        separator: DecimalSeparator,
+        dims: &[DimensionKind],
+        fold_diacritics: &[Lang],
+        extra_langs: &[Lang],
+    ) -> Self {
         let mut normalized = dims.to_vec();
         normalized.sort_by_key(|d| *d as usize);
         normalized.dedup();
+        let mut extra_langs: Vec<Lang> = extra_langs
+            .iter()
+            .copied()
+            .filter(|lang| *lang != locale.lang)
+            .collect();
+        extra_langs.sort_by_key(|lang| *lang as usize);
+        extra_langs.dedup();
+        // Only the languages whose packs are loaded change the rules.
+        let mut fold_diacritics: Vec<Lang> = fold_diacritics
+            .iter()
+            .copied()
+            .filter(|lang| *lang == locale.lang || extra_langs.contains(lang))
+            .collect();
+        fold_diacritics.sort_by_key(|lang| *lang as usize);
+        fold_diacritics.dedup();
         Self {
-            lang,
-            region,
+            lang: locale.lang,
+            region: locale.region,
+            separator,
             dims: normalized,
+            fold_diacritics,
+            extra_langs,
+        }
+    }
+}
+
+struct CacheEntry {
+    rules: Arc<RuleSet>,
+    last_used: AtomicU64,
+}
+
+/// Least-recently-used map from (locale, dims) to compiled rules.
+/// Capacities are small, so eviction scans for the oldest entry. Recency is
+/// tracked with atomics so lookups work through a shared reference.
+struct RuleCache {
+    entries: HashMap<CacheKey, CacheEntry>,
+    capacity: usize,
+    clock: AtomicU64,
+}
+
+impl RuleCache {
+    fn new(capacity: usize) -> Self {
+        Self {
+            entries: HashMap::new(),
+            capacity: capacity.max(1),
+            clock: AtomicU64::new(0),
+        }
+    }
+
+    fn tick(&self) -> u64 {
+        self.clock.fetch_add(1, Ordering::Relaxed)
+    }
+
+    fn get(&self, key: &CacheKey) -> Option<Arc<RuleSet>> {
+        let entry = self.entries.get(key)?;
+        entry.last_used.store(self.tick(), Ordering::Relaxed);
+        Some(Arc::clone(&entry.rules))
+    }
+
+    /// Insert `rules` unless another thread already cached this key, and
+    /// return whichever rule set ends up cached.
+    fn insert(&mut self, key: CacheKey, rules: Arc<RuleSet>) -> Arc<RuleSet> {
+        if let Some(existing) = self.get(&key) {
+            return existing;
+        }
+        while self.entries.len() >= self.capacity {
+            self.evict_oldest();
+        }
+        let last_used = AtomicU64::new(self.tick());
+        self.entries.insert(
+            key,
+            CacheEntry {
+                rules: Arc::clone(&rules),
+                last_used,
+            },
+        );
+        rules
+    }
+
+    fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity.max(1);
+        while self.entries.len() > self.capacity {
+            self.evict_oldest();
+        }
+    }
+
+    fn evict_oldest(&mut self) {
+        let oldest = self
+            .entries
+            .iter()
+            .min_by_key(|(_, entry)| entry.last_used.load(Ordering::Relaxed))
+            .map(|(key, _)| key.clone());
+        if let Some(key) = oldest {
+            self.entries.remove(&key);
         }
     }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    #[cfg(test)]
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+}
+
+fn rule_cache() -> &'static RwLock<RuleCache> {
+    static CACHE: OnceLock<RwLock<RuleCache>> = OnceLock::new();
+    CACHE.get_or_init(|| RwLock::new(RuleCache::new(DEFAULT_RULE_CACHE_CAPACITY)))
 }
 
-fn rule_cache() -> &'static Mutex<HashMap<CacheKey, &'static [Rule]>> {
-    static CACHE: OnceLock<Mutex<HashMap<CacheKey, &'static [Rule]>>> = OnceLock::new();
-    CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+/// Make every regex in `rules` also match its accented letters typed without
+/// the accent.
+fn fold_diacritics(mut rules: Vec<Rule>) -> Vec<Rule> {
+    for rule in &mut rules {
+        for item in &mut rule.pattern {
+            if let PatternItem::Regex(re) = item {
+                *re = re.folded();
+            }
+        }
+    }
+    rules
 }
 
-fn build_rules(locale: Locale, dims: &[DimensionKind]) -> Vec<Rule> {
+fn build_rules(
+    locale: Locale,
+    separator: DecimalSeparator,
+    dims: &[DimensionKind],
+    extra_langs: &[Lang],
+    fold_langs: &[Lang],
+) -> RuleSet {
+    let pack = |lang: Lang, rules: Vec<Rule>| {
+        if fold_langs.contains(&lang) {
+            fold_diacritics(rules)
+        } else {
+            rules
+        }
+    };
     let needed = collect_needed_dims(locale.lang, dims);
+    let extra_needed: Vec<(Lang, Vec<DimensionKind>)> = extra_langs
+        .iter()
+        .map(|lang| (*lang, collect_needed_dims(*lang, dims)))
+        .collect();
+    let mut common_needed = needed.clone();
+    for dim in extra_needed.iter().flat_map(|(_, needed)| needed) {
+        if !common_needed.contains(dim) {
+            common_needed.push(*dim);
+        }
+    }
+    let mut rules = RuleSet::default();
+    rules.extend(common_rules(&common_needed), RuleSource::Common);
     match locale.region {
         Some(region) => {
-            let mut rules = common_rules(&needed);
-            rules.extend(lang_rules(locale.lang, &needed));
-            rules.extend(locale_rules(locale.lang, region, &needed));
-            rules
-        }
-        None => {
-            let mut rules = common_rules(&needed);
-            rules.extend(default_rules(locale.lang, &needed));
-            rules
+            let replaced = replaced_rules(locale.lang, region);
+            let mut base = lang_rules(locale.lang, &needed);
+            base.retain(|rule| !replaced.contains(&rule.name.as_str()));
+            rules.extend(pack(locale.lang, base), RuleSource::Lang);
+            rules.extend(
+                pack(locale.lang, locale_rules(locale.lang, region, &needed)),
+                RuleSource::Locale,
+            );
         }
+        None => rules.extend(
+            pack(locale.lang, default_rules(locale.lang, &needed)),
+            RuleSource::Lang,
+        ),
     }
+    for (lang, needed) in &extra_needed {
+        rules.extend(pack(*lang, lang_rules(*lang, needed)), RuleSource::Lang);
+    }
+    if needed.contains(&DimensionKind::Numeral) && separator != locale.lang.decimal_separator() {
+        // Swap the language's own digit-separator rules for ones reading
+        // the requested convention.
+        let native: Vec<String> = lang_rules(locale.lang, &[DimensionKind::Numeral])
+            .into_iter()
+            .filter(dimensions::numeral::separators::is_separator_rule)
+            .map(|r| r.name)
+            .collect();
+        rules.rules.retain(|r| {
+            !(native.contains(&r.name) && dimensions::numeral::separators::is_separator_rule(r))
+        });
+        rules.extend(
+            dimensions::numeral::separators::rules(separator),
+            RuleSource::Locale,
+        );
+    }
+    rules
 }
 
 fn collect_needed_dims(lang: Lang, dims: &[DimensionKind]) -> Vec<DimensionKind> {
@@ -112,6 +363,7 @@ fn collect_needed_dims(lang: Lang, dims: &[DimensionKind]) -> Vec<DimensionKind>
                 DimensionKind::Ordinal,
                 DimensionKind::Temperature,
                 DimensionKind::TimeGrain,
+                DimensionKind::Time,
                 DimensionKind::Volume,
             ],
             Lang::CS => vec![DimensionKind::Numeral, DimensionKind::Distance],
@@ -128,11 +380,17 @@ fn collect_needed_dims(lang: Lang, dims: &[DimensionKind]) -> Vec<DimensionKind>
             Lang::DA => vec![DimensionKind::Numeral, DimensionKind::Ordinal],
             Lang::EL => vec![DimensionKind::Numeral, DimensionKind::Ordinal],
             Lang::ET => vec![DimensionKind::Numeral, DimensionKind::Ordinal],
-            Lang::FA => vec![DimensionKind::Numeral],
+            Lang::FA => vec![
+                DimensionKind::Numeral,
+                DimensionKind::AmountOfMoney,
+                DimensionKind::TimeGrain,
+                DimensionKind::Time,
+            ],
             Lang::HE => vec![
                 DimensionKind::Numeral,
                 DimensionKind::Ordinal,
                 DimensionKind::AmountOfMoney,
+                DimensionKind::Time,
             ],
             Lang::HI => vec![
                 DimensionKind::Numeral,
@@ -143,6 +401,9 @@ fn collect_needed_dims(lang: Lang, dims: &[DimensionKind]) -> Vec<DimensionKind>
                 DimensionKind::Numeral,
                 DimensionKind::Ordinal,
                 DimensionKind::AmountOfMoney,
+                DimensionKind::Distance,
+                DimensionKind::Volume,
+                DimensionKind::Time,
             ],
             Lang::JA => vec![
                 DimensionKind::Numeral,
@@ -153,6 +414,9 @@ fn collect_needed_dims(lang: Lang, dims: &[DimensionKind]) -> Vec<DimensionKind>
                 DimensionKind::Numeral,
                 DimensionKind::Ordinal,
                 DimensionKind::AmountOfMoney,
+                DimensionKind::Duration,
+                DimensionKind::TimeGrain,
+                DimensionKind::Time,
             ],
             Lang::KM => vec![
                 DimensionKind::Numeral,
@@ -161,6 +425,7 @@ fn collect_needed_dims(lang: Lang, dims: &[DimensionKind]) -> Vec<DimensionKind>
                 DimensionKind::Quantity,
                 DimensionKind::Temperature,
                 DimensionKind::Volume,
+                DimensionKind::Time,
             ],
             Lang::KN => vec![DimensionKind::Numeral],
             Lang::KO => vec![
@@ -172,7 +437,7 @@ fn collect_needed_dims(lang: Lang, dims: &[DimensionKind]) -> Vec<DimensionKind>
                 DimensionKind::Temperature,
                 DimensionKind::Volume,
             ],
-            Lang::LO => vec![DimensionKind::Numeral],
+            Lang::LO => vec![DimensionKind::Numeral, DimensionKind::Time],
             Lang::VI => vec![
                 DimensionKind::Numeral,
                 DimensionKind::Ordinal,
@@ -243,6 +508,7 @@ fn collect_needed_dims(lang: Lang, dims: &[DimensionKind]) -> Vec<DimensionKind>
                 DimensionKind::Email,
                 DimensionKind::Ordinal,
                 DimensionKind::Temperature,
+                DimensionKind::Time,
                 DimensionKind::Volume,
             ],
             Lang::IS => vec![DimensionKind::Numeral, DimensionKind::Email],
@@ -254,6 +520,7 @@ fn collect_needed_dims(lang: Lang, dims: &[DimensionKind]) -> Vec<DimensionKind>
                 DimensionKind::Ordinal,
                 DimensionKind::Quantity,
                 DimensionKind::TimeGrain,
+                DimensionKind::Time,
                 DimensionKind::Volume,
             ],
             Lang::PT => vec![
@@ -264,6 +531,8 @@ fn collect_needed_dims(lang: Lang, dims: &[DimensionKind]) -> Vec<DimensionKind>
                 DimensionKind::Quantity,
                 DimensionKind::Temperature,
                 DimensionKind::TimeGrain,
+                DimensionKind::Duration,
+                DimensionKind::Time,
                 DimensionKind::Volume,
             ],
             Lang::RO => vec![
@@ -275,6 +544,7 @@ fn collect_needed_dims(lang: Lang, dims: &[DimensionKind]) -> Vec<DimensionKind>
                 DimensionKind::Quantity,
                 DimensionKind::Temperature,
                 DimensionKind::TimeGrain,
+                DimensionKind::Time,
                 DimensionKind::Volume,
             ],
             Lang::RU => vec![
@@ -283,6 +553,9 @@ fn collect_needed_dims(lang: Lang, dims: &[DimensionKind]) -> Vec<DimensionKind>
                 DimensionKind::Ordinal,
                 DimensionKind::Distance,
                 DimensionKind::Quantity,
+                DimensionKind::TimeGrain,
+                DimensionKind::Duration,
+                DimensionKind::Time,
                 DimensionKind::Volume,
             ],
             Lang::SV => vec![
@@ -297,10 +570,14 @@ fn collect_needed_dims(lang: Lang, dims: &[DimensionKind]) -> Vec<DimensionKind>
             Lang::SK => vec![DimensionKind::Numeral],
             Lang::HU => vec![DimensionKind::Numeral, DimensionKind::Ordinal],
             Lang::ML => vec![DimensionKind::Numeral, DimensionKind::Ordinal],
-            Lang::PL => vec![DimensionKind::Numeral, DimensionKind::Ordinal],
+            Lang::PL => vec![
+                DimensionKind::Numeral,
+                DimensionKind::Ordinal,
+                DimensionKind::Time,
+            ],
             Lang::TA => vec![DimensionKind::Numeral, DimensionKind::Ordinal],
             Lang::TE => vec![DimensionKind::Numeral],
-            Lang::TH => vec![DimensionKind::Numeral],
+            Lang::TH => vec![DimensionKind::Numeral, DimensionKind::Time],
             Lang::TR => vec![
                 DimensionKind::Numeral,
                 DimensionKind::AmountOfMoney,
@@ -309,9 +586,14 @@ fn collect_needed_dims(lang: Lang, dims: &[DimensionKind]) -> Vec<DimensionKind>
                 DimensionKind::Ordinal,
                 DimensionKind::Temperature,
                 DimensionKind::TimeGrain,
+                DimensionKind::Time,
                 DimensionKind::Volume,
             ],
-            Lang::UK => vec![DimensionKind::Numeral, DimensionKind::Ordinal],
+            Lang::UK => vec![
+                DimensionKind::Numeral,
+                DimensionKind::Ordinal,
+                DimensionKind::Time,
+            ],
         }
     } else {
         needed
@@ -339,6 +621,7 @@ fn common_rules(needed: &[DimensionKind]) -> Vec<Rule> {
             DimensionKind::Duration => {
                 rules.extend(crate::dimensions::duration::en::common_rules())
             }
+            DimensionKind::Time => rules.extend(crate::dimensions::time::en::common_rules()),
             DimensionKind::AmountOfMoney => {
                 rules.extend(crate::dimensions::amount_of_money::en::common_rules())
             }
@@ -348,6 +631,12 @@ fn common_rules(needed: &[DimensionKind]) -> Vec<Rule> {
                 rules.extend(crate::dimensions::phone_number::rules::rules())
             }
             DimensionKind::Url => rules.extend(crate::dimensions::url::rules::rules()),
+            DimensionKind::NetworkAddress => {
+                rules.extend(crate::dimensions::network_address::rules::rules())
+            }
+            DimensionKind::Coordinate => {
+                rules.extend(crate::dimensions::coordinate::rules::rules())
+            }
             DimensionKind::CreditCardNumber => {
                 rules.extend(crate::dimensions::credit_card_number::rules::rules())
             }
@@ -373,15 +662,19 @@ fn lang_rules(lang: Lang, needed: &[DimensionKind]) -> Vec<Rule> {
             Lang::DA => pre_time_rules.extend(crate::dimensions::time::da::rules()),
             Lang::DE => pre_time_rules.extend(crate::dimensions::time::de::rules()),
             Lang::EL => pre_time_rules.extend(crate::dimensions::time::el::rules()),
+            Lang::FA => pre_time_rules.extend(crate::dimensions::time::fa::rules()),
             Lang::FR => pre_time_rules.extend(crate::dimensions::time::fr::rules()),
             Lang::GA => pre_time_rules.extend(crate::dimensions::time::ga::rules()),
             Lang::HE => pre_time_rules.extend(crate::dimensions::time::he::rules()),
             Lang::HR => pre_time_rules.extend(crate::dimensions::time::hr::rules()),
             Lang::HU => pre_time_rules.extend(crate::dimensions::time::hu::rules()),
+            Lang::ID => pre_time_rules.extend(crate::dimensions::time::id::rules()),
             Lang::IT => pre_time_rules.extend(crate::dimensions::time::it::rules()),
             Lang::JA => pre_time_rules.extend(crate::dimensions::time::ja::rules()),
             Lang::KA => pre_time_rules.extend(crate::dimensions::time::ka::rules()),
+            Lang::KM => pre_time_rules.extend(crate::dimensions::time::km::rules()),
             Lang::KO => pre_time_rules.extend(crate::dimensions::time::ko::rules()),
+            Lang::LO => pre_time_rules.extend(crate::dimensions::time::lo::rules()),
             Lang::NB => pre_time_rules.extend(crate::dimensions::time::nb::rules()),
             Lang::NL => pre_time_rules.extend(crate::dimensions::time::nl::rules()),
             Lang::PL => pre_time_rules.extend(crate::dimensions::time::pl::rules()),
@@ -389,6 +682,7 @@ fn lang_rules(lang: Lang, needed: &[DimensionKind]) -> Vec<Rule> {
             Lang::RO => pre_time_rules.extend(crate::dimensions::time::ro::rules()),
             Lang::RU => pre_time_rules.extend(crate::dimensions::time::ru::rules()),
             Lang::SV => pre_time_rules.extend(crate::dimensions::time::sv::rules()),
+            Lang::TH => pre_time_rules.extend(crate::dimensions::time::th::rules()),
             Lang::TR => pre_time_rules.extend(crate::dimensions::time::tr::rules()),
             Lang::UK => pre_time_rules.extend(crate::dimensions::time::uk::rules()),
             Lang::VI => pre_time_rules.extend(crate::dimensions::time::vi::rules()),
@@ -620,8 +914,12 @@ fn lang_rules(lang: Lang, needed: &[DimensionKind]) -> Vec<Rule> {
         Lang::FA => {
             let mut rules = Vec::new();
             for dim in needed {
-                if *dim == DimensionKind::Numeral {
-                    rules.extend(crate::dimensions::numeral::fa::rules());
+                match dim {
+                    DimensionKind::Numeral => rules.extend(crate::dimensions::numeral::fa::rules()),
+                    DimensionKind::AmountOfMoney => {
+                        rules.extend(crate::dimensions::amount_of_money::fa::rules())
+                    }
+                    _ => {}
                 }
             }
             rules
@@ -669,6 +967,10 @@ fn lang_rules(lang: Lang, needed: &[DimensionKind]) -> Vec<Rule> {
                     DimensionKind::AmountOfMoney => {
                         rules.extend(crate::dimensions::amount_of_money::id::rules())
                     }
+                    DimensionKind::Distance => {
+                        rules.extend(crate::dimensions::distance::id::rules())
+                    }
+                    DimensionKind::Volume => rules.extend(crate::dimensions::volume::id::rules()),
                     _ => {}
                 }
             }
@@ -1289,9 +1591,27 @@ fn lang_rules(lang: Lang, needed: &[DimensionKind]) -> Vec<Rule> {
     }
 }
 
+/// Names of `lang`'s rules that the `region` overlay swaps for its own.
+fn replaced_rules(lang: Lang, region: Region) -> &'static [&'static str] {
+    match lang {
+        Lang::DE => de::replaced_rules(),
+        Lang::EN => en::replaced_rules(Some(region)),
+        Lang::ES => es::replaced_rules(),
+        Lang::FR => fr::replaced_rules(),
+        Lang::PT => pt::replaced_rules(),
+        Lang::ZH => zh::replaced_rules(region),
+        _ => &[],
+    }
+}
+
 fn locale_rules(lang: Lang, region: Region, needed: &[DimensionKind]) -> Vec<Rule> {
     match lang {
+        Lang::DE => de::locale_rules(region, needed),
         Lang::EN => en::locale_rules(Some(region), needed),
+        Lang::ES => es::locale_rules(region, needed),
+        Lang::FR => fr::locale_rules(region, needed),
+        Lang::PT => pt::locale_rules(region, needed),
+        Lang::ZH => zh::locale_rules(region, needed),
         _ => Vec::new(),
     }
 }
@@ -1304,8 +1624,14 @@ mod tests {
     #[test]
     fn subset_dims_use_fewer_rules_than_all_dims() {
         let locale = Locale::new(Lang::EN, None);
-        let all = rules_for(locale, &[]);
-        let url_only = rules_for(locale, &[DimensionKind::Url]);
+        let all = rules_for(locale, locale.decimal_separator(), &[], &[], &[]);
+        let url_only = rules_for(
+            locale,
+            locale.decimal_separator(),
+            &[DimensionKind::Url],
+            &[],
+            &[],
+        );
         assert!(!url_only.is_empty(), "expected URL rules to be loaded");
         assert!(
             url_only.len() < all.len(),
@@ -1314,4 +1640,71 @@ mod tests {
             all.len()
         );
     }
+
+    #[test]
+    fn default_dims_include_time_where_a_time_pack_ships() {
+        for lang in [
+            Lang::BG,
+            Lang::HE,
+            Lang::IT,
+            Lang::NL,
+            Lang::PL,
+            Lang::RO,
+            Lang::RU,
+            Lang::TR,
+            Lang::UK,
+        ] {
+            assert!(
+                collect_needed_dims(lang, &[]).contains(&DimensionKind::Time),
+                "{lang:?}"
+            );
+        }
+
+        let locale = Locale::new(Lang::RO, None);
+        let entities = crate::parse(
+            "pe 3 martie",
+            &locale,
+            &[],
+            &crate::Context::default(),
+            &crate::Options::default(),
+        );
+        assert!(
+            entities
+                .iter()
+                .any(|e| e.body == "pe 3 martie" && e.value.dim_kind() == DimensionKind::Time),
+            "{entities:?}"
+        );
+    }
+
+    #[test]
+    fn rule_cache_evicts_least_recently_used() {
+        let key = |lang| {
+            CacheKey::new(
+                Locale::new(lang, None),
+                DecimalSeparator::Point,
+                &[],
+                &[],
+                &[],
+            )
+        };
+        let mut cache = RuleCache::new(2);
+        let en = Arc::new(RuleSet::default());
+        // The prefilter lives in the rule set, so it goes with it.
+        en.regex_set();
+        let weak_en = Arc::downgrade(&en);
+        cache.insert(key(Lang::EN), en);
+        cache.insert(key(Lang::FR), Arc::new(RuleSet::default()));
+        assert!(cache.get(&key(Lang::FR)).is_some());
+
+        cache.insert(key(Lang::DE), Arc::new(RuleSet::default()));
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get(&key(Lang::EN)).is_none());
+        assert!(cache.get(&key(Lang::FR)).is_some());
+        assert!(weak_en.upgrade().is_none(), "evicted rules should be freed");
+
+        cache.set_capacity(0);
+        assert_eq!(cache.len(), 1);
+        cache.clear();
+        assert_eq!(cache.len(), 0);
+    }
 }
@@ -0,0 +1,22 @@
+use crate::dimensions;
+use crate::locale::Region;
+use crate::types::{DimensionKind, Rule};
+
+/// Region-specific rule overlays for Portuguese locales.
+pub(crate) fn locale_rules(region: Region, needed: &[DimensionKind]) -> Vec<Rule> {
+    let mut rules = Vec::new();
+    if needed.contains(&DimensionKind::AmountOfMoney) {
+        rules.extend(dimensions::amount_of_money::pt::locale_rules(region));
+    }
+    if needed.contains(&DimensionKind::Time) {
+        rules.extend(dimensions::time::pt::locale_rules(region));
+    }
+    rules
+}
+
+/// Base rules that `locale_rules` replaces: the month-first numeric dates
+/// taken over from English, and the Brazilian holidays, which only the
+/// PT-BR overlay brings back.
+pub(crate) fn replaced_rules() -> &'static [&'static str] {
+    &["date MM/DD(/YYYY)", "Tiradentes (pt)", "Independecia (pt)"]
+}
@@ -0,0 +1,21 @@
+use crate::dimensions;
+use crate::locale::Region;
+use crate::types::{DimensionKind, Rule};
+
+/// Region-specific rule overlays for Chinese locales.
+pub(crate) fn locale_rules(region: Region, needed: &[DimensionKind]) -> Vec<Rule> {
+    let mut rules = Vec::new();
+    if needed.contains(&DimensionKind::Time) {
+        rules.extend(dimensions::time::zh::locale_rules(region));
+    }
+    rules
+}
+
+/// Base rules that `locale_rules` replaces: in Taiwan "國慶" is Double
+/// Tenth, which the overlay reads instead of October 1.
+pub(crate) fn replaced_rules(region: Region) -> &'static [&'static str] {
+    match region {
+        Region::TW => &["guoqing (zh)"],
+        _ => &[],
+    }
+}
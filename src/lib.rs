@@ -4,14 +4,20 @@
 
 #[cfg(not(debug_assertions))]
 use std::any::Any;
+use std::ops::Range;
 #[cfg(not(debug_assertions))]
 use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::time::Instant;
 
+pub(crate) mod detect;
 pub(crate) mod dimensions;
 pub(crate) mod document;
 pub(crate) mod engine;
+pub(crate) mod format;
+pub(crate) mod fuzzy;
 pub(crate) mod lang;
 pub(crate) mod locale;
+pub(crate) mod parser;
 pub(crate) mod pattern;
 pub(crate) mod ranking;
 pub(crate) mod resolve;
@@ -20,17 +26,43 @@ pub(crate) mod stash;
 pub(crate) mod testing;
 pub(crate) mod types;
 
+pub mod time_math;
+
 /// Corpus examples for training classifiers.
 #[cfg(feature = "train")]
 pub mod corpus;
 
 // Re-exports for convenience
+#[cfg(feature = "lang-detect")]
+pub use detect::HeuristicDetector;
+pub use detect::LangDetector;
+pub use dimensions::age::AgeUnit;
+pub use dimensions::amount_of_money::MoneyCurrency;
+pub use dimensions::distance::DistanceUnit;
+pub use dimensions::duration::DurationQualifier;
+#[cfg(feature = "medical")]
+pub use dimensions::medical::{DoseFrequency, DoseUnit};
+pub use dimensions::quantity::QuantityUnit;
+pub use dimensions::rate::RatePer;
+pub use dimensions::temperature::{DegreePolicy, TemperatureUnit};
+pub use dimensions::time::holidays::RegionalHolidays;
 pub use dimensions::time_grain::Grain;
-pub use locale::{Lang, Locale, Region};
-pub use resolve::{Context, Options};
+pub use dimensions::volume::VolumeUnit;
+pub use document::Document;
+pub use lang::DEFAULT_RULE_CACHE_CAPACITY;
+pub use locale::{
+    DecimalSeparator, Hemisphere, Lang, Locale, NextWeekdayPolicy, ObservedShift, Region,
+};
+pub use parser::Parser;
+pub use ranking::OverlapStrategy;
+pub use resolve::{
+    resolve_entity, Context, CustomHolidays, HolidayCalendar, HolidayResolver, Options,
+    OptionsBuilder, PartOfDayConfig, TimePreference, WeekNumbering,
+};
 pub use types::{
-    DimensionKind, DimensionValue, Entity, IntervalEndpoints, MeasurementPoint, MeasurementValue,
-    TimePoint, TimeValue,
+    Diagnostic, DiagnosticReason, DimensionKind, DimensionValue, Entity, EntityMetadata,
+    IntervalEndpoints, MeasurementPoint, MeasurementValue, ParseReport, Precision, RuleSource,
+    TimeExpr, TimePoint, TimeValue, Unit, UtcInterval,
 };
 
 #[cfg(feature = "train")]
@@ -46,12 +78,17 @@ pub fn train_classifiers(
     corpus: &ranking::train::TrainingCorpus,
     dims: &[DimensionKind],
 ) -> Classifiers {
-    let rules = lang::rules_for(*locale, dims);
-    ranking::train::make_classifiers(rules, corpus, dims)
+    let rules = lang::rules_for(*locale, locale.decimal_separator(), dims, &[], &[]);
+    ranking::train::make_classifiers(&rules, corpus, dims)
 }
 
 /// Parse natural language text and return structured entities.
 ///
+/// Entities are ordered by start offset, then by dimension in the order
+/// [`DimensionKind`] declares them, then by end offset. The order depends
+/// only on the input, not on the run or thread, and [`Entity::id`] gives
+/// each entity a stable identity.
+///
 /// # Arguments
 /// * `text` - The input text to parse
 /// * `locale` - The locale (language + optional region)
@@ -77,39 +114,409 @@ pub fn parse(
     context: &Context,
     options: &Options,
 ) -> Vec<Entity> {
+    parse_with_report(text, locale, dims, context, options).entities
+}
+
+/// Like [`parse`], but also reports whether [`Options::max_input_len`] or
+/// [`Options::time_budget`] cut the parse short.
+pub fn parse_with_report(
+    text: &str,
+    locale: &Locale,
+    dims: &[DimensionKind],
+    context: &Context,
+    options: &Options,
+) -> ParseReport {
+    let deadline = deadline_for(options);
+    guarded(|| {
+        let rules = rules_for_options(locale, dims, options);
+        let mut scratch = engine::ParseScratch::default();
+        parse_inner(
+            text,
+            locale,
+            dims,
+            context,
+            options,
+            &rules,
+            &mut scratch,
+            deadline,
+            None,
+        )
+    })
+}
+
+/// Like [`parse`], but also returns [`Diagnostic`]s for candidates that
+/// resolution dropped or could not read as written, and why: a year out of
+/// range, a holiday without a date for the year asked for, an unsupported
+/// timezone.
+///
+/// Meant for debugging; diagnostics cost extra work, so use [`parse`] when
+/// they're not needed.
+///
+/// # Example
+/// ```
+/// use duckling::{parse_with_diagnostics, Context, DiagnosticReason, DimensionKind, Lang, Locale, Options};
+///
+/// let locale = Locale::new(Lang::EN, None);
+/// // The lunar calendar table doesn't reach back to 1910, so the date
+/// // returned for this is only a fallback.
+/// let (entities, diagnostics) = parse_with_diagnostics("chinese new year 1910", &locale, &[DimensionKind::Time], &Context::default(), &Options::default());
+/// assert_eq!(entities[0].body, "chinese new year 1910");
+/// assert!(matches!(diagnostics[0].reason, DiagnosticReason::HolidayYearUnknown { year: 1910, .. }));
+/// assert!(!diagnostics[0].dropped);
+/// ```
+pub fn parse_with_diagnostics(
+    text: &str,
+    locale: &Locale,
+    dims: &[DimensionKind],
+    context: &Context,
+    options: &Options,
+) -> (Vec<Entity>, Vec<Diagnostic>) {
+    let deadline = deadline_for(options);
+    guarded(|| {
+        let rules = rules_for_options(locale, dims, options);
+        let mut scratch = engine::ParseScratch::default();
+        let mut diagnostics = Vec::new();
+        let report = parse_inner(
+            text,
+            locale,
+            dims,
+            context,
+            options,
+            &rules,
+            &mut scratch,
+            deadline,
+            Some(&mut diagnostics),
+        );
+        (report.entities, diagnostics)
+    })
+}
+
+/// The rules for `locale` and `dims` with the decimal separator, diacritic
+/// folding and extra languages `options` asks for.
+fn rules_for_options(
+    locale: &Locale,
+    dims: &[DimensionKind],
+    options: &Options,
+) -> std::sync::Arc<lang::RuleSet> {
+    let separator = options
+        .decimal_separator
+        .unwrap_or_else(|| locale.decimal_separator());
+    lang::rules_for(
+        *locale,
+        separator,
+        dims,
+        &options.fold_diacritics,
+        &options.extra_langs,
+    )
+}
+
+/// Parse only the given byte ranges of `text`, e.g. regions a tagger has
+/// already flagged, skipping the rest of the document.
+///
+/// Overlapping or touching spans are merged, and spans that are empty, out of
+/// bounds or not on char boundaries are ignored. Each span is parsed on its
+/// own, so it should cover whole words. Entity offsets are relative to `text`.
+/// [`Options::time_budget`] covers the whole call; other options apply per span.
+///
+/// # Example
+/// ```
+/// use duckling::{parse_spans, Context, DimensionKind, Lang, Locale, Options};
+///
+/// let text = "ticket 4471: refund 30 dollars by friday";
+/// let locale = Locale::new(Lang::EN, None);
+/// let entities = parse_spans(text, &[13..30], &locale, &[DimensionKind::AmountOfMoney], &Context::default(), &Options::default());
+/// assert_eq!(entities.len(), 1);
+/// assert_eq!(&text[entities[0].start..entities[0].end], "30 dollars");
+/// ```
+pub fn parse_spans(
+    text: &str,
+    spans: &[Range<usize>],
+    locale: &Locale,
+    dims: &[DimensionKind],
+    context: &Context,
+    options: &Options,
+) -> Vec<Entity> {
+    let deadline = deadline_for(options);
+    guarded(|| {
+        let rules = rules_for_options(locale, dims, options);
+        let mut scratch = engine::ParseScratch::default();
+        parse_spans_inner(
+            text,
+            spans,
+            locale,
+            dims,
+            context,
+            options,
+            &rules,
+            &mut scratch,
+            deadline,
+        )
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+fn parse_spans_inner(
+    text: &str,
+    spans: &[Range<usize>],
+    locale: &Locale,
+    dims: &[DimensionKind],
+    context: &Context,
+    options: &Options,
+    rules: &lang::RuleSet,
+    scratch: &mut engine::ParseScratch,
+    deadline: Option<Instant>,
+) -> Vec<Entity> {
+    let mut entities = Vec::new();
+    for span in merge_spans(text, spans) {
+        if engine::past(deadline) {
+            break;
+        }
+        let report = parse_inner(
+            &text[span.clone()],
+            locale,
+            dims,
+            context,
+            options,
+            rules,
+            scratch,
+            deadline,
+            None,
+        );
+        let shift = |range: Range<usize>| {
+            range.start.saturating_add(span.start)..range.end.saturating_add(span.start)
+        };
+        entities.extend(report.entities.into_iter().map(|mut entity| {
+            remap_entity(&mut entity, &shift, text);
+            entity
+        }));
+    }
+    entities
+}
+
+/// The valid ranges among `spans`, sorted, with overlapping or touching
+/// ones merged.
+fn merge_spans(text: &str, spans: &[Range<usize>]) -> Vec<Range<usize>> {
+    let mut valid: Vec<Range<usize>> = spans
+        .iter()
+        .filter(|span| {
+            span.start < span.end
+                && span.end <= text.len()
+                && text.is_char_boundary(span.start)
+                && text.is_char_boundary(span.end)
+        })
+        .cloned()
+        .collect();
+    valid.sort_by_key(|span| span.start);
+    let mut merged: Vec<Range<usize>> = Vec::with_capacity(valid.len());
+    for span in valid {
+        match merged.last_mut() {
+            Some(last) if span.start <= last.end => last.end = last.end.max(span.end),
+            _ => merged.push(span),
+        }
+    }
+    merged
+}
+
+/// Move an entity parsed from derived text to the range `to_source` gives
+/// in `source`, taking its body from there.
+fn remap_entity(
+    entity: &mut Entity,
+    to_source: &dyn Fn(Range<usize>) -> Range<usize>,
+    source: &str,
+) {
+    let range = to_source(entity.start..entity.end);
+    if let Some(body) = source.get(range.clone()) {
+        entity.body = body.to_string();
+    }
+    entity.start = range.start;
+    entity.end = range.end;
+    if let Some(metadata) = entity.metadata.as_mut() {
+        for alternate in &mut metadata.alternates {
+            remap_entity(alternate, to_source, source);
+        }
+    }
+}
+
+/// Parse a [`Document`]'s built text, reporting entities at their offsets
+/// and with their bodies in its original text.
+pub fn parse_document(
+    doc: &Document,
+    locale: &Locale,
+    dims: &[DimensionKind],
+    context: &Context,
+    options: &Options,
+) -> Vec<Entity> {
+    let mut entities = parse(doc.text(), locale, dims, context, options);
+    for entity in &mut entities {
+        remap_entity(entity, &|range| doc.to_original(range), doc.original());
+    }
+    entities
+}
+
+/// Parse `text` in the language `detector` finds, falling back to
+/// `context.locale` when it finds none. A detected language other than the
+/// context's is parsed with its default region.
+pub fn parse_auto(
+    text: &str,
+    detector: &dyn LangDetector,
+    dims: &[DimensionKind],
+    context: &Context,
+    options: &Options,
+) -> Vec<Entity> {
+    let context = detected_context(detector.detect(text), context);
+    parse(text, &context.locale, dims, &context, options)
+}
+
+/// Like [`parse_auto`], but detects the language of each sentence, for
+/// mixed-language text. Consecutive sentences in the same language (or
+/// with none detected) are parsed together, so entities still span
+/// sentence breaks within one language.
+pub fn parse_auto_sentences(
+    text: &str,
+    detector: &dyn LangDetector,
+    dims: &[DimensionKind],
+    context: &Context,
+    options: &Options,
+) -> Vec<Entity> {
+    let mut groups: Vec<(Option<Lang>, Range<usize>)> = Vec::new();
+    for sentence in sentence_ranges(text) {
+        let lang = detector.detect(&text[sentence.clone()]);
+        match groups.last_mut() {
+            Some((group_lang, range)) if lang.is_none() || lang == *group_lang => {
+                range.end = sentence.end;
+            }
+            Some((group_lang @ None, range)) => {
+                *group_lang = lang;
+                range.end = sentence.end;
+            }
+            _ => groups.push((lang, sentence)),
+        }
+    }
+    groups
+        .into_iter()
+        .flat_map(|(lang, range)| {
+            let context = detected_context(lang, context);
+            parse_spans(text, &[range], &context.locale, dims, &context, options)
+        })
+        .collect()
+}
+
+/// `context` switched to `lang`, unless that is already its language.
+fn detected_context(lang: Option<Lang>, context: &Context) -> Context {
+    match lang {
+        Some(lang) if lang != context.locale.lang => Context {
+            locale: Locale::new(lang, None),
+            ..context.clone()
+        },
+        _ => context.clone(),
+    }
+}
+
+/// Byte ranges of the sentences in `text`: each ends after a run of
+/// terminal punctuation that is followed by whitespace, or after a
+/// full-width terminator.
+fn sentence_ranges(text: &str) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+    while let Some((i, ch)) = chars.next() {
+        let next = chars.peek().map(|&(_, c)| c);
+        let ends = match ch {
+            '。' | '！' | '？' => true,
+            '.' | '!' | '?' => next.is_none_or(char::is_whitespace),
+            _ => false,
+        };
+        if ends {
+            let end = i.saturating_add(ch.len_utf8());
+            ranges.push(start..end);
+            start = end;
+        }
+    }
+    if start < text.len() {
+        ranges.push(start..text.len());
+    }
+    ranges
+}
+
+/// When a parse starting now must stop, per [`Options::time_budget`].
+fn deadline_for(options: &Options) -> Option<Instant> {
+    options
+        .time_budget
+        .and_then(|budget| Instant::now().checked_add(budget))
+}
+
+/// `text` cut to at most `max_len` bytes, backing off to a char boundary.
+fn truncate_input(text: &str, max_len: usize) -> &str {
+    let mut end = max_len.min(text.len());
+    while !text.is_char_boundary(end) {
+        end = end.saturating_sub(1);
+    }
+    &text[..end]
+}
+
+/// Run a parse, turning a panic into an empty result in release builds.
+fn guarded<T: Default>(run: impl FnOnce() -> T) -> T {
     #[cfg(debug_assertions)]
     {
-        parse_inner(text, locale, dims, context, options)
+        run()
     }
 
     #[cfg(not(debug_assertions))]
     {
-        match catch_unwind(AssertUnwindSafe(|| {
-            parse_inner(text, locale, dims, context, options)
-        })) {
-            Ok(entities) => entities,
+        match catch_unwind(AssertUnwindSafe(run)) {
+            Ok(result) => result,
             Err(payload) => {
                 log::error!(
                     "duckling::parse panicked: {}",
                     panic_payload_message(&payload)
                 );
-                Vec::new()
+                T::default()
             }
         }
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn parse_inner(
     text: &str,
     locale: &Locale,
     dims: &[DimensionKind],
     context: &Context,
     options: &Options,
-) -> Vec<Entity> {
+    rules: &lang::RuleSet,
+    scratch: &mut engine::ParseScratch,
+    deadline: Option<Instant>,
+    mut diagnostics: Option<&mut Vec<Diagnostic>>,
+) -> ParseReport {
     use types::ResolvedToken;
 
-    let rules = lang::rules_for(*locale, dims);
-    let stash = engine::parse_string(text, rules);
+    let full_len = text.len();
+    let text = match options.max_input_len {
+        Some(max_len) => truncate_input(text, max_len),
+        None => text,
+    };
+    let input_truncated = text.len() < full_len;
+    let original = text;
+    let normalized = options
+        .normalize_unicode
+        .then(|| document::normalize(text))
+        .flatten();
+    let text = normalized.as_ref().map_or(text, |doc| doc.text());
+    let corrected = options
+        .fuzzy_matching
+        .then(|| fuzzy::correct(text, locale.lang))
+        .flatten();
+    let text = corrected.as_ref().map_or(text, |doc| doc.text());
+    let rewritten = normalized.is_some() || corrected.is_some();
+    let to_original = |range: Range<usize>| {
+        let range = corrected
+            .as_ref()
+            .map_or(range.clone(), |doc| doc.to_original(range));
+        normalized
+            .as_ref()
+            .map_or(range.clone(), |doc| doc.to_original(range))
+    };
+    let stash = engine::parse_string_with(text, rules, rules.regex_set(), scratch, deadline);
 
     // Resolve all nodes first, then rank — matching Haskell's
     // parseAndResolve → rank pipeline from Api.hs/Engine.hs.
@@ -121,18 +528,120 @@ fn parse_inner(
                 .map(|dk| dims.is_empty() || dims.contains(&dk))
                 .unwrap_or(false)
         })
+        .take_while(|_| !engine::past(deadline))
         .filter_map(|node| {
-            let entity = resolve::resolve(node, context, options, text)?;
+            let resolved = resolve::resolve(node, context, options, text);
+            if let Some(diagnostics) = diagnostics.as_deref_mut() {
+                note_diagnostic(
+                    node,
+                    resolved.is_none(),
+                    context,
+                    options,
+                    text,
+                    diagnostics,
+                );
+            }
+            let mut entity = resolved?;
+            if options.with_metadata {
+                entity.metadata = Some(EntityMetadata {
+                    rule: node.rule_name.clone(),
+                    source: node.rule_name.as_deref().and_then(|n| rules.source(n)),
+                    alternates: Vec::new(),
+                    time_expr: match &node.token_data {
+                        types::TokenData::Time(data) => Some(TimeExpr(data.clone())),
+                        _ => None,
+                    },
+                });
+            }
             Some(ResolvedToken {
-                node: node.clone(),
+                node: node.as_ref().clone(),
                 entity,
             })
         })
         .collect();
+    let budget_exceeded = engine::past(deadline);
+
+    let candidates: Vec<Entity> = if options.with_metadata {
+        resolved_tokens.iter().map(|rt| rt.entity.clone()).collect()
+    } else {
+        Vec::new()
+    };
+    let strategy = options.overlap_strategy;
+    let ranked = ranking::rank_resolved(resolved_tokens, locale, dims, strategy);
+    let mut entities = ranking::resolve_overlaps(ranked, locale, strategy);
+    if options.with_metadata {
+        let discarded: Vec<Entity> = candidates
+            .into_iter()
+            .filter(|c| !entities.contains(c))
+            .collect();
+        ranking::attach_alternates(&mut entities, discarded);
+    }
+    if let Some(diagnostics) = diagnostics {
+        // Notes on candidates ranking dropped anyway are noise.
+        diagnostics.retain(|d| {
+            d.dropped
+                || entities
+                    .iter()
+                    .any(|e| e.start == d.start && e.end == d.end && e.value.dim_kind() == d.dim)
+        });
+        if rewritten {
+            for d in diagnostics.iter_mut() {
+                let range = to_original(d.start..d.end);
+                d.body = original[range.clone()].to_string();
+                (d.start, d.end) = (range.start, range.end);
+            }
+        }
+        diagnostics.sort_by_key(|d| (d.start, d.end));
+    }
+    if rewritten {
+        for entity in &mut entities {
+            remap_entity(entity, &to_original, original);
+        }
+    }
+    ranking::sort_entities(&mut entities);
+    ParseReport {
+        entities,
+        input_truncated,
+        budget_exceeded,
+    }
+}
 
-    let ranked = ranking::rank_resolved(resolved_tokens, locale, dims);
-    let entities: Vec<Entity> = ranked.into_iter().map(|rt| rt.entity).collect();
-    ranking::remove_overlapping(entities)
+/// Record why `node`'s time token was dropped or misread, if
+/// [`dimensions::time::diagnose`] knows. Latent candidates dropped for being
+/// latent are not reported.
+fn note_diagnostic(
+    node: &types::Node,
+    dropped: bool,
+    context: &Context,
+    options: &Options,
+    text: &str,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let types::TokenData::Time(data) = &node.token_data else {
+        return;
+    };
+    if dropped && data.latent && !options.with_latent {
+        return;
+    }
+    let Some(reason) = dimensions::time::diagnose(data, context) else {
+        return;
+    };
+    let (start, end) = (node.range.start, node.range.end);
+    if diagnostics
+        .iter()
+        .any(|d| d.start == start && d.end == end && d.reason == reason)
+    {
+        return;
+    }
+    diagnostics.push(Diagnostic {
+        start,
+        end,
+        body: text[start..end].to_string(),
+        dim: DimensionKind::Time,
+        rule: node.rule_name.clone(),
+        reason,
+        dropped,
+    });
 }
 
 #[cfg(not(debug_assertions))]
@@ -146,6 +655,19 @@ fn panic_payload_message(payload: &Box<dyn Any + Send>) -> String {
     "non-string panic payload".to_string()
 }
 
+/// Clear the compiled rule cache, releasing rule sets not used by an in-flight parse.
+/// The next parse for each locale and dimension set recompiles its rules.
+pub fn clear_rule_cache() {
+    lang::clear_rule_cache();
+}
+
+/// Set how many (locale, dimensions) rule sets are kept compiled.
+/// Least recently used sets are evicted beyond this limit; the default is
+/// [`DEFAULT_RULE_CACHE_CAPACITY`]. A capacity of 0 is treated as 1.
+pub fn set_rule_cache_capacity(capacity: usize) {
+    lang::set_rule_cache_capacity(capacity);
+}
+
 /// Convenience function to parse text with default settings for English.
 ///
 /// ```
@@ -153,7 +675,8 @@ fn panic_payload_message(payload: &Box<dyn Any + Send>) -> String {
 ///
 /// assert_eq!(parse_en("forty-two", &[DimensionKind::Numeral]), vec![Entity {
 ///     body: "forty-two".into(), start: 0, end: 9, latent: Some(false),
-///     value: DimensionValue::Numeral(42.0),
+///     value: DimensionValue::Numeral(42.0), metadata: None, grain: None, precision: None,
+///     components: vec![],
 /// }]);
 /// ```
 pub fn parse_en(text: &str, dims: &[DimensionKind]) -> Vec<Entity> {
@@ -236,6 +759,135 @@ mod integration_tests {
         assert!(found, "Expected URL, got: {:?}", entities);
     }
 
+    #[test]
+    fn test_parse_network_address() {
+        let entities = parse_en("host 2001:DB8::1 is down", &[DimensionKind::NetworkAddress]);
+        let found = entities.iter().any(|e| {
+            matches!(&e.value, DimensionValue::NetworkAddress { value, kind }
+                if value == "2001:db8::1" && kind == "ipv6")
+        });
+        assert!(found, "Expected IPv6 address, got: {:?}", entities);
+    }
+
+    #[test]
+    fn test_parse_percentage_next_to_money() {
+        let entities = parse_en(
+            "20% of $50",
+            &[DimensionKind::Percentage, DimensionKind::AmountOfMoney],
+        );
+        let values: Vec<&DimensionValue> = entities.iter().map(|e| &e.value).collect();
+        assert!(
+            values.contains(&&DimensionValue::Percentage(20.0)),
+            "Expected 20%, got: {:?}",
+            values
+        );
+        assert!(
+            values.iter().any(|v| matches!(v, DimensionValue::AmountOfMoney(MeasurementValue::Value { value, .. }) if (*value - 50.0).abs() < 0.01)),
+            "Expected $50, got: {:?}",
+            values
+        );
+    }
+
+    #[test]
+    fn test_parse_with_metadata() {
+        let parse_meta = |text: &str, region: Option<Region>, dims: &[DimensionKind]| {
+            let locale = Locale::new(Lang::EN, region);
+            let context = Context {
+                locale,
+                ..Context::default()
+            };
+            let options = Options {
+                with_metadata: true,
+                ..Options::default()
+            };
+            parse(text, &locale, dims, &context, &options)
+        };
+
+        let dims = [DimensionKind::Duration, DimensionKind::Numeral];
+        let entities = parse_meta("in 2 hours", None, &dims);
+        assert_eq!(entities.len(), 1, "got: {:?}", entities);
+        let metadata = entities[0].metadata.as_ref().expect("metadata requested");
+        assert!(metadata.rule.is_some());
+        assert!(
+            metadata
+                .alternates
+                .iter()
+                .any(|alt| alt.body == "2" && alt.value == DimensionValue::Numeral(2.0)),
+            "Expected the numeral 2 as an alternate, got: {:?}",
+            metadata.alternates
+        );
+
+        let entities = parse_meta("42", None, &[DimensionKind::Numeral]);
+        let metadata = entities[0].metadata.as_ref().expect("metadata requested");
+        assert_eq!(metadata.rule.as_deref(), Some("integer (numeric)"));
+        assert_eq!(metadata.source, Some(RuleSource::Common));
+
+        let entities = parse_meta("tomorrow", None, &[DimensionKind::Time]);
+        let metadata = entities[0].metadata.as_ref().expect("metadata requested");
+        assert_eq!(metadata.source, Some(RuleSource::Lang));
+
+        let entities = parse_meta("lakh", Some(Region::IN), &[DimensionKind::Numeral]);
+        let metadata = entities[0].metadata.as_ref().expect("metadata requested");
+        assert_eq!(metadata.source, Some(RuleSource::Locale));
+
+        // Metadata stays off unless asked for.
+        let entities = parse_en("in 2 hours", &[DimensionKind::Duration]);
+        assert!(entities.iter().all(|e| e.metadata.is_none()));
+    }
+
+    #[test]
+    fn test_parse_overlap_strategies() {
+        let locale = Locale::new(Lang::EN, None);
+        let context = Context {
+            locale,
+            ..Context::default()
+        };
+        let dims = [DimensionKind::Time, DimensionKind::Numeral];
+        let bodies = |text: &str, strategy: OverlapStrategy| -> Vec<(String, DimensionKind)> {
+            let options = Options {
+                overlap_strategy: strategy,
+                ..Options::default()
+            };
+            parse(text, &locale, &dims, &context, &options)
+                .into_iter()
+                .map(|e| (e.body, e.value.dim_kind()))
+                .collect()
+        };
+        let time = ("tomorrow at 5".to_string(), DimensionKind::Time);
+        let numeral = ("5".to_string(), DimensionKind::Numeral);
+
+        let longest = bodies("tomorrow at 5", OverlapStrategy::KeepLongest);
+        assert_eq!(longest, vec![time.clone()]);
+
+        let per_dim = bodies("tomorrow at 5", OverlapStrategy::PerDimension);
+        assert!(
+            per_dim.contains(&time) && per_dim.contains(&numeral),
+            "got: {:?}",
+            per_dim
+        );
+        assert_eq!(per_dim.len(), 2, "got: {:?}", per_dim);
+
+        let all = bodies("tomorrow at 5", OverlapStrategy::KeepAllNonIdentical);
+        assert!(
+            all.contains(&time) && all.contains(&numeral),
+            "got: {:?}",
+            all
+        );
+        assert!(all.contains(&("tomorrow".to_string(), DimensionKind::Time)));
+
+        let options = Options {
+            overlap_strategy: OverlapStrategy::KeepHighestScore,
+            ..Options::default()
+        };
+        let best = parse("tomorrow at 5", &locale, &dims, &context, &options);
+        assert!(!best.is_empty());
+        for (i, a) in best.iter().enumerate() {
+            for b in &best[i + 1..] {
+                assert!(a.end <= b.start || b.end <= a.start, "overlap: {:?}", best);
+            }
+        }
+    }
+
     #[test]
     fn test_parse_money() {
         let entities = parse_en("$42.50", &[DimensionKind::AmountOfMoney]);
@@ -246,6 +898,34 @@ mod integration_tests {
         assert!(found, "Expected $42.50, got: {:?}", entities);
     }
 
+    #[test]
+    fn test_typed_units() {
+        let entities = parse_en("5 miles", &[DimensionKind::Distance]);
+        assert_eq!(
+            entities[0].value.unit(),
+            Some(Unit::Distance(DistanceUnit::Mile))
+        );
+        let json = serde_json::to_value(&entities[0].value).unwrap();
+        assert_eq!(json["Distance"]["Value"]["unit"], "mile");
+
+        let locale = Locale::new(Lang::EN, Some(Region::CA));
+        let context = Context {
+            locale,
+            ..Context::default()
+        };
+        let entities = parse(
+            "$20",
+            &locale,
+            &[DimensionKind::AmountOfMoney],
+            &context,
+            &Options::default(),
+        );
+        assert_eq!(
+            entities[0].value.unit(),
+            Some(Unit::Money(MoneyCurrency::CAD))
+        );
+    }
+
     #[test]
     fn test_parse_ordinal() {
         let entities = parse_en("the 3rd", &[DimensionKind::Ordinal]);
@@ -335,7 +1015,10 @@ mod integration_tests {
     fn test_entity_latent_flag_is_set_when_enabled() {
         let locale = Locale::new(Lang::EN, None);
         let context = Context::default();
-        let options = Options { with_latent: true };
+        let options = Options {
+            with_latent: true,
+            ..Options::default()
+        };
         let entities = parse(
             "morning",
             &locale,
@@ -406,6 +1089,7 @@ mod integration_tests {
             reference_time: Utc.with_ymd_and_hms(2013, 2, 12, 4, 30, 0).unwrap(),
             locale,
             timezone_offset_minutes: -120,
+            ..Context::default()
         };
         let options = Options::default();
         let entities = parse("15/2", &locale, &[DimensionKind::Time], &context, &options);
@@ -431,6 +1115,7 @@ mod integration_tests {
             reference_time: Utc.with_ymd_and_hms(2013, 2, 12, 4, 30, 0).unwrap(),
             locale,
             timezone_offset_minutes: -120,
+            ..Context::default()
         };
         let options = Options::default();
         let entities = parse(
@@ -453,4 +1138,322 @@ mod integration_tests {
             entities
         );
     }
+
+    #[test]
+    fn test_max_input_len_parses_prefix_only() {
+        let locale = Locale::new(Lang::EN, None);
+        let dims = [DimensionKind::Numeral];
+        let options = Options {
+            max_input_len: Some(12),
+            ..Options::default()
+        };
+        // Byte 12 falls inside "ü", so the cut backs off to byte 11.
+        let report = parse_with_report(
+            "42 and grün 17",
+            &locale,
+            &dims,
+            &Context::default(),
+            &options,
+        );
+        assert!(report.input_truncated && report.is_partial());
+        assert_eq!(report.entities.len(), 1);
+        assert_eq!(report.entities[0].body, "42");
+
+        let report = parse_with_report("42", &locale, &dims, &Context::default(), &options);
+        assert!(!report.is_partial());
+    }
+
+    #[test]
+    fn test_time_budget_stops_parse() {
+        let locale = Locale::new(Lang::EN, None);
+        let text = "tomorrow at 3pm for $50";
+        let spent = Options {
+            time_budget: Some(std::time::Duration::ZERO),
+            ..Options::default()
+        };
+        let report = parse_with_report(text, &locale, &[], &Context::default(), &spent);
+        assert!(report.budget_exceeded);
+        assert!(report.entities.is_empty(), "{:?}", report.entities);
+
+        let ample = Options {
+            time_budget: Some(std::time::Duration::from_secs(3600)),
+            ..Options::default()
+        };
+        let report = parse_with_report(text, &locale, &[], &Context::default(), &ample);
+        assert!(!report.is_partial());
+        assert_eq!(
+            report.entities,
+            parse(text, &locale, &[], &Context::default(), &Options::default())
+        );
+    }
+
+    #[test]
+    fn test_fold_diacritics_option() {
+        let bodies = |text: &str, lang: Lang, options: &Options| -> Vec<String> {
+            let locale = Locale::new(lang, None);
+            parse(
+                text,
+                &locale,
+                &[DimensionKind::Time],
+                &Context::default(),
+                options,
+            )
+            .into_iter()
+            .map(|e| e.body)
+            .collect()
+        };
+        let folding = Options {
+            fold_diacritics: vec![Lang::ES, Lang::DE],
+            ..Options::default()
+        };
+        assert!(bodies("el ano pasado", Lang::ES, &Options::default()).is_empty());
+        assert_eq!(
+            bodies("el ano pasado", Lang::ES, &folding),
+            ["el ano pasado"]
+        );
+        assert_eq!(
+            bodies("el año pasado", Lang::ES, &folding),
+            ["el año pasado"]
+        );
+        assert_eq!(
+            bodies("nachste woche", Lang::DE, &folding),
+            ["nachste woche"]
+        );
+        // Only the listed languages fold.
+        let spanish_only = Options {
+            fold_diacritics: vec![Lang::ES],
+            ..Options::default()
+        };
+        assert!(bodies("nachste woche", Lang::DE, &spanish_only).is_empty());
+        // Also when both packs are loaded together.
+        let mixed = Options {
+            extra_langs: vec![Lang::DE],
+            ..spanish_only
+        };
+        assert_eq!(bodies("el ano pasado", Lang::ES, &mixed), ["el ano pasado"]);
+        assert_eq!(bodies("nächste woche", Lang::ES, &mixed), ["nächste woche"]);
+        assert!(bodies("nachste woche", Lang::ES, &mixed).is_empty());
+    }
+
+    #[test]
+    fn test_buddhist_era_years_option() {
+        let locale = Locale::new(Lang::TH, None);
+        let context = Context {
+            locale,
+            ..Context::default()
+        };
+        let dates = |text: &str, options: &Options| -> Vec<chrono::NaiveDate> {
+            parse(text, &locale, &[DimensionKind::Time], &context, options)
+                .into_iter()
+                .filter(|e| e.body == text)
+                .filter_map(|e| match e.value {
+                    DimensionValue::Time(TimeValue::Single {
+                        value: TimePoint::Naive { value, .. },
+                        ..
+                    }) => Some(value.date()),
+                    _ => None,
+                })
+                .collect()
+        };
+        let date = |y, m, d| chrono::NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let literal = Options::builder().buddhist_era_years(false).build();
+        assert_eq!(
+            dates("13 เมษายน 2568", &Options::default()),
+            [date(2025, 4, 13)]
+        );
+        assert_eq!(dates("13 เมษายน 2568", &literal), [date(2568, 4, 13)]);
+        // A marked era is read as marked either way
+        assert_eq!(dates("13 เมษายน พ.ศ. 2568", &literal), [date(2025, 4, 13)]);
+    }
+
+    #[test]
+    fn test_entity_order_and_ids_are_deterministic() {
+        let text = "pay 20 dollars on the 3rd, call 555-0100 at 4pm for 2 hours";
+        let run = || {
+            let options = Options {
+                overlap_strategy: OverlapStrategy::KeepAllNonIdentical,
+                ..Options::default()
+            };
+            parse(
+                text,
+                &Locale::new(Lang::EN, None),
+                &[],
+                &Context::default(),
+                &options,
+            )
+        };
+        let first = run();
+        let key = |e: &Entity| (e.start, e.value.dim_kind() as usize, e.end);
+        assert!(first.windows(2).all(|w| key(&w[0]) <= key(&w[1])));
+        let threads: Vec<_> = (0..4).map(|_| std::thread::spawn(run)).collect();
+        for thread in threads {
+            let entities = thread.join().unwrap();
+            assert_eq!(entities, first);
+            let ids = |es: &[Entity]| es.iter().map(Entity::id).collect::<Vec<_>>();
+            assert_eq!(ids(&entities), ids(&first));
+        }
+    }
+
+    #[test]
+    fn test_parse_with_diagnostics() {
+        let diagnose = |text: &str| {
+            parse_with_diagnostics(
+                text,
+                &Locale::new(Lang::EN, None),
+                &[DimensionKind::Time],
+                &Context::default(),
+                &Options::default(),
+            )
+        };
+        let (entities, diagnostics) = diagnose("see you diwali 1910");
+        assert!(entities.iter().any(|e| e.body == "diwali 1910"));
+        assert_eq!(diagnostics.len(), 1, "{diagnostics:?}");
+        let diagnostic = &diagnostics[0];
+        assert_eq!(
+            (diagnostic.start, diagnostic.body.as_str()),
+            (8, "diwali 1910")
+        );
+        assert!(!diagnostic.dropped);
+        assert_eq!(
+            diagnostic.reason,
+            DiagnosticReason::HolidayYearUnknown {
+                name: "diwali".to_string(),
+                year: 1910,
+            }
+        );
+        // Entities match `parse`, and clean input has no diagnostics.
+        let (entities, diagnostics) = diagnose("christmas 1910 at 3pm");
+        assert!(diagnostics.is_empty(), "{diagnostics:?}");
+        assert_eq!(
+            entities,
+            parse(
+                "christmas 1910 at 3pm",
+                &Locale::new(Lang::EN, None),
+                &[DimensionKind::Time],
+                &Context::default(),
+                &Options::default(),
+            )
+        );
+    }
+
+    #[test]
+    fn test_extra_langs_compose_with_locale_rules() {
+        let locale = Locale::new(Lang::EN, None);
+        let context = Context::default();
+        let mixed = Options {
+            extra_langs: vec![Lang::DE],
+            ..Options::default()
+        };
+        let time = |text: &str, options: &Options| {
+            parse(text, &locale, &[DimensionKind::Time], &context, options)
+                .into_iter()
+                .map(|e| (e.body, e.value))
+                .collect::<Vec<_>>()
+        };
+        assert!(time("3rd of März", &Options::default())
+            .iter()
+            .all(|(body, _)| body != "3rd of März"));
+        let found = time("3rd of März", &mixed);
+        assert_eq!(found.len(), 1, "{found:?}");
+        assert_eq!(found[0].0, "3rd of März");
+        assert_eq!(found[0].1, time("3rd of March", &Options::default())[0].1);
+        // The locale's own rules still apply, and a reused parser follows the
+        // option.
+        assert_eq!(time("tomorrow", &mixed).len(), 1);
+        let mut parser = Parser::new(locale, &[DimensionKind::Time]);
+        assert_eq!(parser.parse("3rd of März", &context, &mixed).len(), 1);
+        assert!(parser
+            .parse("3rd of März", &context, &Options::default())
+            .iter()
+            .all(|e| e.body != "3rd of März"));
+    }
+
+    #[test]
+    fn test_entity_grain_and_precision() {
+        let fields = |text: &str, dim: DimensionKind| {
+            let entities = parse_en(text, &[dim]);
+            assert_eq!(entities.len(), 1, "{text:?}: {entities:?}");
+            (entities[0].grain, entities[0].precision)
+        };
+        use DimensionKind::*;
+        assert_eq!(
+            fields("around $100", AmountOfMoney),
+            (None, Some(Precision::Approximate))
+        );
+        assert_eq!(
+            fields("exactly 3 hours", Duration),
+            (Some(Grain::Hour), Some(Precision::Exact))
+        );
+        assert_eq!(fields("3 hours", Duration), (Some(Grain::Hour), None));
+        assert_eq!(fields("tomorrow", Time), (Some(Grain::Day), None));
+        assert_eq!(
+            fields("about 2 cups of sugar", Quantity),
+            (None, Some(Precision::Approximate))
+        );
+        assert_eq!(fields("42", Numeral), (None, None));
+
+        let json = serde_json::to_value(&parse_en("around $100", &[AmountOfMoney])[0]).unwrap();
+        assert_eq!(json["precision"], "approx");
+        assert!(json.get("grain").is_none());
+    }
+
+    #[test]
+    fn test_resolve_entity_against_new_reference_time() {
+        use chrono::{TimeZone, Utc};
+        let locale = Locale::new(Lang::EN, None);
+        let options = Options {
+            with_metadata: true,
+            ..Options::default()
+        };
+        let at = |day: u32| Context {
+            reference_time: Utc.with_ymd_and_hms(2013, 2, day, 4, 30, 0).unwrap(),
+            timezone_offset_minutes: -120,
+            ..Context::default()
+        };
+        for text in [
+            "next friday at 3pm",
+            "in 2 hours",
+            "the last day of the month",
+        ] {
+            let parsed = parse(text, &locale, &[DimensionKind::Time], &at(12), &options);
+            assert_eq!(parsed.len(), 1, "{text:?}: {parsed:?}");
+            let stored = parsed[0]
+                .metadata
+                .as_ref()
+                .unwrap()
+                .time_expr
+                .as_ref()
+                .unwrap();
+            let json = serde_json::to_string(stored).unwrap();
+            let expr: TimeExpr = serde_json::from_str(&json).unwrap();
+            for day in [12, 20, 28] {
+                let reparsed = parse(text, &locale, &[DimensionKind::Time], &at(day), &options);
+                assert_eq!(
+                    resolve_entity(&expr, &at(day), &options).as_ref(),
+                    Some(&reparsed[0].value),
+                    "{text:?} on day {day}"
+                );
+            }
+        }
+        let numeral = parse("42", &locale, &[DimensionKind::Numeral], &at(12), &options);
+        assert_eq!(numeral[0].metadata.as_ref().unwrap().time_expr, None);
+    }
+
+    #[test]
+    fn test_parse_spans_offsets_and_merging() {
+        let locale = Locale::new(Lang::EN, None);
+        let dims = [DimensionKind::Numeral];
+        let text = "see 42 then ignore 17 but read 99";
+        let entities = parse_spans(
+            text,
+            &[26..33, 0..4, 3..7, 40..50, 1..1],
+            &locale,
+            &dims,
+            &Context::default(),
+            &Options::default(),
+        );
+        let bodies: Vec<&str> = entities.iter().map(|e| &text[e.start..e.end]).collect();
+        assert_eq!(bodies, ["42", "99"]);
+        assert!(entities.iter().all(|e| e.body == text[e.start..e.end]));
+    }
 }
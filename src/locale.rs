@@ -1,3 +1,7 @@
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+
+use crate::dimensions::temperature::TemperatureUnit;
+
 /// Supported languages.
 #[allow(missing_docs)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -109,6 +113,145 @@ impl Lang {
             Lang::ZH => "zh",
         }
     }
+
+    /// Parses an ISO 639-1 language code ("en", "PT"), the inverse of
+    /// [`Lang::code`].
+    pub fn from_code(code: &str) -> Option<Lang> {
+        Some(match code.to_ascii_lowercase().as_str() {
+            "af" => Lang::AF,
+            "ar" => Lang::AR,
+            "bg" => Lang::BG,
+            "bn" => Lang::BN,
+            "ca" => Lang::CA,
+            "cs" => Lang::CS,
+            "da" => Lang::DA,
+            "de" => Lang::DE,
+            "el" => Lang::EL,
+            "en" => Lang::EN,
+            "es" => Lang::ES,
+            "et" => Lang::ET,
+            "fa" => Lang::FA,
+            "fi" => Lang::FI,
+            "fr" => Lang::FR,
+            "ga" => Lang::GA,
+            "he" => Lang::HE,
+            "hi" => Lang::HI,
+            "hr" => Lang::HR,
+            "hu" => Lang::HU,
+            "id" => Lang::ID,
+            "is" => Lang::IS,
+            "it" => Lang::IT,
+            "ja" => Lang::JA,
+            "ka" => Lang::KA,
+            "km" => Lang::KM,
+            "kn" => Lang::KN,
+            "ko" => Lang::KO,
+            "lo" => Lang::LO,
+            "ml" => Lang::ML,
+            "mn" => Lang::MN,
+            "my" => Lang::MY,
+            "nb" => Lang::NB,
+            "ne" => Lang::NE,
+            "nl" => Lang::NL,
+            "pl" => Lang::PL,
+            "pt" => Lang::PT,
+            "ro" => Lang::RO,
+            "ru" => Lang::RU,
+            "sk" => Lang::SK,
+            "sv" => Lang::SV,
+            "sw" => Lang::SW,
+            "ta" => Lang::TA,
+            "te" => Lang::TE,
+            "th" => Lang::TH,
+            "tr" => Lang::TR,
+            "uk" => Lang::UK,
+            "vi" => Lang::VI,
+            "zh" => Lang::ZH,
+            _ => return None,
+        })
+    }
+
+    /// The decimal separator this language's numeral rules read digits with.
+    pub fn decimal_separator(&self) -> DecimalSeparator {
+        match self {
+            Lang::AF
+            | Lang::CA
+            | Lang::DA
+            | Lang::DE
+            | Lang::EL
+            | Lang::ES
+            | Lang::FR
+            | Lang::HR
+            | Lang::ID
+            | Lang::IT
+            | Lang::NB
+            | Lang::NL
+            | Lang::PT
+            | Lang::RO
+            | Lang::SK
+            | Lang::SV
+            | Lang::TR
+            | Lang::VI => DecimalSeparator::Comma,
+            _ => DecimalSeparator::Point,
+        }
+    }
+}
+
+/// How digit strings separate decimals from thousands groups.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DecimalSeparator {
+    /// "1,234.56": decimal point, comma-grouped thousands.
+    Point,
+    /// "1.234,56": decimal comma, point- or space-grouped thousands.
+    Comma,
+}
+
+/// Which half of the globe a locale's seasons follow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Hemisphere {
+    /// Summer from June to September.
+    #[default]
+    Northern,
+    /// Summer from December to March.
+    Southern,
+}
+
+/// How a holiday falling on a weekend moves to the day it is observed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ObservedShift {
+    /// Saturday to the Friday before, Sunday to the Monday after (US).
+    NearestWeekday,
+    /// Saturday or Sunday to the following Monday (GB, AU, CA).
+    #[default]
+    NextMonday,
+    /// Only Sunday moves, to the following Monday (ZA).
+    SundayToMonday,
+}
+
+impl ObservedShift {
+    /// The day `date` is observed on under this policy.
+    pub fn apply(&self, date: NaiveDate) -> NaiveDate {
+        let days = match (self, date.weekday()) {
+            (ObservedShift::NearestWeekday, Weekday::Sat) => -1,
+            (ObservedShift::NearestWeekday, Weekday::Sun) => 1,
+            (ObservedShift::NextMonday, Weekday::Sat) => 2,
+            (ObservedShift::NextMonday | ObservedShift::SundayToMonday, Weekday::Sun) => 1,
+            _ => 0,
+        };
+        Duration::try_days(days)
+            .and_then(|d| date.checked_add_signed(d))
+            .unwrap_or(date)
+    }
+}
+
+/// Which Friday "next Friday" names, said on a weekday before Friday.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum NextWeekdayPolicy {
+    /// The coming Friday, the same day as plain "Friday" (US).
+    Upcoming,
+    /// Friday of next week, skipping this week's (GB and elsewhere).
+    #[default]
+    FollowingWeek,
 }
 
 /// Supported regions for locale-specific behavior.
@@ -122,10 +265,16 @@ pub enum Region {
     GB,
     /// Australia
     AU,
+    /// Austria
+    AT,
     BE,
+    /// Brazil
+    BR,
     BZ,
     /// Canada
     CA,
+    /// Switzerland
+    CH,
     CL,
     CN,
     CO,
@@ -141,6 +290,8 @@ pub enum Region {
     NZ,
     PE,
     PH,
+    /// Portugal
+    PT,
     TT,
     TW,
     VE,
@@ -155,9 +306,12 @@ impl Region {
             Region::US => "US",
             Region::GB => "GB",
             Region::AU => "AU",
+            Region::AT => "AT",
             Region::BE => "BE",
+            Region::BR => "BR",
             Region::BZ => "BZ",
             Region::CA => "CA",
+            Region::CH => "CH",
             Region::CL => "CL",
             Region::CN => "CN",
             Region::CO => "CO",
@@ -172,12 +326,85 @@ impl Region {
             Region::NZ => "NZ",
             Region::PE => "PE",
             Region::PH => "PH",
+            Region::PT => "PT",
             Region::TT => "TT",
             Region::TW => "TW",
             Region::VE => "VE",
             Region::ZA => "ZA",
         }
     }
+
+    /// Parses an ISO 3166-1 alpha-2 region code ("US", "gb"), the inverse of
+    /// [`Region::code`].
+    pub fn from_code(code: &str) -> Option<Region> {
+        Some(match code.to_ascii_uppercase().as_str() {
+            "AR" => Region::AR,
+            "US" => Region::US,
+            "GB" => Region::GB,
+            "AU" => Region::AU,
+            "AT" => Region::AT,
+            "BE" => Region::BE,
+            "BR" => Region::BR,
+            "BZ" => Region::BZ,
+            "CA" => Region::CA,
+            "CH" => Region::CH,
+            "CL" => Region::CL,
+            "CN" => Region::CN,
+            "CO" => Region::CO,
+            "EG" => Region::EG,
+            "ES" => Region::ES,
+            "HK" => Region::HK,
+            "IE" => Region::IE,
+            "IN" => Region::IN,
+            "JM" => Region::JM,
+            "MO" => Region::MO,
+            "MX" => Region::MX,
+            "NZ" => Region::NZ,
+            "PE" => Region::PE,
+            "PH" => Region::PH,
+            "PT" => Region::PT,
+            "TT" => Region::TT,
+            "TW" => Region::TW,
+            "VE" => Region::VE,
+            "ZA" => Region::ZA,
+            _ => return None,
+        })
+    }
+
+    /// How this region observes holidays that fall on a weekend.
+    pub fn observed_shift(&self) -> ObservedShift {
+        match self {
+            Region::US => ObservedShift::NearestWeekday,
+            Region::ZA => ObservedShift::SundayToMonday,
+            _ => ObservedShift::NextMonday,
+        }
+    }
+
+    /// What "next <weekday>" means in this region's colloquial usage.
+    pub fn next_weekday_policy(&self) -> NextWeekdayPolicy {
+        match self {
+            Region::US => NextWeekdayPolicy::Upcoming,
+            _ => NextWeekdayPolicy::FollowingWeek,
+        }
+    }
+
+    /// The scale this region gives temperatures in.
+    pub fn temperature_scale(&self) -> TemperatureUnit {
+        match self {
+            Region::US | Region::BZ => TemperatureUnit::Fahrenheit,
+            _ => TemperatureUnit::Celsius,
+        }
+    }
+
+    /// The hemisphere whose seasons this region follows.
+    pub fn hemisphere(&self) -> Hemisphere {
+        match self {
+            Region::AR | Region::AU | Region::BR | Region::CL | Region::NZ | Region::ZA => {
+                Hemisphere::Southern
+            }
+            _ => Hemisphere::Northern,
+        }
+    }
 }
 
 /// A locale combining a language and optional region.
@@ -197,6 +424,62 @@ impl Locale {
             region: normalize_region(lang, region),
         }
     }
+
+    /// Parses a locale code such as "en", "en_US" or "pt-BR". Regions the
+    /// language has no rules for are dropped, as in [`Locale::new`].
+    pub fn from_code(code: &str) -> Option<Locale> {
+        let (lang, region) = match code.split_once(['_', '-']) {
+            Some((lang, region)) => (lang, Some(Region::from_code(region)?)),
+            None => (code, None),
+        };
+        Some(Locale::new(Lang::from_code(lang)?, region))
+    }
+}
+
+impl Locale {
+    /// The decimal separator used for digit strings in this locale: the
+    /// region's convention where it differs from the language default
+    /// (e.g. en_ZA, es_MX), otherwise the language's own.
+    pub fn decimal_separator(&self) -> DecimalSeparator {
+        match (self.lang, self.region) {
+            (Lang::EN, Some(Region::ZA)) => DecimalSeparator::Comma,
+            (Lang::ES, Some(Region::MX | Region::PE)) => DecimalSeparator::Point,
+            (lang, _) => lang.decimal_separator(),
+        }
+    }
+
+    /// How weekend holidays requested as "(observed)" move: the region's
+    /// policy, or [`ObservedShift::NextMonday`] when no region is set.
+    pub fn observed_shift(&self) -> ObservedShift {
+        self.region
+            .map(|region| region.observed_shift())
+            .unwrap_or_default()
+    }
+
+    /// What "next Friday" resolves to: the region's policy, or
+    /// [`NextWeekdayPolicy::FollowingWeek`] when no region is set.
+    pub fn next_weekday_policy(&self) -> NextWeekdayPolicy {
+        self.region
+            .map(|region| region.next_weekday_policy())
+            .unwrap_or_default()
+    }
+
+    /// The scale bare degrees are read in under
+    /// [`DegreePolicy::Regional`](crate::DegreePolicy::Regional): the
+    /// region's, or Celsius when no region is set.
+    pub fn temperature_scale(&self) -> TemperatureUnit {
+        self.region
+            .map(|region| region.temperature_scale())
+            .unwrap_or(TemperatureUnit::Celsius)
+    }
+
+    /// The hemisphere used for season words ("this summer"): the region's,
+    /// or northern when no region is set.
+    pub fn hemisphere(&self) -> Hemisphere {
+        self.region
+            .map(|region| region.hemisphere())
+            .unwrap_or_default()
+    }
 }
 
 impl Default for Locale {
@@ -229,6 +512,7 @@ fn normalize_region(lang: Lang, region: Option<Region>) -> Option<Region> {
                 | Region::ZA),
             ),
         ) => Some(r),
+        (Lang::DE, Some(r @ (Region::AT | Region::CH))) => Some(r),
         (
             Lang::ES,
             Some(
@@ -241,7 +525,9 @@ fn normalize_region(lang: Lang, region: Option<Region>) -> Option<Region> {
                 | Region::VE),
             ),
         ) => Some(r),
+        (Lang::FR, Some(r @ (Region::BE | Region::CA | Region::CH))) => Some(r),
         (Lang::NL, Some(r @ Region::BE)) => Some(r),
+        (Lang::PT, Some(r @ (Region::BR | Region::PT))) => Some(r),
         (Lang::ZH, Some(r @ (Region::CN | Region::HK | Region::MO | Region::TW))) => Some(r),
         _ => None,
     }
@@ -262,4 +548,99 @@ mod tests {
         let locale = Locale::new(Lang::EN, Some(Region::GB));
         assert_eq!(locale.region, Some(Region::GB));
     }
+
+    #[test]
+    fn decimal_separator_follows_region() {
+        assert_eq!(
+            Locale::new(Lang::EN, Some(Region::US)).decimal_separator(),
+            DecimalSeparator::Point
+        );
+        assert_eq!(
+            Locale::new(Lang::EN, Some(Region::ZA)).decimal_separator(),
+            DecimalSeparator::Comma
+        );
+        assert_eq!(
+            Locale::new(Lang::DE, None).decimal_separator(),
+            DecimalSeparator::Comma
+        );
+        assert_eq!(
+            Locale::new(Lang::ES, Some(Region::MX)).decimal_separator(),
+            DecimalSeparator::Point
+        );
+    }
+
+    #[test]
+    fn locale_from_code() {
+        assert_eq!(
+            Locale::from_code("en_US"),
+            Some(Locale::new(Lang::EN, Some(Region::US)))
+        );
+        assert_eq!(
+            Locale::from_code("pt-br"),
+            Some(Locale::new(Lang::PT, Some(Region::BR)))
+        );
+        assert_eq!(Locale::from_code("DE"), Some(Locale::new(Lang::DE, None)));
+        assert_eq!(Locale::from_code("xx_US"), None);
+        assert_eq!(Locale::from_code("en_XX"), None);
+    }
+
+    #[test]
+    fn hemisphere_follows_region() {
+        assert_eq!(
+            Locale::new(Lang::EN, Some(Region::AU)).hemisphere(),
+            Hemisphere::Southern
+        );
+        assert_eq!(
+            Locale::new(Lang::PT, Some(Region::BR)).hemisphere(),
+            Hemisphere::Southern
+        );
+        assert_eq!(
+            Locale::new(Lang::EN, Some(Region::GB)).hemisphere(),
+            Hemisphere::Northern
+        );
+        assert_eq!(
+            Locale::new(Lang::EN, None).hemisphere(),
+            Hemisphere::Northern
+        );
+    }
+
+    #[test]
+    fn observed_shift_moves_weekend_days() {
+        // 2021-12-25 is a Saturday, 2022-12-25 a Sunday
+        let sat = NaiveDate::from_ymd_opt(2021, 12, 25).unwrap();
+        let sun = NaiveDate::from_ymd_opt(2022, 12, 25).unwrap();
+        let us = Locale::new(Lang::EN, Some(Region::US)).observed_shift();
+        assert_eq!(
+            us.apply(sat),
+            NaiveDate::from_ymd_opt(2021, 12, 24).unwrap()
+        );
+        assert_eq!(
+            us.apply(sun),
+            NaiveDate::from_ymd_opt(2022, 12, 26).unwrap()
+        );
+        let gb = Locale::new(Lang::EN, Some(Region::GB)).observed_shift();
+        assert_eq!(
+            gb.apply(sat),
+            NaiveDate::from_ymd_opt(2021, 12, 27).unwrap()
+        );
+        let za = Locale::new(Lang::EN, Some(Region::ZA)).observed_shift();
+        assert_eq!(za.apply(sat), sat);
+        assert_eq!(
+            za.apply(sun),
+            NaiveDate::from_ymd_opt(2022, 12, 26).unwrap()
+        );
+    }
+
+    #[test]
+    fn locale_keeps_portuguese_regions() {
+        assert_eq!(
+            Locale::new(Lang::PT, Some(Region::BR)).region,
+            Some(Region::BR)
+        );
+        assert_eq!(
+            Locale::new(Lang::PT, Some(Region::PT)).region,
+            Some(Region::PT)
+        );
+        assert_eq!(Locale::new(Lang::PT, Some(Region::US)).region, None);
+    }
 }
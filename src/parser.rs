@@ -0,0 +1,186 @@
+use std::ops::Range;
+use std::sync::Arc;
+
+use crate::document::Document;
+use crate::engine::ParseScratch;
+use crate::lang::{self, RuleSet};
+use crate::locale::{DecimalSeparator, Lang, Locale};
+use crate::resolve::{Context, Options};
+use crate::types::{DimensionKind, Entity, ParseReport};
+
+/// A reusable parser for one locale and set of dimensions.
+///
+/// [`parse`](crate::parse) looks up its rules and builds its working buffers
+/// on every call. A `Parser` holds on to both, so parsing many texts with the
+/// same locale and dimensions allocates less. Results are identical to
+/// [`parse`](crate::parse) with the same arguments.
+///
+/// # Example
+/// ```
+/// use duckling::{Context, DimensionKind, Lang, Locale, Options, Parser};
+///
+/// let mut parser = Parser::new(Locale::new(Lang::EN, None), &[DimensionKind::Numeral]);
+/// let context = Context::default();
+/// let options = Options::default();
+///
+/// for text in ["42", "seven"] {
+///     assert_eq!(parser.parse(text, &context, &options).len(), 1);
+/// }
+/// ```
+pub struct Parser {
+    locale: Locale,
+    dims: Vec<DimensionKind>,
+    separator: DecimalSeparator,
+    fold_diacritics: Vec<Lang>,
+    extra_langs: Vec<Lang>,
+    rules: Arc<RuleSet>,
+    scratch: ParseScratch,
+}
+
+impl Parser {
+    /// Create a parser for `locale` extracting `dims` (empty = all).
+    pub fn new(locale: Locale, dims: &[DimensionKind]) -> Self {
+        let separator = locale.decimal_separator();
+        Parser {
+            locale,
+            dims: dims.to_vec(),
+            separator,
+            fold_diacritics: Vec::new(),
+            extra_langs: Vec::new(),
+            rules: lang::rules_for(locale, separator, dims, &[], &[]),
+            scratch: ParseScratch::default(),
+        }
+    }
+
+    /// The locale this parser was built for.
+    pub fn locale(&self) -> Locale {
+        self.locale
+    }
+
+    /// The dimensions this parser extracts.
+    pub fn dims(&self) -> &[DimensionKind] {
+        &self.dims
+    }
+
+    /// Parse `text`, reusing the rules and buffers from earlier calls.
+    pub fn parse(&mut self, text: &str, context: &Context, options: &Options) -> Vec<Entity> {
+        self.parse_with_report(text, context, options).entities
+    }
+
+    /// Parse only the given byte ranges of `text`; see [`crate::parse_spans`].
+    pub fn parse_spans(
+        &mut self,
+        text: &str,
+        spans: &[Range<usize>],
+        context: &Context,
+        options: &Options,
+    ) -> Vec<Entity> {
+        let deadline = crate::deadline_for(options);
+        self.refresh_rules(options);
+        let Parser {
+            locale,
+            dims,
+            rules,
+            scratch,
+            ..
+        } = self;
+        crate::guarded(|| {
+            crate::parse_spans_inner(
+                text, spans, locale, dims, context, options, rules, scratch, deadline,
+            )
+        })
+    }
+
+    /// Parse a [`Document`]'s text with offsets in its original; see
+    /// [`crate::parse_document`].
+    pub fn parse_document(
+        &mut self,
+        doc: &Document,
+        context: &Context,
+        options: &Options,
+    ) -> Vec<Entity> {
+        let mut entities = self.parse(doc.text(), context, options);
+        for entity in &mut entities {
+            crate::remap_entity(entity, &|range| doc.to_original(range), doc.original());
+        }
+        entities
+    }
+
+    /// Like [`Parser::parse`], but also reports whether a safeguard in
+    /// [`Options`] cut the parse short; see [`crate::parse_with_report`].
+    pub fn parse_with_report(
+        &mut self,
+        text: &str,
+        context: &Context,
+        options: &Options,
+    ) -> ParseReport {
+        let deadline = crate::deadline_for(options);
+        self.refresh_rules(options);
+        let Parser {
+            locale,
+            dims,
+            rules,
+            scratch,
+            ..
+        } = self;
+        crate::guarded(|| {
+            crate::parse_inner(
+                text, locale, dims, context, options, rules, scratch, deadline, None,
+            )
+        })
+    }
+
+    /// Swap in rules for the decimal separator, diacritic folding and extra
+    /// languages `options` asks for, if they differ from the current rules'.
+    fn refresh_rules(&mut self, options: &Options) {
+        let separator = options
+            .decimal_separator
+            .unwrap_or_else(|| self.locale.decimal_separator());
+        if separator != self.separator
+            || options.fold_diacritics != self.fold_diacritics
+            || options.extra_langs != self.extra_langs
+        {
+            self.rules = lang::rules_for(
+                self.locale,
+                separator,
+                &self.dims,
+                &options.fold_diacritics,
+                &options.extra_langs,
+            );
+            self.separator = separator;
+            self.fold_diacritics.clone_from(&options.fold_diacritics);
+            self.extra_langs.clone_from(&options.extra_langs);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::locale::Lang;
+
+    #[test]
+    fn reused_parser_matches_one_shot_parse() {
+        let locale = Locale::new(Lang::EN, None);
+        let dims = [DimensionKind::Numeral, DimensionKind::Time];
+        let context = Context::default();
+        let comma = Options {
+            decimal_separator: Some(DecimalSeparator::Comma),
+            ..Options::default()
+        };
+        let mut parser = Parser::new(locale, &dims);
+        for (text, options) in [
+            ("tomorrow at 3pm", Options::default()),
+            ("3.5 million", Options::default()),
+            ("3,5 million", comma),
+            ("", Options::default()),
+            ("tomorrow at 3pm", Options::default()),
+        ] {
+            assert_eq!(
+                parser.parse(text, &context, &options),
+                crate::parse(text, &locale, &dims, &context, &options),
+                "{text:?}"
+            );
+        }
+    }
+}
@@ -1,13 +1,117 @@
+use std::ops::Deref;
+
 use crate::types::{DimensionKind, PatternItem, TokenData};
+use once_cell::sync::OnceCell;
 use regex::Regex;
 
+/// A regex compiled the first time it is matched. Building a rule set only
+/// records pattern strings. The engine's prefilter compiles the patterns that
+/// lead a rule on the first parse; a pattern later in a rule is compiled only
+/// once text reaches it.
+pub(crate) struct LazyRegex {
+    pattern: String,
+    compiled: OnceCell<Regex>,
+}
+
+impl LazyRegex {
+    fn new(pattern: String) -> Self {
+        LazyRegex {
+            pattern,
+            compiled: OnceCell::new(),
+        }
+    }
+
+    /// The pattern source, available without compiling it.
+    pub(crate) fn as_str(&self) -> &str {
+        &self.pattern
+    }
+
+    /// This regex, also matching its accented letters written without the
+    /// accent; see [`fold_diacritics`].
+    pub(crate) fn folded(&self) -> LazyRegex {
+        LazyRegex::new(fold_diacritics(&self.pattern))
+    }
+}
+
+impl Deref for LazyRegex {
+    type Target = Regex;
+
+    fn deref(&self) -> &Regex {
+        self.compiled.get_or_init(|| {
+            Regex::new(&self.pattern)
+                .unwrap_or_else(|e| panic!("Invalid regex pattern '{}': {}", self.pattern, e))
+        })
+    }
+}
+
 /// Create a regex pattern item. The pattern is matched case-insensitively
-/// against the original document text.
+/// against the original document text, and compiled on first use.
 pub fn regex(pattern: &str) -> PatternItem {
-    let full = format!("(?i){}", pattern);
-    PatternItem::Regex(
-        Regex::new(&full).unwrap_or_else(|e| panic!("Invalid regex pattern '{}': {}", pattern, e)),
-    )
+    PatternItem::Regex(LazyRegex::new(format!("(?i){}", pattern)))
+}
+
+/// The unaccented letter an accented Latin letter is typed as, if any.
+fn base_letter(ch: char) -> Option<char> {
+    Some(match ch {
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => 'a',
+        'ç' | 'ć' | 'č' => 'c',
+        'ď' | 'đ' => 'd',
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ė' | 'ę' | 'ě' => 'e',
+        'ğ' => 'g',
+        'ì' | 'í' | 'î' | 'ï' | 'ī' | 'į' | 'ı' => 'i',
+        'ł' | 'ľ' | 'ĺ' => 'l',
+        'ñ' | 'ń' | 'ň' => 'n',
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ő' => 'o',
+        'ŕ' | 'ř' => 'r',
+        'ś' | 'š' | 'ş' | 'ș' => 's',
+        'ť' | 'ţ' | 'ț' => 't',
+        'ù' | 'ú' | 'û' | 'ü' | 'ū' | 'ů' | 'ű' | 'ų' => 'u',
+        'ý' | 'ÿ' => 'y',
+        'ź' | 'ż' | 'ž' => 'z',
+        _ => return None,
+    })
+}
+
+/// Rewrite a regex so each accented Latin letter in it also matches its
+/// unaccented form: "mañana" matches "manana". Escapes are copied as they
+/// are, and letters at either end of a class range (`[à-ÿ]`) are left alone.
+pub(crate) fn fold_diacritics(pattern: &str) -> String {
+    let chars: Vec<char> = pattern.chars().collect();
+    let mut folded = String::with_capacity(pattern.len());
+    let mut class_depth = 0usize;
+    let mut escaped = false;
+    for (i, &ch) in chars.iter().enumerate() {
+        if escaped {
+            escaped = false;
+            folded.push(ch);
+            continue;
+        }
+        match ch {
+            '\\' => escaped = true,
+            '[' => class_depth = class_depth.saturating_add(1),
+            ']' => class_depth = class_depth.saturating_sub(1),
+            _ => {}
+        }
+        let in_class = class_depth > 0;
+        let base = ch.to_lowercase().next().and_then(base_letter);
+        let in_range = in_class
+            && (chars.get(i.wrapping_sub(1)) == Some(&'-')
+                || chars.get(i.saturating_add(1)) == Some(&'-'));
+        match base {
+            Some(base) if !in_range => {
+                if !in_class {
+                    folded.push('[');
+                }
+                folded.push(ch);
+                folded.push(base);
+                if !in_class {
+                    folded.push(']');
+                }
+            }
+            _ => folded.push(ch),
+        }
+    }
+    folded
 }
 
 /// Create a dimension pattern item that matches any token of the given dimension.
@@ -22,3 +126,34 @@ where
 {
     PatternItem::Predicate(Box::new(f))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn regex_compiles_on_first_match() {
+        let PatternItem::Regex(re) = regex(r"\bfoo\b") else {
+            unreachable!()
+        };
+        assert_eq!(re.as_str(), r"(?i)\bfoo\b");
+        assert!(re.compiled.get().is_none());
+        assert!(re.is_match("FOO bar"));
+        assert!(re.compiled.get().is_some());
+    }
+
+    #[test]
+    fn folded_regex_matches_unaccented_text() {
+        assert_eq!(
+            fold_diacritics(r"ma(ñ|n)ana|über[ \-]?morgen|[[:alpha:]àé]\é|[à-ÿ]"),
+            r"ma([ñn]|n)ana|[üu]ber[ \-]?morgen|[[:alpha:]àaée]\é|[à-ÿ]"
+        );
+        let PatternItem::Regex(re) = regex(r"\bmañana\b") else {
+            unreachable!()
+        };
+        let folded = re.folded();
+        assert!(!re.is_match("manana"));
+        assert!(folded.is_match("Manana"));
+        assert!(folded.is_match("MAÑANA"));
+    }
+}
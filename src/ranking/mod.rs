@@ -5,7 +5,7 @@ use std::sync::OnceLock;
 use crate::dimensions::time::TimeForm;
 use crate::dimensions::time_grain::Grain;
 use crate::locale::{Lang, Locale};
-use crate::types::{DimensionKind, Entity, Node, ResolvedToken, TokenData};
+use crate::types::{DimensionKind, Entity, EntityMetadata, Node, ResolvedToken, TokenData};
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "train")]
@@ -110,7 +110,10 @@ struct Candidate {
 
 fn time_form_grain(form: &TimeForm) -> Option<Grain> {
     match form {
-        TimeForm::Year(_) => Some(Grain::Year),
+        TimeForm::Year(_)
+        | TimeForm::BuddhistEraYear { .. }
+        | TimeForm::Decade(_)
+        | TimeForm::Century(_) => Some(Grain::Year),
         TimeForm::Month(_) => Some(Grain::Month),
         TimeForm::Quarter(_) | TimeForm::QuarterYear(_, _) => Some(Grain::Quarter),
         TimeForm::DayOfWeek(_)
@@ -130,10 +133,12 @@ fn time_form_grain(form: &TimeForm) -> Option<Grain> {
         TimeForm::RelativeGrain { grain, .. } => Some(*grain),
         TimeForm::PartOfDay(_) => Some(Grain::Hour),
         TimeForm::GrainOffset { grain, .. } => Some(*grain),
-        TimeForm::NthGrain { grain, .. } => Some(*grain),
-        TimeForm::Now => Some(Grain::Second),
+        TimeForm::NthGrain { grain, .. } | TimeForm::Window { grain, .. } => Some(*grain),
+        TimeForm::Now | TimeForm::Timestamp(_) => Some(Grain::Second),
         TimeForm::Composed(a, b) => time_form_grain(&a.form).or_else(|| time_form_grain(&b.form)),
         TimeForm::Interval(_, _, _) => Some(Grain::Hour),
+        TimeForm::EndOfBusiness(_) => Some(Grain::Hour),
+        TimeForm::BusinessDays { .. } | TimeForm::Observed(_) => Some(Grain::Day),
         TimeForm::BeginEnd { target, .. } => time_form_grain(target),
         TimeForm::NthDOWOfTime { base, .. } => time_form_grain(&base.form),
         TimeForm::LastDOWOfTime { base, .. } => time_form_grain(&base.form),
@@ -143,8 +148,39 @@ fn time_form_grain(form: &TimeForm) -> Option<Grain> {
         TimeForm::NthGrainOfTime { base, .. } => time_form_grain(&base.form),
         TimeForm::NthLastDayOfTime { base, .. } => time_form_grain(&base.form),
         TimeForm::DurationAfter { grain, .. } => Some(*grain),
-        TimeForm::NthLastCycleOfTime { grain, .. } => Some(*grain),
+        TimeForm::NthLastCycleOfTime { grain, .. } | TimeForm::CycleNthAfter { grain, .. } => {
+            Some(*grain)
+        }
         TimeForm::AllGrain(g) | TimeForm::RestOfGrain(g) => Some(*g),
+        TimeForm::WeekOfYear { .. } => Some(Grain::Week),
+    }
+}
+
+/// Coarsest and finest grain among the parts of a time form.
+fn grain_span(form: &TimeForm) -> Option<(Grain, Grain)> {
+    match form {
+        TimeForm::Composed(a, b) => match (grain_span(&a.form), grain_span(&b.form)) {
+            (Some((ca, fa)), Some((cb, fb))) => Some((ca.max(cb), fa.min(fb))),
+            (a, b) => a.or(b),
+        },
+        _ => time_form_grain(form).map(|g| (g, g)),
+    }
+}
+
+/// Whether `node` composes a month, quarter or year directly with a clock
+/// time, leaving the day unsaid: "June" with "5 after 8pm" in "June 5 after
+/// 8pm", where "June 5" with "after 8pm" is the reading meant.
+fn skips_day(node: &Node) -> bool {
+    let TokenData::Time(t) = &node.token_data else {
+        return false;
+    };
+    let TimeForm::Composed(a, b) = &t.form else {
+        return false;
+    };
+    match (grain_span(&a.form), grain_span(&b.form)) {
+        (Some((_, fa)), Some((cb, _))) if fa >= Grain::Month && cb <= Grain::Hour => true,
+        (Some((ca, _)), Some((_, fb))) => fb >= Grain::Month && ca <= Grain::Hour,
+        _ => false,
     }
 }
 
@@ -237,8 +273,12 @@ fn compare_candidate(a: &Candidate, b: &Candidate) -> Ordering {
             .cmp(&b.resolved.node.range.start);
         let ends = a.resolved.node.range.end.cmp(&b.resolved.node.range.end);
         return match starts {
+            // Same span: a reading that fills in the day beats one that
+            // skips it, then the classifier decides
             Ordering::Equal => match ends {
-                Ordering::Equal => a.score.partial_cmp(&b.score).unwrap_or(Ordering::Equal),
+                Ordering::Equal => skips_day(&b.resolved.node)
+                    .cmp(&skips_day(&a.resolved.node))
+                    .then_with(|| a.score.partial_cmp(&b.score).unwrap_or(Ordering::Equal)),
                 z => z,
             },
             Ordering::Less => match ends {
@@ -315,10 +355,30 @@ fn classifiers_for_locale(locale: &Locale) -> &'static Classifiers {
     }
 }
 
+/// How candidates whose spans overlap are reconciled, selected with
+/// [`Options::overlap_strategy`](crate::Options::overlap_strategy).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverlapStrategy {
+    /// One winner per span: anything strictly inside a longer entity is
+    /// dropped, whatever its dimension.
+    #[default]
+    KeepLongest,
+    /// Among entities that overlap at all, keep the best-scoring one even
+    /// if it is shorter.
+    KeepHighestScore,
+    /// Keep every candidate, dropping only exact duplicates (same span and
+    /// value).
+    KeepAllNonIdentical,
+    /// Like `KeepLongest`, but only within a dimension, so e.g. a Time and a
+    /// Numeral may cover the same text.
+    PerDimension,
+}
+
 pub(crate) fn rank_resolved(
     tokens: Vec<ResolvedToken>,
     locale: &Locale,
     dims: &[DimensionKind],
+    strategy: OverlapStrategy,
 ) -> Vec<ResolvedToken> {
     let classifiers = classifiers_for_locale(locale);
     let candidates: Vec<Candidate> = tokens
@@ -335,10 +395,15 @@ pub(crate) fn rank_resolved(
 
     let winners: Vec<ResolvedToken> = candidates
         .iter()
-        .filter(|x| {
-            !candidates
+        .filter(|x| match strategy {
+            OverlapStrategy::KeepAllNonIdentical => true,
+            OverlapStrategy::PerDimension => !candidates.iter().any(|y| {
+                same_dimension(&x.resolved.node, &y.resolved.node)
+                    && compare_candidate(x, y) == Ordering::Less
+            }),
+            _ => !candidates
                 .iter()
-                .any(|y| compare_candidate(x, y) == Ordering::Less)
+                .any(|y| compare_candidate(x, y) == Ordering::Less),
         })
         .map(|c| c.resolved.clone())
         .collect();
@@ -378,21 +443,129 @@ pub fn remove_overlapping(entities: Vec<Entity>) -> Vec<Entity> {
     let mut result: Vec<Entity> = Vec::new();
 
     for entity in entities {
-        let dominated = result.iter().any(|existing| {
-            existing.start <= entity.start
-                && entity.end <= existing.end
-                && (existing.start < entity.start || entity.end < existing.end)
-        });
+        let dominated = result.iter().any(|existing| dominates(existing, &entity));
 
         if !dominated {
-            result.retain(|existing| {
-                !(entity.start <= existing.start
-                    && existing.end <= entity.end
-                    && (entity.start < existing.start || existing.end < entity.end))
-            });
+            result.retain(|existing| !dominates(&entity, existing));
             result.push(entity);
         }
     }
 
     result
 }
+
+/// Reconcile overlapping ranked tokens according to `strategy`.
+pub(crate) fn resolve_overlaps(
+    ranked: Vec<ResolvedToken>,
+    locale: &Locale,
+    strategy: OverlapStrategy,
+) -> Vec<Entity> {
+    match strategy {
+        OverlapStrategy::KeepLongest => {
+            remove_overlapping(ranked.into_iter().map(|rt| rt.entity).collect())
+        }
+        OverlapStrategy::KeepHighestScore => {
+            let classifiers = classifiers_for_locale(locale);
+            let mut scored: Vec<(f64, Entity)> = ranked
+                .into_iter()
+                .map(|rt| (score_node(classifiers, &rt.node), rt.entity))
+                .collect();
+            scored.sort_by(|(sa, a), (sb, b)| {
+                sb.partial_cmp(sa)
+                    .unwrap_or(Ordering::Equal)
+                    .then_with(|| {
+                        (b.end.saturating_sub(b.start)).cmp(&a.end.saturating_sub(a.start))
+                    })
+                    .then_with(|| a.start.cmp(&b.start))
+            });
+            let mut kept: Vec<Entity> = Vec::new();
+            for (_, entity) in scored {
+                if !kept
+                    .iter()
+                    .any(|k| k.start < entity.end && entity.start < k.end)
+                {
+                    kept.push(entity);
+                }
+            }
+            kept.sort_by(|a, b| a.start.cmp(&b.start).then_with(|| a.end.cmp(&b.end)));
+            kept
+        }
+        OverlapStrategy::KeepAllNonIdentical => {
+            let mut kept: Vec<Entity> = Vec::new();
+            for rt in ranked {
+                let entity = rt.entity;
+                if !kept.iter().any(|k| {
+                    k.start == entity.start && k.end == entity.end && k.value == entity.value
+                }) {
+                    kept.push(entity);
+                }
+            }
+            kept
+        }
+        OverlapStrategy::PerDimension => {
+            let mut by_dim: Vec<(Option<DimensionKind>, Vec<Entity>)> = Vec::new();
+            for rt in ranked {
+                let dim = rt.node.token_data.dimension_kind();
+                match by_dim.iter_mut().find(|(d, _)| *d == dim) {
+                    Some((_, group)) => group.push(rt.entity),
+                    None => by_dim.push((dim, vec![rt.entity])),
+                }
+            }
+            let mut kept: Vec<Entity> = by_dim
+                .into_iter()
+                .flat_map(|(_, group)| remove_overlapping(group))
+                .collect();
+            kept.sort_by(|a, b| a.start.cmp(&b.start).then_with(|| a.end.cmp(&b.end)));
+            kept
+        }
+    }
+}
+
+/// Put entities in output order: by start, then dimension priority (the
+/// declaration order of [`DimensionKind`]), then end. The sort is stable, so
+/// entities equal on all three keep their ranked order.
+pub(crate) fn sort_entities(entities: &mut [Entity]) {
+    entities.sort_by_key(|e| (e.start, e.value.dim_kind() as usize, e.end));
+}
+
+/// File each entity ranking discarded under the first kept entity whose span
+/// covers it, skipping ones already recorded there.
+pub(crate) fn attach_alternates(kept: &mut [Entity], discarded: Vec<Entity>) {
+    for loser in discarded {
+        let Some(winner) = kept
+            .iter_mut()
+            .find(|e| e.start <= loser.start && loser.end <= e.end)
+        else {
+            continue;
+        };
+        let duplicate =
+            |e: &Entity| e.start == loser.start && e.end == loser.end && e.value == loser.value;
+        if duplicate(winner)
+            || winner
+                .metadata
+                .as_ref()
+                .is_some_and(|m| m.alternates.iter().any(duplicate))
+        {
+            continue;
+        }
+        push_alternate(winner, loser);
+    }
+}
+
+/// Whether `a`'s span strictly contains `b`'s.
+fn dominates(a: &Entity, b: &Entity) -> bool {
+    a.start <= b.start && b.end <= a.end && (a.start < b.start || b.end < a.end)
+}
+
+fn push_alternate(winner: &mut Entity, loser: Entity) {
+    winner
+        .metadata
+        .get_or_insert_with(|| EntityMetadata {
+            rule: None,
+            source: None,
+            alternates: Vec::new(),
+            time_expr: None,
+        })
+        .alternates
+        .push(loser);
+}
@@ -1,9 +1,18 @@
 use crate::dimensions;
-use crate::locale::Locale;
-use crate::types::{DimensionValue, Entity, Node, TokenData};
-use chrono::{DateTime, Utc};
+use crate::dimensions::temperature::DegreePolicy;
+use crate::locale::{
+    DecimalSeparator, Hemisphere, Lang, Locale, NextWeekdayPolicy, ObservedShift, Region,
+};
+use crate::ranking::OverlapStrategy;
+use crate::types::{DimensionValue, Entity, Node, TimeExpr, TokenData};
+use chrono::{DateTime, Datelike, NaiveDate, Utc, Weekday};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::panic::RefUnwindSafe;
 #[cfg(not(debug_assertions))]
 use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::Arc;
+use std::time::Duration;
 
 /// Context for resolving parsed tokens into structured values.
 #[derive(Debug, Clone)]
@@ -14,6 +23,13 @@ pub struct Context {
     pub locale: Locale,
     /// Context timezone offset in minutes from UTC (e.g., -120 for UTC-2)
     pub timezone_offset_minutes: i32,
+    /// Extra non-working days skipped by business-day arithmetic
+    /// ("in 3 business days"), on top of weekends.
+    pub holiday_calendar: HolidayCalendar,
+    /// Dates for named holidays ("independence day", "labour day"), consulted
+    /// before the locale region's [`RegionalHolidays`](crate::RegionalHolidays)
+    /// and the built-in dates.
+    pub holiday_resolver: Option<Arc<dyn HolidayResolver>>,
 }
 
 impl Default for Context {
@@ -22,15 +38,486 @@ impl Default for Context {
             reference_time: Utc::now(),
             locale: Locale::default(),
             timezone_offset_minutes: 0,
+            holiday_calendar: HolidayCalendar::default(),
+            holiday_resolver: None,
         }
     }
 }
 
+/// A set of dates treated as non-working days.
+///
+/// # Example
+/// ```
+/// use chrono::NaiveDate;
+/// use duckling::HolidayCalendar;
+///
+/// let christmas = NaiveDate::from_ymd_opt(2013, 12, 25).unwrap();
+/// let calendar: HolidayCalendar = [christmas].into_iter().collect();
+/// assert!(!calendar.is_business_day(christmas));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HolidayCalendar {
+    dates: BTreeSet<NaiveDate>,
+}
+
+impl HolidayCalendar {
+    /// Create an empty calendar; only weekends are non-working days.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Mark `date` as a holiday.
+    pub fn add(&mut self, date: NaiveDate) {
+        self.dates.insert(date);
+    }
+
+    /// Whether `date` was added as a holiday.
+    pub fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.dates.contains(&date)
+    }
+
+    /// Whether `date` is a weekday that is not a holiday.
+    pub fn is_business_day(&self, date: NaiveDate) -> bool {
+        !matches!(date.weekday(), Weekday::Sat | Weekday::Sun) && !self.is_holiday(date)
+    }
+}
+
+impl FromIterator<NaiveDate> for HolidayCalendar {
+    fn from_iter<I: IntoIterator<Item = NaiveDate>>(iter: I) -> Self {
+        HolidayCalendar {
+            dates: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// Looks up the date a named holiday falls on, so regions and callers can
+/// supply their own days.
+///
+/// `name` is the holiday as matched, lowercased with single spaces
+/// ("independence day"). Returning `None` defers to the next resolver and
+/// finally to the built-in dates.
+pub trait HolidayResolver: fmt::Debug + Send + Sync + RefUnwindSafe {
+    /// The date of `name` in `year` for `region`, if this resolver knows it.
+    fn resolve(&self, name: &str, year: i32, region: Option<Region>) -> Option<NaiveDate>;
+}
+
+/// Caller-defined holiday dates, e.g. company days off, for any region.
+///
+/// Only names the grammar already recognizes as holidays are looked up.
+///
+/// # Example
+/// ```
+/// use chrono::NaiveDate;
+/// use duckling::{CustomHolidays, HolidayResolver};
+///
+/// let mut holidays = CustomHolidays::new();
+/// holidays.add_yearly("Labour Day", 5, 1);
+/// assert_eq!(
+///     holidays.resolve("labour day", 2013, None),
+///     NaiveDate::from_ymd_opt(2013, 5, 1)
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CustomHolidays {
+    yearly: BTreeMap<String, (u32, u32)>,
+    dated: BTreeMap<(String, i32), NaiveDate>,
+}
+
+impl CustomHolidays {
+    /// Create an empty set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Observe `name` on `month`/`day` every year.
+    pub fn add_yearly(&mut self, name: &str, month: u32, day: u32) {
+        self.yearly.insert(holiday_key(name), (month, day));
+    }
+
+    /// Observe `name` on `date` in that date's year, overriding any yearly day.
+    pub fn add(&mut self, name: &str, date: NaiveDate) {
+        self.dated.insert((holiday_key(name), date.year()), date);
+    }
+}
+
+impl HolidayResolver for CustomHolidays {
+    fn resolve(&self, name: &str, year: i32, _region: Option<Region>) -> Option<NaiveDate> {
+        let key = holiday_key(name);
+        if let Some(date) = self.dated.get(&(key.clone(), year)) {
+            return Some(*date);
+        }
+        let (month, day) = self.yearly.get(&key)?;
+        NaiveDate::from_ymd_opt(year, *month, *day)
+    }
+}
+
+/// Lowercase `name` and collapse its whitespace, as resolvers receive it.
+pub(crate) fn holiday_key(name: &str) -> String {
+    name.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// Hour ranges for parts of the day ("this morning", "evening at 6",
+/// "dinner"), which vary by product and deployment.
+///
+/// Each range is `(start, end)` in hours of the day, end exclusive; an end
+/// past 24 runs into the following day. The ranges set the intervals for
+/// standalone parts of the day and pick AM or PM for a clock time composed
+/// with one ("evening at 6" → 18:00). "early" keeps all but the last three
+/// hours of a range and "late" keeps only the last three.
+///
+/// # Example
+/// ```
+/// use duckling::PartOfDayConfig;
+///
+/// let config = PartOfDayConfig {
+///     evening: (17, 21),
+///     ..PartOfDayConfig::default()
+/// };
+/// assert_eq!(config.morning, (0, 12));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PartOfDayConfig {
+    /// "morning". Defaults to 00:00–12:00.
+    pub morning: (u32, u32),
+    /// "afternoon". Defaults to 12:00–19:00.
+    pub afternoon: (u32, u32),
+    /// "evening", "tonight". Defaults to 18:00–24:00.
+    pub evening: (u32, u32),
+    /// "night". Defaults to 18:00–24:00.
+    pub night: (u32, u32),
+    /// "breakfast". Defaults to 06:00–10:00.
+    pub breakfast: (u32, u32),
+    /// "lunch", "at lunch". Defaults to 12:00–14:00.
+    pub lunch: (u32, u32),
+    /// "dinner", "supper". Defaults to 18:00–21:00.
+    pub dinner: (u32, u32),
+}
+
+impl Default for PartOfDayConfig {
+    fn default() -> Self {
+        PartOfDayConfig {
+            morning: (0, 12),
+            afternoon: (12, 19),
+            evening: (18, 24),
+            night: (18, 24),
+            breakfast: (6, 10),
+            lunch: (12, 14),
+            dinner: (18, 21),
+        }
+    }
+}
+
+/// Which occurrence a time without an explicit direction resolves to, e.g.
+/// "Friday" or "March 3", selected with [`Options::prefer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimePreference {
+    /// The next occurrence: "Friday" is the coming Friday.
+    #[default]
+    Future,
+    /// The most recent occurrence, for historical text: "the incident
+    /// happened Friday" is the last Friday.
+    Past,
+    /// Whichever of the next and most recent occurrences is closer to the
+    /// reference time, preferring the future on a tie.
+    Nearest,
+}
+
+/// How week numbers ("week 23", "the 12th week of 2025") count the weeks of
+/// a year, selected with [`Options::week_numbering`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+pub enum WeekNumbering {
+    /// ISO 8601: weeks run Monday to Sunday, and week 1 is the week holding
+    /// the year's first Thursday.
+    #[default]
+    Iso,
+    /// US: weeks run Sunday to Saturday, and week 1 is the week holding
+    /// January 1.
+    Us,
+}
+
 /// Options for controlling parsing behavior.
-#[derive(Debug, Clone, Default)]
+///
+/// New options may be added in any release, so outside this crate an
+/// `Options` is built with [`Options::builder`], or taken from
+/// [`Options::default`] and assigned to field by field.
+///
+/// # Example
+/// ```
+/// use duckling::{Options, TimePreference};
+///
+/// let options = Options::builder()
+///     .with_latent(true)
+///     .prefer(TimePreference::Past)
+///     .business_day_end_hour(18)
+///     .build();
+/// assert!(options.with_latent);
+/// assert_eq!(options.business_day_end_hour, Some(18));
+/// ```
+#[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct Options {
     /// Whether to include latent (ambiguous) matches in results.
     pub with_latent: bool,
+    /// Hour (0-23) at which business shorthand like "COB" or "by EOD Friday"
+    /// ends the day. `None` (or an out-of-range hour) uses midnight at the end
+    /// of the calendar day instead. Defaults to 17.
+    pub business_day_end_hour: Option<u32>,
+    /// Decimal separator for digit strings like "1.234,56", overriding
+    /// [`Locale::decimal_separator`]. Defaults to `None`.
+    pub decimal_separator: Option<DecimalSeparator>,
+    /// Attach [`EntityMetadata`](crate::EntityMetadata) (rule provenance and
+    /// suppressed alternates) to each entity. Defaults to `false`.
+    pub with_metadata: bool,
+    /// How overlapping candidates are reconciled. Defaults to
+    /// [`OverlapStrategy::KeepLongest`].
+    pub overlap_strategy: OverlapStrategy,
+    /// Hour ranges for parts of the day. Defaults to
+    /// [`PartOfDayConfig::default`].
+    pub part_of_day: PartOfDayConfig,
+    /// Hemisphere for season words ("this summer"), overriding
+    /// [`Locale::hemisphere`]. Defaults to `None`.
+    pub hemisphere: Option<Hemisphere>,
+    /// How "(observed)" holidays move off weekends, overriding
+    /// [`Locale::observed_shift`]. Defaults to `None`.
+    pub observed_shift: Option<ObservedShift>,
+    /// Whether "next Friday" is the coming Friday or Friday of next week,
+    /// overriding [`Locale::next_weekday_policy`]. Defaults to `None`.
+    pub next_weekday_policy: Option<NextWeekdayPolicy>,
+    /// Read 10-digit Unix timestamps and 13-digit epoch milliseconds as time
+    /// instants, from 2000 to five years past the reference time. Off by
+    /// default, as such digit runs are more often ids.
+    pub parse_epoch_timestamps: bool,
+    /// Read years written without an era in Thai, Lao and Khmer dates
+    /// ("13 เมษายน 2568") as Buddhist-era years, converted to the common
+    /// era. Years marked with an era ("พ.ศ. 2568", "ค.ศ. 2025") are read
+    /// as marked either way. Defaults to `true`.
+    pub buddhist_era_years: bool,
+    /// How temperatures in bare degrees ("3 degrees outside") are read.
+    /// Defaults to [`DegreePolicy::Unspecified`], which keeps the unit
+    /// [`TemperatureUnit::Degree`](crate::TemperatureUnit::Degree).
+    pub degree_policy: DegreePolicy,
+    /// How many grains "few" counts in windows like "over the next few
+    /// days". Defaults to 3.
+    pub few_count: u32,
+    /// How week numbers ("week 23") are counted. "ISO week 7" is always read
+    /// as an ISO week. Defaults to [`WeekNumbering::Iso`].
+    pub week_numbering: WeekNumbering,
+    /// Which occurrence times like "Friday" resolve to when the text gives no
+    /// direction ("next", "last"). Defaults to [`TimePreference::Future`].
+    pub prefer: TimePreference,
+    /// Fill in [`TimeValue::Single`](crate::TimeValue::Single)'s
+    /// `meridiem_candidates` with both readings of a clock time given without
+    /// AM/PM ("at 7"), so callers can ask which was meant. Defaults to `false`.
+    pub with_meridiem_candidates: bool,
+    /// Languages whose patterns also match words typed without their
+    /// accents, e.g. "ano" for Spanish "año" or "nachste" for German
+    /// "nächste". Patterns already ignore case. Defaults to none, as folding can
+    /// let a pattern match an unrelated unaccented word.
+    pub fold_diacritics: Vec<Lang>,
+    /// Read month and weekday names, relative days ("tomorow") and currency
+    /// words ("dollers") with one typo as the word meant. Only English has
+    /// such a word list so far. Defaults to `false`.
+    pub fuzzy_matching: bool,
+    /// Fold full-width characters ("１２３"), no-break spaces, curly
+    /// apostrophes and letters written with combining accents to the forms
+    /// patterns are written in before matching. Entities keep their offsets
+    /// and bodies in the original text. Defaults to `true`.
+    pub normalize_unicode: bool,
+    /// Languages whose rules are loaded alongside the locale's, for text that
+    /// mixes them: with German added to an English locale, "3rd of März"
+    /// reads the English ordinal with the German month. Digit and symbol
+    /// rules are shared, so they compose with every pack. Defaults to none.
+    pub extra_langs: Vec<Lang>,
+    /// Longest text, in bytes, that is parsed; anything after it is ignored.
+    /// Defaults to `None` (no limit).
+    pub max_input_len: Option<usize>,
+    /// Wall-clock time a parse may take. Once spent, parsing stops and
+    /// returns what it resolved so far. Defaults to `None` (no limit).
+    pub time_budget: Option<Duration>,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            with_latent: false,
+            business_day_end_hour: Some(17),
+            decimal_separator: None,
+            with_metadata: false,
+            overlap_strategy: OverlapStrategy::KeepLongest,
+            part_of_day: PartOfDayConfig::default(),
+            hemisphere: None,
+            observed_shift: None,
+            next_weekday_policy: None,
+            parse_epoch_timestamps: false,
+            buddhist_era_years: true,
+            degree_policy: DegreePolicy::Unspecified,
+            few_count: 3,
+            week_numbering: WeekNumbering::Iso,
+            prefer: TimePreference::Future,
+            with_meridiem_candidates: false,
+            fold_diacritics: Vec::new(),
+            fuzzy_matching: false,
+            normalize_unicode: true,
+            extra_langs: Vec::new(),
+            max_input_len: None,
+            time_budget: None,
+        }
+    }
+}
+
+impl Options {
+    /// A builder starting from [`Options::default`].
+    pub fn builder() -> OptionsBuilder {
+        OptionsBuilder::default()
+    }
+}
+
+/// Builds [`Options`]; each method sets the option of the same name.
+#[derive(Debug, Clone, Default)]
+pub struct OptionsBuilder {
+    options: Options,
+}
+
+impl OptionsBuilder {
+    /// Sets [`Options::with_latent`].
+    pub fn with_latent(mut self, with_latent: bool) -> Self {
+        self.options.with_latent = with_latent;
+        self
+    }
+
+    /// Sets [`Options::business_day_end_hour`].
+    pub fn business_day_end_hour(mut self, hour: impl Into<Option<u32>>) -> Self {
+        self.options.business_day_end_hour = hour.into();
+        self
+    }
+
+    /// Sets [`Options::decimal_separator`].
+    pub fn decimal_separator(mut self, separator: impl Into<Option<DecimalSeparator>>) -> Self {
+        self.options.decimal_separator = separator.into();
+        self
+    }
+
+    /// Sets [`Options::with_metadata`].
+    pub fn with_metadata(mut self, with_metadata: bool) -> Self {
+        self.options.with_metadata = with_metadata;
+        self
+    }
+
+    /// Sets [`Options::overlap_strategy`].
+    pub fn overlap_strategy(mut self, strategy: OverlapStrategy) -> Self {
+        self.options.overlap_strategy = strategy;
+        self
+    }
+
+    /// Sets [`Options::part_of_day`].
+    pub fn part_of_day(mut self, config: PartOfDayConfig) -> Self {
+        self.options.part_of_day = config;
+        self
+    }
+
+    /// Sets [`Options::hemisphere`].
+    pub fn hemisphere(mut self, hemisphere: impl Into<Option<Hemisphere>>) -> Self {
+        self.options.hemisphere = hemisphere.into();
+        self
+    }
+
+    /// Sets [`Options::observed_shift`].
+    pub fn observed_shift(mut self, shift: impl Into<Option<ObservedShift>>) -> Self {
+        self.options.observed_shift = shift.into();
+        self
+    }
+
+    /// Sets [`Options::next_weekday_policy`].
+    pub fn next_weekday_policy(mut self, policy: impl Into<Option<NextWeekdayPolicy>>) -> Self {
+        self.options.next_weekday_policy = policy.into();
+        self
+    }
+
+    /// Sets [`Options::parse_epoch_timestamps`].
+    pub fn parse_epoch_timestamps(mut self, parse: bool) -> Self {
+        self.options.parse_epoch_timestamps = parse;
+        self
+    }
+
+    /// Sets [`Options::buddhist_era_years`].
+    pub fn buddhist_era_years(mut self, buddhist_era: bool) -> Self {
+        self.options.buddhist_era_years = buddhist_era;
+        self
+    }
+
+    /// Sets [`Options::degree_policy`].
+    pub fn degree_policy(mut self, policy: DegreePolicy) -> Self {
+        self.options.degree_policy = policy;
+        self
+    }
+
+    /// Sets [`Options::few_count`].
+    pub fn few_count(mut self, count: u32) -> Self {
+        self.options.few_count = count;
+        self
+    }
+
+    /// Sets [`Options::week_numbering`].
+    pub fn week_numbering(mut self, numbering: WeekNumbering) -> Self {
+        self.options.week_numbering = numbering;
+        self
+    }
+
+    /// Sets [`Options::prefer`].
+    pub fn prefer(mut self, preference: TimePreference) -> Self {
+        self.options.prefer = preference;
+        self
+    }
+
+    /// Sets [`Options::with_meridiem_candidates`].
+    pub fn with_meridiem_candidates(mut self, with_candidates: bool) -> Self {
+        self.options.with_meridiem_candidates = with_candidates;
+        self
+    }
+
+    /// Sets [`Options::fold_diacritics`].
+    pub fn fold_diacritics(mut self, langs: impl IntoIterator<Item = Lang>) -> Self {
+        self.options.fold_diacritics = langs.into_iter().collect();
+        self
+    }
+
+    /// Sets [`Options::fuzzy_matching`].
+    pub fn fuzzy_matching(mut self, fuzzy: bool) -> Self {
+        self.options.fuzzy_matching = fuzzy;
+        self
+    }
+
+    /// Sets [`Options::normalize_unicode`].
+    pub fn normalize_unicode(mut self, normalize: bool) -> Self {
+        self.options.normalize_unicode = normalize;
+        self
+    }
+
+    /// Sets [`Options::extra_langs`].
+    pub fn extra_langs(mut self, langs: impl IntoIterator<Item = Lang>) -> Self {
+        self.options.extra_langs = langs.into_iter().collect();
+        self
+    }
+
+    /// Sets [`Options::max_input_len`].
+    pub fn max_input_len(mut self, len: impl Into<Option<usize>>) -> Self {
+        self.options.max_input_len = len.into();
+        self
+    }
+
+    /// Sets [`Options::time_budget`].
+    pub fn time_budget(mut self, budget: impl Into<Option<Duration>>) -> Self {
+        self.options.time_budget = budget.into();
+        self
+    }
+
+    /// The options set so far.
+    pub fn build(self) -> Options {
+        self.options
+    }
 }
 
 /// Resolve a node into a structured entity.
@@ -43,11 +530,25 @@ pub fn resolve(node: &Node, context: &Context, options: &Options, text: &str) ->
         body,
         start: node.range.start,
         end: node.range.end,
+        grain: resolved.grain(),
+        precision: node.token_data.precision(),
+        components: node.token_data.components(),
         value: resolved,
         latent: Some(latent),
+        metadata: None,
     })
 }
 
+/// Resolve a stored [`TimeExpr`] against `context`, as if its text had been
+/// parsed again with that reference time; `None` when it has no value then.
+pub fn resolve_entity(
+    expr: &TimeExpr,
+    context: &Context,
+    options: &Options,
+) -> Option<DimensionValue> {
+    resolve_token(&TokenData::Time(expr.0.clone()), context, options)
+}
+
 fn resolve_token(
     token: &TokenData,
     context: &Context,
@@ -58,22 +559,37 @@ fn resolve_token(
         match token {
             TokenData::Numeral(data) => Some(dimensions::numeral::resolve(data)),
             TokenData::Ordinal(data) => Some(dimensions::ordinal::resolve(data)),
-            TokenData::Temperature(data) => dimensions::temperature::resolve(data),
+            TokenData::Temperature(data) => {
+                dimensions::temperature::resolve(data, options.degree_policy.unit(&context.locale))
+            }
             TokenData::Distance(data) => dimensions::distance::resolve(data),
             TokenData::Volume(data) => dimensions::volume::resolve(data),
             TokenData::Quantity(data) => dimensions::quantity::resolve(data),
-            TokenData::AmountOfMoney(data) => {
-                dimensions::amount_of_money::resolve(data, options.with_latent)
+            TokenData::Age(data) => dimensions::age::resolve(data),
+            TokenData::AmountOfMoney(data) => dimensions::amount_of_money::resolve(
+                data,
+                options.with_latent,
+                context.locale.region,
+            ),
+            TokenData::Percentage(data) => Some(dimensions::percentage::resolve(data)),
+            TokenData::Rate(data) => Some(dimensions::rate::resolve(data)),
+            #[cfg(feature = "medical")]
+            TokenData::BloodPressure(data) => {
+                Some(dimensions::medical::resolve_blood_pressure(data))
             }
+            #[cfg(feature = "medical")]
+            TokenData::Dosage(data) => Some(dimensions::medical::resolve_dosage(data)),
             TokenData::Email(data) => Some(dimensions::email::resolve(data)),
             TokenData::PhoneNumber(data) => Some(dimensions::phone_number::resolve(data)),
             TokenData::Url(data) => Some(dimensions::url::resolve(data)),
+            TokenData::NetworkAddress(data) => Some(dimensions::network_address::resolve(data)),
+            TokenData::Coordinate(data) => Some(dimensions::coordinate::resolve(data)),
             TokenData::CreditCardNumber(data) => {
                 Some(dimensions::credit_card_number::resolve(data))
             }
             TokenData::TimeGrain(grain) => Some(dimensions::time_grain::resolve(grain)),
             TokenData::Duration(data) => Some(dimensions::duration::resolve(data)),
-            TokenData::Time(data) => dimensions::time::resolve(data, context, options.with_latent),
+            TokenData::Time(data) => dimensions::time::resolve(data, context, options),
             TokenData::RegexMatch(_) => None,
         }
     }
@@ -83,22 +599,37 @@ fn resolve_token(
         catch_unwind(AssertUnwindSafe(|| match token {
             TokenData::Numeral(data) => Some(dimensions::numeral::resolve(data)),
             TokenData::Ordinal(data) => Some(dimensions::ordinal::resolve(data)),
-            TokenData::Temperature(data) => dimensions::temperature::resolve(data),
+            TokenData::Temperature(data) => {
+                dimensions::temperature::resolve(data, options.degree_policy.unit(&context.locale))
+            }
             TokenData::Distance(data) => dimensions::distance::resolve(data),
             TokenData::Volume(data) => dimensions::volume::resolve(data),
             TokenData::Quantity(data) => dimensions::quantity::resolve(data),
-            TokenData::AmountOfMoney(data) => {
-                dimensions::amount_of_money::resolve(data, options.with_latent)
+            TokenData::Age(data) => dimensions::age::resolve(data),
+            TokenData::AmountOfMoney(data) => dimensions::amount_of_money::resolve(
+                data,
+                options.with_latent,
+                context.locale.region,
+            ),
+            TokenData::Percentage(data) => Some(dimensions::percentage::resolve(data)),
+            TokenData::Rate(data) => Some(dimensions::rate::resolve(data)),
+            #[cfg(feature = "medical")]
+            TokenData::BloodPressure(data) => {
+                Some(dimensions::medical::resolve_blood_pressure(data))
             }
+            #[cfg(feature = "medical")]
+            TokenData::Dosage(data) => Some(dimensions::medical::resolve_dosage(data)),
             TokenData::Email(data) => Some(dimensions::email::resolve(data)),
             TokenData::PhoneNumber(data) => Some(dimensions::phone_number::resolve(data)),
             TokenData::Url(data) => Some(dimensions::url::resolve(data)),
+            TokenData::NetworkAddress(data) => Some(dimensions::network_address::resolve(data)),
+            TokenData::Coordinate(data) => Some(dimensions::coordinate::resolve(data)),
             TokenData::CreditCardNumber(data) => {
                 Some(dimensions::credit_card_number::resolve(data))
             }
             TokenData::TimeGrain(grain) => Some(dimensions::time_grain::resolve(grain)),
             TokenData::Duration(data) => Some(dimensions::duration::resolve(data)),
-            TokenData::Time(data) => dimensions::time::resolve(data, context, options.with_latent),
+            TokenData::Time(data) => dimensions::time::resolve(data, context, options),
             TokenData::RegexMatch(_) => None,
         }))
         .ok()
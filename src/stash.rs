@@ -1,11 +1,13 @@
 use crate::types::Node;
 use std::collections::BTreeMap;
+use std::rc::Rc;
 
 /// A Stash stores parsed nodes keyed by their start position.
 /// This allows efficient lookup of nodes at a given position.
+/// Nodes are shared, so using one as a child of a new node doesn't copy it.
 #[derive(Debug, Clone, Default)]
 pub struct Stash {
-    nodes: BTreeMap<usize, Vec<Node>>,
+    nodes: BTreeMap<usize, Vec<Rc<Node>>>,
     count: usize,
 }
 
@@ -18,11 +20,15 @@ impl Stash {
     }
 
     pub fn add(&mut self, node: Node) {
+        self.add_shared(Rc::new(node));
+    }
+
+    pub fn add_shared(&mut self, node: Rc<Node>) {
         self.nodes.entry(node.range.start).or_default().push(node);
         self.count = self.count.saturating_add(1);
     }
 
-    pub fn all_nodes(&self) -> impl Iterator<Item = &Node> {
+    pub fn all_nodes(&self) -> impl Iterator<Item = &Rc<Node>> {
         self.nodes.values().flat_map(|v| v.iter())
     }
 
@@ -35,22 +41,19 @@ impl Stash {
     }
 
     pub fn merge_from(&mut self, other: Stash) {
-        for (_pos, nodes) in other.nodes {
-            for node in nodes {
-                self.nodes.entry(node.range.start).or_default().push(node);
-                self.count = self.count.saturating_add(1);
-            }
+        for node in other.into_nodes() {
+            self.add_shared(node);
         }
     }
 
-    /// Consume the stash and return an iterator over all owned nodes.
-    pub fn into_nodes(self) -> impl Iterator<Item = Node> {
+    /// Consume the stash and return an iterator over all nodes.
+    pub fn into_nodes(self) -> impl Iterator<Item = Rc<Node>> {
         self.nodes.into_values().flat_map(|v| v.into_iter())
     }
 
     /// Iterate over nodes starting at or after the given position.
     /// Uses BTreeMap's range for efficient lookup.
-    pub fn nodes_starting_from(&self, pos: usize) -> impl Iterator<Item = &Node> {
+    pub fn nodes_starting_from(&self, pos: usize) -> impl Iterator<Item = &Rc<Node>> {
         self.nodes.range(pos..).flat_map(|(_, v)| v.iter())
     }
 }
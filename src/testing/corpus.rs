@@ -0,0 +1,339 @@
+// Per-language corpus registry and runner, ported from Duckling/Testing/Asserts.hs.
+// Every (language, dimension) pair registers a Corpus: a context plus groups of
+// texts that should all resolve to the same expected value on an entity spanning
+// the whole text, and negative texts that must not produce any entity for that
+// dimension.
+
+mod time;
+
+use super::{check_corpus, Corpus};
+use crate::lang;
+use crate::locale::{Lang, Locale, Region};
+use crate::resolve::Context;
+use crate::types::{DimensionKind, DimensionValue, Entity, MeasurementValue, TimePoint, TimeValue};
+use crate::{AgeUnit, Grain};
+use chrono::{NaiveDate, TimeZone, Utc};
+use std::collections::BTreeMap;
+
+/// A corpus registered for a single (locale, dimension) pair.
+pub struct LangCorpus {
+    pub dim: DimensionKind,
+    pub corpus: Corpus,
+}
+
+/// Outcome of running one registered corpus.
+pub struct CorpusReport {
+    pub locale: Locale,
+    pub dim: DimensionKind,
+    pub total: usize,
+    pub failures: Vec<String>,
+}
+
+impl CorpusReport {
+    pub fn passed(&self) -> usize {
+        self.total.saturating_sub(self.failures.len())
+    }
+}
+
+/// Reference context shared by the Haskell corpora: 2013-02-12 04:30:00 UTC-2.
+pub fn test_context(lang: Lang, region: Option<Region>) -> Context {
+    Context {
+        reference_time: Utc.with_ymd_and_hms(2013, 2, 12, 4, 30, 0).unwrap(),
+        locale: Locale::new(lang, region),
+        timezone_offset_minutes: -120,
+        ..Context::default()
+    }
+}
+
+/// Check that an entity resolves to exactly `expected`.
+pub fn value(expected: DimensionValue) -> impl Fn(&Entity) -> bool {
+    move |e: &Entity| e.value == expected
+}
+
+/// Check that an entity is a numeral within 0.01 of `expected`.
+pub fn numeral(expected: f64) -> impl Fn(&Entity) -> bool {
+    move |e: &Entity| matches!(&e.value, DimensionValue::Numeral(v) if (*v - expected).abs() < 0.01)
+}
+
+/// Check that an entity is a duration of `value` `grain`s.
+pub fn duration(value: i64, grain: Grain) -> impl Fn(&Entity) -> bool {
+    move |e: &Entity| matches!(&e.value, DimensionValue::Duration { value: v, grain: g, .. } if *v == value && *g == grain)
+}
+
+/// Port of Haskell's `datetime` predicate for single time values.
+pub fn datetime(y: i32, m: u32, d: u32, h: u32, mi: u32, grain: Grain) -> impl Fn(&Entity) -> bool {
+    let expected = NaiveDate::from_ymd_opt(y, m, d)
+        .and_then(|date| date.and_hms_opt(h, mi, 0))
+        .expect("valid corpus datetime");
+    move |e: &Entity| match &e.value {
+        DimensionValue::Time(TimeValue::Single { value, .. }) => match value {
+            TimePoint::Naive { value, grain: g } => *value == expected && *g == grain,
+            TimePoint::Instant { value, grain: g } => value.naive_utc() == expected && *g == grain,
+        },
+        _ => false,
+    }
+}
+
+/// Port of Haskell's `datetimeInterval` predicate for closed time intervals.
+pub fn datetime_interval(
+    from: (i32, u32, u32, u32, u32),
+    to: (i32, u32, u32, u32, u32),
+    grain: Grain,
+) -> impl Fn(&Entity) -> bool {
+    let naive = |(y, m, d, h, mi): (i32, u32, u32, u32, u32)| {
+        NaiveDate::from_ymd_opt(y, m, d)
+            .and_then(|date| date.and_hms_opt(h, mi, 0))
+            .expect("valid corpus datetime")
+    };
+    let (from, to) = (naive(from), naive(to));
+    let point = |p: &TimePoint| match p {
+        TimePoint::Naive { value, grain } => (*value, *grain),
+        TimePoint::Instant { value, grain } => (value.naive_utc(), *grain),
+    };
+    move |e: &Entity| match &e.value {
+        DimensionValue::Time(TimeValue::Interval {
+            from: Some(f),
+            to: Some(t),
+            ..
+        }) => point(f) == (from, grain) && point(t).0 == to,
+        _ => false,
+    }
+}
+
+fn numeral_en() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::EN, None));
+    corpus.add(vec!["0", "zero", "naught"], numeral(0.0));
+    corpus.add(vec!["1", "one", "single"], numeral(1.0));
+    corpus.add(vec!["33", "thirty three", "0033"], numeral(33.0));
+    corpus.add(vec!["100K", "100k", "100 thousand"], numeral(100_000.0));
+    corpus.add(vec!["1.1", "1.10", "01.10"], numeral(1.1));
+    corpus.add(vec!["-1200000", "minus 1,200,000"], numeral(-1_200_000.0));
+    LangCorpus {
+        dim: DimensionKind::Numeral,
+        corpus,
+    }
+}
+
+fn numeral_es() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::ES, None));
+    corpus.add(vec!["1", "uno", "una"], numeral(1.0));
+    corpus.add(vec!["11", "once"], numeral(11.0));
+    corpus.add(vec!["16", "dieciséis", "dieciseis"], numeral(16.0));
+    corpus.add(vec!["23", "veintitrés", "veinte y tres"], numeral(23.0));
+    LangCorpus {
+        dim: DimensionKind::Numeral,
+        corpus,
+    }
+}
+
+/// Check that an entity is an amount of money of `expected` in `unit`.
+pub fn money(expected: f64, unit: &'static str) -> impl Fn(&Entity) -> bool {
+    move |e: &Entity| {
+        matches!(&e.value, DimensionValue::AmountOfMoney(MeasurementValue::Value { value, unit: u })
+            if (*value - expected).abs() < 0.01 && u == unit)
+    }
+}
+
+fn amount_of_money_en() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::EN, None));
+    corpus.add(vec!["$10", "ten dollars", "10$"], money(10.0, "USD"));
+    corpus.add(vec!["$42.50", "42.50 dollars"], money(42.5, "USD"));
+    corpus.add(vec!["a grand"], money(1000.0, "USD"));
+    corpus.add_negative(vec!["she is 42", "42", "room 12"]);
+    LangCorpus {
+        dim: DimensionKind::AmountOfMoney,
+        corpus,
+    }
+}
+
+fn age_en() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::EN, None));
+    corpus.add(
+        vec!["32 years old", "32-year-old", "aged 32", "32 y/o"],
+        value(DimensionValue::Age(MeasurementValue::Value {
+            value: 32.0,
+            unit: AgeUnit::Year.into(),
+        })),
+    );
+    corpus.add(
+        vec!["6 months old", "6-month-old"],
+        value(DimensionValue::Age(MeasurementValue::Value {
+            value: 6.0,
+            unit: AgeUnit::Month.into(),
+        })),
+    );
+    corpus.add_negative(vec!["32", "32 years", "room 12"]);
+    LangCorpus {
+        dim: DimensionKind::Age,
+        corpus,
+    }
+}
+
+fn duration_en() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::EN, None));
+    corpus.add(
+        vec!["one sec", "1 second", "1\""],
+        duration(1, Grain::Second),
+    );
+    corpus.add(
+        vec!["2 mins", "two minutes", "2'"],
+        duration(2, Grain::Minute),
+    );
+    corpus.add(
+        vec!["30 days"],
+        value(DimensionValue::Duration {
+            value: 30,
+            grain: Grain::Day,
+            normalized_seconds: 2_592_000,
+            iso8601: "P30D".into(),
+            qualifier: None,
+        }),
+    );
+    corpus.add(vec!["seven weeks"], duration(7, Grain::Week));
+    corpus.add_negative(vec!["he is 42", "for", "days"]);
+    LangCorpus {
+        dim: DimensionKind::Duration,
+        corpus,
+    }
+}
+
+fn duration_ru() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::RU, None));
+    corpus.add(vec!["три дня"], duration(3, Grain::Day));
+    LangCorpus {
+        dim: DimensionKind::Duration,
+        corpus,
+    }
+}
+
+fn time_grain_en() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::EN, None));
+    corpus.add(
+        vec!["week", "weekly", "per week", "every week"],
+        value(DimensionValue::TimeGrain(Grain::Week)),
+    );
+    corpus.add(
+        vec!["monthly", "per month", "each month"],
+        value(DimensionValue::TimeGrain(Grain::Month)),
+    );
+    corpus.add(
+        vec!["yearly", "annually", "per year"],
+        value(DimensionValue::TimeGrain(Grain::Year)),
+    );
+    LangCorpus {
+        dim: DimensionKind::TimeGrain,
+        corpus,
+    }
+}
+
+/// Every registered corpus. New language rules should add their corpus here,
+/// time corpora in the `time` module.
+pub fn all_corpora() -> Vec<LangCorpus> {
+    vec![
+        numeral_en(),
+        numeral_es(),
+        amount_of_money_en(),
+        age_en(),
+        duration_en(),
+        duration_ru(),
+        time_grain_en(),
+        time::time_en(),
+        time::time_pt(),
+        time::time_pt_br(),
+        time::time_pt_pt(),
+        time::time_ru(),
+        time::time_ca(),
+        time::time_el(),
+        time::time_ga(),
+        time::time_he(),
+        time::time_hr(),
+        time::time_hu(),
+        time::time_it(),
+        time::time_ka(),
+        time::time_pl(),
+        time::time_sv(),
+        time::time_nb(),
+        time::time_da(),
+        time::time_tr(),
+        time::time_uk(),
+        time::time_nl(),
+        time::time_vi(),
+        time::time_ro(),
+        time::time_bg(),
+        time::time_de(),
+        time::time_de_at(),
+        time::time_de_ch(),
+        time::time_es_mx(),
+        time::time_es_ar(),
+        time::time_es_es(),
+        time::time_fr_ca(),
+        time::time_fr_be(),
+        time::time_fr_ch(),
+        time::time_zh_cn(),
+        time::time_zh_tw(),
+        time::time_zh_hk(),
+        time::time_zh_mo(),
+        time::time_fa(),
+        time::time_id(),
+        time::time_th(),
+        time::time_lo(),
+        time::time_km(),
+    ]
+}
+
+/// Run a single registered corpus against the rules for its locale.
+pub fn run_corpus(lang_corpus: &LangCorpus) -> CorpusReport {
+    let locale = lang_corpus.corpus.context.locale;
+    let dims = [lang_corpus.dim];
+    let rules = lang::rules_for(locale, locale.decimal_separator(), &dims, &[], &[]);
+    CorpusReport {
+        locale,
+        dim: lang_corpus.dim,
+        total: lang_corpus.corpus.len(),
+        failures: check_corpus(&lang_corpus.corpus, &rules, &dims),
+    }
+}
+
+/// Format per-language pass rates, one line per language.
+pub fn summarize(reports: &[CorpusReport]) -> String {
+    let mut by_lang: BTreeMap<String, (usize, usize)> = BTreeMap::new();
+    for report in reports {
+        let entry = by_lang
+            .entry(format!("{:?}", report.locale.lang))
+            .or_insert((0, 0));
+        entry.0 = entry.0.saturating_add(report.passed());
+        entry.1 = entry.1.saturating_add(report.total);
+    }
+    by_lang
+        .iter()
+        .map(|(lang, (passed, total))| {
+            let rate = if *total == 0 {
+                100.0
+            } else {
+                *passed as f64 * 100.0 / *total as f64
+            };
+            format!("{}: {}/{} ({:.1}%)", lang, passed, total, rate)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_all_corpora() {
+        let reports: Vec<CorpusReport> = all_corpora().iter().map(run_corpus).collect();
+        println!("{}", summarize(&reports));
+        let failures: Vec<String> = reports
+            .iter()
+            .flat_map(|r| {
+                r.failures
+                    .iter()
+                    .map(move |f| format!("[{:?} {:?}] {}", r.locale.lang, r.dim, f))
+            })
+            .collect();
+        assert!(failures.is_empty(), "Failures:\n{}", failures.join("\n"));
+    }
+}
@@ -0,0 +1,1572 @@
+// Time corpora, one function per language.
+
+use super::{datetime, datetime_interval, test_context, LangCorpus};
+use crate::locale::{Lang, Region};
+use crate::testing::Corpus;
+use crate::types::{DimensionKind, Entity};
+use crate::Grain;
+
+pub(super) fn time_en() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::EN, None));
+    corpus.add(
+        vec!["now", "right now"],
+        datetime(2013, 2, 12, 4, 30, Grain::Second),
+    );
+    corpus.add(vec!["today"], datetime(2013, 2, 12, 0, 0, Grain::Day));
+    corpus.add(vec!["tomorrow"], datetime(2013, 2, 13, 0, 0, Grain::Day));
+    corpus.add(vec!["yesterday"], datetime(2013, 2, 11, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["monday", "mon.", "this monday"],
+        datetime(2013, 2, 18, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["march 3 2015", "3/3/2015"],
+        datetime(2015, 3, 3, 0, 0, Grain::Day),
+    );
+    // From negativeExamples in Duckling/Time/EN/Corpus.hs, plus version strings.
+    corpus.add_negative(vec![
+        "laughing out loud",
+        "1 adult",
+        "we are separated",
+        "25",
+        "this is the one",
+        "v2",
+        "version 2.1",
+    ]);
+    LangCorpus {
+        dim: DimensionKind::Time,
+        corpus,
+    }
+}
+
+pub(super) fn time_pt() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::PT, None));
+    corpus.add(vec!["hoje"], datetime(2013, 2, 12, 0, 0, Grain::Day));
+    corpus.add(vec!["amanhã"], datetime(2013, 2, 13, 0, 0, Grain::Day));
+    corpus.add(vec!["ontem"], datetime(2013, 2, 11, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["depois de amanhã"],
+        datetime(2013, 2, 14, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["3 de março de 2025"],
+        datetime(2025, 3, 3, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["de 3 a 5 de março", "entre 3 e 5 de março"],
+        datetime_interval((2013, 3, 3, 0, 0), (2013, 3, 5, 0, 0), Grain::Day),
+    );
+    corpus.add(vec!["Carnaval"], datetime(2013, 2, 12, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["carnaval de 2014"],
+        datetime(2014, 3, 4, 0, 0, Grain::Day),
+    );
+    corpus.add(vec!["Páscoa"], datetime(2013, 3, 31, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["páscoa de 2014"],
+        datetime(2014, 4, 20, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["sexta-feira santa"],
+        datetime(2013, 3, 29, 0, 0, Grain::Day),
+    );
+    LangCorpus {
+        dim: DimensionKind::Time,
+        corpus,
+    }
+}
+
+pub(super) fn time_pt_br() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::PT, Some(Region::BR)));
+    corpus.add(
+        vec!["3 de março de 2025"],
+        datetime(2025, 3, 3, 0, 0, Grain::Day),
+    );
+    corpus.add(vec!["3/4"], datetime(2013, 4, 3, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["dia dos namorados"],
+        datetime(2013, 6, 12, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["consciência negra"],
+        datetime(2013, 11, 20, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["Tiradentes", "dia de Tiradentes"],
+        datetime(2013, 4, 21, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["Independência do Brasil", "dia da independência"],
+        datetime(2013, 9, 7, 0, 0, Grain::Day),
+    );
+    corpus.add_negative(vec!["dia de Portugal"]);
+    LangCorpus {
+        dim: DimensionKind::Time,
+        corpus,
+    }
+}
+
+pub(super) fn time_pt_pt() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::PT, Some(Region::PT)));
+    corpus.add(
+        vec!["3 de março de 2025"],
+        datetime(2025, 3, 3, 0, 0, Grain::Day),
+    );
+    corpus.add(vec!["3/4"], datetime(2013, 4, 3, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["dia dos namorados"],
+        datetime(2013, 2, 14, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["dia de Portugal"],
+        datetime(2013, 6, 10, 0, 0, Grain::Day),
+    );
+    corpus.add_negative(vec![
+        "Tiradentes",
+        "dia de Tiradentes",
+        "Independência do Brasil",
+        "dia da independência",
+    ]);
+    LangCorpus {
+        dim: DimensionKind::Time,
+        corpus,
+    }
+}
+
+pub(super) fn time_ru() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::RU, None));
+    corpus.add(vec!["сегодня"], datetime(2013, 2, 12, 0, 0, Grain::Day));
+    corpus.add(vec!["вчера"], datetime(2013, 2, 11, 0, 0, Grain::Day));
+    corpus.add(vec!["позавчера"], datetime(2013, 2, 10, 0, 0, Grain::Day));
+    corpus.add(vec!["завтра"], datetime(2013, 2, 13, 0, 0, Grain::Day));
+    corpus.add(vec!["послезавтра"], datetime(2013, 2, 14, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["в понедельник"],
+        datetime(2013, 2, 18, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["во вторник", "в следующий вторник"],
+        datetime(2013, 2, 19, 0, 0, Grain::Day),
+    );
+    corpus.add(vec!["в пятницу"], datetime(2013, 2, 15, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["в прошлую среду"],
+        datetime(2013, 2, 6, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["18 февраля", "восемнадцатого февраля"],
+        datetime(2013, 2, 18, 0, 0, Grain::Day),
+    );
+    corpus.add(vec!["в марте"], datetime(2013, 3, 1, 0, 0, Grain::Month));
+    corpus.add(vec!["в мае"], datetime(2013, 5, 1, 0, 0, Grain::Month));
+    corpus.add(vec!["3 марта 2015"], datetime(2015, 3, 3, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["5 марта 2024 года"],
+        datetime(2024, 3, 5, 0, 0, Grain::Day),
+    );
+    corpus.add(vec!["в 2024 году"], datetime(2024, 1, 1, 0, 0, Grain::Year));
+    corpus.add(
+        vec!["через три дня"],
+        datetime(2013, 2, 15, 4, 0, Grain::Hour),
+    );
+    corpus.add(
+        vec!["через 2 недели"],
+        datetime(2013, 2, 26, 0, 0, Grain::Day),
+    );
+    corpus.add(vec!["3 дня назад"], datetime(2013, 2, 9, 4, 0, Grain::Hour));
+    corpus.add(
+        vec!["Новый год", "на Новый год"],
+        datetime(2014, 1, 1, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["День Победы", "ко Дню Победы"],
+        datetime(2013, 5, 9, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["международный женский день"],
+        datetime(2013, 3, 8, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["день защитника отечества"],
+        datetime(2013, 2, 23, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["в День России"],
+        datetime(2013, 6, 12, 0, 0, Grain::Day),
+    );
+    LangCorpus {
+        dim: DimensionKind::Time,
+        corpus,
+    }
+}
+
+pub(super) fn time_ca() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::CA, None));
+    corpus.add(vec!["demà"], datetime(2013, 2, 13, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["abans d'ahir"],
+        datetime(2013, 2, 10, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["dimarts vinent"],
+        datetime(2013, 2, 19, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["el diumenge passat"],
+        datetime(2013, 2, 10, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["dimecres d'aquesta setmana"],
+        datetime(2013, 2, 13, 0, 0, Grain::Day),
+    );
+    corpus.add(vec!["el 3 de març"], datetime(2013, 3, 3, 0, 0, Grain::Day));
+    corpus.add(vec!["l'u de març"], datetime(2013, 3, 1, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["5 d'abril de 2014"],
+        datetime(2014, 4, 5, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["dilluns, Febrer 18"],
+        datetime(2013, 2, 18, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["a les 5 de la tarda"],
+        datetime(2013, 2, 12, 17, 0, Grain::Hour),
+    );
+    corpus.add(
+        vec!["a les 10 de la nit"],
+        datetime(2013, 2, 12, 22, 0, Grain::Hour),
+    );
+    corpus.add(
+        vec!["tres i quart"],
+        datetime(2013, 2, 12, 15, 15, Grain::Minute),
+    );
+    corpus.add(vec!["migdia"], datetime(2013, 2, 12, 12, 0, Grain::Hour));
+    corpus.add(
+        vec!["un quart de cinc"],
+        datetime(2013, 2, 12, 4, 15, Grain::Minute),
+    );
+    corpus.add(
+        vec!["tres quarts de cinc", "a les 5 menys quart"],
+        datetime(2013, 2, 12, 4, 45, Grain::Minute),
+    );
+    corpus.add(
+        vec!["a les 5 menys 10"],
+        datetime(2013, 2, 12, 4, 50, Grain::Minute),
+    );
+    corpus.add(
+        vec!["Sant Jordi", "la diada de Sant Jordi"],
+        datetime(2013, 4, 23, 0, 0, Grain::Day),
+    );
+    corpus.add(vec!["el Nadal"], datetime(2013, 12, 25, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["Sant Esteve"],
+        datetime(2013, 12, 26, 0, 0, Grain::Day),
+    );
+    LangCorpus {
+        dim: DimensionKind::Time,
+        corpus,
+    }
+}
+
+pub(super) fn time_el() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::EL, None));
+    corpus.add(vec!["αύριο"], datetime(2013, 2, 13, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["την επόμενη Τρίτη"],
+        datetime(2013, 2, 19, 0, 0, Grain::Day),
+    );
+    corpus.add(vec!["3 Μαρτίου"], datetime(2013, 3, 3, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["στις 15 Φεβρουαρίου"],
+        datetime(2013, 2, 15, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["στις 5 το απόγευμα"],
+        datetime(2013, 2, 12, 17, 0, Grain::Minute),
+    );
+    corpus.add(
+        vec!["στις 15:30"],
+        datetime(2013, 2, 12, 15, 30, Grain::Minute),
+    );
+    corpus.add(
+        vec!["αύριο στις 9"],
+        datetime(2013, 2, 13, 9, 0, Grain::Hour),
+    );
+    // Orthodox Easter 2013 fell on May 5, five weeks after the Western one
+    corpus.add(vec!["Πάσχα"], datetime(2013, 5, 5, 0, 0, Grain::Day));
+    corpus.add(vec!["Πάσχα 2025"], datetime(2025, 4, 20, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["Καθαρά Δευτέρα"],
+        datetime(2013, 3, 18, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["Μεγάλη Παρασκευή"],
+        datetime(2013, 5, 3, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["Δευτέρα του Πάσχα"],
+        datetime(2013, 5, 6, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["Κυριακή των Βαΐων"],
+        datetime(2013, 4, 28, 0, 0, Grain::Day),
+    );
+    corpus.add(vec!["Πεντηκοστή"], datetime(2013, 6, 23, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["του Αγίου Πνεύματος"],
+        datetime(2013, 6, 24, 0, 0, Grain::Day),
+    );
+    LangCorpus {
+        dim: DimensionKind::Time,
+        corpus,
+    }
+}
+
+pub(super) fn time_ga() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::GA, None));
+    corpus.add(vec!["amárach"], datetime(2013, 2, 13, 0, 0, Grain::Day));
+    corpus.add(vec!["arú amárach"], datetime(2013, 2, 14, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["Dé Máirt seo chugainn", "an Mháirt"],
+        datetime(2013, 2, 19, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["ar an Aoine", "Dé hAoine"],
+        datetime(2013, 2, 15, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["Dé Domhnaigh seo caite"],
+        datetime(2013, 2, 10, 0, 0, Grain::Day),
+    );
+    corpus.add(vec!["an 3 Márta"], datetime(2013, 3, 3, 0, 0, Grain::Day));
+    corpus.add(vec!["3 Márta 2025"], datetime(2025, 3, 3, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["an 5ú Deireadh Fómhair"],
+        datetime(2013, 10, 5, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["i mí an Mhárta"],
+        datetime(2013, 3, 1, 0, 0, Grain::Month),
+    );
+    corpus.add(
+        vec!["mí na Samhna"],
+        datetime(2013, 11, 1, 0, 0, Grain::Month),
+    );
+    corpus.add(
+        vec!["amárach ag 3 a chlog"],
+        datetime(2013, 2, 13, 3, 0, Grain::Hour),
+    );
+    corpus.add(
+        vec!["Lá Fhéile Pádraig", "Lá Féile Phádraig"],
+        datetime(2013, 3, 17, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["Lá Fhéile Bríde"],
+        datetime(2014, 2, 1, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["Oíche Nollag"],
+        datetime(2013, 12, 24, 0, 0, Grain::Day),
+    );
+    corpus.add(vec!["Lá Nollag"], datetime(2013, 12, 25, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["Oíche Shamhna"],
+        datetime(2013, 10, 31, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["Domhnach Cásca"],
+        datetime(2013, 3, 31, 0, 0, Grain::Day),
+    );
+    LangCorpus {
+        dim: DimensionKind::Time,
+        corpus,
+    }
+}
+
+pub(super) fn time_he() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::HE, None));
+    corpus.add(vec!["מחר"], datetime(2013, 2, 13, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["יום שלישי הבא"],
+        datetime(2013, 2, 19, 0, 0, Grain::Day),
+    );
+    corpus.add(vec!["שישי הבא"], datetime(2013, 2, 22, 0, 0, Grain::Day));
+    corpus.add(vec!["ביום ראשון"], datetime(2013, 2, 17, 0, 0, Grain::Day));
+    corpus.add(vec!["שני שעבר"], datetime(2013, 2, 11, 0, 0, Grain::Day));
+    corpus.add_negative(vec!["שנייה"]);
+    corpus.add(vec!["בשעה שלוש"], datetime(2013, 2, 12, 15, 0, Grain::Hour));
+    corpus.add(
+        vec!["בשעה שלוש וחצי", "בשעה 15:30", "ב-15:30"],
+        datetime(2013, 2, 12, 15, 30, Grain::Minute),
+    );
+    corpus.add(
+        vec!["מחר בשעה 15:30"],
+        datetime(2013, 2, 13, 15, 30, Grain::Minute),
+    );
+    corpus.add(vec!["3 במאי"], datetime(2013, 5, 3, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["ב-3 במאי 2025"],
+        datetime(2025, 5, 3, 0, 0, Grain::Day),
+    );
+    corpus.add(vec!["ה15 בפברואר"], datetime(2013, 2, 15, 0, 0, Grain::Day));
+    corpus.add(vec!["במאי"], datetime(2013, 5, 1, 0, 0, Grain::Month));
+    corpus.add(vec!["מרץ הבא"], datetime(2013, 3, 1, 0, 0, Grain::Month));
+    corpus.add(
+        vec!["ראש השנה"],
+        datetime_interval((2013, 9, 4, 0, 0), (2013, 9, 7, 0, 0), Grain::Day),
+    );
+    corpus.add(
+        vec!["ראש השנה 2025"],
+        datetime_interval((2025, 9, 22, 0, 0), (2025, 9, 25, 0, 0), Grain::Day),
+    );
+    corpus.add(vec!["יום כיפור"], datetime(2013, 9, 13, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["פסח", "בחג הפסח"],
+        datetime_interval((2013, 3, 25, 0, 0), (2013, 4, 3, 0, 0), Grain::Day),
+    );
+    corpus.add(
+        vec!["חנוכה"],
+        datetime_interval((2013, 11, 27, 0, 0), (2013, 12, 5, 0, 0), Grain::Day),
+    );
+    corpus.add(vec!["פורים"], datetime(2013, 2, 23, 0, 0, Grain::Day));
+    corpus.add(vec!["ט״ו בשבט"], datetime(2014, 1, 15, 0, 0, Grain::Day));
+    // "weeks", not Shavuot
+    corpus.add_negative(vec!["בעוד שלושה שבועות"]);
+    LangCorpus {
+        dim: DimensionKind::Time,
+        corpus,
+    }
+}
+
+pub(super) fn time_hr() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::HR, None));
+    corpus.add(
+        vec!["sljedecu srijedu"],
+        datetime(2013, 2, 13, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["prosle nedjelje"],
+        datetime(2013, 2, 10, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["petak nakon sljedeceg"],
+        datetime(2013, 2, 22, 0, 0, Grain::Day),
+    );
+    corpus.add(vec!["u ozujku"], datetime(2013, 3, 1, 0, 0, Grain::Month));
+    corpus.add(
+        vec!["zadnji ponedjeljak u ozujku"],
+        datetime(2013, 3, 25, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["u subotu u 9 sati ujutro"],
+        datetime(2013, 2, 16, 9, 0, Grain::Hour),
+    );
+    corpus.add(
+        vec!["četvrt do pet"],
+        datetime(2013, 2, 12, 4, 45, Grain::Minute),
+    );
+    corpus.add(
+        vec!["deset do pet"],
+        datetime(2013, 2, 12, 4, 50, Grain::Minute),
+    );
+    corpus.add(
+        vec!["dvadeset i pet do pet"],
+        datetime(2013, 2, 12, 4, 35, Grain::Minute),
+    );
+    corpus.add(vec!["Badnjak"], datetime(2013, 12, 24, 0, 0, Grain::Day));
+    corpus.add(vec!["Božić"], datetime(2013, 12, 25, 0, 0, Grain::Day));
+    corpus.add(vec!["Uskrs"], datetime(2013, 3, 31, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["uskrsni ponedjeljak"],
+        datetime(2013, 4, 1, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["Tijelovo", "Dan državnosti"],
+        datetime(2013, 5, 30, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["Velika Gospa"],
+        datetime(2013, 8, 15, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["Sveti Stjepan"],
+        datetime(2013, 12, 26, 0, 0, Grain::Day),
+    );
+    LangCorpus {
+        dim: DimensionKind::Time,
+        corpus,
+    }
+}
+
+pub(super) fn time_hu() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::HU, None));
+    corpus.add(vec!["jövő kedden"], datetime(2013, 2, 19, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["május 3-án", "2013. május 3."],
+        datetime(2013, 5, 3, 0, 0, Grain::Day),
+    );
+    corpus.add(vec!["2013.08.20"], datetime(2013, 8, 20, 0, 0, Grain::Day));
+    corpus.add(vec!["délután 3"], datetime(2013, 2, 12, 15, 0, Grain::Hour));
+    corpus.add(
+        vec!["negyed öt"],
+        datetime(2013, 2, 12, 4, 15, Grain::Minute),
+    );
+    corpus.add(vec!["fél öt"], datetime(2013, 2, 12, 4, 30, Grain::Minute));
+    corpus.add(
+        vec!["háromnegyed 5"],
+        datetime(2013, 2, 12, 4, 45, Grain::Minute),
+    );
+    corpus.add(
+        vec!["tíz perccel öt előtt"],
+        datetime(2013, 2, 12, 4, 50, Grain::Minute),
+    );
+    corpus.add(
+        vec!["öt után tíz perccel"],
+        datetime(2013, 2, 12, 5, 10, Grain::Minute),
+    );
+    corpus.add(vec!["szenteste"], datetime(2013, 12, 24, 0, 0, Grain::Day));
+    corpus.add(vec!["karácsony"], datetime(2013, 12, 25, 0, 0, Grain::Day));
+    corpus.add(vec!["húsvét"], datetime(2013, 3, 31, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["pünkösdhétfő"],
+        datetime(2013, 5, 20, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["Szent István napja"],
+        datetime(2013, 8, 20, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["mindenszentek"],
+        datetime(2013, 11, 1, 0, 0, Grain::Day),
+    );
+    LangCorpus {
+        dim: DimensionKind::Time,
+        corpus,
+    }
+}
+
+pub(super) fn time_it() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::IT, None));
+    corpus.add(vec!["domani"], datetime(2013, 2, 13, 0, 0, Grain::Day));
+    corpus.add(vec!["dopodomani"], datetime(2013, 2, 14, 0, 0, Grain::Day));
+    corpus.add(vec!["ieri"], datetime(2013, 2, 11, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["l'altro ieri"],
+        datetime(2013, 2, 10, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["martedì prossimo"],
+        datetime(2013, 2, 19, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["venerdì scorso"],
+        datetime(2013, 2, 8, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["domani alle 15"],
+        datetime(2013, 2, 13, 15, 0, Grain::Hour),
+    );
+    corpus.add(vec!["il 3 marzo"], datetime(2013, 3, 3, 0, 0, Grain::Day));
+    corpus.add(vec!["3 marzo 2025"], datetime(2025, 3, 3, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["il 3 marzo alle 9"],
+        datetime(2013, 3, 3, 9, 0, Grain::Hour),
+    );
+    corpus.add(
+        vec!["primo di marzo"],
+        datetime(2013, 3, 1, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["le idi di marzo"],
+        datetime(2013, 3, 15, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["il terzo giorno di ottobre"],
+        datetime(2013, 10, 3, 0, 0, Grain::Day),
+    );
+    corpus.add(vec!["in marzo"], datetime(2013, 3, 1, 0, 0, Grain::Month));
+    corpus.add(vec!["alle 15"], datetime(2013, 2, 12, 15, 0, Grain::Hour));
+    corpus.add(
+        vec!["alle 15:30"],
+        datetime(2013, 2, 12, 15, 30, Grain::Minute),
+    );
+    corpus.add(
+        vec!["a mezzogiorno"],
+        datetime(2013, 2, 12, 12, 0, Grain::Hour),
+    );
+    corpus.add(
+        vec!["mezzogiorno meno un quarto"],
+        datetime(2013, 2, 12, 11, 45, Grain::Minute),
+    );
+    // "a" only makes a clock time of a number with "alle"
+    corpus.add_negative(vec!["a 3 giorni"]);
+    corpus.add(
+        vec!["dalle 9 alle 11"],
+        datetime_interval((2013, 2, 12, 9, 0), (2013, 2, 12, 12, 0), Grain::Hour),
+    );
+    corpus.add(
+        vec!["dalle 14 alle 16"],
+        datetime_interval((2013, 2, 12, 14, 0), (2013, 2, 12, 17, 0), Grain::Hour),
+    );
+    corpus.add(vec!["Ferragosto"], datetime(2013, 8, 15, 0, 0, Grain::Day));
+    corpus.add(vec!["Natale"], datetime(2013, 12, 25, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["vigilia di Natale"],
+        datetime(2013, 12, 24, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["santo stefano"],
+        datetime(2013, 12, 26, 0, 0, Grain::Day),
+    );
+    corpus.add(vec!["Pasqua"], datetime(2013, 3, 31, 0, 0, Grain::Day));
+    corpus.add(vec!["Pasquetta"], datetime(2013, 4, 1, 0, 0, Grain::Day));
+    corpus.add(vec!["epifania"], datetime(2014, 1, 6, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["festa della repubblica"],
+        datetime(2013, 6, 2, 0, 0, Grain::Day),
+    );
+    LangCorpus {
+        dim: DimensionKind::Time,
+        corpus,
+    }
+}
+
+pub(super) fn time_ka() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::KA, None));
+    corpus.add(vec!["ხვალ"], datetime(2013, 2, 13, 0, 0, Grain::Day));
+    corpus.add(vec!["გუშინწინ"], datetime(2013, 2, 10, 0, 0, Grain::Day));
+    corpus.add(vec!["ამ ორშაბათს"], datetime(2013, 2, 18, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["მომავალ სამშაბათს"],
+        datetime(2013, 2, 26, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["ბოლო სამშაბათს"],
+        datetime(2013, 2, 5, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["წინა კვირის კვირას"],
+        datetime(2013, 2, 10, 0, 0, Grain::Day),
+    );
+    corpus.add(vec!["3 მაისი"], datetime(2013, 5, 3, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["2015 წლის 14 აპრილს"],
+        datetime(2015, 4, 14, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["ოქტომბრის მეორე ოთხშაბათი"],
+        datetime(2013, 10, 9, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["შემდეგი მარტი"],
+        datetime(2014, 3, 1, 0, 0, Grain::Month),
+    );
+    corpus.add(vec!["15 საათზე"], datetime(2013, 2, 12, 15, 0, Grain::Hour));
+    corpus.add(
+        vec!["საღამოს 7 საათზე"],
+        datetime(2013, 2, 12, 19, 0, Grain::Hour),
+    );
+    corpus.add(
+        vec!["4-ის ნახევარზე", "15:30-ზე"],
+        datetime(2013, 2, 12, 15, 30, Grain::Minute),
+    );
+    corpus.add(
+        vec!["ხვალ შუადღის 5-ზე"],
+        datetime(2013, 2, 13, 17, 0, Grain::Hour),
+    );
+    corpus.add(
+        vec!["გუშინწინ8-ზე"],
+        datetime(2013, 2, 10, 8, 0, Grain::Hour),
+    );
+    corpus.add(
+        vec!["5-ის 15 წუთზე"],
+        datetime(2013, 2, 12, 4, 15, Grain::Minute),
+    );
+    corpus.add(
+        vec!["5 საათს აკლია 10 წუთი"],
+        datetime(2013, 2, 12, 4, 50, Grain::Minute),
+    );
+    corpus.add(
+        vec!["5-ს აკლია 15 წუთი"],
+        datetime(2013, 2, 12, 4, 45, Grain::Minute),
+    );
+    LangCorpus {
+        dim: DimensionKind::Time,
+        corpus,
+    }
+}
+
+pub(super) fn time_pl() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::PL, None));
+    corpus.add(
+        vec!["w przyszły wtorek"],
+        datetime(2013, 2, 19, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["w przyszłą środę"],
+        datetime(2013, 2, 20, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["w zeszły piątek"],
+        datetime(2013, 2, 8, 0, 0, Grain::Day),
+    );
+    corpus.add(vec!["w piątek"], datetime(2013, 2, 15, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["w przyszłym tygodniu"],
+        datetime(2013, 2, 18, 0, 0, Grain::Week),
+    );
+    corpus.add(vec!["za dwa dni"], datetime(2013, 2, 14, 0, 0, Grain::Day));
+    corpus.add(vec!["3 maja"], datetime(2013, 5, 3, 0, 0, Grain::Day));
+    corpus.add(vec!["5 stycznia"], datetime(2014, 1, 5, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["12 czerwca 2020"],
+        datetime(2020, 6, 12, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["1 października"],
+        datetime(2013, 10, 1, 0, 0, Grain::Day),
+    );
+    corpus.add(vec!["w marcu"], datetime(2013, 3, 1, 0, 0, Grain::Month));
+    corpus.add(vec!["kwiecień"], datetime(2013, 4, 1, 0, 0, Grain::Month));
+    corpus.add(vec!["o 15"], datetime(2013, 2, 12, 15, 0, Grain::Hour));
+    corpus.add(
+        vec!["o godz. 15:30"],
+        datetime(2013, 2, 12, 15, 30, Grain::Minute),
+    );
+    corpus.add(
+        vec!["od 9 do 17"],
+        datetime_interval((2013, 2, 12, 9, 0), (2013, 2, 12, 18, 0), Grain::Hour),
+    );
+    corpus.add(vec!["jutro o 9"], datetime(2013, 2, 13, 9, 0, Grain::Hour));
+    corpus.add(
+        vec!["14 lutego o 15:30"],
+        datetime(2013, 2, 14, 15, 30, Grain::Minute),
+    );
+    corpus.add(
+        vec!["Wigilia", "Wigilia Bożego Narodzenia"],
+        datetime(2013, 12, 24, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["Boże Narodzenie"],
+        datetime(2013, 12, 25, 0, 0, Grain::Day),
+    );
+    corpus.add(vec!["Sylwester"], datetime(2013, 12, 31, 0, 0, Grain::Day));
+    LangCorpus {
+        dim: DimensionKind::Time,
+        corpus,
+    }
+}
+
+pub(super) fn time_sv() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::SV, None));
+    corpus.add(vec!["i morgon"], datetime(2013, 2, 13, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["i övermorgon"],
+        datetime(2013, 2, 14, 0, 0, Grain::Day),
+    );
+    // "mån" inside "månad" is not Monday
+    corpus.add(
+        vec!["nästa månad"],
+        datetime(2013, 3, 1, 0, 0, Grain::Month),
+    );
+    corpus.add(
+        vec!["nästa tisdag"],
+        datetime(2013, 2, 19, 0, 0, Grain::Day),
+    );
+    corpus.add(vec!["förra fredag"], datetime(2013, 2, 8, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["klockan 15"],
+        datetime(2013, 2, 12, 15, 0, Grain::Minute),
+    );
+    corpus.add(
+        vec!["kl. 15:30"],
+        datetime(2013, 2, 12, 15, 30, Grain::Minute),
+    );
+    corpus.add(
+        vec!["kvart över tre"],
+        datetime(2013, 2, 12, 15, 15, Grain::Minute),
+    );
+    corpus.add(
+        vec!["kvart i fem"],
+        datetime(2013, 2, 12, 4, 45, Grain::Minute),
+    );
+    corpus.add(
+        vec!["halv fem"],
+        datetime(2013, 2, 12, 4, 30, Grain::Minute),
+    );
+    corpus.add(
+        vec!["fem i halv fem"],
+        datetime(2013, 2, 12, 4, 25, Grain::Minute),
+    );
+    corpus.add(
+        vec!["kl. 20 över 15"],
+        datetime(2013, 2, 12, 15, 20, Grain::Minute),
+    );
+    // Swedish midsummer moves with the weekday
+    corpus.add(
+        vec!["midsommar", "midsommarafton"],
+        datetime(2013, 6, 21, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["midsommardagen"],
+        datetime(2013, 6, 22, 0, 0, Grain::Day),
+    );
+    LangCorpus {
+        dim: DimensionKind::Time,
+        corpus,
+    }
+}
+
+pub(super) fn time_nb() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::NB, None));
+    corpus.add(vec!["i morgen"], datetime(2013, 2, 13, 0, 0, Grain::Day));
+    corpus.add(vec!["i forigårs"], datetime(2013, 2, 10, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["neste tirsdag"],
+        datetime(2013, 2, 19, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["forrige fredag"],
+        datetime(2013, 2, 8, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["klokka 15"],
+        datetime(2013, 2, 12, 15, 0, Grain::Minute),
+    );
+    corpus.add(
+        vec!["kvart på fem"],
+        datetime(2013, 2, 12, 4, 45, Grain::Minute),
+    );
+    corpus.add(
+        vec!["ti over halv fem"],
+        datetime(2013, 2, 12, 4, 40, Grain::Minute),
+    );
+    corpus.add(
+        vec!["sankthansaften"],
+        datetime(2013, 6, 23, 0, 0, Grain::Day),
+    );
+    LangCorpus {
+        dim: DimensionKind::Time,
+        corpus,
+    }
+}
+
+pub(super) fn time_da() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::DA, None));
+    corpus.add(vec!["i morgen"], datetime(2013, 2, 13, 0, 0, Grain::Day));
+    corpus.add(vec!["i går"], datetime(2013, 2, 11, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["næste tirsdag"],
+        datetime(2013, 2, 19, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["næste fredag igen"],
+        datetime(2013, 2, 22, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["sidste fredag"],
+        datetime(2013, 2, 8, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["klokken 15"],
+        datetime(2013, 2, 12, 15, 0, Grain::Minute),
+    );
+    corpus.add(
+        vec!["kl 15:30"],
+        datetime(2013, 2, 12, 15, 30, Grain::Minute),
+    );
+    corpus.add(
+        vec!["kvart over 15"],
+        datetime(2013, 2, 12, 15, 15, Grain::Minute),
+    );
+    corpus.add(
+        vec!["20 i 12"],
+        datetime(2013, 2, 12, 11, 40, Grain::Minute),
+    );
+    corpus.add(vec!["sankthans"], datetime(2013, 6, 23, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["sankthansdag"],
+        datetime(2013, 6, 24, 0, 0, Grain::Day),
+    );
+    LangCorpus {
+        dim: DimensionKind::Time,
+        corpus,
+    }
+}
+
+pub(super) fn time_tr() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::TR, None));
+    corpus.add(
+        vec!["yarın", "yarına"],
+        datetime(2013, 2, 13, 0, 0, Grain::Day),
+    );
+    corpus.add(vec!["bugünden"], datetime(2013, 2, 12, 0, 0, Grain::Day));
+    corpus.add(vec!["dün"], datetime(2013, 2, 11, 0, 0, Grain::Day));
+    corpus.add_negative(vec!["dünya"]);
+    corpus.add(
+        vec!["önümüzdeki salı", "salı günü"],
+        datetime(2013, 2, 19, 0, 0, Grain::Day),
+    );
+    corpus.add(vec!["cumaya"], datetime(2013, 2, 15, 0, 0, Grain::Day));
+    corpus.add(vec!["cumartesiye"], datetime(2013, 2, 16, 0, 0, Grain::Day));
+    corpus.add(vec!["pazar"], datetime(2013, 2, 17, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["pazartesi gününe"],
+        datetime(2013, 2, 18, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["3 Mayıs 2025", "3 Mayıs 2025'te"],
+        datetime(2025, 5, 3, 0, 0, Grain::Day),
+    );
+    corpus.add(vec!["3 Mayıs'ta"], datetime(2013, 5, 3, 0, 0, Grain::Day));
+    corpus.add(vec!["15 Ocak'ta"], datetime(2014, 1, 15, 0, 0, Grain::Day));
+    corpus.add(vec!["Mayıs'ta"], datetime(2013, 5, 1, 0, 0, Grain::Month));
+    corpus.add(vec!["Ocağın"], datetime(2014, 1, 1, 0, 0, Grain::Month));
+    corpus.add(vec!["2025'te"], datetime(2025, 1, 1, 0, 0, Grain::Year));
+    corpus.add(
+        vec!["saat 15:30'da"],
+        datetime(2013, 2, 12, 15, 30, Grain::Minute),
+    );
+    corpus.add(vec!["sabah 9'da"], datetime(2013, 2, 12, 9, 0, Grain::Hour));
+    corpus.add(
+        vec!["önümüzdeki salı saat 15:30'da"],
+        datetime(2013, 2, 19, 15, 30, Grain::Minute),
+    );
+    corpus.add(
+        vec!["cuma günü saat 10'da"],
+        datetime(2013, 2, 15, 10, 0, Grain::Minute),
+    );
+    corpus.add(
+        vec!["Ramazan Bayramı", "Şeker Bayramında"],
+        datetime(2013, 8, 8, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["Kurban Bayramı"],
+        datetime(2013, 10, 15, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["2025 Kurban Bayramı'nda"],
+        datetime(2025, 6, 6, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["Ramazan Bayramı 2025"],
+        datetime(2025, 3, 30, 0, 0, Grain::Day),
+    );
+    LangCorpus {
+        dim: DimensionKind::Time,
+        corpus,
+    }
+}
+
+pub(super) fn time_uk() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::UK, None));
+    corpus.add(vec!["завтра"], datetime(2013, 2, 13, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["наступного вівторка", "у вівторок"],
+        datetime(2013, 2, 19, 0, 0, Grain::Day),
+    );
+    corpus.add(vec!["в п'ятницю"], datetime(2013, 2, 15, 0, 0, Grain::Day));
+    // "пн" inside "наступного" is not Monday
+    corpus.add_negative(vec!["наступного"]);
+    corpus.add(
+        vec!["3 травня 2025"],
+        datetime(2025, 5, 3, 0, 0, Grain::Day),
+    );
+    corpus.add(vec!["у березні"], datetime(2013, 3, 1, 0, 0, Grain::Month));
+    corpus.add(
+        vec!["третій день у жовтні"],
+        datetime(2013, 10, 3, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["о 15:30"],
+        datetime(2013, 2, 12, 15, 30, Grain::Minute),
+    );
+    corpus.add(
+        vec!["завтра о 15:30"],
+        datetime(2013, 2, 13, 15, 30, Grain::Minute),
+    );
+    corpus.add(vec!["Великдень"], datetime(2013, 5, 5, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["Великдень 2025"],
+        datetime(2025, 4, 20, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["Страсна п'ятниця"],
+        datetime(2013, 5, 3, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["Вербна неділя"],
+        datetime(2013, 4, 28, 0, 0, Grain::Day),
+    );
+    corpus.add(vec!["Трійця"], datetime(2013, 6, 23, 0, 0, Grain::Day));
+    LangCorpus {
+        dim: DimensionKind::Time,
+        corpus,
+    }
+}
+
+pub(super) fn time_nl() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::NL, None));
+    corpus.add(
+        vec!["half vier", "om half vier", "half 4"],
+        datetime(2013, 2, 12, 15, 30, Grain::Minute),
+    );
+    corpus.add(
+        vec!["kwart over drie"],
+        datetime(2013, 2, 12, 15, 15, Grain::Minute),
+    );
+    corpus.add(
+        vec!["kwart voor 12"],
+        datetime(2013, 2, 12, 11, 45, Grain::Minute),
+    );
+    corpus.add(
+        vec!["kwart voor vijf"],
+        datetime(2013, 2, 12, 4, 45, Grain::Minute),
+    );
+    corpus.add(vec!["morgen"], datetime(2013, 2, 13, 0, 0, Grain::Day));
+    corpus.add(vec!["overmorgen"], datetime(2013, 2, 14, 0, 0, Grain::Day));
+    corpus.add(vec!["gisteren"], datetime(2013, 2, 11, 0, 0, Grain::Day));
+    corpus.add(vec!["eergisteren"], datetime(2013, 2, 10, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["volgende week dinsdag"],
+        datetime(2013, 2, 19, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["dinsdag volgende week om 15:00"],
+        datetime(2013, 2, 19, 15, 0, Grain::Minute),
+    );
+    corpus.add(
+        vec![
+            "3 uur 's middags",
+            "drie uur 's middags",
+            "rond 3 uur 's middags",
+        ],
+        datetime(2013, 2, 12, 15, 0, Grain::Hour),
+    );
+    corpus.add(
+        vec!["zes uur 's avonds"],
+        datetime(2013, 2, 12, 18, 0, Grain::Hour),
+    );
+    corpus.add(
+        vec!["11 uur 's nachts"],
+        datetime(2013, 2, 12, 23, 0, Grain::Hour),
+    );
+    corpus.add(vec!["15 uur"], datetime(2013, 2, 12, 15, 0, Grain::Hour));
+    corpus.add(
+        vec!["half vier 's middags"],
+        datetime(2013, 2, 12, 15, 30, Grain::Minute),
+    );
+    corpus.add(
+        vec!["morgen om 3 uur 's middags"],
+        datetime(2013, 2, 13, 15, 0, Grain::Hour),
+    );
+    corpus.add(vec!["Koningsdag"], datetime(2013, 4, 27, 0, 0, Grain::Day));
+    // 27 April 2014 is a Sunday
+    corpus.add(
+        vec!["koningsdag 2014"],
+        datetime(2014, 4, 26, 0, 0, Grain::Day),
+    );
+    corpus.add(vec!["Sinterklaas"], datetime(2013, 12, 6, 0, 0, Grain::Day));
+    corpus.add(vec!["pakjesavond"], datetime(2013, 12, 5, 0, 0, Grain::Day));
+    LangCorpus {
+        dim: DimensionKind::Time,
+        corpus,
+    }
+}
+
+pub(super) fn time_vi() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::VI, None));
+    corpus.add(vec!["ngày mai"], datetime(2013, 2, 13, 0, 0, Grain::Day));
+    corpus.add(vec!["hôm nay"], datetime(2013, 2, 12, 0, 0, Grain::Day));
+    corpus.add(vec!["hôm qua"], datetime(2013, 2, 11, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["thứ ba tuần sau", "thứ ba tuần tới"],
+        datetime(2013, 2, 19, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["thứ năm tuần tới", "thứ 5 tuần sau"],
+        datetime(2013, 2, 21, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["thứ hai tuần sau"],
+        datetime(2013, 2, 18, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["3 giờ chiều", "vào lúc 3 giờ chiều"],
+        datetime(2013, 2, 12, 15, 0, Grain::Minute),
+    );
+    corpus.add(
+        vec!["8 giờ sáng"],
+        datetime(2013, 2, 12, 8, 0, Grain::Minute),
+    );
+    corpus.add(
+        vec!["thứ ba tuần sau lúc 3 giờ chiều"],
+        datetime(2013, 2, 19, 15, 0, Grain::Minute),
+    );
+    LangCorpus {
+        dim: DimensionKind::Time,
+        corpus,
+    }
+}
+
+pub(super) fn time_ro() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::RO, None));
+    corpus.add(vec!["pe 3 martie"], datetime(2013, 3, 3, 0, 0, Grain::Day));
+    corpus.add(vec!["întâi martie"], datetime(2013, 3, 1, 0, 0, Grain::Day));
+    corpus.add(vec!["3 mai 2025"], datetime(2025, 5, 3, 0, 0, Grain::Day));
+    corpus.add(vec!["în mai"], datetime(2013, 5, 1, 0, 0, Grain::Month));
+    corpus.add(vec!["Marti pe 19"], datetime(2013, 2, 19, 0, 0, Grain::Day));
+    corpus.add(vec!["alaltaieri"], datetime(2013, 2, 10, 0, 0, Grain::Day));
+    // "mai" alone is "more"
+    corpus.add_negative(vec!["mai"]);
+    corpus.add(
+        vec!["Ziua Națională"],
+        datetime(2013, 12, 1, 0, 0, Grain::Day),
+    );
+    corpus.add(vec!["Sf. Andrei"], datetime(2013, 11, 30, 0, 0, Grain::Day));
+    corpus.add(vec!["Crăciun"], datetime(2013, 12, 25, 0, 0, Grain::Day));
+    // Orthodox Easter 2013 fell on May 5
+    corpus.add(vec!["Paște"], datetime(2013, 5, 5, 0, 0, Grain::Day));
+    corpus.add(vec!["Rusalii"], datetime(2013, 6, 23, 0, 0, Grain::Day));
+    LangCorpus {
+        dim: DimensionKind::Time,
+        corpus,
+    }
+}
+
+pub(super) fn time_bg() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::BG, None));
+    corpus.add(vec!["на 3 март"], datetime(2013, 3, 3, 0, 0, Grain::Day));
+    corpus.add(vec!["3:18"], datetime(2013, 2, 13, 3, 18, Grain::Minute));
+    corpus.add(vec!["в 3"], datetime(2013, 2, 12, 15, 0, Grain::Hour));
+    corpus.add(vec!["Трети март"], datetime(2013, 3, 3, 0, 0, Grain::Day));
+    corpus.add(vec!["Гергьовден"], datetime(2013, 5, 6, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["Денят на славянската писменост"],
+        datetime(2013, 5, 24, 0, 0, Grain::Day),
+    );
+    corpus.add(vec!["Съединението"], datetime(2013, 9, 6, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["Денят на независимостта"],
+        datetime(2013, 9, 22, 0, 0, Grain::Day),
+    );
+    corpus.add(vec!["Великден"], datetime(2013, 5, 5, 0, 0, Grain::Day));
+    LangCorpus {
+        dim: DimensionKind::Time,
+        corpus,
+    }
+}
+
+pub(super) fn time_de() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::DE, None));
+    corpus.add(
+        vec!["halb drei"],
+        datetime(2013, 2, 12, 14, 30, Grain::Minute),
+    );
+    corpus.add(
+        vec!["um halb 4"],
+        datetime(2013, 2, 12, 15, 30, Grain::Minute),
+    );
+    corpus.add(
+        vec!["halb eins"],
+        datetime(2013, 2, 12, 12, 30, Grain::Minute),
+    );
+    corpus.add(
+        vec!["um viertel nach 3", "viertel nach drei Uhr"],
+        datetime(2013, 2, 12, 15, 15, Grain::Minute),
+    );
+    corpus.add(
+        vec!["viertel vor 12"],
+        datetime(2013, 2, 12, 11, 45, Grain::Minute),
+    );
+    corpus.add(
+        vec!["viertel vor fünf"],
+        datetime(2013, 2, 12, 4, 45, Grain::Minute),
+    );
+    corpus.add(
+        vec!["dreiviertel sieben"],
+        datetime(2013, 2, 12, 6, 45, Grain::Minute),
+    );
+    corpus.add(
+        vec!["halb 17 uhr"],
+        datetime(2013, 2, 12, 16, 30, Grain::Minute),
+    );
+    corpus.add(
+        vec!["Dienstag für drei Tage"],
+        datetime_interval((2013, 2, 19, 0, 0), (2013, 2, 23, 0, 0), Grain::Day),
+    );
+    corpus.add_negative(vec!["viertel drei"]);
+    LangCorpus {
+        dim: DimensionKind::Time,
+        corpus,
+    }
+}
+
+pub(super) fn time_de_at() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::DE, Some(Region::AT)));
+    corpus.add(
+        vec!["viertel drei"],
+        datetime(2013, 2, 12, 14, 15, Grain::Minute),
+    );
+    corpus.add(
+        vec!["dreiviertel drei"],
+        datetime(2013, 2, 12, 14, 45, Grain::Minute),
+    );
+    corpus.add(vec!["3. Jänner"], datetime(2014, 1, 3, 0, 0, Grain::Day));
+    corpus.add(vec!["Feber"], datetime(2013, 2, 1, 0, 0, Grain::Month));
+    corpus.add(
+        vec!["Nationalfeiertag"],
+        datetime(2013, 10, 26, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["Staatsfeiertag"],
+        datetime(2013, 5, 1, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["Mariä Empfängnis"],
+        datetime(2013, 12, 8, 0, 0, Grain::Day),
+    );
+    corpus.add(vec!["Stefanitag"], datetime(2013, 12, 26, 0, 0, Grain::Day));
+    LangCorpus {
+        dim: DimensionKind::Time,
+        corpus,
+    }
+}
+
+pub(super) fn time_de_ch() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::DE, Some(Region::CH)));
+    corpus.add(
+        vec!["um viertel ab drei"],
+        datetime(2013, 2, 12, 15, 15, Grain::Minute),
+    );
+    corpus.add(
+        vec!["Nationalfeiertag", "Bundesfeier"],
+        datetime(2013, 8, 1, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["Berchtoldstag"],
+        datetime(2014, 1, 2, 0, 0, Grain::Day),
+    );
+    corpus.add_negative(vec!["Staatsfeiertag"]);
+    LangCorpus {
+        dim: DimensionKind::Time,
+        corpus,
+    }
+}
+
+pub(super) fn time_es_mx() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::ES, Some(Region::MX)));
+    corpus.add(vec!["3/4"], datetime(2013, 4, 3, 0, 0, Grain::Day));
+    corpus.add(vec!["3/4/2014"], datetime(2014, 4, 3, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["día de muertos"],
+        datetime(2013, 11, 2, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["día de la revolución"],
+        datetime(2013, 11, 20, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["día de la independencia"],
+        datetime(2013, 9, 16, 0, 0, Grain::Day),
+    );
+    LangCorpus {
+        dim: DimensionKind::Time,
+        corpus,
+    }
+}
+
+pub(super) fn time_es_ar() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::ES, Some(Region::AR)));
+    corpus.add(vec!["3/4"], datetime(2013, 4, 3, 0, 0, Grain::Day));
+    corpus.add(vec!["3/4/2014"], datetime(2014, 4, 3, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["día de la independencia"],
+        datetime(2013, 7, 9, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["revolución de mayo"],
+        datetime(2013, 5, 25, 0, 0, Grain::Day),
+    );
+    LangCorpus {
+        dim: DimensionKind::Time,
+        corpus,
+    }
+}
+
+pub(super) fn time_es_es() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::ES, Some(Region::ES)));
+    corpus.add(vec!["3/4"], datetime(2013, 4, 3, 0, 0, Grain::Day));
+    corpus.add(vec!["3/4/2014"], datetime(2014, 4, 3, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["día de la hispanidad"],
+        datetime(2013, 10, 12, 0, 0, Grain::Day),
+    );
+    corpus.add_negative(vec!["día de muertos"]);
+    LangCorpus {
+        dim: DimensionKind::Time,
+        corpus,
+    }
+}
+
+pub(super) fn time_fr_ca() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::FR, Some(Region::CA)));
+    corpus.add(vec!["3/4"], datetime(2013, 4, 3, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["15 h 30"],
+        datetime(2013, 2, 12, 15, 30, Grain::Minute),
+    );
+    corpus.add(vec!["15 h"], datetime(2013, 2, 12, 15, 0, Grain::Minute));
+    corpus.add(
+        vec!["la Saint-Jean-Baptiste"],
+        datetime(2013, 6, 24, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["l'action de grâce"],
+        datetime(2013, 10, 14, 0, 0, Grain::Day),
+    );
+    LangCorpus {
+        dim: DimensionKind::Time,
+        corpus,
+    }
+}
+
+pub(super) fn time_fr_be() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::FR, Some(Region::BE)));
+    corpus.add(vec!["3/4"], datetime(2013, 4, 3, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["fête nationale"],
+        datetime(2013, 7, 21, 0, 0, Grain::Day),
+    );
+    corpus.add_negative(vec!["la Saint-Jean-Baptiste"]);
+    LangCorpus {
+        dim: DimensionKind::Time,
+        corpus,
+    }
+}
+
+pub(super) fn time_fr_ch() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::FR, Some(Region::CH)));
+    corpus.add(vec!["3/4"], datetime(2013, 4, 3, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["fête nationale"],
+        datetime(2013, 8, 1, 0, 0, Grain::Day),
+    );
+    LangCorpus {
+        dim: DimensionKind::Time,
+        corpus,
+    }
+}
+
+/// "this weekend": Friday 18:00 to Monday 00:00.
+fn weekend() -> impl Fn(&Entity) -> bool {
+    datetime_interval((2013, 2, 15, 18, 0), (2013, 2, 18, 0, 0), Grain::Hour)
+}
+
+pub(super) fn time_zh_cn() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::ZH, Some(Region::CN)));
+    corpus.add(vec!["国庆"], datetime(2013, 10, 1, 0, 0, Grain::Day));
+    corpus.add(vec!["周末"], weekend());
+    LangCorpus {
+        dim: DimensionKind::Time,
+        corpus,
+    }
+}
+
+pub(super) fn time_zh_tw() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::ZH, Some(Region::TW)));
+    corpus.add(
+        vec!["國慶日", "雙十節"],
+        datetime(2013, 10, 10, 0, 0, Grain::Day),
+    );
+    corpus.add(vec!["和平紀念日"], datetime(2013, 2, 28, 0, 0, Grain::Day));
+    corpus.add(vec!["雙十一"], datetime(2013, 11, 11, 0, 0, Grain::Day));
+    corpus.add(vec!["週末"], weekend());
+    corpus.add_negative(vec!["回歸紀念日"]);
+    LangCorpus {
+        dim: DimensionKind::Time,
+        corpus,
+    }
+}
+
+pub(super) fn time_zh_hk() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::ZH, Some(Region::HK)));
+    corpus.add(vec!["國慶"], datetime(2013, 10, 1, 0, 0, Grain::Day));
+    corpus.add(vec!["回歸紀念日"], datetime(2013, 7, 1, 0, 0, Grain::Day));
+    corpus.add(vec!["這個週末"], weekend());
+    corpus.add(vec!["琴日"], datetime(2013, 2, 11, 0, 0, Grain::Day));
+    LangCorpus {
+        dim: DimensionKind::Time,
+        corpus,
+    }
+}
+
+pub(super) fn time_zh_mo() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::ZH, Some(Region::MO)));
+    corpus.add(vec!["回歸紀念日"], datetime(2013, 12, 20, 0, 0, Grain::Day));
+    LangCorpus {
+        dim: DimensionKind::Time,
+        corpus,
+    }
+}
+
+pub(super) fn time_fa() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::FA, None));
+    corpus.add(vec!["امروز"], datetime(2013, 2, 12, 0, 0, Grain::Day));
+    corpus.add(vec!["فردا"], datetime(2013, 2, 13, 0, 0, Grain::Day));
+    corpus.add(vec!["دیروز"], datetime(2013, 2, 11, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["پس\u{200c}فردا"],
+        datetime(2013, 2, 14, 0, 0, Grain::Day),
+    );
+    corpus.add(
+        vec!["سه\u{200c}شنبه آینده", "سه شنبه آینده"],
+        datetime(2013, 2, 19, 0, 0, Grain::Day),
+    );
+    corpus.add(vec!["جمعه گذشته"], datetime(2013, 2, 8, 0, 0, Grain::Day));
+    corpus.add(vec!["هفته آینده"], datetime(2013, 2, 18, 0, 0, Grain::Week));
+    corpus.add(vec!["۵ مارس"], datetime(2013, 3, 5, 0, 0, Grain::Day));
+    corpus.add(vec!["۵ مارس ۲۰۱۴"], datetime(2014, 3, 5, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["ساعت ۵ بعد از ظهر"],
+        datetime(2013, 2, 12, 17, 0, Grain::Hour),
+    );
+    corpus.add(
+        vec!["ساعت پنج و نیم"],
+        datetime(2013, 2, 12, 5, 30, Grain::Minute),
+    );
+    corpus.add(vec!["۱۷:۳۰"], datetime(2013, 2, 12, 17, 30, Grain::Minute));
+    corpus.add(
+        vec!["ساعت یه ربع به پنج"],
+        datetime(2013, 2, 12, 4, 45, Grain::Minute),
+    );
+    corpus.add(
+        vec!["ده دقیقه به پنج"],
+        datetime(2013, 2, 12, 4, 50, Grain::Minute),
+    );
+    corpus.add(
+        vec!["ساعت پنج و ده دقیقه"],
+        datetime(2013, 2, 12, 5, 10, Grain::Minute),
+    );
+    corpus.add(
+        vec!["نوروز", "عید نوروز"],
+        datetime(2013, 3, 21, 0, 0, Grain::Day),
+    );
+    LangCorpus {
+        dim: DimensionKind::Time,
+        corpus,
+    }
+}
+
+pub(super) fn time_id() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::ID, None));
+    corpus.add(vec!["hari ini"], datetime(2013, 2, 12, 0, 0, Grain::Day));
+    corpus.add(vec!["besok"], datetime(2013, 2, 13, 0, 0, Grain::Day));
+    corpus.add(vec!["kemarin"], datetime(2013, 2, 11, 0, 0, Grain::Day));
+    corpus.add(vec!["lusa"], datetime(2013, 2, 14, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["Selasa depan"],
+        datetime(2013, 2, 19, 0, 0, Grain::Day),
+    );
+    corpus.add(vec!["Jumat lalu"], datetime(2013, 2, 8, 0, 0, Grain::Day));
+    corpus.add(vec!["hari Minggu"], datetime(2013, 2, 17, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["minggu depan"],
+        datetime(2013, 2, 18, 0, 0, Grain::Week),
+    );
+    corpus.add(vec!["3 Mei 2025"], datetime(2025, 5, 3, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["tanggal 17 Agustus"],
+        datetime(2013, 8, 17, 0, 0, Grain::Day),
+    );
+    corpus.add(vec!["3/4"], datetime(2013, 4, 3, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["jam 3 sore"],
+        datetime(2013, 2, 12, 15, 0, Grain::Hour),
+    );
+    corpus.add(vec!["jam 8 pagi"], datetime(2013, 2, 12, 8, 0, Grain::Hour));
+    corpus.add(
+        vec!["pukul 15.30"],
+        datetime(2013, 2, 12, 15, 30, Grain::Minute),
+    );
+    corpus.add(
+        vec!["jam 5 kurang seperempat"],
+        datetime(2013, 2, 12, 4, 45, Grain::Minute),
+    );
+    corpus.add(
+        vec!["jam 3 lewat seperempat"],
+        datetime(2013, 2, 12, 15, 15, Grain::Minute),
+    );
+    corpus.add(
+        vec!["jam 5 kurang 10 menit"],
+        datetime(2013, 2, 12, 4, 50, Grain::Minute),
+    );
+    corpus.add(
+        vec!["jam 5 lewat 20"],
+        datetime(2013, 2, 12, 5, 20, Grain::Minute),
+    );
+    LangCorpus {
+        dim: DimensionKind::Time,
+        corpus,
+    }
+}
+
+pub(super) fn time_th() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::TH, None));
+    corpus.add(vec!["พรุ่งนี้"], datetime(2013, 2, 13, 0, 0, Grain::Day));
+    corpus.add(vec!["เมื่อวาน"], datetime(2013, 2, 11, 0, 0, Grain::Day));
+    corpus.add(vec!["มะรืนนี้"], datetime(2013, 2, 14, 0, 0, Grain::Day));
+    corpus.add(vec!["วันอังคารหน้า"], datetime(2013, 2, 19, 0, 0, Grain::Day));
+    corpus.add(vec!["วันศุกร์ที่แล้ว"], datetime(2013, 2, 8, 0, 0, Grain::Day));
+    corpus.add(vec!["อาทิตย์หน้า"], datetime(2013, 2, 18, 0, 0, Grain::Week));
+    // Buddhist-era years, marked or not, in Thai or ASCII digits
+    corpus.add(
+        vec![
+            "13 เมษายน 2568",
+            "13 เมษายน พ.ศ. 2568",
+            "วันที่ 13 เม.ย. 2568",
+            "๑๓ เมษายน ๒๕๖๘",
+            "13 เมษายน 2025",
+        ],
+        datetime(2025, 4, 13, 0, 0, Grain::Day),
+    );
+    corpus.add(vec!["ค.ศ. 2025"], datetime(2025, 1, 1, 0, 0, Grain::Year));
+    corpus.add(vec!["3/4"], datetime(2013, 4, 3, 0, 0, Grain::Day));
+    LangCorpus {
+        dim: DimensionKind::Time,
+        corpus,
+    }
+}
+
+pub(super) fn time_lo() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::LO, None));
+    corpus.add(vec!["ມື້ອື່ນ"], datetime(2013, 2, 13, 0, 0, Grain::Day));
+    corpus.add(vec!["ມື້ວານ"], datetime(2013, 2, 11, 0, 0, Grain::Day));
+    corpus.add(vec!["ວັນອັງຄານໜ້າ"], datetime(2013, 2, 19, 0, 0, Grain::Day));
+    corpus.add(
+        vec!["13 ເມສາ 2568", "໑໓ ເດືອນເມສາ ພ.ສ. ໒໕໖໘"],
+        datetime(2025, 4, 13, 0, 0, Grain::Day),
+    );
+    LangCorpus {
+        dim: DimensionKind::Time,
+        corpus,
+    }
+}
+
+pub(super) fn time_km() -> LangCorpus {
+    let mut corpus = Corpus::new(test_context(Lang::KM, None));
+    corpus.add(vec!["ស្អែក"], datetime(2013, 2, 13, 0, 0, Grain::Day));
+    corpus.add(vec!["ម្សិលមិញ"], datetime(2013, 2, 11, 0, 0, Grain::Day));
+    corpus.add(vec!["ខានស្អែក"], datetime(2013, 2, 14, 0, 0, Grain::Day));
+    corpus.add(vec!["ថ្ងៃអង្គារក្រោយ"], datetime(2013, 2, 19, 0, 0, Grain::Day));
+    // The Cambodian Buddhist era is a year ahead of the Thai one
+    corpus.add(
+        vec!["ថ្ងៃទី១៣ ខែមេសា ឆ្នាំ២០២៥", "១៣ មេសា ២៥៦៩"],
+        datetime(2025, 4, 13, 0, 0, Grain::Day),
+    );
+    LangCorpus {
+        dim: DimensionKind::Time,
+        corpus,
+    }
+}
@@ -1,3 +1,5 @@
+pub mod corpus;
+
 use crate::engine;
 use crate::resolve::{Context, Options};
 use crate::types::{DimensionKind, Entity, Rule};
@@ -8,6 +10,8 @@ type ExampleCheck = Box<dyn Fn(&Entity) -> bool>;
 pub struct Corpus {
     pub context: Context,
     pub examples: Vec<(Vec<String>, ExampleCheck)>,
+    /// Texts that must not produce any entity for the checked dimensions.
+    pub negatives: Vec<String>,
 }
 
 impl Corpus {
@@ -15,6 +19,7 @@ impl Corpus {
         Corpus {
             context,
             examples: Vec::new(),
+            negatives: Vec::new(),
         }
     }
 
@@ -26,23 +31,44 @@ impl Corpus {
         let texts: Vec<String> = texts.into_iter().map(String::from).collect();
         self.examples.push((texts, Box::new(check)));
     }
+
+    /// Add example texts that should produce no entity at all.
+    pub fn add_negative(&mut self, texts: Vec<&str>) {
+        self.negatives.extend(texts.into_iter().map(String::from));
+    }
+
+    /// Number of positive and negative texts in the corpus.
+    pub fn len(&self) -> usize {
+        self.examples
+            .iter()
+            .map(|(texts, _)| texts.len())
+            .sum::<usize>()
+            .saturating_add(self.negatives.len())
+    }
 }
 
-/// Check a corpus against a set of rules and return failures.
+/// Check a corpus against a set of rules and return failures. As in
+/// Haskell's corpus tests, an example only counts when an entity spans the
+/// whole text.
 pub fn check_corpus(corpus: &Corpus, rules: &[Rule], dims: &[DimensionKind]) -> Vec<String> {
-    let options = Options { with_latent: false };
+    let options = Options {
+        with_latent: false,
+        ..Options::default()
+    };
     let mut failures = Vec::new();
+    let dim_str = dims
+        .iter()
+        .map(|d| format!("{:?}", d))
+        .collect::<Vec<_>>()
+        .join(", ");
 
     for (texts, check) in &corpus.examples {
         for text in texts {
             let entities = engine::parse_and_resolve(text, rules, &corpus.context, &options, dims);
-            let any_match = entities.iter().any(check);
+            let any_match = entities
+                .iter()
+                .any(|e| e.start == 0 && e.end == text.len() && check(e));
             if !any_match {
-                let dim_str = dims
-                    .iter()
-                    .map(|d| format!("{:?}", d))
-                    .collect::<Vec<_>>()
-                    .join(", ");
                 failures.push(format!(
                     "FAIL: \"{}\" - expected match for [{}], got {} entities: {:?}",
                     text,
@@ -57,5 +83,21 @@ pub fn check_corpus(corpus: &Corpus, rules: &[Rule], dims: &[DimensionKind]) ->
         }
     }
 
+    for text in &corpus.negatives {
+        let entities = engine::parse_and_resolve(text, rules, &corpus.context, &options, dims);
+        if !entities.is_empty() {
+            failures.push(format!(
+                "FAIL: \"{}\" - expected no match for [{}], got {} entities: {:?}",
+                text,
+                dim_str,
+                entities.len(),
+                entities
+                    .iter()
+                    .map(|e| format!("{}({:?})", e.value.dim_kind(), e.value))
+                    .collect::<Vec<_>>()
+            ));
+        }
+    }
+
     failures
 }
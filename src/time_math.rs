@@ -0,0 +1,43 @@
+//! Calendar arithmetic by [`Grain`], the same steps the parser takes when it
+//! resolves relative times like "in 3 months" or "next week".
+//!
+//! # Example
+//! ```
+//! use chrono::{TimeZone, Utc};
+//! use duckling::time_math::{add_grain, grain_end, grain_start};
+//! use duckling::Grain;
+//!
+//! let jan_31 = Utc.with_ymd_and_hms(2013, 1, 31, 10, 30, 0).unwrap();
+//! // Months clamp to the last day of the target month.
+//! assert_eq!(
+//!     add_grain(jan_31, Grain::Month, 1),
+//!     Some(Utc.with_ymd_and_hms(2013, 2, 28, 10, 30, 0).unwrap())
+//! );
+//! // Weeks start on Monday.
+//! assert_eq!(grain_start(jan_31, Grain::Week), Utc.with_ymd_and_hms(2013, 1, 28, 0, 0, 0).unwrap());
+//! assert_eq!(grain_end(jan_31, Grain::Quarter), Some(Utc.with_ymd_and_hms(2013, 4, 1, 0, 0, 0).unwrap()));
+//! ```
+
+use chrono::{DateTime, Utc};
+
+use crate::dimensions::time;
+use crate::dimensions::time_grain::Grain;
+
+/// `dt` moved by `n` units of `grain` (negative `n` moves back). Months,
+/// quarters and years keep the day of month, clamped to the month's length.
+/// `None` if the result is out of range.
+pub fn add_grain(dt: DateTime<Utc>, grain: Grain, n: i64) -> Option<DateTime<Utc>> {
+    time::add_grain(dt, grain, n)
+}
+
+/// The start of the `grain` unit containing `dt`: midnight for days, Monday
+/// for weeks, the first of the month for months, and so on.
+pub fn grain_start(dt: DateTime<Utc>, grain: Grain) -> DateTime<Utc> {
+    time::grain_start(dt, grain)
+}
+
+/// The start of the `grain` unit after the one containing `dt`, i.e. the
+/// exclusive end of the unit; `None` if out of range.
+pub fn grain_end(dt: DateTime<Utc>, grain: Grain) -> Option<DateTime<Utc>> {
+    add_grain(grain_start(dt, grain), grain, 1)
+}
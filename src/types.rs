@@ -1,21 +1,30 @@
 use std::fmt;
 use std::rc::Rc;
 
-use crate::dimensions::amount_of_money::AmountOfMoneyData;
+use smallvec::SmallVec;
+
+use crate::dimensions::age::{AgeData, AgeUnit};
+use crate::dimensions::amount_of_money::{AmountOfMoneyData, MoneyCurrency};
+use crate::dimensions::coordinate::CoordinateData;
 use crate::dimensions::credit_card_number::CreditCardNumberData;
-use crate::dimensions::distance::DistanceData;
-use crate::dimensions::duration::DurationData;
+use crate::dimensions::distance::{DistanceData, DistanceUnit};
+use crate::dimensions::duration::{DurationData, DurationQualifier};
 use crate::dimensions::email::EmailData;
+#[cfg(feature = "medical")]
+use crate::dimensions::medical::{BloodPressureData, DosageData, DoseFrequency, DoseUnit};
+use crate::dimensions::network_address::NetworkAddressData;
 use crate::dimensions::numeral::NumeralData;
 use crate::dimensions::ordinal::OrdinalData;
+use crate::dimensions::percentage::PercentageData;
 use crate::dimensions::phone_number::PhoneNumberData;
-use crate::dimensions::quantity::QuantityData;
-use crate::dimensions::temperature::TemperatureData;
-use crate::dimensions::time::TimeData;
+use crate::dimensions::quantity::{QuantityData, QuantityUnit};
+use crate::dimensions::rate::{RateData, RatePer};
+use crate::dimensions::temperature::{TemperatureData, TemperatureUnit};
+use crate::dimensions::time::{add_grain, grain_start, TimeData};
 use crate::dimensions::time_grain::Grain;
 use crate::dimensions::url::UrlData;
-use crate::dimensions::volume::VolumeData;
-use chrono::{DateTime, NaiveDateTime, Utc};
+use crate::dimensions::volume::{VolumeData, VolumeUnit};
+use chrono::{DateTime, Duration, FixedOffset, NaiveDateTime, SecondsFormat, Utc};
 
 /// The kind of dimension to extract from text.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -32,17 +41,33 @@ pub enum DimensionKind {
     Volume,
     /// Quantities with product: "5 pounds of sugar"
     Quantity,
+    /// Ages of people: "32 years old", "in her 40s", "aged 5-7"
+    Age,
     /// Money: "$42.50", "3 euros"
     AmountOfMoney,
+    /// Percentages: "15%", "fifteen percent", "a quarter of a percent"
+    Percentage,
+    /// Frequencies and rates: "twice a week", "every 6 hours", "60 km/h"
+    Rate,
+    /// Blood pressure readings: "120/80", "BP 130 over 85"
+    #[cfg(feature = "medical")]
+    BloodPressure,
+    /// Medication doses: "5 mg twice daily", "2 puffs q4h"
+    #[cfg(feature = "medical")]
+    Dosage,
     /// Email addresses: "user@example.com"
     Email,
     /// Phone numbers: "(555) 123-4567"
     PhoneNumber,
     /// URLs: `"https://example.com"`
     Url,
+    /// IPv4, IPv6 and MAC addresses: "192.168.0.1", "2001:db8::1"
+    NetworkAddress,
+    /// Geographic coordinates: "40.7128° N, 74.0060° W", "40.7128,-74.0060"
+    Coordinate,
     /// Credit card numbers
     CreditCardNumber,
-    /// Time grains: "day", "week", "month"
+    /// Time grains and recurrence units: "day", "weekly", "per month"
     TimeGrain,
     /// Durations: "3 days", "2 hours"
     Duration,
@@ -50,6 +75,39 @@ pub enum DimensionKind {
     Time,
 }
 
+impl DimensionKind {
+    /// Parses a dimension name as printed by `Display` ("time",
+    /// "amount-of-money"), the names Duckling uses for its dims.
+    pub fn from_name(name: &str) -> Option<DimensionKind> {
+        Some(match name {
+            "number" => DimensionKind::Numeral,
+            "ordinal" => DimensionKind::Ordinal,
+            "temperature" => DimensionKind::Temperature,
+            "distance" => DimensionKind::Distance,
+            "volume" => DimensionKind::Volume,
+            "quantity" => DimensionKind::Quantity,
+            "age" => DimensionKind::Age,
+            "amount-of-money" => DimensionKind::AmountOfMoney,
+            "percentage" => DimensionKind::Percentage,
+            "rate" => DimensionKind::Rate,
+            #[cfg(feature = "medical")]
+            "blood-pressure" => DimensionKind::BloodPressure,
+            #[cfg(feature = "medical")]
+            "dosage" => DimensionKind::Dosage,
+            "email" => DimensionKind::Email,
+            "phone-number" => DimensionKind::PhoneNumber,
+            "url" => DimensionKind::Url,
+            "network-address" => DimensionKind::NetworkAddress,
+            "coordinate" => DimensionKind::Coordinate,
+            "credit-card-number" => DimensionKind::CreditCardNumber,
+            "time-grain" => DimensionKind::TimeGrain,
+            "duration" => DimensionKind::Duration,
+            "time" => DimensionKind::Time,
+            _ => return None,
+        })
+    }
+}
+
 impl fmt::Display for DimensionKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let s = match self {
@@ -59,10 +117,19 @@ impl fmt::Display for DimensionKind {
             DimensionKind::Distance => "distance",
             DimensionKind::Volume => "volume",
             DimensionKind::Quantity => "quantity",
+            DimensionKind::Age => "age",
             DimensionKind::AmountOfMoney => "amount-of-money",
+            DimensionKind::Percentage => "percentage",
+            DimensionKind::Rate => "rate",
+            #[cfg(feature = "medical")]
+            DimensionKind::BloodPressure => "blood-pressure",
+            #[cfg(feature = "medical")]
+            DimensionKind::Dosage => "dosage",
             DimensionKind::Email => "email",
             DimensionKind::PhoneNumber => "phone-number",
             DimensionKind::Url => "url",
+            DimensionKind::NetworkAddress => "network-address",
+            DimensionKind::Coordinate => "coordinate",
             DimensionKind::CreditCardNumber => "credit-card-number",
             DimensionKind::TimeGrain => "time-grain",
             DimensionKind::Duration => "duration",
@@ -73,14 +140,14 @@ impl fmt::Display for DimensionKind {
 }
 
 /// A measurement with a numeric value and unit. Used by Temperature, Distance,
-/// Volume, Quantity, and AmountOfMoney dimensions.
+/// Volume, Quantity, Age, and AmountOfMoney dimensions.
 ///
 /// ```
-/// use duckling::{parse_en, DimensionKind, DimensionValue, MeasurementValue};
+/// use duckling::{parse_en, DimensionKind, DimensionValue, MeasurementValue, MoneyCurrency};
 ///
 /// let results = parse_en("$42.50", &[DimensionKind::AmountOfMoney]);
 /// assert_eq!(results[0].value, DimensionValue::AmountOfMoney(MeasurementValue::Value {
-///     value: 42.5, unit: "USD".into(),
+///     value: 42.5, unit: MoneyCurrency::USD.into(),
 /// }));
 /// ```
 #[derive(Debug, Clone, PartialEq, serde::Serialize)]
@@ -89,8 +156,8 @@ pub enum MeasurementValue {
     Value {
         /// The numeric value.
         value: f64,
-        /// The unit (e.g. "fahrenheit", "mile", "USD").
-        unit: String,
+        /// The unit (e.g. fahrenheit, mile, USD).
+        unit: Unit,
     },
     /// A range of measurements (e.g. "between 3 and 5 dollars").
     Interval {
@@ -107,7 +174,123 @@ pub struct MeasurementPoint {
     /// The numeric value.
     pub value: f64,
     /// The unit.
-    pub unit: String,
+    pub unit: Unit,
+}
+
+impl MeasurementValue {
+    /// The unit of the measurement, or of its first bound for an interval.
+    pub fn unit(&self) -> Option<Unit> {
+        match self {
+            MeasurementValue::Value { unit, .. } => Some(*unit),
+            MeasurementValue::Interval { from, to } => {
+                from.as_ref().or(to.as_ref()).map(|p| p.unit)
+            }
+        }
+    }
+}
+
+/// The unit of a [`MeasurementValue`], typed by the dimension it measures.
+///
+/// Units serialize, print and compare against strings by their name, so
+/// code written against string units keeps working:
+///
+/// ```
+/// use duckling::{parse_en, DimensionKind, DimensionValue, TemperatureUnit, Unit};
+///
+/// let value = &parse_en("80 degrees fahrenheit", &[DimensionKind::Temperature])[0].value;
+/// let DimensionValue::Temperature(m) = value else { panic!("expected a temperature") };
+/// assert_eq!(m.unit(), Some(Unit::Temperature(TemperatureUnit::Fahrenheit)));
+/// assert_eq!(m.unit().unwrap().as_str(), "fahrenheit");
+/// assert!(m.unit().unwrap() == "fahrenheit");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Unit {
+    /// A temperature unit.
+    Temperature(TemperatureUnit),
+    /// A distance unit.
+    Distance(DistanceUnit),
+    /// A volume unit.
+    Volume(VolumeUnit),
+    /// A quantity unit (weights and kitchen measures).
+    Quantity(QuantityUnit),
+    /// An age unit.
+    Age(AgeUnit),
+    /// A currency.
+    Money(MoneyCurrency),
+}
+
+impl Unit {
+    /// Returns the unit name (e.g. `"fahrenheit"`, `"mile"`, `"USD"`).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Unit::Temperature(u) => u.as_str(),
+            Unit::Distance(u) => u.as_str(),
+            Unit::Volume(u) => u.as_str(),
+            Unit::Quantity(u) => u.as_str(),
+            Unit::Age(u) => u.as_str(),
+            Unit::Money(c) => c.as_str(),
+        }
+    }
+}
+
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl serde::Serialize for Unit {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl PartialEq<str> for Unit {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Unit {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl From<TemperatureUnit> for Unit {
+    fn from(u: TemperatureUnit) -> Self {
+        Unit::Temperature(u)
+    }
+}
+
+impl From<DistanceUnit> for Unit {
+    fn from(u: DistanceUnit) -> Self {
+        Unit::Distance(u)
+    }
+}
+
+impl From<VolumeUnit> for Unit {
+    fn from(u: VolumeUnit) -> Self {
+        Unit::Volume(u)
+    }
+}
+
+impl From<QuantityUnit> for Unit {
+    fn from(u: QuantityUnit) -> Self {
+        Unit::Quantity(u)
+    }
+}
+
+impl From<AgeUnit> for Unit {
+    fn from(u: AgeUnit) -> Self {
+        Unit::Age(u)
+    }
+}
+
+impl From<MoneyCurrency> for Unit {
+    fn from(c: MoneyCurrency) -> Self {
+        Unit::Money(c)
+    }
 }
 
 /// A resolved time point — either an absolute UTC instant or a naive wall-clock time.
@@ -163,6 +346,66 @@ impl TimePoint {
             TimePoint::Instant { grain, .. } | TimePoint::Naive { grain, .. } => *grain,
         }
     }
+
+    /// The UTC instant of this point. Naive wall-clock values are read in a
+    /// timezone `offset_minutes` from UTC (as in [`Context`](crate::Context)).
+    pub fn to_utc(&self, offset_minutes: i32) -> DateTime<Utc> {
+        match self {
+            TimePoint::Instant { value, .. } => *value,
+            TimePoint::Naive { value, .. } => {
+                let utc = value.and_utc();
+                Duration::try_minutes(i64::from(offset_minutes))
+                    .and_then(|offset| utc.checked_sub_signed(offset))
+                    .unwrap_or(utc)
+            }
+        }
+    }
+
+    /// Format as RFC 3339 in a timezone `offset_minutes` from UTC, truncated
+    /// to the point's grain: a `full-date` ("2013-02-13") for day grains and
+    /// coarser, a `date-time` ("2013-02-13T15:00:00-02:00") otherwise.
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    /// use duckling::{Grain, TimePoint};
+    ///
+    /// let value = NaiveDate::from_ymd_opt(2013, 2, 13).unwrap().and_hms_opt(15, 0, 0).unwrap();
+    /// let hour = TimePoint::Naive { value, grain: Grain::Hour };
+    /// assert_eq!(hour.to_rfc3339(-120), "2013-02-13T15:00:00-02:00");
+    /// let month = TimePoint::Naive { value, grain: Grain::Month };
+    /// assert_eq!(month.to_rfc3339(-120), "2013-02-01");
+    /// ```
+    pub fn to_rfc3339(&self, offset_minutes: i32) -> String {
+        let offset = offset_minutes
+            .checked_mul(60)
+            .and_then(FixedOffset::east_opt)
+            .unwrap_or_else(|| FixedOffset::east_opt(0).unwrap());
+        let local = self.to_utc(offset_minutes).with_timezone(&offset);
+        let truncated = grain_start(local.naive_local().and_utc(), self.grain()).naive_utc();
+        if self.grain() >= Grain::Day {
+            truncated.date().to_string()
+        } else {
+            truncated
+                .and_local_timezone(offset)
+                .single()
+                .unwrap_or(local)
+                .to_rfc3339_opts(SecondsFormat::Secs, true)
+        }
+    }
+
+    /// The point one grain after this one, keeping its kind and grain.
+    fn grain_end(&self) -> Option<TimePoint> {
+        match self {
+            TimePoint::Instant { value, grain } => Some(TimePoint::Instant {
+                value: add_grain(*value, *grain, 1)?,
+                grain: *grain,
+            }),
+            TimePoint::Naive { value, grain } => Some(TimePoint::Naive {
+                value: add_grain(value.and_utc(), *grain, 1)?.naive_utc(),
+                grain: *grain,
+            }),
+        }
+    }
 }
 
 /// A resolved time value — either a single point or an interval.
@@ -204,6 +447,10 @@ pub struct IntervalEndpoints {
     pub to: Option<TimePoint>,
 }
 
+/// The bounds of a time interval as UTC instants, `None` on an open side,
+/// from [`DimensionValue::as_interval`].
+pub type UtcInterval = (Option<DateTime<Utc>>, Option<DateTime<Utc>>);
+
 /// A resolved time value — either a single point or an interval.
 /// Includes a `values` array of up to 3 next occurrences, matching Haskell's
 /// `TimeValue SingleTimeValue [SingleTimeValue]`.
@@ -220,6 +467,20 @@ pub enum TimeValue {
         /// Serialized as `"holidayBeta"` when present.
         #[serde(skip_serializing_if = "Option::is_none", rename = "holidayBeta")]
         holiday: Option<String>,
+        /// Whether an "(observed)" holiday was moved off a weekend to the
+        /// day it is observed. Serialized only when `true`.
+        #[serde(skip_serializing_if = "std::ops::Not::not")]
+        observed: bool,
+        /// Set when the time came from a duration prefix: "in 2 hours" is
+        /// [`Delayed`](DurationQualifier::Delayed). Serialized only when present.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        qualifier: Option<DurationQualifier>,
+        /// Both readings of a clock time without AM/PM, morning first: "at 7"
+        /// gives 07:00 and 19:00 on the resolved day. Filled in only with
+        /// [`Options::with_meridiem_candidates`](crate::Options::with_meridiem_candidates);
+        /// serialized only when non-empty.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        meridiem_candidates: Vec<TimePoint>,
     },
     /// A time interval with additional future occurrences.
     Interval {
@@ -233,11 +494,178 @@ pub enum TimeValue {
         /// Serialized as `"holidayBeta"` when present.
         #[serde(skip_serializing_if = "Option::is_none", rename = "holidayBeta")]
         holiday: Option<String>,
+        /// Set when the interval came from a duration prefix: "within 2
+        /// hours" is [`Bounded`](DurationQualifier::Bounded). Serialized only
+        /// when present.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        qualifier: Option<DurationQualifier>,
     },
 }
 
+impl TimeValue {
+    /// The half-open span `[from, to)` this value covers. A single point
+    /// covers one unit of its grain; `None` marks an unbounded side.
+    fn span(&self) -> (Option<TimePoint>, Option<TimePoint>) {
+        match self {
+            TimeValue::Single { value, .. } => (Some(value.clone()), value.grain_end()),
+            TimeValue::Interval { from, to, .. } => (from.clone(), to.clone()),
+        }
+    }
+
+    /// Whether this value is an interval rather than a single point.
+    pub fn is_interval(&self) -> bool {
+        matches!(self, TimeValue::Interval { .. })
+    }
+
+    /// The point itself, or the start of an interval (`None` when the
+    /// interval is open at the start, as in "before 5pm").
+    pub fn start(&self) -> Option<&TimePoint> {
+        match self {
+            TimeValue::Single { value, .. } => Some(value),
+            TimeValue::Interval { from, .. } => from.as_ref(),
+        }
+    }
+
+    /// The end of an interval; `None` for a single point or an interval
+    /// open at the end, as in "after 5pm".
+    pub fn end(&self) -> Option<&TimePoint> {
+        match self {
+            TimeValue::Single { .. } => None,
+            TimeValue::Interval { to, .. } => to.as_ref(),
+        }
+    }
+
+    /// The grain of the point, or of whichever interval endpoint is set,
+    /// preferring the start.
+    pub fn grain(&self) -> Option<Grain> {
+        self.start().or_else(|| self.end()).map(TimePoint::grain)
+    }
+
+    /// The start and optional end as UTC instants, or `None` when there is no
+    /// start. Naive values are read in a timezone `offset_minutes` from UTC.
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use duckling::{parse, Context, DimensionKind, Lang, Locale, Options};
+    ///
+    /// let locale = Locale::new(Lang::EN, None);
+    /// let context = Context {
+    ///     reference_time: Utc.with_ymd_and_hms(2013, 2, 12, 4, 30, 0).unwrap(),
+    ///     ..Context::default()
+    /// };
+    /// let results = parse("from 3pm to 5pm", &locale, &[DimensionKind::Time], &context, &Options::default());
+    /// let value = results[0].value.as_time().unwrap();
+    /// assert!(value.is_interval());
+    /// assert_eq!(value.to_utc_bounds(0), Some((
+    ///     Utc.with_ymd_and_hms(2013, 2, 12, 15, 0, 0).unwrap(),
+    ///     Some(Utc.with_ymd_and_hms(2013, 2, 12, 18, 0, 0).unwrap()),
+    /// )));
+    /// ```
+    pub fn to_utc_bounds(
+        &self,
+        offset_minutes: i32,
+    ) -> Option<(DateTime<Utc>, Option<DateTime<Utc>>)> {
+        let start = self.start()?.to_utc(offset_minutes);
+        Some((start, self.end().map(|end| end.to_utc(offset_minutes))))
+    }
+
+    /// Whether `instant` falls inside this value: within the grain of a
+    /// single point, or in `[from, to)` of an interval. Naive values are read
+    /// in a timezone `offset_minutes` from UTC.
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use duckling::{parse, Context, DimensionKind, DimensionValue, Lang, Locale, Options};
+    ///
+    /// let locale = Locale::new(Lang::EN, None);
+    /// let context = Context {
+    ///     reference_time: Utc.with_ymd_and_hms(2013, 2, 12, 4, 30, 0).unwrap(),
+    ///     ..Context::default()
+    /// };
+    /// let results = parse("from 3pm to 5pm", &locale, &[DimensionKind::Time], &context, &Options::default());
+    /// let DimensionValue::Time(value) = &results[0].value else { panic!("expected time") };
+    /// assert!(value.contains(Utc.with_ymd_and_hms(2013, 2, 12, 16, 0, 0).unwrap(), 0));
+    /// assert!(!value.contains(Utc.with_ymd_and_hms(2013, 2, 12, 19, 0, 0).unwrap(), 0));
+    /// ```
+    pub fn contains(&self, instant: DateTime<Utc>, offset_minutes: i32) -> bool {
+        let (from, to) = self.span();
+        from.is_none_or(|from| from.to_utc(offset_minutes) <= instant)
+            && to.is_none_or(|to| instant < to.to_utc(offset_minutes))
+    }
+
+    /// The interval covered by both values, or `None` when they don't
+    /// overlap. Endpoints keep the kind and grain of the value they came
+    /// from; naive values are compared in a timezone `offset_minutes` from UTC.
+    pub fn intersect(&self, other: &TimeValue, offset_minutes: i32) -> Option<TimeValue> {
+        let (a_from, a_to) = self.span();
+        let (b_from, b_to) = other.span();
+        let at = |p: &TimePoint| p.to_utc(offset_minutes);
+        let from = match (a_from, b_from) {
+            (Some(a), Some(b)) => Some(if at(&a) >= at(&b) { a } else { b }),
+            (a, b) => a.or(b),
+        };
+        let to = match (a_to, b_to) {
+            (Some(a), Some(b)) => Some(if at(&a) <= at(&b) { a } else { b }),
+            (a, b) => a.or(b),
+        };
+        if let (Some(f), Some(t)) = (&from, &to) {
+            if at(f) >= at(t) {
+                return None;
+            }
+        }
+        Some(TimeValue::Interval {
+            from: from.clone(),
+            to: to.clone(),
+            values: vec![IntervalEndpoints { from, to }],
+            holiday: None,
+            qualifier: None,
+        })
+    }
+}
+
 /// The resolved value of a parsed entity.
 ///
+/// Each variant is the value of one [`DimensionKind`], given by
+/// [`DimensionValue::kind`]. Variants with a Duckling dimension serialize to
+/// its JSON shape; the kind's `Display` form is Duckling's `dim` name:
+///
+/// | Variant | Duckling `dim` |
+/// |---|---|
+/// | `Numeral` | `number` |
+/// | `Ordinal` | `ordinal` |
+/// | `Temperature` | `temperature` |
+/// | `Distance` | `distance` |
+/// | `Volume` | `volume` |
+/// | `Quantity` | `quantity` |
+/// | `AmountOfMoney` | `amount-of-money` |
+/// | `Email` | `email` |
+/// | `PhoneNumber` | `phone-number` |
+/// | `Url` | `url` |
+/// | `CreditCardNumber` | `credit-card-number` |
+/// | `TimeGrain` | `time-grain` |
+/// | `Duration` | `duration` |
+/// | `Time` | `time` |
+///
+/// `Age`, `Percentage`, `Rate`, `NetworkAddress`, `Coordinate` and, with
+/// the `medical` feature, `BloodPressure` and `Dosage` have no Duckling
+/// counterpart.
+///
+/// New dimensions are added as new variants, so matches outside this crate
+/// need a catch-all arm. Match the variants you handle and send the rest
+/// to `_`, using [`DimensionValue::kind`] to tell them apart if needed:
+///
+/// ```
+/// use duckling::{parse_en, DimensionKind, DimensionValue};
+///
+/// let describe = |value: &DimensionValue| match value {
+///     DimensionValue::Numeral(n) => format!("the number {n}"),
+///     DimensionValue::Email(address) => format!("an email to {address}"),
+///     other => format!("a {} value", other.kind()),
+/// };
+/// let entities = parse_en("3 km", &[DimensionKind::Distance]);
+/// assert_eq!(describe(&entities[0].value), "a distance value");
+/// ```
+///
 /// ```
 /// use duckling::{parse_en, DimensionKind, DimensionValue, Grain};
 ///
@@ -248,12 +676,22 @@ pub enum TimeValue {
 ///     DimensionValue::Ordinal(3));
 ///
 /// assert_eq!(parse_en("3 days", &[DimensionKind::Duration])[0].value,
-///     DimensionValue::Duration { value: 3, grain: Grain::Day, normalized_seconds: 259200 });
+///     DimensionValue::Duration {
+///         value: 3,
+///         grain: Grain::Day,
+///         normalized_seconds: 259200,
+///         iso8601: "P3D".into(),
+///         qualifier: None,
+///     });
+///
+/// assert_eq!(parse_en("per month", &[DimensionKind::TimeGrain])[0].value,
+///     DimensionValue::TimeGrain(Grain::Month));
 ///
 /// assert_eq!(parse_en("user@example.com", &[DimensionKind::Email])[0].value,
 ///     DimensionValue::Email("user@example.com".into()));
 /// ```
 #[derive(Debug, Clone, PartialEq, serde::Serialize)]
+#[non_exhaustive]
 pub enum DimensionValue {
     /// A numeric value.
     Numeral(f64),
@@ -261,9 +699,13 @@ pub enum DimensionValue {
     Ordinal(i64),
     /// A temperature measurement.
     Temperature(MeasurementValue),
-    /// A distance measurement.
+    /// A distance measurement, in the unit written ("3 km" is 3 kilometre);
+    /// a compound ("5 feet 10 inches") is summed in metres.
     Distance(MeasurementValue),
-    /// A volume measurement.
+    /// A volume measurement, in the unit written ("750ml" is 750
+    /// millilitre); a compound ("1 litre and 250 ml") or a count of
+    /// containers ("two 500ml bottles") is summed in litres. Use
+    /// [`VolumeUnit::to_litres`] for the litres of any volume.
     Volume(MeasurementValue),
     /// A quantity with an optional product name.
     Quantity {
@@ -272,8 +714,44 @@ pub enum DimensionValue {
         /// The product (e.g. "sugar" in "5 pounds of sugar").
         product: Option<String>,
     },
+    /// A person's age, in years or months.
+    Age(MeasurementValue),
     /// An amount of money.
     AmountOfMoney(MeasurementValue),
+    /// A percentage, in percent (15.0 for "15%").
+    Percentage(f64),
+    /// A frequency or rate: 2 per week for "twice a week", 60 km per hour
+    /// for "60 km/h".
+    Rate {
+        /// How many (2 in "twice a week", 60 in "60 km/h").
+        count: f64,
+        /// What is counted, for rates of a measure (km in "60 km/h");
+        /// `None` for a number of occurrences. Serialized only when present.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        unit: Option<Unit>,
+        /// What the count is per.
+        per: RatePer,
+    },
+    /// A blood pressure reading, in mmHg.
+    #[cfg(feature = "medical")]
+    BloodPressure {
+        /// The systolic (upper) pressure.
+        systolic: u32,
+        /// The diastolic (lower) pressure.
+        diastolic: u32,
+    },
+    /// A medication dose, with how often it is taken when stated.
+    #[cfg(feature = "medical")]
+    Dosage {
+        /// The amount per dose (5 in "5 mg").
+        value: f64,
+        /// The unit of `value`.
+        unit: DoseUnit,
+        /// How often the dose is taken ("twice daily", "q6h"), if stated.
+        /// Serialized only when present.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        frequency: Option<DoseFrequency>,
+    },
     /// An email address.
     Email(String),
     /// A phone number.
@@ -285,6 +763,22 @@ pub enum DimensionValue {
         /// The domain.
         domain: String,
     },
+    /// An IP or MAC address.
+    NetworkAddress {
+        /// The canonical address: RFC 5952 form for IPv6, lowercase
+        /// colon-separated for MAC.
+        value: String,
+        /// The address kind: "ipv4", "ipv6" or "mac".
+        kind: String,
+    },
+    /// A geographic coordinate, in decimal degrees. A lone component
+    /// ("N40°42'46\"") gives a partial coordinate with the other part `None`.
+    Coordinate {
+        /// Latitude, positive north.
+        lat: Option<f64>,
+        /// Longitude, positive east.
+        lon: Option<f64>,
+    },
     /// A credit card number.
     CreditCardNumber {
         /// The card number.
@@ -292,7 +786,7 @@ pub enum DimensionValue {
         /// The card issuer (e.g. "visa", "mastercard").
         issuer: String,
     },
-    /// A time grain.
+    /// A time grain, e.g. the recurrence unit of "weekly" or "per month".
     TimeGrain(Grain),
     /// A duration.
     Duration {
@@ -302,14 +796,20 @@ pub enum DimensionValue {
         grain: Grain,
         /// The duration normalized to seconds.
         normalized_seconds: i64,
+        /// The duration as an ISO 8601 string (e.g. "PT1H30M").
+        iso8601: String,
+        /// Whether the phrase spans the duration ("for 2 hours"), when its
+        /// prefix says so. Serialized only when present.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        qualifier: Option<DurationQualifier>,
     },
     /// A time or date.
     Time(TimeValue),
 }
 
 impl DimensionValue {
-    /// Returns the [`DimensionKind`] for this value.
-    pub fn dim_kind(&self) -> DimensionKind {
+    /// The [`DimensionKind`] this value belongs to, for every variant.
+    pub fn kind(&self) -> DimensionKind {
         match self {
             DimensionValue::Numeral(_) => DimensionKind::Numeral,
             DimensionValue::Ordinal(_) => DimensionKind::Ordinal,
@@ -317,16 +817,206 @@ impl DimensionValue {
             DimensionValue::Distance(_) => DimensionKind::Distance,
             DimensionValue::Volume(_) => DimensionKind::Volume,
             DimensionValue::Quantity { .. } => DimensionKind::Quantity,
+            DimensionValue::Age(_) => DimensionKind::Age,
             DimensionValue::AmountOfMoney(_) => DimensionKind::AmountOfMoney,
+            DimensionValue::Percentage(_) => DimensionKind::Percentage,
+            DimensionValue::Rate { .. } => DimensionKind::Rate,
+            #[cfg(feature = "medical")]
+            DimensionValue::BloodPressure { .. } => DimensionKind::BloodPressure,
+            #[cfg(feature = "medical")]
+            DimensionValue::Dosage { .. } => DimensionKind::Dosage,
             DimensionValue::Email(_) => DimensionKind::Email,
             DimensionValue::PhoneNumber(_) => DimensionKind::PhoneNumber,
             DimensionValue::Url { .. } => DimensionKind::Url,
+            DimensionValue::NetworkAddress { .. } => DimensionKind::NetworkAddress,
+            DimensionValue::Coordinate { .. } => DimensionKind::Coordinate,
             DimensionValue::CreditCardNumber { .. } => DimensionKind::CreditCardNumber,
             DimensionValue::TimeGrain(_) => DimensionKind::TimeGrain,
             DimensionValue::Duration { .. } => DimensionKind::Duration,
             DimensionValue::Time(_) => DimensionKind::Time,
         }
     }
+
+    /// Same as [`DimensionValue::kind`].
+    pub fn dim_kind(&self) -> DimensionKind {
+        self.kind()
+    }
+
+    /// Length of a duration in seconds, or `None` for other dimensions.
+    /// Months, quarters and years count as 30, 90 and 365 days.
+    ///
+    /// ```
+    /// use duckling::{parse_en, DimensionKind};
+    ///
+    /// let value = &parse_en("1 hour and 30 minutes", &[DimensionKind::Duration])[0].value;
+    /// assert_eq!(value.as_seconds(), Some(5400));
+    /// assert_eq!(value.to_iso8601().as_deref(), Some("PT1H30M"));
+    /// ```
+    pub fn as_seconds(&self) -> Option<i64> {
+        match self {
+            DimensionValue::Duration {
+                normalized_seconds, ..
+            } => Some(*normalized_seconds),
+            _ => None,
+        }
+    }
+
+    /// The grain of a time (see [`TimeValue::grain`]), a duration's unit,
+    /// or a time grain itself; `None` for other dimensions.
+    ///
+    /// ```
+    /// use duckling::{parse_en, DimensionKind, Grain};
+    ///
+    /// let value = &parse_en("3 days", &[DimensionKind::Duration])[0].value;
+    /// assert_eq!(value.grain(), Some(Grain::Day));
+    /// ```
+    pub fn grain(&self) -> Option<Grain> {
+        match self {
+            DimensionValue::Time(value) => value.grain(),
+            DimensionValue::Duration { grain, .. } | DimensionValue::TimeGrain(grain) => {
+                Some(*grain)
+            }
+            _ => None,
+        }
+    }
+
+    /// The time value, or `None` for other dimensions.
+    pub fn as_time(&self) -> Option<&TimeValue> {
+        match self {
+            DimensionValue::Time(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// The number of a numeric value: a numeral, ordinal or percentage, an
+    /// exact measurement, a rate's count or a dose. `None` for ranges such
+    /// as "between 3 and 5 dollars" and for other dimensions.
+    ///
+    /// ```
+    /// use duckling::{parse_en, DimensionKind};
+    ///
+    /// let value = &parse_en("$42.50", &[DimensionKind::AmountOfMoney])[0].value;
+    /// assert_eq!(value.as_f64(), Some(42.5));
+    /// ```
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            DimensionValue::Numeral(value) | DimensionValue::Percentage(value) => Some(*value),
+            DimensionValue::Ordinal(value) => Some(*value as f64),
+            DimensionValue::Temperature(m)
+            | DimensionValue::Distance(m)
+            | DimensionValue::Volume(m)
+            | DimensionValue::Quantity { measurement: m, .. }
+            | DimensionValue::Age(m)
+            | DimensionValue::AmountOfMoney(m) => match m {
+                MeasurementValue::Value { value, .. } => Some(*value),
+                MeasurementValue::Interval { .. } => None,
+            },
+            DimensionValue::Rate { count, .. } => Some(*count),
+            #[cfg(feature = "medical")]
+            DimensionValue::Dosage { value, .. } => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// The text of an email, phone number, URL, network address or credit
+    /// card number, or `None` for other dimensions.
+    ///
+    /// ```
+    /// use duckling::{parse_en, DimensionKind};
+    ///
+    /// let value = &parse_en("mail alice@example.com", &[DimensionKind::Email])[0].value;
+    /// assert_eq!(value.as_str_value(), Some("alice@example.com"));
+    /// ```
+    pub fn as_str_value(&self) -> Option<&str> {
+        match self {
+            DimensionValue::Email(value)
+            | DimensionValue::PhoneNumber(value)
+            | DimensionValue::Url { value, .. }
+            | DimensionValue::NetworkAddress { value, .. }
+            | DimensionValue::CreditCardNumber { value, .. } => Some(value),
+            _ => None,
+        }
+    }
+
+    /// The UTC instant of a single time point, reading naive values in a
+    /// timezone `offset_minutes` from UTC; `None` for intervals and other
+    /// dimensions.
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use duckling::{parse, Context, DimensionKind, Lang, Locale, Options};
+    ///
+    /// let context = Context {
+    ///     reference_time: Utc.with_ymd_and_hms(2013, 2, 12, 4, 30, 0).unwrap(),
+    ///     ..Context::default()
+    /// };
+    /// let locale = Locale::new(Lang::EN, None);
+    /// let results = parse("tomorrow at 3pm", &locale, &[DimensionKind::Time], &context, &Options::default());
+    /// assert_eq!(
+    ///     results[0].value.as_datetime(-120),
+    ///     Some(Utc.with_ymd_and_hms(2013, 2, 13, 17, 0, 0).unwrap()),
+    /// );
+    /// ```
+    pub fn as_datetime(&self, offset_minutes: i32) -> Option<DateTime<Utc>> {
+        match self {
+            DimensionValue::Time(TimeValue::Single { value, .. }) => {
+                Some(value.to_utc(offset_minutes))
+            }
+            _ => None,
+        }
+    }
+
+    /// The bounds of a time interval as UTC instants, `None` on an open
+    /// side, reading naive values in a timezone `offset_minutes` from UTC;
+    /// `None` for single points and other dimensions.
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use duckling::{parse, Context, DimensionKind, Lang, Locale, Options};
+    ///
+    /// let context = Context {
+    ///     reference_time: Utc.with_ymd_and_hms(2013, 2, 12, 4, 30, 0).unwrap(),
+    ///     ..Context::default()
+    /// };
+    /// let locale = Locale::new(Lang::EN, None);
+    /// let results = parse("after 5pm", &locale, &[DimensionKind::Time], &context, &Options::default());
+    /// assert_eq!(
+    ///     results[0].value.as_interval(0),
+    ///     Some((Some(Utc.with_ymd_and_hms(2013, 2, 12, 17, 0, 0).unwrap()), None)),
+    /// );
+    /// ```
+    pub fn as_interval(&self, offset_minutes: i32) -> Option<UtcInterval> {
+        match self {
+            DimensionValue::Time(TimeValue::Interval { from, to, .. }) => Some((
+                from.as_ref().map(|p| p.to_utc(offset_minutes)),
+                to.as_ref().map(|p| p.to_utc(offset_minutes)),
+            )),
+            _ => None,
+        }
+    }
+
+    /// The unit of a measurement dimension (temperature, distance, volume,
+    /// quantity, age or amount of money), or `None` for other dimensions.
+    pub fn unit(&self) -> Option<Unit> {
+        match self {
+            DimensionValue::Temperature(m)
+            | DimensionValue::Distance(m)
+            | DimensionValue::Volume(m)
+            | DimensionValue::Quantity { measurement: m, .. }
+            | DimensionValue::Age(m)
+            | DimensionValue::AmountOfMoney(m) => m.unit(),
+            _ => None,
+        }
+    }
+
+    /// ISO 8601 form of a duration ("P3D", "PT1H30M"), or `None` for other
+    /// dimensions.
+    pub fn to_iso8601(&self) -> Option<String> {
+        match self {
+            DimensionValue::Duration { iso8601, .. } => Some(iso8601.clone()),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -337,10 +1027,19 @@ pub(crate) enum TokenData {
     Distance(DistanceData),
     Volume(VolumeData),
     Quantity(QuantityData),
+    Age(AgeData),
     AmountOfMoney(AmountOfMoneyData),
+    Percentage(PercentageData),
+    Rate(RateData),
+    #[cfg(feature = "medical")]
+    BloodPressure(BloodPressureData),
+    #[cfg(feature = "medical")]
+    Dosage(DosageData),
     Email(EmailData),
     PhoneNumber(PhoneNumberData),
     Url(UrlData),
+    NetworkAddress(NetworkAddressData),
+    Coordinate(CoordinateData),
     CreditCardNumber(CreditCardNumberData),
     TimeGrain(Grain),
     Duration(DurationData),
@@ -357,10 +1056,19 @@ impl TokenData {
             TokenData::Distance(_) => Some(DimensionKind::Distance),
             TokenData::Volume(_) => Some(DimensionKind::Volume),
             TokenData::Quantity(_) => Some(DimensionKind::Quantity),
+            TokenData::Age(_) => Some(DimensionKind::Age),
             TokenData::AmountOfMoney(_) => Some(DimensionKind::AmountOfMoney),
+            TokenData::Percentage(_) => Some(DimensionKind::Percentage),
+            TokenData::Rate(_) => Some(DimensionKind::Rate),
+            #[cfg(feature = "medical")]
+            TokenData::BloodPressure(_) => Some(DimensionKind::BloodPressure),
+            #[cfg(feature = "medical")]
+            TokenData::Dosage(_) => Some(DimensionKind::Dosage),
             TokenData::Email(_) => Some(DimensionKind::Email),
             TokenData::PhoneNumber(_) => Some(DimensionKind::PhoneNumber),
             TokenData::Url(_) => Some(DimensionKind::Url),
+            TokenData::NetworkAddress(_) => Some(DimensionKind::NetworkAddress),
+            TokenData::Coordinate(_) => Some(DimensionKind::Coordinate),
             TokenData::CreditCardNumber(_) => Some(DimensionKind::CreditCardNumber),
             TokenData::TimeGrain(_) => Some(DimensionKind::TimeGrain),
             TokenData::Duration(_) => Some(DimensionKind::Duration),
@@ -369,6 +1077,56 @@ impl TokenData {
         }
     }
 
+    /// The precision a qualifier rule recorded on a measurement or duration.
+    pub(crate) fn precision(&self) -> Option<Precision> {
+        match self {
+            TokenData::AmountOfMoney(data) => data.precision,
+            TokenData::Quantity(data) => data.precision,
+            TokenData::Distance(data) => data.precision,
+            TokenData::Volume(data) => data.precision,
+            TokenData::Duration(data) => data.precision,
+            _ => None,
+        }
+    }
+
+    /// The parts a compound measurement was written in, for
+    /// [`Entity::components`].
+    pub(crate) fn components(&self) -> Vec<MeasurementPoint> {
+        match self {
+            TokenData::Distance(data) => data
+                .components
+                .iter()
+                .map(|&(value, unit)| MeasurementPoint {
+                    value,
+                    unit: unit.into(),
+                })
+                .collect(),
+            TokenData::Volume(data) => data
+                .components
+                .iter()
+                .map(|&(value, unit)| MeasurementPoint {
+                    value,
+                    unit: unit.into(),
+                })
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// This token with `precision` recorded, for the dimensions that keep
+    /// one; other tokens are returned unchanged.
+    pub(crate) fn with_precision(mut self, precision: Option<Precision>) -> TokenData {
+        match &mut self {
+            TokenData::AmountOfMoney(data) => data.precision = precision,
+            TokenData::Quantity(data) => data.precision = precision,
+            TokenData::Distance(data) => data.precision = precision,
+            TokenData::Volume(data) => data.precision = precision,
+            TokenData::Duration(data) => data.precision = precision,
+            _ => {}
+        }
+        self
+    }
+
     pub(crate) fn is_latent(&self) -> bool {
         match self {
             TokenData::AmountOfMoney(data) => data.latent,
@@ -401,11 +1159,15 @@ impl Range {
     }
 }
 
+/// A node's children, one per pattern item; rule patterns rarely exceed four
+/// items, so they stay inline.
+pub(crate) type Children = SmallVec<[Rc<Node>; 4]>;
+
 #[derive(Debug, Clone)]
 pub(crate) struct Node {
     pub(crate) range: Range,
     pub(crate) token_data: TokenData,
-    pub(crate) children: Vec<Rc<Node>>,
+    pub(crate) children: Children,
     pub(crate) rule_name: Option<String>,
 }
 
@@ -414,7 +1176,7 @@ impl Node {
         Node {
             range,
             token_data,
-            children: Vec::new(),
+            children: Children::new(),
             rule_name: None,
         }
     }
@@ -424,7 +1186,7 @@ pub(crate) type Predicate = Box<dyn Fn(&TokenData) -> bool + Send + Sync>;
 pub(crate) type Production = Box<dyn Fn(&[&Node]) -> Option<TokenData> + Send + Sync>;
 
 pub(crate) enum PatternItem {
-    Regex(regex::Regex),
+    Regex(crate::pattern::LazyRegex),
     Dimension(DimensionKind),
     Predicate(Predicate),
 }
@@ -469,7 +1231,8 @@ pub(crate) struct ResolvedToken {
 ///
 /// assert_eq!(parse_en("I need 42 widgets", &[DimensionKind::Numeral]), vec![Entity {
 ///     body: "42".into(), start: 7, end: 9, latent: Some(false),
-///     value: DimensionValue::Numeral(42.0),
+///     value: DimensionValue::Numeral(42.0), metadata: None, grain: None, precision: None,
+///     components: vec![],
 /// }]);
 /// ```
 #[derive(Debug, Clone, PartialEq, serde::Serialize)]
@@ -485,4 +1248,262 @@ pub struct Entity {
     /// Whether this is a latent (ambiguous) match.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub latent: Option<bool>,
+    /// Provenance and suppressed alternates, filled in when
+    /// [`Options::with_metadata`](crate::Options::with_metadata) is set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<EntityMetadata>,
+    /// The value's grain, for dimensions that have one; see
+    /// [`DimensionValue::grain`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grain: Option<Grain>,
+    /// Whether the text qualified the value as exact ("exactly $100") or
+    /// approximate ("around $100"); `None` when it said neither.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub precision: Option<Precision>,
+    /// The measurements a compound value sums, in the order written: 5 foot
+    /// and 10 inch for "5 feet 10 inches", whose value is 1.778 metre, or two
+    /// 500 millilitre for "two 500ml bottles". Empty for a single
+    /// measurement.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub components: Vec<MeasurementPoint>,
+}
+
+impl Entity {
+    /// The value's number; see [`DimensionValue::as_f64`].
+    pub fn as_f64(&self) -> Option<f64> {
+        self.value.as_f64()
+    }
+
+    /// The value's text; see [`DimensionValue::as_str_value`].
+    pub fn as_str_value(&self) -> Option<&str> {
+        self.value.as_str_value()
+    }
+
+    /// The value's instant; see [`DimensionValue::as_datetime`].
+    pub fn as_datetime(&self, offset_minutes: i32) -> Option<DateTime<Utc>> {
+        self.value.as_datetime(offset_minutes)
+    }
+
+    /// The value's interval bounds; see [`DimensionValue::as_interval`].
+    pub fn as_interval(&self, offset_minutes: i32) -> Option<UtcInterval> {
+        self.value.as_interval(offset_minutes)
+    }
+
+    /// A hash of the entity's span, dimension and value, for matching
+    /// entities across parses, e.g. to diff results between versions.
+    ///
+    /// The hash is FNV-1a over a canonical encoding, so it is the same across
+    /// runs, threads and platforms; it changes only if the entity's span or
+    /// value does. The body and metadata are not part of it.
+    ///
+    /// ```
+    /// use duckling::{parse_en, DimensionKind};
+    ///
+    /// let a = parse_en("42 and 42", &[DimensionKind::Numeral]);
+    /// assert_ne!(a[0].id(), a[1].id());
+    /// assert_eq!(a[0].id(), parse_en("42", &[DimensionKind::Numeral])[0].id());
+    /// ```
+    pub fn id(&self) -> u64 {
+        const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+        let value = serde_json::to_string(&self.value).unwrap_or_default();
+        let key = format!(
+            "{}:{}:{}:{value}",
+            self.start,
+            self.end,
+            self.value.dim_kind()
+        );
+        key.bytes().fold(FNV_OFFSET, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+        })
+    }
+}
+
+/// Entities from [`parse_with_report`](crate::parse_with_report), and
+/// whether a safeguard in [`Options`](crate::Options) cut the parse short.
+///
+/// # Example
+/// ```
+/// use duckling::{parse_with_report, Context, DimensionKind, Lang, Locale, Options};
+///
+/// let options = Options::builder().max_input_len(7).build();
+/// let locale = Locale::new(Lang::EN, None);
+/// let report = parse_with_report("42 and 17", &locale, &[DimensionKind::Numeral], &Context::default(), &options);
+/// assert!(report.input_truncated);
+/// assert_eq!(report.entities.len(), 1);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize)]
+pub struct ParseReport {
+    /// The entities found; only those in the parsed part when cut short.
+    pub entities: Vec<Entity>,
+    /// The text exceeded [`Options::max_input_len`](crate::Options::max_input_len)
+    /// and only its prefix was parsed.
+    pub input_truncated: bool,
+    /// [`Options::time_budget`](crate::Options::time_budget) ran out before
+    /// parsing finished.
+    pub budget_exceeded: bool,
+}
+
+impl ParseReport {
+    /// Whether some of the text may hold entities that weren't returned.
+    pub fn is_partial(&self) -> bool {
+        self.input_truncated || self.budget_exceeded
+    }
+}
+
+/// Which rule pack produced an entity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleSource {
+    /// Language-independent rules shared by every locale (digits, emails, URLs...).
+    Common,
+    /// Rules for the entity's language.
+    Lang,
+    /// Region overlays on top of the language rules (e.g. en_IN, pt_BR).
+    Locale,
+}
+
+/// Extra information about how an [`Entity`] was produced.
+///
+/// ```
+/// use duckling::{parse, Context, DimensionKind, Lang, Locale, Options, RuleSource};
+///
+/// let locale = Locale::new(Lang::EN, None);
+/// let options = Options::builder().with_metadata(true).build();
+/// let dims = [DimensionKind::Time];
+/// let entities = parse("tomorrow", &locale, &dims, &Context::default(), &options);
+/// let metadata = entities[0].metadata.as_ref().unwrap();
+/// assert_eq!(metadata.source, Some(RuleSource::Lang));
+/// ```
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct EntityMetadata {
+    /// Name of the rule that produced the entity's top-level token.
+    pub rule: Option<String>,
+    /// The pack that rule belongs to.
+    pub source: Option<RuleSource>,
+    /// Interpretations inside this entity's span that ranking or overlap
+    /// removal dropped in its favour, e.g. the numeral "2" inside "2 hours".
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub alternates: Vec<Entity>,
+    /// The parsed form of a time entity, for re-resolving it later against
+    /// another reference time with [`resolve_entity`](crate::resolve_entity).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_expr: Option<TimeExpr>,
+}
+
+/// The parsed form of a time expression, before it is resolved against a
+/// reference time: "every other Friday at 3pm" rather than a date.
+///
+/// It serializes with serde, so it can be stored once and resolved again
+/// with [`resolve_entity`](crate::resolve_entity) whenever the reference time
+/// changes, without parsing the text again. The encoding is stable within a
+/// release but may change between releases.
+///
+/// ```
+/// use duckling::{parse, resolve_entity, Context, DimensionKind, Lang, Locale, Options};
+///
+/// let locale = Locale::new(Lang::EN, None);
+/// let options = Options::builder().with_metadata(true).build();
+/// let entities = parse("tomorrow", &locale, &[DimensionKind::Time], &Context::default(), &options);
+/// let expr = entities[0].metadata.as_ref().unwrap().time_expr.clone().unwrap();
+/// assert_eq!(
+///     resolve_entity(&expr, &Context::default(), &options),
+///     Some(entities[0].value.clone())
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+pub struct TimeExpr(pub(crate) TimeData);
+
+/// How precisely the text states a value, from a qualifier such as
+/// "exactly" or "about".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize)]
+pub enum Precision {
+    /// "exactly 3 hours", "precisely $20".
+    #[serde(rename = "exact")]
+    Exact,
+    /// "about 3 hours", "around $100", "~2 cups".
+    #[serde(rename = "approx")]
+    Approximate,
+}
+
+impl Precision {
+    /// Words, across languages, that mark a value as exact; any other
+    /// qualifier marks it approximate.
+    const EXACT_WORDS: &'static [&'static str] = &[
+        "exactly",
+        "precisely",
+        "exact",
+        "exakt",
+        "genau",
+        "präzise",
+        "precis",
+        "precies",
+        "esattamente",
+        "exactement",
+        "tam",
+        "точно",
+        "ровно",
+        "рівно",
+        "яг",
+    ];
+
+    /// The precision the qualifier word matched by `node` expresses, for
+    /// "about|exactly <dimension>" rules.
+    pub(crate) fn of_qualifier(node: &Node) -> Option<Precision> {
+        let TokenData::RegexMatch(m) = &node.token_data else {
+            return None;
+        };
+        let text = m.group(0)?.to_lowercase();
+        if text
+            .split_whitespace()
+            .any(|word| Precision::EXACT_WORDS.contains(&word))
+        {
+            Some(Precision::Exact)
+        } else {
+            Some(Precision::Approximate)
+        }
+    }
+}
+
+/// A note from [`parse_with_diagnostics`](crate::parse_with_diagnostics)
+/// about a candidate that resolution dropped or could not read as written.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    /// Byte offset of the candidate's start.
+    pub start: usize,
+    /// Byte offset of the candidate's end.
+    pub end: usize,
+    /// The candidate's text.
+    pub body: String,
+    /// The dimension the candidate belongs to.
+    pub dim: DimensionKind,
+    /// Name of the rule that produced the candidate.
+    pub rule: Option<String>,
+    /// What went wrong.
+    pub reason: DiagnosticReason,
+    /// Whether the candidate was dropped. `false` when it was still returned
+    /// with a fallback value, e.g. a holiday without a date for its year read
+    /// as the reference day.
+    pub dropped: bool,
+}
+
+/// Why a candidate was dropped or could not be read as written.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DiagnosticReason {
+    /// A year no date can be built for.
+    YearOutOfRange(i32),
+    /// A holiday that has no date for the year asked for, e.g. a lunar
+    /// holiday beyond the years its table covers.
+    HolidayYearUnknown {
+        /// The holiday's name.
+        name: String,
+        /// The year asked for.
+        year: i32,
+    },
+    /// A holiday name with no known date in any year.
+    UnknownHoliday(String),
+    /// A timezone with no known offset. The time is read in the
+    /// [`Context`](crate::Context)'s timezone instead.
+    UnsupportedTimezone(String),
 }
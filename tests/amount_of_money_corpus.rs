@@ -367,3 +367,293 @@ fn test_money_uah_3_04() {
     check_money("UAH 3.04", 3.04, "UAH");
     check_money("3 UAH 4 kopiykas", 3.04, "UAH");
 }
+
+#[test]
+fn test_money_iso_codes() {
+    check_money("USD 30", 30.0, "USD");
+    check_money("30 GBP", 30.0, "GBP");
+    check_money("PLN 12.50", 12.5, "PLN");
+}
+
+#[test]
+fn test_money_currency_symbols() {
+    check_money("₹500", 500.0, "INR");
+    check_money("₽ 300", 300.0, "RUB");
+    check_money("₩1000", 1000.0, "KRW");
+    check_money("₺40", 40.0, "TRY");
+    check_money("R$ 20", 20.0, "BRL");
+    check_money("5000₫", 5000.0, "VND");
+    check_money("20 zł", 20.0, "PLN");
+    check_money("AU$10", 10.0, "AUD");
+    check_money("CA$ 3", 3.0, "CAD");
+    check_money("C$5", 5.0, "CAD");
+    check_money("NZ$7", 7.0, "NZD");
+    check_money("250 rand", 250.0, "ZAR");
+}
+
+#[test]
+fn test_money_dollar_follows_region() {
+    use chrono::{TimeZone, Utc};
+    use duckling::{parse, Context, Lang, Locale, Options, Region};
+
+    for (region, expected_unit) in [
+        (None, "USD"),
+        (Some(Region::US), "USD"),
+        (Some(Region::AU), "AUD"),
+        (Some(Region::CA), "CAD"),
+        (Some(Region::NZ), "NZD"),
+    ] {
+        let locale = Locale::new(Lang::EN, region);
+        let context = Context {
+            reference_time: Utc.with_ymd_and_hms(2013, 2, 12, 4, 30, 0).unwrap(),
+            locale,
+            ..Context::default()
+        };
+        for text in ["$5", "5 dollars"] {
+            let entities = parse(
+                text,
+                &locale,
+                &[DimensionKind::AmountOfMoney],
+                &context,
+                &Options::default(),
+            );
+            let found = entities.iter().any(|e| {
+                matches!(&e.value, DimensionValue::AmountOfMoney(MeasurementValue::Value { value, unit })
+                    if (*value - 5.0).abs() < 0.01 && unit == expected_unit)
+            });
+            assert!(
+                found,
+                "Expected 5 {} for '{}' in {:?}, got: {:?}",
+                expected_unit, text, region, entities
+            );
+        }
+    }
+    // An explicit prefix wins over the region default.
+    let locale = Locale::new(Lang::EN, Some(Region::AU));
+    let context = Context {
+        locale,
+        ..Context::default()
+    };
+    let entities = parse(
+        "US$5",
+        &locale,
+        &[DimensionKind::AmountOfMoney],
+        &context,
+        &Options::default(),
+    );
+    assert!(
+        entities.iter().any(|e| matches!(&e.value, DimensionValue::AmountOfMoney(MeasurementValue::Value { unit, .. }) if unit == "USD")),
+        "Expected USD for 'US$5' in AU, got: {:?}",
+        entities
+    );
+}
+
+#[test]
+fn test_money_regional_packs() {
+    use chrono::{TimeZone, Utc};
+    use duckling::{parse, Context, Lang, Locale, Options, Region};
+
+    for (text, lang, region, expected_unit) in [
+        ("$100", Lang::ES, Some(Region::MX), "MXN"),
+        ("$100", Lang::ES, Some(Region::AR), "ARS"),
+        ("$100", Lang::ES, Some(Region::ES), "USD"),
+        ("$100", Lang::ES, None, "USD"),
+        ("100 pesos", Lang::ES, Some(Region::AR), "ARS"),
+        ("100 pesos", Lang::ES, Some(Region::MX), "MXN"),
+        ("100 pesos chilenos", Lang::ES, Some(Region::MX), "CLP"),
+        ("100 dólares", Lang::ES, Some(Region::MX), "USD"),
+        ("100 pavos", Lang::ES, Some(Region::ES), "EUR"),
+        ("$100", Lang::FR, Some(Region::CA), "CAD"),
+        ("100 piasses", Lang::FR, Some(Region::CA), "CAD"),
+        ("$100", Lang::FR, Some(Region::CH), "USD"),
+        ("100 paus", Lang::PT, Some(Region::BR), "BRL"),
+        ("100 paus", Lang::PT, Some(Region::PT), "EUR"),
+        ("100 Franken", Lang::DE, Some(Region::CH), "CHF"),
+        ("Fr. 100", Lang::DE, Some(Region::CH), "CHF"),
+        ("100元", Lang::ZH, Some(Region::CN), "CNY"),
+        ("100元", Lang::ZH, Some(Region::TW), "TWD"),
+        ("100元", Lang::ZH, Some(Region::HK), "HKD"),
+        ("100元", Lang::ZH, Some(Region::MO), "MOP"),
+        ("100元", Lang::ZH, None, "USD"),
+        ("100新台幣", Lang::ZH, Some(Region::HK), "TWD"),
+        ("۱۰۰ ریال", Lang::FA, None, "IRR"),
+        ("۱۰ تومان", Lang::FA, None, "IRR"),
+        ("١٠٠ دلار", Lang::FA, None, "USD"),
+    ] {
+        let locale = Locale::new(lang, region);
+        let context = Context {
+            reference_time: Utc.with_ymd_and_hms(2013, 2, 12, 4, 30, 0).unwrap(),
+            locale,
+            ..Context::default()
+        };
+        let entities = parse(
+            text,
+            &locale,
+            &[DimensionKind::AmountOfMoney],
+            &context,
+            &Options::default(),
+        );
+        let found = entities.iter().any(|e| {
+            matches!(&e.value, DimensionValue::AmountOfMoney(MeasurementValue::Value { value, unit })
+                if (*value - 100.0).abs() < 0.01 && unit == expected_unit)
+        });
+        assert!(
+            found,
+            "Expected 100 {} for '{}' in {:?}, got: {:?}",
+            expected_unit, text, region, entities
+        );
+    }
+}
+
+#[test]
+fn test_money_cents_composition() {
+    for (text, expected_val, expected_unit) in [
+        ("3 dollars and 50 cents", 3.5, "USD"),
+        ("$3 and 50 cents", 3.5, "USD"),
+        ("two pounds fifty", 2.5, "GBP"),
+        ("1 euro 99", 1.99, "EUR"),
+        ("€1 99", 1.99, "EUR"),
+        ("twenty dollars forty three", 20.43, "USD"),
+    ] {
+        let entities = parse_en(text, &[DimensionKind::AmountOfMoney]);
+        let found = entities.iter().any(|e| {
+            e.body == text
+                && matches!(&e.value, DimensionValue::AmountOfMoney(MeasurementValue::Value { value, unit })
+                    if (*value - expected_val).abs() < 0.001 && unit == expected_unit)
+        });
+        assert!(
+            found,
+            "Expected a single {} {} entity for '{}', got: {:?}",
+            expected_val, expected_unit, text, entities
+        );
+    }
+}
+
+#[test]
+fn test_money_cents_composition_ru() {
+    use duckling::{parse, Context, Lang, Locale, Options};
+
+    let locale = Locale::new(Lang::RU, None);
+    let context = Context {
+        locale,
+        ..Context::default()
+    };
+    for text in [
+        "$20 и 43ц",
+        "$20 43",
+        "20 долларов 43ц",
+        "20 долларов 43 центов",
+        "20 долларами 43 центами",
+        "двадцать долларов и 43",
+    ] {
+        let entities = parse(
+            text,
+            &locale,
+            &[DimensionKind::AmountOfMoney],
+            &context,
+            &Options::default(),
+        );
+        let found = entities.iter().any(|e| {
+            e.body == text
+                && matches!(&e.value, DimensionValue::AmountOfMoney(MeasurementValue::Value { value, unit })
+                    if (*value - 20.43).abs() < 0.001 && unit == "USD")
+        });
+        assert!(
+            found,
+            "Expected 20.43 USD for '{}', got: {:?}",
+            text, entities
+        );
+    }
+}
+
+#[test]
+fn test_money_vnd_shorthand_vi() {
+    use duckling::{parse, Context, Lang, Locale, Options};
+
+    let locale = Locale::new(Lang::VI, None);
+    let context = Context {
+        locale,
+        ..Context::default()
+    };
+    let parse_vi = |text: &str, with_latent: bool| {
+        let options = Options::builder().with_latent(with_latent).build();
+        parse(
+            text,
+            &locale,
+            &[DimensionKind::AmountOfMoney],
+            &context,
+            &options,
+        )
+    };
+    let is_vnd = |value: f64| {
+        move |mv: &DimensionValue| {
+            matches!(mv, DimensionValue::AmountOfMoney(MeasurementValue::Value { value: v, unit })
+                if (*v - value).abs() < 0.001 && unit == "VND")
+        }
+    };
+    for (text, expected) in [
+        ("200k", 200_000.0),
+        ("200k đồng", 200_000.0),
+        ("200.000đ", 200_000.0),
+        ("200.000 ₫", 200_000.0),
+        ("1,5tr", 1_500_000.0),
+        ("2tr5", 2_500_000.0),
+        ("2 triệu đồng", 2_000_000.0),
+        ("hai triệu đồng", 2_000_000.0),
+        ("500 nghìn đồng", 500_000.0),
+    ] {
+        let entities = parse_vi(text, false);
+        assert!(
+            entities
+                .iter()
+                .any(|e| e.body == text && is_vnd(expected)(&e.value)),
+            "Expected {} VND for '{}', got: {:?}",
+            expected,
+            text,
+            entities
+        );
+    }
+    // Without a currency, a spelled-out amount is only a latent guess
+    assert!(parse_vi("2 triệu", false).is_empty());
+    let entities = parse_vi("2 triệu", true);
+    assert!(
+        entities.iter().any(|e| is_vnd(2_000_000.0)(&e.value)),
+        "{entities:?}"
+    );
+    assert!(parse_vi("2 xuống", true).is_empty());
+}
+
+#[test]
+fn test_money_idr_shorthand_id() {
+    use duckling::{parse, Context, Lang, Locale, Options};
+
+    let locale = Locale::new(Lang::ID, None);
+    let context = Context {
+        locale,
+        ..Context::default()
+    };
+    for (text, expected) in [
+        ("150rb", 150_000.0),
+        ("Rp 150rb", 150_000.0),
+        ("1,5jt", 1_500_000.0),
+        ("Rp150.000", 150_000.0),
+        ("150 ribu rupiah", 150_000.0),
+    ] {
+        let entities = parse(
+            text,
+            &locale,
+            &[DimensionKind::AmountOfMoney],
+            &context,
+            &Options::default(),
+        );
+        assert!(
+            entities.iter().any(|e| e.body == text
+                && matches!(&e.value, DimensionValue::AmountOfMoney(MeasurementValue::Value { value, unit })
+                    if (*value - expected).abs() < 0.001 && unit == "IDR")),
+            "Expected {} IDR for '{}', got: {:?}",
+            expected,
+            text,
+            entities
+        );
+    }
+}
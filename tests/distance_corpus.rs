@@ -1,5 +1,5 @@
 // Ported from Duckling/Distance/EN/Corpus.hs
-use duckling::{parse_en, DimensionKind, DimensionValue, MeasurementPoint, MeasurementValue};
+use duckling::{parse_en, DimensionKind, DimensionValue, MeasurementPoint, MeasurementValue, Unit};
 
 fn check_distance(text: &str, expected_val: f64, expected_unit: &str) {
     let entities = parse_en(text, &[DimensionKind::Distance]);
@@ -83,12 +83,12 @@ fn test_distance_1_87_metres() {
     check_distance("1.87 meters", 1.87, "metre");
 }
 
-// Composite values: 7 feet and 10 inches = 94 inches
+// Composite values: 7 feet and 10 inches = 2.3876 metres
 #[test]
 fn test_distance_composite_feet_inches() {
-    check_distance("7 feet and 10 inches", 94.0, "inch");
-    check_distance("7 feet, 10 inches", 94.0, "inch");
-    check_distance("7 feet 10 inches", 94.0, "inch");
+    check_distance("7 feet and 10 inches", 2.3876, "metre");
+    check_distance("7 feet, 10 inches", 2.3876, "metre");
+    check_distance("7 feet 10 inches", 2.3876, "metre");
 }
 
 // 2 km and 1 meter = 2001 metres
@@ -99,32 +99,28 @@ fn test_distance_composite_km_m() {
     check_distance("2 kilometer 1 metre", 2001.0, "metre");
 }
 
-// 2 yards 7 ft 10 inches = 166 inches
+// 2 yards 7 ft 10 inches = 4.2164 metres
 #[test]
 fn test_distance_composite_yards_feet_inches() {
-    check_distance("2 yards 7 ft 10 inches", 166.0, "inch");
-    check_distance("2 yds, 7 feet and 10 inches", 166.0, "inch");
-    check_distance("2 yards, 7 feet, 10 in", 166.0, "inch");
+    check_distance("2 yards 7 ft 10 inches", 4.2164, "metre");
+    check_distance("2 yds, 7 feet and 10 inches", 4.2164, "metre");
+    check_distance("2 yards, 7 feet, 10 in", 4.2164, "metre");
 }
 
-// 2 yards and 7 feet = 13 feet
+// 2 yards and 7 feet = 3.9624 metres
 #[test]
 fn test_distance_composite_yards_feet() {
-    check_distance("2 yards and 7 feet", 13.0, "foot");
-    check_distance("2 yards, 7 feet", 13.0, "foot");
-    check_distance("2 yd 7'", 13.0, "foot");
+    check_distance("2 yards and 7 feet", 3.9624, "metre");
+    check_distance("2 yards, 7 feet", 3.9624, "metre");
+    check_distance("2 yd 7'", 3.9624, "metre");
 }
 
-// 10 kms 8 metres 6 cm = 1000806 centimetres
+// 10 kms 8 metres 6 cm = 10008.06 metres
 #[test]
 fn test_distance_composite_km_m_cm() {
-    check_distance("10 kms 8 metres 6 cm", 1000806.0, "centimetre");
-    check_distance("10 kms, 8 meters, 6 cm", 1000806.0, "centimetre");
-    check_distance(
-        "10 kms, 8 meters and 6 centimeters",
-        1000806.0,
-        "centimetre",
-    );
+    check_distance("10 kms 8 metres 6 cm", 10008.06, "metre");
+    check_distance("10 kms, 8 meters, 6 cm", 10008.06, "metre");
+    check_distance("10 kms, 8 meters and 6 centimeters", 10008.06, "metre");
 }
 
 // 1 meter and 1 foot = 1.3048 metres
@@ -133,10 +129,10 @@ fn test_distance_composite_m_ft() {
     check_distance("1 meter and 1 foot", 1.3048, "metre");
 }
 
-// 1 kilometer and 1 mile = 2.609344 kilometres
+// 1 kilometer and 1 mile = 2609.344 metres
 #[test]
 fn test_distance_composite_km_mi() {
-    check_distance("1 kilometer and 1 mile", 2.609344, "kilometre");
+    check_distance("1 kilometer and 1 mile", 2609.344, "metre");
 }
 
 // 3m is ambiguous
@@ -145,16 +141,16 @@ fn test_distance_3m_ambiguous() {
     check_distance("3m", 3.0, "m");
 }
 
-// 3m and 5cm = 305 centimetres (m inferred as metres)
+// 3m and 5cm = 3.05 metres (m inferred as metres)
 #[test]
 fn test_distance_3m_5cm() {
-    check_distance("3m and 5cm", 305.0, "centimetre");
+    check_distance("3m and 5cm", 3.05, "metre");
 }
 
-// 1m and 1ft = 5281 feet (m inferred as miles)
+// 1m and 1ft = 1609.6488 metres (m inferred as miles)
 #[test]
 fn test_distance_1m_1ft() {
-    check_distance("1m and 1ft", 5281.0, "foot");
+    check_distance("1m and 1ft", 1609.6488, "metre");
 }
 
 // Ranges: between 3 and 5 kilometres
@@ -168,6 +164,58 @@ fn test_distance_range_3_5_km() {
 }
 
 // under 3.5 miles
+// Heights with the trailing unit left unsaid
+#[test]
+fn test_distance_compound_implicit_unit() {
+    check_distance("6 foot 2", 1.8796, "metre");
+    check_distance("6'2", 1.8796, "metre");
+    check_distance("6'2\"", 1.8796, "metre");
+    check_distance("1m 75", 1.75, "metre");
+    check_distance("1 metre 80", 1.8, "metre");
+}
+
+#[test]
+fn test_distance_compound_components() {
+    let entities = parse_en("5 feet 10 inches", &[DimensionKind::Distance]);
+    assert_eq!(entities.len(), 1, "{entities:?}");
+    let parts: Vec<(f64, &str)> = entities[0]
+        .components
+        .iter()
+        .map(|p| (p.value, p.unit.as_str()))
+        .collect();
+    assert_eq!(parts, [(5.0, "foot"), (10.0, "inch")]);
+    let metres: f64 = entities[0]
+        .components
+        .iter()
+        .map(|p| match p.unit {
+            Unit::Distance(u) => u.to_metres(p.value),
+            _ => unreachable!(),
+        })
+        .sum();
+    assert!((metres - 1.778).abs() < 1e-9);
+    match &entities[0].value {
+        DimensionValue::Distance(MeasurementValue::Value { value, unit }) => {
+            assert!((value - metres).abs() < 1e-9);
+            assert_eq!(unit, "metre");
+        }
+        other => panic!("{other:?}"),
+    }
+
+    // "m" beside an imperial unit is read as miles
+    let entities = parse_en("1m and 1ft", &[DimensionKind::Distance]);
+    assert_eq!(
+        entities[0]
+            .components
+            .iter()
+            .map(|p| p.unit.as_str())
+            .collect::<Vec<_>>(),
+        ["mile", "foot"]
+    );
+    assert!(parse_en("5 feet", &[DimensionKind::Distance])[0]
+        .components
+        .is_empty());
+}
+
 #[test]
 fn test_distance_under_3_5_miles() {
     check_distance("under 3.5 miles", 3.5, "mile");
@@ -191,3 +239,34 @@ fn test_distance_between_5_6_mm() {
     check_distance("between 5 and six millimetres", 5.0, "millimetre");
     check_distance("5-6 mm", 5.0, "millimetre");
 }
+
+#[test]
+fn test_distance_id_units() {
+    use duckling::{parse, Context, Lang, Locale, Options};
+
+    let locale = Locale::new(Lang::ID, None);
+    let context = Context {
+        locale,
+        ..Context::default()
+    };
+    for (text, expected_val, expected_unit) in [
+        ("5 km", 5.0, "kilometre"),
+        ("3 meter", 3.0, "metre"),
+        ("10 sentimeter", 10.0, "centimetre"),
+        ("2 mil", 2.0, "mile"),
+    ] {
+        let entities = parse(
+            text,
+            &locale,
+            &[DimensionKind::Distance],
+            &context,
+            &Options::default(),
+        );
+        assert!(
+            entities.iter().any(|e| e.body == text
+                && matches!(&e.value, DimensionValue::Distance(MeasurementValue::Value { value, unit })
+                    if (*value - expected_val).abs() < 0.01 && unit == expected_unit)),
+            "Expected distance {expected_val} {expected_unit} for '{text}', got: {entities:?}"
+        );
+    }
+}
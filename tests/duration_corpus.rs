@@ -1,5 +1,5 @@
 // Ported from Duckling/Duration/EN/Corpus.hs
-use duckling::{parse_en, DimensionKind, DimensionValue, Grain};
+use duckling::{parse_en, DimensionKind, DimensionValue, DurationQualifier, Grain};
 
 fn check_duration(text: &str, expected_val: i64, expected_unit: &str) {
     let expected_grain = Grain::from_str(expected_unit);
@@ -255,3 +255,43 @@ fn test_duration_negative_minutes_alone() {
 fn test_duration_negative_i_second_that() {
     check_no_duration("I second that");
 }
+
+#[test]
+fn test_duration_normalized_representations() {
+    for (text, seconds, iso) in [
+        ("1 hour and 30 minutes", 5400, "PT1H30M"),
+        ("3 days", 259_200, "P3D"),
+        ("2 weeks", 1_209_600, "P2W"),
+        ("45 seconds", 45, "PT45S"),
+    ] {
+        let entities = parse_en(text, &[DimensionKind::Duration]);
+        let value = &entities
+            .first()
+            .unwrap_or_else(|| panic!("Expected a duration for '{}'", text))
+            .value;
+        assert_eq!(value.as_seconds(), Some(seconds), "seconds for '{}'", text);
+        assert_eq!(
+            value.to_iso8601().as_deref(),
+            Some(iso),
+            "ISO 8601 for '{}'",
+            text
+        );
+    }
+}
+
+#[test]
+fn test_duration_for_prefix_is_spanning() {
+    let qualifier = |text: &str| {
+        parse_en(text, &[DimensionKind::Duration])
+            .into_iter()
+            .find_map(|e| match e.value {
+                DimensionValue::Duration { qualifier, .. } => Some((e.body, qualifier)),
+                _ => None,
+            })
+    };
+    assert_eq!(
+        qualifier("for 2 hours"),
+        Some(("for 2 hours".to_string(), Some(DurationQualifier::Spanning)))
+    );
+    assert_eq!(qualifier("2 hours"), Some(("2 hours".to_string(), None)));
+}
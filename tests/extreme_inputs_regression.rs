@@ -1,7 +1,7 @@
 use chrono::{NaiveDate, TimeZone, Utc};
 use duckling::{
     parse, Context, DimensionKind, DimensionValue, Entity, Grain, Lang, Locale, MeasurementValue,
-    Options, TimePoint, TimeValue,
+    MoneyCurrency, Options, TimePoint, TimeValue,
 };
 
 fn context_en() -> Context {
@@ -9,6 +9,7 @@ fn context_en() -> Context {
         reference_time: Utc.with_ymd_and_hms(2013, 2, 12, 4, 30, 0).unwrap(),
         locale: Locale::new(Lang::EN, None),
         timezone_offset_minutes: -120,
+        ..Context::default()
     }
 }
 
@@ -120,6 +121,7 @@ fn test_real_world_event_listing_exact_entities() {
         reference_time: Utc.with_ymd_and_hms(2025, 3, 5, 12, 0, 0).unwrap(),
         locale: Locale::new(Lang::EN, None),
         timezone_offset_minutes: -360,
+        ..Context::default()
     };
     let options = Options::default();
     let dims = [
@@ -145,8 +147,12 @@ fn test_real_world_event_listing_exact_entities() {
             body: body.to_string(),
             start,
             end,
+            grain: value.grain(),
             value,
             latent,
+            metadata: None,
+            precision: None,
+            components: Vec::new(),
         }
     }
     fn ndt(y: i32, m: u32, d: u32, h: u32, mi: u32, s: u32) -> chrono::NaiveDateTime {
@@ -155,10 +161,10 @@ fn test_real_world_event_listing_exact_entities() {
             .and_hms_opt(h, mi, s)
             .unwrap()
     }
-    fn money(value: f64, unit: &str) -> DimensionValue {
+    fn money(value: f64, unit: MoneyCurrency) -> DimensionValue {
         DimensionValue::AmountOfMoney(MeasurementValue::Value {
             value,
-            unit: unit.to_string(),
+            unit: unit.into(),
         })
     }
     fn num(v: f64) -> DimensionValue {
@@ -190,6 +196,9 @@ fn test_real_world_event_listing_exact_entities() {
             value: point,
             values,
             holiday: None,
+            observed: false,
+            qualifier: None,
+            meridiem_candidates: Vec::new(),
         })
     }
     let l = Some(false);
@@ -227,7 +236,7 @@ fn test_real_world_event_listing_exact_entities() {
             time_single(2025, 3, 11, 20, 15, 0, Grain::Minute, &[]),
             l,
         ),
-        e("cl", 102, 104, money(100_000.0, "cent"), l),
+        e("cl", 102, 104, money(100_000.0, MoneyCurrency::Cent), l),
         e(
             "Mon",
             126,
@@ -249,16 +258,16 @@ fn test_real_world_event_listing_exact_entities() {
         e("L", 194, 195, num(100_000.0), l),
         e("l", 199, 200, num(100_000.0), l),
         e("l", 230, 231, num(100_000.0), l),
-        e("cl", 247, 249, money(100_000.0, "cent"), l),
+        e("cl", 247, 249, money(100_000.0, MoneyCurrency::Cent), l),
         e("l", 314, 315, num(100_000.0), l),
         e("l", 315, 316, num(100_000.0), l),
-        e("alet", 333, 337, money(1.0, "EGP"), l),
+        e("alet", 333, 337, money(1.0, MoneyCurrency::EGP), l),
         e("l", 341, 342, num(100_000.0), l),
         e("L", 366, 367, num(100_000.0), l),
         e("l", 371, 372, num(100_000.0), l),
         e("l", 390, 391, num(100_000.0), l),
-        e("$17", 399, 402, money(17.0, "USD"), l),
-        e("17 c", 400, 404, money(17.0, "cent"), l),
+        e("$17", 399, 402, money(17.0, MoneyCurrency::USD), l),
+        e("17 c", 400, 404, money(17.0, MoneyCurrency::Cent), l),
         e("l", 413, 414, num(100_000.0), l),
         e("l", 414, 415, num(100_000.0), l),
         e("l", 431, 432, num(100_000.0), l),
@@ -328,6 +337,7 @@ fn test_email_like_marketing_text_no_time_entities_1() {
         reference_time: Utc.with_ymd_and_hms(2025, 3, 5, 12, 0, 0).unwrap(),
         locale: Locale::new(Lang::EN, None),
         timezone_offset_minutes: -360,
+        ..Context::default()
     };
     let options = Options::default();
     let dims = [
@@ -359,6 +369,7 @@ fn test_email_like_marketing_text_no_time_entities_2() {
         reference_time: Utc.with_ymd_and_hms(2025, 3, 5, 12, 0, 0).unwrap(),
         locale: Locale::new(Lang::EN, None),
         timezone_offset_minutes: -360,
+        ..Context::default()
     };
     let options = Options::default();
     let dims = [
@@ -390,6 +401,7 @@ fn test_iso_date_in_sentence_() {
         reference_time: Utc.with_ymd_and_hms(2025, 3, 5, 12, 0, 0).unwrap(),
         locale: Locale::new(Lang::EN, None),
         timezone_offset_minutes: -360,
+        ..Context::default()
     };
     let options = Options::default();
     let dims = [
@@ -420,9 +432,16 @@ fn test_iso_date_in_sentence_() {
                 value: point.clone(),
                 values: vec![point],
                 holiday: None,
+                observed: false,
+                qualifier: None,
+                meridiem_candidates: Vec::new(),
             })
         },
         latent: Some(false),
+        metadata: None,
+        grain: Some(Grain::Day),
+        precision: None,
+        components: Vec::new(),
     }];
 
     assert_eq!(entities, expected);
@@ -0,0 +1,293 @@
+// Differential test against a running Haskell Duckling server.
+//
+// Sends every example of the English corpus files under haskell_upstream/
+// to both parsers and diffs the entities field by field. Ignored by default;
+// start the Haskell server (`stack exec duckling-example-exe`) and run:
+//
+//     DUCKLING_HASKELL_URL=http://localhost:8000 \
+//         cargo test --test haskell_differential -- --ignored --nocapture
+//
+// The test only fails if the server can't be reached. Differences go to a
+// Markdown report, `target/haskell-differential-report.md` by default or the
+// path in DUCKLING_DIFF_REPORT, with a per-dimension summary on stdout.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use chrono::{TimeZone, Utc};
+use duckling::{parse, Context, DimensionKind, Entity, Lang, Locale, Options};
+use serde_json::Value;
+
+/// Reference time of Haskell's `testContext`, 2013-02-12 04:30:00 at UTC-2,
+/// as the instant the server expects.
+const REFERENCE_TIME_MS: i64 = 1_360_650_600_000;
+/// The same UTC-2 offset as a tz database name (POSIX signs are inverted).
+const HASKELL_TZ: &str = "Etc/GMT+2";
+
+/// Corpus files to compare: each dimension's EN corpus, or its common one for
+/// dimensions without a language split.
+fn corpus_files() -> Vec<(DimensionKind, PathBuf)> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/haskell_upstream/Duckling");
+    let mut files = Vec::new();
+    let mut dirs: Vec<PathBuf> = std::fs::read_dir(&root)
+        .expect("haskell_upstream corpus missing")
+        .filter_map(|entry| Some(entry.ok()?.path()))
+        .collect();
+    dirs.sort();
+    for dir in dirs {
+        let Some(dim) = dir
+            .file_name()
+            .and_then(|n| n.to_str())
+            .and_then(|n| DimensionKind::from_name(&haskell_dim_name(n)))
+        else {
+            continue;
+        };
+        let en = dir.join("EN/Corpus.hs");
+        let common = dir.join("Corpus.hs");
+        if en.exists() {
+            files.push((dim, en));
+        } else if common.exists() {
+            files.push((dim, common));
+        }
+    }
+    files
+}
+
+/// "AmountOfMoney" -> "amount-of-money"; "Numeral" -> "number".
+fn haskell_dim_name(module: &str) -> String {
+    if module == "Numeral" {
+        return "number".to_string();
+    }
+    let mut name = String::new();
+    for (i, ch) in module.chars().enumerate() {
+        if ch.is_uppercase() && i > 0 {
+            name.push('-');
+        }
+        name.extend(ch.to_lowercase());
+    }
+    name
+}
+
+/// The example texts of a corpus file: string literals that are list
+/// elements, one per line, in both the positive and negative corpora.
+fn corpus_examples(source: &str) -> Vec<String> {
+    source
+        .lines()
+        .filter_map(|line| {
+            let item = line.trim().strip_prefix(['[', ','])?.trim();
+            let literal = item.strip_prefix('"')?.strip_suffix('"')?;
+            Some(unescape_haskell(literal))
+        })
+        .collect()
+}
+
+fn unescape_haskell(literal: &str) -> String {
+    let mut out = String::new();
+    let mut chars = literal.chars().peekable();
+    while let Some(ch) = chars.next() {
+        if ch != '\\' {
+            out.push(ch);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('t') => out.push('\t'),
+            Some('&') => {}
+            Some(d) if d.is_ascii_digit() => {
+                let mut code = d.to_digit(10).unwrap_or(0);
+                while let Some(d) = chars.peek().and_then(|c| c.to_digit(10)) {
+                    code = code.saturating_mul(10).saturating_add(d);
+                    chars.next();
+                }
+                out.extend(char::from_u32(code));
+            }
+            Some(other) => out.push(other),
+            None => {}
+        }
+    }
+    out
+}
+
+fn haskell_parse(url: &str, text: &str, dim: DimensionKind) -> Result<Vec<Value>, String> {
+    let output = Command::new("curl")
+        .args(["-s", "--fail", "-X", "POST", &format!("{url}/parse")])
+        .args(["--data-urlencode", "locale=en_US"])
+        .args(["--data-urlencode", &format!("text={text}")])
+        .args(["--data-urlencode", &format!("dims=[\"{dim}\"]")])
+        .args(["--data-urlencode", &format!("reftime={REFERENCE_TIME_MS}")])
+        .args(["--data-urlencode", &format!("tz={HASKELL_TZ}")])
+        .output()
+        .map_err(|e| format!("could not run curl: {e}"))?;
+    if !output.status.success() {
+        return Err(format!("{url} answered with curl status {}", output.status));
+    }
+    serde_json::from_slice(&output.stdout).map_err(|e| format!("bad JSON from {url}: {e}"))
+}
+
+/// Parse with the context the Rust corpus tests use for `testContext`.
+fn rust_parse(text: &str, dim: DimensionKind) -> Vec<Entity> {
+    let context = Context {
+        reference_time: Utc.with_ymd_and_hms(2013, 2, 12, 4, 30, 0).unwrap(),
+        locale: Locale::new(Lang::EN, None),
+        timezone_offset_minutes: -120,
+        ..Context::default()
+    };
+    parse(text, &context.locale, &[dim], &context, &Options::default())
+}
+
+/// Leaf values of `value` keyed by their dotted path under `prefix`.
+fn flatten(prefix: &str, value: &Value, out: &mut BTreeMap<String, Value>) {
+    match value {
+        Value::Object(map) => {
+            for (key, v) in map {
+                flatten(&format!("{prefix}.{key}"), v, out);
+            }
+        }
+        Value::Array(items) => {
+            for (i, v) in items.iter().enumerate() {
+                flatten(&format!("{prefix}[{i}]"), v, out);
+            }
+        }
+        leaf => {
+            out.insert(prefix.to_string(), leaf.clone());
+        }
+    }
+}
+
+/// A Rust entity's fields in the shape of Haskell's JSON: the value's enum
+/// tag is dropped, and a bare value sits at `value.value`.
+fn rust_fields(entity: &Entity) -> BTreeMap<String, Value> {
+    let mut fields = BTreeMap::new();
+    fields.insert("body".to_string(), Value::from(entity.body.clone()));
+    fields.insert(
+        "dim".to_string(),
+        Value::from(entity.value.dim_kind().to_string()),
+    );
+    fields.insert(
+        "latent".to_string(),
+        Value::from(entity.latent == Some(true)),
+    );
+    let value = serde_json::to_value(&entity.value).unwrap_or(Value::Null);
+    let inner = match value {
+        Value::Object(map) if map.len() == 1 => map.into_iter().next().map(|(_, v)| v),
+        other => Some(other),
+    };
+    match inner {
+        Some(Value::Object(map)) => flatten("value", &Value::Object(map), &mut fields),
+        Some(leaf) => flatten("value.value", &leaf, &mut fields),
+        None => {}
+    }
+    fields
+}
+
+fn haskell_fields(entity: &Value) -> BTreeMap<String, Value> {
+    let mut fields = BTreeMap::new();
+    for key in ["body", "dim", "latent"] {
+        if let Some(v) = entity.get(key) {
+            fields.insert(key.to_string(), v.clone());
+        }
+    }
+    if let Some(value) = entity.get("value") {
+        flatten("value", value, &mut fields);
+    }
+    fields
+}
+
+fn same_leaf(a: &Value, b: &Value) -> bool {
+    match (a.as_f64(), b.as_f64()) {
+        (Some(x), Some(y)) => (x - y).abs() < 1e-9,
+        _ => a == b,
+    }
+}
+
+/// Field differences between the two parsers' entities, matched by span.
+fn diff(rust: &[Entity], haskell: &[Value]) -> Vec<String> {
+    let span = |v: &Value, key: &str| v.get(key).and_then(Value::as_u64).unwrap_or(u64::MAX);
+    let mut lines = Vec::new();
+    for h in haskell {
+        let (start, end) = (span(h, "start"), span(h, "end"));
+        let Some(r) = rust
+            .iter()
+            .find(|r| r.start as u64 == start && r.end as u64 == end)
+        else {
+            lines.push(format!("only in Haskell: {start}..{end} {h}"));
+            continue;
+        };
+        let (rf, hf) = (rust_fields(r), haskell_fields(h));
+        for (path, hv) in &hf {
+            match rf.get(path) {
+                Some(rv) if same_leaf(rv, hv) => {}
+                Some(rv) => lines.push(format!("{start}..{end} {path}: rust {rv}, haskell {hv}")),
+                None => lines.push(format!(
+                    "{start}..{end} {path}: missing in rust, haskell {hv}"
+                )),
+            }
+        }
+        for path in rf.keys().filter(|p| !hf.contains_key(*p)) {
+            lines.push(format!("{start}..{end} {path}: missing in haskell"));
+        }
+    }
+    for r in rust {
+        let matched = haskell
+            .iter()
+            .any(|h| span(h, "start") == r.start as u64 && span(h, "end") == r.end as u64);
+        if !matched {
+            lines.push(format!(
+                "only in Rust: {}..{} {:?}",
+                r.start, r.end, r.value
+            ));
+        }
+    }
+    lines
+}
+
+#[test]
+#[ignore = "needs a running Haskell Duckling server; see the file header"]
+fn differential_en_corpus() {
+    let url = std::env::var("DUCKLING_HASKELL_URL")
+        .unwrap_or_else(|_| "http://localhost:8000".to_string());
+    let report_path = std::env::var("DUCKLING_DIFF_REPORT").map_or_else(
+        |_| Path::new(env!("CARGO_MANIFEST_DIR")).join("target/haskell-differential-report.md"),
+        PathBuf::from,
+    );
+    let mut summary = String::new();
+    let mut details = String::new();
+    for (dim, path) in corpus_files() {
+        let source = std::fs::read_to_string(&path).expect("unreadable corpus file");
+        let examples = corpus_examples(&source);
+        let mut agree = 0usize;
+        writeln!(details, "\n## {dim} ({})\n", path.display()).unwrap();
+        for text in &examples {
+            let haskell = haskell_parse(&url, text, dim)
+                .unwrap_or_else(|e| panic!("Haskell Duckling unreachable: {e}"));
+            let lines = diff(&rust_parse(text, dim), &haskell);
+            if lines.is_empty() {
+                agree += 1;
+                continue;
+            }
+            writeln!(details, "- `{text}`").unwrap();
+            for line in lines {
+                writeln!(details, "  - {line}").unwrap();
+            }
+        }
+        writeln!(summary, "| {dim} | {agree} | {} |", examples.len()).unwrap();
+    }
+    let report = format!(
+        "# Rust vs Haskell Duckling (EN corpus)\n\n| Dimension | Agree | Examples |\n|---|---:|---:|\n{summary}{details}"
+    );
+    if let Some(dir) = report_path.parent() {
+        std::fs::create_dir_all(dir).expect("cannot create report directory");
+    }
+    std::fs::write(&report_path, report).expect("cannot write report");
+    println!("{summary}report: {}", report_path.display());
+}
+
+#[test]
+fn corpus_examples_are_read_from_list_items() {
+    let source = "  [ examples (NumeralValue 2)\n             [ \"2\"\n             , \"a \\\"pair\\\"\"\n             , \"caf\\233\"\n             ]\n";
+    assert_eq!(corpus_examples(source), ["2", "a \"pair\"", "café"]);
+    assert_eq!(haskell_dim_name("AmountOfMoney"), "amount-of-money");
+    assert!(corpus_files().len() >= 10);
+}
@@ -286,3 +286,203 @@ fn test_numeral_forty_five() {
     check_numeral("forty-five (45)", 45.0);
     check_numeral("45 (forty five)", 45.0);
 }
+
+#[test]
+fn test_numeral_scientific_notation() {
+    check_numeral("3.2e6", 3200000.0);
+    check_numeral("1E-3", 0.001);
+    check_numeral("6.02e+23", 6.02e23);
+}
+
+#[test]
+fn test_numeral_magnitude_suffixes_and_negatives() {
+    check_numeral("1.5M", 1500000.0);
+    check_numeral("2bn", 2000000000.0);
+    check_numeral("3mn", 3000000.0);
+    check_numeral("-5", -5.0);
+    check_numeral("minus twelve", -12.0);
+}
+
+#[test]
+fn test_numeral_lakh_crore_region_gating() {
+    use duckling::{parse, Context, Lang, Locale, Options, Region};
+
+    let numerals = |text: &str, region: Option<Region>| -> Vec<f64> {
+        let locale = Locale::new(Lang::EN, region);
+        let context = Context {
+            locale,
+            ..Context::default()
+        };
+        parse(
+            text,
+            &locale,
+            &[DimensionKind::Numeral],
+            &context,
+            &Options::default(),
+        )
+        .into_iter()
+        .filter_map(|e| match e.value {
+            DimensionValue::Numeral(v) => Some(v),
+            _ => None,
+        })
+        .collect()
+    };
+    for region in [None, Some(Region::IN)] {
+        assert!(numerals("7 lakh", region).contains(&700000.0));
+        assert!(numerals("3 crore", region).contains(&30000000.0));
+    }
+    for region in [Some(Region::US), Some(Region::GB)] {
+        assert!(!numerals("7 lakh", region).contains(&700000.0));
+        assert!(!numerals("3 crore", region).contains(&30000000.0));
+    }
+}
+
+#[test]
+fn test_numeral_fr_regional_tens() {
+    use duckling::{parse, Context, Lang, Locale, Options, Region};
+
+    let numeral = |text: &str, region: Option<Region>| {
+        let locale = Locale::new(Lang::FR, region);
+        let context = Context {
+            locale,
+            ..Context::default()
+        };
+        parse(
+            text,
+            &locale,
+            &[DimensionKind::Numeral],
+            &context,
+            &Options::default(),
+        )
+        .into_iter()
+        .find(|e| e.body == text)
+        .map(|e| e.value)
+    };
+    for region in [Region::BE, Region::CH] {
+        assert_eq!(
+            numeral("septante-deux", Some(region)),
+            Some(DimensionValue::Numeral(72.0))
+        );
+        assert_eq!(
+            numeral("nonante et un", Some(region)),
+            Some(DimensionValue::Numeral(91.0))
+        );
+    }
+    assert_eq!(
+        numeral("huitante", Some(Region::CH)),
+        Some(DimensionValue::Numeral(80.0))
+    );
+    assert_eq!(numeral("huitante", Some(Region::BE)), None);
+    assert_eq!(numeral("septante", None), None);
+    assert_eq!(numeral("septante", Some(Region::CA)), None);
+}
+
+#[test]
+fn test_numeral_fa_digits() {
+    use duckling::{parse, Context, Lang, Locale, Options};
+
+    let locale = Locale::new(Lang::FA, None);
+    let context = Context {
+        locale,
+        ..Context::default()
+    };
+    for (text, expected) in [
+        ("۵۰۰", 500.0),
+        ("٥٠٠", 500.0),
+        ("۱۲٬۵۰۰", 12500.0),
+        ("۲٫۵", 2.5),
+        ("۵۰۰ هزار", 500000.0),
+    ] {
+        let value = parse(
+            text,
+            &locale,
+            &[DimensionKind::Numeral],
+            &context,
+            &Options::default(),
+        )
+        .into_iter()
+        .find(|e| e.body == text)
+        .map(|e| e.value);
+        assert_eq!(value, Some(DimensionValue::Numeral(expected)), "{text}");
+    }
+}
+
+#[test]
+fn test_numeral_thai_lao_digits() {
+    use duckling::{parse, Context, Lang, Locale, Options};
+
+    for (lang, text, expected) in [
+        (Lang::TH, "๒๕๖๘", 2568.0),
+        (Lang::LO, "໒໕໖໘", 2568.0),
+        (Lang::KM, "២៥៦៩", 2569.0),
+    ] {
+        let locale = Locale::new(lang, None);
+        let context = Context {
+            locale,
+            ..Context::default()
+        };
+        let value = parse(
+            text,
+            &locale,
+            &[DimensionKind::Numeral],
+            &context,
+            &Options::default(),
+        )
+        .into_iter()
+        .find(|e| e.body == text)
+        .map(|e| e.value);
+        assert_eq!(value, Some(DimensionValue::Numeral(expected)), "{text}");
+    }
+}
+
+#[test]
+fn test_numeral_digit_grouping_by_locale() {
+    use duckling::{parse, Context, DecimalSeparator, Lang, Locale, Options, Region};
+
+    let numeral = |text: &str, locale: Locale, separator: Option<DecimalSeparator>| {
+        let context = Context {
+            locale,
+            ..Context::default()
+        };
+        let options = Options::builder().decimal_separator(separator).build();
+        parse(text, &locale, &[DimensionKind::Numeral], &context, &options)
+            .into_iter()
+            .find(|e| e.body == text)
+            .map(|e| e.value)
+    };
+    let de = Locale::new(Lang::DE, None);
+    let en_us = Locale::new(Lang::EN, Some(Region::US));
+
+    assert_eq!(
+        numeral("1.234,56", de, None),
+        Some(DimensionValue::Numeral(1234.56))
+    );
+    assert_eq!(numeral("1.234,56", en_us, None), None);
+    assert_eq!(
+        numeral("1,234.56", en_us, None),
+        Some(DimensionValue::Numeral(1234.56))
+    );
+    assert_eq!(
+        numeral("1.234", de, None),
+        Some(DimensionValue::Numeral(1234.0))
+    );
+    assert_eq!(
+        numeral("1.234", en_us, None),
+        Some(DimensionValue::Numeral(1.234))
+    );
+
+    // The region convention wins over the language default...
+    assert_eq!(
+        numeral("3,5", Locale::new(Lang::EN, Some(Region::ZA)), None),
+        Some(DimensionValue::Numeral(3.5))
+    );
+    // ...and Options override both.
+    assert_eq!(
+        numeral("1.234,56", en_us, Some(DecimalSeparator::Comma)),
+        Some(DimensionValue::Numeral(1234.56))
+    );
+    assert_eq!(
+        numeral("1,234.56", de, Some(DecimalSeparator::Point)),
+        Some(DimensionValue::Numeral(1234.56))
+    );
+}
@@ -88,6 +88,7 @@ fn parse_context(locale: Locale) -> Context {
         reference_time: Utc.with_ymd_and_hms(2013, 2, 12, 4, 30, 0).unwrap(),
         locale,
         timezone_offset_minutes: -120,
+        ..Context::default()
     }
 }
 
@@ -265,7 +266,7 @@ fn pending_amountofmoney_ar_amountofmoney_ar_corpus_hs_latent() {
     let dim = dim_from_name("AmountOfMoney");
     let locale = Locale::new(lang_from_code("AR"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -350,7 +351,7 @@ fn pending_amountofmoney_bg_amountofmoney_bg_corpus_hs_latent() {
     let dim = dim_from_name("AmountOfMoney");
     let locale = Locale::new(lang_from_code("BG"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -435,7 +436,7 @@ fn pending_amountofmoney_ca_amountofmoney_ca_corpus_hs_latent() {
     let dim = dim_from_name("AmountOfMoney");
     let locale = Locale::new(lang_from_code("CA"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -520,7 +521,7 @@ fn pending_amountofmoney_en_au_amountofmoney_en_au_corpus_hs_latent() {
     let dim = dim_from_name("AmountOfMoney");
     let locale = Locale::new(lang_from_code("EN"), region_from_code("AU"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -605,7 +606,7 @@ fn pending_amountofmoney_en_bz_amountofmoney_en_bz_corpus_hs_latent() {
     let dim = dim_from_name("AmountOfMoney");
     let locale = Locale::new(lang_from_code("EN"), region_from_code("BZ"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -690,7 +691,7 @@ fn pending_amountofmoney_en_ca_amountofmoney_en_ca_corpus_hs_latent() {
     let dim = dim_from_name("AmountOfMoney");
     let locale = Locale::new(lang_from_code("EN"), region_from_code("CA"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -775,7 +776,7 @@ fn pending_amountofmoney_en_gb_amountofmoney_en_gb_corpus_hs_latent() {
     let dim = dim_from_name("AmountOfMoney");
     let locale = Locale::new(lang_from_code("EN"), region_from_code("GB"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -860,7 +861,7 @@ fn pending_amountofmoney_en_ie_amountofmoney_en_ie_corpus_hs_latent() {
     let dim = dim_from_name("AmountOfMoney");
     let locale = Locale::new(lang_from_code("EN"), region_from_code("IE"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -945,7 +946,7 @@ fn pending_amountofmoney_en_in_amountofmoney_en_in_corpus_hs_latent() {
     let dim = dim_from_name("AmountOfMoney");
     let locale = Locale::new(lang_from_code("EN"), region_from_code("IN"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -1030,7 +1031,7 @@ fn pending_amountofmoney_en_jm_amountofmoney_en_jm_corpus_hs_latent() {
     let dim = dim_from_name("AmountOfMoney");
     let locale = Locale::new(lang_from_code("EN"), region_from_code("JM"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -1115,7 +1116,7 @@ fn pending_amountofmoney_en_nz_amountofmoney_en_nz_corpus_hs_latent() {
     let dim = dim_from_name("AmountOfMoney");
     let locale = Locale::new(lang_from_code("EN"), region_from_code("NZ"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -1200,7 +1201,7 @@ fn pending_amountofmoney_en_ph_amountofmoney_en_ph_corpus_hs_latent() {
     let dim = dim_from_name("AmountOfMoney");
     let locale = Locale::new(lang_from_code("EN"), region_from_code("PH"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -1285,7 +1286,7 @@ fn pending_amountofmoney_en_tt_amountofmoney_en_tt_corpus_hs_latent() {
     let dim = dim_from_name("AmountOfMoney");
     let locale = Locale::new(lang_from_code("EN"), region_from_code("TT"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -1370,7 +1371,7 @@ fn pending_amountofmoney_en_us_amountofmoney_en_us_corpus_hs_latent() {
     let dim = dim_from_name("AmountOfMoney");
     let locale = Locale::new(lang_from_code("EN"), region_from_code("US"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -1455,7 +1456,7 @@ fn pending_amountofmoney_en_za_amountofmoney_en_za_corpus_hs_latent() {
     let dim = dim_from_name("AmountOfMoney");
     let locale = Locale::new(lang_from_code("EN"), region_from_code("ZA"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -1540,7 +1541,7 @@ fn pending_amountofmoney_es_amountofmoney_es_corpus_hs_latent() {
     let dim = dim_from_name("AmountOfMoney");
     let locale = Locale::new(lang_from_code("ES"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -1625,7 +1626,7 @@ fn pending_amountofmoney_fr_amountofmoney_fr_corpus_hs_latent() {
     let dim = dim_from_name("AmountOfMoney");
     let locale = Locale::new(lang_from_code("FR"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -1710,7 +1711,7 @@ fn pending_amountofmoney_ga_amountofmoney_ga_corpus_hs_latent() {
     let dim = dim_from_name("AmountOfMoney");
     let locale = Locale::new(lang_from_code("GA"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -1795,7 +1796,7 @@ fn pending_amountofmoney_he_amountofmoney_he_corpus_hs_latent() {
     let dim = dim_from_name("AmountOfMoney");
     let locale = Locale::new(lang_from_code("HE"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -1880,7 +1881,7 @@ fn pending_amountofmoney_hr_amountofmoney_hr_corpus_hs_latent() {
     let dim = dim_from_name("AmountOfMoney");
     let locale = Locale::new(lang_from_code("HR"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -1965,7 +1966,7 @@ fn pending_amountofmoney_id_amountofmoney_id_corpus_hs_latent() {
     let dim = dim_from_name("AmountOfMoney");
     let locale = Locale::new(lang_from_code("ID"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -2050,7 +2051,7 @@ fn pending_amountofmoney_it_amountofmoney_it_corpus_hs_latent() {
     let dim = dim_from_name("AmountOfMoney");
     let locale = Locale::new(lang_from_code("IT"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -2135,7 +2136,7 @@ fn pending_amountofmoney_ka_amountofmoney_ka_corpus_hs_latent() {
     let dim = dim_from_name("AmountOfMoney");
     let locale = Locale::new(lang_from_code("KA"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -2220,7 +2221,7 @@ fn pending_amountofmoney_ko_amountofmoney_ko_corpus_hs_latent() {
     let dim = dim_from_name("AmountOfMoney");
     let locale = Locale::new(lang_from_code("KO"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -2305,7 +2306,7 @@ fn pending_amountofmoney_mn_amountofmoney_mn_corpus_hs_latent() {
     let dim = dim_from_name("AmountOfMoney");
     let locale = Locale::new(lang_from_code("MN"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -2390,7 +2391,7 @@ fn pending_amountofmoney_nb_amountofmoney_nb_corpus_hs_latent() {
     let dim = dim_from_name("AmountOfMoney");
     let locale = Locale::new(lang_from_code("NB"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -2475,7 +2476,7 @@ fn pending_amountofmoney_nl_amountofmoney_nl_corpus_hs_latent() {
     let dim = dim_from_name("AmountOfMoney");
     let locale = Locale::new(lang_from_code("NL"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -2560,7 +2561,7 @@ fn pending_amountofmoney_pt_amountofmoney_pt_corpus_hs_latent() {
     let dim = dim_from_name("AmountOfMoney");
     let locale = Locale::new(lang_from_code("PT"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -2645,7 +2646,7 @@ fn pending_amountofmoney_ro_amountofmoney_ro_corpus_hs_latent() {
     let dim = dim_from_name("AmountOfMoney");
     let locale = Locale::new(lang_from_code("RO"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -2730,7 +2731,7 @@ fn pending_amountofmoney_ru_amountofmoney_ru_corpus_hs_latent() {
     let dim = dim_from_name("AmountOfMoney");
     let locale = Locale::new(lang_from_code("RU"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -2815,7 +2816,7 @@ fn pending_amountofmoney_sv_amountofmoney_sv_corpus_hs_latent() {
     let dim = dim_from_name("AmountOfMoney");
     let locale = Locale::new(lang_from_code("SV"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -2900,7 +2901,7 @@ fn pending_amountofmoney_tr_amountofmoney_tr_corpus_hs_latent() {
     let dim = dim_from_name("AmountOfMoney");
     let locale = Locale::new(lang_from_code("TR"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -2985,7 +2986,7 @@ fn pending_amountofmoney_vi_amountofmoney_vi_corpus_hs_latent() {
     let dim = dim_from_name("AmountOfMoney");
     let locale = Locale::new(lang_from_code("VI"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -3070,7 +3071,7 @@ fn pending_amountofmoney_zh_amountofmoney_zh_corpus_hs_latent() {
     let dim = dim_from_name("AmountOfMoney");
     let locale = Locale::new(lang_from_code("ZH"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -3155,7 +3156,7 @@ fn pending_distance_bg_distance_bg_corpus_hs_latent() {
     let dim = dim_from_name("Distance");
     let locale = Locale::new(lang_from_code("BG"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -3240,7 +3241,7 @@ fn pending_distance_ca_distance_ca_corpus_hs_latent() {
     let dim = dim_from_name("Distance");
     let locale = Locale::new(lang_from_code("CA"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -3325,7 +3326,7 @@ fn pending_distance_cs_distance_cs_corpus_hs_latent() {
     let dim = dim_from_name("Distance");
     let locale = Locale::new(lang_from_code("CS"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -3410,7 +3411,7 @@ fn pending_distance_de_distance_de_corpus_hs_latent() {
     let dim = dim_from_name("Distance");
     let locale = Locale::new(lang_from_code("DE"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -3495,7 +3496,7 @@ fn pending_distance_es_distance_es_corpus_hs_latent() {
     let dim = dim_from_name("Distance");
     let locale = Locale::new(lang_from_code("ES"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -3580,7 +3581,7 @@ fn pending_distance_fr_distance_fr_corpus_hs_latent() {
     let dim = dim_from_name("Distance");
     let locale = Locale::new(lang_from_code("FR"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -3665,7 +3666,7 @@ fn pending_distance_ga_distance_ga_corpus_hs_latent() {
     let dim = dim_from_name("Distance");
     let locale = Locale::new(lang_from_code("GA"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -3750,7 +3751,7 @@ fn pending_distance_hr_distance_hr_corpus_hs_latent() {
     let dim = dim_from_name("Distance");
     let locale = Locale::new(lang_from_code("HR"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -3835,7 +3836,7 @@ fn pending_distance_it_distance_it_corpus_hs_latent() {
     let dim = dim_from_name("Distance");
     let locale = Locale::new(lang_from_code("IT"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -3920,7 +3921,7 @@ fn pending_distance_km_distance_km_corpus_hs_latent() {
     let dim = dim_from_name("Distance");
     let locale = Locale::new(lang_from_code("KM"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -4005,7 +4006,7 @@ fn pending_distance_ko_distance_ko_corpus_hs_latent() {
     let dim = dim_from_name("Distance");
     let locale = Locale::new(lang_from_code("KO"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -4090,7 +4091,7 @@ fn pending_distance_mn_distance_mn_corpus_hs_latent() {
     let dim = dim_from_name("Distance");
     let locale = Locale::new(lang_from_code("MN"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -4175,7 +4176,7 @@ fn pending_distance_nl_distance_nl_corpus_hs_latent() {
     let dim = dim_from_name("Distance");
     let locale = Locale::new(lang_from_code("NL"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -4260,7 +4261,7 @@ fn pending_distance_pt_distance_pt_corpus_hs_latent() {
     let dim = dim_from_name("Distance");
     let locale = Locale::new(lang_from_code("PT"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -4345,7 +4346,7 @@ fn pending_distance_ro_distance_ro_corpus_hs_latent() {
     let dim = dim_from_name("Distance");
     let locale = Locale::new(lang_from_code("RO"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -4430,7 +4431,7 @@ fn pending_distance_ru_distance_ru_corpus_hs_latent() {
     let dim = dim_from_name("Distance");
     let locale = Locale::new(lang_from_code("RU"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -4515,7 +4516,7 @@ fn pending_distance_sv_distance_sv_corpus_hs_latent() {
     let dim = dim_from_name("Distance");
     let locale = Locale::new(lang_from_code("SV"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -4600,7 +4601,7 @@ fn pending_distance_tr_distance_tr_corpus_hs_latent() {
     let dim = dim_from_name("Distance");
     let locale = Locale::new(lang_from_code("TR"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -4685,7 +4686,7 @@ fn pending_distance_zh_distance_zh_corpus_hs_latent() {
     let dim = dim_from_name("Distance");
     let locale = Locale::new(lang_from_code("ZH"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -4770,7 +4771,7 @@ fn pending_duration_ar_duration_ar_corpus_hs_latent() {
     let dim = dim_from_name("Duration");
     let locale = Locale::new(lang_from_code("AR"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -4855,7 +4856,7 @@ fn pending_duration_bg_duration_bg_corpus_hs_latent() {
     let dim = dim_from_name("Duration");
     let locale = Locale::new(lang_from_code("BG"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -4940,7 +4941,7 @@ fn pending_duration_ca_duration_ca_corpus_hs_latent() {
     let dim = dim_from_name("Duration");
     let locale = Locale::new(lang_from_code("CA"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -5025,7 +5026,7 @@ fn pending_duration_de_duration_de_corpus_hs_latent() {
     let dim = dim_from_name("Duration");
     let locale = Locale::new(lang_from_code("DE"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -5110,7 +5111,7 @@ fn pending_duration_el_duration_el_corpus_hs_latent() {
     let dim = dim_from_name("Duration");
     let locale = Locale::new(lang_from_code("EL"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -5195,7 +5196,7 @@ fn pending_duration_es_duration_es_corpus_hs_latent() {
     let dim = dim_from_name("Duration");
     let locale = Locale::new(lang_from_code("ES"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -5280,7 +5281,7 @@ fn pending_duration_fr_duration_fr_corpus_hs_latent() {
     let dim = dim_from_name("Duration");
     let locale = Locale::new(lang_from_code("FR"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -5365,7 +5366,7 @@ fn pending_duration_ga_duration_ga_corpus_hs_latent() {
     let dim = dim_from_name("Duration");
     let locale = Locale::new(lang_from_code("GA"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -5450,7 +5451,7 @@ fn pending_duration_hi_duration_hi_corpus_hs_latent() {
     let dim = dim_from_name("Duration");
     let locale = Locale::new(lang_from_code("HI"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -5535,7 +5536,7 @@ fn pending_duration_hu_duration_hu_corpus_hs_latent() {
     let dim = dim_from_name("Duration");
     let locale = Locale::new(lang_from_code("HU"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -5620,7 +5621,7 @@ fn pending_duration_ja_duration_ja_corpus_hs_latent() {
     let dim = dim_from_name("Duration");
     let locale = Locale::new(lang_from_code("JA"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -5705,7 +5706,7 @@ fn pending_duration_ka_duration_ka_corpus_hs_latent() {
     let dim = dim_from_name("Duration");
     let locale = Locale::new(lang_from_code("KA"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -5790,7 +5791,7 @@ fn pending_duration_ko_duration_ko_corpus_hs_latent() {
     let dim = dim_from_name("Duration");
     let locale = Locale::new(lang_from_code("KO"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -5875,7 +5876,7 @@ fn pending_duration_mn_duration_mn_corpus_hs_latent() {
     let dim = dim_from_name("Duration");
     let locale = Locale::new(lang_from_code("MN"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -5960,7 +5961,7 @@ fn pending_duration_nb_duration_nb_corpus_hs_latent() {
     let dim = dim_from_name("Duration");
     let locale = Locale::new(lang_from_code("NB"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -6045,7 +6046,7 @@ fn pending_duration_nl_duration_nl_corpus_hs_latent() {
     let dim = dim_from_name("Duration");
     let locale = Locale::new(lang_from_code("NL"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -6130,7 +6131,7 @@ fn pending_duration_pl_duration_pl_corpus_hs_latent() {
     let dim = dim_from_name("Duration");
     let locale = Locale::new(lang_from_code("PL"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -6215,7 +6216,7 @@ fn pending_duration_pt_duration_pt_corpus_hs_latent() {
     let dim = dim_from_name("Duration");
     let locale = Locale::new(lang_from_code("PT"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -6300,7 +6301,7 @@ fn pending_duration_ro_duration_ro_corpus_hs_latent() {
     let dim = dim_from_name("Duration");
     let locale = Locale::new(lang_from_code("RO"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -6385,7 +6386,7 @@ fn pending_duration_ru_duration_ru_corpus_hs_latent() {
     let dim = dim_from_name("Duration");
     let locale = Locale::new(lang_from_code("RU"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -6470,7 +6471,7 @@ fn pending_duration_sv_duration_sv_corpus_hs_latent() {
     let dim = dim_from_name("Duration");
     let locale = Locale::new(lang_from_code("SV"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -6555,7 +6556,7 @@ fn pending_duration_tr_duration_tr_corpus_hs_latent() {
     let dim = dim_from_name("Duration");
     let locale = Locale::new(lang_from_code("TR"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -6640,7 +6641,7 @@ fn pending_duration_uk_duration_uk_corpus_hs_latent() {
     let dim = dim_from_name("Duration");
     let locale = Locale::new(lang_from_code("UK"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -6725,7 +6726,7 @@ fn pending_duration_zh_duration_zh_corpus_hs_latent() {
     let dim = dim_from_name("Duration");
     let locale = Locale::new(lang_from_code("ZH"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -6810,7 +6811,7 @@ fn pending_email_common_email_corpus_hs_latent() {
     let dim = dim_from_name("Email");
     let locale = Locale::new(lang_from_code("COMMON"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -6895,7 +6896,7 @@ fn pending_email_de_email_de_corpus_hs_latent() {
     let dim = dim_from_name("Email");
     let locale = Locale::new(lang_from_code("DE"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -6980,7 +6981,7 @@ fn pending_email_fr_email_fr_corpus_hs_latent() {
     let dim = dim_from_name("Email");
     let locale = Locale::new(lang_from_code("FR"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -7065,7 +7066,7 @@ fn pending_email_is_email_is_corpus_hs_latent() {
     let dim = dim_from_name("Email");
     let locale = Locale::new(lang_from_code("IS"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -7150,7 +7151,7 @@ fn pending_email_it_email_it_corpus_hs_latent() {
     let dim = dim_from_name("Email");
     let locale = Locale::new(lang_from_code("IT"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -7235,7 +7236,7 @@ fn pending_numeral_af_numeral_af_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("AF"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -7320,7 +7321,7 @@ fn pending_numeral_ar_numeral_ar_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("AR"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -7405,7 +7406,7 @@ fn pending_numeral_ar_eg_numeral_ar_eg_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("AR"), region_from_code("EG"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -7490,7 +7491,7 @@ fn pending_numeral_bg_numeral_bg_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("BG"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -7575,7 +7576,7 @@ fn pending_numeral_bn_numeral_bn_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("BN"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -7660,7 +7661,7 @@ fn pending_numeral_ca_numeral_ca_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("CA"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -7745,7 +7746,7 @@ fn pending_numeral_cs_numeral_cs_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("CS"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -7830,7 +7831,7 @@ fn pending_numeral_da_numeral_da_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("DA"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -7915,7 +7916,7 @@ fn pending_numeral_de_numeral_de_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("DE"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -8000,7 +8001,7 @@ fn pending_numeral_el_numeral_el_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("EL"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -8085,7 +8086,7 @@ fn pending_numeral_es_ar_numeral_es_ar_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("ES"), region_from_code("AR"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -8170,7 +8171,7 @@ fn pending_numeral_es_cl_numeral_es_cl_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("ES"), region_from_code("CL"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -8255,7 +8256,7 @@ fn pending_numeral_es_co_numeral_es_co_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("ES"), region_from_code("CO"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -8340,7 +8341,7 @@ fn pending_numeral_es_numeral_es_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("ES"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -8425,7 +8426,7 @@ fn pending_numeral_es_es_numeral_es_es_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("ES"), region_from_code("ES"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -8510,7 +8511,7 @@ fn pending_numeral_es_mx_numeral_es_mx_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("ES"), region_from_code("MX"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -8595,7 +8596,7 @@ fn pending_numeral_es_pe_numeral_es_pe_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("ES"), region_from_code("PE"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -8680,7 +8681,7 @@ fn pending_numeral_es_ve_numeral_es_ve_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("ES"), region_from_code("VE"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -8765,7 +8766,7 @@ fn pending_numeral_et_numeral_et_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("ET"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -8850,7 +8851,7 @@ fn pending_numeral_fa_numeral_fa_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("FA"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -8935,7 +8936,7 @@ fn pending_numeral_fi_numeral_fi_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("FI"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -9020,7 +9021,7 @@ fn pending_numeral_fr_numeral_fr_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("FR"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -9105,7 +9106,7 @@ fn pending_numeral_ga_numeral_ga_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("GA"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -9190,7 +9191,7 @@ fn pending_numeral_he_numeral_he_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("HE"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -9275,7 +9276,7 @@ fn pending_numeral_hi_numeral_hi_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("HI"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -9360,7 +9361,7 @@ fn pending_numeral_hr_numeral_hr_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("HR"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -9445,7 +9446,7 @@ fn pending_numeral_hu_numeral_hu_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("HU"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -9530,7 +9531,7 @@ fn pending_numeral_id_numeral_id_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("ID"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -9615,7 +9616,7 @@ fn pending_numeral_is_numeral_is_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("IS"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -9700,7 +9701,7 @@ fn pending_numeral_it_numeral_it_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("IT"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -9785,7 +9786,7 @@ fn pending_numeral_ja_numeral_ja_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("JA"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -9870,7 +9871,7 @@ fn pending_numeral_ka_numeral_ka_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("KA"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -9955,7 +9956,7 @@ fn pending_numeral_km_numeral_km_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("KM"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -10040,7 +10041,7 @@ fn pending_numeral_kn_numeral_kn_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("KN"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -10125,7 +10126,7 @@ fn pending_numeral_ko_numeral_ko_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("KO"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -10210,7 +10211,7 @@ fn pending_numeral_lo_numeral_lo_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("LO"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -10295,7 +10296,7 @@ fn pending_numeral_ml_numeral_ml_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("ML"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -10380,7 +10381,7 @@ fn pending_numeral_mn_numeral_mn_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("MN"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -10465,7 +10466,7 @@ fn pending_numeral_my_numeral_my_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("MY"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -10550,7 +10551,7 @@ fn pending_numeral_nb_numeral_nb_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("NB"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -10635,7 +10636,7 @@ fn pending_numeral_ne_numeral_ne_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("NE"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -10720,7 +10721,7 @@ fn pending_numeral_nl_numeral_nl_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("NL"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -10805,7 +10806,7 @@ fn pending_numeral_pl_numeral_pl_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("PL"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -10890,7 +10891,7 @@ fn pending_numeral_pt_numeral_pt_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("PT"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -10975,7 +10976,7 @@ fn pending_numeral_ro_numeral_ro_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("RO"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -11060,7 +11061,7 @@ fn pending_numeral_ru_numeral_ru_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("RU"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -11145,7 +11146,7 @@ fn pending_numeral_sk_numeral_sk_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("SK"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -11230,7 +11231,7 @@ fn pending_numeral_sv_numeral_sv_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("SV"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -11315,7 +11316,7 @@ fn pending_numeral_sw_numeral_sw_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("SW"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -11400,7 +11401,7 @@ fn pending_numeral_ta_numeral_ta_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("TA"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -11485,7 +11486,7 @@ fn pending_numeral_te_numeral_te_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("TE"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -11570,7 +11571,7 @@ fn pending_numeral_th_numeral_th_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("TH"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -11655,7 +11656,7 @@ fn pending_numeral_tr_numeral_tr_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("TR"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -11740,7 +11741,7 @@ fn pending_numeral_uk_numeral_uk_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("UK"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -11825,7 +11826,7 @@ fn pending_numeral_vi_numeral_vi_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("VI"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -11910,7 +11911,7 @@ fn pending_numeral_zh_numeral_zh_corpus_hs_latent() {
     let dim = dim_from_name("Numeral");
     let locale = Locale::new(lang_from_code("ZH"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -11995,7 +11996,7 @@ fn pending_ordinal_ar_ordinal_ar_corpus_hs_latent() {
     let dim = dim_from_name("Ordinal");
     let locale = Locale::new(lang_from_code("AR"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -12080,7 +12081,7 @@ fn pending_ordinal_bg_ordinal_bg_corpus_hs_latent() {
     let dim = dim_from_name("Ordinal");
     let locale = Locale::new(lang_from_code("BG"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -12165,7 +12166,7 @@ fn pending_ordinal_ca_ordinal_ca_corpus_hs_latent() {
     let dim = dim_from_name("Ordinal");
     let locale = Locale::new(lang_from_code("CA"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -12250,7 +12251,7 @@ fn pending_ordinal_da_ordinal_da_corpus_hs_latent() {
     let dim = dim_from_name("Ordinal");
     let locale = Locale::new(lang_from_code("DA"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -12335,7 +12336,7 @@ fn pending_ordinal_de_ordinal_de_corpus_hs_latent() {
     let dim = dim_from_name("Ordinal");
     let locale = Locale::new(lang_from_code("DE"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -12420,7 +12421,7 @@ fn pending_ordinal_el_ordinal_el_corpus_hs_latent() {
     let dim = dim_from_name("Ordinal");
     let locale = Locale::new(lang_from_code("EL"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -12505,7 +12506,7 @@ fn pending_ordinal_es_ordinal_es_corpus_hs_latent() {
     let dim = dim_from_name("Ordinal");
     let locale = Locale::new(lang_from_code("ES"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -12590,7 +12591,7 @@ fn pending_ordinal_et_ordinal_et_corpus_hs_latent() {
     let dim = dim_from_name("Ordinal");
     let locale = Locale::new(lang_from_code("ET"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -12675,7 +12676,7 @@ fn pending_ordinal_fr_ordinal_fr_corpus_hs_latent() {
     let dim = dim_from_name("Ordinal");
     let locale = Locale::new(lang_from_code("FR"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -12760,7 +12761,7 @@ fn pending_ordinal_ga_ordinal_ga_corpus_hs_latent() {
     let dim = dim_from_name("Ordinal");
     let locale = Locale::new(lang_from_code("GA"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -12845,7 +12846,7 @@ fn pending_ordinal_he_ordinal_he_corpus_hs_latent() {
     let dim = dim_from_name("Ordinal");
     let locale = Locale::new(lang_from_code("HE"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -12930,7 +12931,7 @@ fn pending_ordinal_hi_ordinal_hi_corpus_hs_latent() {
     let dim = dim_from_name("Ordinal");
     let locale = Locale::new(lang_from_code("HI"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -13015,7 +13016,7 @@ fn pending_ordinal_hr_ordinal_hr_corpus_hs_latent() {
     let dim = dim_from_name("Ordinal");
     let locale = Locale::new(lang_from_code("HR"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -13100,7 +13101,7 @@ fn pending_ordinal_hu_ordinal_hu_corpus_hs_latent() {
     let dim = dim_from_name("Ordinal");
     let locale = Locale::new(lang_from_code("HU"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -13185,7 +13186,7 @@ fn pending_ordinal_id_ordinal_id_corpus_hs_latent() {
     let dim = dim_from_name("Ordinal");
     let locale = Locale::new(lang_from_code("ID"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -13270,7 +13271,7 @@ fn pending_ordinal_it_ordinal_it_corpus_hs_latent() {
     let dim = dim_from_name("Ordinal");
     let locale = Locale::new(lang_from_code("IT"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -13355,7 +13356,7 @@ fn pending_ordinal_ja_ordinal_ja_corpus_hs_latent() {
     let dim = dim_from_name("Ordinal");
     let locale = Locale::new(lang_from_code("JA"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -13440,7 +13441,7 @@ fn pending_ordinal_ka_ordinal_ka_corpus_hs_latent() {
     let dim = dim_from_name("Ordinal");
     let locale = Locale::new(lang_from_code("KA"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -13525,7 +13526,7 @@ fn pending_ordinal_km_ordinal_km_corpus_hs_latent() {
     let dim = dim_from_name("Ordinal");
     let locale = Locale::new(lang_from_code("KM"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -13610,7 +13611,7 @@ fn pending_ordinal_ko_ordinal_ko_corpus_hs_latent() {
     let dim = dim_from_name("Ordinal");
     let locale = Locale::new(lang_from_code("KO"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -13695,7 +13696,7 @@ fn pending_ordinal_ml_ordinal_ml_corpus_hs_latent() {
     let dim = dim_from_name("Ordinal");
     let locale = Locale::new(lang_from_code("ML"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -13780,7 +13781,7 @@ fn pending_ordinal_mn_ordinal_mn_corpus_hs_latent() {
     let dim = dim_from_name("Ordinal");
     let locale = Locale::new(lang_from_code("MN"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -13865,7 +13866,7 @@ fn pending_ordinal_nb_ordinal_nb_corpus_hs_latent() {
     let dim = dim_from_name("Ordinal");
     let locale = Locale::new(lang_from_code("NB"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -13950,7 +13951,7 @@ fn pending_ordinal_nl_ordinal_nl_corpus_hs_latent() {
     let dim = dim_from_name("Ordinal");
     let locale = Locale::new(lang_from_code("NL"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -14035,7 +14036,7 @@ fn pending_ordinal_pl_ordinal_pl_corpus_hs_latent() {
     let dim = dim_from_name("Ordinal");
     let locale = Locale::new(lang_from_code("PL"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -14120,7 +14121,7 @@ fn pending_ordinal_pt_ordinal_pt_corpus_hs_latent() {
     let dim = dim_from_name("Ordinal");
     let locale = Locale::new(lang_from_code("PT"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -14205,7 +14206,7 @@ fn pending_ordinal_ro_ordinal_ro_corpus_hs_latent() {
     let dim = dim_from_name("Ordinal");
     let locale = Locale::new(lang_from_code("RO"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -14290,7 +14291,7 @@ fn pending_ordinal_ru_ordinal_ru_corpus_hs_latent() {
     let dim = dim_from_name("Ordinal");
     let locale = Locale::new(lang_from_code("RU"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -14375,7 +14376,7 @@ fn pending_ordinal_sv_ordinal_sv_corpus_hs_latent() {
     let dim = dim_from_name("Ordinal");
     let locale = Locale::new(lang_from_code("SV"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -14460,7 +14461,7 @@ fn pending_ordinal_ta_ordinal_ta_corpus_hs_latent() {
     let dim = dim_from_name("Ordinal");
     let locale = Locale::new(lang_from_code("TA"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -14545,7 +14546,7 @@ fn pending_ordinal_tr_ordinal_tr_corpus_hs_latent() {
     let dim = dim_from_name("Ordinal");
     let locale = Locale::new(lang_from_code("TR"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -14630,7 +14631,7 @@ fn pending_ordinal_uk_ordinal_uk_corpus_hs_latent() {
     let dim = dim_from_name("Ordinal");
     let locale = Locale::new(lang_from_code("UK"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -14715,7 +14716,7 @@ fn pending_ordinal_vi_ordinal_vi_corpus_hs_latent() {
     let dim = dim_from_name("Ordinal");
     let locale = Locale::new(lang_from_code("VI"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -14800,7 +14801,7 @@ fn pending_ordinal_zh_ordinal_zh_corpus_hs_latent() {
     let dim = dim_from_name("Ordinal");
     let locale = Locale::new(lang_from_code("ZH"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -14885,7 +14886,7 @@ fn pending_phonenumber_ar_phonenumber_ar_corpus_hs_latent() {
     let dim = dim_from_name("PhoneNumber");
     let locale = Locale::new(lang_from_code("AR"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -14970,7 +14971,7 @@ fn pending_phonenumber_pt_phonenumber_pt_corpus_hs_latent() {
     let dim = dim_from_name("PhoneNumber");
     let locale = Locale::new(lang_from_code("PT"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -15055,7 +15056,7 @@ fn pending_quantity_ar_quantity_ar_corpus_hs_latent() {
     let dim = dim_from_name("Quantity");
     let locale = Locale::new(lang_from_code("AR"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -15140,7 +15141,7 @@ fn pending_quantity_es_quantity_es_corpus_hs_latent() {
     let dim = dim_from_name("Quantity");
     let locale = Locale::new(lang_from_code("ES"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -15225,7 +15226,7 @@ fn pending_quantity_fr_quantity_fr_corpus_hs_latent() {
     let dim = dim_from_name("Quantity");
     let locale = Locale::new(lang_from_code("FR"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -15310,7 +15311,7 @@ fn pending_quantity_hr_quantity_hr_corpus_hs_latent() {
     let dim = dim_from_name("Quantity");
     let locale = Locale::new(lang_from_code("HR"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -15395,7 +15396,7 @@ fn pending_quantity_km_quantity_km_corpus_hs_latent() {
     let dim = dim_from_name("Quantity");
     let locale = Locale::new(lang_from_code("KM"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -15480,7 +15481,7 @@ fn pending_quantity_ko_quantity_ko_corpus_hs_latent() {
     let dim = dim_from_name("Quantity");
     let locale = Locale::new(lang_from_code("KO"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -15565,7 +15566,7 @@ fn pending_quantity_mn_quantity_mn_corpus_hs_latent() {
     let dim = dim_from_name("Quantity");
     let locale = Locale::new(lang_from_code("MN"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -15650,7 +15651,7 @@ fn pending_quantity_nl_quantity_nl_corpus_hs_latent() {
     let dim = dim_from_name("Quantity");
     let locale = Locale::new(lang_from_code("NL"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -15735,7 +15736,7 @@ fn pending_quantity_pt_quantity_pt_corpus_hs_latent() {
     let dim = dim_from_name("Quantity");
     let locale = Locale::new(lang_from_code("PT"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -15820,7 +15821,7 @@ fn pending_quantity_ro_quantity_ro_corpus_hs_latent() {
     let dim = dim_from_name("Quantity");
     let locale = Locale::new(lang_from_code("RO"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -15905,7 +15906,7 @@ fn pending_quantity_ru_quantity_ru_corpus_hs_latent() {
     let dim = dim_from_name("Quantity");
     let locale = Locale::new(lang_from_code("RU"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -15990,7 +15991,7 @@ fn pending_quantity_zh_quantity_zh_corpus_hs_latent() {
     let dim = dim_from_name("Quantity");
     let locale = Locale::new(lang_from_code("ZH"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -16075,7 +16076,7 @@ fn pending_temperature_ar_temperature_ar_corpus_hs_latent() {
     let dim = dim_from_name("Temperature");
     let locale = Locale::new(lang_from_code("AR"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -16160,7 +16161,7 @@ fn pending_temperature_ca_temperature_ca_corpus_hs_latent() {
     let dim = dim_from_name("Temperature");
     let locale = Locale::new(lang_from_code("CA"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -16245,7 +16246,7 @@ fn pending_temperature_es_temperature_es_corpus_hs_latent() {
     let dim = dim_from_name("Temperature");
     let locale = Locale::new(lang_from_code("ES"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -16330,7 +16331,7 @@ fn pending_temperature_fr_temperature_fr_corpus_hs_latent() {
     let dim = dim_from_name("Temperature");
     let locale = Locale::new(lang_from_code("FR"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -16415,7 +16416,7 @@ fn pending_temperature_ga_temperature_ga_corpus_hs_latent() {
     let dim = dim_from_name("Temperature");
     let locale = Locale::new(lang_from_code("GA"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -16500,7 +16501,7 @@ fn pending_temperature_hi_temperature_hi_corpus_hs_latent() {
     let dim = dim_from_name("Temperature");
     let locale = Locale::new(lang_from_code("HI"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -16585,7 +16586,7 @@ fn pending_temperature_hr_temperature_hr_corpus_hs_latent() {
     let dim = dim_from_name("Temperature");
     let locale = Locale::new(lang_from_code("HR"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -16670,7 +16671,7 @@ fn pending_temperature_it_temperature_it_corpus_hs_latent() {
     let dim = dim_from_name("Temperature");
     let locale = Locale::new(lang_from_code("IT"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -16755,7 +16756,7 @@ fn pending_temperature_ja_temperature_ja_corpus_hs_latent() {
     let dim = dim_from_name("Temperature");
     let locale = Locale::new(lang_from_code("JA"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -16840,7 +16841,7 @@ fn pending_temperature_km_temperature_km_corpus_hs_latent() {
     let dim = dim_from_name("Temperature");
     let locale = Locale::new(lang_from_code("KM"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -16925,7 +16926,7 @@ fn pending_temperature_ko_temperature_ko_corpus_hs_latent() {
     let dim = dim_from_name("Temperature");
     let locale = Locale::new(lang_from_code("KO"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -17010,7 +17011,7 @@ fn pending_temperature_mn_temperature_mn_corpus_hs_latent() {
     let dim = dim_from_name("Temperature");
     let locale = Locale::new(lang_from_code("MN"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -17095,7 +17096,7 @@ fn pending_temperature_pt_temperature_pt_corpus_hs_latent() {
     let dim = dim_from_name("Temperature");
     let locale = Locale::new(lang_from_code("PT"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -17180,7 +17181,7 @@ fn pending_temperature_ro_temperature_ro_corpus_hs_latent() {
     let dim = dim_from_name("Temperature");
     let locale = Locale::new(lang_from_code("RO"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -17265,7 +17266,7 @@ fn pending_temperature_tr_temperature_tr_corpus_hs_latent() {
     let dim = dim_from_name("Temperature");
     let locale = Locale::new(lang_from_code("TR"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -17350,7 +17351,7 @@ fn pending_temperature_zh_temperature_zh_corpus_hs_latent() {
     let dim = dim_from_name("Temperature");
     let locale = Locale::new(lang_from_code("ZH"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -17435,7 +17436,7 @@ fn pending_time_ar_time_ar_corpus_hs_latent() {
     let dim = dim_from_name("Time");
     let locale = Locale::new(lang_from_code("AR"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -17520,7 +17521,7 @@ fn pending_time_bg_time_bg_corpus_hs_latent() {
     let dim = dim_from_name("Time");
     let locale = Locale::new(lang_from_code("BG"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -17605,7 +17606,7 @@ fn pending_time_ca_time_ca_corpus_hs_latent() {
     let dim = dim_from_name("Time");
     let locale = Locale::new(lang_from_code("CA"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -17689,7 +17690,7 @@ fn pending_time_common_time_corpus_hs_latent() {
     let dim = dim_from_name("Time");
     let locale = Locale::new(lang_from_code("COMMON"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -17774,7 +17775,7 @@ fn pending_time_da_time_da_corpus_hs_latent() {
     let dim = dim_from_name("Time");
     let locale = Locale::new(lang_from_code("DA"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -17859,7 +17860,7 @@ fn pending_time_de_time_de_corpus_hs_latent() {
     let dim = dim_from_name("Time");
     let locale = Locale::new(lang_from_code("DE"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -17944,7 +17945,7 @@ fn pending_time_el_time_el_corpus_hs_latent() {
     let dim = dim_from_name("Time");
     let locale = Locale::new(lang_from_code("EL"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -18029,7 +18030,7 @@ fn pending_time_en_au_time_en_au_corpus_hs_latent() {
     let dim = dim_from_name("Time");
     let locale = Locale::new(lang_from_code("EN"), region_from_code("AU"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -18114,7 +18115,7 @@ fn pending_time_en_bz_time_en_bz_corpus_hs_latent() {
     let dim = dim_from_name("Time");
     let locale = Locale::new(lang_from_code("EN"), region_from_code("BZ"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -18199,7 +18200,7 @@ fn pending_time_en_ca_time_en_ca_corpus_hs_latent() {
     let dim = dim_from_name("Time");
     let locale = Locale::new(lang_from_code("EN"), region_from_code("CA"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -18284,7 +18285,7 @@ fn pending_time_en_gb_time_en_gb_corpus_hs_latent() {
     let dim = dim_from_name("Time");
     let locale = Locale::new(lang_from_code("EN"), region_from_code("GB"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -18369,7 +18370,7 @@ fn pending_time_en_ie_time_en_ie_corpus_hs_latent() {
     let dim = dim_from_name("Time");
     let locale = Locale::new(lang_from_code("EN"), region_from_code("IE"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -18454,7 +18455,7 @@ fn pending_time_en_in_time_en_in_corpus_hs_latent() {
     let dim = dim_from_name("Time");
     let locale = Locale::new(lang_from_code("EN"), region_from_code("IN"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -18539,7 +18540,7 @@ fn pending_time_en_jm_time_en_jm_corpus_hs_latent() {
     let dim = dim_from_name("Time");
     let locale = Locale::new(lang_from_code("EN"), region_from_code("JM"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -18624,7 +18625,7 @@ fn pending_time_en_nz_time_en_nz_corpus_hs_latent() {
     let dim = dim_from_name("Time");
     let locale = Locale::new(lang_from_code("EN"), region_from_code("NZ"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -18709,7 +18710,7 @@ fn pending_time_en_ph_time_en_ph_corpus_hs_latent() {
     let dim = dim_from_name("Time");
     let locale = Locale::new(lang_from_code("EN"), region_from_code("PH"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -18794,7 +18795,7 @@ fn pending_time_en_tt_time_en_tt_corpus_hs_latent() {
     let dim = dim_from_name("Time");
     let locale = Locale::new(lang_from_code("EN"), region_from_code("TT"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -18879,7 +18880,7 @@ fn pending_time_en_us_time_en_us_corpus_hs_latent() {
     let dim = dim_from_name("Time");
     let locale = Locale::new(lang_from_code("EN"), region_from_code("US"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -18964,7 +18965,7 @@ fn pending_time_en_za_time_en_za_corpus_hs_latent() {
     let dim = dim_from_name("Time");
     let locale = Locale::new(lang_from_code("EN"), region_from_code("ZA"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -19049,7 +19050,7 @@ fn pending_time_es_time_es_corpus_hs_latent() {
     let dim = dim_from_name("Time");
     let locale = Locale::new(lang_from_code("ES"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -19134,7 +19135,7 @@ fn pending_time_fr_time_fr_corpus_hs_latent() {
     let dim = dim_from_name("Time");
     let locale = Locale::new(lang_from_code("FR"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -19219,7 +19220,7 @@ fn pending_time_ga_time_ga_corpus_hs_latent() {
     let dim = dim_from_name("Time");
     let locale = Locale::new(lang_from_code("GA"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -19304,7 +19305,7 @@ fn pending_time_he_time_he_corpus_hs_latent() {
     let dim = dim_from_name("Time");
     let locale = Locale::new(lang_from_code("HE"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -19389,7 +19390,7 @@ fn pending_time_hr_time_hr_corpus_hs_latent() {
     let dim = dim_from_name("Time");
     let locale = Locale::new(lang_from_code("HR"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -19474,7 +19475,7 @@ fn pending_time_hu_time_hu_corpus_hs_latent() {
     let dim = dim_from_name("Time");
     let locale = Locale::new(lang_from_code("HU"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -19559,7 +19560,7 @@ fn pending_time_it_time_it_corpus_hs_latent() {
     let dim = dim_from_name("Time");
     let locale = Locale::new(lang_from_code("IT"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -19644,7 +19645,7 @@ fn pending_time_ja_time_ja_corpus_hs_latent() {
     let dim = dim_from_name("Time");
     let locale = Locale::new(lang_from_code("JA"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -19729,7 +19730,7 @@ fn pending_time_ka_time_ka_corpus_hs_latent() {
     let dim = dim_from_name("Time");
     let locale = Locale::new(lang_from_code("KA"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -19814,7 +19815,7 @@ fn pending_time_ko_time_ko_corpus_hs_latent() {
     let dim = dim_from_name("Time");
     let locale = Locale::new(lang_from_code("KO"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -19899,7 +19900,7 @@ fn pending_time_nb_time_nb_corpus_hs_latent() {
     let dim = dim_from_name("Time");
     let locale = Locale::new(lang_from_code("NB"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -19984,7 +19985,7 @@ fn pending_time_nl_be_time_nl_be_corpus_hs_latent() {
     let dim = dim_from_name("Time");
     let locale = Locale::new(lang_from_code("NL"), region_from_code("BE"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -20069,7 +20070,7 @@ fn pending_time_nl_time_nl_corpus_hs_latent() {
     let dim = dim_from_name("Time");
     let locale = Locale::new(lang_from_code("NL"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -20154,7 +20155,7 @@ fn pending_time_pl_time_pl_corpus_hs_latent() {
     let dim = dim_from_name("Time");
     let locale = Locale::new(lang_from_code("PL"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -20239,7 +20240,7 @@ fn pending_time_pt_time_pt_corpus_hs_latent() {
     let dim = dim_from_name("Time");
     let locale = Locale::new(lang_from_code("PT"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -20324,7 +20325,7 @@ fn pending_time_ro_time_ro_corpus_hs_latent() {
     let dim = dim_from_name("Time");
     let locale = Locale::new(lang_from_code("RO"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -20409,7 +20410,7 @@ fn pending_time_ru_time_ru_corpus_hs_latent() {
     let dim = dim_from_name("Time");
     let locale = Locale::new(lang_from_code("RU"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -20494,7 +20495,7 @@ fn pending_time_sv_time_sv_corpus_hs_latent() {
     let dim = dim_from_name("Time");
     let locale = Locale::new(lang_from_code("SV"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -20579,7 +20580,7 @@ fn pending_time_tr_time_tr_corpus_hs_latent() {
     let dim = dim_from_name("Time");
     let locale = Locale::new(lang_from_code("TR"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -20664,7 +20665,7 @@ fn pending_time_uk_time_uk_corpus_hs_latent() {
     let dim = dim_from_name("Time");
     let locale = Locale::new(lang_from_code("UK"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -20749,7 +20750,7 @@ fn pending_time_vi_time_vi_corpus_hs_latent() {
     let dim = dim_from_name("Time");
     let locale = Locale::new(lang_from_code("VI"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -20834,7 +20835,7 @@ fn pending_time_zh_cn_time_zh_cn_corpus_hs_latent() {
     let dim = dim_from_name("Time");
     let locale = Locale::new(lang_from_code("ZH"), region_from_code("CN"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -20919,7 +20920,7 @@ fn pending_time_zh_time_zh_corpus_hs_latent() {
     let dim = dim_from_name("Time");
     let locale = Locale::new(lang_from_code("ZH"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -21004,7 +21005,7 @@ fn pending_time_zh_hk_time_zh_hk_corpus_hs_latent() {
     let dim = dim_from_name("Time");
     let locale = Locale::new(lang_from_code("ZH"), region_from_code("HK"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -21089,7 +21090,7 @@ fn pending_time_zh_mo_time_zh_mo_corpus_hs_latent() {
     let dim = dim_from_name("Time");
     let locale = Locale::new(lang_from_code("ZH"), region_from_code("MO"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -21174,7 +21175,7 @@ fn pending_time_zh_tw_time_zh_tw_corpus_hs_latent() {
     let dim = dim_from_name("Time");
     let locale = Locale::new(lang_from_code("ZH"), region_from_code("TW"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -21259,7 +21260,7 @@ fn pending_volume_ar_volume_ar_corpus_hs_latent() {
     let dim = dim_from_name("Volume");
     let locale = Locale::new(lang_from_code("AR"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -21344,7 +21345,7 @@ fn pending_volume_ca_volume_ca_corpus_hs_latent() {
     let dim = dim_from_name("Volume");
     let locale = Locale::new(lang_from_code("CA"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -21429,7 +21430,7 @@ fn pending_volume_de_volume_de_corpus_hs_latent() {
     let dim = dim_from_name("Volume");
     let locale = Locale::new(lang_from_code("DE"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -21514,7 +21515,7 @@ fn pending_volume_es_volume_es_corpus_hs_latent() {
     let dim = dim_from_name("Volume");
     let locale = Locale::new(lang_from_code("ES"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -21599,7 +21600,7 @@ fn pending_volume_fr_volume_fr_corpus_hs_latent() {
     let dim = dim_from_name("Volume");
     let locale = Locale::new(lang_from_code("FR"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -21684,7 +21685,7 @@ fn pending_volume_ga_volume_ga_corpus_hs_latent() {
     let dim = dim_from_name("Volume");
     let locale = Locale::new(lang_from_code("GA"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -21769,7 +21770,7 @@ fn pending_volume_hr_volume_hr_corpus_hs_latent() {
     let dim = dim_from_name("Volume");
     let locale = Locale::new(lang_from_code("HR"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -21854,7 +21855,7 @@ fn pending_volume_it_volume_it_corpus_hs_latent() {
     let dim = dim_from_name("Volume");
     let locale = Locale::new(lang_from_code("IT"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -21939,7 +21940,7 @@ fn pending_volume_km_volume_km_corpus_hs_latent() {
     let dim = dim_from_name("Volume");
     let locale = Locale::new(lang_from_code("KM"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -22024,7 +22025,7 @@ fn pending_volume_ko_volume_ko_corpus_hs_latent() {
     let dim = dim_from_name("Volume");
     let locale = Locale::new(lang_from_code("KO"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -22109,7 +22110,7 @@ fn pending_volume_mn_volume_mn_corpus_hs_latent() {
     let dim = dim_from_name("Volume");
     let locale = Locale::new(lang_from_code("MN"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -22194,7 +22195,7 @@ fn pending_volume_nl_volume_nl_corpus_hs_latent() {
     let dim = dim_from_name("Volume");
     let locale = Locale::new(lang_from_code("NL"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -22279,7 +22280,7 @@ fn pending_volume_pt_volume_pt_corpus_hs_latent() {
     let dim = dim_from_name("Volume");
     let locale = Locale::new(lang_from_code("PT"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -22364,7 +22365,7 @@ fn pending_volume_ro_volume_ro_corpus_hs_latent() {
     let dim = dim_from_name("Volume");
     let locale = Locale::new(lang_from_code("RO"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -22449,7 +22450,7 @@ fn pending_volume_ru_volume_ru_corpus_hs_latent() {
     let dim = dim_from_name("Volume");
     let locale = Locale::new(lang_from_code("RU"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -22534,7 +22535,7 @@ fn pending_volume_tr_volume_tr_corpus_hs_latent() {
     let dim = dim_from_name("Volume");
     let locale = Locale::new(lang_from_code("TR"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
@@ -22619,7 +22620,7 @@ fn pending_volume_zh_volume_zh_corpus_hs_latent() {
     let dim = dim_from_name("Volume");
     let locale = Locale::new(lang_from_code("ZH"), region_from_code("-"));
     let ctx = parse_context(locale);
-    let options = Options { with_latent: true };
+    let options = Options::builder().with_latent(true).build();
     let utterances = extract_latent_examples_strings(corpus);
     if utterances.is_empty() {
         return;
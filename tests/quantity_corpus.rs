@@ -212,3 +212,44 @@ fn test_quantity_above_4oz_chocolate() {
         "chocolate",
     );
 }
+
+// Kitchen units and counts of items, beyond the Haskell corpus.
+#[test]
+fn test_quantity_kitchen_units() {
+    check_quantity("2 tablespoons", 2.0, "tablespoon");
+    check_quantity("1.5 tbsp", 1.5, "tablespoon");
+    check_quantity_with_product("a teaspoon of salt", 1.0, "teaspoon", "salt");
+    check_quantity("3 tsp.", 3.0, "teaspoon");
+    check_quantity_with_product("two sticks of butter", 2.0, "stick", "butter");
+    check_quantity_with_product("a pinch of salt", 1.0, "pinch", "salt");
+    check_quantity("2 pinches", 2.0, "pinch");
+    check_quantity_with_product("3 cans of tomatoes", 3.0, "can", "tomatoes");
+}
+
+#[test]
+fn test_quantity_item_counts() {
+    check_quantity_with_product("two dozen eggs", 24.0, "unnamed", "eggs");
+    check_quantity_with_product("a dozen of eggs", 12.0, "unnamed", "eggs");
+    check_quantity_with_product("a couple of lemons", 2.0, "unnamed", "lemons");
+    check_quantity_with_product("a few apples", 3.0, "unnamed", "apples");
+    for text in [
+        "a few days",
+        "a couple of weeks",
+        "a few more",
+        "two dozen times",
+    ] {
+        let entities = parse_en(text, &[DimensionKind::Quantity]);
+        assert!(entities.is_empty(), "{text:?}: {entities:?}");
+    }
+    // A unit after "a couple of" is counted by its own rule, not also
+    // taken as the product
+    for (text, unit) in [("a couple of cans", "can"), ("two dozen cups", "cup")] {
+        let entities = parse_en(text, &[DimensionKind::Quantity]);
+        assert!(
+            matches!(entities.as_slice(), [e] if matches!(&e.value,
+                DimensionValue::Quantity { measurement: MeasurementValue::Value { unit: u, .. }, product: None }
+                    if u == unit)),
+            "{text:?}: {entities:?}"
+        );
+    }
+}
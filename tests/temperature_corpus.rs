@@ -1,5 +1,8 @@
 // Ported from Duckling/Temperature/EN/Corpus.hs
-use duckling::{parse_en, DimensionKind, DimensionValue, MeasurementPoint, MeasurementValue};
+use duckling::{
+    parse, parse_en, Context, DegreePolicy, DimensionKind, DimensionValue, Lang, Locale,
+    MeasurementPoint, MeasurementValue, Options, Region, TemperatureUnit,
+};
 
 fn check_temperature(text: &str, expected_val: f64, expected_unit: &str) {
     let entities = parse_en(text, &[DimensionKind::Temperature]);
@@ -118,3 +121,67 @@ fn test_temp_under_40_degree() {
     check_temperature("less than 40 degrees", 40.0, "degree");
     check_temperature("lower than 40 degrees", 40.0, "degree");
 }
+
+fn temperature_with(text: &str, region: Option<Region>, policy: DegreePolicy) -> MeasurementValue {
+    let locale = Locale::new(Lang::EN, region);
+    let context = Context {
+        locale,
+        ..Context::default()
+    };
+    let options = Options::builder().degree_policy(policy).build();
+    match parse(
+        text,
+        &locale,
+        &[DimensionKind::Temperature],
+        &context,
+        &options,
+    )
+    .into_iter()
+    .map(|e| e.value)
+    .next()
+    {
+        Some(DimensionValue::Temperature(mv)) => mv,
+        other => panic!("Expected a temperature for '{text}', got: {other:?}"),
+    }
+}
+
+#[test]
+fn test_temp_degree_policy() {
+    // Bare degrees keep the degree unit unless a policy says otherwise
+    let mv = temperature_with(
+        "3 degrees outside",
+        Some(Region::US),
+        DegreePolicy::default(),
+    );
+    assert_eq!(mv.unit().unwrap(), "degree");
+
+    for (region, expected) in [
+        (Some(Region::US), "fahrenheit"),
+        (Some(Region::GB), "celsius"),
+        (None, "celsius"),
+    ] {
+        let mv = temperature_with("3 degrees outside", region, DegreePolicy::Regional);
+        assert_eq!(mv.unit().unwrap(), expected, "{region:?}");
+    }
+
+    let fixed = DegreePolicy::Fixed(TemperatureUnit::Celsius);
+    let mv = temperature_with("under 40 degrees", Some(Region::US), fixed);
+    assert_eq!(
+        mv,
+        MeasurementValue::Interval {
+            from: None,
+            to: Some(MeasurementPoint {
+                value: 40.0,
+                unit: TemperatureUnit::Celsius.into(),
+            }),
+        }
+    );
+
+    // A stated scale is kept whatever the policy
+    let mv = temperature_with(
+        "70 degrees fahrenheit",
+        Some(Region::GB),
+        DegreePolicy::Regional,
+    );
+    assert_eq!(mv.unit().unwrap(), "fahrenheit");
+}
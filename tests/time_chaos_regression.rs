@@ -9,6 +9,7 @@ fn context(locale: Locale) -> Context {
         reference_time: Utc.with_ymd_and_hms(2013, 2, 12, 4, 30, 0).unwrap(),
         locale,
         timezone_offset_minutes: -300,
+        ..Context::default()
     }
 }
 
@@ -4,15 +4,18 @@
 
 use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone, Utc};
 use duckling::{
-    parse, Context, DimensionKind, DimensionValue, Entity, Grain, Lang, Locale, Options, TimePoint,
-    TimeValue,
+    parse, Context, CustomHolidays, DimensionKind, DimensionValue, DurationQualifier, Entity,
+    Grain, Hemisphere, Lang, Locale, NextWeekdayPolicy, ObservedShift, Options, PartOfDayConfig,
+    Region, TimePoint, TimePreference, TimeValue, WeekNumbering,
 };
+use std::sync::Arc;
 
 fn make_context() -> Context {
     Context {
         reference_time: Utc.with_ymd_and_hms(2013, 2, 12, 4, 30, 0).unwrap(),
         locale: Locale::new(Lang::EN, None),
         timezone_offset_minutes: -120, // UTC-2, matching Haskell test context
+        ..Context::default()
     }
 }
 
@@ -1443,6 +1446,40 @@ fn test_time_interval_10_days_from_18th_dec() {
     );
 }
 
+#[test]
+fn test_time_interval_across_midnight() {
+    check_time_interval(
+        "Fri 11pm - Sat 1am",
+        dt(2013, 2, 15, 23, 0, 0),
+        dt(2013, 2, 16, 2, 0, 0),
+        "hour",
+    );
+    check_time_interval(
+        "Friday 10pm to Saturday 2am",
+        dt(2013, 2, 15, 22, 0, 0),
+        dt(2013, 2, 16, 3, 0, 0),
+        "hour",
+    );
+    check_time_interval(
+        "Dec 31 10pm to Jan 1 2am",
+        dt(2013, 12, 31, 22, 0, 0),
+        dt(2014, 1, 1, 3, 0, 0),
+        "hour",
+    );
+    check_time_interval(
+        "Friday 10pm - 2am",
+        dt(2013, 2, 15, 22, 0, 0),
+        dt(2013, 2, 16, 3, 0, 0),
+        "hour",
+    );
+    check_time_interval(
+        "monday to friday",
+        dt(2013, 2, 18, 0, 0, 0),
+        dt(2013, 2, 23, 0, 0, 0),
+        "day",
+    );
+}
+
 #[test]
 fn test_time_interval_30_min_from_4pm() {
     check_time_interval(
@@ -2699,12 +2736,7 @@ fn test_time_by_2pm() {
 
 #[test]
 fn test_time_eod() {
-    check_time_interval(
-        "EOD",
-        dt(2013, 2, 12, 17, 0, 0),
-        dt(2013, 2, 13, 0, 0, 0),
-        "hour",
-    );
+    check_time_naive("EOD", dt(2013, 2, 12, 17, 0, 0), "hour");
     check_time_interval(
         "end of day",
         dt(2013, 2, 12, 17, 0, 0),
@@ -2727,12 +2759,7 @@ fn test_time_eod() {
 
 #[test]
 fn test_time_by_eod() {
-    check_time_interval(
-        "by EOD",
-        dt(2013, 2, 12, 4, 30, 0),
-        dt(2013, 2, 13, 0, 0, 0),
-        "second",
-    );
+    check_time_open_interval_before("by EOD", dt(2013, 2, 12, 17, 0, 0), "hour");
     check_time_interval(
         "by end of day",
         dt(2013, 2, 12, 4, 30, 0),
@@ -2775,6 +2802,680 @@ fn test_time_by_eom() {
     );
 }
 
+#[test]
+fn test_time_business_shorthand() {
+    check_time_naive("COB", dt(2013, 2, 12, 17, 0, 0), "hour");
+    check_time_naive("close of business", dt(2013, 2, 12, 17, 0, 0), "hour");
+    check_time_naive("EOD Friday", dt(2013, 2, 15, 17, 0, 0), "hour");
+    check_time_naive("COB tomorrow", dt(2013, 2, 13, 17, 0, 0), "hour");
+    check_time_naive("EOW", dt(2013, 2, 15, 17, 0, 0), "hour");
+    check_time_open_interval_before("by COB", dt(2013, 2, 12, 17, 0, 0), "hour");
+    check_time_open_interval_before("by EOD Friday", dt(2013, 2, 15, 17, 0, 0), "hour");
+    check_time_open_interval_before("by EOW", dt(2013, 2, 15, 17, 0, 0), "hour");
+    check_time_interval(
+        "EOQ",
+        dt(2013, 3, 1, 0, 0, 0),
+        dt(2013, 4, 1, 0, 0, 0),
+        "month",
+    );
+    check_time_interval(
+        "by EOQ",
+        dt(2013, 2, 12, 4, 30, 0),
+        dt(2013, 4, 1, 0, 0, 0),
+        "second",
+    );
+}
+
+#[test]
+fn test_time_windows_from_now() {
+    check_time_interval(
+        "in the next 2 weeks",
+        dt(2013, 2, 12, 4, 30, 0),
+        dt(2013, 2, 26, 4, 30, 0),
+        "second",
+    );
+    check_time_interval(
+        "in the coming 3 months",
+        dt(2013, 2, 12, 4, 30, 0),
+        dt(2013, 5, 12, 4, 30, 0),
+        "second",
+    );
+    check_time_interval(
+        "within the last 30 days",
+        dt(2013, 1, 13, 4, 30, 0),
+        dt(2013, 2, 12, 4, 30, 0),
+        "second",
+    );
+    check_time_interval(
+        "over the past 6 hours",
+        dt(2013, 2, 11, 22, 30, 0),
+        dt(2013, 2, 12, 4, 30, 0),
+        "second",
+    );
+    check_time_interval(
+        "over the next few days",
+        dt(2013, 2, 12, 4, 30, 0),
+        dt(2013, 2, 15, 4, 30, 0),
+        "second",
+    );
+    // Without the preposition, "next 3 days" stays the upstream calendar days
+    check_time_interval(
+        "next 3 days",
+        dt(2013, 2, 13, 0, 0, 0),
+        dt(2013, 2, 16, 0, 0, 0),
+        "day",
+    );
+}
+
+#[test]
+fn test_time_window_few_count_option() {
+    let context = make_context();
+    let options = Options::builder().few_count(5).build();
+    let entities = parse(
+        "during the past few weeks",
+        &context.locale,
+        &[DimensionKind::Time],
+        &context,
+        &options,
+    );
+    let found = entities.iter().any(|e| match &e.value {
+        DimensionValue::Time(TimeValue::Interval {
+            from: Some(f),
+            to: Some(t),
+            ..
+        }) => {
+            tp_value_grain(f).0 == dt(2013, 1, 8, 4, 30, 0)
+                && tp_value_grain(t).0 == dt(2013, 2, 12, 4, 30, 0)
+        }
+        _ => false,
+    });
+    assert!(found, "Expected a five-week window, got: {:?}", entities);
+}
+
+#[test]
+fn test_time_relative_to_days_and_holidays() {
+    check_time_naive("two weeks from today", dt(2013, 2, 26, 0, 0, 0), "day");
+    check_time_naive("a month from Tuesday", dt(2013, 3, 5, 0, 0, 0), "day");
+    check_time_naive("a week from next friday", dt(2013, 3, 1, 0, 0, 0), "day");
+    check_time_naive(
+        "two days after last friday",
+        dt(2013, 2, 10, 0, 0, 0),
+        "day",
+    );
+    check_time_naive("the day after Easter", dt(2013, 4, 1, 0, 0, 0), "day");
+    check_time_naive("the day before easter", dt(2013, 3, 30, 0, 0, 0), "day");
+    check_time_naive("day after thanksgiving", dt(2013, 11, 29, 0, 0, 0), "day");
+    check_time_naive("christmas eve", dt(2013, 12, 24, 0, 0, 0), "day");
+    check_time_naive("the eve of Christmas", dt(2013, 12, 24, 0, 0, 0), "day");
+    check_time_naive("New Year's Eve eve", dt(2013, 12, 30, 0, 0, 0), "day");
+    check_time_naive("eve of new year's day", dt(2013, 12, 31, 0, 0, 0), "day");
+}
+
+#[test]
+fn test_time_february_29th() {
+    check_time_naive("february 29th", dt(2016, 2, 29, 0, 0, 0), "day");
+    check_time_naive("2/29", dt(2016, 2, 29, 0, 0, 0), "day");
+}
+
+#[test]
+fn test_time_week_numbers() {
+    check_time_naive("week 23", dt(2013, 6, 3, 0, 0, 0), "week");
+    check_time_naive("ISO week 7", dt(2013, 2, 11, 0, 0, 0), "week");
+    check_time_naive("wk 5", dt(2014, 1, 27, 0, 0, 0), "week");
+    check_time_naive("week 1", dt(2013, 12, 30, 0, 0, 0), "week");
+    check_time_naive("week 7 of 2014", dt(2014, 2, 10, 0, 0, 0), "week");
+    check_time_naive("the 12th week of 2025", dt(2025, 3, 17, 0, 0, 0), "week");
+    check_time_naive("the first week of 2014", dt(2013, 12, 30, 0, 0, 0), "week");
+    check_time_naive("the 53rd week of 2015", dt(2015, 12, 28, 0, 0, 0), "week");
+    assert!(parse_time("week 53 of 2014").is_empty());
+}
+
+#[test]
+fn test_time_week_numbering_option() {
+    let context = make_context();
+    let options = Options::builder().week_numbering(WeekNumbering::Us).build();
+    for (text, expected) in [
+        ("week 7", dt(2013, 2, 10, 0, 0, 0)),
+        ("week 1 of 2014", dt(2013, 12, 29, 0, 0, 0)),
+        ("ISO week 7", dt(2013, 2, 11, 0, 0, 0)),
+    ] {
+        let entities = parse(
+            text,
+            &context.locale,
+            &[DimensionKind::Time],
+            &context,
+            &options,
+        );
+        let found = entities.iter().any(|e| match &e.value {
+            DimensionValue::Time(TimeValue::Single { value, .. }) => {
+                tp_value_grain(value) == (expected, Grain::Week)
+            }
+            _ => false,
+        });
+        assert!(found, "Expected {expected} for {text:?}, got: {entities:?}");
+    }
+}
+
+#[test]
+fn test_time_business_day_end_hour_option() {
+    let context = make_context();
+    let options = Options::builder().business_day_end_hour(None).build();
+    let entities = parse(
+        "by EOD Friday",
+        &context.locale,
+        &[DimensionKind::Time],
+        &context,
+        &options,
+    );
+    let found = entities.iter().any(|e| match &e.value {
+        DimensionValue::Time(TimeValue::Interval {
+            from: None,
+            to: Some(t),
+            ..
+        }) => tp_value_grain(t) == (dt(2013, 2, 16, 0, 0, 0), Grain::Day),
+        _ => false,
+    });
+    assert!(
+        found,
+        "Expected open interval before midnight, got: {:?}",
+        entities
+    );
+}
+
+#[test]
+fn test_time_eod_resolves_like_cob() {
+    let context = make_context();
+    let options = Options::builder().business_day_end_hour(18).build();
+    let value = |text: &str| {
+        let entities = parse(
+            text,
+            &context.locale,
+            &[DimensionKind::Time],
+            &context,
+            &options,
+        );
+        entities
+            .into_iter()
+            .find(|e| e.body == text)
+            .map(|e| e.value)
+            .unwrap_or_else(|| panic!("no entity for {text:?}"))
+    };
+    assert_eq!(value("EOD"), value("COB"));
+    assert_eq!(value("by EOD"), value("by COB"));
+    match value("EOD") {
+        DimensionValue::Time(TimeValue::Single { value, .. }) => {
+            assert_eq!(
+                tp_value_grain(&value),
+                (dt(2013, 2, 12, 18, 0, 0), Grain::Hour)
+            );
+        }
+        other => panic!("expected a single time, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_time_part_of_day_boundaries() {
+    check_time_interval(
+        "tonight",
+        dt(2013, 2, 12, 18, 0, 0),
+        dt(2013, 2, 13, 0, 0, 0),
+        "hour",
+    );
+    check_time_interval(
+        "overnight",
+        dt(2013, 2, 12, 22, 0, 0),
+        dt(2013, 2, 13, 6, 0, 0),
+        "hour",
+    );
+    check_time_interval(
+        "noonish",
+        dt(2013, 2, 12, 11, 0, 0),
+        dt(2013, 2, 12, 13, 0, 0),
+        "hour",
+    );
+    check_time_interval(
+        "tomorrow midnightish",
+        dt(2013, 2, 13, 23, 0, 0),
+        dt(2013, 2, 14, 1, 0, 0),
+        "hour",
+    );
+    check_time_interval(
+        "later today",
+        dt(2013, 2, 12, 4, 0, 0),
+        dt(2013, 2, 13, 0, 0, 0),
+        "hour",
+    );
+    check_time_interval(
+        "tomorrow at breakfast",
+        dt(2013, 2, 13, 6, 0, 0),
+        dt(2013, 2, 13, 10, 0, 0),
+        "hour",
+    );
+    check_time_naive("midday", dt(2013, 2, 12, 12, 0, 0), "hour");
+    check_time_naive("dinner at 7", dt(2013, 2, 12, 19, 0, 0), "hour");
+}
+
+#[test]
+fn test_time_part_of_day_config() {
+    let context = make_context();
+    let options = Options::builder()
+        .part_of_day(PartOfDayConfig {
+            evening: (17, 21),
+            night: (0, 6),
+            dinner: (17, 20),
+            ..PartOfDayConfig::default()
+        })
+        .build();
+    let parse_with = |text: &str| {
+        parse(
+            text,
+            &context.locale,
+            &[DimensionKind::Time],
+            &context,
+            &options,
+        )
+    };
+    for (text, start, end) in [
+        (
+            "tomorrow at dinner",
+            dt(2013, 2, 13, 17, 0, 0),
+            dt(2013, 2, 13, 20, 0, 0),
+        ),
+        (
+            "this evening",
+            dt(2013, 2, 12, 17, 0, 0),
+            dt(2013, 2, 12, 21, 0, 0),
+        ),
+        (
+            "late this evening",
+            dt(2013, 2, 12, 18, 0, 0),
+            dt(2013, 2, 12, 21, 0, 0),
+        ),
+        (
+            "tomorrow night",
+            dt(2013, 2, 13, 0, 0, 0),
+            dt(2013, 2, 13, 6, 0, 0),
+        ),
+    ] {
+        let entities = parse_with(text);
+        let found = entities.iter().any(|e| match &e.value {
+            DimensionValue::Time(TimeValue::Interval {
+                from: Some(from),
+                to: Some(to),
+                ..
+            }) => tp_value_grain(from).0 == start && tp_value_grain(to).0 == end,
+            _ => false,
+        });
+        assert!(
+            found,
+            "Expected {start}-{end} for {text:?}, got: {entities:?}"
+        );
+    }
+    for (text, expected) in [
+        ("tomorrow night at 3", dt(2013, 2, 13, 3, 0, 0)),
+        ("evening at 6", dt(2013, 2, 12, 18, 0, 0)),
+    ] {
+        let entities = parse_with(text);
+        let found = entities.iter().any(|e| match &e.value {
+            DimensionValue::Time(TimeValue::Single { value, .. }) => {
+                tp_value_grain(value).0 == expected
+            }
+            _ => false,
+        });
+        assert!(found, "Expected {expected} for {text:?}, got: {entities:?}");
+    }
+}
+
+#[test]
+fn test_time_season_hemisphere() {
+    let summer_interval = |locale: Locale, options: &Options| {
+        let context = Context {
+            locale,
+            ..make_context()
+        };
+        let entities = parse(
+            "this summer",
+            &locale,
+            &[DimensionKind::Time],
+            &context,
+            options,
+        );
+        entities.iter().find_map(|e| match &e.value {
+            DimensionValue::Time(TimeValue::Interval {
+                from: Some(from),
+                to: Some(to),
+                ..
+            }) => Some((tp_value_grain(from).0, tp_value_grain(to).0)),
+            _ => None,
+        })
+    };
+    let southern = Some((dt(2012, 12, 21, 0, 0, 0), dt(2013, 3, 21, 0, 0, 0)));
+    let northern = Some((dt(2013, 6, 21, 0, 0, 0), dt(2013, 9, 24, 0, 0, 0)));
+    let au = Locale::new(Lang::EN, Some(Region::AU));
+    let us = Locale::new(Lang::EN, Some(Region::US));
+    assert_eq!(summer_interval(au, &Options::default()), southern);
+    assert_eq!(summer_interval(us, &Options::default()), northern);
+    let northern_options = Options::builder().hemisphere(Hemisphere::Northern).build();
+    assert_eq!(summer_interval(au, &northern_options), northern);
+    let southern_options = Options::builder().hemisphere(Hemisphere::Southern).build();
+    assert_eq!(summer_interval(us, &southern_options), southern);
+}
+
+#[test]
+fn test_time_regional_holidays() {
+    let holiday_date = |text: &str, context: &Context| {
+        let entities = parse(
+            text,
+            &context.locale,
+            &[DimensionKind::Time],
+            context,
+            &Options::default(),
+        );
+        entities.iter().find_map(|e| match &e.value {
+            DimensionValue::Time(TimeValue::Single { value, .. }) => Some(tp_value_grain(value)),
+            _ => None,
+        })
+    };
+    let in_region = |region: Region| Context {
+        locale: Locale::new(Lang::EN, Some(region)),
+        ..make_context()
+    };
+    let day = |m: u32, d: u32, y: i32| Some((dt(y, m, d, 0, 0, 0), Grain::Day));
+
+    assert_eq!(
+        holiday_date("independence day", &in_region(Region::US)),
+        day(7, 4, 2013)
+    );
+    assert_eq!(
+        holiday_date("independence day", &in_region(Region::IN)),
+        day(8, 15, 2013)
+    );
+    assert_eq!(
+        holiday_date("labor day", &in_region(Region::US)),
+        day(9, 2, 2013)
+    );
+    assert_eq!(
+        holiday_date("labour day", &in_region(Region::AU)),
+        day(10, 7, 2013)
+    );
+    assert_eq!(
+        holiday_date("labour day 2014", &in_region(Region::GB)),
+        day(5, 5, 2014)
+    );
+    assert_eq!(
+        holiday_date("last labour day", &in_region(Region::IN)),
+        day(5, 1, 2012)
+    );
+    assert_eq!(
+        holiday_date("labour day of last year", &in_region(Region::NZ)),
+        day(10, 22, 2012)
+    );
+    assert_eq!(
+        holiday_date("thanksgiving", &in_region(Region::CA)),
+        day(10, 14, 2013)
+    );
+    assert_eq!(
+        holiday_date("thanksgiving", &in_region(Region::US)),
+        day(11, 28, 2013)
+    );
+    assert_eq!(
+        holiday_date("fathers day 1996", &in_region(Region::AU)),
+        day(9, 1, 1996)
+    );
+    assert_eq!(
+        holiday_date("admin day", &in_region(Region::ZA)),
+        day(9, 4, 2013)
+    );
+
+    let mut company = CustomHolidays::new();
+    company.add(
+        "Independence Day",
+        NaiveDate::from_ymd_opt(2013, 7, 5).unwrap(),
+    );
+    let context = Context {
+        holiday_resolver: Some(Arc::new(company)),
+        ..in_region(Region::US)
+    };
+    assert_eq!(holiday_date("independence day", &context), day(7, 5, 2013));
+    assert_eq!(holiday_date("labor day", &context), day(9, 2, 2013));
+}
+
+#[test]
+fn test_time_day_first_regions() {
+    let date = |text: &str, region: Region| {
+        let context = Context {
+            locale: Locale::new(Lang::EN, Some(region)),
+            ..make_context()
+        };
+        let entities = parse(
+            text,
+            &context.locale,
+            &[DimensionKind::Time],
+            &context,
+            &Options::default(),
+        );
+        entities.iter().find_map(|e| match &e.value {
+            DimensionValue::Time(TimeValue::Single { value, .. }) => Some(tp_value_grain(value).0),
+            _ => None,
+        })
+    };
+    let third_of_april = Some(dt(2013, 4, 3, 0, 0, 0));
+    for region in [Region::AU, Region::GB, Region::IN, Region::NZ, Region::ZA] {
+        assert_eq!(date("3/4", region), third_of_april, "{region:?}");
+        assert_eq!(date("12/25", region), Some(dt(2013, 12, 25, 0, 0, 0)));
+    }
+    for region in [Region::US, Region::CA] {
+        assert_eq!(
+            date("3/4", region),
+            Some(dt(2013, 3, 4, 0, 0, 0)),
+            "{region:?}"
+        );
+        assert_eq!(date("15/2", region), Some(dt(2013, 2, 15, 0, 0, 0)));
+    }
+    assert_eq!(date("3/4/2014", Region::AU), Some(dt(2014, 4, 3, 0, 0, 0)));
+}
+
+#[test]
+fn test_time_observed_holidays() {
+    let observed_date = |text: &str, region: Region, options: &Options| {
+        let context = Context {
+            locale: Locale::new(Lang::EN, Some(region)),
+            ..make_context()
+        };
+        let entities = parse(
+            text,
+            &context.locale,
+            &[DimensionKind::Time],
+            &context,
+            options,
+        );
+        entities
+            .iter()
+            .filter(|e| e.body == text)
+            .find_map(|e| match &e.value {
+                DimensionValue::Time(TimeValue::Single {
+                    value, observed, ..
+                }) => Some((tp_value_grain(value).0, *observed)),
+                _ => None,
+            })
+    };
+    let defaults = Options::default();
+    let on = |y: i32, m: u32, d: u32, shifted: bool| Some((dt(y, m, d, 0, 0, 0), shifted));
+
+    // Saturday July 4th 2015
+    assert_eq!(
+        observed_date("independence day 2015 (observed)", Region::US, &defaults),
+        on(2015, 7, 3, true)
+    );
+    // Sunday December 25th 2016
+    assert_eq!(
+        observed_date("observed christmas 2016", Region::US, &defaults),
+        on(2016, 12, 26, true)
+    );
+    // Saturday December 25th 2021
+    assert_eq!(
+        observed_date("christmas 2021 observed", Region::GB, &defaults),
+        on(2021, 12, 27, true)
+    );
+    assert_eq!(
+        observed_date("christmas 2021 observed", Region::ZA, &defaults),
+        on(2021, 12, 25, false)
+    );
+    // Wednesday December 25th 2013
+    assert_eq!(
+        observed_date("christmas (observed)", Region::US, &defaults),
+        on(2013, 12, 25, false)
+    );
+    let next_monday = Options::builder()
+        .observed_shift(ObservedShift::NextMonday)
+        .build();
+    assert_eq!(
+        observed_date("christmas 2021 observed", Region::US, &next_monday),
+        on(2021, 12, 27, true)
+    );
+}
+
+#[test]
+fn test_time_next_weekday_policy() {
+    let next = |text: &str, region: Option<Region>, options: &Options| {
+        let context = Context {
+            locale: Locale::new(Lang::EN, region),
+            ..make_context()
+        };
+        let entities = parse(
+            text,
+            &context.locale,
+            &[DimensionKind::Time],
+            &context,
+            options,
+        );
+        entities.iter().find_map(|e| match &e.value {
+            DimensionValue::Time(TimeValue::Single { value, .. }) => Some(tp_value_grain(value)),
+            _ => None,
+        })
+    };
+    let defaults = Options::default();
+    let day = |d: u32| Some((dt(2013, 2, d, 0, 0, 0), Grain::Day));
+
+    // Tuesday the 12th: the coming Friday in the US, next week's elsewhere
+    assert_eq!(next("next friday", Some(Region::US), &defaults), day(15));
+    assert_eq!(next("next wednesday", Some(Region::US), &defaults), day(13));
+    assert_eq!(next("next tuesday", Some(Region::US), &defaults), day(19));
+    assert_eq!(next("next friday", Some(Region::GB), &defaults), day(22));
+    assert_eq!(next("next wednesday", Some(Region::GB), &defaults), day(20));
+    assert_eq!(next("next friday", None, &defaults), day(22));
+    assert_eq!(
+        next("next friday at 3pm", Some(Region::US), &defaults),
+        Some((dt(2013, 2, 15, 15, 0, 0), Grain::Hour))
+    );
+
+    let following_week = Options::builder()
+        .next_weekday_policy(NextWeekdayPolicy::FollowingWeek)
+        .build();
+    assert_eq!(
+        next("next friday", Some(Region::US), &following_week),
+        day(22)
+    );
+    let upcoming = Options::builder()
+        .next_weekday_policy(NextWeekdayPolicy::Upcoming)
+        .build();
+    assert_eq!(next("next friday", Some(Region::GB), &upcoming), day(15));
+    assert_eq!(
+        next("friday after next", Some(Region::GB), &upcoming),
+        day(22)
+    );
+}
+
+#[test]
+fn test_time_composed_date_and_time_win_ranking() {
+    // Chat lines where a date and a clock time sit side by side: the whole
+    // date+time must come out as the only Time entity
+    for (text, body, value, grain) in [
+        (
+            "see you June 5 at 8pm!",
+            "June 5 at 8pm",
+            dt(2013, 6, 5, 20, 0, 0),
+            Grain::Hour,
+        ),
+        (
+            "drinks june 5th @ 8:30pm?",
+            "june 5th @ 8:30pm",
+            dt(2013, 6, 5, 20, 30, 0),
+            Grain::Minute,
+        ),
+        (
+            "ok so 8pm on June 5 works",
+            "8pm on June 5",
+            dt(2013, 6, 5, 20, 0, 0),
+            Grain::Hour,
+        ),
+        (
+            "can we do June 5, 8pm instead",
+            "June 5, 8pm",
+            dt(2013, 6, 5, 20, 0, 0),
+            Grain::Hour,
+        ),
+        (
+            "free June 5 after 8pm",
+            "June 5 after 8pm",
+            dt(2013, 6, 5, 20, 0, 0),
+            Grain::Hour,
+        ),
+    ] {
+        let context = make_context();
+        let entities = parse(text, &context.locale, &[], &context, &Options::default());
+        let times: Vec<_> = entities
+            .iter()
+            .filter_map(|e| match &e.value {
+                DimensionValue::Time(TimeValue::Single { value, .. }) => {
+                    Some((e.body.as_str(), Some(tp_value_grain(value))))
+                }
+                DimensionValue::Time(_) => Some((e.body.as_str(), None)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(times, [(body, Some((value, grain)))], "{text:?}");
+    }
+}
+
+#[test]
+fn test_time_business_days() {
+    check_time_naive("in 3 business days", dt(2013, 2, 15, 0, 0, 0), "day");
+    check_time_naive("in 4 working days", dt(2013, 2, 18, 0, 0, 0), "day");
+    check_time_naive(
+        "5 working days from Friday",
+        dt(2013, 2, 22, 0, 0, 0),
+        "day",
+    );
+    check_time_naive("2 business days ago", dt(2013, 2, 8, 0, 0, 0), "day");
+    check_time_naive(
+        "3 business days before march 4",
+        dt(2013, 2, 27, 0, 0, 0),
+        "day",
+    );
+}
+
+#[test]
+fn test_time_business_days_skip_holiday_calendar() {
+    let context = Context {
+        holiday_calendar: [NaiveDate::from_ymd_opt(2013, 2, 14).unwrap()]
+            .into_iter()
+            .collect(),
+        ..make_context()
+    };
+    let entities = parse(
+        "in 3 business days",
+        &context.locale,
+        &[DimensionKind::Time],
+        &context,
+        &Options::default(),
+    );
+    let found = entities.iter().any(|e| {
+        matches!(&e.value, DimensionValue::Time(TimeValue::Single { value: TimePoint::Naive { value, .. }, .. }) if *value == dt(2013, 2, 18, 0, 0, 0))
+    });
+    assert!(found, "Expected Monday 2013-02-18, got: {:?}", entities);
+}
+
 #[test]
 fn test_time_eom() {
     check_time_interval(
@@ -3174,13 +3875,14 @@ fn test_iso8601_t_separator_z_parses_as_single_datetime() {
         reference_time: Utc.with_ymd_and_hms(2013, 2, 12, 4, 30, 0).unwrap(),
         locale,
         timezone_offset_minutes: 0,
+        ..Context::default()
     };
     let options = Options::default();
     let entities = parse(text, &locale, &[DimensionKind::Time], &context, &options);
     let found = entities.iter().any(|e| {
         matches!(
             &e.value,
-            DimensionValue::Time(TimeValue::Single { value: TimePoint::Instant { value, grain: Grain::Minute }, .. })
+            DimensionValue::Time(TimeValue::Single { value: TimePoint::Instant { value, grain: Grain::Second }, .. })
                 if value.naive_utc() == dt(2018, 4, 1, 18, 3, 40)
         )
     });
@@ -3191,6 +3893,34 @@ fn test_iso8601_t_separator_z_parses_as_single_datetime() {
     );
 }
 
+#[test]
+fn test_time_iso8601_and_rfc3339_literals() {
+    // An offset names an absolute instant, whatever the context's UTC-2
+    check_time_instant("2024-05-03T14:30:00Z", dt(2024, 5, 3, 14, 30, 0), "second");
+    check_time_instant("2024-05-03T14:30Z", dt(2024, 5, 3, 14, 30, 0), "minute");
+    check_time_instant(
+        "2024-05-03T14:30:00.250+02:00",
+        dt(2024, 5, 3, 12, 30, 0),
+        "second",
+    );
+    check_time_instant(
+        "2024-05-03 14:30-05:00",
+        dt(2024, 5, 3, 19, 30, 0),
+        "minute",
+    );
+    check_time_instant("20240503T143000Z", dt(2024, 5, 3, 14, 30, 0), "second");
+    check_time_instant("20240503T1430+0530", dt(2024, 5, 3, 9, 0, 0), "minute");
+    assert_eq!(
+        parse_time("2024-05-03T14:30:00+02:00")[0].as_datetime(-120),
+        Some(dt_utc(2024, 5, 3, 12, 30, 0))
+    );
+    // Without an offset the value stays wall-clock
+    check_time_naive("20240503T1430", dt(2024, 5, 3, 14, 30, 0), "minute");
+    check_time_naive("2024-05-03T14:30:15", dt(2024, 5, 3, 14, 30, 15), "second");
+    check_time_naive("2024-05-03", dt(2024, 5, 3, 0, 0, 0), "day");
+    check_no_time("20241303T1430");
+}
+
 #[test]
 fn test_time_additional_regression_inputs() {
     let should_parse = [
@@ -3243,6 +3973,7 @@ fn test_last_april_first_march_2018_ref() {
         reference_time: Utc.with_ymd_and_hms(2018, 3, 15, 12, 0, 0).unwrap(),
         locale,
         timezone_offset_minutes: 0,
+        ..Context::default()
     };
     let options = Options::default();
     let entities = parse(
@@ -3392,6 +4123,70 @@ fn test_time_late_march() {
     );
 }
 
+#[test]
+fn test_time_parts_of_relative_periods() {
+    for (text, from, to, grain) in [
+        (
+            "beginning of next week",
+            dt(2013, 2, 18, 0, 0, 0),
+            dt(2013, 2, 21, 0, 0, 0),
+            "day",
+        ),
+        (
+            "the middle of next week",
+            dt(2013, 2, 20, 0, 0, 0),
+            dt(2013, 2, 22, 0, 0, 0),
+            "day",
+        ),
+        (
+            "middle of the month",
+            dt(2013, 2, 11, 0, 0, 0),
+            dt(2013, 2, 21, 0, 0, 0),
+            "day",
+        ),
+        (
+            "early next month",
+            dt(2013, 3, 1, 0, 0, 0),
+            dt(2013, 3, 11, 0, 0, 0),
+            "day",
+        ),
+        (
+            "end of Q3",
+            dt(2013, 9, 1, 0, 0, 0),
+            dt(2013, 10, 1, 0, 0, 0),
+            "month",
+        ),
+        (
+            "beginning of next quarter",
+            dt(2013, 4, 1, 0, 0, 0),
+            dt(2013, 5, 1, 0, 0, 0),
+            "month",
+        ),
+        (
+            "mid q3 2014",
+            dt(2014, 8, 1, 0, 0, 0),
+            dt(2014, 9, 1, 0, 0, 0),
+            "month",
+        ),
+        (
+            "late next year",
+            dt(2014, 9, 1, 0, 0, 0),
+            dt(2015, 1, 1, 0, 0, 0),
+            "month",
+        ),
+        (
+            "middle of the year",
+            dt(2013, 4, 1, 0, 0, 0),
+            dt(2013, 9, 1, 0, 0, 0),
+            "month",
+        ),
+    ] {
+        check_time_interval(text, from, to, grain);
+    }
+    // A bare "Q3" is latent
+    assert!(parse_time("Q3").is_empty());
+}
+
 #[test]
 fn test_time_last_weekend_of_october() {
     check_time_interval(
@@ -5078,6 +5873,7 @@ fn make_saturday_context() -> Context {
         reference_time: Utc.with_ymd_and_hms(2013, 2, 9, 10, 0, 0).unwrap(),
         locale: Locale::new(Lang::EN, None),
         timezone_offset_minutes: -120,
+        ..Context::default()
     }
 }
 
@@ -5087,6 +5883,7 @@ fn make_friday_evening_context() -> Context {
         reference_time: Utc.with_ymd_and_hms(2013, 2, 8, 20, 0, 0).unwrap(),
         locale: Locale::new(Lang::EN, None),
         timezone_offset_minutes: -120,
+        ..Context::default()
     }
 }
 
@@ -5190,3 +5987,647 @@ fn test_diff_corpus_examples() {
     // check_time_naive("3 fridays from now", dt(2013, 3, 8, 0, 0, 0), "day");
     // check_time_naive("three fridays from now", dt(2013, 3, 8, 0, 0, 0), "day");
 }
+
+fn first_time_value(text: &str) -> TimeValue {
+    match parse_time(text).into_iter().next().map(|e| e.value) {
+        Some(DimensionValue::Time(value)) => value,
+        other => panic!("Expected a time for '{}', got: {:?}", text, other),
+    }
+}
+
+#[test]
+fn test_time_point_to_rfc3339() {
+    let rfc = |text: &str| match first_time_value(text) {
+        TimeValue::Single { value, .. } => value.to_rfc3339(-120),
+        other => panic!("Expected a single time for '{}', got: {:?}", text, other),
+    };
+    assert_eq!(rfc("tomorrow at 3pm"), "2013-02-13T15:00:00-02:00");
+    assert_eq!(rfc("tomorrow"), "2013-02-13");
+    assert_eq!(rfc("march 2015"), "2015-03-01");
+    assert_eq!(rfc("in one hour"), "2013-02-12T03:30:00-02:00");
+}
+
+#[test]
+fn test_time_value_contains() {
+    let tomorrow = first_time_value("tomorrow");
+    // Naive values are read at UTC-2, so tomorrow starts at 02:00 UTC.
+    assert!(tomorrow.contains(dt_utc(2013, 2, 13, 2, 0, 0), -120));
+    assert!(tomorrow.contains(dt_utc(2013, 2, 14, 1, 59, 59), -120));
+    assert!(!tomorrow.contains(dt_utc(2013, 2, 13, 1, 0, 0), -120));
+    assert!(!tomorrow.contains(dt_utc(2013, 2, 14, 2, 0, 0), -120));
+
+    let afternoon = first_time_value("from 3pm to 5pm");
+    assert!(afternoon.contains(dt_utc(2013, 2, 12, 15, 0, 0), 0));
+    assert!(!afternoon.contains(dt_utc(2013, 2, 12, 18, 0, 0), 0));
+
+    let after = first_time_value("after 5pm");
+    assert!(after.contains(dt_utc(2020, 1, 1, 0, 0, 0), 0));
+}
+
+#[test]
+fn test_time_value_intersect() {
+    let tomorrow = first_time_value("tomorrow");
+    let afternoon = first_time_value("from 3pm tomorrow to 8am the day after tomorrow");
+    match tomorrow.intersect(&afternoon, -120) {
+        Some(TimeValue::Interval {
+            from: Some(from),
+            to: Some(to),
+            ..
+        }) => {
+            assert_eq!(
+                tp_value_grain(&from),
+                (dt(2013, 2, 13, 15, 0, 0), Grain::Hour)
+            );
+            assert_eq!(tp_value_grain(&to), (dt(2013, 2, 14, 0, 0, 0), Grain::Day));
+        }
+        other => panic!("Expected a bounded interval, got: {:?}", other),
+    }
+
+    let yesterday = first_time_value("yesterday");
+    assert_eq!(tomorrow.intersect(&yesterday, -120), None);
+}
+
+#[test]
+fn test_time_value_accessors() {
+    let tomorrow = first_time_value("tomorrow at 3pm");
+    assert!(!tomorrow.is_interval());
+    assert_eq!(tomorrow.grain(), Some(Grain::Hour));
+    assert_eq!(tomorrow.end(), None);
+    assert_eq!(
+        tomorrow.to_utc_bounds(-120),
+        Some((dt_utc(2013, 2, 13, 17, 0, 0), None))
+    );
+
+    let afternoon = first_time_value("from 3pm to 5pm");
+    assert!(afternoon.is_interval());
+    assert_eq!(
+        afternoon.start().map(tp_value_grain),
+        Some((dt(2013, 2, 12, 15, 0, 0), Grain::Hour))
+    );
+    assert_eq!(
+        afternoon.end().map(tp_value_grain),
+        Some((dt(2013, 2, 12, 18, 0, 0), Grain::Hour))
+    );
+
+    let before = first_time_value("before 5pm");
+    assert_eq!(before.start(), None);
+    assert_eq!(before.grain(), Some(Grain::Hour));
+    assert_eq!(before.to_utc_bounds(0), None);
+}
+
+#[test]
+fn test_time_meridiem_candidates_option() {
+    let options = Options::builder().with_meridiem_candidates(true).build();
+    let candidates = |text: &str| {
+        let entities = parse(
+            text,
+            &Locale::new(Lang::EN, None),
+            &[DimensionKind::Time],
+            &make_context(),
+            &options,
+        );
+        match entities.into_iter().next().map(|e| e.value) {
+            Some(DimensionValue::Time(TimeValue::Single {
+                meridiem_candidates,
+                ..
+            })) => meridiem_candidates
+                .iter()
+                .map(tp_value_grain)
+                .collect::<Vec<_>>(),
+            other => panic!("Expected a single time for '{}', got: {:?}", text, other),
+        }
+    };
+    assert_eq!(
+        candidates("at 7"),
+        [
+            (dt(2013, 2, 12, 7, 0, 0), Grain::Hour),
+            (dt(2013, 2, 12, 19, 0, 0), Grain::Hour)
+        ]
+    );
+    assert_eq!(
+        candidates("tomorrow at 7:30"),
+        [
+            (dt(2013, 2, 13, 7, 30, 0), Grain::Minute),
+            (dt(2013, 2, 13, 19, 30, 0), Grain::Minute)
+        ]
+    );
+    assert_eq!(
+        candidates("at 12"),
+        [
+            (dt(2013, 2, 12, 0, 0, 0), Grain::Hour),
+            (dt(2013, 2, 12, 12, 0, 0), Grain::Hour)
+        ]
+    );
+    assert!(candidates("at 7pm").is_empty());
+    assert!(candidates("at 19:00").is_empty());
+    assert!(candidates("at 7 in the evening").is_empty());
+
+    // Off by default.
+    match first_time_value("at 7") {
+        TimeValue::Single {
+            meridiem_candidates,
+            ..
+        } => assert!(meridiem_candidates.is_empty()),
+        other => panic!("Expected a single time, got: {:?}", other),
+    }
+}
+
+#[test]
+fn test_time_preference_option() {
+    let first = |text: &str, prefer: TimePreference| {
+        let options = Options::builder().prefer(prefer).build();
+        let entities = parse(
+            text,
+            &Locale::new(Lang::EN, None),
+            &[DimensionKind::Time],
+            &make_context(),
+            &options,
+        );
+        match entities.into_iter().next().map(|e| e.value) {
+            Some(DimensionValue::Time(value)) => tp_value_grain(value.start().unwrap()),
+            other => panic!("Expected a time for '{}', got: {:?}", text, other),
+        }
+    };
+    let day = |m, d| (dt(2013, m, d, 0, 0, 0), Grain::Day);
+
+    assert_eq!(first("friday", TimePreference::Future), day(2, 15));
+    assert_eq!(first("friday", TimePreference::Past), day(2, 8));
+    assert_eq!(first("friday", TimePreference::Nearest), day(2, 15));
+    assert_eq!(first("monday", TimePreference::Nearest), day(2, 11));
+    assert_eq!(first("the 5th", TimePreference::Nearest), day(2, 5));
+    assert_eq!(
+        first("march 5", TimePreference::Past),
+        (dt(2012, 3, 5, 0, 0, 0), Grain::Day)
+    );
+    assert_eq!(
+        first("friday at 3pm", TimePreference::Past),
+        (dt(2013, 2, 8, 15, 0, 0), Grain::Hour)
+    );
+    assert_eq!(
+        first("at 7", TimePreference::Past),
+        (dt(2013, 2, 11, 19, 0, 0), Grain::Hour)
+    );
+
+    // Explicit directions and deictic times are unaffected.
+    assert_eq!(first("next friday", TimePreference::Past), day(2, 22));
+    assert_eq!(first("this friday", TimePreference::Past), day(2, 15));
+    assert_eq!(first("tomorrow", TimePreference::Past), day(2, 13));
+}
+
+#[test]
+fn test_time_preference_values_follow_direction() {
+    let values = |text: &str, prefer: TimePreference| {
+        let options = Options::builder().prefer(prefer).build();
+        let entities = parse(
+            text,
+            &Locale::new(Lang::EN, None),
+            &[DimensionKind::Time],
+            &make_context(),
+            &options,
+        );
+        match entities.into_iter().next().map(|e| e.value) {
+            Some(DimensionValue::Time(TimeValue::Single { value, values, .. })) => (
+                tp_value_grain(&value).0,
+                values
+                    .iter()
+                    .map(|v| tp_value_grain(v).0)
+                    .collect::<Vec<_>>(),
+            ),
+            other => panic!("Expected a single time for '{}', got: {:?}", text, other),
+        }
+    };
+    let day = |y, m, d| dt(y, m, d, 0, 0, 0);
+
+    assert_eq!(
+        values("friday", TimePreference::Past),
+        (
+            day(2013, 2, 8),
+            vec![day(2013, 2, 8), day(2013, 2, 1), day(2013, 1, 25)]
+        )
+    );
+    assert_eq!(
+        values("the 5th", TimePreference::Past),
+        (
+            day(2013, 2, 5),
+            vec![day(2013, 2, 5), day(2013, 1, 5), day(2012, 12, 5)]
+        )
+    );
+    assert_eq!(
+        values("march 3", TimePreference::Past),
+        (day(2012, 3, 3), vec![day(2012, 3, 3)])
+    );
+    // Nearest lists the series in the direction of the occurrence it picked.
+    assert_eq!(
+        values("monday", TimePreference::Nearest),
+        (
+            day(2013, 2, 11),
+            vec![day(2013, 2, 11), day(2013, 2, 4), day(2013, 1, 28)]
+        )
+    );
+    assert_eq!(
+        values("friday", TimePreference::Nearest),
+        (
+            day(2013, 2, 15),
+            vec![day(2013, 2, 15), day(2013, 2, 22), day(2013, 3, 1)]
+        )
+    );
+}
+
+/// The single entity covering all of `text`, as (value, grain).
+fn whole_day(text: &str, context: &Context) -> Option<(NaiveDateTime, Grain)> {
+    let entities = parse_time_with_context(text, context);
+    match entities.as_slice() {
+        [Entity {
+            start: 0,
+            end,
+            value: DimensionValue::Time(TimeValue::Single { value, .. }),
+            ..
+        }] if *end == text.len() => Some(tp_value_grain(value)),
+        _ => None,
+    }
+}
+
+#[test]
+fn test_ordinal_day_of_month_like_base() {
+    let context = make_context();
+    for (text, expected) in [
+        ("the 3rd of next month", dt(2013, 3, 3, 0, 0, 0)),
+        ("the 15th of last month", dt(2013, 1, 15, 0, 0, 0)),
+        ("the 3rd of the following month", dt(2013, 3, 3, 0, 0, 0)),
+        ("the 15th of the month after next", dt(2013, 4, 15, 0, 0, 0)),
+        ("the 3rd day of next month", dt(2013, 3, 3, 0, 0, 0)),
+        ("the last day of next month", dt(2013, 3, 31, 0, 0, 0)),
+        (
+            "the last day of the month before last",
+            dt(2012, 12, 31, 0, 0, 0),
+        ),
+        ("the first friday of next month", dt(2013, 3, 1, 0, 0, 0)),
+        ("the last friday of next month", dt(2013, 3, 29, 0, 0, 0)),
+        (
+            "the second tuesday of the month after next",
+            dt(2013, 4, 9, 0, 0, 0),
+        ),
+    ] {
+        assert_eq!(
+            whole_day(text, &context),
+            Some((expected, Grain::Day)),
+            "{text}"
+        );
+    }
+}
+
+#[test]
+fn test_ordinal_day_missing_from_month() {
+    // Tuesday Mar 12, 2013: April has no 31st
+    let context = Context {
+        reference_time: Utc.with_ymd_and_hms(2013, 3, 12, 4, 30, 0).unwrap(),
+        ..make_context()
+    };
+    assert_eq!(whole_day("the 31st of next month", &context), None);
+    assert_eq!(
+        whole_day("the 30th of next month", &context),
+        Some((dt(2013, 4, 30, 0, 0, 0), Grain::Day))
+    );
+    assert_eq!(
+        whole_day("the 31st of last month", &make_context()),
+        Some((dt(2013, 1, 31, 0, 0, 0), Grain::Day))
+    );
+}
+
+#[test]
+fn test_time_decades() {
+    check_time_interval(
+        "the 90s",
+        dt(1990, 1, 1, 0, 0, 0),
+        dt(2000, 1, 1, 0, 0, 0),
+        "year",
+    );
+    check_time_interval(
+        "the '80s",
+        dt(1980, 1, 1, 0, 0, 0),
+        dt(1990, 1, 1, 0, 0, 0),
+        "year",
+    );
+    check_time_interval(
+        "the 1980s",
+        dt(1980, 1, 1, 0, 0, 0),
+        dt(1990, 1, 1, 0, 0, 0),
+        "year",
+    );
+    check_time_interval(
+        "the nineties",
+        dt(1990, 1, 1, 0, 0, 0),
+        dt(2000, 1, 1, 0, 0, 0),
+        "year",
+    );
+    check_time_interval(
+        "the 2010s",
+        dt(2010, 1, 1, 0, 0, 0),
+        dt(2020, 1, 1, 0, 0, 0),
+        "year",
+    );
+    // The reference year is 2013, so "the 10s" have begun and "the 20s" have not
+    check_time_interval(
+        "the 10s",
+        dt(2010, 1, 1, 0, 0, 0),
+        dt(2020, 1, 1, 0, 0, 0),
+        "year",
+    );
+    check_time_interval(
+        "the 20s",
+        dt(1920, 1, 1, 0, 0, 0),
+        dt(1930, 1, 1, 0, 0, 0),
+        "year",
+    );
+    check_time_interval(
+        "early 2000s",
+        dt(2000, 1, 1, 0, 0, 0),
+        dt(2004, 1, 1, 0, 0, 0),
+        "year",
+    );
+    check_time_interval(
+        "mid-90s",
+        dt(1994, 1, 1, 0, 0, 0),
+        dt(1996, 1, 1, 0, 0, 0),
+        "year",
+    );
+    check_time_interval(
+        "late 1970s",
+        dt(1976, 1, 1, 0, 0, 0),
+        dt(1980, 1, 1, 0, 0, 0),
+        "year",
+    );
+    check_no_time("90s");
+}
+
+#[test]
+fn test_time_centuries() {
+    check_time_interval(
+        "the 21st century",
+        dt(2000, 1, 1, 0, 0, 0),
+        dt(2100, 1, 1, 0, 0, 0),
+        "year",
+    );
+    check_time_interval(
+        "19th-century",
+        dt(1800, 1, 1, 0, 0, 0),
+        dt(1900, 1, 1, 0, 0, 0),
+        "year",
+    );
+    check_time_interval(
+        "early 20th century",
+        dt(1900, 1, 1, 0, 0, 0),
+        dt(1934, 1, 1, 0, 0, 0),
+        "year",
+    );
+    check_time_interval(
+        "the late eighteenth century",
+        dt(1766, 1, 1, 0, 0, 0),
+        dt(1800, 1, 1, 0, 0, 0),
+        "year",
+    );
+}
+
+#[test]
+fn test_time_half_hour_by_region() {
+    let half_three = |region: Option<Region>| {
+        let locale = Locale::new(Lang::EN, region);
+        let context = Context {
+            locale,
+            ..make_context()
+        };
+        parse(
+            "half three",
+            &locale,
+            &[DimensionKind::Time],
+            &context,
+            &Options::default(),
+        )
+        .iter()
+        .find_map(|e| match &e.value {
+            DimensionValue::Time(TimeValue::Single { value, .. }) if e.body == "half three" => {
+                Some(tp_value_grain(value))
+            }
+            _ => None,
+        })
+    };
+    let three_thirty = Some((dt(2013, 2, 12, 15, 30, 0), Grain::Minute));
+    assert_eq!(half_three(None), three_thirty);
+    assert_eq!(half_three(Some(Region::GB)), three_thirty);
+    assert_eq!(half_three(Some(Region::AU)), three_thirty);
+    assert_eq!(half_three(Some(Region::US)), None);
+    assert_eq!(half_three(Some(Region::CA)), None);
+
+    check_time_naive("half past three", dt(2013, 2, 12, 15, 30, 0), "minute");
+    check_time_naive("quarter to five", dt(2013, 2, 12, 4, 45, 0), "minute");
+    check_time_naive("a quarter past 3", dt(2013, 2, 12, 15, 15, 0), "minute");
+}
+
+#[test]
+fn test_time_minutes_around_the_hour() {
+    check_time_naive("quarter til 3", dt(2013, 2, 12, 14, 45, 0), "minute");
+    check_time_naive("ten of five", dt(2013, 2, 12, 4, 50, 0), "minute");
+    check_time_naive("20 minutes to noon", dt(2013, 2, 12, 11, 40, 0), "minute");
+    check_time_naive(
+        "five and twenty past four",
+        dt(2013, 2, 12, 4, 25, 0),
+        "minute",
+    );
+    check_time_naive(
+        "a quarter to midnight",
+        dt(2013, 2, 12, 23, 45, 0),
+        "minute",
+    );
+}
+
+#[test]
+fn test_time_ordinal_dates() {
+    check_time_naive("March the 3rd", dt(2013, 3, 3, 0, 0, 0), "day");
+    check_time_naive("March the third", dt(2013, 3, 3, 0, 0, 0), "day");
+    check_time_naive("March the 3rd, 2025", dt(2025, 3, 3, 0, 0, 0), "day");
+    check_time_naive("the third of March, 2025", dt(2025, 3, 3, 0, 0, 0), "day");
+    check_time_naive("the 3rd", dt(2013, 3, 3, 0, 0, 0), "day");
+    check_time_naive("the 3rd inst.", dt(2013, 2, 3, 0, 0, 0), "day");
+    check_time_naive("15th inst", dt(2013, 2, 15, 0, 0, 0), "day");
+    check_time_naive("the 22nd of next month", dt(2013, 3, 22, 0, 0, 0), "day");
+    check_time_naive("the 22nd of last month", dt(2013, 1, 22, 0, 0, 0), "day");
+    check_time_naive(
+        "Monday the 3rd of March 2025",
+        dt(2025, 3, 3, 0, 0, 0),
+        "day",
+    );
+    check_time_naive(
+        "Monday, March the 3rd, 2025",
+        dt(2025, 3, 3, 0, 0, 0),
+        "day",
+    );
+    check_time_naive("Monday the 3rd of March", dt(2014, 3, 3, 0, 0, 0), "day");
+}
+
+#[test]
+fn test_time_epoch_timestamps_option() {
+    let context = Context {
+        reference_time: Utc.with_ymd_and_hms(2024, 5, 1, 0, 0, 0).unwrap(),
+        ..make_context()
+    };
+    let epoch = |text: &str, options: &Options| {
+        parse(
+            text,
+            &context.locale,
+            &[DimensionKind::Time],
+            &context,
+            options,
+        )
+        .iter()
+        .find_map(|e| match &e.value {
+            DimensionValue::Time(TimeValue::Single {
+                value: TimePoint::Instant { value, grain },
+                ..
+            }) if e.body == text => Some((value.naive_utc(), *grain)),
+            _ => None,
+        })
+    };
+    let opt_in = Options::builder().parse_epoch_timestamps(true).build();
+    // 2024-05-03 14:30:00 UTC, shown in the context's UTC-2
+    let expected = Some((dt(2024, 5, 3, 12, 30, 0), Grain::Second));
+    assert_eq!(epoch("1714746600", &opt_in), expected);
+    assert_eq!(epoch("1714746600000", &opt_in), expected);
+    assert_eq!(
+        epoch("1714746600123", &opt_in),
+        Some((
+            dt(2024, 5, 3, 12, 30, 0) + chrono::Duration::milliseconds(123),
+            Grain::Second
+        ))
+    );
+    assert_eq!(epoch("1714746600", &Options::default()), None);
+    assert_eq!(epoch("17147466001", &opt_in), None);
+    // Outside 2000 to five years past the reference time: a phone number
+    // (2145), the last millisecond of 1999, and 2033
+    assert_eq!(epoch("5551234567", &opt_in), None);
+    assert_eq!(epoch("0946684799999", &opt_in), None);
+    assert_eq!(epoch("1999999999", &opt_in), None);
+}
+
+#[test]
+fn test_time_duration_prefix_qualifier() {
+    let qualifier = |text: &str| {
+        parse_time(text).into_iter().find_map(|e| match e.value {
+            DimensionValue::Time(TimeValue::Single { qualifier, .. })
+            | DimensionValue::Time(TimeValue::Interval { qualifier, .. })
+                if e.body == text =>
+            {
+                Some(qualifier)
+            }
+            _ => None,
+        })
+    };
+    assert_eq!(
+        qualifier("in 2 hours"),
+        Some(Some(DurationQualifier::Delayed))
+    );
+    assert_eq!(qualifier("in 15"), Some(Some(DurationQualifier::Delayed)));
+    assert_eq!(
+        qualifier("within 2 hours"),
+        Some(Some(DurationQualifier::Bounded))
+    );
+    assert_eq!(qualifier("tomorrow"), Some(None));
+}
+
+#[test]
+fn test_time_anchor_with_spanning_duration() {
+    check_time_interval(
+        "from 3pm for two hours",
+        dt(2013, 2, 12, 15, 0, 0),
+        dt(2013, 2, 12, 18, 0, 0),
+        "hour",
+    );
+    check_time_interval(
+        "for 2 hours starting at 3pm",
+        dt(2013, 2, 12, 15, 0, 0),
+        dt(2013, 2, 12, 18, 0, 0),
+        "hour",
+    );
+    check_time_interval(
+        "starting Tuesday for 3 days",
+        dt(2013, 2, 19, 0, 0, 0),
+        dt(2013, 2, 23, 0, 0, 0),
+        "day",
+    );
+    check_time_interval(
+        "tomorrow for 2 days",
+        dt(2013, 2, 13, 0, 0, 0),
+        dt(2013, 2, 16, 0, 0, 0),
+        "day",
+    );
+}
+
+#[test]
+fn test_fuzzy_matching_option() {
+    let fuzzy = Options::builder().fuzzy_matching(true).build();
+    let parse_fuzzy = |text: &str| {
+        parse(
+            text,
+            &Locale::new(Lang::EN, None),
+            &[],
+            &make_context(),
+            &fuzzy,
+        )
+    };
+
+    let entities = parse_fuzzy("tomorow at 3pm");
+    assert_eq!(entities.len(), 1, "{entities:?}");
+    assert_eq!(entities[0].body, "tomorow at 3pm");
+    match &entities[0].value {
+        DimensionValue::Time(value) => assert_eq!(
+            tp_value_grain(value.start().unwrap()),
+            (dt(2013, 2, 13, 15, 0, 0), Grain::Hour)
+        ),
+        other => panic!("Expected a time, got: {:?}", other),
+    }
+    let money = parse_fuzzy("pay 20 dollers by febuary 3rd");
+    let bodies: Vec<&str> = money.iter().map(|e| e.body.as_str()).collect();
+    assert_eq!(bodies, ["20 dollers", "by febuary 3rd"]);
+    assert!(parse_time("febuary 3rd").is_empty());
+
+    // No precision regression: the negative corpus still yields no time,
+    // nor do real words one letter off a keyword.
+    let negatives = [
+        "laughing out loud",
+        "1 adult",
+        "we are separated",
+        "25",
+        "this is the one",
+        "this one",
+        "this past one",
+        "at single",
+        "at a couple of",
+        "at pairs",
+        "at a few",
+        "at dozens",
+        "single o'clock",
+        "dozens o'clock",
+        "Rat 6",
+        "rat 6",
+        "3 30",
+        "three twenty",
+        "at 650.650.6500",
+        "at 650-650-6500",
+        "two sixty a m",
+        "Pay ABC 2000",
+        "4a",
+        "4a.",
+        "A4 A5",
+        "palm",
+        "Martin Luther King' day",
+        "two three",
+        "a match near the marsh",
+        "a sundae for the toady",
+    ];
+    for text in negatives {
+        let found: Vec<Entity> = parse_fuzzy(text)
+            .into_iter()
+            .filter(|e| matches!(e.value, DimensionValue::Time(_)))
+            .collect();
+        assert!(found.is_empty(), "{text:?}: {found:?}");
+    }
+}
@@ -0,0 +1,260 @@
+// Property tests for time resolution: random reference times between 1900
+// and 2100, every weekday, instants around DST changes and a spread of
+// timezone offsets. Debug builds resolve without catch_unwind, so a panic
+// anywhere in resolution fails the property instead of dropping the entity.
+// As in Duckling's corpus, the reference time's date and clock are those of
+// the context's timezone.
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Utc, Weekday};
+use duckling::{
+    parse, Context, DimensionKind, DimensionValue, Entity, Grain, Lang, Locale, Options, TimePoint,
+    TimeValue,
+};
+use proptest::prelude::*;
+
+/// Phrases with the grain of the value they resolve to, and whether that
+/// value is an instant (pinned to UTC) rather than a wall-clock time.
+const PHRASES: &[(&str, Grain, bool)] = &[
+    ("now", Grain::Second, true),
+    ("today", Grain::Day, false),
+    ("tomorrow", Grain::Day, false),
+    ("yesterday", Grain::Day, false),
+    ("monday", Grain::Day, false),
+    ("next friday", Grain::Day, false),
+    ("last sunday", Grain::Day, false),
+    ("next week", Grain::Week, false),
+    ("next month", Grain::Month, false),
+    ("next year", Grain::Year, false),
+    ("this quarter", Grain::Quarter, false),
+    ("in 2 hours", Grain::Minute, true),
+    ("in 15 minutes", Grain::Second, true),
+    ("3 days ago", Grain::Hour, true),
+    ("at 3pm", Grain::Hour, false),
+    ("tomorrow at 7:30", Grain::Minute, false),
+    ("christmas", Grain::Day, false),
+    ("the day after easter", Grain::Day, false),
+    ("last day of the month", Grain::Day, false),
+    ("week 23", Grain::Week, false),
+    ("march 15", Grain::Day, false),
+    ("the 3rd of next month", Grain::Day, false),
+    ("the first monday of march", Grain::Day, false),
+    ("the 31st", Grain::Day, false),
+    ("february 29th", Grain::Day, false),
+    ("the last friday of the month", Grain::Day, false),
+    ("in 3 business days", Grain::Day, false),
+];
+
+/// Phrases that resolve to intervals, with whether their bounds are instants.
+const INTERVALS: &[(&str, bool)] = &[
+    ("from 3pm to 5pm", false),
+    ("this weekend", false),
+    ("tonight", false),
+    ("this morning", false),
+    ("after 5pm", false),
+    ("before noon", false),
+    ("in the next 2 weeks", true),
+    ("within the last 30 days", true),
+    ("from monday to friday", false),
+    ("last 3 days", false),
+];
+
+const WEEKDAYS: &[(&str, Weekday)] = &[
+    ("monday", Weekday::Mon),
+    ("tuesday", Weekday::Tue),
+    ("wednesday", Weekday::Wed),
+    ("thursday", Weekday::Thu),
+    ("friday", Weekday::Fri),
+    ("saturday", Weekday::Sat),
+    ("sunday", Weekday::Sun),
+];
+
+fn utc(date: NaiveDate, hour: u32, minute: u32) -> DateTime<Utc> {
+    date.and_hms_opt(hour, minute, 0).unwrap().and_utc()
+}
+
+/// Any second from 1900 through 2100.
+fn any_instant() -> impl Strategy<Value = DateTime<Utc>> {
+    let start = Utc
+        .with_ymd_and_hms(1900, 1, 1, 0, 0, 0)
+        .unwrap()
+        .timestamp();
+    let end = Utc
+        .with_ymd_and_hms(2100, 12, 31, 23, 59, 59)
+        .unwrap()
+        .timestamp();
+    (start..=end).prop_map(|secs| DateTime::from_timestamp(secs, 0).unwrap())
+}
+
+/// A few seconds either side of a daylight saving change: the US changes
+/// (second Sunday of March, first Sunday of November, 2am local) and the EU
+/// ones (last Sunday of March and October, 1am UTC).
+fn dst_instant() -> impl Strategy<Value = DateTime<Utc>> {
+    (1970..=2100i32, 0..4usize, -90..=90i64).prop_map(|(year, change, secs)| {
+        let at = match change {
+            0 => utc(
+                NaiveDate::from_weekday_of_month_opt(year, 3, Weekday::Sun, 2).unwrap(),
+                7,
+                0,
+            ),
+            1 => utc(
+                NaiveDate::from_weekday_of_month_opt(year, 11, Weekday::Sun, 1).unwrap(),
+                6,
+                0,
+            ),
+            2 => utc(last_sunday(year, 3), 1, 0),
+            _ => utc(last_sunday(year, 10), 1, 0),
+        };
+        at + Duration::seconds(secs)
+    })
+}
+
+fn last_sunday(year: i32, month: u32) -> NaiveDate {
+    let last = NaiveDate::from_ymd_opt(year, month + 1, 1)
+        .unwrap()
+        .pred_opt()
+        .unwrap();
+    last - Duration::days(i64::from(last.weekday().num_days_from_sunday()))
+}
+
+fn reference_time() -> impl Strategy<Value = DateTime<Utc>> {
+    prop_oneof![3 => any_instant(), 1 => dst_instant()]
+}
+
+/// Offsets from UTC-12:00 to UTC+14:00, in quarter hours.
+fn offset_minutes() -> impl Strategy<Value = i32> {
+    (-48..=56i32).prop_map(|quarters| quarters * 15)
+}
+
+fn parse_at(text: &str, reference_time: DateTime<Utc>, offset: i32) -> Vec<Entity> {
+    let context = Context {
+        reference_time,
+        locale: Locale::new(Lang::EN, None),
+        timezone_offset_minutes: offset,
+        ..Context::default()
+    };
+    parse(
+        text,
+        &context.locale,
+        &[DimensionKind::Time],
+        &context,
+        &Options::default(),
+    )
+}
+
+/// The time value of the entity spanning all of `text`.
+fn whole_time(entities: &[Entity], text: &str) -> Option<TimeValue> {
+    entities
+        .iter()
+        .find(|e| e.start == 0 && e.end == text.len())
+        .and_then(|e| match &e.value {
+            DimensionValue::Time(value) => Some(value.clone()),
+            _ => None,
+        })
+}
+
+fn is_instant(point: &TimePoint) -> bool {
+    matches!(point, TimePoint::Instant { .. })
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(128))]
+
+    #[test]
+    fn single_times_keep_their_grain_and_kind(
+        reference in reference_time(),
+        offset in offset_minutes(),
+        (text, grain, instant) in proptest::sample::select(PHRASES),
+    ) {
+        let entities = parse_at(text, reference, offset);
+        let value = whole_time(&entities, text);
+        let Some(TimeValue::Single { value, values, .. }) = value else {
+            return Err(TestCaseError::fail(format!("{text:?} at {reference}: {entities:?}")));
+        };
+        prop_assert_eq!(value.grain(), grain, "{:?} at {}", text, reference);
+        prop_assert_eq!(is_instant(&value), instant, "{:?} at {}", text, reference);
+        prop_assert!(!values.is_empty() && values.len() <= 3);
+        for later in &values {
+            prop_assert_eq!(is_instant(later), instant);
+            prop_assert_eq!(later.grain(), grain);
+        }
+    }
+
+    #[test]
+    fn intervals_are_ordered(
+        reference in reference_time(),
+        offset in offset_minutes(),
+        (text, instant) in proptest::sample::select(INTERVALS),
+    ) {
+        let entities = parse_at(text, reference, offset);
+        let value = whole_time(&entities, text);
+        let Some(TimeValue::Interval { from, to, values, .. }) = value else {
+            return Err(TestCaseError::fail(format!("{text:?} at {reference}: {entities:?}")));
+        };
+        prop_assert!(from.is_some() || to.is_some());
+        for point in from.iter().chain(to.iter()) {
+            prop_assert_eq!(is_instant(point), instant, "{:?} at {}", text, reference);
+        }
+        if let (Some(from), Some(to)) = (&from, &to) {
+            prop_assert!(
+                from.to_utc(offset) <= to.to_utc(offset),
+                "{:?} at {}: {:?} after {:?}", text, reference, from, to
+            );
+        }
+        for pair in &values {
+            if let (Some(from), Some(to)) = (&pair.from, &pair.to) {
+                prop_assert!(from.to_utc(offset) <= to.to_utc(offset));
+            }
+        }
+    }
+
+    #[test]
+    fn weekdays_resolve_within_the_coming_week(
+        reference in reference_time(),
+        offset in offset_minutes(),
+        (text, weekday) in proptest::sample::select(WEEKDAYS),
+    ) {
+        let local_today = reference.date_naive();
+        let entities = parse_at(text, reference, offset);
+        let Some(TimeValue::Single { value: TimePoint::Naive { value, grain }, .. }) =
+            whole_time(&entities, text)
+        else {
+            return Err(TestCaseError::fail(format!("{text:?} at {reference}: {entities:?}")));
+        };
+        prop_assert_eq!(grain, Grain::Day);
+        prop_assert_eq!(value.weekday(), weekday);
+        let ahead = (value.date() - local_today).num_days();
+        prop_assert!((0..=7).contains(&ahead), "{:?} at {} is {} days ahead", text, reference, ahead);
+    }
+
+    #[test]
+    fn relative_days_follow_the_local_date(
+        reference in reference_time(),
+        offset in offset_minutes(),
+    ) {
+        let local_today = reference.date_naive();
+        for (text, days) in [("yesterday", -1), ("today", 0), ("tomorrow", 1)] {
+            let entities = parse_at(text, reference, offset);
+            let Some(TimeValue::Single { value, .. }) = whole_time(&entities, text) else {
+                return Err(TestCaseError::fail(format!("{text:?} at {reference}: {entities:?}")));
+            };
+            let TimePoint::Naive { value, .. } = value else {
+                return Err(TestCaseError::fail(format!("{text:?} resolved to an instant")));
+            };
+            prop_assert_eq!(value.date(), local_today + Duration::days(days));
+        }
+    }
+
+    #[test]
+    fn kind_does_not_depend_on_the_reference_time(
+        first in reference_time(),
+        second in reference_time(),
+        offset in offset_minutes(),
+        (text, _, _) in proptest::sample::select(PHRASES),
+    ) {
+        let kind = |reference| {
+            whole_time(&parse_at(text, reference, offset), text)
+                .and_then(|value| value.start().map(is_instant))
+        };
+        prop_assert_eq!(kind(first), kind(second), "{:?} at {} and {}", text, first, second);
+    }
+}
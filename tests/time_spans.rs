@@ -0,0 +1,135 @@
+// Byte offsets of time entities in text with multi-byte letters and bidi
+// marks, and words that must not yield an entity for a shorter prefix.
+// The values themselves are covered by the corpora in src/testing/corpus.
+// Reference time for tests: 2013-02-12 04:30:00 at UTC-2
+
+use chrono::{NaiveDate, NaiveDateTime, TimeZone, Utc};
+use duckling::{
+    parse, Context, DimensionKind, DimensionValue, Entity, Lang, Locale, Options, TimePoint,
+    TimeValue,
+};
+
+fn parse_in(lang: Lang, text: &str) -> Vec<Entity> {
+    let locale = Locale::new(lang, None);
+    let context = Context {
+        reference_time: Utc.with_ymd_and_hms(2013, 2, 12, 4, 30, 0).unwrap(),
+        locale,
+        timezone_offset_minutes: -120,
+        ..Context::default()
+    };
+    parse(
+        text,
+        &locale,
+        &[DimensionKind::Time],
+        &context,
+        &Options::default(),
+    )
+}
+
+fn dt(y: i32, m: u32, d: u32, h: u32, mi: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(y, m, d)
+        .unwrap()
+        .and_hms_opt(h, mi, 0)
+        .unwrap()
+}
+
+/// The start of a single time or of an interval.
+fn start_of(entity: &Entity) -> Option<NaiveDateTime> {
+    let point = match &entity.value {
+        DimensionValue::Time(TimeValue::Single { value, .. }) => value,
+        DimensionValue::Time(TimeValue::Interval {
+            from: Some(value), ..
+        }) => value,
+        _ => return None,
+    };
+    Some(match point {
+        TimePoint::Naive { value, .. } => *value,
+        TimePoint::Instant { value, .. } => value.naive_utc(),
+    })
+}
+
+/// Asserts that `text` has one time entity, at byte offsets
+/// `start..end` with body `text[start..end]`, starting at `expected`.
+fn check_span(lang: Lang, text: &str, start: usize, end: usize, expected: NaiveDateTime) {
+    let entities = parse_in(lang, text);
+    assert_eq!(entities.len(), 1, "{text:?}: {entities:?}");
+    let entity = &entities[0];
+    assert_eq!((entity.start, entity.end), (start, end), "{text:?}");
+    assert_eq!(entity.body, text[start..end]);
+    assert_eq!(start_of(entity), Some(expected));
+}
+
+/// Asserts that every time entity in `text` spans the whole of it.
+fn check_whole(lang: Lang, text: &str) {
+    let entities = parse_in(lang, text);
+    assert!(
+        entities.iter().all(|e| e.body == text),
+        "{text:?}: {entities:?}"
+    );
+}
+
+#[test]
+fn test_he_spans_in_bidi_text() {
+    // "meeting at 15:30 tomorrow": Hebrew letters are two bytes each
+    check_span(
+        Lang::HE,
+        "פגישה ב-15:30 מחר",
+        11,
+        26,
+        dt(2013, 2, 13, 15, 30),
+    );
+    // The same with the right-to-left and left-to-right marks an RTL
+    // editor puts around words and numbers; the marks at the edges stay
+    // outside the entity.
+    check_span(
+        Lang::HE,
+        "פגישה \u{200f}מחר\u{200f} בשעה \u{200e}15:30\u{200e}",
+        14,
+        41,
+        dt(2013, 2, 13, 15, 30),
+    );
+    // Bidi isolates around a holiday and year
+    check_span(
+        Lang::HE,
+        "\u{2067}ראש השנה 2025\u{2069} בבית",
+        3,
+        23,
+        dt(2025, 9, 22, 0, 0),
+    );
+}
+
+#[test]
+fn test_uk_spans_in_cyrillic_text() {
+    // Cyrillic letters are two bytes each: "зустріч " is 15 bytes
+    check_span(
+        Lang::UK,
+        "зустріч завтра о 15:30",
+        15,
+        36,
+        dt(2013, 2, 13, 15, 30),
+    );
+    check_span(
+        Lang::UK,
+        "прийду наступного вівторка",
+        13,
+        50,
+        dt(2013, 2, 19, 0, 0),
+    );
+    check_span(
+        Lang::UK,
+        "свято на Великдень 2025",
+        11,
+        39,
+        dt(2025, 4, 20, 0, 0),
+    );
+}
+
+#[test]
+fn test_abbreviations_inside_words() {
+    // "mar" is also short for martedì, but not inside "marzo"
+    check_whole(Lang::IT, "il 3 marzo");
+    // "nocy" is not Sunday ("nd")
+    check_whole(Lang::PL, "w nocy");
+    // "martie" holds no Tuesday
+    check_whole(Lang::RO, "martie");
+}
@@ -170,3 +170,103 @@ fn test_volume_above_4_ml() {
     check_volume("over four milliliters", 4.0, "millilitre");
     check_volume("more than four mls", 4.0, "millilitre");
 }
+
+#[test]
+fn test_volume_id_units() {
+    use duckling::{parse, Context, Lang, Locale, Options};
+
+    let locale = Locale::new(Lang::ID, None);
+    let context = Context {
+        locale,
+        ..Context::default()
+    };
+    for (text, expected_val, expected_unit) in [
+        ("2 liter", 2.0, "litre"),
+        ("250 ml", 250.0, "millilitre"),
+        ("setengah liter", 0.5, "litre"),
+        ("3 sendok makan", 3.0, "tablespoon"),
+    ] {
+        let entities = parse(
+            text,
+            &locale,
+            &[DimensionKind::Volume],
+            &context,
+            &Options::default(),
+        );
+        assert!(
+            entities.iter().any(|e| e.body == text
+                && matches!(&e.value, DimensionValue::Volume(MeasurementValue::Value { value, unit })
+                    if (*value - expected_val).abs() < 0.01 && unit == expected_unit)),
+            "Expected volume {expected_val} {expected_unit} for '{text}', got: {entities:?}"
+        );
+    }
+}
+
+#[test]
+fn test_volume_halves_and_compounds() {
+    check_volume("1.5 liters", 1.5, "litre");
+    check_volume("750ml", 750.0, "millilitre");
+    check_volume("a pint and a half", 1.5, "pint");
+    check_volume("one and a half pints", 1.5, "pint");
+    check_volume("1 litre and 250 ml", 1.25, "litre");
+    check_volume("1 gallon 2 quarts", 5.678, "litre");
+}
+
+#[test]
+fn test_volume_containers() {
+    check_volume("two 500ml bottles", 1.0, "litre");
+    check_volume("2 x 500ml", 1.0, "litre");
+    check_volume("six cans of 330ml", 1.98, "litre");
+    check_volume("a 750ml bottle", 750.0, "millilitre");
+
+    let entities = parse_en("two 500ml bottles", &[DimensionKind::Volume]);
+    assert_eq!(entities.len(), 1, "{entities:?}");
+    let parts: Vec<(f64, &str)> = entities[0]
+        .components
+        .iter()
+        .map(|p| (p.value, p.unit.as_str()))
+        .collect();
+    assert_eq!(parts, [(500.0, "millilitre"), (500.0, "millilitre")]);
+}
+
+#[test]
+fn test_volume_compounds_fr_de() {
+    use duckling::{parse, Context, Lang, Locale, Options};
+
+    for (lang, text, expected_val, expected_unit) in [
+        (Lang::FR, "un litre et demi", 1.5, "litre"),
+        (Lang::FR, "deux bouteilles de 75 cl", 1.5, "litre"),
+        (Lang::FR, "2 x 33 cl", 0.66, "litre"),
+        (Lang::FR, "1 litre et 250 ml", 1.25, "litre"),
+        (Lang::FR, "750 ml", 750.0, "millilitre"),
+        (Lang::FR, "750ml", 750.0, "millilitre"),
+        (Lang::FR, "1,5 litre", 1.5, "litre"),
+        (Lang::DE, "eineinhalb Liter", 1.5, "litre"),
+        (Lang::DE, "zwei Flaschen à 0,5 Liter", 1.0, "litre"),
+        (Lang::DE, "6 Dosen mit 330 ml", 1.98, "litre"),
+        (Lang::DE, "1 Liter und 250 ml", 1.25, "litre"),
+        (Lang::DE, "750 ml", 750.0, "millilitre"),
+        (Lang::DE, "750ml", 750.0, "millilitre"),
+        (Lang::DE, "1,5 Liter", 1.5, "litre"),
+    ] {
+        let locale = Locale::new(lang, None);
+        let context = Context {
+            locale,
+            ..Context::default()
+        };
+        let entities = parse(
+            text,
+            &locale,
+            &[DimensionKind::Volume],
+            &context,
+            &Options::default(),
+        );
+        // Exactly one reading: "750 ml" is not 750 million litres
+        assert!(
+            matches!(entities.as_slice(), [e] if e.body == text
+                && matches!(&e.value, DimensionValue::Volume(MeasurementValue::Value { value, unit })
+                    if (*value - expected_val).abs() < 0.01 && unit == expected_unit)),
+            "Expected volume {expected_val} {expected_unit} for '{text}', got: {entities:?}"
+        );
+    }
+}